@@ -40,11 +40,57 @@ pub trait IntUnderlying: Sized
 /// ```no_run
 /// use winsafe::prelude::*;
 /// ```
-pub trait NativeConst: IntUnderlying
+pub trait NativeConst: IntUnderlying + 'static
 	+ Default + fmt::Debug + fmt::Display
 	+ fmt::LowerHex + fmt::UpperHex
 	+ fmt::Binary + fmt::Octal
-{}
+{
+	/// Returns the names and the values of all public constants of this type,
+	/// in the order they are declared. Values may be repeated under different
+	/// names.
+	///
+	/// The error types – [`CDERR`](crate::co::CDERR),
+	/// [`ERROR`](crate::co::ERROR) and [`HRESULT`](crate::co::HRESULT) –
+	/// return the constants of the embedded message tables, sorted by code,
+	/// when the `error_messages` feature is enabled; otherwise, an empty slice.
+	#[must_use]
+	fn named_values() -> &'static [(&'static str, Self)] {
+		&[]
+	}
+
+	/// Returns the name of the first public constant with this value, if any.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// assert_eq!(co::WM::CLOSE.name(), Some("CLOSE"));
+	/// ```
+	#[must_use]
+	fn name(&self) -> Option<&'static str> {
+		Self::named_values().iter()
+			.find(|(_, val)| val == self)
+			.map(|(name, _)| *name)
+	}
+
+	/// Returns the public constant with the given name, which may be prefixed
+	/// with the type name, like `WM::CLOSE`.
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// there is no constant with the name.
+	fn from_name(name: &str) -> SysResult<Self> {
+		let type_name = std::any::type_name::<Self>().rsplit("::").next().unwrap_or_default();
+		let name = name.trim();
+		let name = name.strip_prefix(type_name)
+			.and_then(|s| s.strip_prefix("::"))
+			.unwrap_or(name);
+		Self::named_values().iter()
+			.find(|(n, _)| *n == name)
+			.map(|(_, val)| *val)
+			.ok_or(co::ERROR::INVALID_DATA)
+	}
+}
 
 /// A native typed bitflag constant.
 ///
@@ -54,16 +100,77 @@ pub trait NativeConst: IntUnderlying
 /// use winsafe::prelude::*;
 /// ```
 pub trait NativeBitflag: NativeConst
-	+ ops::BitAnd + ops::BitAndAssign
-	+ ops::BitOr + ops::BitOrAssign
-	+ ops::BitXor + ops::BitXorAssign
-	+ ops::Not
+	+ ops::BitAnd<Output = Self> + ops::BitAndAssign
+	+ ops::BitOr<Output = Self> + ops::BitOrAssign
+	+ ops::BitXor<Output = Self> + ops::BitXorAssign
+	+ ops::Not<Output = Self>
 {
 	/// Tells whether other bitflag style is present.
 	///
 	/// Equivalent to `(val & other) != 0`.
 	#[must_use]
 	fn has(&self, other: Self) -> bool;
+
+	/// Decomposes the value into the public constants which compose it.
+	///
+	/// Composite constants, like `WS::OVERLAPPEDWINDOW`, are preferred over
+	/// the ones they contain, and come first. Bits which don't belong to
+	/// any public constant are not returned; zero returns an empty `Vec`.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let style = co::WS::OVERLAPPEDWINDOW | co::WS::VISIBLE;
+	/// for flag in style.flags() {
+	///     println!("{:?}", flag);
+	/// }
+	/// ```
+	#[must_use]
+	fn flags(&self) -> Vec<Self> {
+		let zero = Self::default();
+		let table = Self::named_values();
+		let mut rest = *self;
+		let mut flags = Vec::<Self>::default();
+
+		loop {
+			let num_found = flags.len();
+			for &(_, val) in table.iter() {
+				let is_free = |v: Self| v != zero && (v & rest) == v;
+				let has_bigger = table.iter()
+					.any(|&(_, other)| other != val && (other & val) == val && is_free(other));
+				if is_free(val) && !has_bigger {
+					flags.push(val);
+					rest &= !val;
+				}
+			}
+			if flags.len() == num_found {
+				break;
+			}
+		}
+
+		flags.sort_by_cached_key(|&flag| std::cmp::Reverse( // how many constants it contains
+			table.iter()
+				.filter(|&&(_, val)| val != zero && val != flag && (val & flag) == val)
+				.count(),
+		));
+		flags
+	}
+
+	/// Parses the names of public constants separated by `|`, each one
+	/// optionally prefixed with the type name, like
+	/// `WS::CAPTION | WS::VISIBLE`. An empty string returns zero.
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// a name is not found.
+	fn from_names(names: &str) -> SysResult<Self> {
+		if names.trim().is_empty() {
+			return Ok(Self::default());
+		}
+		names.split('|')
+			.try_fold(Self::default(), |acc, name| Ok(acc | Self::from_name(name)?))
+	}
 }
 
 /// A native typed constant mapped to a string.
@@ -101,3 +208,67 @@ pub trait FormattedError: Into<u32> {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn names() {
+		assert_eq!(co::REG::SZ.name(), Some("SZ"));
+		assert_eq!(co::LANG::AZERBAIJANI.name(), Some("AZERI")); // first with the value
+		assert_eq!(unsafe { co::REG::from_raw(99) }.name(), None);
+
+		assert_eq!(co::REG::from_name("SZ"), Ok(co::REG::SZ));
+		assert_eq!(co::REG::from_name(" REG::MULTI_SZ "), Ok(co::REG::MULTI_SZ));
+		assert_eq!(co::LANG::from_name("AZERBAIJANI"), Ok(co::LANG::AZERI));
+		assert_eq!(co::REG::from_name("LANG::SZ"), Err(co::ERROR::INVALID_DATA));
+		assert_eq!(co::REG::from_name("BOGUS"), Err(co::ERROR::INVALID_DATA));
+	}
+
+	#[test]
+	fn flags() {
+		assert_eq!(co::KEY::default().flags(), []);
+		assert_eq!(co::KEY::READ.flags(), [co::KEY::READ]); // EXECUTE has the same value
+		assert_eq!((co::KEY::READ | co::KEY::SET_VALUE).flags(),
+			[co::KEY::READ, co::KEY::SET_VALUE]); // composites first
+		assert_eq!((co::KEY::WOW64_32KEY | co::KEY::WOW64_64KEY).flags(), [co::KEY::WOW64_RES]);
+		assert_eq!(unsafe { co::KEY::from_raw(0x4000_0001) }.flags(), [co::KEY::QUERY_VALUE]);
+	}
+
+	#[test]
+	fn from_names() {
+		assert_eq!(co::KEY::from_names("KEY::READ | SET_VALUE"), Ok(co::KEY::READ | co::KEY::SET_VALUE));
+		assert_eq!(co::KEY::from_names("NOTIFY|NOTIFY"), Ok(co::KEY::NOTIFY));
+		assert_eq!(co::KEY::from_names(""), Ok(co::KEY::default()));
+		assert_eq!(co::KEY::from_names("  "), Ok(co::KEY::default()));
+		assert_eq!(co::KEY::from_names("READ | BOGUS"), Err(co::ERROR::INVALID_DATA));
+		assert_eq!(co::KEY::from_names("READ |"), Err(co::ERROR::INVALID_DATA));
+	}
+
+	#[test]
+	fn debug_output() {
+		assert_eq!(format!("{:?}", co::REG::SZ), "REG::SZ");
+		assert_eq!(format!("{:?}", unsafe { co::REG::from_raw(99) }), "[0x0063 99] REG");
+		assert_eq!(format!("{:?}", unsafe { co::REG::from_raw(0x1_0000) }), "[0x00010000 65536] REG");
+
+		assert_eq!(format!("{:?}", co::KEY::default()), "[0x0000 0] KEY");
+		assert_eq!(format!("{:?}", co::KEY::EXECUTE), "KEY::READ");
+		assert_eq!(format!("{:?}", co::KEY::NOTIFY | co::KEY::READ | co::KEY::CREATE_LINK),
+			"KEY::READ | KEY::CREATE_LINK");
+		assert_eq!(format!("{:?}", unsafe { co::KEY::from_raw(0x4000_0002) }),
+			"KEY::SET_VALUE | 0x40000000");
+	}
+
+	#[cfg(feature = "error_messages")]
+	#[test]
+	fn error_names() {
+		let err = co::ERROR::FILE_NOT_FOUND;
+		assert_eq!(NativeConst::name(&err), Some("FILE_NOT_FOUND"));
+		assert_eq!(err.symbolic_name(), Some("ERROR_FILE_NOT_FOUND"));
+		assert_eq!(co::ERROR::from_name("ERROR::FILE_NOT_FOUND"), Ok(err));
+		assert_eq!(format!("{:?}", err),
+			"ERROR_FILE_NOT_FOUND (0x2): The system cannot find the file specified.");
+		assert_eq!(unsafe { co::ERROR::from_raw(0xffff_fff0) }.symbolic_name(), None);
+	}
+}
@@ -15,6 +15,42 @@ const_bitflag! { ACCESS_RIGHTS: u32;
 	SYNCHRONIZE 0x0010_0000
 }
 
+const_bitflag! { ACE: u8;
+	/// [`ACE_HEADER`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-ace_header)
+	/// `AceFlags` (`u8`).
+	///
+	/// Originally has `ACE` suffix.
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	OBJECT_INHERIT 0x01
+	CONTAINER_INHERIT 0x02
+	NO_PROPAGATE_INHERIT 0x04
+	INHERIT_ONLY 0x08
+	INHERITED 0x10
+	SUCCESSFUL_ACCESS 0x40
+	FAILED_ACCESS 0x80
+}
+
+const_ordinary! { ACE_TYPE: u8;
+	/// [`ACE_HEADER`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-ace_header)
+	/// `AceType` (`u8`).
+	///
+	/// Originally has `ACE_TYPE` suffix.
+	=>
+	=>
+	ACCESS_ALLOWED 0x00
+	ACCESS_DENIED 0x01
+	SYSTEM_AUDIT 0x02
+	SYSTEM_ALARM 0x03
+	ACCESS_ALLOWED_OBJECT 0x05
+	ACCESS_DENIED_OBJECT 0x06
+	SYSTEM_AUDIT_OBJECT 0x07
+	SYSTEM_ALARM_OBJECT 0x08
+	SYSTEM_MANDATORY_LABEL 0x11
+}
+
 const_bitflag! { CONSOLE: u32;
 	/// [`SetConsoleMode`](crate::prelude::kernel_Hstd::SetConsoleMode) `mode`
 	/// (`u32`).
@@ -204,6 +240,15 @@ const_ordinary! { DRIVE: u32;
 	RAMDISK 6
 }
 
+const_bitflag! { DUPLICATE: u32;
+	/// [`HPROCESS::DuplicateHandle`](crate::prelude::kernel_Hprocess::DuplicateHandle)
+	/// `options` (`u32`).
+	=>
+	=>
+	CLOSE_SOURCE 0x0000_0001
+	SAME_ACCESS 0x0000_0002
+}
+
 const_ordinary! { EVENTLOG: u16;
 	/// [`HEVENTLOG::ReportEvent`](crate::prelude::kernel_Heventlog::ReportEvent)
 	/// `event_type` [`u16`].
@@ -515,6 +560,72 @@ const_ordinary! { HF32: u32;
 	DEFAULT 1
 }
 
+const_ordinary! { IMAGE_FILE_MACHINE: u16;
+	/// [`IMAGE_FILE_HEADER`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-image_file_header)
+	/// `Machine`
+	/// [types](https://learn.microsoft.com/en-us/windows/win32/sysinfo/image-file-machine-constants)
+	/// (`u16`).
+	=>
+	=>
+	UNKNOWN 0
+	TARGET_HOST 0x0001
+	I386 0x014c
+	R3000 0x0162
+	R4000 0x0166
+	R10000 0x0168
+	WCEMIPSV2 0x0169
+	ALPHA 0x0184
+	SH3 0x01a2
+	SH3DSP 0x01a3
+	SH3E 0x01a4
+	SH4 0x01a6
+	SH5 0x01a8
+	ARM 0x01c0
+	THUMB 0x01c2
+	ARMNT 0x01c4
+	AM33 0x01d3
+	POWERPC 0x01f0
+	POWERPCFP 0x01f1
+	IA64 0x0200
+	MIPS16 0x0266
+	ALPHA64 0x0284
+	AXP64 0x0284
+	MIPSFPU 0x0366
+	MIPSFPU16 0x0466
+	TRICORE 0x0520
+	CEF 0x0cef
+	EBC 0x0ebc
+	AMD64 0x8664
+	M32R 0x9041
+	ARM64 0xaa64
+	CEE 0xc0ee
+}
+
+const_bitflag! { IMAGE_SCN: u32;
+	/// [`IMAGE_SECTION_HEADER`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-image_section_header)
+	/// `Characteristics` (`u32`).
+	=>
+	=>
+	TYPE_NO_PAD 0x0000_0008
+	CNT_CODE 0x0000_0020
+	CNT_INITIALIZED_DATA 0x0000_0040
+	CNT_UNINITIALIZED_DATA 0x0000_0080
+	LNK_OTHER 0x0000_0100
+	LNK_INFO 0x0000_0200
+	LNK_REMOVE 0x0000_0800
+	LNK_COMDAT 0x0000_1000
+	NO_DEFER_SPEC_EXC 0x0000_4000
+	GPREL 0x0000_8000
+	LNK_NRELOC_OVFL 0x0100_0000
+	MEM_DISCARDABLE 0x0200_0000
+	MEM_NOT_CACHED 0x0400_0000
+	MEM_NOT_PAGED 0x0800_0000
+	MEM_SHARED 0x1000_0000
+	MEM_EXECUTE 0x2000_0000
+	MEM_READ 0x4000_0000
+	MEM_WRITE 0x8000_0000
+}
+
 const_bitflag! { KEY: u32;
 	/// [Registry access rights](https://learn.microsoft.com/en-us/windows/win32/sysinfo/registry-key-security-and-access-rights)
 	/// (`u32`).
@@ -721,6 +832,22 @@ const_bitflag! { MBC: u32;
 	USEGLYPHCHARS 0x0000_0004
 }
 
+const_bitflag! { MEMORYFLAGS: u16;
+	/// [`RESOURCEHEADER`](https://learn.microsoft.com/en-us/windows/win32/menurc/resourceheader)
+	/// `MemoryFlags` (`u16`). Ignored by 32-bit Windows, but still written by
+	/// the resource compiler.
+	///
+	/// Originally has no prefix.
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	MOVEABLE 0x0010
+	PURE 0x0020
+	PRELOAD 0x0040
+	DISCARDABLE 0x1000
+}
+
 const_ordinary! { PAGE: u32;
 	/// [`HFILE::CreateFileMapping`](crate::prelude::kernel_Hfile::CreateFileMapping)
 	/// `protect` (`u32`).
@@ -1,5 +1,8 @@
 #![allow(non_snake_case, non_upper_case_globals)]
 
+#[cfg(feature = "error_messages")] use std::sync::OnceLock;
+
+#[cfg(feature = "error_messages")] use crate::kernel::privs::*;
 use crate::prelude::FormattedError;
 
 const_no_debug_display! { ERROR: u32;
@@ -33,6 +36,16 @@ const_no_debug_display! { ERROR: u32;
 	///
 	/// The numbers inside the brackets are the system error code, in
 	/// hexadecimal and decimal formats for convenience.
+	///
+	/// With the `error_messages` feature, the symbolic names and the English
+	/// descriptions are embedded in the binary, so the output doesn't depend on
+	/// the OS language, and it can be parsed by tools:
+	///
+	/// ```text
+	/// ERROR_OUT_OF_PAPER (0x1c): The printer is out of paper.
+	/// ```
+	///
+	/// Codes without a constant keep the bracketed format.
 }
 
 impl std::error::Error for ERROR {
@@ -43,6 +56,11 @@ impl std::error::Error for ERROR {
 
 impl std::fmt::Display for ERROR {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		#[cfg(feature = "error_messages")]
+		if let Some((name, desc)) = find_error_msg(super::error_msg::ERROR_MSGS, self.0) {
+			return write!(f, "{} ({:#x}): {}", name, self.0, desc);
+		}
+
 		if self.0 > 0xffff {
 			write!(f, "[{:#010x} {}] {}",
 				self.0, self.0, self.FormatMessage())
@@ -60,6 +78,48 @@ impl std::fmt::Debug for ERROR {
 
 impl FormattedError for ERROR {}
 
+#[cfg(not(feature = "error_messages"))]
+impl crate::prelude::NativeConst for ERROR {}
+
+#[cfg(feature = "error_messages")]
+impl crate::prelude::NativeConst for ERROR {
+	fn named_values() -> &'static [(&'static str, Self)] {
+		static NAMED: OnceLock<Vec<(&str, ERROR)>> = OnceLock::new();
+		NAMED.get_or_init(|| {
+			super::error_msg::ERROR_MSGS.iter()
+				.map(|(code, name, _)| {
+					let name = name.strip_prefix("ERROR_").unwrap_or(name);
+					(name, unsafe { Self::from_raw(*code) })
+				})
+				.collect()
+		})
+	}
+}
+
+#[cfg(feature = "error_messages")]
+impl ERROR {
+	/// Returns the embedded English description of the error code, if any.
+	///
+	/// Available with the `error_messages` feature.
+	#[must_use]
+	pub fn description(self) -> Option<&'static str> {
+		find_error_msg(super::error_msg::ERROR_MSGS, self.0)
+			.map(|(_, desc)| desc)
+	}
+
+	/// Returns the symbolic name of the error code, like
+	/// `ERROR_FILE_NOT_FOUND`, if it's a known constant. Unlike
+	/// [`NativeConst::name`](crate::prelude::NativeConst::name), which returns
+	/// `FILE_NOT_FOUND`, the `ERROR_` prefix is kept.
+	///
+	/// Available with the `error_messages` feature.
+	#[must_use]
+	pub fn symbolic_name(self) -> Option<&'static str> {
+		find_error_msg(super::error_msg::ERROR_MSGS, self.0)
+			.map(|(name, _)| name)
+	}
+}
+
 const_values! { ERROR;
 	=>
 	/// The operation completed successfully.
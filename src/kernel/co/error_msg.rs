@@ -0,0 +1,2610 @@
+/// Names and English descriptions of [`ERROR`](crate::co::ERROR) constants.
+///
+/// Sorted by code, so it can be binary-searched.
+pub(crate) const ERROR_MSGS: &[(u32, &str, &str)] = &[
+	(0x0000_0000, "ERROR_SUCCESS", "The operation completed successfully."),
+	(0x0000_0001, "ERROR_INVALID_FUNCTION", "Incorrect function."),
+	(0x0000_0002, "ERROR_FILE_NOT_FOUND", "The system cannot find the file specified."),
+	(0x0000_0003, "ERROR_PATH_NOT_FOUND", "The system cannot find the path specified."),
+	(0x0000_0004, "ERROR_TOO_MANY_OPEN_FILES", "The system cannot open the file."),
+	(0x0000_0005, "ERROR_ACCESS_DENIED", "Access is denied."),
+	(0x0000_0006, "ERROR_INVALID_HANDLE", "The handle is invalid."),
+	(0x0000_0007, "ERROR_ARENA_TRASHED", "The storage control blocks were destroyed."),
+	(0x0000_0008, "ERROR_NOT_ENOUGH_MEMORY", "Not enough memory resources are available to process this command."),
+	(0x0000_0009, "ERROR_INVALID_BLOCK", "The storage control block address is invalid."),
+	(0x0000_000a, "ERROR_BAD_ENVIRONMENT", "The environment is incorrect."),
+	(0x0000_000b, "ERROR_BAD_FORMAT", "An attempt was made to load a program with an incorrect format."),
+	(0x0000_000c, "ERROR_INVALID_ACCESS", "The access code is invalid."),
+	(0x0000_000d, "ERROR_INVALID_DATA", "The data is invalid."),
+	(0x0000_000e, "ERROR_OUTOFMEMORY", "Not enough memory resources are available to complete this operation."),
+	(0x0000_000f, "ERROR_INVALID_DRIVE", "The system cannot find the drive specified."),
+	(0x0000_0010, "ERROR_CURRENT_DIRECTORY", "The directory cannot be removed."),
+	(0x0000_0011, "ERROR_NOT_SAME_DEVICE", "The system cannot move the file to a different disk drive."),
+	(0x0000_0012, "ERROR_NO_MORE_FILES", "There are no more files."),
+	(0x0000_0013, "ERROR_WRITE_PROTECT", "The media is write protected."),
+	(0x0000_0014, "ERROR_BAD_UNIT", "The system cannot find the device specified."),
+	(0x0000_0015, "ERROR_NOT_READY", "The device is not ready."),
+	(0x0000_0016, "ERROR_BAD_COMMAND", "The device does not recognize the command."),
+	(0x0000_0017, "ERROR_CRC", "Data error (cyclic redundancy check)."),
+	(0x0000_0018, "ERROR_BAD_LENGTH", "The program issued a command but the command length is incorrect."),
+	(0x0000_0019, "ERROR_SEEK", "The drive cannot locate a specific area or track on the disk."),
+	(0x0000_001a, "ERROR_NOT_DOS_DISK", "The specified disk or diskette cannot be accessed."),
+	(0x0000_001b, "ERROR_SECTOR_NOT_FOUND", "The drive cannot find the sector requested."),
+	(0x0000_001c, "ERROR_OUT_OF_PAPER", "The printer is out of paper."),
+	(0x0000_001d, "ERROR_WRITE_FAULT", "The system cannot write to the specified device."),
+	(0x0000_001e, "ERROR_READ_FAULT", "The system cannot read from the specified device."),
+	(0x0000_001f, "ERROR_GEN_FAILURE", "A device attached to the system is not functioning."),
+	(0x0000_0020, "ERROR_SHARING_VIOLATION", "The process cannot access the file because it is being used by another process."),
+	(0x0000_0021, "ERROR_LOCK_VIOLATION", "The process cannot access the file because another process has locked a portion of the file."),
+	(0x0000_0022, "ERROR_WRONG_DISK", "The wrong diskette is in the drive. Insert %2 (Volume Serial Number: %3) into drive %1."),
+	(0x0000_0024, "ERROR_SHARING_BUFFER_EXCEEDED", "Too many files opened for sharing."),
+	(0x0000_0026, "ERROR_HANDLE_EOF", "Reached the end of the file."),
+	(0x0000_0027, "ERROR_HANDLE_DISK_FULL", "The disk is full."),
+	(0x0000_0032, "ERROR_NOT_SUPPORTED", "The request is not supported."),
+	(0x0000_0033, "ERROR_REM_NOT_LIST", "Windows cannot find the network path. Verify that the network path is correct and the destination computer is not busy or turned off. If Windows still cannot find the network path, contact your network administrator."),
+	(0x0000_0034, "ERROR_DUP_NAME", "You were not connected because a duplicate name exists on the network. If joining a domain, go to System in Control Panel to change the computer name and try again. If joining a workgroup, choose another workgroup name."),
+	(0x0000_0035, "ERROR_BAD_NETPATH", "The network path was not found."),
+	(0x0000_0036, "ERROR_NETWORK_BUSY", "The network is busy."),
+	(0x0000_0037, "ERROR_DEV_NOT_EXIST", "The specified network resource or device is no longer available."),
+	(0x0000_0038, "ERROR_TOO_MANY_CMDS", "The network BIOS command limit has been reached."),
+	(0x0000_0039, "ERROR_ADAP_HDW_ERR", "A network adapter hardware error occurred."),
+	(0x0000_003a, "ERROR_BAD_NET_RESP", "The specified server cannot perform the requested operation."),
+	(0x0000_003b, "ERROR_UNEXP_NET_ERR", "An unexpected network error occurred."),
+	(0x0000_003c, "ERROR_BAD_REM_ADAP", "The remote adapter is not compatible."),
+	(0x0000_003d, "ERROR_PRINTQ_FULL", "The printer queue is full."),
+	(0x0000_003e, "ERROR_NO_SPOOL_SPACE", "Space to store the file waiting to be printed is not available on the server."),
+	(0x0000_003f, "ERROR_PRINT_CANCELLED", "Your file waiting to be printed was deleted."),
+	(0x0000_0040, "ERROR_NETNAME_DELETED", "The specified network name is no longer available."),
+	(0x0000_0041, "ERROR_NETWORK_ACCESS_DENIED", "Network access is denied."),
+	(0x0000_0042, "ERROR_BAD_DEV_TYPE", "The network resource type is not correct."),
+	(0x0000_0043, "ERROR_BAD_NET_NAME", "The network name cannot be found."),
+	(0x0000_0044, "ERROR_TOO_MANY_NAMES", "The name limit for the local computer network adapter card was exceeded."),
+	(0x0000_0045, "ERROR_TOO_MANY_SESS", "The network BIOS session limit was exceeded."),
+	(0x0000_0046, "ERROR_SHARING_PAUSED", "The remote server has been paused or is in the process of being started."),
+	(0x0000_0047, "ERROR_REQ_NOT_ACCEP", "No more connections can be made to this remote computer at this time because there are already as many connections as the computer can accept."),
+	(0x0000_0048, "ERROR_REDIR_PAUSED", "The specified printer or disk device has been paused."),
+	(0x0000_0050, "ERROR_FILE_EXISTS", "The file exists."),
+	(0x0000_0052, "ERROR_CANNOT_MAKE", "The directory or file cannot be created."),
+	(0x0000_0053, "ERROR_FAIL_I24", "Fail on INT 24."),
+	(0x0000_0054, "ERROR_OUT_OF_STRUCTURES", "Storage to process this request is not available."),
+	(0x0000_0055, "ERROR_ALREADY_ASSIGNED", "The local device name is already in use."),
+	(0x0000_0056, "ERROR_INVALID_PASSWORD", "The specified network password is not correct."),
+	(0x0000_0057, "ERROR_INVALID_PARAMETER", "The parameter is incorrect."),
+	(0x0000_0058, "ERROR_NET_WRITE_FAULT", "A write fault occurred on the network."),
+	(0x0000_0059, "ERROR_NO_PROC_SLOTS", "The system cannot start another process at this time."),
+	(0x0000_0064, "ERROR_TOO_MANY_SEMAPHORES", "Cannot create another system semaphore."),
+	(0x0000_0065, "ERROR_EXCL_SEM_ALREADY_OWNED", "The exclusive semaphore is owned by another process."),
+	(0x0000_0066, "ERROR_SEM_IS_SET", "The semaphore is set and cannot be closed."),
+	(0x0000_0067, "ERROR_TOO_MANY_SEM_REQUESTS", "The semaphore cannot be set again."),
+	(0x0000_0068, "ERROR_INVALID_AT_INTERRUPT_TIME", "Cannot request exclusive semaphores at interrupt time."),
+	(0x0000_0069, "ERROR_SEM_OWNER_DIED", "The previous ownership of this semaphore has ended."),
+	(0x0000_006a, "ERROR_SEM_USER_LIMIT", "Insert the diskette for drive %1."),
+	(0x0000_006b, "ERROR_DISK_CHANGE", "The program stopped because an alternate diskette was not inserted."),
+	(0x0000_006c, "ERROR_DRIVE_LOCKED", "The disk is in use or locked by another process."),
+	(0x0000_006d, "ERROR_BROKEN_PIPE", "The pipe has been ended."),
+	(0x0000_006e, "ERROR_OPEN_FAILED", "The system cannot open the device or file specified."),
+	(0x0000_006f, "ERROR_BUFFER_OVERFLOW", "The file name is too long."),
+	(0x0000_0070, "ERROR_DISK_FULL", "There is not enough space on the disk."),
+	(0x0000_0071, "ERROR_NO_MORE_SEARCH_HANDLES", "No more internal file identifiers available."),
+	(0x0000_0072, "ERROR_INVALID_TARGET_HANDLE", "The target internal file identifier is incorrect."),
+	(0x0000_0075, "ERROR_INVALID_CATEGORY", "The IOCTL call made by the application program is not correct."),
+	(0x0000_0076, "ERROR_INVALID_VERIFY_SWITCH", "The verify-on-write switch parameter value is not correct."),
+	(0x0000_0077, "ERROR_BAD_DRIVER_LEVEL", "The system does not support the command requested."),
+	(0x0000_0078, "ERROR_CALL_NOT_IMPLEMENTED", "This function is not supported on this system."),
+	(0x0000_0079, "ERROR_SEM_TIMEOUT", "The semaphore timeout period has expired."),
+	(0x0000_007a, "ERROR_INSUFFICIENT_BUFFER", "The data area passed to a system call is too small."),
+	(0x0000_007b, "ERROR_INVALID_NAME", "The filename, directory name, or volume label syntax is incorrect."),
+	(0x0000_007c, "ERROR_INVALID_LEVEL", "The system call level is not correct."),
+	(0x0000_007d, "ERROR_NO_VOLUME_LABEL", "The disk has no volume label."),
+	(0x0000_007e, "ERROR_MOD_NOT_FOUND", "The specified module could not be found."),
+	(0x0000_007f, "ERROR_PROC_NOT_FOUND", "The specified procedure could not be found."),
+	(0x0000_0080, "ERROR_WAIT_NO_CHILDREN", "There are no child processes to wait for."),
+	(0x0000_0081, "ERROR_CHILD_NOT_COMPLETE", "The %1 application cannot be run in Win32 mode."),
+	(0x0000_0082, "ERROR_DIRECT_ACCESS_HANDLE", "Attempt to use a file handle to an open disk partition for an operation other than raw disk I/O."),
+	(0x0000_0083, "ERROR_NEGATIVE_SEEK", "An attempt was made to move the file pointer before the beginning of the file."),
+	(0x0000_0084, "ERROR_SEEK_ON_DEVICE", "The file pointer cannot be set on the specified device or file."),
+	(0x0000_0085, "ERROR_IS_JOIN_TARGET", "A JOIN or SUBST command cannot be used for a drive that contains previously joined drives."),
+	(0x0000_0086, "ERROR_IS_JOINED", "An attempt was made to use a JOIN or SUBST command on a drive that has already been joined."),
+	(0x0000_0087, "ERROR_IS_SUBSTED", "An attempt was made to use a JOIN or SUBST command on a drive that has already been substituted."),
+	(0x0000_0088, "ERROR_NOT_JOINED", "The system tried to delete the JOIN of a drive that is not joined."),
+	(0x0000_0089, "ERROR_NOT_SUBSTED", "The system tried to delete the substitution of a drive that is not substituted."),
+	(0x0000_008a, "ERROR_JOIN_TO_JOIN", "The system tried to join a drive to a directory on a joined drive."),
+	(0x0000_008b, "ERROR_SUBST_TO_SUBST", "The system tried to substitute a drive to a directory on a substituted drive."),
+	(0x0000_008c, "ERROR_JOIN_TO_SUBST", "The system tried to join a drive to a directory on a substituted drive."),
+	(0x0000_008d, "ERROR_SUBST_TO_JOIN", "The system tried to SUBST a drive to a directory on a joined drive."),
+	(0x0000_008e, "ERROR_BUSY_DRIVE", "The system cannot perform a JOIN or SUBST at this time."),
+	(0x0000_008f, "ERROR_SAME_DRIVE", "The system cannot join or substitute a drive to or for a directory on the same drive."),
+	(0x0000_0090, "ERROR_DIR_NOT_ROOT", "The directory is not a subdirectory of the root directory."),
+	(0x0000_0091, "ERROR_DIR_NOT_EMPTY", "The directory is not empty."),
+	(0x0000_0092, "ERROR_IS_SUBST_PATH", "The path specified is being used in a substitute."),
+	(0x0000_0093, "ERROR_IS_JOIN_PATH", "Not enough resources are available to process this command."),
+	(0x0000_0094, "ERROR_PATH_BUSY", "The path specified cannot be used at this time."),
+	(0x0000_0095, "ERROR_IS_SUBST_TARGET", "The system tried to substitute a drive to a directory on a substituted drive."),
+	(0x0000_0096, "ERROR_SYSTEM_TRACE", "System trace information was not specified in your CONFIG.SYS file, or tracing is disallowed."),
+	(0x0000_0097, "ERROR_INVALID_EVENT_COUNT", "The number of specified semaphore events for DosMuxSemWait is not correct."),
+	(0x0000_0098, "ERROR_TOO_MANY_MUXWAITERS", "DosMuxSemWait did not execute; too many semaphores are already set."),
+	(0x0000_0099, "ERROR_INVALID_LIST_FORMAT", "The DosMuxSemWait list is not correct."),
+	(0x0000_009a, "ERROR_LABEL_TOO_LONG", "The volume label you entered exceeds the label character limit of the target file system."),
+	(0x0000_009b, "ERROR_TOO_MANY_TCBS", "Cannot create another thread."),
+	(0x0000_009c, "ERROR_SIGNAL_REFUSED", "The recipient process has refused the signal."),
+	(0x0000_009d, "ERROR_DISCARDED", "The segment is already discarded and cannot be locked."),
+	(0x0000_009e, "ERROR_NOT_LOCKED", "The segment is already unlocked."),
+	(0x0000_009f, "ERROR_BAD_THREADID_ADDR", "The address for the thread ID is not correct."),
+	(0x0000_00a0, "ERROR_BAD_ARGUMENTS", "One or more arguments are not correct."),
+	(0x0000_00a1, "ERROR_BAD_PATHNAME", "The specified path is invalid."),
+	(0x0000_00a2, "ERROR_SIGNAL_PENDING", "A signal is already pending."),
+	(0x0000_00a4, "ERROR_MAX_THRDS_REACHED", "No more threads can be created in the system."),
+	(0x0000_00a7, "ERROR_LOCK_FAILED", "Unable to lock a region of a file."),
+	(0x0000_00aa, "ERROR_BUSY", "The requested resource is in use."),
+	(0x0000_00ab, "ERROR_DEVICE_SUPPORT_IN_PROGRESS", "Device's command support detection is in progress."),
+	(0x0000_00ad, "ERROR_CANCEL_VIOLATION", "A lock request was not outstanding for the supplied cancel region."),
+	(0x0000_00ae, "ERROR_ATOMIC_LOCKS_NOT_SUPPORTED", "The file system does not support atomic changes to the lock type."),
+	(0x0000_00b4, "ERROR_INVALID_SEGMENT_NUMBER", "The system detected a segment number that was not correct."),
+	(0x0000_00b6, "ERROR_INVALID_ORDINAL", "The operating system cannot run %1."),
+	(0x0000_00b7, "ERROR_ALREADY_EXISTS", "Cannot create a file when that file already exists."),
+	(0x0000_00ba, "ERROR_INVALID_FLAG_NUMBER", "The flag passed is not correct."),
+	(0x0000_00bb, "ERROR_SEM_NOT_FOUND", "The specified system semaphore name was not found."),
+	(0x0000_00bc, "ERROR_INVALID_STARTING_CODESEG", "The operating system cannot run %1."),
+	(0x0000_00bd, "ERROR_INVALID_STACKSEG", "The operating system cannot run %1."),
+	(0x0000_00be, "ERROR_INVALID_MODULETYPE", "The operating system cannot run %1."),
+	(0x0000_00bf, "ERROR_INVALID_EXE_SIGNATURE", "Cannot run %1 in Win32 mode."),
+	(0x0000_00c0, "ERROR_EXE_MARKED_INVALID", "The operating system cannot run %1."),
+	(0x0000_00c1, "ERROR_BAD_EXE_FORMAT", "%1 is not a valid Win32 application."),
+	(0x0000_00c2, "ERROR_ITERATED_DATA_EXCEEDS_64k", "The operating system cannot run %1."),
+	(0x0000_00c3, "ERROR_INVALID_MINALLOCSIZE", "The operating system cannot run %1."),
+	(0x0000_00c4, "ERROR_DYNLINK_FROM_INVALID_RING", "The operating system cannot run this application program."),
+	(0x0000_00c5, "ERROR_IOPL_NOT_ENABLED", "The operating system is not presently configured to run this application."),
+	(0x0000_00c6, "ERROR_INVALID_SEGDPL", "The operating system cannot run %1."),
+	(0x0000_00c7, "ERROR_AUTODATASEG_EXCEEDS_64k", "The operating system cannot run this application program."),
+	(0x0000_00c8, "ERROR_RING2SEG_MUST_BE_MOVABLE", "The code segment cannot be greater than or equal to 64K."),
+	(0x0000_00c9, "ERROR_RELOC_CHAIN_XEEDS_SEGLIM", "The operating system cannot run %1."),
+	(0x0000_00ca, "ERROR_INFLOOP_IN_RELOC_CHAIN", "The operating system cannot run %1."),
+	(0x0000_00cb, "ERROR_ENVVAR_NOT_FOUND", "The system could not find the environment option that was entered."),
+	(0x0000_00cd, "ERROR_NO_SIGNAL_SENT", "No process in the command subtree has a signal handler."),
+	(0x0000_00ce, "ERROR_FILENAME_EXCED_RANGE", "The filename or extension is too long."),
+	(0x0000_00cf, "ERROR_RING2_STACK_IN_USE", "The ring 2 stack is in use."),
+	(0x0000_00d0, "ERROR_META_EXPANSION_TOO_LONG", "The global filename characters, * or ?, are entered incorrectly or too many global filename characters are specified."),
+	(0x0000_00d1, "ERROR_INVALID_SIGNAL_NUMBER", "The signal being posted is not correct."),
+	(0x0000_00d2, "ERROR_THREAD_1_INACTIVE", "The signal handler cannot be set."),
+	(0x0000_00d4, "ERROR_LOCKED", "The segment is locked and cannot be reallocated."),
+	(0x0000_00d6, "ERROR_TOO_MANY_MODULES", "Too many dynamic-link modules are attached to this program or dynamic-link module."),
+	(0x0000_00d7, "ERROR_NESTING_NOT_ALLOWED", "Cannot nest calls to LoadModule."),
+	(0x0000_00d8, "ERROR_EXE_MACHINE_TYPE_MISMATCH", "This version of %1 is not compatible with the version of Windows you're running. Check your computer's system information and then contact the software publisher."),
+	(0x0000_00d9, "ERROR_EXE_CANNOT_MODIFY_SIGNED_BINARY", "The image file %1 is signed, unable to modify."),
+	(0x0000_00da, "ERROR_EXE_CANNOT_MODIFY_STRONG_SIGNED_BINARY", "The image file %1 is strong signed, unable to modify."),
+	(0x0000_00dc, "ERROR_FILE_CHECKED_OUT", "This file is checked out or locked for editing by another user."),
+	(0x0000_00dd, "ERROR_CHECKOUT_REQUIRED", "The file must be checked out before saving changes."),
+	(0x0000_00de, "ERROR_BAD_FILE_TYPE", "The file type being saved or retrieved has been blocked."),
+	(0x0000_00df, "ERROR_FILE_TOO_LARGE", "The file size exceeds the limit allowed and cannot be saved."),
+	(0x0000_00e0, "ERROR_FORMS_AUTH_REQUIRED", "Access to the specified file has been restricted by an administrator. Please contact your administrator."),
+	(0x0000_00e1, "ERROR_VIRUS_INFECTED", "Operation did not complete successfully because the file contains a virus or potentially unwanted software."),
+	(0x0000_00e2, "ERROR_VIRUS_DELETED", "This file contains a virus or potentially unwanted software and was deleted."),
+	(0x0000_00e5, "ERROR_PIPE_LOCAL", "The pipe is local."),
+	(0x0000_00e6, "ERROR_BAD_PIPE", "The pipe state is invalid."),
+	(0x0000_00e7, "ERROR_PIPE_BUSY", "All pipe instances are busy."),
+	(0x0000_00e8, "ERROR_NO_DATA", "The pipe is being closed."),
+	(0x0000_00e9, "ERROR_PIPE_NOT_CONNECTED", "No process is on the other end of the pipe."),
+	(0x0000_00ea, "ERROR_MORE_DATA", "More data is available."),
+	(0x0000_00eb, "ERROR_NO_WORK_DONE", "Operation was unable to find any work to do."),
+	(0x0000_00f0, "ERROR_VC_DISCONNECTED", "The session was canceled."),
+	(0x0000_00fe, "ERROR_INVALID_EA_NAME", "The specified extended attribute name was invalid."),
+	(0x0000_00ff, "ERROR_EA_LIST_INCONSISTENT", "The extended attributes are inconsistent."),
+	(0x0000_0103, "ERROR_NO_MORE_ITEMS", "No more data is available."),
+	(0x0000_010a, "ERROR_CANNOT_COPY", "The copy functions cannot be used."),
+	(0x0000_010b, "ERROR_DIRECTORY", "The directory name is invalid."),
+	(0x0000_0113, "ERROR_EAS_DIDNT_FIT", "The extended attributes did not fit in the buffer."),
+	(0x0000_0114, "ERROR_EA_FILE_CORRUPT", "The extended attribute file on the mounted file system is corrupt."),
+	(0x0000_0115, "ERROR_EA_TABLE_FULL", "The extended attribute table file is full."),
+	(0x0000_0116, "ERROR_INVALID_EA_HANDLE", "The specified extended attribute handle is invalid."),
+	(0x0000_011a, "ERROR_EAS_NOT_SUPPORTED", "The mounted file system does not support extended attributes."),
+	(0x0000_0120, "ERROR_NOT_OWNER", "Attempt to release mutex not owned by caller."),
+	(0x0000_012a, "ERROR_TOO_MANY_POSTS", "Too many posts were made to a semaphore."),
+	(0x0000_012b, "ERROR_PARTIAL_COPY", "Only part of a ReadProcessMemory or WriteProcessMemory request was completed."),
+	(0x0000_012c, "ERROR_OPLOCK_NOT_GRANTED", "The oplock request is denied."),
+	(0x0000_012d, "ERROR_INVALID_OPLOCK_PROTOCOL", "An invalid oplock acknowledgment was received by the system."),
+	(0x0000_012e, "ERROR_DISK_TOO_FRAGMENTED", "The volume is too fragmented to complete this operation."),
+	(0x0000_012f, "ERROR_DELETE_PENDING", "The file cannot be opened because it is in the process of being deleted."),
+	(0x0000_0130, "ERROR_INCOMPATIBLE_WITH_GLOBAL_SHORT_NAME_REGISTRY_SETTING", "This operation cannot be performed because the global short name registry setting is incompatible."),
+	(0x0000_0131, "ERROR_SHORT_NAMES_NOT_ENABLED_ON_VOLUME", "Short names are not enabled on this volume."),
+	(0x0000_0132, "ERROR_SECURITY_STREAM_IS_INCONSISTENT", "The security stream for the given volume is in an inconsistent state. Please run CHKDSK on the volume."),
+	(0x0000_0133, "ERROR_INVALID_LOCK_RANGE", "A requested file lock operation cannot be processed due to an invalid byte range."),
+	(0x0000_0134, "ERROR_IMAGE_SUBSYSTEM_NOT_PRESENT", "The subsystem needed to support the image type is not present."),
+	(0x0000_0135, "ERROR_NOTIFICATION_GUID_ALREADY_DEFINED", "The specified file already has a notification GUID associated with it."),
+	(0x0000_0136, "ERROR_INVALID_EXCEPTION_HANDLER", "An invalid exception handler routine has been detected."),
+	(0x0000_0137, "ERROR_DUPLICATE_PRIVILEGES", "Duplicate privileges were specified for the token."),
+	(0x0000_0138, "ERROR_NO_RANGES_PROCESSED", "No ranges for the specified operation were able to be processed."),
+	(0x0000_0139, "ERROR_NOT_ALLOWED_ON_SYSTEM_FILE", "Operation is not allowed on a file system internal file."),
+	(0x0000_013a, "ERROR_DISK_RESOURCES_EXHAUSTED", "The physical resources of this disk have been exhausted."),
+	(0x0000_013b, "ERROR_INVALID_TOKEN", "The token representing the data is invalid."),
+	(0x0000_013c, "ERROR_DEVICE_FEATURE_NOT_SUPPORTED", "The device does not support the command feature."),
+	(0x0000_013d, "ERROR_MR_MID_NOT_FOUND", "The system cannot find message text for message number 0x%1 in the message file for %2."),
+	(0x0000_013e, "ERROR_SCOPE_NOT_FOUND", "The scope specified was not found."),
+	(0x0000_013f, "ERROR_UNDEFINED_SCOPE", "The Central Access Policy specified is not defined on the target machine."),
+	(0x0000_0140, "ERROR_INVALID_CAP", "The Central Access Policy obtained from Active Directory is invalid."),
+	(0x0000_0141, "ERROR_DEVICE_UNREACHABLE", "The device is unreachable."),
+	(0x0000_0142, "ERROR_DEVICE_NO_RESOURCES", "The target device has insufficient resources to complete the operation."),
+	(0x0000_0143, "ERROR_DATA_CHECKSUM_ERROR", "A data integrity checksum error occurred. Data in the file stream is corrupt."),
+	(0x0000_0144, "ERROR_INTERMIXED_KERNEL_EA_OPERATION", "An attempt was made to modify both a KERNEL and normal Extended Attribute (EA) in the same operation."),
+	(0x0000_0146, "ERROR_FILE_LEVEL_TRIM_NOT_SUPPORTED", "Device does not support file-level TRIM."),
+	(0x0000_0147, "ERROR_OFFSET_ALIGNMENT_VIOLATION", "The command specified a data offset that does not align to the device's granularity/alignment."),
+	(0x0000_0148, "ERROR_INVALID_FIELD_IN_PARAMETER_LIST", "The command specified an invalid field in its parameter list."),
+	(0x0000_0149, "ERROR_OPERATION_IN_PROGRESS", "An operation is currently in progress with the device."),
+	(0x0000_014a, "ERROR_BAD_DEVICE_PATH", "An attempt was made to send down the command via an invalid path to the target device."),
+	(0x0000_014b, "ERROR_TOO_MANY_DESCRIPTORS", "The command specified a number of descriptors that exceeded the maximum supported by the device."),
+	(0x0000_014c, "ERROR_SCRUB_DATA_DISABLED", "Scrub is disabled on the specified file."),
+	(0x0000_014d, "ERROR_NOT_REDUNDANT_STORAGE", "The storage device does not provide redundancy."),
+	(0x0000_014e, "ERROR_RESIDENT_FILE_NOT_SUPPORTED", "An operation is not supported on a resident file."),
+	(0x0000_014f, "ERROR_COMPRESSED_FILE_NOT_SUPPORTED", "An operation is not supported on a compressed file."),
+	(0x0000_0150, "ERROR_DIRECTORY_NOT_SUPPORTED", "An operation is not supported on a directory."),
+	(0x0000_0151, "ERROR_NOT_READ_FROM_COPY", "The specified copy of the requested data could not be read."),
+	(0x0000_0152, "ERROR_FT_WRITE_FAILURE", "The specified data could not be written to any of the copies."),
+	(0x0000_0153, "ERROR_FT_DI_SCAN_REQUIRED", "One or more copies of data on this device may be out of sync. No writes may be performed until a data integrity scan is completed."),
+	(0x0000_0154, "ERROR_INVALID_KERNEL_INFO_VERSION", "The supplied kernel information version is invalid."),
+	(0x0000_0155, "ERROR_INVALID_PEP_INFO_VERSION", "The supplied PEP information version is invalid."),
+	(0x0000_0156, "ERROR_OBJECT_NOT_EXTERNALLY_BACKED", "This object is not externally backed by any provider."),
+	(0x0000_0157, "ERROR_EXTERNAL_BACKING_PROVIDER_UNKNOWN", "The external backing provider is not recognized."),
+	(0x0000_0158, "ERROR_COMPRESSION_NOT_BENEFICIAL", "Compressing this object would not save space."),
+	(0x0000_0159, "ERROR_STORAGE_TOPOLOGY_ID_MISMATCH", "The request failed due to a storage topology ID mismatch."),
+	(0x0000_015a, "ERROR_BLOCKED_BY_PARENTAL_CONTROLS", "The operation was blocked by parental controls."),
+	(0x0000_015b, "ERROR_BLOCK_TOO_MANY_REFERENCES", "A file system block being referenced has already reached the maximum reference count and can't be referenced any further."),
+	(0x0000_015c, "ERROR_MARKED_TO_DISALLOW_WRITES", "The requested operation failed because the file stream is marked to disallow writes."),
+	(0x0000_015d, "ERROR_ENCLAVE_FAILURE", "The requested operation failed with an architecture-specific failure code."),
+	(0x0000_015e, "ERROR_FAIL_NOACTION_REBOOT", "No action was taken as a system reboot is required."),
+	(0x0000_015f, "ERROR_FAIL_SHUTDOWN", "The shutdown operation failed."),
+	(0x0000_0160, "ERROR_FAIL_RESTART", "The restart operation failed."),
+	(0x0000_0161, "ERROR_MAX_SESSIONS_REACHED", "The maximum number of sessions has been reached."),
+	(0x0000_0162, "ERROR_NETWORK_ACCESS_DENIED_EDP", "Windows Information Protection policy does not allow access to this network resource."),
+	(0x0000_0163, "ERROR_DEVICE_HINT_NAME_BUFFER_TOO_SMALL", "The device hint name buffer is too small to receive the remaining name."),
+	(0x0000_0164, "ERROR_EDP_POLICY_DENIES_OPERATION", "The requested operation was blocked by Windows Information Protection policy. For more information, contact your system administrator."),
+	(0x0000_0165, "ERROR_EDP_DPL_POLICY_CANT_BE_SATISFIED", "The requested operation cannot be performed because hardware or software configuration of the device does not comply with Windows Information Protection under Lock policy. Please, verify that user PIN has been created. For more information, contact your system administrator."),
+	(0x0000_0166, "ERROR_CLOUD_FILE_SYNC_ROOT_METADATA_CORRUPT", "The cloud sync root metadata is corrupted."),
+	(0x0000_0167, "ERROR_DEVICE_IN_MAINTENANCE", "The device is in maintenance mode."),
+	(0x0000_0168, "ERROR_NOT_SUPPORTED_ON_DAX", "This operation is not supported on a DAX volume."),
+	(0x0000_0169, "ERROR_DAX_MAPPING_EXISTS", "The volume has active DAX mappings."),
+	(0x0000_016a, "ERROR_CLOUD_FILE_PROVIDER_NOT_RUNNING", "The cloud file provider is not running."),
+	(0x0000_016b, "ERROR_CLOUD_FILE_METADATA_CORRUPT", "The cloud file metadata is corrupt and unreadable."),
+	(0x0000_016c, "ERROR_CLOUD_FILE_METADATA_TOO_LARGE", "The cloud file metadata is too large."),
+	(0x0000_016d, "ERROR_CLOUD_FILE_PROPERTY_BLOB_TOO_LARGE", "The cloud file property is too large."),
+	(0x0000_016e, "ERROR_CLOUD_FILE_PROPERTY_BLOB_CHECKSUM_MISMATCH", "The cloud file property is possibly corrupt. The on-disk checksum does not match the computed checksum."),
+	(0x0000_016f, "ERROR_CHILD_PROCESS_BLOCKED", "The process creation has been blocked."),
+	(0x0000_0170, "ERROR_STORAGE_LOST_DATA_PERSISTENCE", "The storage device has lost data or persistence."),
+	(0x0000_0171, "ERROR_FILE_SYSTEM_VIRTUALIZATION_UNAVAILABLE", "The provider that supports file system virtualization is temporarily unavailable."),
+	(0x0000_0172, "ERROR_FILE_SYSTEM_VIRTUALIZATION_METADATA_CORRUPT", "The metadata for file system virtualization is corrupt and unreadable."),
+	(0x0000_0173, "ERROR_FILE_SYSTEM_VIRTUALIZATION_BUSY", "The provider that supports file system virtualization is too busy to complete this operation."),
+	(0x0000_0174, "ERROR_FILE_SYSTEM_VIRTUALIZATION_PROVIDER_UNKNOWN", "The provider that supports file system virtualization is unknown."),
+	(0x0000_0175, "ERROR_GDI_HANDLE_LEAK", "GDI handles were potentially leaked by the application."),
+	(0x0000_0176, "ERROR_CLOUD_FILE_TOO_MANY_PROPERTY_BLOBS", "The maximum number of cloud file properties has been reached."),
+	(0x0000_0177, "ERROR_CLOUD_FILE_PROPERTY_VERSION_NOT_SUPPORTED", "The version of the cloud file property store is not supported."),
+	(0x0000_0178, "ERROR_NOT_A_CLOUD_FILE", "The file is not a cloud file."),
+	(0x0000_0179, "ERROR_CLOUD_FILE_NOT_IN_SYNC", "The file is not in sync with the cloud."),
+	(0x0000_017a, "ERROR_CLOUD_FILE_ALREADY_CONNECTED", "The cloud sync root is already connected with another cloud sync provider."),
+	(0x0000_017b, "ERROR_CLOUD_FILE_NOT_SUPPORTED", "The operation is not supported by the cloud sync provider."),
+	(0x0000_017c, "ERROR_CLOUD_FILE_INVALID_REQUEST", "The cloud operation is invalid."),
+	(0x0000_017d, "ERROR_CLOUD_FILE_READ_ONLY_VOLUME", "The cloud operation is not supported on a read-only volume."),
+	(0x0000_017e, "ERROR_CLOUD_FILE_CONNECTED_PROVIDER_ONLY", "The operation is reserved for a connected cloud sync provider."),
+	(0x0000_017f, "ERROR_CLOUD_FILE_VALIDATION_FAILED", "The cloud sync provider failed to validate the downloaded data."),
+	(0x0000_0180, "ERROR_SMB1_NOT_AVAILABLE", "You can't connect to the file share because it's not secure. This share requires the obsolete SMB1 protocol, which is unsafe and could expose your system to attack. Your system requires SMB2 or higher. For more info on resolving this issue, see: https://go.microsoft.com/fwlink/?linkid=852747"),
+	(0x0000_0181, "ERROR_FILE_SYSTEM_VIRTUALIZATION_INVALID_OPERATION", "The virtualization operation is not allowed on the file in its current state."),
+	(0x0000_0182, "ERROR_CLOUD_FILE_AUTHENTICATION_FAILED", "The cloud sync provider failed user authentication."),
+	(0x0000_0183, "ERROR_CLOUD_FILE_INSUFFICIENT_RESOURCES", "The cloud sync provider failed to perform the operation due to low system resources."),
+	(0x0000_0184, "ERROR_CLOUD_FILE_NETWORK_UNAVAILABLE", "The cloud sync provider failed to perform the operation due to network being unavailable."),
+	(0x0000_0185, "ERROR_CLOUD_FILE_UNSUCCESSFUL", "The cloud operation was unsuccessful."),
+	(0x0000_0186, "ERROR_CLOUD_FILE_NOT_UNDER_SYNC_ROOT", "The operation is only supported on files under a cloud sync root."),
+	(0x0000_0187, "ERROR_CLOUD_FILE_IN_USE", "The operation cannot be performed on cloud files in use."),
+	(0x0000_0188, "ERROR_CLOUD_FILE_PINNED", "The operation cannot be performed on pinned cloud files."),
+	(0x0000_0189, "ERROR_CLOUD_FILE_REQUEST_ABORTED", "The cloud operation was aborted."),
+	(0x0000_018a, "ERROR_CLOUD_FILE_PROPERTY_CORRUPT", "The cloud file's property store is corrupt."),
+	(0x0000_018b, "ERROR_CLOUD_FILE_ACCESS_DENIED", "Access to the cloud file is denied."),
+	(0x0000_018c, "ERROR_CLOUD_FILE_INCOMPATIBLE_HARDLINKS", "The cloud operation cannot be performed on a file with incompatible hardlinks."),
+	(0x0000_018d, "ERROR_CLOUD_FILE_PROPERTY_LOCK_CONFLICT", "The operation failed due to a conflicting cloud file property lock."),
+	(0x0000_018e, "ERROR_CLOUD_FILE_REQUEST_CANCELED", "The cloud operation was canceled by user."),
+	(0x0000_018f, "ERROR_EXTERNAL_SYSKEY_NOT_SUPPORTED", "An externally encrypted syskey has been configured, but the system no longer supports this feature. Please see https://go.microsoft.com/fwlink/?linkid=851152 for more information."),
+	(0x0000_0190, "ERROR_THREAD_MODE_ALREADY_BACKGROUND", "The thread is already in background processing mode."),
+	(0x0000_0191, "ERROR_THREAD_MODE_NOT_BACKGROUND", "The thread is not in background processing mode."),
+	(0x0000_0192, "ERROR_PROCESS_MODE_ALREADY_BACKGROUND", "The process is already in background processing mode."),
+	(0x0000_0193, "ERROR_PROCESS_MODE_NOT_BACKGROUND", "The process is not in background processing mode."),
+	(0x0000_0194, "ERROR_CLOUD_FILE_PROVIDER_TERMINATED", "The cloud file provider exited unexpectedly."),
+	(0x0000_0195, "ERROR_NOT_A_CLOUD_SYNC_ROOT", "The file is not a cloud sync root."),
+	(0x0000_0196, "ERROR_FILE_PROTECTED_UNDER_DPL", "The read or write operation to an encrypted file could not be completed because the file can only be accessed when the device is unlocked."),
+	(0x0000_0197, "ERROR_VOLUME_NOT_CLUSTER_ALIGNED", "The volume is not cluster aligned on the disk."),
+	(0x0000_0198, "ERROR_NO_PHYSICALLY_ALIGNED_FREE_SPACE_FOUND", "No physically aligned free space was found on the volume."),
+	(0x0000_0199, "ERROR_APPX_FILE_NOT_ENCRYPTED", "The APPX file can not be accessed because it is not encrypted as expected."),
+	(0x0000_019a, "ERROR_RWRAW_ENCRYPTED_FILE_NOT_ENCRYPTED", "A read or write of raw encrypted data cannot be performed because the file is not encrypted."),
+	(0x0000_019b, "ERROR_RWRAW_ENCRYPTED_INVALID_EDATAINFO_FILEOFFSET", "An invalid file offset in the encrypted data info block was passed for read or write operation of file's raw encrypted data."),
+	(0x0000_019c, "ERROR_RWRAW_ENCRYPTED_INVALID_EDATAINFO_FILERANGE", "An invalid offset and length combination in the encrypted data info block was passed for read or write operation of file's raw encrypted data."),
+	(0x0000_019d, "ERROR_RWRAW_ENCRYPTED_INVALID_EDATAINFO_PARAMETER", "An invalid parameter in the encrypted data info block was passed for read or write operation of file's raw encrypted data."),
+	(0x0000_019e, "ERROR_LINUX_SUBSYSTEM_NOT_PRESENT", "The Windows Subsystem for Linux has not been enabled."),
+	(0x0000_019f, "ERROR_FT_READ_FAILURE", "The specified data could not be read from any of the copies."),
+	(0x0000_01a0, "ERROR_STORAGE_RESERVE_ID_INVALID", "The specified storage reserve ID is invalid."),
+	(0x0000_01a1, "ERROR_STORAGE_RESERVE_DOES_NOT_EXIST", "The specified storage reserve does not exist."),
+	(0x0000_01a2, "ERROR_STORAGE_RESERVE_ALREADY_EXISTS", "The specified storage reserve already exists."),
+	(0x0000_01a3, "ERROR_STORAGE_RESERVE_NOT_EMPTY", "The specified storage reserve is not empty."),
+	(0x0000_01a4, "ERROR_NOT_A_DAX_VOLUME", "This operation requires a DAX volume."),
+	(0x0000_01a5, "ERROR_NOT_DAX_MAPPABLE", "This stream is not DAX mappable."),
+	(0x0000_01a6, "ERROR_TIME_SENSITIVE_THREAD", "Operation cannot be performed on a time critical thread."),
+	(0x0000_01a7, "ERROR_DPL_NOT_SUPPORTED_FOR_USER", "User data protection is not supported for the current or provided user."),
+	(0x0000_01a8, "ERROR_CASE_DIFFERING_NAMES_IN_DIR", "This directory contains entries whose names differ only in case."),
+	(0x0000_01a9, "ERROR_FILE_NOT_SUPPORTED", "The file cannot be safely opened because it is not supported by this version of Windows."),
+	(0x0000_01aa, "ERROR_CLOUD_FILE_REQUEST_TIMEOUT", "The cloud operation was not completed before the time-out period expired."),
+	(0x0000_01ab, "ERROR_NO_TASK_QUEUE", "A task queue is required for this operation but none is available."),
+	(0x0000_01ac, "ERROR_SRC_SRV_DLL_LOAD_FAILED", "Failed loading a valid version of srcsrv.dll."),
+	(0x0000_01ad, "ERROR_NOT_SUPPORTED_WITH_BTT", "This operation is not supported with BTT enabled."),
+	(0x0000_01ae, "ERROR_ENCRYPTION_DISABLED", "This operation cannot be performed because encryption is currently disabled."),
+	(0x0000_01af, "ERROR_ENCRYPTING_METADATA_DISALLOWED", "This encryption operation cannot be performed on filesystem metadata."),
+	(0x0000_01b0, "ERROR_CANT_CLEAR_ENCRYPTION_FLAG", "Encryption cannot be cleared on this file/directory because it still has an encrypted attribute."),
+	(0x0000_01b1, "ERROR_NO_SUCH_DEVICE", "A device which does not exist was specified."),
+	(0x0000_01c2, "ERROR_CAPAUTHZ_NOT_DEVUNLOCKED", "Neither developer unlocked mode nor side loading mode is enabled on the device."),
+	(0x0000_01c3, "ERROR_CAPAUTHZ_CHANGE_TYPE", "Can not change application type during upgrade or re-provision."),
+	(0x0000_01c4, "ERROR_CAPAUTHZ_NOT_PROVISIONED", "The application has not been provisioned."),
+	(0x0000_01c5, "ERROR_CAPAUTHZ_NOT_AUTHORIZED", "The requested capability can not be authorized for this application."),
+	(0x0000_01c6, "ERROR_CAPAUTHZ_NO_POLICY", "There is no capability authorization policy on the device."),
+	(0x0000_01c7, "ERROR_CAPAUTHZ_DB_CORRUPTED", "The capability authorization database has been corrupted."),
+	(0x0000_01c8, "ERROR_CAPAUTHZ_SCCD_INVALID_CATALOG", "The custom capability's SCCD has an invalid catalog."),
+	(0x0000_01c9, "ERROR_CAPAUTHZ_SCCD_NO_AUTH_ENTITY", "None of the authorized entity elements in the SCCD matched the app being installed; either the PFNs don't match, or the element's signature hash doesn't validate."),
+	(0x0000_01ca, "ERROR_CAPAUTHZ_SCCD_PARSE_ERROR", "The custom capability's SCCD failed to parse."),
+	(0x0000_01cb, "ERROR_CAPAUTHZ_SCCD_DEV_MODE_REQUIRED", "The custom capability's SCCD requires developer mode."),
+	(0x0000_01cc, "ERROR_CAPAUTHZ_SCCD_NO_CAPABILITY_MATCH", "There not all declared custom capabilities are found in the SCCD."),
+	(0x0000_01e0, "ERROR_PNP_QUERY_REMOVE_DEVICE_TIMEOUT", "The operation timed out waiting for this device to complete a PnP query-remove request due to a potential hang in its device stack. The system may need to be rebooted to complete the request."),
+	(0x0000_01e1, "ERROR_PNP_QUERY_REMOVE_RELATED_DEVICE_TIMEOUT", "The operation timed out waiting for this device to complete a PnP query-remove request due to a potential hang in the device stack of a related device. The system may need to be rebooted to complete the operation."),
+	(0x0000_01e2, "ERROR_PNP_QUERY_REMOVE_UNRELATED_DEVICE_TIMEOUT", "The operation timed out waiting for this device to complete a PnP query-remove request due to a potential hang in the device stack of an unrelated device. The system may need to be rebooted to complete the operation."),
+	(0x0000_01e3, "ERROR_DEVICE_HARDWARE_ERROR", "The request failed due to a fatal device hardware error."),
+	(0x0000_01e7, "ERROR_INVALID_ADDRESS", "Attempt to access invalid address."),
+	(0x0000_01f4, "ERROR_USER_PROFILE_LOAD", "User profile cannot be loaded."),
+	(0x0000_0216, "ERROR_ARITHMETIC_OVERFLOW", "Arithmetic result exceeded 32 bits."),
+	(0x0000_0217, "ERROR_PIPE_CONNECTED", "There is a process on other end of the pipe."),
+	(0x0000_0218, "ERROR_PIPE_LISTENING", "Waiting for a process to open the other end of the pipe."),
+	(0x0000_0219, "ERROR_VERIFIER_STOP", "Application verifier has found an error in the current process."),
+	(0x0000_021a, "ERROR_ABIOS_ERROR", "An error occurred in the ABIOS subsystem."),
+	(0x0000_021b, "ERROR_WX86_WARNING", "A warning occurred in the WX86 subsystem."),
+	(0x0000_021c, "ERROR_WX86_ERROR", "An error occurred in the WX86 subsystem."),
+	(0x0000_021d, "ERROR_TIMER_NOT_CANCELED", "An attempt was made to cancel or set a timer that has an associated APC and the subject thread is not the thread that originally set the timer with an associated APC routine."),
+	(0x0000_021e, "ERROR_UNWIND", "Unwind exception code."),
+	(0x0000_021f, "ERROR_BAD_STACK", "An invalid or unaligned stack was encountered during an unwind operation."),
+	(0x0000_0220, "ERROR_INVALID_UNWIND_TARGET", "An invalid unwind target was encountered during an unwind operation."),
+	(0x0000_0221, "ERROR_INVALID_PORT_ATTRIBUTES", "Invalid Object Attributes specified to NtCreatePort or invalid Port Attributes specified to NtConnectPort"),
+	(0x0000_0222, "ERROR_PORT_MESSAGE_TOO_LONG", "Length of message passed to NtRequestPort or NtRequestWaitReplyPort was longer than the maximum message allowed by the port."),
+	(0x0000_0223, "ERROR_INVALID_QUOTA_LOWER", "An attempt was made to lower a quota limit below the current usage."),
+	(0x0000_0224, "ERROR_DEVICE_ALREADY_ATTACHED", "An attempt was made to attach to a device that was already attached to another device."),
+	(0x0000_0225, "ERROR_INSTRUCTION_MISALIGNMENT", "An attempt was made to execute an instruction at an unaligned address and the host system does not support unaligned instruction references."),
+	(0x0000_0226, "ERROR_PROFILING_NOT_STARTED", "Profiling not started."),
+	(0x0000_0227, "ERROR_PROFILING_NOT_STOPPED", "Profiling not stopped."),
+	(0x0000_0228, "ERROR_COULD_NOT_INTERPRET", "The passed ACL did not contain the minimum required information."),
+	(0x0000_0229, "ERROR_PROFILING_AT_LIMIT", "The number of active profiling objects is at the maximum and no more may be started."),
+	(0x0000_022a, "ERROR_CANT_WAIT", "Used to indicate that an operation cannot continue without blocking for I/O."),
+	(0x0000_022b, "ERROR_CANT_TERMINATE_SELF", "Indicates that a thread attempted to terminate itself by default (called NtTerminateThread with NULL) and it was the last thread in the current process."),
+	(0x0000_022c, "ERROR_UNEXPECTED_MM_CREATE_ERR", "If an MM error is returned which is not defined in the standard FsRtl filter, it is converted to one of the following errors which is guaranteed to be in the filter. In this case information is lost, however, the filter correctly handles the exception."),
+	(0x0000_022d, "ERROR_UNEXPECTED_MM_MAP_ERROR", "If an MM error is returned which is not defined in the standard FsRtl filter, it is converted to one of the following errors which is guaranteed to be in the filter. In this case information is lost, however, the filter correctly handles the exception."),
+	(0x0000_022e, "ERROR_UNEXPECTED_MM_EXTEND_ERR", "If an MM error is returned which is not defined in the standard FsRtl filter, it is converted to one of the following errors which is guaranteed to be in the filter. In this case information is lost, however, the filter correctly handles the exception."),
+	(0x0000_022f, "ERROR_BAD_FUNCTION_TABLE", "A malformed function table was encountered during an unwind operation."),
+	(0x0000_0230, "ERROR_NO_GUID_TRANSLATION", "Indicates that an attempt was made to assign protection to a file system file or directory and one of the SIDs in the security descriptor could not be translated into a GUID that could be stored by the file system. This causes the protection attempt to fail, which may cause a file creation attempt to fail."),
+	(0x0000_0231, "ERROR_INVALID_LDT_SIZE", "Indicates that an attempt was made to grow an LDT by setting its size, or that the size was not an even number of selectors."),
+	(0x0000_0233, "ERROR_INVALID_LDT_OFFSET", "Indicates that the starting value for the LDT information was not an integral multiple of the selector size."),
+	(0x0000_0234, "ERROR_INVALID_LDT_DESCRIPTOR", "Indicates that the user supplied an invalid descriptor when trying to set up Ldt descriptors."),
+	(0x0000_0235, "ERROR_TOO_MANY_THREADS", "Indicates a process has too many threads to perform the requested action. For example, assignment of a primary token may only be performed when a process has zero or one threads."),
+	(0x0000_0236, "ERROR_THREAD_NOT_IN_PROCESS", "An attempt was made to operate on a thread within a specific process, but the thread specified is not in the process specified."),
+	(0x0000_0237, "ERROR_PAGEFILE_QUOTA_EXCEEDED", "Page file quota was exceeded."),
+	(0x0000_0238, "ERROR_LOGON_SERVER_CONFLICT", "The Netlogon service cannot start because another Netlogon service running in the domain conflicts with the specified role."),
+	(0x0000_0239, "ERROR_SYNCHRONIZATION_REQUIRED", "The SAM database on a Windows Server is significantly out of synchronization with the copy on the Domain Controller. A complete synchronization is required."),
+	(0x0000_023a, "ERROR_NET_OPEN_FAILED", "The NtCreateFile API failed. This error should never be returned to an application, it is a place holder for the Windows Lan Manager Redirector to use in its internal error mapping routines."),
+	(0x0000_023b, "ERROR_IO_PRIVILEGE_FAILED", "{Privilege Failed} The I/O permissions for the process could not be changed."),
+	(0x0000_023c, "ERROR_CONTROL_C_EXIT", "{Application Exit by CTRL+C} The application terminated as a result of a CTRL+C."),
+	(0x0000_023d, "ERROR_MISSING_SYSTEMFILE", "{Missing System File} The required system file %hs is bad or missing."),
+	(0x0000_023e, "ERROR_UNHANDLED_EXCEPTION", "{Application Error} The exception %s (0x%08lx) occurred in the application at location 0x%08lx."),
+	(0x0000_023f, "ERROR_APP_INIT_FAILURE", "{Application Error} The application was unable to start correctly (0x%lx). Click OK to close the application."),
+	(0x0000_0240, "ERROR_PAGEFILE_CREATE_FAILED", "{Unable to Create Paging File} The creation of the paging file %hs failed (%lx). The requested size was %ld."),
+	(0x0000_0241, "ERROR_INVALID_IMAGE_HASH", "Windows cannot verify the digital signature for this file. A recent hardware or software change might have installed a file that is signed incorrectly or damaged, or that might be malicious software from an unknown source."),
+	(0x0000_0242, "ERROR_NO_PAGEFILE", "{No Paging File Specified} No paging file was specified in the system configuration."),
+	(0x0000_0243, "ERROR_ILLEGAL_FLOAT_CONTEXT", "{EXCEPTION} A real-mode application issued a floating-point instruction and floating-point hardware is not present."),
+	(0x0000_0244, "ERROR_NO_EVENT_PAIR", "An event pair synchronization operation was performed using the thread specific client/server event pair object, but no event pair object was associated with the thread."),
+	(0x0000_0245, "ERROR_DOMAIN_CTRLR_CONFIG_ERROR", "A Windows Server has an incorrect configuration."),
+	(0x0000_0246, "ERROR_ILLEGAL_CHARACTER", "An illegal character was encountered. For a multi-byte character set this includes a lead byte without a succeeding trail byte. For the Unicode character set this includes the characters 0xFFFF and 0xFFFE."),
+	(0x0000_0247, "ERROR_UNDEFINED_CHARACTER", "The Unicode character is not defined in the Unicode character set installed on the system."),
+	(0x0000_0248, "ERROR_FLOPPY_VOLUME", "The paging file cannot be created on a floppy diskette."),
+	(0x0000_0249, "ERROR_BIOS_FAILED_TO_CONNECT_INTERRUPT", "The system BIOS failed to connect a system interrupt to the device or bus for which the device is connected."),
+	(0x0000_024a, "ERROR_BACKUP_CONTROLLER", "This operation is only allowed for the Primary Domain Controller of the domain."),
+	(0x0000_024b, "ERROR_MUTANT_LIMIT_EXCEEDED", "An attempt was made to acquire a mutant such that its maximum count would have been exceeded."),
+	(0x0000_024c, "ERROR_FS_DRIVER_REQUIRED", "A volume has been accessed for which a file system driver is required that has not yet been loaded."),
+	(0x0000_024d, "ERROR_CANNOT_LOAD_REGISTRY_FILE", "{Registry File Failure} The registry cannot load the hive (file): %hs or its log or alternate. It is corrupt, absent, or not writable."),
+	(0x0000_024e, "ERROR_DEBUG_ATTACH_FAILED", "{Unexpected Failure in DebugActiveProcess} An unexpected failure occurred while processing a DebugActiveProcess API request. You may choose OK to terminate the process, or Cancel to ignore the error."),
+	(0x0000_024f, "ERROR_SYSTEM_PROCESS_TERMINATED", "{Fatal System Error} The %hs system process terminated unexpectedly with a status of 0x%08x (0x%08x 0x%08x). The system has been shut down."),
+	(0x0000_0250, "ERROR_DATA_NOT_ACCEPTED", "{Data Not Accepted} The TDI client could not handle the data received during an indication."),
+	(0x0000_0251, "ERROR_VDM_HARD_ERROR", "NTVDM encountered a hard error."),
+	(0x0000_0252, "ERROR_DRIVER_CANCEL_TIMEOUT", "{Cancel Timeout} The driver %hs failed to complete a cancelled I/O request in the allotted time."),
+	(0x0000_0253, "ERROR_REPLY_MESSAGE_MISMATCH", "{Reply Message Mismatch} An attempt was made to reply to an LPC message, but the thread specified by the client ID in the message was not waiting on that message."),
+	(0x0000_0254, "ERROR_LOST_WRITEBEHIND_DATA", "{Delayed Write Failed} Windows was unable to save all the data for the file %hs. The data has been lost. This error may be caused by a failure of your computer hardware or network connection. Please try to save this file elsewhere."),
+	(0x0000_0255, "ERROR_CLIENT_SERVER_PARAMETERS_INVALID", "The parameter(s) passed to the server in the client/server shared memory window were invalid. Too much data may have been put in the shared memory window."),
+	(0x0000_0256, "ERROR_NOT_TINY_STREAM", "The stream is not a tiny stream."),
+	(0x0000_0257, "ERROR_STACK_OVERFLOW_READ", "The request must be handled by the stack overflow code."),
+	(0x0000_0258, "ERROR_CONVERT_TO_LARGE", "Internal OFS status codes indicating how an allocation operation is handled. Either it is retried after the containing onode is moved or the extent stream is converted to a large stream."),
+	(0x0000_0259, "ERROR_FOUND_OUT_OF_SCOPE", "The attempt to find the object found an object matching by ID on the volume but it is out of the scope of the handle used for the operation."),
+	(0x0000_025a, "ERROR_ALLOCATE_BUCKET", "The bucket array must be grown. Retry transaction after doing so."),
+	(0x0000_025b, "ERROR_MARSHALL_OVERFLOW", "The user/kernel marshalling buffer has overflowed."),
+	(0x0000_025c, "ERROR_INVALID_VARIANT", "The supplied variant structure contains invalid data."),
+	(0x0000_025d, "ERROR_BAD_COMPRESSION_BUFFER", "The specified buffer contains ill-formed data."),
+	(0x0000_025e, "ERROR_AUDIT_FAILED", "{Audit Failed} An attempt to generate a security audit failed."),
+	(0x0000_025f, "ERROR_TIMER_RESOLUTION_NOT_SET", "The timer resolution was not previously set by the current process."),
+	(0x0000_0260, "ERROR_INSUFFICIENT_LOGON_INFO", "There is insufficient account information to log you on."),
+	(0x0000_0261, "ERROR_BAD_DLL_ENTRYPOINT", "{Invalid DLL Entrypoint} The dynamic link library %hs is not written correctly. The stack pointer has been left in an inconsistent state. The entrypoint should be declared as WINAPI or STDCALL. Select YES to fail the DLL load. Select NO to continue execution. Selecting NO may cause the application to operate incorrectly."),
+	(0x0000_0262, "ERROR_BAD_SERVICE_ENTRYPOINT", "{Invalid Service Callback Entrypoint} The %hs service is not written correctly. The stack pointer has been left in an inconsistent state. The callback entrypoint should be declared as WINAPI or STDCALL. Selecting OK will cause the service to continue operation. However, the service process may operate incorrectly."),
+	(0x0000_0263, "ERROR_IP_ADDRESS_CONFLICT1", "There is an IP address conflict with another system on the network."),
+	(0x0000_0264, "ERROR_IP_ADDRESS_CONFLICT2", "There is an IP address conflict with another system on the network."),
+	(0x0000_0265, "ERROR_REGISTRY_QUOTA_LIMIT", "{Low On Registry Space} The system has reached the maximum size allowed for the system part of the registry. Additional storage requests will be ignored."),
+	(0x0000_0266, "ERROR_NO_CALLBACK_ACTIVE", "A callback return system service cannot be executed when no callback is active."),
+	(0x0000_0267, "ERROR_PWD_TOO_SHORT", "The password provided is too short to meet the policy of your user account. Please choose a longer password."),
+	(0x0000_0268, "ERROR_PWD_TOO_RECENT", "The policy of your user account does not allow you to change passwords too frequently. This is done to prevent users from changing back to a familiar, but potentially discovered, password. If you feel your password has been compromised then please contact your administrator immediately to have a new one assigned."),
+	(0x0000_0269, "ERROR_PWD_HISTORY_CONFLICT", "You have attempted to change your password to one that you have used in the past. The policy of your user account does not allow this. Please select a password that you have not previously used."),
+	(0x0000_026a, "ERROR_UNSUPPORTED_COMPRESSION", "The specified compression format is unsupported."),
+	(0x0000_026b, "ERROR_INVALID_HW_PROFILE", "The specified hardware profile configuration is invalid."),
+	(0x0000_026c, "ERROR_INVALID_PLUGPLAY_DEVICE_PATH", "The specified Plug and Play registry device path is invalid."),
+	(0x0000_026d, "ERROR_QUOTA_LIST_INCONSISTENT", "The specified quota list is internally inconsistent with its descriptor."),
+	(0x0000_026e, "ERROR_EVALUATION_EXPIRATION", "{Windows Evaluation Notification} The evaluation period for this installation of Windows has expired. This system will shutdown in 1 hour. To restore access to this installation of Windows, please upgrade this installation using a licensed distribution of this product."),
+	(0x0000_026f, "ERROR_ILLEGAL_DLL_RELOCATION", "{Illegal System DLL Relocation} The system DLL %hs was relocated in memory. The application will not run properly. The relocation occurred because the DLL %hs occupied an address range reserved for Windows system DLLs. The vendor supplying the DLL should be contacted for a new DLL."),
+	(0x0000_0270, "ERROR_DLL_INIT_FAILED_LOGOFF", "{DLL Initialization Failed} The application failed to initialize because the window station is shutting down."),
+	(0x0000_0271, "ERROR_VALIDATE_CONTINUE", "The validation process needs to continue on to the next step."),
+	(0x0000_0272, "ERROR_NO_MORE_MATCHES", "There are no more matches for the current index enumeration."),
+	(0x0000_0273, "ERROR_RANGE_LIST_CONFLICT", "The range could not be added to the range list because of a conflict."),
+	(0x0000_0274, "ERROR_SERVER_SID_MISMATCH", "The server process is running under a SID different than that required by client."),
+	(0x0000_0275, "ERROR_CANT_ENABLE_DENY_ONLY", "A group marked use for deny only cannot be enabled."),
+	(0x0000_0276, "ERROR_FLOAT_MULTIPLE_FAULTS", "{EXCEPTION} Multiple floating point faults."),
+	(0x0000_0277, "ERROR_FLOAT_MULTIPLE_TRAPS", "{EXCEPTION} Multiple floating point traps."),
+	(0x0000_0278, "ERROR_NOINTERFACE", "The requested interface is not supported."),
+	(0x0000_0279, "ERROR_DRIVER_FAILED_SLEEP", "{System Standby Failed} The driver %hs does not support standby mode. Updating this driver may allow the system to go to standby mode."),
+	(0x0000_027a, "ERROR_CORRUPT_SYSTEM_FILE", "The system file %1 has become corrupt and has been replaced."),
+	(0x0000_027b, "ERROR_COMMITMENT_MINIMUM", "{Virtual Memory Minimum Too Low} Your system is low on virtual memory. Windows is increasing the size of your virtual memory paging file. During this process, memory requests for some applications may be denied. For more information, see Help."),
+	(0x0000_027c, "ERROR_PNP_RESTART_ENUMERATION", "A device was removed so enumeration must be restarted."),
+	(0x0000_027d, "ERROR_SYSTEM_IMAGE_BAD_SIGNATURE", "{Fatal System Error} The system image %s is not properly signed. The file has been replaced with the signed file. The system has been shut down."),
+	(0x0000_027e, "ERROR_PNP_REBOOT_REQUIRED", "Device will not start without a reboot."),
+	(0x0000_027f, "ERROR_INSUFFICIENT_POWER", "There is not enough power to complete the requested operation."),
+	(0x0000_0280, "ERROR_MULTIPLE_FAULT_VIOLATION", "Multiple faults occurred while handling a single access violation."),
+	(0x0000_0281, "ERROR_SYSTEM_SHUTDOWN", "The system is in the process of shutting down."),
+	(0x0000_0282, "ERROR_PORT_NOT_SET", "An attempt to remove a processes DebugPort was made, but a port was not already associated with the process."),
+	(0x0000_0283, "ERROR_DS_VERSION_CHECK_FAILURE", "This version of Windows is not compatible with the behavior version of directory forest, domain or domain controller."),
+	(0x0000_0284, "ERROR_RANGE_NOT_FOUND", "The specified range could not be found in the range list."),
+	(0x0000_0286, "ERROR_NOT_SAFE_MODE_DRIVER", "The driver was not loaded because the system is booting into safe mode."),
+	(0x0000_0287, "ERROR_FAILED_DRIVER_ENTRY", "The driver was not loaded because it failed its initialization call."),
+	(0x0000_0288, "ERROR_DEVICE_ENUMERATION_ERROR", "The \"%hs\" encountered an error while applying power or reading the device configuration. This may be caused by a failure of your hardware or by a poor connection."),
+	(0x0000_0289, "ERROR_MOUNT_POINT_NOT_RESOLVED", "The create operation failed because the name contained at least one mount point which resolves to a volume to which the specified device object is not attached."),
+	(0x0000_028a, "ERROR_INVALID_DEVICE_OBJECT_PARAMETER", "The device object parameter is either not a valid device object or is not attached to the volume specified by the file name."),
+	(0x0000_028b, "ERROR_MCA_OCCURED", "A Machine Check Error has occurred. Please check the system eventlog for additional information."),
+	(0x0000_028c, "ERROR_DRIVER_DATABASE_ERROR", "There was error [%2] processing the driver database."),
+	(0x0000_028d, "ERROR_SYSTEM_HIVE_TOO_LARGE", "System hive size has exceeded its limit."),
+	(0x0000_028e, "ERROR_DRIVER_FAILED_PRIOR_UNLOAD", "The driver could not be loaded because a previous version of the driver is still in memory."),
+	(0x0000_028f, "ERROR_VOLSNAP_PREPARE_HIBERNATE", "{Volume Shadow Copy Service} Please wait while the Volume Shadow Copy Service prepares volume %hs for hibernation."),
+	(0x0000_0290, "ERROR_HIBERNATION_FAILURE", "The system has failed to hibernate (The error code is %hs). Hibernation will be disabled until the system is restarted."),
+	(0x0000_0291, "ERROR_PWD_TOO_LONG", "The password provided is too long to meet the policy of your user account. Please choose a shorter password."),
+	(0x0000_0299, "ERROR_FILE_SYSTEM_LIMITATION", "The requested operation could not be completed due to a file system limitation."),
+	(0x0000_029c, "ERROR_ASSERTION_FAILURE", "An assertion failure has occurred."),
+	(0x0000_029d, "ERROR_ACPI_ERROR", "An error occurred in the ACPI subsystem."),
+	(0x0000_029e, "ERROR_WOW_ASSERTION", "WOW Assertion Error."),
+	(0x0000_029f, "ERROR_PNP_BAD_MPS_TABLE", "A device is missing in the system BIOS MPS table. This device will not be used. Please contact your system vendor for system BIOS update."),
+	(0x0000_02a0, "ERROR_PNP_TRANSLATION_FAILED", "A translator failed to translate resources."),
+	(0x0000_02a1, "ERROR_PNP_IRQ_TRANSLATION_FAILED", "A IRQ translator failed to translate resources."),
+	(0x0000_02a2, "ERROR_PNP_INVALID_ID", "Driver %2 returned invalid ID for a child device (%3)."),
+	(0x0000_02a3, "ERROR_WAKE_SYSTEM_DEBUGGER", "{Kernel Debugger Awakened} the system debugger was awakened by an interrupt."),
+	(0x0000_02a4, "ERROR_HANDLES_CLOSED", "{Handles Closed} Handles to objects have been automatically closed as a result of the requested operation."),
+	(0x0000_02a5, "ERROR_EXTRANEOUS_INFORMATION", "{Too Much Information} The specified access control list (ACL) contained more information than was expected."),
+	(0x0000_02a6, "ERROR_RXACT_COMMIT_NECESSARY", "This warning level status indicates that the transaction state already exists for the registry sub-tree, but that a transaction commit was previously aborted. The commit has NOT been completed, but has not been rolled back either (so it may still be committed if desired)."),
+	(0x0000_02a7, "ERROR_MEDIA_CHECK", "{Media Changed} The media may have changed."),
+	(0x0000_02a8, "ERROR_GUID_SUBSTITUTION_MADE", "{GUID Substitution} During the translation of a global identifier (GUID) to a Windows security ID (SID), no administratively-defined GUID prefix was found. A substitute prefix was used, which will not compromise system security. However, this may provide a more restrictive access than intended."),
+	(0x0000_02a9, "ERROR_STOPPED_ON_SYMLINK", "The create operation stopped after reaching a symbolic link."),
+	(0x0000_02aa, "ERROR_LONGJUMP", "A long jump has been executed."),
+	(0x0000_02ab, "ERROR_PLUGPLAY_QUERY_VETOED", "The Plug and Play query operation was not successful."),
+	(0x0000_02ac, "ERROR_UNWIND_CONSOLIDATE", "A frame consolidation has been executed."),
+	(0x0000_02ad, "ERROR_REGISTRY_HIVE_RECOVERED", "{Registry Hive Recovered} Registry hive (file): %hs was corrupted and it has been recovered. Some data might have been lost."),
+	(0x0000_02ae, "ERROR_DLL_MIGHT_BE_INSECURE", "The application is attempting to run executable code from the module %hs. This may be insecure. An alternative, %hs, is available. Should the application use the secure module %hs?"),
+	(0x0000_02af, "ERROR_DLL_MIGHT_BE_INCOMPATIBLE", "The application is loading executable code from the module %hs. This is secure, but may be incompatible with previous releases of the operating system. An alternative, %hs, is available. Should the application use the secure module %hs?"),
+	(0x0000_02b0, "ERROR_DBG_EXCEPTION_NOT_HANDLED", "Debugger did not handle the exception."),
+	(0x0000_02b1, "ERROR_DBG_REPLY_LATER", "Debugger will reply later."),
+	(0x0000_02b2, "ERROR_DBG_UNABLE_TO_PROVIDE_HANDLE", "Debugger cannot provide handle."),
+	(0x0000_02b3, "ERROR_DBG_TERMINATE_THREAD", "Debugger terminated thread."),
+	(0x0000_02b4, "ERROR_DBG_TERMINATE_PROCESS", "Debugger terminated process."),
+	(0x0000_02b5, "ERROR_DBG_CONTROL_C", "Debugger got control C."),
+	(0x0000_02b6, "ERROR_DBG_PRINTEXCEPTION_C", "Debugger printed exception on control C."),
+	(0x0000_02b7, "ERROR_DBG_RIPEXCEPTION", "Debugger received RIP exception."),
+	(0x0000_02b8, "ERROR_DBG_CONTROL_BREAK", "Debugger received control break."),
+	(0x0000_02b9, "ERROR_DBG_COMMAND_EXCEPTION", "Debugger command communication exception."),
+	(0x0000_02ba, "ERROR_OBJECT_NAME_EXISTS", "{Object Exists} An attempt was made to create an object and the object name already existed."),
+	(0x0000_02bb, "ERROR_THREAD_WAS_SUSPENDED", "{Thread Suspended} A thread termination occurred while the thread was suspended. The thread was resumed, and termination proceeded."),
+	(0x0000_02bc, "ERROR_IMAGE_NOT_AT_BASE", "{Image Relocated} An image file could not be mapped at the address specified in the image file. Local fixups must be performed on this image."),
+	(0x0000_02bd, "ERROR_RXACT_STATE_CREATED", "This informational level status indicates that a specified registry sub-tree transaction state did not yet exist and had to be created."),
+	(0x0000_02be, "ERROR_SEGMENT_NOTIFICATION", "{Segment Load} A virtual DOS machine (VDM) is loading, unloading, or moving an MS-DOS or Win16 program segment image. An exception is raised so a debugger can load, unload or track symbols and breakpoints within these 16-bit segments."),
+	(0x0000_02bf, "ERROR_BAD_CURRENT_DIRECTORY", "{Invalid Current Directory} The process cannot switch to the startup current directory %hs. Select OK to set current directory to %hs, or select CANCEL to exit."),
+	(0x0000_02c0, "ERROR_FT_READ_RECOVERY_FROM_BACKUP", "{Redundant Read} To satisfy a read request, the NT fault-tolerant file system successfully read the requested data from a redundant copy. This was done because the file system encountered a failure on a member of the fault-tolerant volume, but was unable to reassign the failing area of the device."),
+	(0x0000_02c1, "ERROR_FT_WRITE_RECOVERY", "{Redundant Write} To satisfy a write request, the NT fault-tolerant file system successfully wrote a redundant copy of the information. This was done because the file system encountered a failure on a member of the fault-tolerant volume, but was not able to reassign the failing area of the device."),
+	(0x0000_02c2, "ERROR_IMAGE_MACHINE_TYPE_MISMATCH", "{Machine Type Mismatch} The image file %hs is valid, but is for a machine type other than the current machine. Select OK to continue, or CANCEL to fail the DLL load."),
+	(0x0000_02c3, "ERROR_RECEIVE_PARTIAL", "{Partial Data Received} The network transport returned partial data to its client. The remaining data will be sent later."),
+	(0x0000_02c4, "ERROR_RECEIVE_EXPEDITED", "{Expedited Data Received} The network transport returned data to its client that was marked as expedited by the remote system."),
+	(0x0000_02c5, "ERROR_RECEIVE_PARTIAL_EXPEDITED", "{Partial Expedited Data Received} The network transport returned partial data to its client and this data was marked as expedited by the remote system. The remaining data will be sent later."),
+	(0x0000_02c6, "ERROR_EVENT_DONE", "{TDI Event Done} The TDI indication has completed successfully."),
+	(0x0000_02c7, "ERROR_EVENT_PENDING", "{TDI Event Pending} The TDI indication has entered the pending state."),
+	(0x0000_02c8, "ERROR_CHECKING_FILE_SYSTEM", "Checking file system on %wZ."),
+	(0x0000_02c9, "ERROR_FATAL_APP_EXIT", "{Fatal Application Exit} %hs"),
+	(0x0000_02ca, "ERROR_PREDEFINED_HANDLE", "The specified registry key is referenced by a predefined handle."),
+	(0x0000_02cb, "ERROR_WAS_UNLOCKED", "{Page Unlocked} The page protection of a locked page was changed to 'No Access' and the page was unlocked from memory and from the process."),
+	(0x0000_02cc, "ERROR_SERVICE_NOTIFICATION", "%hs"),
+	(0x0000_02cd, "ERROR_WAS_LOCKED", "{Page Locked} One of the pages to lock was already locked."),
+	(0x0000_02ce, "ERROR_LOG_HARD_ERROR", "Application popup: %1 : %2"),
+	(0x0000_02cf, "ERROR_ALREADY_WIN32", "The status code is already a Win32 error code."),
+	(0x0000_02d0, "ERROR_IMAGE_MACHINE_TYPE_MISMATCH_EXE", "{Machine Type Mismatch} The image file %hs is valid, but is for a machine type other than the current machine."),
+	(0x0000_02d1, "ERROR_NO_YIELD_PERFORMED", "A yield execution was performed and no thread was available to run."),
+	(0x0000_02d2, "ERROR_TIMER_RESUME_IGNORED", "The resumable flag to a timer API was ignored."),
+	(0x0000_02d3, "ERROR_ARBITRATION_UNHANDLED", "The arbiter has deferred arbitration of these resources to its parent."),
+	(0x0000_02d4, "ERROR_CARDBUS_NOT_SUPPORTED", "The inserted CardBus device cannot be started because of a configuration error on \"%hs\"."),
+	(0x0000_02d5, "ERROR_MP_PROCESSOR_MISMATCH", "The CPUs in this multiprocessor system are not all the same revision level. To use all processors the operating system restricts itself to the features of the least capable processor in the system. Should problems occur with this system, contact the CPU manufacturer to see if this mix of processors is supported."),
+	(0x0000_02d6, "ERROR_HIBERNATED", "The system was put into hibernation."),
+	(0x0000_02d7, "ERROR_RESUME_HIBERNATION", "The system was resumed from hibernation."),
+	(0x0000_02d8, "ERROR_FIRMWARE_UPDATED", "Windows has detected that the system firmware (BIOS) was updated [previous firmware date = %2, current firmware date %3]."),
+	(0x0000_02d9, "ERROR_DRIVERS_LEAKING_LOCKED_PAGES", "A device driver is leaking locked I/O pages causing system degradation. The system has automatically enabled tracking code in order to try and catch the culprit."),
+	(0x0000_02da, "ERROR_WAKE_SYSTEM", "The system has awoken."),
+	(0x0000_02db, "ERROR_WAIT_1", "The wait was satisfied by the object at index 1."),
+	(0x0000_02dc, "ERROR_WAIT_2", "The wait was satisfied by the object at index 2."),
+	(0x0000_02dd, "ERROR_WAIT_3", "The wait was satisfied by the object at index 3."),
+	(0x0000_02de, "ERROR_WAIT_63", "The wait was satisfied by the object at index 63."),
+	(0x0000_02df, "ERROR_ABANDONED_WAIT_0", "The wait was satisfied by an abandoned mutex at index 0."),
+	(0x0000_02e0, "ERROR_ABANDONED_WAIT_63", "The wait was satisfied by an abandoned mutex at index 63."),
+	(0x0000_02e1, "ERROR_USER_APC", "A user-mode asynchronous procedure call was delivered during the wait."),
+	(0x0000_02e2, "ERROR_KERNEL_APC", "A kernel-mode asynchronous procedure call was delivered during the wait."),
+	(0x0000_02e3, "ERROR_ALERTED", "The wait was ended because the thread was alerted."),
+	(0x0000_02e4, "ERROR_ELEVATION_REQUIRED", "The requested operation requires elevation."),
+	(0x0000_02e5, "ERROR_REPARSE", "A reparse should be performed by the Object Manager since the name of the file resulted in a symbolic link."),
+	(0x0000_02e6, "ERROR_OPLOCK_BREAK_IN_PROGRESS", "An open/create operation completed while an oplock break is underway."),
+	(0x0000_02e7, "ERROR_VOLUME_MOUNTED", "A new volume has been mounted by a file system."),
+	(0x0000_02e8, "ERROR_RXACT_COMMITTED", "This success level status indicates that the transaction state already exists for the registry sub-tree, but that a transaction commit was previously aborted. The commit has now been completed."),
+	(0x0000_02e9, "ERROR_NOTIFY_CLEANUP", "This indicates that a notify change request has been completed due to closing the handle which made the notify change request."),
+	(0x0000_02ea, "ERROR_PRIMARY_TRANSPORT_CONNECT_FAILED", "{Connect Failure on Primary Transport} An attempt was made to connect to the remote server %hs on the primary transport, but the connection failed. The computer WAS able to connect on a secondary transport."),
+	(0x0000_02eb, "ERROR_PAGE_FAULT_TRANSITION", "Page fault was a transition fault."),
+	(0x0000_02ec, "ERROR_PAGE_FAULT_DEMAND_ZERO", "Page fault was a demand zero fault."),
+	(0x0000_02ed, "ERROR_PAGE_FAULT_COPY_ON_WRITE", "Page fault was a demand zero fault."),
+	(0x0000_02ee, "ERROR_PAGE_FAULT_GUARD_PAGE", "Page fault was a demand zero fault."),
+	(0x0000_02ef, "ERROR_PAGE_FAULT_PAGING_FILE", "Page fault was satisfied by reading from a secondary storage device."),
+	(0x0000_02f0, "ERROR_CACHE_PAGE_LOCKED", "Cached page was locked during operation."),
+	(0x0000_02f1, "ERROR_CRASH_DUMP", "Crash dump exists in paging file."),
+	(0x0000_02f2, "ERROR_BUFFER_ALL_ZEROS", "Specified buffer contains all zeros."),
+	(0x0000_02f3, "ERROR_REPARSE_OBJECT", "A reparse should be performed by the Object Manager since the name of the file resulted in a symbolic link."),
+	(0x0000_02f4, "ERROR_RESOURCE_REQUIREMENTS_CHANGED", "The device has succeeded a query-stop and its resource requirements have changed."),
+	(0x0000_02f5, "ERROR_TRANSLATION_COMPLETE", "The translator has translated these resources into the global space and no further translations should be performed."),
+	(0x0000_02f6, "ERROR_NOTHING_TO_TERMINATE", "No process is in the job."),
+	(0x0000_02f7, "ERROR_PROCESS_NOT_IN_JOB", "The specified process is not part of a job."),
+	(0x0000_02f8, "ERROR_PROCESS_IN_JOB", "The specified process is part of a job."),
+	(0x0000_02f9, "ERROR_VOLSNAP_HIBERNATE_READY", "{Volume Shadow Copy Service} The system is now ready for hibernation."),
+	(0x0000_02fa, "ERROR_FSFILTER_OP_COMPLETED_SUCCESSFULLY", "A file system or file system filter driver has successfully completed an FsFilter operation."),
+	(0x0000_02fb, "ERROR_INTERRUPT_VECTOR_ALREADY_CONNECTED", "The specified interrupt vector was already connected."),
+	(0x0000_02fc, "ERROR_INTERRUPT_STILL_CONNECTED", "The specified interrupt vector is still connected."),
+	(0x0000_02fd, "ERROR_WAIT_FOR_OPLOCK", "An operation is blocked waiting for an oplock."),
+	(0x0000_02fe, "ERROR_DBG_EXCEPTION_HANDLED", "Debugger handled exception."),
+	(0x0000_02ff, "ERROR_DBG_CONTINUE", "Debugger continued."),
+	(0x0000_0300, "ERROR_CALLBACK_POP_STACK", "An exception occurred in a user mode callback and the kernel callback frame should be removed."),
+	(0x0000_0301, "ERROR_COMPRESSION_DISABLED", "Compression is disabled for this volume."),
+	(0x0000_0302, "ERROR_CANTFETCHBACKWARDS", "The data provider cannot fetch backwards through a result set."),
+	(0x0000_0303, "ERROR_CANTSCROLLBACKWARDS", "The data provider cannot scroll backwards through a result set."),
+	(0x0000_0304, "ERROR_ROWSNOTRELEASED", "The data provider requires that previously fetched data is released before asking for more data."),
+	(0x0000_0305, "ERROR_BAD_ACCESSOR_FLAGS", "The data provider was not able to interpret the flags set for a column binding in an accessor."),
+	(0x0000_0306, "ERROR_ERRORS_ENCOUNTERED", "One or more errors occurred while processing the request."),
+	(0x0000_0307, "ERROR_NOT_CAPABLE", "The implementation is not capable of performing the request."),
+	(0x0000_0308, "ERROR_REQUEST_OUT_OF_SEQUENCE", "The client of a component requested an operation which is not valid given the state of the component instance."),
+	(0x0000_0309, "ERROR_VERSION_PARSE_ERROR", "A version number could not be parsed."),
+	(0x0000_030a, "ERROR_BADSTARTPOSITION", "The iterator's start position is invalid."),
+	(0x0000_030b, "ERROR_MEMORY_HARDWARE", "The hardware has reported an uncorrectable memory error."),
+	(0x0000_030c, "ERROR_DISK_REPAIR_DISABLED", "The attempted operation required self healing to be enabled."),
+	(0x0000_030d, "ERROR_INSUFFICIENT_RESOURCE_FOR_SPECIFIED_SHARED_SECTION_SIZE", "The Desktop heap encountered an error while allocating session memory. There is more information in the system event log."),
+	(0x0000_030e, "ERROR_SYSTEM_POWERSTATE_TRANSITION", "The system power state is transitioning from %2 to %3."),
+	(0x0000_030f, "ERROR_SYSTEM_POWERSTATE_COMPLEX_TRANSITION", "The system power state is transitioning from %2 to %3 but could enter %4."),
+	(0x0000_0310, "ERROR_MCA_EXCEPTION", "A thread is getting dispatched with MCA EXCEPTION because of MCA."),
+	(0x0000_0311, "ERROR_ACCESS_AUDIT_BY_POLICY", "Access to %1 is monitored by policy rule %2."),
+	(0x0000_0312, "ERROR_ACCESS_DISABLED_NO_SAFER_UI_BY_POLICY", "Access to %1 has been restricted by your Administrator by policy rule %2."),
+	(0x0000_0313, "ERROR_ABANDON_HIBERFILE", "A valid hibernation file has been invalidated and should be abandoned."),
+	(0x0000_0314, "ERROR_LOST_WRITEBEHIND_DATA_NETWORK_DISCONNECTED", "{Delayed Write Failed} Windows was unable to save all the data for the file %hs; the data has been lost. This error may be caused by network connectivity issues. Please try to save this file elsewhere."),
+	(0x0000_0315, "ERROR_LOST_WRITEBEHIND_DATA_NETWORK_SERVER_ERROR", "{Delayed Write Failed} Windows was unable to save all the data for the file %hs; the data has been lost. This error was returned by the server on which the file exists. Please try to save this file elsewhere."),
+	(0x0000_0316, "ERROR_LOST_WRITEBEHIND_DATA_LOCAL_DISK_ERROR", "{Delayed Write Failed} Windows was unable to save all the data for the file %hs; the data has been lost. This error may be caused if the device has been removed or the media is write-protected."),
+	(0x0000_0317, "ERROR_BAD_MCFG_TABLE", "The resources required for this device conflict with the MCFG table."),
+	(0x0000_0318, "ERROR_DISK_REPAIR_REDIRECTED", "The volume repair could not be performed while it is online. Please schedule to take the volume offline so that it can be repaired."),
+	(0x0000_0319, "ERROR_DISK_REPAIR_UNSUCCESSFUL", "The volume repair was not successful."),
+	(0x0000_031a, "ERROR_CORRUPT_LOG_OVERFULL", "One of the volume corruption logs is full. Further corruptions that may be detected won't be logged."),
+	(0x0000_031b, "ERROR_CORRUPT_LOG_CORRUPTED", "One of the volume corruption logs is internally corrupted and needs to be recreated. The volume may contain undetected corruptions and must be scanned."),
+	(0x0000_031c, "ERROR_CORRUPT_LOG_UNAVAILABLE", "One of the volume corruption logs is unavailable for being operated on."),
+	(0x0000_031d, "ERROR_CORRUPT_LOG_DELETED_FULL", "One of the volume corruption logs was deleted while still having corruption records in them. The volume contains detected corruptions and must be scanned."),
+	(0x0000_031e, "ERROR_CORRUPT_LOG_CLEARED", "One of the volume corruption logs was cleared by chkdsk and no longer contains real corruptions."),
+	(0x0000_031f, "ERROR_ORPHAN_NAME_EXHAUSTED", "Orphaned files exist on the volume but could not be recovered because no more new names could be created in the recovery directory. Files must be moved from the recovery directory."),
+	(0x0000_0320, "ERROR_OPLOCK_SWITCHED_TO_NEW_HANDLE", "The oplock that was associated with this handle is now associated with a different handle."),
+	(0x0000_0321, "ERROR_CANNOT_GRANT_REQUESTED_OPLOCK", "An oplock of the requested level cannot be granted. An oplock of a lower level may be available."),
+	(0x0000_0322, "ERROR_CANNOT_BREAK_OPLOCK", "The operation did not complete successfully because it would cause an oplock to be broken. The caller has requested that existing oplocks not be broken."),
+	(0x0000_0323, "ERROR_OPLOCK_HANDLE_CLOSED", "The handle with which this oplock was associated has been closed. The oplock is now broken."),
+	(0x0000_0324, "ERROR_NO_ACE_CONDITION", "The specified access control entry (ACE) does not contain a condition."),
+	(0x0000_0325, "ERROR_INVALID_ACE_CONDITION", "The specified access control entry (ACE) contains an invalid condition."),
+	(0x0000_0326, "ERROR_FILE_HANDLE_REVOKED", "Access to the specified file handle has been revoked."),
+	(0x0000_0327, "ERROR_IMAGE_AT_DIFFERENT_BASE", "{Image Relocated} An image file was mapped at a different address from the one specified in the image file but fixups will still be automatically performed on the image."),
+	(0x0000_0328, "ERROR_ENCRYPTED_IO_NOT_POSSIBLE", "The read or write operation to an encrypted file could not be completed because the file has not been opened for data access."),
+	(0x0000_0329, "ERROR_FILE_METADATA_OPTIMIZATION_IN_PROGRESS", "File metadata optimization is already in progress."),
+	(0x0000_032a, "ERROR_QUOTA_ACTIVITY", "The requested operation failed due to quota operation is still in progress."),
+	(0x0000_032b, "ERROR_HANDLE_REVOKED", "Access to the specified handle has been revoked."),
+	(0x0000_032c, "ERROR_CALLBACK_INVOKE_INLINE", "The callback function must be invoked inline."),
+	(0x0000_032d, "ERROR_CPU_SET_INVALID", "The specified CPU Set IDs are invalid."),
+	(0x0000_032e, "ERROR_ENCLAVE_NOT_TERMINATED", "The specified enclave has not yet been terminated."),
+	(0x0000_032f, "ERROR_ENCLAVE_VIOLATION", "An attempt was made to access protected memory in violation of its secure access policy."),
+	(0x0000_03e2, "ERROR_EA_ACCESS_DENIED", "Access to the extended attribute was denied."),
+	(0x0000_03e3, "ERROR_OPERATION_ABORTED", "The I/O operation has been aborted because of either a thread exit or an application request."),
+	(0x0000_03e4, "ERROR_IO_INCOMPLETE", "Overlapped I/O event is not in a signaled state."),
+	(0x0000_03e5, "ERROR_IO_PENDING", "Overlapped I/O operation is in progress."),
+	(0x0000_03e6, "ERROR_NOACCESS", "Invalid access to memory location."),
+	(0x0000_03e7, "ERROR_SWAPERROR", "Error performing inpage operation."),
+	(0x0000_03e9, "ERROR_STACK_OVERFLOW", "Recursion too deep; the stack overflowed."),
+	(0x0000_03ea, "ERROR_INVALID_MESSAGE", "The window cannot act on the sent message."),
+	(0x0000_03eb, "ERROR_CAN_NOT_COMPLETE", "Cannot complete this function."),
+	(0x0000_03ec, "ERROR_INVALID_FLAGS", "Invalid flags."),
+	(0x0000_03ed, "ERROR_UNRECOGNIZED_VOLUME", "The volume does not contain a recognized file system. Please make sure that all required file system drivers are loaded and that the volume is not corrupted."),
+	(0x0000_03ee, "ERROR_FILE_INVALID", "The volume for a file has been externally altered so that the opened file is no longer valid."),
+	(0x0000_03ef, "ERROR_FULLSCREEN_MODE", "The requested operation cannot be performed in full-screen mode."),
+	(0x0000_03f0, "ERROR_NO_TOKEN", "An attempt was made to reference a token that does not exist."),
+	(0x0000_03f1, "ERROR_BADDB", "The configuration registry database is corrupt."),
+	(0x0000_03f2, "ERROR_BADKEY", "The configuration registry key is invalid."),
+	(0x0000_03f3, "ERROR_CANTOPEN", "The configuration registry key could not be opened."),
+	(0x0000_03f4, "ERROR_CANTREAD", "The configuration registry key could not be read."),
+	(0x0000_03f5, "ERROR_CANTWRITE", "The configuration registry key could not be written."),
+	(0x0000_03f6, "ERROR_REGISTRY_RECOVERED", "One of the files in the registry database had to be recovered by use of a log or alternate copy. The recovery was successful."),
+	(0x0000_03f7, "ERROR_REGISTRY_CORRUPT", "The registry is corrupted. The structure of one of the files containing registry data is corrupted, or the system's memory image of the file is corrupted, or the file could not be recovered because the alternate copy or log was absent or corrupted."),
+	(0x0000_03f8, "ERROR_REGISTRY_IO_FAILED", "An I/O operation initiated by the registry failed unrecoverably. The registry could not read in, or write out, or flush, one of the files that contain the system's image of the registry."),
+	(0x0000_03f9, "ERROR_NOT_REGISTRY_FILE", "The system has attempted to load or restore a file into the registry, but the specified file is not in a registry file format."),
+	(0x0000_03fa, "ERROR_KEY_DELETED", "Illegal operation attempted on a registry key that has been marked for deletion."),
+	(0x0000_03fb, "ERROR_NO_LOG_SPACE", "System could not allocate the required space in a registry log."),
+	(0x0000_03fc, "ERROR_KEY_HAS_CHILDREN", "Cannot create a symbolic link in a registry key that already has subkeys or values."),
+	(0x0000_03fd, "ERROR_CHILD_MUST_BE_VOLATILE", "Cannot create a stable subkey under a volatile parent key."),
+	(0x0000_03fe, "ERROR_NOTIFY_ENUM_DIR", "A notify change request is being completed and the information is not being returned in the caller's buffer. The caller now needs to enumerate the files to find the changes."),
+	(0x0000_041b, "ERROR_DEPENDENT_SERVICES_RUNNING", "A stop control has been sent to a service that other running services are dependent on."),
+	(0x0000_041c, "ERROR_INVALID_SERVICE_CONTROL", "The requested control is not valid for this service."),
+	(0x0000_041d, "ERROR_SERVICE_REQUEST_TIMEOUT", "The service did not respond to the start or control request in a timely fashion."),
+	(0x0000_041e, "ERROR_SERVICE_NO_THREAD", "A thread could not be created for the service."),
+	(0x0000_041f, "ERROR_SERVICE_DATABASE_LOCKED", "The service database is locked."),
+	(0x0000_0420, "ERROR_SERVICE_ALREADY_RUNNING", "An instance of the service is already running."),
+	(0x0000_0421, "ERROR_INVALID_SERVICE_ACCOUNT", "The account name is invalid or does not exist, or the password is invalid for the account name specified."),
+	(0x0000_0422, "ERROR_SERVICE_DISABLED", "The service cannot be started, either because it is disabled or because it has no enabled devices associated with it."),
+	(0x0000_0423, "ERROR_CIRCULAR_DEPENDENCY", "Circular service dependency was specified."),
+	(0x0000_0424, "ERROR_SERVICE_DOES_NOT_EXIST", "The specified service does not exist as an installed service."),
+	(0x0000_0425, "ERROR_SERVICE_CANNOT_ACCEPT_CTRL", "The service cannot accept control messages at this time."),
+	(0x0000_0426, "ERROR_SERVICE_NOT_ACTIVE", "The service has not been started."),
+	(0x0000_0427, "ERROR_FAILED_SERVICE_CONTROLLER_CONNECT", "The service process could not connect to the service controller."),
+	(0x0000_0428, "ERROR_EXCEPTION_IN_SERVICE", "An exception occurred in the service when handling the control request."),
+	(0x0000_0429, "ERROR_DATABASE_DOES_NOT_EXIST", "The database specified does not exist."),
+	(0x0000_042a, "ERROR_SERVICE_SPECIFIC_ERROR", "The service has returned a service-specific error code."),
+	(0x0000_042b, "ERROR_PROCESS_ABORTED", "The process terminated unexpectedly."),
+	(0x0000_042c, "ERROR_SERVICE_DEPENDENCY_FAIL", "The dependency service or group failed to start."),
+	(0x0000_042d, "ERROR_SERVICE_LOGON_FAILED", "The service did not start due to a logon failure."),
+	(0x0000_042e, "ERROR_SERVICE_START_HANG", "After starting, the service hung in a start-pending state."),
+	(0x0000_042f, "ERROR_INVALID_SERVICE_LOCK", "The specified service database lock is invalid."),
+	(0x0000_0430, "ERROR_SERVICE_MARKED_FOR_DELETE", "The specified service has been marked for deletion."),
+	(0x0000_0431, "ERROR_SERVICE_EXISTS", "The specified service already exists."),
+	(0x0000_0432, "ERROR_ALREADY_RUNNING_LKG", "The current boot has already been accepted for use as the last-known-good control set."),
+	(0x0000_0433, "ERROR_SERVICE_DEPENDENCY_DELETED", "The dependency service does not exist or has been marked for deletion."),
+	(0x0000_0434, "ERROR_BOOT_ALREADY_ACCEPTED", "The current boot has already been accepted for use as the last-known-good control set."),
+	(0x0000_0435, "ERROR_SERVICE_NEVER_STARTED", "No attempts to start the service have been made since the last boot."),
+	(0x0000_0436, "ERROR_DUPLICATE_SERVICE_NAME", "The name is already in use as either a service name or a service display name."),
+	(0x0000_0437, "ERROR_DIFFERENT_SERVICE_ACCOUNT", "The account specified for this service is different from the account specified for other services running in the same process."),
+	(0x0000_0438, "ERROR_CANNOT_DETECT_DRIVER_FAILURE", "Failure actions can only be set for Win32 services, not for drivers."),
+	(0x0000_0439, "ERROR_CANNOT_DETECT_PROCESS_ABORT", "This service runs in the same process as the service control manager. Therefore, the service control manager cannot take action if this service's process terminates unexpectedly."),
+	(0x0000_043a, "ERROR_NO_RECOVERY_PROGRAM", "No recovery program has been configured for this service."),
+	(0x0000_043b, "ERROR_SERVICE_NOT_IN_EXE", "The executable program that this service is configured to run in does not implement the service."),
+	(0x0000_043c, "ERROR_NOT_SAFEBOOT_SERVICE", "This service cannot be started in Safe Mode."),
+	(0x0000_044c, "ERROR_END_OF_MEDIA", "The physical end of the tape has been reached."),
+	(0x0000_044d, "ERROR_FILEMARK_DETECTED", "A tape access reached a filemark."),
+	(0x0000_044e, "ERROR_BEGINNING_OF_MEDIA", "The beginning of the tape or a partition was encountered."),
+	(0x0000_044f, "ERROR_SETMARK_DETECTED", "A tape access reached the end of a set of files."),
+	(0x0000_0450, "ERROR_NO_DATA_DETECTED", "No more data is on the tape."),
+	(0x0000_0451, "ERROR_PARTITION_FAILURE", "Tape could not be partitioned."),
+	(0x0000_0452, "ERROR_INVALID_BLOCK_LENGTH", "When accessing a new tape of a multivolume partition, the current block size is incorrect."),
+	(0x0000_0453, "ERROR_DEVICE_NOT_PARTITIONED", "Tape partition information could not be found when loading a tape."),
+	(0x0000_0454, "ERROR_UNABLE_TO_LOCK_MEDIA", "Unable to lock the media eject mechanism."),
+	(0x0000_0455, "ERROR_UNABLE_TO_UNLOAD_MEDIA", "Unable to unload the media."),
+	(0x0000_0456, "ERROR_MEDIA_CHANGED", "The media in the drive may have changed."),
+	(0x0000_0457, "ERROR_BUS_RESET", "The I/O bus was reset."),
+	(0x0000_0458, "ERROR_NO_MEDIA_IN_DRIVE", "No media in drive."),
+	(0x0000_0459, "ERROR_NO_UNICODE_TRANSLATION", "No mapping for the Unicode character exists in the target multi-byte code page."),
+	(0x0000_045a, "ERROR_DLL_INIT_FAILED", "A dynamic link library (DLL) initialization routine failed."),
+	(0x0000_045b, "ERROR_SHUTDOWN_IN_PROGRESS", "A system shutdown is in progress."),
+	(0x0000_045c, "ERROR_NO_SHUTDOWN_IN_PROGRESS", "Unable to abort the system shutdown because no shutdown was in progress."),
+	(0x0000_045d, "ERROR_IO_DEVICE", "The request could not be performed because of an I/O device error."),
+	(0x0000_045e, "ERROR_SERIAL_NO_DEVICE", "No serial device was successfully initialized. The serial driver will unload."),
+	(0x0000_045f, "ERROR_IRQ_BUSY", "Unable to open a device that was sharing an interrupt request (IRQ) with other devices. At least one other device that uses that IRQ was already opened."),
+	(0x0000_0460, "ERROR_MORE_WRITES", "A serial I/O operation was completed by another write to the serial port. The IOCTL_SERIAL_XOFF_COUNTER reached zero."),
+	(0x0000_0461, "ERROR_COUNTER_TIMEOUT", "A serial I/O operation completed because the timeout period expired. The IOCTL_SERIAL_XOFF_COUNTER did not reach zero."),
+	(0x0000_0462, "ERROR_FLOPPY_ID_MARK_NOT_FOUND", "No ID address mark was found on the floppy disk."),
+	(0x0000_0463, "ERROR_FLOPPY_WRONG_CYLINDER", "Mismatch between the floppy disk sector ID field and the floppy disk controller track address."),
+	(0x0000_0464, "ERROR_FLOPPY_UNKNOWN_ERROR", "The floppy disk controller reported an error that is not recognized by the floppy disk driver."),
+	(0x0000_0465, "ERROR_FLOPPY_BAD_REGISTERS", "The floppy disk controller returned inconsistent results in its registers."),
+	(0x0000_0466, "ERROR_DISK_RECALIBRATE_FAILED", "While accessing the hard disk, a recalibrate operation failed, even after retries."),
+	(0x0000_0467, "ERROR_DISK_OPERATION_FAILED", "While accessing the hard disk, a disk operation failed even after retries."),
+	(0x0000_0468, "ERROR_DISK_RESET_FAILED", "While accessing the hard disk, a disk controller reset was needed, but even that failed."),
+	(0x0000_0469, "ERROR_EOM_OVERFLOW", "Physical end of tape encountered."),
+	(0x0000_046a, "ERROR_NOT_ENOUGH_SERVER_MEMORY", "Not enough server memory resources are available to process this command."),
+	(0x0000_046b, "ERROR_POSSIBLE_DEADLOCK", "A potential deadlock condition has been detected."),
+	(0x0000_046c, "ERROR_MAPPED_ALIGNMENT", "The base address or the file offset specified does not have the proper alignment."),
+	(0x0000_0474, "ERROR_SET_POWER_STATE_VETOED", "An attempt to change the system power state was vetoed by another application or driver."),
+	(0x0000_0475, "ERROR_SET_POWER_STATE_FAILED", "The system BIOS failed an attempt to change the system power state."),
+	(0x0000_0476, "ERROR_TOO_MANY_LINKS", "An attempt was made to create more links on a file than the file system supports."),
+	(0x0000_047e, "ERROR_OLD_WIN_VERSION", "The specified program requires a newer version of Windows."),
+	(0x0000_047f, "ERROR_APP_WRONG_OS", "The specified program requires a newer version of Windows."),
+	(0x0000_0480, "ERROR_SINGLE_INSTANCE_APP", "Cannot start more than one instance of the specified program."),
+	(0x0000_0481, "ERROR_RMODE_APP", "The specified program was written for an earlier version of Windows."),
+	(0x0000_0482, "ERROR_INVALID_DLL", "One of the library files needed to run this application is damaged."),
+	(0x0000_0483, "ERROR_NO_ASSOCIATION", "No application is associated with the specified file for this operation."),
+	(0x0000_0484, "ERROR_DDE_FAIL", "An error occurred in sending the command to the application."),
+	(0x0000_0485, "ERROR_DLL_NOT_FOUND", "One of the library files needed to run this application cannot be found."),
+	(0x0000_0486, "ERROR_NO_MORE_USER_HANDLES", "The current process has used all of its system allowance of handles for Window Manager objects."),
+	(0x0000_0487, "ERROR_MESSAGE_SYNC_ONLY", "The message can be used only with synchronous operations."),
+	(0x0000_0488, "ERROR_SOURCE_ELEMENT_EMPTY", "The indicated source element has no media."),
+	(0x0000_0489, "ERROR_DESTINATION_ELEMENT_FULL", "The indicated destination element already contains media."),
+	(0x0000_048a, "ERROR_ILLEGAL_ELEMENT_ADDRESS", "The indicated element does not exist."),
+	(0x0000_048b, "ERROR_MAGAZINE_NOT_PRESENT", "The indicated element is part of a magazine that is not present."),
+	(0x0000_048c, "ERROR_DEVICE_REINITIALIZATION_NEEDED", "The indicated device requires reinitialization due to hardware errors."),
+	(0x0000_048d, "ERROR_DEVICE_REQUIRES_CLEANING", "The device has indicated that cleaning is required before further operations are attempted."),
+	(0x0000_048e, "ERROR_DEVICE_DOOR_OPEN", "The device has indicated that its door is open."),
+	(0x0000_048f, "ERROR_DEVICE_NOT_CONNECTED", "The device is not connected."),
+	(0x0000_0490, "ERROR_NOT_FOUND", "Element not found."),
+	(0x0000_0491, "ERROR_NO_MATCH", "There was no match for the specified key in the index."),
+	(0x0000_0492, "ERROR_SET_NOT_FOUND", "The property set specified does not exist on the object."),
+	(0x0000_0493, "ERROR_POINT_NOT_FOUND", "Element not found."),
+	(0x0000_0494, "ERROR_NO_TRACKING_SERVICE", "The tracking (workstation) service is not running."),
+	(0x0000_0495, "ERROR_NO_VOLUME_ID", "The Volume ID could not be found."),
+	(0x0000_0497, "ERROR_UNABLE_TO_REMOVE_REPLACED", "Unable to remove the file to be replaced."),
+	(0x0000_0498, "ERROR_UNABLE_TO_MOVE_REPLACEMENT", "Unable to move the replacement file to the file to be replaced. The file to be replaced has retained its original name."),
+	(0x0000_0499, "ERROR_UNABLE_TO_MOVE_REPLACEMENT_2", "Unable to move the replacement file to the file to be replaced. The file to be replaced has been renamed using the backup name."),
+	(0x0000_049a, "ERROR_JOURNAL_DELETE_IN_PROGRESS", "The volume change journal is being deleted."),
+	(0x0000_049b, "ERROR_JOURNAL_NOT_ACTIVE", "The volume change journal is not active."),
+	(0x0000_049c, "ERROR_POTENTIAL_FILE_FOUND", "A file was found, but it may not be the correct file."),
+	(0x0000_049d, "ERROR_JOURNAL_ENTRY_DELETED", "The journal entry has been deleted from the journal."),
+	(0x0000_049f, "ERROR_VRF_CFG_ENABLED", "Driver Verifier Volatile settings cannot be set when CFG is enabled."),
+	(0x0000_04a0, "ERROR_PARTITION_TERMINATING", "An attempt was made to access a partition that has begun termination."),
+	(0x0000_04a6, "ERROR_SHUTDOWN_IS_SCHEDULED", "A system shutdown has already been scheduled."),
+	(0x0000_04a7, "ERROR_SHUTDOWN_USERS_LOGGED_ON", "The system shutdown cannot be initiated because there are other users logged on to the computer."),
+	(0x0000_04b0, "ERROR_BAD_DEVICE", "The specified device name is invalid."),
+	(0x0000_04b1, "ERROR_CONNECTION_UNAVAIL", "The device is not currently connected but it is a remembered connection."),
+	(0x0000_04b2, "ERROR_DEVICE_ALREADY_REMEMBERED", "The local device name has a remembered connection to another network resource."),
+	(0x0000_04b3, "ERROR_NO_NET_OR_BAD_PATH", "The network path was either typed incorrectly, does not exist, or the network provider is not currently available. Please try retyping the path or contact your network administrator."),
+	(0x0000_04b4, "ERROR_BAD_PROVIDER", "The specified network provider name is invalid."),
+	(0x0000_04b5, "ERROR_CANNOT_OPEN_PROFILE", "Unable to open the network connection profile."),
+	(0x0000_04b6, "ERROR_BAD_PROFILE", "The network connection profile is corrupted."),
+	(0x0000_04b7, "ERROR_NOT_CONTAINER", "Cannot enumerate a noncontainer."),
+	(0x0000_04b8, "ERROR_EXTENDED_ERROR", "An extended error has occurred."),
+	(0x0000_04b9, "ERROR_INVALID_GROUPNAME", "The format of the specified group name is invalid."),
+	(0x0000_04ba, "ERROR_INVALID_COMPUTERNAME", "The format of the specified computer name is invalid."),
+	(0x0000_04bb, "ERROR_INVALID_EVENTNAME", "The format of the specified event name is invalid."),
+	(0x0000_04bc, "ERROR_INVALID_DOMAINNAME", "The format of the specified domain name is invalid."),
+	(0x0000_04bd, "ERROR_INVALID_SERVICENAME", "The format of the specified service name is invalid."),
+	(0x0000_04be, "ERROR_INVALID_NETNAME", "The format of the specified network name is invalid."),
+	(0x0000_04bf, "ERROR_INVALID_SHARENAME", "The format of the specified share name is invalid."),
+	(0x0000_04c0, "ERROR_INVALID_PASSWORDNAME", "The format of the specified password is invalid."),
+	(0x0000_04c1, "ERROR_INVALID_MESSAGENAME", "The format of the specified message name is invalid."),
+	(0x0000_04c2, "ERROR_INVALID_MESSAGEDEST", "The format of the specified message destination is invalid."),
+	(0x0000_04c3, "ERROR_SESSION_CREDENTIAL_CONFLICT", "Multiple connections to a server or shared resource by the same user, using more than one user name, are not allowed. Disconnect all previous connections to the server or shared resource and try again."),
+	(0x0000_04c4, "ERROR_REMOTE_SESSION_LIMIT_EXCEEDED", "An attempt was made to establish a session to a network server, but there are already too many sessions established to that server."),
+	(0x0000_04c5, "ERROR_DUP_DOMAINNAME", "The workgroup or domain name is already in use by another computer on the network."),
+	(0x0000_04c6, "ERROR_NO_NETWORK", "The network is not present or not started."),
+	(0x0000_04c7, "ERROR_CANCELLED", "The operation was canceled by the user."),
+	(0x0000_04c8, "ERROR_USER_MAPPED_FILE", "The requested operation cannot be performed on a file with a user-mapped section open."),
+	(0x0000_04c9, "ERROR_CONNECTION_REFUSED", "The remote computer refused the network connection."),
+	(0x0000_04ca, "ERROR_GRACEFUL_DISCONNECT", "The network connection was gracefully closed."),
+	(0x0000_04cb, "ERROR_ADDRESS_ALREADY_ASSOCIATED", "The network transport endpoint already has an address associated with it."),
+	(0x0000_04cc, "ERROR_ADDRESS_NOT_ASSOCIATED", "An address has not yet been associated with the network endpoint."),
+	(0x0000_04cd, "ERROR_CONNECTION_INVALID", "An operation was attempted on a nonexistent network connection."),
+	(0x0000_04ce, "ERROR_CONNECTION_ACTIVE", "An invalid operation was attempted on an active network connection."),
+	(0x0000_04cf, "ERROR_NETWORK_UNREACHABLE", "The network location cannot be reached. For information about network troubleshooting, see Windows Help."),
+	(0x0000_04d0, "ERROR_HOST_UNREACHABLE", "The network location cannot be reached. For information about network troubleshooting, see Windows Help."),
+	(0x0000_04d1, "ERROR_PROTOCOL_UNREACHABLE", "The network location cannot be reached. For information about network troubleshooting, see Windows Help."),
+	(0x0000_04d2, "ERROR_PORT_UNREACHABLE", "No service is operating at the destination network endpoint on the remote system."),
+	(0x0000_04d3, "ERROR_REQUEST_ABORTED", "The request was aborted."),
+	(0x0000_04d4, "ERROR_CONNECTION_ABORTED", "The network connection was aborted by the local system."),
+	(0x0000_04d5, "ERROR_RETRY", "The operation could not be completed. A retry should be performed."),
+	(0x0000_04d6, "ERROR_CONNECTION_COUNT_LIMIT", "A connection to the server could not be made because the limit on the number of concurrent connections for this account has been reached."),
+	(0x0000_04d7, "ERROR_LOGIN_TIME_RESTRICTION", "Attempting to log in during an unauthorized time of day for this account."),
+	(0x0000_04d8, "ERROR_LOGIN_WKSTA_RESTRICTION", "The account is not authorized to log in from this station."),
+	(0x0000_04d9, "ERROR_INCORRECT_ADDRESS", "The network address could not be used for the operation requested."),
+	(0x0000_04da, "ERROR_ALREADY_REGISTERED", "The service is already registered."),
+	(0x0000_04db, "ERROR_SERVICE_NOT_FOUND", "The specified service does not exist."),
+	(0x0000_04dc, "ERROR_NOT_AUTHENTICATED", "The operation being requested was not performed because the user has not been authenticated."),
+	(0x0000_04dd, "ERROR_NOT_LOGGED_ON", "The operation being requested was not performed because the user has not logged on to the network. The specified service does not exist."),
+	(0x0000_04de, "ERROR_CONTINUE", "Continue with work in progress."),
+	(0x0000_04df, "ERROR_ALREADY_INITIALIZED", "An attempt was made to perform an initialization operation when initialization has already been completed."),
+	(0x0000_04e0, "ERROR_NO_MORE_DEVICES", "No more local devices."),
+	(0x0000_04e1, "ERROR_NO_SUCH_SITE", "The specified site does not exist."),
+	(0x0000_04e2, "ERROR_DOMAIN_CONTROLLER_EXISTS", "A domain controller with the specified name already exists."),
+	(0x0000_04e3, "ERROR_ONLY_IF_CONNECTED", "This operation is supported only when you are connected to the server."),
+	(0x0000_04e4, "ERROR_OVERRIDE_NOCHANGES", "The group policy framework should call the extension even if there are no changes."),
+	(0x0000_04e5, "ERROR_BAD_USER_PROFILE", "The specified user does not have a valid profile."),
+	(0x0000_04e6, "ERROR_NOT_SUPPORTED_ON_SBS", "This operation is not supported on a computer running Windows Server 2003 for Small Business Server."),
+	(0x0000_04e7, "ERROR_SERVER_SHUTDOWN_IN_PROGRESS", "The server machine is shutting down."),
+	(0x0000_04e8, "ERROR_HOST_DOWN", "The remote system is not available. For information about network troubleshooting, see Windows Help."),
+	(0x0000_04e9, "ERROR_NON_ACCOUNT_SID", "The security identifier provided is not from an account domain."),
+	(0x0000_04ea, "ERROR_NON_DOMAIN_SID", "The security identifier provided does not have a domain component."),
+	(0x0000_04eb, "ERROR_APPHELP_BLOCK", "AppHelp dialog canceled thus preventing the application from starting."),
+	(0x0000_04ec, "ERROR_ACCESS_DISABLED_BY_POLICY", "This program is blocked by group policy. For more information, contact your system administrator."),
+	(0x0000_04ed, "ERROR_REG_NAT_CONSUMPTION", "A program attempt to use an invalid register value. Normally caused by an uninitialized register. This error is Itanium specific."),
+	(0x0000_04ee, "ERROR_CSCSHARE_OFFLINE", "The share is currently offline or does not exist."),
+	(0x0000_04ef, "ERROR_PKINIT_FAILURE", "The Kerberos protocol encountered an error while validating the KDC certificate during smartcard logon. There is more information in the system event log."),
+	(0x0000_04f0, "ERROR_SMARTCARD_SUBSYSTEM_FAILURE", "The Kerberos protocol encountered an error while attempting to utilize the smartcard subsystem."),
+	(0x0000_04f1, "ERROR_DOWNGRADE_DETECTED", "The system cannot contact a domain controller to service the authentication request. Please try again later."),
+	(0x0000_04f7, "ERROR_MACHINE_LOCKED", "The machine is locked and cannot be shut down without the force option."),
+	(0x0000_04f8, "ERROR_SMB_GUEST_LOGON_BLOCKED", "You can't access this shared folder because your organization's security policies block unauthenticated guest access. These policies help protect your PC from unsafe or malicious devices on the network."),
+	(0x0000_04f9, "ERROR_CALLBACK_SUPPLIED_INVALID_DATA", "The callback function supplied invalid data."),
+	(0x0000_04fa, "ERROR_SYNC_FOREGROUND_REFRESH_REQUIRED", "The group policy framework should call the extension in the synchronous foreground policy refresh."),
+	(0x0000_04fb, "ERROR_DRIVER_BLOCKED", "This driver has been blocked from loading."),
+	(0x0000_04fc, "ERROR_INVALID_IMPORT_OF_NON_DLL", "A dynamic link library (DLL) referenced a module that was neither a DLL nor the process's executable image."),
+	(0x0000_04fd, "ERROR_ACCESS_DISABLED_WEBBLADE", "Windows cannot open this program since it has been disabled."),
+	(0x0000_04fe, "ERROR_ACCESS_DISABLED_WEBBLADE_TAMPER", "Windows cannot open this program because the license enforcement system has been tampered with or become corrupted."),
+	(0x0000_04ff, "ERROR_RECOVERY_FAILURE", "A transaction recover failed."),
+	(0x0000_0500, "ERROR_ALREADY_FIBER", "The current thread has already been converted to a fiber."),
+	(0x0000_0501, "ERROR_ALREADY_THREAD", "The current thread has already been converted from a fiber."),
+	(0x0000_0502, "ERROR_STACK_BUFFER_OVERRUN", "The system detected an overrun of a stack-based buffer in this application. This overrun could potentially allow a malicious user to gain control of this application."),
+	(0x0000_0503, "ERROR_PARAMETER_QUOTA_EXCEEDED", "Data present in one of the parameters is more than the function can operate on."),
+	(0x0000_0504, "ERROR_DEBUGGER_INACTIVE", "An attempt to do an operation on a debug object failed because the object is in the process of being deleted."),
+	(0x0000_0505, "ERROR_DELAY_LOAD_FAILED", "An attempt to delay-load a .dll or get a function address in a delay-loaded .dll failed."),
+	(0x0000_0506, "ERROR_VDM_DISALLOWED", "%1 is a 16-bit application. You do not have permissions to execute 16-bit applications. Check your permissions with your system administrator."),
+	(0x0000_0507, "ERROR_UNIDENTIFIED_ERROR", "Insufficient information exists to identify the cause of failure."),
+	(0x0000_0508, "ERROR_INVALID_CRUNTIME_PARAMETER", "The parameter passed to a C runtime function is incorrect."),
+	(0x0000_0509, "ERROR_BEYOND_VDL", "The operation occurred beyond the valid data length of the file."),
+	(0x0000_050a, "ERROR_INCOMPATIBLE_SERVICE_SID_TYPE", "The service start failed since one or more services in the same process have an incompatible service SID type setting. A service with restricted service SID type can only coexist in the same process with other services with a restricted SID type. If the service SID type for this service was just configured, the hosting process must be restarted in order to start this service."),
+	(0x0000_050b, "ERROR_DRIVER_PROCESS_TERMINATED", "The process hosting the driver for this device has been terminated."),
+	(0x0000_050c, "ERROR_IMPLEMENTATION_LIMIT", "An operation attempted to exceed an implementation-defined limit."),
+	(0x0000_050d, "ERROR_PROCESS_IS_PROTECTED", "Either the target process, or the target thread's containing process, is a protected process."),
+	(0x0000_050e, "ERROR_SERVICE_NOTIFY_CLIENT_LAGGING", "The service notification client is lagging too far behind the current state of services in the machine."),
+	(0x0000_050f, "ERROR_DISK_QUOTA_EXCEEDED", "The requested file operation failed because the storage quota was exceeded. To free up disk space, move files to a different location or delete unnecessary files. For more information, contact your system administrator."),
+	(0x0000_0510, "ERROR_CONTENT_BLOCKED", "The requested file operation failed because the storage policy blocks that type of file. For more information, contact your system administrator."),
+	(0x0000_0511, "ERROR_INCOMPATIBLE_SERVICE_PRIVILEGE", "A privilege that the service requires to function properly does not exist in the service account configuration. You may use the Services Microsoft Management Console (MMC) snap-in (services.msc) and the Local Security Settings MMC snap-in (secpol.msc) to view the service configuration and the account configuration."),
+	(0x0000_0512, "ERROR_APP_HANG", "A thread involved in this operation appears to be unresponsive."),
+	(0x0000_0513, "ERROR_INVALID_LABEL", "Indicates a particular Security ID may not be assigned as the label of an object."),
+	(0x0000_0514, "ERROR_NOT_ALL_ASSIGNED", "Not all privileges or groups referenced are assigned to the caller."),
+	(0x0000_0515, "ERROR_SOME_NOT_MAPPED", "Some mapping between account names and security IDs was not done."),
+	(0x0000_0516, "ERROR_NO_QUOTAS_FOR_ACCOUNT", "No system quota limits are specifically set for this account."),
+	(0x0000_0517, "ERROR_LOCAL_USER_SESSION_KEY", "No encryption key is available. A well-known encryption key was returned."),
+	(0x0000_0518, "ERROR_NULL_LM_PASSWORD", "The password is too complex to be converted to a LAN Manager password. The LAN Manager password returned is a NULL string."),
+	(0x0000_0519, "ERROR_UNKNOWN_REVISION", "The revision level is unknown."),
+	(0x0000_051a, "ERROR_REVISION_MISMATCH", "Indicates two revision levels are incompatible."),
+	(0x0000_051b, "ERROR_INVALID_OWNER", "This security ID may not be assigned as the owner of this object."),
+	(0x0000_051c, "ERROR_INVALID_PRIMARY_GROUP", "This security ID may not be assigned as the primary group of an object."),
+	(0x0000_051d, "ERROR_NO_IMPERSONATION_TOKEN", "An attempt has been made to operate on an impersonation token by a thread that is not currently impersonating a client."),
+	(0x0000_051e, "ERROR_CANT_DISABLE_MANDATORY", "The group may not be disabled."),
+	(0x0000_051f, "ERROR_NO_LOGON_SERVERS", "We can't sign you in with this credential because your domain isn't available. Make sure your device is connected to your organization's network and try again. If you previously signed in on this device with another credential, you can sign in with that credential."),
+	(0x0000_0520, "ERROR_NO_SUCH_LOGON_SESSION", "A specified logon session does not exist. It may already have been terminated."),
+	(0x0000_0521, "ERROR_NO_SUCH_PRIVILEGE", "A specified privilege does not exist."),
+	(0x0000_0522, "ERROR_PRIVILEGE_NOT_HELD", "A required privilege is not held by the client."),
+	(0x0000_0523, "ERROR_INVALID_ACCOUNT_NAME", "The name provided is not a properly formed account name."),
+	(0x0000_0524, "ERROR_USER_EXISTS", "The specified account already exists."),
+	(0x0000_0525, "ERROR_NO_SUCH_USER", "The specified account does not exist."),
+	(0x0000_0526, "ERROR_GROUP_EXISTS", "The specified group already exists."),
+	(0x0000_0527, "ERROR_NO_SUCH_GROUP", "The specified group does not exist."),
+	(0x0000_0528, "ERROR_MEMBER_IN_GROUP", "Either the specified user account is already a member of the specified group, or the specified group cannot be deleted because it contains a member."),
+	(0x0000_0529, "ERROR_MEMBER_NOT_IN_GROUP", "The specified user account is not a member of the specified group account."),
+	(0x0000_052a, "ERROR_LAST_ADMIN", "This operation is disallowed as it could result in an administration account being disabled, deleted or unable to logon."),
+	(0x0000_052b, "ERROR_WRONG_PASSWORD", "Unable to update the password. The value provided as the current password is incorrect."),
+	(0x0000_052c, "ERROR_ILL_FORMED_PASSWORD", "Unable to update the password. The value provided for the new password contains values that are not allowed in passwords."),
+	(0x0000_052d, "ERROR_PASSWORD_RESTRICTION", "Unable to update the password. The value provided for the new password does not meet the length, complexity, or history requirements of the domain."),
+	(0x0000_052e, "ERROR_LOGON_FAILURE", "The user name or password is incorrect."),
+	(0x0000_052f, "ERROR_ACCOUNT_RESTRICTION", "Account restrictions are preventing this user from signing in. For example: blank passwords aren't allowed, sign-in times are limited, or a policy restriction has been enforced."),
+	(0x0000_0530, "ERROR_INVALID_LOGON_HOURS", "Your account has time restrictions that keep you from signing in right now."),
+	(0x0000_0531, "ERROR_INVALID_WORKSTATION", "This user isn't allowed to sign in to this computer."),
+	(0x0000_0532, "ERROR_PASSWORD_EXPIRED", "The password for this account has expired."),
+	(0x0000_0533, "ERROR_ACCOUNT_DISABLED", "This user can't sign in because this account is currently disabled."),
+	(0x0000_0534, "ERROR_NONE_MAPPED", "No mapping between account names and security IDs was done."),
+	(0x0000_0535, "ERROR_TOO_MANY_LUIDS_REQUESTED", "Too many local user identifiers (LUIDs) were requested at one time."),
+	(0x0000_0536, "ERROR_LUIDS_EXHAUSTED", "No more local user identifiers (LUIDs) are available."),
+	(0x0000_0537, "ERROR_INVALID_SUB_AUTHORITY", "The subauthority part of a security ID is invalid for this particular use."),
+	(0x0000_0538, "ERROR_INVALID_ACL", "The access control list (ACL) structure is invalid."),
+	(0x0000_0539, "ERROR_INVALID_SID", "The security ID structure is invalid."),
+	(0x0000_053a, "ERROR_INVALID_SECURITY_DESCR", "The security descriptor structure is invalid."),
+	(0x0000_053c, "ERROR_BAD_INHERITANCE_ACL", "The inherited access control list (ACL) or access control entry (ACE) could not be built."),
+	(0x0000_053d, "ERROR_SERVER_DISABLED", "The server is currently disabled."),
+	(0x0000_053e, "ERROR_SERVER_NOT_DISABLED", "The server is currently enabled."),
+	(0x0000_053f, "ERROR_INVALID_ID_AUTHORITY", "The value provided was an invalid value for an identifier authority."),
+	(0x0000_0540, "ERROR_ALLOTTED_SPACE_EXCEEDED", "No more memory is available for security information updates."),
+	(0x0000_0541, "ERROR_INVALID_GROUP_ATTRIBUTES", "The specified attributes are invalid, or incompatible with the attributes for the group as a whole."),
+	(0x0000_0542, "ERROR_BAD_IMPERSONATION_LEVEL", "Either a required impersonation level was not provided, or the provided impersonation level is invalid."),
+	(0x0000_0543, "ERROR_CANT_OPEN_ANONYMOUS", "Cannot open an anonymous level security token."),
+	(0x0000_0544, "ERROR_BAD_VALIDATION_CLASS", "The validation information class requested was invalid."),
+	(0x0000_0545, "ERROR_BAD_TOKEN_TYPE", "The type of the token is inappropriate for its attempted use."),
+	(0x0000_0546, "ERROR_NO_SECURITY_ON_OBJECT", "Unable to perform a security operation on an object that has no associated security."),
+	(0x0000_0547, "ERROR_CANT_ACCESS_DOMAIN_INFO", "Configuration information could not be read from the domain controller, either because the machine is unavailable, or access has been denied."),
+	(0x0000_0548, "ERROR_INVALID_SERVER_STATE", "The security account manager (SAM) or local security authority (LSA) server was in the wrong state to perform the security operation."),
+	(0x0000_0549, "ERROR_INVALID_DOMAIN_STATE", "The domain was in the wrong state to perform the security operation."),
+	(0x0000_054a, "ERROR_INVALID_DOMAIN_ROLE", "This operation is only allowed for the Primary Domain Controller of the domain."),
+	(0x0000_054b, "ERROR_NO_SUCH_DOMAIN", "The specified domain either does not exist or could not be contacted."),
+	(0x0000_054c, "ERROR_DOMAIN_EXISTS", "The specified domain already exists."),
+	(0x0000_054d, "ERROR_DOMAIN_LIMIT_EXCEEDED", "An attempt was made to exceed the limit on the number of domains per server."),
+	(0x0000_054e, "ERROR_INTERNAL_DB_CORRUPTION", "Unable to complete the requested operation because of either a catastrophic media failure or a data structure corruption on the disk."),
+	(0x0000_054f, "ERROR_INTERNAL_ERROR", "An internal error occurred."),
+	(0x0000_0550, "ERROR_GENERIC_NOT_MAPPED", "Generic access types were contained in an access mask which should already be mapped to nongeneric types."),
+	(0x0000_0551, "ERROR_BAD_DESCRIPTOR_FORMAT", "A security descriptor is not in the right format (absolute or self-relative)."),
+	(0x0000_0552, "ERROR_NOT_LOGON_PROCESS", "The requested action is restricted for use by logon processes only. The calling process has not registered as a logon process."),
+	(0x0000_0553, "ERROR_LOGON_SESSION_EXISTS", "Cannot start a new logon session with an ID that is already in use."),
+	(0x0000_0554, "ERROR_NO_SUCH_PACKAGE", "A specified authentication package is unknown."),
+	(0x0000_0555, "ERROR_BAD_LOGON_SESSION_STATE", "The logon session is not in a state that is consistent with the requested operation."),
+	(0x0000_0556, "ERROR_LOGON_SESSION_COLLISION", "The logon session ID is already in use."),
+	(0x0000_0557, "ERROR_INVALID_LOGON_TYPE", "A logon request contained an invalid logon type value."),
+	(0x0000_0558, "ERROR_CANNOT_IMPERSONATE", "Unable to impersonate using a named pipe until data has been read from that pipe."),
+	(0x0000_0559, "ERROR_RXACT_INVALID_STATE", "The transaction state of a registry subtree is incompatible with the requested operation."),
+	(0x0000_055a, "ERROR_RXACT_COMMIT_FAILURE", "An internal security database corruption has been encountered."),
+	(0x0000_055b, "ERROR_SPECIAL_ACCOUNT", "Cannot perform this operation on built-in accounts."),
+	(0x0000_055c, "ERROR_SPECIAL_GROUP", "Cannot perform this operation on this built-in special group."),
+	(0x0000_055d, "ERROR_SPECIAL_USER", "Cannot perform this operation on this built-in special user."),
+	(0x0000_055e, "ERROR_MEMBERS_PRIMARY_GROUP", "The user cannot be removed from a group because the group is currently the user's primary group."),
+	(0x0000_055f, "ERROR_TOKEN_ALREADY_IN_USE", "The token is already in use as a primary token."),
+	(0x0000_0560, "ERROR_NO_SUCH_ALIAS", "The specified local group does not exist."),
+	(0x0000_0561, "ERROR_MEMBER_NOT_IN_ALIAS", "The specified account name is not a member of the group."),
+	(0x0000_0562, "ERROR_MEMBER_IN_ALIAS", "The specified account name is already a member of the group."),
+	(0x0000_0563, "ERROR_ALIAS_EXISTS", "The specified local group already exists."),
+	(0x0000_0564, "ERROR_LOGON_NOT_GRANTED", "Logon failure: the user has not been granted the requested logon type at this computer."),
+	(0x0000_0565, "ERROR_TOO_MANY_SECRETS", "The maximum number of secrets that may be stored in a single system has been exceeded."),
+	(0x0000_0566, "ERROR_SECRET_TOO_LONG", "The length of a secret exceeds the maximum length allowed."),
+	(0x0000_0567, "ERROR_INTERNAL_DB_ERROR", "The local security authority database contains an internal inconsistency."),
+	(0x0000_0568, "ERROR_TOO_MANY_CONTEXT_IDS", "During a logon attempt, the user's security context accumulated too many security IDs."),
+	(0x0000_0569, "ERROR_LOGON_TYPE_NOT_GRANTED", "Logon failure: the user has not been granted the requested logon type at this computer."),
+	(0x0000_056a, "ERROR_NT_CROSS_ENCRYPTION_REQUIRED", "A cross-encrypted password is necessary to change a user password."),
+	(0x0000_056b, "ERROR_NO_SUCH_MEMBER", "A member could not be added to or removed from the local group because the member does not exist."),
+	(0x0000_056c, "ERROR_INVALID_MEMBER", "A new member could not be added to a local group because the member has the wrong account type."),
+	(0x0000_056d, "ERROR_TOO_MANY_SIDS", "Too many security IDs have been specified."),
+	(0x0000_056e, "ERROR_LM_CROSS_ENCRYPTION_REQUIRED", "A cross-encrypted password is necessary to change this user password."),
+	(0x0000_056f, "ERROR_NO_INHERITANCE", "Indicates an ACL contains no inheritable components."),
+	(0x0000_0570, "ERROR_FILE_CORRUPT", "The file or directory is corrupted and unreadable."),
+	(0x0000_0571, "ERROR_DISK_CORRUPT", "The disk structure is corrupted and unreadable."),
+	(0x0000_0572, "ERROR_NO_USER_SESSION_KEY", "There is no user session key for the specified logon session."),
+	(0x0000_0573, "ERROR_LICENSE_QUOTA_EXCEEDED", "The service being accessed is licensed for a particular number of connections. No more connections can be made to the service at this time because there are already as many connections as the service can accept."),
+	(0x0000_0574, "ERROR_WRONG_TARGET_NAME", "The target account name is incorrect."),
+	(0x0000_0575, "ERROR_MUTUAL_AUTH_FAILED", "Mutual Authentication failed. The server's password is out of date at the domain controller."),
+	(0x0000_0576, "ERROR_TIME_SKEW", "There is a time and/or date difference between the client and server."),
+	(0x0000_0577, "ERROR_CURRENT_DOMAIN_NOT_ALLOWED", "This operation cannot be performed on the current domain."),
+	(0x0000_0578, "ERROR_INVALID_WINDOW_HANDLE", "Invalid window handle."),
+	(0x0000_0579, "ERROR_INVALID_MENU_HANDLE", "Invalid menu handle."),
+	(0x0000_057a, "ERROR_INVALID_CURSOR_HANDLE", "Invalid cursor handle."),
+	(0x0000_057b, "ERROR_INVALID_ACCEL_HANDLE", "Invalid accelerator table handle."),
+	(0x0000_057c, "ERROR_INVALID_HOOK_HANDLE", "Invalid hook handle."),
+	(0x0000_057d, "ERROR_INVALID_DWP_HANDLE", "Invalid handle to a multiple-window position structure."),
+	(0x0000_057e, "ERROR_TLW_WITH_WSCHILD", "Cannot create a top-level child window."),
+	(0x0000_057f, "ERROR_CANNOT_FIND_WND_CLASS", "Cannot find window class."),
+	(0x0000_0580, "ERROR_WINDOW_OF_OTHER_THREAD", "Invalid window; it belongs to other thread."),
+	(0x0000_0581, "ERROR_HOTKEY_ALREADY_REGISTERED", "Hot key is already registered."),
+	(0x0000_0582, "ERROR_CLASS_ALREADY_EXISTS", "Class already exists."),
+	(0x0000_0583, "ERROR_CLASS_DOES_NOT_EXIST", "Class does not exist."),
+	(0x0000_0584, "ERROR_CLASS_HAS_WINDOWS", "Class still has open windows."),
+	(0x0000_0585, "ERROR_INVALID_INDEX", "Invalid index."),
+	(0x0000_0586, "ERROR_INVALID_ICON_HANDLE", "Invalid icon handle."),
+	(0x0000_0587, "ERROR_PRIVATE_DIALOG_INDEX", "Invalid index."),
+	(0x0000_0588, "ERROR_LISTBOX_ID_NOT_FOUND", "The list box identifier was not found."),
+	(0x0000_0589, "ERROR_NO_WILDCARD_CHARACTERS", "No wildcards were found."),
+	(0x0000_058a, "ERROR_CLIPBOARD_NOT_OPEN", "Thread does not have a clipboard open."),
+	(0x0000_058b, "ERROR_HOTKEY_NOT_REGISTERED", "Hot key is not registered."),
+	(0x0000_058c, "ERROR_WINDOW_NOT_DIALOG", "The window is not a valid dialog window."),
+	(0x0000_058d, "ERROR_CONTROL_ID_NOT_FOUND", "Control ID not found."),
+	(0x0000_058e, "ERROR_INVALID_COMBOBOX_MESSAGE", "Invalid message for a combo box because it does not have an edit control."),
+	(0x0000_058f, "ERROR_WINDOW_NOT_COMBOBOX", "The window is not a combo box."),
+	(0x0000_0590, "ERROR_INVALID_EDIT_HEIGHT", "Height must be less than 256."),
+	(0x0000_0591, "ERROR_DC_NOT_FOUND", "Invalid device context (DC) handle."),
+	(0x0000_0592, "ERROR_INVALID_HOOK_FILTER", "Invalid hook procedure type."),
+	(0x0000_0593, "ERROR_INVALID_FILTER_PROC", "Invalid hook procedure."),
+	(0x0000_0594, "ERROR_HOOK_NEEDS_HMOD", "Cannot set nonlocal hook without a module handle."),
+	(0x0000_0595, "ERROR_GLOBAL_ONLY_HOOK", "This hook procedure can only be set globally."),
+	(0x0000_0596, "ERROR_JOURNAL_HOOK_SET", "The journal hook procedure is already installed."),
+	(0x0000_0597, "ERROR_HOOK_NOT_INSTALLED", "The hook procedure is not installed."),
+	(0x0000_0598, "ERROR_INVALID_LB_MESSAGE", "Invalid message for single-selection list box."),
+	(0x0000_0599, "ERROR_SETCOUNT_ON_BAD_LB", "LB_SETCOUNT sent to non-lazy list box."),
+	(0x0000_059a, "ERROR_LB_WITHOUT_TABSTOPS", "This list box does not support tab stops."),
+	(0x0000_059b, "ERROR_DESTROY_OBJECT_OF_OTHER_THREAD", "Cannot destroy object created by another thread."),
+	(0x0000_059c, "ERROR_CHILD_WINDOW_MENU", "Child windows cannot have menus."),
+	(0x0000_059d, "ERROR_NO_SYSTEM_MENU", "The window does not have a system menu."),
+	(0x0000_059e, "ERROR_INVALID_MSGBOX_STYLE", "Invalid message box style."),
+	(0x0000_059f, "ERROR_INVALID_SPI_VALUE", "Invalid system-wide (SPI_*) parameter."),
+	(0x0000_05a0, "ERROR_SCREEN_ALREADY_LOCKED", "Screen already locked."),
+	(0x0000_05a1, "ERROR_HWNDS_HAVE_DIFF_PARENT", "All handles to windows in a multiple-window position structure must have the same parent."),
+	(0x0000_05a2, "ERROR_NOT_CHILD_WINDOW", "The window is not a child window."),
+	(0x0000_05a3, "ERROR_INVALID_GW_COMMAND", "Invalid GW_* command."),
+	(0x0000_05a4, "ERROR_INVALID_THREAD_ID", "Invalid thread identifier."),
+	(0x0000_05a5, "ERROR_NON_MDICHILD_WINDOW", "Cannot process a message from a window that is not a multiple document interface (MDI) window."),
+	(0x0000_05a6, "ERROR_POPUP_ALREADY_ACTIVE", "Popup menu already active."),
+	(0x0000_05a7, "ERROR_NO_SCROLLBARS", "The window does not have scroll bars."),
+	(0x0000_05a8, "ERROR_INVALID_SCROLLBAR_RANGE", "Scroll bar range cannot be greater than MAXLONG."),
+	(0x0000_05a9, "ERROR_INVALID_SHOWWIN_COMMAND", "Cannot show or remove the window in the way specified."),
+	(0x0000_05aa, "ERROR_NO_SYSTEM_RESOURCES", "Insufficient system resources exist to complete the requested service."),
+	(0x0000_05ab, "ERROR_NONPAGED_SYSTEM_RESOURCES", "Insufficient system resources exist to complete the requested service."),
+	(0x0000_05ac, "ERROR_PAGED_SYSTEM_RESOURCES", "Insufficient system resources exist to complete the requested service."),
+	(0x0000_05ad, "ERROR_WORKING_SET_QUOTA", "Insufficient quota to complete the requested service."),
+	(0x0000_05ae, "ERROR_PAGEFILE_QUOTA", "Insufficient quota to complete the requested service."),
+	(0x0000_05af, "ERROR_COMMITMENT_LIMIT", "The paging file is too small for this operation to complete."),
+	(0x0000_05b0, "ERROR_MENU_ITEM_NOT_FOUND", "A menu item was not found."),
+	(0x0000_05b1, "ERROR_INVALID_KEYBOARD_HANDLE", "Invalid keyboard layout handle."),
+	(0x0000_05b2, "ERROR_HOOK_TYPE_NOT_ALLOWED", "Hook type not allowed."),
+	(0x0000_05b3, "ERROR_REQUIRES_INTERACTIVE_WINDOWSTATION", "This operation requires an interactive window station."),
+	(0x0000_05b4, "ERROR_TIMEOUT", "This operation returned because the timeout period expired."),
+	(0x0000_05b5, "ERROR_INVALID_MONITOR_HANDLE", "Invalid monitor handle."),
+	(0x0000_05b6, "ERROR_INCORRECT_SIZE", "Incorrect size argument."),
+	(0x0000_05b7, "ERROR_SYMLINK_CLASS_DISABLED", "The symbolic link cannot be followed because its type is disabled."),
+	(0x0000_05b8, "ERROR_SYMLINK_NOT_SUPPORTED", "This application does not support the current operation on symbolic links."),
+	(0x0000_05b9, "ERROR_XML_PARSE_ERROR", "Windows was unable to parse the requested XML data."),
+	(0x0000_05ba, "ERROR_XMLDSIG_ERROR", "An error was encountered while processing an XML digital signature."),
+	(0x0000_05bb, "ERROR_RESTART_APPLICATION", "This application must be restarted."),
+	(0x0000_05bc, "ERROR_WRONG_COMPARTMENT", "The caller made the connection request in the wrong routing compartment."),
+	(0x0000_05bd, "ERROR_AUTHIP_FAILURE", "There was an AuthIP failure when attempting to connect to the remote host."),
+	(0x0000_05be, "ERROR_NO_NVRAM_RESOURCES", "Insufficient NVRAM resources exist to complete the requested service. A reboot might be required."),
+	(0x0000_05bf, "ERROR_NOT_GUI_PROCESS", "Unable to finish the requested operation because the specified process is not a GUI process."),
+	(0x0000_05dc, "ERROR_EVENTLOG_FILE_CORRUPT", "The event log file is corrupted."),
+	(0x0000_05dd, "ERROR_EVENTLOG_CANT_START", "No event log file could be opened, so the event logging service did not start."),
+	(0x0000_05de, "ERROR_LOG_FILE_FULL", "The event log file is full."),
+	(0x0000_05df, "ERROR_EVENTLOG_FILE_CHANGED", "The event log file has changed between read operations."),
+	(0x0000_05e0, "ERROR_CONTAINER_ASSIGNED", "The specified Job already has a container assigned to it."),
+	(0x0000_05e1, "ERROR_JOB_NO_CONTAINER", "The specified Job does not have a container assigned to it."),
+	(0x0000_060e, "ERROR_INVALID_TASK_NAME", "The specified task name is invalid."),
+	(0x0000_060f, "ERROR_INVALID_TASK_INDEX", "The specified task index is invalid."),
+	(0x0000_0610, "ERROR_THREAD_ALREADY_IN_TASK", "The specified thread is already joining a task."),
+	(0x0000_0641, "ERROR_INSTALL_SERVICE_FAILURE", "The Windows Installer Service could not be accessed. This can occur if the Windows Installer is not correctly installed. Contact your support personnel for assistance."),
+	(0x0000_0642, "ERROR_INSTALL_USEREXIT", "User cancelled installation."),
+	(0x0000_0643, "ERROR_INSTALL_FAILURE", "Fatal error during installation."),
+	(0x0000_0644, "ERROR_INSTALL_SUSPEND", "Installation suspended, incomplete."),
+	(0x0000_0645, "ERROR_UNKNOWN_PRODUCT", "This action is only valid for products that are currently installed."),
+	(0x0000_0646, "ERROR_UNKNOWN_FEATURE", "Feature ID not registered."),
+	(0x0000_0647, "ERROR_UNKNOWN_COMPONENT", "Component ID not registered."),
+	(0x0000_0648, "ERROR_UNKNOWN_PROPERTY", "Unknown property."),
+	(0x0000_0649, "ERROR_INVALID_HANDLE_STATE", "Handle is in an invalid state."),
+	(0x0000_064a, "ERROR_BAD_CONFIGURATION", "The configuration data for this product is corrupt. Contact your support personnel."),
+	(0x0000_064b, "ERROR_INDEX_ABSENT", "Component qualifier not present."),
+	(0x0000_064c, "ERROR_INSTALL_SOURCE_ABSENT", "The installation source for this product is not available. Verify that the source exists and that you can access it."),
+	(0x0000_064d, "ERROR_INSTALL_PACKAGE_VERSION", "This installation package cannot be installed by the Windows Installer service. You must install a Windows service pack that contains a newer version of the Windows Installer service."),
+	(0x0000_064e, "ERROR_PRODUCT_UNINSTALLED", "Product is uninstalled."),
+	(0x0000_064f, "ERROR_BAD_QUERY_SYNTAX", "SQL query syntax invalid or unsupported."),
+	(0x0000_0650, "ERROR_INVALID_FIELD", "Record field does not exist."),
+	(0x0000_0651, "ERROR_DEVICE_REMOVED", "The device has been removed."),
+	(0x0000_0652, "ERROR_INSTALL_ALREADY_RUNNING", "Another installation is already in progress. Complete that installation before proceeding with this install."),
+	(0x0000_0653, "ERROR_INSTALL_PACKAGE_OPEN_FAILED", "This installation package could not be opened. Verify that the package exists and that you can access it, or contact the application vendor to verify that this is a valid Windows Installer package."),
+	(0x0000_0654, "ERROR_INSTALL_PACKAGE_INVALID", "This installation package could not be opened. Contact the application vendor to verify that this is a valid Windows Installer package."),
+	(0x0000_0655, "ERROR_INSTALL_UI_FAILURE", "There was an error starting the Windows Installer service user interface. Contact your support personnel."),
+	(0x0000_0656, "ERROR_INSTALL_LOG_FAILURE", "Error opening installation log file. Verify that the specified log file location exists and that you can write to it."),
+	(0x0000_0657, "ERROR_INSTALL_LANGUAGE_UNSUPPORTED", "The language of this installation package is not supported by your system."),
+	(0x0000_0658, "ERROR_INSTALL_TRANSFORM_FAILURE", "Error applying transforms. Verify that the specified transform paths are valid."),
+	(0x0000_0659, "ERROR_INSTALL_PACKAGE_REJECTED", "This installation is forbidden by system policy. Contact your system administrator."),
+	(0x0000_065a, "ERROR_FUNCTION_NOT_CALLED", "Function could not be executed."),
+	(0x0000_065b, "ERROR_FUNCTION_FAILED", "Function failed during execution."),
+	(0x0000_065c, "ERROR_INVALID_TABLE", "Invalid or unknown table specified."),
+	(0x0000_065d, "ERROR_DATATYPE_MISMATCH", "Data supplied is of wrong type."),
+	(0x0000_065e, "ERROR_UNSUPPORTED_TYPE", "Data of this type is not supported."),
+	(0x0000_065f, "ERROR_CREATE_FAILED", "The Windows Installer service failed to start. Contact your support personnel."),
+	(0x0000_0660, "ERROR_INSTALL_TEMP_UNWRITABLE", "The Temp folder is on a drive that is full or is inaccessible. Free up space on the drive or verify that you have write permission on the Temp folder."),
+	(0x0000_0661, "ERROR_INSTALL_PLATFORM_UNSUPPORTED", "This installation package is not supported by this processor type. Contact your product vendor."),
+	(0x0000_0662, "ERROR_INSTALL_NOTUSED", "Component not used on this computer."),
+	(0x0000_0663, "ERROR_PATCH_PACKAGE_OPEN_FAILED", "This update package could not be opened. Verify that the update package exists and that you can access it, or contact the application vendor to verify that this is a valid Windows Installer update package."),
+	(0x0000_0664, "ERROR_PATCH_PACKAGE_INVALID", "This update package could not be opened. Contact the application vendor to verify that this is a valid Windows Installer update package."),
+	(0x0000_0665, "ERROR_PATCH_PACKAGE_UNSUPPORTED", "This update package cannot be processed by the Windows Installer service. You must install a Windows service pack that contains a newer version of the Windows Installer service."),
+	(0x0000_0666, "ERROR_PRODUCT_VERSION", "Another version of this product is already installed. Installation of this version cannot continue. To configure or remove the existing version of this product, use Add/Remove Programs on the Control Panel."),
+	(0x0000_0667, "ERROR_INVALID_COMMAND_LINE", "Invalid command line argument. Consult the Windows Installer SDK for detailed command line help."),
+	(0x0000_0668, "ERROR_INSTALL_REMOTE_DISALLOWED", "Only administrators have permission to add, remove, or configure server software during a Terminal services remote session. If you want to install or configure software on the server, contact your network administrator."),
+	(0x0000_0669, "ERROR_SUCCESS_REBOOT_INITIATED", "The requested operation completed successfully. The system will be restarted so the changes can take effect."),
+	(0x0000_066a, "ERROR_PATCH_TARGET_NOT_FOUND", "The upgrade cannot be installed by the Windows Installer service because the program to be upgraded may be missing, or the upgrade may update a different version of the program. Verify that the program to be upgraded exists on your computer and that you have the correct upgrade."),
+	(0x0000_066b, "ERROR_PATCH_PACKAGE_REJECTED", "The update package is not permitted by software restriction policy."),
+	(0x0000_066c, "ERROR_INSTALL_TRANSFORM_REJECTED", "One or more customizations are not permitted by software restriction policy."),
+	(0x0000_066d, "ERROR_INSTALL_REMOTE_PROHIBITED", "The Windows Installer does not permit installation from a Remote Desktop Connection."),
+	(0x0000_066e, "ERROR_PATCH_REMOVAL_UNSUPPORTED", "Uninstallation of the update package is not supported."),
+	(0x0000_066f, "ERROR_UNKNOWN_PATCH", "The update is not applied to this product."),
+	(0x0000_0670, "ERROR_PATCH_NO_SEQUENCE", "No valid sequence could be found for the set of updates."),
+	(0x0000_0671, "ERROR_PATCH_REMOVAL_DISALLOWED", "Removal of the update package is not permitted."),
+	(0x0000_0672, "ERROR_INVALID_PATCH_XML", "The XML update data is invalid."),
+	(0x0000_0673, "ERROR_PATCH_MANAGED_ADVERTISED_PRODUCT", "Windows Installer does not permit updating of managed advertised products. At least one feature of the product must be installed before applying the update."),
+	(0x0000_0674, "ERROR_INSTALL_SERVICE_SAFEBOOT", "The Windows Installer service is not accessible in Safe Mode. Please try again when your computer is not in Safe Mode or you can use System Restore to return your machine to a previous good state."),
+	(0x0000_0675, "ERROR_FAIL_FAST_EXCEPTION", "A fail fast exception occurred. Exception handlers will not be invoked and the process will be terminated immediately."),
+	(0x0000_0676, "ERROR_INSTALL_REJECTED", "The app that you are trying to run is not supported on this version of Windows."),
+	(0x0000_0677, "ERROR_DYNAMIC_CODE_BLOCKED", "The operation was blocked as the process prohibits dynamic code generation."),
+	(0x0000_0678, "ERROR_NOT_SAME_OBJECT", "The objects are not identical."),
+	(0x0000_0679, "ERROR_STRICT_CFG_VIOLATION", "The specified image file was blocked from loading because it does not enable a feature required by the process: Control Flow Guard."),
+	(0x0000_067c, "ERROR_SET_CONTEXT_DENIED", "The thread context could not be updated because this has been restricted for the process."),
+	(0x0000_067d, "ERROR_CROSS_PARTITION_VIOLATION", "An invalid cross-partition private file/section access was attempted."),
+	(0x0000_06f8, "ERROR_INVALID_USER_BUFFER", "The supplied user buffer is not valid for the requested operation."),
+	(0x0000_06f9, "ERROR_UNRECOGNIZED_MEDIA", "The disk media is not recognized. It may not be formatted."),
+	(0x0000_06fa, "ERROR_NO_TRUST_LSA_SECRET", "The workstation does not have a trust secret."),
+	(0x0000_06fb, "ERROR_NO_TRUST_SAM_ACCOUNT", "The security database on the server does not have a computer account for this workstation trust relationship."),
+	(0x0000_06fc, "ERROR_TRUSTED_DOMAIN_FAILURE", "The trust relationship between the primary domain and the trusted domain failed."),
+	(0x0000_06fd, "ERROR_TRUSTED_RELATIONSHIP_FAILURE", "The trust relationship between this workstation and the primary domain failed."),
+	(0x0000_06fe, "ERROR_TRUST_FAILURE", "The network logon failed."),
+	(0x0000_0700, "ERROR_NETLOGON_NOT_STARTED", "An attempt was made to logon, but the network logon service was not started."),
+	(0x0000_0701, "ERROR_ACCOUNT_EXPIRED", "The user's account has expired."),
+	(0x0000_0702, "ERROR_REDIRECTOR_HAS_OPEN_HANDLES", "The redirector is in use and cannot be unloaded."),
+	(0x0000_0703, "ERROR_PRINTER_DRIVER_ALREADY_INSTALLED", "The specified printer driver is already installed."),
+	(0x0000_0704, "ERROR_UNKNOWN_PORT", "The specified port is unknown."),
+	(0x0000_0705, "ERROR_UNKNOWN_PRINTER_DRIVER", "The printer driver is unknown."),
+	(0x0000_0706, "ERROR_UNKNOWN_PRINTPROCESSOR", "The print processor is unknown."),
+	(0x0000_0707, "ERROR_INVALID_SEPARATOR_FILE", "The specified separator file is invalid."),
+	(0x0000_0708, "ERROR_INVALID_PRIORITY", "The specified priority is invalid."),
+	(0x0000_0709, "ERROR_INVALID_PRINTER_NAME", "The printer name is invalid."),
+	(0x0000_070a, "ERROR_PRINTER_ALREADY_EXISTS", "The printer already exists."),
+	(0x0000_070b, "ERROR_INVALID_PRINTER_COMMAND", "The printer command is invalid."),
+	(0x0000_070c, "ERROR_INVALID_DATATYPE", "The specified datatype is invalid."),
+	(0x0000_070d, "ERROR_INVALID_ENVIRONMENT", "The environment specified is invalid."),
+	(0x0000_070f, "ERROR_NOLOGON_INTERDOMAIN_TRUST_ACCOUNT", "The account used is an interdomain trust account. Use your global user account or local user account to access this server."),
+	(0x0000_0710, "ERROR_NOLOGON_WORKSTATION_TRUST_ACCOUNT", "The account used is a computer account. Use your global user account or local user account to access this server."),
+	(0x0000_0711, "ERROR_NOLOGON_SERVER_TRUST_ACCOUNT", "The account used is a server trust account. Use your global user account or local user account to access this server."),
+	(0x0000_0712, "ERROR_DOMAIN_TRUST_INCONSISTENT", "The name or security ID (SID) of the domain specified is inconsistent with the trust information for that domain."),
+	(0x0000_0713, "ERROR_SERVER_HAS_OPEN_HANDLES", "The server is in use and cannot be unloaded."),
+	(0x0000_0714, "ERROR_RESOURCE_DATA_NOT_FOUND", "The specified image file did not contain a resource section."),
+	(0x0000_0715, "ERROR_RESOURCE_TYPE_NOT_FOUND", "The specified resource type cannot be found in the image file."),
+	(0x0000_0716, "ERROR_RESOURCE_NAME_NOT_FOUND", "The specified resource name cannot be found in the image file."),
+	(0x0000_0717, "ERROR_RESOURCE_LANG_NOT_FOUND", "The specified resource language ID cannot be found in the image file."),
+	(0x0000_0718, "ERROR_NOT_ENOUGH_QUOTA", "Not enough quota is available to process this command."),
+	(0x0000_076d, "ERROR_INVALID_TIME", "The specified time is invalid."),
+	(0x0000_076e, "ERROR_INVALID_FORM_NAME", "The specified form name is invalid."),
+	(0x0000_076f, "ERROR_INVALID_FORM_SIZE", "The specified form size is invalid."),
+	(0x0000_0770, "ERROR_ALREADY_WAITING", "The specified printer handle is already being waited on."),
+	(0x0000_0771, "ERROR_PRINTER_DELETED", "The specified printer has been deleted."),
+	(0x0000_0772, "ERROR_INVALID_PRINTER_STATE", "The state of the printer is invalid."),
+	(0x0000_0773, "ERROR_PASSWORD_MUST_CHANGE", "The user's password must be changed before signing in."),
+	(0x0000_0774, "ERROR_DOMAIN_CONTROLLER_NOT_FOUND", "Could not find the domain controller for this domain."),
+	(0x0000_0775, "ERROR_ACCOUNT_LOCKED_OUT", "The referenced account is currently locked out and may not be logged on to."),
+	(0x0000_077f, "ERROR_NO_SITENAME", "No site name is available for this machine."),
+	(0x0000_0780, "ERROR_CANT_ACCESS_FILE", "The file cannot be accessed by the system."),
+	(0x0000_0781, "ERROR_CANT_RESOLVE_FILENAME", "The name of the file cannot be resolved by the system."),
+	(0x0000_078a, "ERROR_KM_DRIVER_BLOCKED", "The kernel-mode driver has been blocked from loading."),
+	(0x0000_078b, "ERROR_CONTEXT_EXPIRED", "The context has expired and can no longer be used."),
+	(0x0000_078c, "ERROR_PER_USER_TRUST_QUOTA_EXCEEDED", "The current user has exceeded the quota of trust objects that they may create."),
+	(0x0000_078d, "ERROR_ALL_USER_TRUST_QUOTA_EXCEEDED", "The total quota of trust objects on the system has been exceeded."),
+	(0x0000_078e, "ERROR_USER_DELETE_TRUST_QUOTA_EXCEEDED", "The quota of deleted trust objects for the current user has been exceeded."),
+	(0x0000_078f, "ERROR_AUTHENTICATION_FIREWALL_FAILED", "The computer you are signing into is protected by an authentication firewall. The specified account is not allowed to authenticate to the computer."),
+	(0x0000_0790, "ERROR_REMOTE_PRINT_CONNECTIONS_BLOCKED", "Remote connections to the Print Spooler are blocked by a policy set on your machine."),
+	(0x0000_0791, "ERROR_NTLM_BLOCKED", "Authentication failed because NTLM authentication has been disabled."),
+	(0x0000_0792, "ERROR_PASSWORD_CHANGE_REQUIRED", "The password for this account must be changed before signing in."),
+	(0x0000_0793, "ERROR_LOST_MODE_LOGON_RESTRICTION", "Logon failure: the device is in lost mode, so signing in is restricted."),
+	(0x0000_07d0, "ERROR_INVALID_PIXEL_FORMAT", "The pixel format is invalid."),
+	(0x0000_07d1, "ERROR_BAD_DRIVER", "The specified driver is invalid."),
+	(0x0000_07d2, "ERROR_INVALID_WINDOW_STYLE", "The window style or class attribute is invalid for this operation."),
+	(0x0000_07d3, "ERROR_METAFILE_NOT_SUPPORTED", "The requested metafile operation is not supported."),
+	(0x0000_07d4, "ERROR_TRANSFORM_NOT_SUPPORTED", "The requested transformation operation is not supported."),
+	(0x0000_07d5, "ERROR_CLIPPING_NOT_SUPPORTED", "The requested clipping operation is not supported."),
+	(0x0000_07da, "ERROR_INVALID_CMM", "The specified color management module is invalid."),
+	(0x0000_07db, "ERROR_INVALID_PROFILE", "The specified color profile is invalid."),
+	(0x0000_07dc, "ERROR_TAG_NOT_FOUND", "The specified tag was not found."),
+	(0x0000_07dd, "ERROR_TAG_NOT_PRESENT", "A required tag is not present."),
+	(0x0000_07de, "ERROR_DUPLICATE_TAG", "The specified tag is already present."),
+	(0x0000_07df, "ERROR_PROFILE_NOT_ASSOCIATED_WITH_DEVICE", "The specified color profile is not associated with the specified device."),
+	(0x0000_07e0, "ERROR_PROFILE_NOT_FOUND", "The specified color profile was not found."),
+	(0x0000_07e1, "ERROR_INVALID_COLORSPACE", "The specified color space is invalid."),
+	(0x0000_07e2, "ERROR_ICM_NOT_ENABLED", "Image Color Management is not enabled."),
+	(0x0000_07e3, "ERROR_DELETING_ICM_XFORM", "There was an error while deleting the color transform."),
+	(0x0000_07e4, "ERROR_INVALID_TRANSFORM", "The specified color transform is invalid."),
+	(0x0000_07e5, "ERROR_COLORSPACE_MISMATCH", "The specified transform does not match the bitmap's color space."),
+	(0x0000_07e6, "ERROR_INVALID_COLORINDEX", "The specified named color index is not present in the profile."),
+	(0x0000_07e7, "ERROR_PROFILE_DOES_NOT_MATCH_DEVICE", "The specified profile is intended for a device of a different type than the specified device."),
+	(0x0000_083c, "ERROR_CONNECTED_OTHER_PASSWORD", "The network connection was made successfully, but the user had to be prompted for a password other than the one originally specified."),
+	(0x0000_083d, "ERROR_CONNECTED_OTHER_PASSWORD_DEFAULT", "The network connection was made successfully using default credentials."),
+	(0x0000_089a, "ERROR_BAD_USERNAME", "The specified username is invalid."),
+	(0x0000_08ca, "ERROR_NOT_CONNECTED", "This network connection does not exist."),
+	(0x0000_0961, "ERROR_OPEN_FILES", "This network connection has files open or requests pending."),
+	(0x0000_0962, "ERROR_ACTIVE_CONNECTIONS", "Active connections still exist."),
+	(0x0000_0964, "ERROR_DEVICE_IN_USE", "The device is in use by an active process and cannot be disconnected."),
+	(0x0000_0bb8, "ERROR_UNKNOWN_PRINT_MONITOR", "The specified print monitor is unknown."),
+	(0x0000_0bb9, "ERROR_PRINTER_DRIVER_IN_USE", "The specified printer driver is currently in use."),
+	(0x0000_0bba, "ERROR_SPOOL_FILE_NOT_FOUND", "The spool file was not found."),
+	(0x0000_0bbb, "ERROR_SPL_NO_STARTDOC", "A StartDocPrinter call was not issued."),
+	(0x0000_0bbc, "ERROR_SPL_NO_ADDJOB", "An AddJob call was not issued."),
+	(0x0000_0bbd, "ERROR_PRINT_PROCESSOR_ALREADY_INSTALLED", "The specified print processor has already been installed."),
+	(0x0000_0bbe, "ERROR_PRINT_MONITOR_ALREADY_INSTALLED", "The specified print monitor has already been installed."),
+	(0x0000_0bbf, "ERROR_INVALID_PRINT_MONITOR", "The specified print monitor does not have the required functions."),
+	(0x0000_0bc0, "ERROR_PRINT_MONITOR_IN_USE", "The specified print monitor is currently in use."),
+	(0x0000_0bc1, "ERROR_PRINTER_HAS_JOBS_QUEUED", "The requested operation is not allowed when there are jobs queued to the printer."),
+	(0x0000_0bc2, "ERROR_SUCCESS_REBOOT_REQUIRED", "The requested operation is successful. Changes will not be effective until the system is rebooted."),
+	(0x0000_0bc3, "ERROR_SUCCESS_RESTART_REQUIRED", "The requested operation is successful. Changes will not be effective until the service is restarted."),
+	(0x0000_0bc4, "ERROR_PRINTER_NOT_FOUND", "No printers were found."),
+	(0x0000_0bc5, "ERROR_PRINTER_DRIVER_WARNED", "The printer driver is known to be unreliable."),
+	(0x0000_0bc6, "ERROR_PRINTER_DRIVER_BLOCKED", "The printer driver is known to harm the system."),
+	(0x0000_0bc7, "ERROR_PRINTER_DRIVER_PACKAGE_IN_USE", "The specified printer driver package is currently in use."),
+	(0x0000_0bc8, "ERROR_CORE_DRIVER_PACKAGE_NOT_FOUND", "Unable to find a core driver package that is required by the printer driver package."),
+	(0x0000_0bc9, "ERROR_FAIL_REBOOT_REQUIRED", "The requested operation failed. A system reboot is required to roll back changes made."),
+	(0x0000_0bca, "ERROR_FAIL_REBOOT_INITIATED", "The requested operation failed. A system reboot has been initiated to roll back changes made."),
+	(0x0000_0bcb, "ERROR_PRINTER_DRIVER_DOWNLOAD_NEEDED", "The specified printer driver was not found on the system and needs to be downloaded."),
+	(0x0000_0bcc, "ERROR_PRINT_JOB_RESTART_REQUIRED", "The requested print job has failed to print. A print system update requires the job to be resubmitted."),
+	(0x0000_0bcd, "ERROR_INVALID_PRINTER_DRIVER_MANIFEST", "The printer driver does not contain a valid manifest, or contains too many manifests."),
+	(0x0000_0bce, "ERROR_PRINTER_NOT_SHAREABLE", "The specified printer cannot be shared."),
+	(0x0000_0bea, "ERROR_REQUEST_PAUSED", "The operation was paused."),
+	(0x0000_0bf4, "ERROR_APPEXEC_CONDITION_NOT_SATISFIED", "The condition supplied for the app execution request was not satisfied, so the request was not performed."),
+	(0x0000_0bf5, "ERROR_APPEXEC_HANDLE_INVALIDATED", "The supplied handle has been invalidated and may not be used for the requested operation."),
+	(0x0000_0bf6, "ERROR_APPEXEC_INVALID_HOST_GENERATION", "The supplied host generation has been invalidated and may not be used for the requested operation."),
+	(0x0000_0bf7, "ERROR_APPEXEC_UNEXPECTED_PROCESS_REGISTRATION", "An attempt to register a process failed because the target host was not in a valid state to receive process registrations."),
+	(0x0000_0bf8, "ERROR_APPEXEC_INVALID_HOST_STATE", "The host is not in a valid state to support the execution request."),
+	(0x0000_0bf9, "ERROR_APPEXEC_NO_DONOR", "The operation was not completed because a required resource donor was not found for the host."),
+	(0x0000_0bfa, "ERROR_APPEXEC_HOST_ID_MISMATCH", "The operation was not performed because an unexpected host ID was encountered."),
+	(0x0000_0bfb, "ERROR_APPEXEC_UNKNOWN_USER", "The operation was not performed because the specified user was not known to the service."),
+	(0x0000_0f6e, "ERROR_IO_REISSUE_AS_CACHED", "Reissue the given operation as a cached IO operation."),
+	(0x0000_0fa0, "ERROR_WINS_INTERNAL", "WINS encountered an error while processing the command."),
+	(0x0000_0fa1, "ERROR_CAN_NOT_DEL_LOCAL_WINS", "The local WINS cannot be deleted."),
+	(0x0000_0fa2, "ERROR_STATIC_INIT", "The importation from the file failed."),
+	(0x0000_0fa3, "ERROR_INC_BACKUP", "The backup failed. Was a full backup done before?"),
+	(0x0000_0fa4, "ERROR_FULL_BACKUP", "The backup failed. Check the directory to which you are backing the database."),
+	(0x0000_0fa5, "ERROR_REC_NON_EXISTENT", "The name does not exist in the WINS database."),
+	(0x0000_0fa6, "ERROR_RPL_NOT_ALLOWED", "Replication with a nonconfigured partner is not allowed."),
+	(0x0000_1004, "ERROR_DHCP_ADDRESS_CONFLICT", "The DHCP client has obtained an IP address that is already in use on the network. The local interface will be disabled until the DHCP client can obtain a new address."),
+	(0x0000_1068, "ERROR_WMI_GUID_NOT_FOUND", "The GUID passed was not recognized as valid by a WMI data provider."),
+	(0x0000_1069, "ERROR_WMI_INSTANCE_NOT_FOUND", "The instance name passed was not recognized as valid by a WMI data provider."),
+	(0x0000_106a, "ERROR_WMI_ITEMID_NOT_FOUND", "The data item ID passed was not recognized as valid by a WMI data provider."),
+	(0x0000_106b, "ERROR_WMI_TRY_AGAIN", "The WMI request could not be completed and should be retried."),
+	(0x0000_106c, "ERROR_WMI_DP_NOT_FOUND", "The WMI data provider could not be located."),
+	(0x0000_106d, "ERROR_WMI_UNRESOLVED_INSTANCE_REF", "The WMI data provider references an instance set that has not been registered."),
+	(0x0000_106e, "ERROR_WMI_ALREADY_ENABLED", "The WMI data block or event notification has already been enabled."),
+	(0x0000_106f, "ERROR_WMI_GUID_DISCONNECTED", "The WMI data block is no longer available."),
+	(0x0000_1070, "ERROR_WMI_SERVER_UNAVAILABLE", "The WMI data service is not available."),
+	(0x0000_1071, "ERROR_WMI_DP_FAILED", "The WMI data provider failed to carry out the request."),
+	(0x0000_1072, "ERROR_WMI_INVALID_MOF", "The WMI MOF information is not valid."),
+	(0x0000_1073, "ERROR_WMI_INVALID_REGINFO", "The WMI registration information is not valid."),
+	(0x0000_1074, "ERROR_WMI_ALREADY_DISABLED", "The WMI data block or event notification has already been disabled."),
+	(0x0000_1075, "ERROR_WMI_READ_ONLY", "The WMI data item or data block is read only."),
+	(0x0000_1076, "ERROR_WMI_SET_FAILURE", "The WMI data item or data block could not be changed."),
+	(0x0000_109a, "ERROR_NOT_APPCONTAINER", "This operation is only valid in the context of an app container."),
+	(0x0000_109b, "ERROR_APPCONTAINER_REQUIRED", "This application can only run in the context of an app container."),
+	(0x0000_109c, "ERROR_NOT_SUPPORTED_IN_APPCONTAINER", "This functionality is not supported in the context of an app container."),
+	(0x0000_109d, "ERROR_INVALID_PACKAGE_SID_LENGTH", "The length of the SID supplied is not a valid length for app container SIDs."),
+	(0x0000_10cc, "ERROR_INVALID_MEDIA", "The media identifier does not represent a valid medium."),
+	(0x0000_10cd, "ERROR_INVALID_LIBRARY", "The library identifier does not represent a valid library."),
+	(0x0000_10ce, "ERROR_INVALID_MEDIA_POOL", "The media pool identifier does not represent a valid media pool."),
+	(0x0000_10cf, "ERROR_DRIVE_MEDIA_MISMATCH", "The drive and medium are not compatible or exist in different libraries."),
+	(0x0000_10d0, "ERROR_MEDIA_OFFLINE", "The medium currently exists in an offline library and must be online to perform this operation."),
+	(0x0000_10d1, "ERROR_LIBRARY_OFFLINE", "The operation cannot be performed on an offline library."),
+	(0x0000_10d2, "ERROR_EMPTY", "The library, drive, or media pool is empty."),
+	(0x0000_10d3, "ERROR_NOT_EMPTY", "The library, drive, or media pool must be empty to perform this operation."),
+	(0x0000_10d4, "ERROR_MEDIA_UNAVAILABLE", "No media is currently available in this media pool or library."),
+	(0x0000_10d5, "ERROR_RESOURCE_DISABLED", "A resource required for this operation is disabled."),
+	(0x0000_10d6, "ERROR_INVALID_CLEANER", "The media identifier does not represent a valid cleaner."),
+	(0x0000_10d7, "ERROR_UNABLE_TO_CLEAN", "The drive cannot be cleaned or does not support cleaning."),
+	(0x0000_10d8, "ERROR_OBJECT_NOT_FOUND", "The object identifier does not represent a valid object."),
+	(0x0000_10d9, "ERROR_DATABASE_FAILURE", "Unable to read from or write to the database."),
+	(0x0000_10da, "ERROR_DATABASE_FULL", "The database is full."),
+	(0x0000_10db, "ERROR_MEDIA_INCOMPATIBLE", "The medium is not compatible with the device or media pool."),
+	(0x0000_10dc, "ERROR_RESOURCE_NOT_PRESENT", "The resource required for this operation does not exist."),
+	(0x0000_10dd, "ERROR_INVALID_OPERATION", "The operation identifier is not valid."),
+	(0x0000_10de, "ERROR_MEDIA_NOT_AVAILABLE", "The media is not mounted or ready for use."),
+	(0x0000_10df, "ERROR_DEVICE_NOT_AVAILABLE", "The device is not ready for use."),
+	(0x0000_10e0, "ERROR_REQUEST_REFUSED", "The operator or administrator has refused the request."),
+	(0x0000_10e1, "ERROR_INVALID_DRIVE_OBJECT", "The drive identifier does not represent a valid drive."),
+	(0x0000_10e2, "ERROR_LIBRARY_FULL", "Library is full. No slot is available for use."),
+	(0x0000_10e3, "ERROR_MEDIUM_NOT_ACCESSIBLE", "The transport cannot access the medium."),
+	(0x0000_10e4, "ERROR_UNABLE_TO_LOAD_MEDIUM", "Unable to load the medium into the drive."),
+	(0x0000_10e5, "ERROR_UNABLE_TO_INVENTORY_DRIVE", "Unable to retrieve the drive status."),
+	(0x0000_10e6, "ERROR_UNABLE_TO_INVENTORY_SLOT", "Unable to retrieve the slot status."),
+	(0x0000_10e7, "ERROR_UNABLE_TO_INVENTORY_TRANSPORT", "Unable to retrieve status about the transport."),
+	(0x0000_10e8, "ERROR_TRANSPORT_FULL", "Cannot use the transport because it is already in use."),
+	(0x0000_10e9, "ERROR_CONTROLLING_IEPORT", "Unable to open or close the inject/eject port."),
+	(0x0000_10ea, "ERROR_UNABLE_TO_EJECT_MOUNTED_MEDIA", "Unable to eject the medium because it is in a drive."),
+	(0x0000_10eb, "ERROR_CLEANER_SLOT_SET", "A cleaner slot is already reserved."),
+	(0x0000_10ec, "ERROR_CLEANER_SLOT_NOT_SET", "A cleaner slot is not reserved."),
+	(0x0000_10ed, "ERROR_CLEANER_CARTRIDGE_SPENT", "The cleaner cartridge has performed the maximum number of drive cleanings."),
+	(0x0000_10ee, "ERROR_UNEXPECTED_OMID", "Unexpected on-medium identifier."),
+	(0x0000_10ef, "ERROR_CANT_DELETE_LAST_ITEM", "The last remaining item in this group or resource cannot be deleted."),
+	(0x0000_10f0, "ERROR_MESSAGE_EXCEEDS_MAX_SIZE", "The message provided exceeds the maximum size allowed for this parameter."),
+	(0x0000_10f1, "ERROR_VOLUME_CONTAINS_SYS_FILES", "The volume contains system or paging files."),
+	(0x0000_10f2, "ERROR_INDIGENOUS_TYPE", "The media type cannot be removed from this library since at least one drive in the library reports it can support this media type."),
+	(0x0000_10f3, "ERROR_NO_SUPPORTING_DRIVES", "This offline media cannot be mounted on this system since no enabled drives are present which can be used."),
+	(0x0000_10f4, "ERROR_CLEANER_CARTRIDGE_INSTALLED", "A cleaner cartridge is present in the tape library."),
+	(0x0000_10f5, "ERROR_IEPORT_FULL", "Cannot use the inject/eject port because it is not empty."),
+	(0x0000_10fe, "ERROR_FILE_OFFLINE", "This file is currently not available for use on this computer."),
+	(0x0000_10ff, "ERROR_REMOTE_STORAGE_NOT_ACTIVE", "The remote storage service is not operational at this time."),
+	(0x0000_1100, "ERROR_REMOTE_STORAGE_MEDIA_ERROR", "The remote storage service encountered a media error."),
+	(0x0000_1126, "ERROR_NOT_A_REPARSE_POINT", "The file or directory is not a reparse point."),
+	(0x0000_1127, "ERROR_REPARSE_ATTRIBUTE_CONFLICT", "The file or directory is not a reparse point."),
+	(0x0000_1128, "ERROR_INVALID_REPARSE_DATA", "The data present in the reparse point buffer is invalid."),
+	(0x0000_1129, "ERROR_REPARSE_TAG_INVALID", "The tag present in the reparse point buffer is invalid."),
+	(0x0000_112a, "ERROR_REPARSE_TAG_MISMATCH", "There is a mismatch between the tag specified in the request and the tag present in the reparse point."),
+	(0x0000_112b, "ERROR_REPARSE_POINT_ENCOUNTERED", "The object manager encountered a reparse point while retrieving an object."),
+	(0x0000_1130, "ERROR_APP_DATA_NOT_FOUND", "Fast Cache data not found."),
+	(0x0000_1131, "ERROR_APP_DATA_EXPIRED", "Fast Cache data expired."),
+	(0x0000_1132, "ERROR_APP_DATA_CORRUPT", "Fast Cache data corrupt."),
+	(0x0000_1133, "ERROR_APP_DATA_LIMIT_EXCEEDED", "Fast Cache data has exceeded its max size and cannot be updated."),
+	(0x0000_1134, "ERROR_APP_DATA_REBOOT_REQUIRED", "Fast Cache has been ReArmed and requires a reboot until it can be updated."),
+	(0x0000_1144, "ERROR_SECUREBOOT_ROLLBACK_DETECTED", "Secure Boot detected that rollback of protected data has been attempted."),
+	(0x0000_1145, "ERROR_SECUREBOOT_POLICY_VIOLATION", "The value is protected by Secure Boot policy and cannot be modified or deleted."),
+	(0x0000_1146, "ERROR_SECUREBOOT_INVALID_POLICY", "The Secure Boot policy is invalid."),
+	(0x0000_1147, "ERROR_SECUREBOOT_POLICY_PUBLISHER_NOT_FOUND", "A new Secure Boot policy did not contain the current publisher on its update list."),
+	(0x0000_1148, "ERROR_SECUREBOOT_POLICY_NOT_SIGNED", "The Secure Boot policy is either not signed or is signed by a non-trusted signer."),
+	(0x0000_1149, "ERROR_SECUREBOOT_NOT_ENABLED", "Secure Boot is not enabled on this machine."),
+	(0x0000_114a, "ERROR_SECUREBOOT_FILE_REPLACED", "Secure Boot requires that certain files and drivers are not replaced by other files or drivers."),
+	(0x0000_114b, "ERROR_SECUREBOOT_POLICY_NOT_AUTHORIZED", "The Secure Boot Supplemental Policy file was not authorized on this machine."),
+	(0x0000_114c, "ERROR_SECUREBOOT_POLICY_UNKNOWN", "The Supplemental Policy is not recognized on this device."),
+	(0x0000_114d, "ERROR_SECUREBOOT_POLICY_MISSING_ANTIROLLBACKVERSION", "The Antirollback version was not found in the Secure Boot Policy."),
+	(0x0000_114e, "ERROR_SECUREBOOT_PLATFORM_ID_MISMATCH", "The Platform ID specified in the Secure Boot policy does not match the Platform ID on this device."),
+	(0x0000_114f, "ERROR_SECUREBOOT_POLICY_ROLLBACK_DETECTED", "The Secure Boot policy file has an older Antirollback Version than this device."),
+	(0x0000_1150, "ERROR_SECUREBOOT_POLICY_UPGRADE_MISMATCH", "The Secure Boot policy file does not match the upgraded legacy policy."),
+	(0x0000_1151, "ERROR_SECUREBOOT_REQUIRED_POLICY_FILE_MISSING", "The Secure Boot policy file is required but could not be found."),
+	(0x0000_1152, "ERROR_SECUREBOOT_NOT_BASE_POLICY", "Supplemental Secure Boot policy file can not be loaded as a base Secure Boot policy."),
+	(0x0000_1153, "ERROR_SECUREBOOT_NOT_SUPPLEMENTAL_POLICY", "Base Secure Boot policy file can not be loaded as a Supplemental Secure Boot policy."),
+	(0x0000_1158, "ERROR_OFFLOAD_READ_FLT_NOT_SUPPORTED", "The copy offload read operation is not supported by a filter."),
+	(0x0000_1159, "ERROR_OFFLOAD_WRITE_FLT_NOT_SUPPORTED", "The copy offload write operation is not supported by a filter."),
+	(0x0000_115a, "ERROR_OFFLOAD_READ_FILE_NOT_SUPPORTED", "The copy offload read operation is not supported for the file."),
+	(0x0000_115b, "ERROR_OFFLOAD_WRITE_FILE_NOT_SUPPORTED", "The copy offload write operation is not supported for the file."),
+	(0x0000_115c, "ERROR_ALREADY_HAS_STREAM_ID", "This file is currently associated with a different stream id."),
+	(0x0000_115d, "ERROR_SMR_GARBAGE_COLLECTION_REQUIRED", "The volume must undergo garbage collection."),
+	(0x0000_115e, "ERROR_WOF_WIM_HEADER_CORRUPT", "The WOF driver encountered a corruption in WIM image's Header."),
+	(0x0000_115f, "ERROR_WOF_WIM_RESOURCE_TABLE_CORRUPT", "The WOF driver encountered a corruption in WIM image's Resource Table."),
+	(0x0000_1160, "ERROR_WOF_FILE_RESOURCE_TABLE_CORRUPT", "The WOF driver encountered a corruption in the compressed file's Resource Table."),
+	(0x0000_1194, "ERROR_VOLUME_NOT_SIS_ENABLED", "Single Instance Storage is not available on this volume."),
+	(0x0000_11c6, "ERROR_SYSTEM_INTEGRITY_ROLLBACK_DETECTED", "System Integrity detected that policy rollback has been attempted."),
+	(0x0000_11c7, "ERROR_SYSTEM_INTEGRITY_POLICY_VIOLATION", "Your organization used Device Guard to block this app. Contact your support person for more info."),
+	(0x0000_11c8, "ERROR_SYSTEM_INTEGRITY_INVALID_POLICY", "The System Integrity policy is invalid."),
+	(0x0000_11c9, "ERROR_SYSTEM_INTEGRITY_POLICY_NOT_SIGNED", "The System Integrity policy is either not signed or is signed by a non-trusted signer."),
+	(0x0000_11ca, "ERROR_SYSTEM_INTEGRITY_TOO_MANY_POLICIES", "The number of System Integrity policies is out of limit."),
+	(0x0000_11cb, "ERROR_SYSTEM_INTEGRITY_SUPPLEMENTAL_POLICY_NOT_AUTHORIZED", "The Code Integrity supplemental policy is not authorized by a Code Integrity base policy."),
+	(0x0000_11d0, "ERROR_VSM_NOT_INITIALIZED", "Virtual Secure Mode (VSM) is not initialized. The hypervisor or VSM may not be present or enabled."),
+	(0x0000_11d1, "ERROR_VSM_DMA_PROTECTION_NOT_IN_USE", "The hypervisor is not protecting DMA because an IOMMU is not present or not enabled in the BIOS."),
+	(0x0000_11da, "ERROR_PLATFORM_MANIFEST_NOT_AUTHORIZED", "The Platform Manifest file was not authorized on this machine."),
+	(0x0000_11db, "ERROR_PLATFORM_MANIFEST_INVALID", "The Platform Manifest file was not valid."),
+	(0x0000_11dc, "ERROR_PLATFORM_MANIFEST_FILE_NOT_AUTHORIZED", "The file is not authorized on this platform because an entry was not found in the Platform Manifest."),
+	(0x0000_11dd, "ERROR_PLATFORM_MANIFEST_CATALOG_NOT_AUTHORIZED", "The catalog is not authorized on this platform because an entry was not found in the Platform Manifest."),
+	(0x0000_11de, "ERROR_PLATFORM_MANIFEST_BINARY_ID_NOT_FOUND", "The file is not authorized on this platform because a Binary ID was not found in the embedded signature."),
+	(0x0000_11df, "ERROR_PLATFORM_MANIFEST_NOT_ACTIVE", "No active Platform Manifest exists on this system."),
+	(0x0000_11e0, "ERROR_PLATFORM_MANIFEST_NOT_SIGNED", "The Platform Manifest file was not properly signed."),
+	(0x0000_1389, "ERROR_DEPENDENT_RESOURCE_EXISTS", "The operation cannot be completed because other resources are dependent on this resource."),
+	(0x0000_138a, "ERROR_DEPENDENCY_NOT_FOUND", "The cluster resource dependency cannot be found."),
+	(0x0000_138b, "ERROR_DEPENDENCY_ALREADY_EXISTS", "The cluster resource cannot be made dependent on the specified resource because it is already dependent."),
+	(0x0000_138c, "ERROR_RESOURCE_NOT_ONLINE", "The cluster resource is not online."),
+	(0x0000_138d, "ERROR_HOST_NODE_NOT_AVAILABLE", "A cluster node is not available for this operation."),
+	(0x0000_138e, "ERROR_RESOURCE_NOT_AVAILABLE", "The cluster resource is not available."),
+	(0x0000_138f, "ERROR_RESOURCE_NOT_FOUND", "The cluster resource could not be found."),
+	(0x0000_1390, "ERROR_SHUTDOWN_CLUSTER", "The cluster is being shut down."),
+	(0x0000_1391, "ERROR_CANT_EVICT_ACTIVE_NODE", "A cluster node cannot be evicted from the cluster unless the node is down or it is the last node."),
+	(0x0000_1392, "ERROR_OBJECT_ALREADY_EXISTS", "The object already exists."),
+	(0x0000_1393, "ERROR_OBJECT_IN_LIST", "The object is already in the list."),
+	(0x0000_1394, "ERROR_GROUP_NOT_AVAILABLE", "The cluster group is not available for any new requests."),
+	(0x0000_1395, "ERROR_GROUP_NOT_FOUND", "The cluster group could not be found."),
+	(0x0000_1396, "ERROR_GROUP_NOT_ONLINE", "The operation could not be completed because the cluster group is not online."),
+	(0x0000_1397, "ERROR_HOST_NODE_NOT_RESOURCE_OWNER", "The operation failed because either the specified cluster node is not the owner of the resource, or the node is not a possible owner of the resource."),
+	(0x0000_1398, "ERROR_HOST_NODE_NOT_GROUP_OWNER", "The operation failed because either the specified cluster node is not the owner of the group, or the node is not a possible owner of the group."),
+	(0x0000_1399, "ERROR_RESMON_CREATE_FAILED", "The cluster resource could not be created in the specified resource monitor."),
+	(0x0000_139a, "ERROR_RESMON_ONLINE_FAILED", "The cluster resource could not be brought online by the resource monitor."),
+	(0x0000_139b, "ERROR_RESOURCE_ONLINE", "The operation could not be completed because the cluster resource is online."),
+	(0x0000_139c, "ERROR_QUORUM_RESOURCE", "The cluster resource could not be deleted or brought offline because it is the quorum resource."),
+	(0x0000_139d, "ERROR_NOT_QUORUM_CAPABLE", "The cluster could not make the specified resource a quorum resource because it is not capable of being a quorum resource."),
+	(0x0000_139e, "ERROR_CLUSTER_SHUTTING_DOWN", "The cluster software is shutting down."),
+	(0x0000_139f, "ERROR_INVALID_STATE", "The group or resource is not in the correct state to perform the requested operation."),
+	(0x0000_13a0, "ERROR_RESOURCE_PROPERTIES_STORED", "The properties were stored but not all changes will take effect until the next time the resource is brought online."),
+	(0x0000_13a1, "ERROR_NOT_QUORUM_CLASS", "The cluster could not make the specified resource a quorum resource because it does not belong to a shared storage class."),
+	(0x0000_13a2, "ERROR_CORE_RESOURCE", "The cluster resource could not be deleted since it is a core resource."),
+	(0x0000_13a3, "ERROR_QUORUM_RESOURCE_ONLINE_FAILED", "The quorum resource failed to come online."),
+	(0x0000_13a4, "ERROR_QUORUMLOG_OPEN_FAILED", "The quorum log could not be created or mounted successfully."),
+	(0x0000_13a5, "ERROR_CLUSTERLOG_CORRUPT", "The cluster log is corrupt."),
+	(0x0000_13a6, "ERROR_CLUSTERLOG_RECORD_EXCEEDS_MAXSIZE", "The record could not be written to the cluster log since it exceeds the maximum size."),
+	(0x0000_13a7, "ERROR_CLUSTERLOG_EXCEEDS_MAXSIZE", "The cluster log exceeds its maximum size."),
+	(0x0000_13a8, "ERROR_CLUSTERLOG_CHKPOINT_NOT_FOUND", "No checkpoint record was found in the cluster log."),
+	(0x0000_13a9, "ERROR_CLUSTERLOG_NOT_ENOUGH_SPACE", "The minimum required disk space needed for logging is not available."),
+	(0x0000_13aa, "ERROR_QUORUM_OWNER_ALIVE", "The cluster node failed to take control of the quorum resource because the resource is owned by another active node."),
+	(0x0000_13ab, "ERROR_NETWORK_NOT_AVAILABLE", "A cluster network is not available for this operation."),
+	(0x0000_13ac, "ERROR_NODE_NOT_AVAILABLE", "A cluster node is not available for this operation."),
+	(0x0000_13ad, "ERROR_ALL_NODES_NOT_AVAILABLE", "All cluster nodes must be running to perform this operation."),
+	(0x0000_13ae, "ERROR_RESOURCE_FAILED", "A cluster resource failed."),
+	(0x0000_13af, "ERROR_CLUSTER_INVALID_NODE", "The cluster node is not valid."),
+	(0x0000_13b0, "ERROR_CLUSTER_NODE_EXISTS", "The cluster node already exists."),
+	(0x0000_13b1, "ERROR_CLUSTER_JOIN_IN_PROGRESS", "A node is in the process of joining the cluster."),
+	(0x0000_13b2, "ERROR_CLUSTER_NODE_NOT_FOUND", "The cluster node was not found."),
+	(0x0000_13b3, "ERROR_CLUSTER_LOCAL_NODE_NOT_FOUND", "The cluster local node information was not found."),
+	(0x0000_13b4, "ERROR_CLUSTER_NETWORK_EXISTS", "The cluster network already exists."),
+	(0x0000_13b5, "ERROR_CLUSTER_NETWORK_NOT_FOUND", "The cluster network was not found."),
+	(0x0000_13b6, "ERROR_CLUSTER_NETINTERFACE_EXISTS", "The cluster network interface already exists."),
+	(0x0000_13b7, "ERROR_CLUSTER_NETINTERFACE_NOT_FOUND", "The cluster network interface was not found."),
+	(0x0000_13b8, "ERROR_CLUSTER_INVALID_REQUEST", "The cluster request is not valid for this object."),
+	(0x0000_13b9, "ERROR_CLUSTER_INVALID_NETWORK_PROVIDER", "The cluster network provider is not valid."),
+	(0x0000_13ba, "ERROR_CLUSTER_NODE_DOWN", "The cluster node is down."),
+	(0x0000_13bb, "ERROR_CLUSTER_NODE_UNREACHABLE", "The cluster node is not reachable."),
+	(0x0000_13bc, "ERROR_CLUSTER_NODE_NOT_MEMBER", "The cluster node is not a member of the cluster."),
+	(0x0000_13bd, "ERROR_CLUSTER_JOIN_NOT_IN_PROGRESS", "A cluster join operation is not in progress."),
+	(0x0000_13be, "ERROR_CLUSTER_INVALID_NETWORK", "The cluster network is not valid."),
+	(0x0000_13c0, "ERROR_CLUSTER_NODE_UP", "The cluster node is up."),
+	(0x0000_13c1, "ERROR_CLUSTER_IPADDR_IN_USE", "The cluster IP address is already in use."),
+	(0x0000_13c2, "ERROR_CLUSTER_NODE_NOT_PAUSED", "The cluster node is not paused."),
+	(0x0000_13c3, "ERROR_CLUSTER_NO_SECURITY_CONTEXT", "No cluster security context is available."),
+	(0x0000_13c4, "ERROR_CLUSTER_NETWORK_NOT_INTERNAL", "The cluster network is not configured for internal cluster communication."),
+	(0x0000_13c5, "ERROR_CLUSTER_NODE_ALREADY_UP", "The cluster node is already up."),
+	(0x0000_13c6, "ERROR_CLUSTER_NODE_ALREADY_DOWN", "The cluster node is already down."),
+	(0x0000_13c7, "ERROR_CLUSTER_NETWORK_ALREADY_ONLINE", "The cluster network is already online."),
+	(0x0000_13c8, "ERROR_CLUSTER_NETWORK_ALREADY_OFFLINE", "The cluster network is already offline."),
+	(0x0000_13c9, "ERROR_CLUSTER_NODE_ALREADY_MEMBER", "The cluster node is already a member of the cluster."),
+	(0x0000_13ca, "ERROR_CLUSTER_LAST_INTERNAL_NETWORK", "The cluster network is the only one configured for internal cluster communication between two or more active cluster nodes. The internal communication capability cannot be removed from the network."),
+	(0x0000_13cb, "ERROR_CLUSTER_NETWORK_HAS_DEPENDENTS", "One or more cluster resources depend on the network to provide service to clients. The client access capability cannot be removed from the network."),
+	(0x0000_13cc, "ERROR_INVALID_OPERATION_ON_QUORUM", "This operation cannot currently be performed on the cluster group containing the quorum resource."),
+	(0x0000_13cd, "ERROR_DEPENDENCY_NOT_ALLOWED", "The cluster quorum resource is not allowed to have any dependencies."),
+	(0x0000_13ce, "ERROR_CLUSTER_NODE_PAUSED", "The cluster node is paused."),
+	(0x0000_13cf, "ERROR_NODE_CANT_HOST_RESOURCE", "The cluster resource cannot be brought online. The owner node cannot run this resource."),
+	(0x0000_13d0, "ERROR_CLUSTER_NODE_NOT_READY", "The cluster node is not ready to perform the requested operation."),
+	(0x0000_13d1, "ERROR_CLUSTER_NODE_SHUTTING_DOWN", "The cluster node is shutting down."),
+	(0x0000_13d2, "ERROR_CLUSTER_JOIN_ABORTED", "The cluster join operation was aborted."),
+	(0x0000_13d3, "ERROR_CLUSTER_INCOMPATIBLE_VERSIONS", "The node failed to join the cluster because the joining node and other nodes in the cluster have incompatible operating system versions."),
+	(0x0000_13d4, "ERROR_CLUSTER_MAXNUM_OF_RESOURCES_EXCEEDED", "This resource cannot be created because the cluster has reached the limit on the number of resources it can monitor."),
+	(0x0000_13d5, "ERROR_CLUSTER_SYSTEM_CONFIG_CHANGED", "The system configuration changed during the cluster join or form operation. The join or form operation was aborted."),
+	(0x0000_13d6, "ERROR_CLUSTER_RESOURCE_TYPE_NOT_FOUND", "The specified resource type was not found."),
+	(0x0000_13d7, "ERROR_CLUSTER_RESTYPE_NOT_SUPPORTED", "The specified node does not support a resource of this type."),
+	(0x0000_13d8, "ERROR_CLUSTER_RESNAME_NOT_FOUND", "The specified resource name is not supported by this resource DLL."),
+	(0x0000_13d9, "ERROR_CLUSTER_NO_RPC_PACKAGES_REGISTERED", "No authentication package could be registered with the RPC server."),
+	(0x0000_13da, "ERROR_CLUSTER_OWNER_NOT_IN_PREFLIST", "You cannot bring the group online because the owner of the group is not in the preferred list for the group."),
+	(0x0000_13db, "ERROR_CLUSTER_DATABASE_SEQMISMATCH", "The join operation failed because the cluster database sequence number has changed or is incompatible with the locker node."),
+	(0x0000_13dc, "ERROR_RESMON_INVALID_STATE", "The resource monitor will not allow the fail operation to be performed while the resource is in its current state."),
+	(0x0000_13dd, "ERROR_CLUSTER_GUM_NOT_LOCKER", "A non locker code got a request to reserve the lock for making global updates."),
+	(0x0000_13de, "ERROR_QUORUM_DISK_NOT_FOUND", "The quorum disk could not be located by the cluster service."),
+	(0x0000_13df, "ERROR_DATABASE_BACKUP_CORRUPT", "The backed up cluster database is possibly corrupt."),
+	(0x0000_13e0, "ERROR_CLUSTER_NODE_ALREADY_HAS_DFS_ROOT", "A DFS root already exists in this cluster node."),
+	(0x0000_13e1, "ERROR_RESOURCE_PROPERTY_UNCHANGEABLE", "An attempt to modify a resource property failed because it conflicts with another existing property."),
+	(0x0000_13e2, "ERROR_NO_ADMIN_ACCESS_POINT", "This operation is not supported on a cluster without an Administrator Access Point."),
+	(0x0000_1702, "ERROR_CLUSTER_MEMBERSHIP_INVALID_STATE", "An operation was attempted that is incompatible with the current membership state of the node."),
+	(0x0000_1703, "ERROR_CLUSTER_QUORUMLOG_NOT_FOUND", "The quorum resource does not contain the quorum log."),
+	(0x0000_1704, "ERROR_CLUSTER_MEMBERSHIP_HALT", "The membership engine requested shutdown of the cluster service on this node."),
+	(0x0000_1705, "ERROR_CLUSTER_INSTANCE_ID_MISMATCH", "The join operation failed because the cluster instance ID of the joining node does not match the cluster instance ID of the sponsor node."),
+	(0x0000_1706, "ERROR_CLUSTER_NETWORK_NOT_FOUND_FOR_IP", "A matching cluster network for the specified IP address could not be found."),
+	(0x0000_1707, "ERROR_CLUSTER_PROPERTY_DATA_TYPE_MISMATCH", "The actual data type of the property did not match the expected data type of the property."),
+	(0x0000_1708, "ERROR_CLUSTER_EVICT_WITHOUT_CLEANUP", "The cluster node was evicted from the cluster successfully, but the node was not cleaned up."),
+	(0x0000_1709, "ERROR_CLUSTER_PARAMETER_MISMATCH", "Two or more parameter values specified for a resource's properties are in conflict."),
+	(0x0000_170a, "ERROR_NODE_CANNOT_BE_CLUSTERED", "This computer cannot be made a member of a cluster."),
+	(0x0000_170b, "ERROR_CLUSTER_WRONG_OS_VERSION", "This computer cannot be made a member of a cluster because it does not have the correct version of Windows installed."),
+	(0x0000_170c, "ERROR_CLUSTER_CANT_CREATE_DUP_CLUSTER_NAME", "A cluster cannot be created with the specified cluster name because that cluster name is already in use."),
+	(0x0000_170d, "ERROR_CLUSCFG_ALREADY_COMMITTED", "The cluster configuration action has already been committed."),
+	(0x0000_170e, "ERROR_CLUSCFG_ROLLBACK_FAILED", "The cluster configuration action could not be rolled back."),
+	(0x0000_170f, "ERROR_CLUSCFG_SYSTEM_DISK_DRIVE_LETTER_CONFLICT", "The drive letter assigned to a system disk on one node conflicted with the drive letter assigned to a disk on another node."),
+	(0x0000_1710, "ERROR_CLUSTER_OLD_VERSION", "One or more nodes in the cluster are running a version of Windows that does not support this operation."),
+	(0x0000_1711, "ERROR_CLUSTER_MISMATCHED_COMPUTER_ACCT_NAME", "The name of the corresponding computer account does not match the Network Name for this resource."),
+	(0x0000_1712, "ERROR_CLUSTER_NO_NET_ADAPTERS", "No network adapters are available."),
+	(0x0000_1713, "ERROR_CLUSTER_POISONED", "The cluster node has been poisoned."),
+	(0x0000_1714, "ERROR_CLUSTER_GROUP_MOVING", "The group is unable to accept the request since it is moving to another node."),
+	(0x0000_1715, "ERROR_CLUSTER_RESOURCE_TYPE_BUSY", "The resource type cannot accept the request since is too busy performing another operation."),
+	(0x0000_1716, "ERROR_RESOURCE_CALL_TIMED_OUT", "The call to the cluster resource DLL timed out."),
+	(0x0000_1717, "ERROR_INVALID_CLUSTER_IPV6_ADDRESS", "The address is not valid for an IPv6 Address resource. A global IPv6 address is required, and it must match a cluster network. Compatibility addresses are not permitted."),
+	(0x0000_1718, "ERROR_CLUSTER_INTERNAL_INVALID_FUNCTION", "An internal cluster error occurred. A call to an invalid function was attempted."),
+	(0x0000_1719, "ERROR_CLUSTER_PARAMETER_OUT_OF_BOUNDS", "A parameter value is out of acceptable range."),
+	(0x0000_171a, "ERROR_CLUSTER_PARTIAL_SEND", "A network error occurred while sending data to another node in the cluster. The number of bytes transmitted was less than required."),
+	(0x0000_171b, "ERROR_CLUSTER_REGISTRY_INVALID_FUNCTION", "An invalid cluster registry operation was attempted."),
+	(0x0000_171c, "ERROR_CLUSTER_INVALID_STRING_TERMINATION", "An input string of characters is not properly terminated."),
+	(0x0000_171d, "ERROR_CLUSTER_INVALID_STRING_FORMAT", "An input string of characters is not in a valid format for the data it represents."),
+	(0x0000_171e, "ERROR_CLUSTER_DATABASE_TRANSACTION_IN_PROGRESS", "An internal cluster error occurred. A cluster database transaction was attempted while a transaction was already in progress."),
+	(0x0000_171f, "ERROR_CLUSTER_DATABASE_TRANSACTION_NOT_IN_PROGRESS", "An internal cluster error occurred. There was an attempt to commit a cluster database transaction while no transaction was in progress."),
+	(0x0000_1720, "ERROR_CLUSTER_NULL_DATA", "An internal cluster error occurred. Data was not properly initialized."),
+	(0x0000_1721, "ERROR_CLUSTER_PARTIAL_READ", "An error occurred while reading from a stream of data. An unexpected number of bytes was returned."),
+	(0x0000_1722, "ERROR_CLUSTER_PARTIAL_WRITE", "An error occurred while writing to a stream of data. The required number of bytes could not be written."),
+	(0x0000_1723, "ERROR_CLUSTER_CANT_DESERIALIZE_DATA", "An error occurred while deserializing a stream of cluster data."),
+	(0x0000_1724, "ERROR_DEPENDENT_RESOURCE_PROPERTY_CONFLICT", "One or more property values for this resource are in conflict with one or more property values associated with its dependent resources."),
+	(0x0000_1725, "ERROR_CLUSTER_NO_QUORUM", "A quorum of cluster nodes was not present to form a cluster."),
+	(0x0000_1726, "ERROR_CLUSTER_INVALID_IPV6_NETWORK", "The cluster network is not valid for an IPv6 Address resource, or it does not match the configured address."),
+	(0x0000_1727, "ERROR_CLUSTER_INVALID_IPV6_TUNNEL_NETWORK", "The cluster network is not valid for an IPv6 Tunnel resource. Check the configuration of the IP Address resource on which the IPv6 Tunnel resource depends."),
+	(0x0000_1728, "ERROR_QUORUM_NOT_ALLOWED_IN_THIS_GROUP", "Quorum resource cannot reside in the Available Storage group."),
+	(0x0000_1729, "ERROR_DEPENDENCY_TREE_TOO_COMPLEX", "The dependencies for this resource are nested too deeply."),
+	(0x0000_172a, "ERROR_EXCEPTION_IN_RESOURCE_CALL", "The call into the resource DLL raised an unhandled exception."),
+	(0x0000_172b, "ERROR_CLUSTER_RHS_FAILED_INITIALIZATION", "The RHS process failed to initialize."),
+	(0x0000_172c, "ERROR_CLUSTER_NOT_INSTALLED", "The Failover Clustering feature is not installed on this node."),
+	(0x0000_172d, "ERROR_CLUSTER_RESOURCES_MUST_BE_ONLINE_ON_THE_SAME_NODE", "The resources must be online on the same node for this operation."),
+	(0x0000_172e, "ERROR_CLUSTER_MAX_NODES_IN_CLUSTER", "A new node can not be added since this cluster is already at its maximum number of nodes."),
+	(0x0000_172f, "ERROR_CLUSTER_TOO_MANY_NODES", "This cluster can not be created since the specified number of nodes exceeds the maximum allowed limit."),
+	(0x0000_1730, "ERROR_CLUSTER_OBJECT_ALREADY_USED", "An attempt to use the specified cluster name failed because an enabled computer object with the given name already exists in the domain."),
+	(0x0000_1731, "ERROR_NONCORE_GROUPS_FOUND", "This cluster cannot be destroyed. It has non-core application groups which must be deleted before the cluster can be destroyed."),
+	(0x0000_1732, "ERROR_FILE_SHARE_RESOURCE_CONFLICT", "File share associated with file share witness resource cannot be hosted by this cluster or any of its nodes."),
+	(0x0000_1733, "ERROR_CLUSTER_EVICT_INVALID_REQUEST", "Eviction of this node is invalid at this time. Due to quorum requirements node eviction will result in cluster shutdown."),
+	(0x0000_1734, "ERROR_CLUSTER_SINGLETON_RESOURCE", "Only one instance of this resource type is allowed in the cluster."),
+	(0x0000_1735, "ERROR_CLUSTER_GROUP_SINGLETON_RESOURCE", "Only one instance of this resource type is allowed per resource group."),
+	(0x0000_1736, "ERROR_CLUSTER_RESOURCE_PROVIDER_FAILED", "The resource failed to come online due to the failure of one or more provider resources."),
+	(0x0000_1737, "ERROR_CLUSTER_RESOURCE_CONFIGURATION_ERROR", "The resource has indicated that it cannot come online on any node."),
+	(0x0000_1738, "ERROR_CLUSTER_GROUP_BUSY", "The current operation cannot be performed on this group at this time."),
+	(0x0000_1739, "ERROR_CLUSTER_NOT_SHARED_VOLUME", "The directory or file is not located on a cluster shared volume."),
+	(0x0000_173a, "ERROR_CLUSTER_INVALID_SECURITY_DESCRIPTOR", "The Security Descriptor does not meet the requirements for a cluster."),
+	(0x0000_173b, "ERROR_CLUSTER_SHARED_VOLUMES_IN_USE", "There is one or more shared volumes resources configured in the cluster. Those resources must be moved to available storage in order for operation to succeed."),
+	(0x0000_173c, "ERROR_CLUSTER_USE_SHARED_VOLUMES_API", "This group or resource cannot be directly manipulated. Use shared volume APIs to perform desired operation."),
+	(0x0000_173d, "ERROR_CLUSTER_BACKUP_IN_PROGRESS", "Back up is in progress. Please wait for backup completion before trying this operation again."),
+	(0x0000_173e, "ERROR_NON_CSV_PATH", "The path does not belong to a cluster shared volume."),
+	(0x0000_173f, "ERROR_CSV_VOLUME_NOT_LOCAL", "The cluster shared volume is not locally mounted on this node."),
+	(0x0000_1740, "ERROR_CLUSTER_WATCHDOG_TERMINATING", "The cluster watchdog is terminating."),
+	(0x0000_1741, "ERROR_CLUSTER_RESOURCE_VETOED_MOVE_INCOMPATIBLE_NODES", "A resource vetoed a move between two nodes because they are incompatible."),
+	(0x0000_1742, "ERROR_CLUSTER_INVALID_NODE_WEIGHT", "The request is invalid either because node weight cannot be changed while the cluster is in disk-only quorum mode, or because changing the node weight would violate the minimum cluster quorum requirements."),
+	(0x0000_1743, "ERROR_CLUSTER_RESOURCE_VETOED_CALL", "The resource vetoed the call."),
+	(0x0000_1744, "ERROR_RESMON_SYSTEM_RESOURCES_LACKING", "Resource could not start or run because it could not reserve sufficient system resources."),
+	(0x0000_1745, "ERROR_CLUSTER_RESOURCE_VETOED_MOVE_NOT_ENOUGH_RESOURCES_ON_DESTINATION", "A resource vetoed a move between two nodes because the destination currently does not have enough resources to complete the operation."),
+	(0x0000_1746, "ERROR_CLUSTER_RESOURCE_VETOED_MOVE_NOT_ENOUGH_RESOURCES_ON_SOURCE", "A resource vetoed a move between two nodes because the source currently does not have enough resources to complete the operation."),
+	(0x0000_1747, "ERROR_CLUSTER_GROUP_QUEUED", "The requested operation can not be completed because the group is queued for an operation."),
+	(0x0000_1748, "ERROR_CLUSTER_RESOURCE_LOCKED_STATUS", "The requested operation can not be completed because a resource has locked status."),
+	(0x0000_1749, "ERROR_CLUSTER_SHARED_VOLUME_FAILOVER_NOT_ALLOWED", "The resource cannot move to another node because a cluster shared volume vetoed the operation."),
+	(0x0000_174a, "ERROR_CLUSTER_NODE_DRAIN_IN_PROGRESS", "A node drain is already in progress."),
+	(0x0000_174b, "ERROR_CLUSTER_DISK_NOT_CONNECTED", "Clustered storage is not connected to the node."),
+	(0x0000_174c, "ERROR_DISK_NOT_CSV_CAPABLE", "The disk is not configured in a way to be used with CSV. CSV disks must have at least one partition that is formatted with NTFS or REFS."),
+	(0x0000_174d, "ERROR_RESOURCE_NOT_IN_AVAILABLE_STORAGE", "The resource must be part of the Available Storage group to complete this action."),
+	(0x0000_174e, "ERROR_CLUSTER_SHARED_VOLUME_REDIRECTED", "CSVFS failed operation as volume is in redirected mode."),
+	(0x0000_174f, "ERROR_CLUSTER_SHARED_VOLUME_NOT_REDIRECTED", "CSVFS failed operation as volume is not in redirected mode."),
+	(0x0000_1750, "ERROR_CLUSTER_CANNOT_RETURN_PROPERTIES", "Cluster properties cannot be returned at this time."),
+	(0x0000_1751, "ERROR_CLUSTER_RESOURCE_CONTAINS_UNSUPPORTED_DIFF_AREA_FOR_SHARED_VOLUMES", "The clustered disk resource contains software snapshot diff area that are not supported for Cluster Shared Volumes."),
+	(0x0000_1752, "ERROR_CLUSTER_RESOURCE_IS_IN_MAINTENANCE_MODE", "The operation cannot be completed because the resource is in maintenance mode."),
+	(0x0000_1753, "ERROR_CLUSTER_AFFINITY_CONFLICT", "The operation cannot be completed because of cluster affinity conflicts."),
+	(0x0000_1754, "ERROR_CLUSTER_RESOURCE_IS_REPLICA_VIRTUAL_MACHINE", "The operation cannot be completed because the resource is a replica virtual machine."),
+	(0x0000_1755, "ERROR_CLUSTER_UPGRADE_INCOMPATIBLE_VERSIONS", "The Cluster Functional Level could not be increased because not all nodes in the cluster support the updated version."),
+	(0x0000_1756, "ERROR_CLUSTER_UPGRADE_FIX_QUORUM_NOT_SUPPORTED", "Updating the cluster functional level failed because the cluster is running in fix quorum mode. Start additional nodes which are members of the cluster until the cluster reaches quorum and the cluster will automatically switch out of fix quorum mode, or stop and restart the cluster without the FixQuorum switch. Once the cluster is out of fix quorum mode retry the Update-ClusterFunctionalLevel PowerShell cmdlet to update the cluster functional level."),
+	(0x0000_1757, "ERROR_CLUSTER_UPGRADE_RESTART_REQUIRED", "The cluster functional level has been successfully updated but not all features are available yet. Restart the cluster by using the Stop-Cluster PowerShell cmdlet followed by the Start-Cluster PowerShell cmdlet and all cluster features will be available."),
+	(0x0000_1758, "ERROR_CLUSTER_UPGRADE_IN_PROGRESS", "The cluster is currently performing a version upgrade."),
+	(0x0000_1759, "ERROR_CLUSTER_UPGRADE_INCOMPLETE", "The cluster did not successfully complete the version upgrade."),
+	(0x0000_175a, "ERROR_CLUSTER_NODE_IN_GRACE_PERIOD", "The cluster node is in grace period."),
+	(0x0000_175b, "ERROR_CLUSTER_CSV_IO_PAUSE_TIMEOUT", "The operation has failed because CSV volume was not able to recover in time specified on this file object."),
+	(0x0000_175c, "ERROR_NODE_NOT_ACTIVE_CLUSTER_MEMBER", "The operation failed because the requested node is not currently part of active cluster membership."),
+	(0x0000_175d, "ERROR_CLUSTER_RESOURCE_NOT_MONITORED", "The operation failed because the requested cluster resource is currently unmonitored."),
+	(0x0000_175e, "ERROR_CLUSTER_RESOURCE_DOES_NOT_SUPPORT_UNMONITORED", "The operation failed because a resource does not support running in an unmonitored state."),
+	(0x0000_175f, "ERROR_CLUSTER_RESOURCE_IS_REPLICATED", "The operation cannot be completed because a resource participates in replication."),
+	(0x0000_1760, "ERROR_CLUSTER_NODE_ISOLATED", "The operation failed because the requested cluster node has been isolated."),
+	(0x0000_1761, "ERROR_CLUSTER_NODE_QUARANTINED", "The operation failed because the requested cluster node has been quarantined."),
+	(0x0000_1762, "ERROR_CLUSTER_DATABASE_UPDATE_CONDITION_FAILED", "The operation failed because the specified database update condition was not met."),
+	(0x0000_1763, "ERROR_CLUSTER_SPACE_DEGRADED", "A clustered space is in a degraded condition and the requested action cannot be completed at this time."),
+	(0x0000_1764, "ERROR_CLUSTER_TOKEN_DELEGATION_NOT_SUPPORTED", "The operation failed because token delegation for this control is not supported."),
+	(0x0000_1765, "ERROR_CLUSTER_CSV_INVALID_HANDLE", "The operation has failed because CSV has invalidated this file object."),
+	(0x0000_1766, "ERROR_CLUSTER_CSV_SUPPORTED_ONLY_ON_COORDINATOR", "This operation is supported only on the CSV coordinator node."),
+	(0x0000_1767, "ERROR_GROUPSET_NOT_AVAILABLE", "The cluster group set is not available for any further requests."),
+	(0x0000_1768, "ERROR_GROUPSET_NOT_FOUND", "The cluster group set could not be found."),
+	(0x0000_1769, "ERROR_GROUPSET_CANT_PROVIDE", "The action cannot be completed at this time because the cluster group set would fall below quorum and not be able to act as a provider."),
+	(0x0000_176a, "ERROR_CLUSTER_FAULT_DOMAIN_PARENT_NOT_FOUND", "The specified parent fault domain is not found."),
+	(0x0000_176b, "ERROR_CLUSTER_FAULT_DOMAIN_INVALID_HIERARCHY", "The fault domain cannot be a child of the parent specified."),
+	(0x0000_176c, "ERROR_CLUSTER_FAULT_DOMAIN_FAILED_S2D_VALIDATION", "Storage Spaces Direct has rejected the proposed fault domain changes because it impacts the fault tolerance of the storage."),
+	(0x0000_176d, "ERROR_CLUSTER_FAULT_DOMAIN_S2D_CONNECTIVITY_LOSS", "Storage Spaces Direct has rejected the proposed fault domain changes because it reduces the storage connected to the system."),
+	(0x0000_176e, "ERROR_CLUSTER_INVALID_INFRASTRUCTURE_FILESERVER_NAME", "Cluster infrastructure file server creation failed because a valid non-empty file server name was not provided."),
+	(0x0000_176f, "ERROR_CLUSTERSET_MANAGEMENT_CLUSTER_UNREACHABLE", "The action cannot be completed because the cluster set management cluster is unreachable."),
+	(0x0000_1770, "ERROR_ENCRYPTION_FAILED", "The specified file could not be encrypted."),
+	(0x0000_1771, "ERROR_DECRYPTION_FAILED", "The specified file could not be decrypted."),
+	(0x0000_1772, "ERROR_FILE_ENCRYPTED", "The specified file is encrypted and the user does not have the ability to decrypt it."),
+	(0x0000_1773, "ERROR_NO_RECOVERY_POLICY", "There is no valid encryption recovery policy configured for this system."),
+	(0x0000_1774, "ERROR_NO_EFS", "The required encryption driver is not loaded for this system."),
+	(0x0000_1775, "ERROR_WRONG_EFS", "The file was encrypted with a different encryption driver than is currently loaded."),
+	(0x0000_1776, "ERROR_NO_USER_KEYS", "There are no EFS keys defined for the user."),
+	(0x0000_1777, "ERROR_FILE_NOT_ENCRYPTED", "The specified file is not encrypted."),
+	(0x0000_1778, "ERROR_NOT_EXPORT_FORMAT", "The specified file is not in the defined EFS export format."),
+	(0x0000_1779, "ERROR_FILE_READ_ONLY", "The specified file is read only."),
+	(0x0000_177a, "ERROR_DIR_EFS_DISALLOWED", "The directory has been disabled for encryption."),
+	(0x0000_177b, "ERROR_EFS_SERVER_NOT_TRUSTED", "The server is not trusted for remote encryption operation."),
+	(0x0000_177c, "ERROR_BAD_RECOVERY_POLICY", "Recovery policy configured for this system contains invalid recovery certificate."),
+	(0x0000_177d, "ERROR_EFS_ALG_BLOB_TOO_BIG", "The encryption algorithm used on the source file needs a bigger key buffer than the one on the destination file."),
+	(0x0000_177e, "ERROR_VOLUME_NOT_SUPPORT_EFS", "The disk partition does not support file encryption."),
+	(0x0000_177f, "ERROR_EFS_DISABLED", "This machine is disabled for file encryption."),
+	(0x0000_1780, "ERROR_EFS_VERSION_NOT_SUPPORT", "A newer system is required to decrypt this encrypted file."),
+	(0x0000_1781, "ERROR_CS_ENCRYPTION_INVALID_SERVER_RESPONSE", "The remote server sent an invalid response for a file being opened with Client Side Encryption."),
+	(0x0000_1782, "ERROR_CS_ENCRYPTION_UNSUPPORTED_SERVER", "Client Side Encryption is not supported by the remote server even though it claims to support it."),
+	(0x0000_1783, "ERROR_CS_ENCRYPTION_EXISTING_ENCRYPTED_FILE", "File is encrypted and should be opened in Client Side Encryption mode."),
+	(0x0000_1784, "ERROR_CS_ENCRYPTION_NEW_ENCRYPTED_FILE", "A new encrypted file is being created and a $EFS needs to be provided."),
+	(0x0000_1785, "ERROR_CS_ENCRYPTION_FILE_NOT_CSE", "The SMB client requested a CSE FSCTL on a non-CSE file."),
+	(0x0000_1786, "ERROR_ENCRYPTION_POLICY_DENIES_OPERATION", "The requested operation was blocked by policy. For more information, contact your system administrator."),
+	(0x0000_1787, "ERROR_WIP_ENCRYPTION_FAILED", "The specified file could not be encrypted with Windows Information Protection."),
+	(0x0000_17e6, "ERROR_NO_BROWSER_SERVERS_FOUND", "The list of servers for this workgroup is not currently available."),
+	(0x0000_19c8, "ERROR_LOG_SECTOR_INVALID", "Log service encountered an invalid log sector."),
+	(0x0000_19c9, "ERROR_LOG_SECTOR_PARITY_INVALID", "Log service encountered a log sector with invalid block parity."),
+	(0x0000_19ca, "ERROR_LOG_SECTOR_REMAPPED", "Log service encountered a remapped log sector."),
+	(0x0000_19cb, "ERROR_LOG_BLOCK_INCOMPLETE", "Log service encountered a partial or incomplete log block."),
+	(0x0000_19cc, "ERROR_LOG_INVALID_RANGE", "Log service encountered an attempt to access data outside the active log range."),
+	(0x0000_19cd, "ERROR_LOG_BLOCKS_EXHAUSTED", "Log service user marshalling buffers are exhausted."),
+	(0x0000_19ce, "ERROR_LOG_READ_CONTEXT_INVALID", "Log service encountered an attempt to read from a marshalling area with an invalid read context."),
+	(0x0000_19cf, "ERROR_LOG_RESTART_INVALID", "Log service encountered an invalid log restart area."),
+	(0x0000_19d0, "ERROR_LOG_BLOCK_VERSION", "Log service encountered an invalid log block version."),
+	(0x0000_19d1, "ERROR_LOG_BLOCK_INVALID", "Log service encountered an invalid log block."),
+	(0x0000_19d2, "ERROR_LOG_READ_MODE_INVALID", "Log service encountered an attempt to read the log with an invalid read mode."),
+	(0x0000_19d3, "ERROR_LOG_NO_RESTART", "Log service encountered a log stream with no restart area."),
+	(0x0000_19d4, "ERROR_LOG_METADATA_CORRUPT", "Log service encountered a corrupted metadata file."),
+	(0x0000_19d5, "ERROR_LOG_METADATA_INVALID", "Log service encountered a metadata file that could not be created by the log file system."),
+	(0x0000_19d6, "ERROR_LOG_METADATA_INCONSISTENT", "Log service encountered a metadata file with inconsistent data."),
+	(0x0000_19d7, "ERROR_LOG_RESERVATION_INVALID", "Log service encountered an attempt to erroneous allocate or dispose reservation space."),
+	(0x0000_19d8, "ERROR_LOG_CANT_DELETE", "Log service cannot delete log file or file system container."),
+	(0x0000_19d9, "ERROR_LOG_CONTAINER_LIMIT_EXCEEDED", "Log service has reached the maximum allowable containers allocated to a log file."),
+	(0x0000_19da, "ERROR_LOG_START_OF_LOG", "Log service has attempted to read or write backward past the start of the log."),
+	(0x0000_19db, "ERROR_LOG_POLICY_ALREADY_INSTALLED", "Log policy could not be installed because a policy of the same type is already present."),
+	(0x0000_19dc, "ERROR_LOG_POLICY_NOT_INSTALLED", "Log policy in question was not installed at the time of the request."),
+	(0x0000_19dd, "ERROR_LOG_POLICY_INVALID", "The installed set of policies on the log is invalid."),
+	(0x0000_19de, "ERROR_LOG_POLICY_CONFLICT", "A policy on the log in question prevented the operation from completing."),
+	(0x0000_19df, "ERROR_LOG_PINNED_ARCHIVE_TAIL", "Log space cannot be reclaimed because the log is pinned by the archive tail."),
+	(0x0000_19e0, "ERROR_LOG_RECORD_NONEXISTENT", "Log record is not a record in the log file."),
+	(0x0000_19e1, "ERROR_LOG_RECORDS_RESERVED_INVALID", "Number of reserved log records or the adjustment of the number of reserved log records is invalid."),
+	(0x0000_19e2, "ERROR_LOG_SPACE_RESERVED_INVALID", "Reserved log space or the adjustment of the log space is invalid."),
+	(0x0000_19e3, "ERROR_LOG_TAIL_INVALID", "An new or existing archive tail or base of the active log is invalid."),
+	(0x0000_19e4, "ERROR_LOG_FULL", "Log space is exhausted."),
+	(0x0000_19e5, "ERROR_COULD_NOT_RESIZE_LOG", "The log could not be set to the requested size."),
+	(0x0000_19e6, "ERROR_LOG_MULTIPLEXED", "Log is multiplexed, no direct writes to the physical log is allowed."),
+	(0x0000_19e7, "ERROR_LOG_DEDICATED", "The operation failed because the log is a dedicated log."),
+	(0x0000_19e8, "ERROR_LOG_ARCHIVE_NOT_IN_PROGRESS", "The operation requires an archive context."),
+	(0x0000_19e9, "ERROR_LOG_ARCHIVE_IN_PROGRESS", "Log archival is in progress."),
+	(0x0000_19ea, "ERROR_LOG_EPHEMERAL", "The operation requires a non-ephemeral log, but the log is ephemeral."),
+	(0x0000_19eb, "ERROR_LOG_NOT_ENOUGH_CONTAINERS", "The log must have at least two containers before it can be read from or written to."),
+	(0x0000_19ec, "ERROR_LOG_CLIENT_ALREADY_REGISTERED", "A log client has already registered on the stream."),
+	(0x0000_19ed, "ERROR_LOG_CLIENT_NOT_REGISTERED", "A log client has not been registered on the stream."),
+	(0x0000_19ee, "ERROR_LOG_FULL_HANDLER_IN_PROGRESS", "A request has already been made to handle the log full condition."),
+	(0x0000_19ef, "ERROR_LOG_CONTAINER_READ_FAILED", "Log service encountered an error when attempting to read from a log container."),
+	(0x0000_19f0, "ERROR_LOG_CONTAINER_WRITE_FAILED", "Log service encountered an error when attempting to write to a log container."),
+	(0x0000_19f1, "ERROR_LOG_CONTAINER_OPEN_FAILED", "Log service encountered an error when attempting open a log container."),
+	(0x0000_19f2, "ERROR_LOG_CONTAINER_STATE_INVALID", "Log service encountered an invalid container state when attempting a requested action."),
+	(0x0000_19f3, "ERROR_LOG_STATE_INVALID", "Log service is not in the correct state to perform a requested action."),
+	(0x0000_19f4, "ERROR_LOG_PINNED", "Log space cannot be reclaimed because the log is pinned."),
+	(0x0000_19f5, "ERROR_LOG_METADATA_FLUSH_FAILED", "Log metadata flush failed."),
+	(0x0000_19f6, "ERROR_LOG_INCONSISTENT_SECURITY", "Security on the log and its containers is inconsistent."),
+	(0x0000_19f7, "ERROR_LOG_APPENDED_FLUSH_FAILED", "Records were appended to the log or reservation changes were made, but the log could not be flushed."),
+	(0x0000_19f8, "ERROR_LOG_PINNED_RESERVATION", "The log is pinned due to reservation consuming most of the log space. Free some reserved records to make space available."),
+	(0x0000_1a2c, "ERROR_INVALID_TRANSACTION", "The transaction handle associated with this operation is not valid."),
+	(0x0000_1a2d, "ERROR_TRANSACTION_NOT_ACTIVE", "The requested operation was made in the context of a transaction that is no longer active."),
+	(0x0000_1a2e, "ERROR_TRANSACTION_REQUEST_NOT_VALID", "The requested operation is not valid on the Transaction object in its current state."),
+	(0x0000_1a2f, "ERROR_TRANSACTION_NOT_REQUESTED", "The caller has called a response API, but the response is not expected because the TM did not issue the corresponding request to the caller."),
+	(0x0000_1a30, "ERROR_TRANSACTION_ALREADY_ABORTED", "It is too late to perform the requested operation, since the Transaction has already been aborted."),
+	(0x0000_1a31, "ERROR_TRANSACTION_ALREADY_COMMITTED", "It is too late to perform the requested operation, since the Transaction has already been committed."),
+	(0x0000_1a32, "ERROR_TM_INITIALIZATION_FAILED", "The Transaction Manager was unable to be successfully initialized. Transacted operations are not supported."),
+	(0x0000_1a33, "ERROR_RESOURCEMANAGER_READ_ONLY", "The specified ResourceManager made no changes or updates to the resource under this transaction."),
+	(0x0000_1a34, "ERROR_TRANSACTION_NOT_JOINED", "The resource manager has attempted to prepare a transaction that it has not successfully joined."),
+	(0x0000_1a35, "ERROR_TRANSACTION_SUPERIOR_EXISTS", "The Transaction object already has a superior enlistment, and the caller attempted an operation that would have created a new superior. Only a single superior enlistment is allow."),
+	(0x0000_1a36, "ERROR_CRM_PROTOCOL_ALREADY_EXISTS", "The RM tried to register a protocol that already exists."),
+	(0x0000_1a37, "ERROR_TRANSACTION_PROPAGATION_FAILED", "The attempt to propagate the Transaction failed."),
+	(0x0000_1a38, "ERROR_CRM_PROTOCOL_NOT_FOUND", "The requested propagation protocol was not registered as a CRM."),
+	(0x0000_1a39, "ERROR_TRANSACTION_INVALID_MARSHALL_BUFFER", "The buffer passed in to PushTransaction or PullTransaction is not in a valid format."),
+	(0x0000_1a3a, "ERROR_CURRENT_TRANSACTION_NOT_VALID", "The current transaction context associated with the thread is not a valid handle to a transaction object."),
+	(0x0000_1a3b, "ERROR_TRANSACTION_NOT_FOUND", "The specified Transaction object could not be opened, because it was not found."),
+	(0x0000_1a3c, "ERROR_RESOURCEMANAGER_NOT_FOUND", "The specified ResourceManager object could not be opened, because it was not found."),
+	(0x0000_1a3d, "ERROR_ENLISTMENT_NOT_FOUND", "The specified Enlistment object could not be opened, because it was not found."),
+	(0x0000_1a3e, "ERROR_TRANSACTIONMANAGER_NOT_FOUND", "The specified TransactionManager object could not be opened, because it was not found."),
+	(0x0000_1a3f, "ERROR_TRANSACTIONMANAGER_NOT_ONLINE", "The object specified could not be created or opened, because its associated TransactionManager is not online. The TransactionManager must be brought fully Online by calling RecoverTransactionManager to recover to the end of its LogFile before objects in its Transaction or ResourceManager namespaces can be opened. In addition, errors in writing records to its LogFile can cause a TransactionManager to go offline."),
+	(0x0000_1a40, "ERROR_TRANSACTIONMANAGER_RECOVERY_NAME_COLLISION", "The specified TransactionManager was unable to create the objects contained in its logfile in the Ob namespace. Therefore, the TransactionManager was unable to recover."),
+	(0x0000_1a41, "ERROR_TRANSACTION_NOT_ROOT", "The call to create a superior Enlistment on this Transaction object could not be completed, because the Transaction object specified for the enlistment is a subordinate branch of the Transaction. Only the root of the Transaction can be enlisted on as a superior."),
+	(0x0000_1a42, "ERROR_TRANSACTION_OBJECT_EXPIRED", "Because the associated transaction manager or resource manager has been closed, the handle is no longer valid."),
+	(0x0000_1a43, "ERROR_TRANSACTION_RESPONSE_NOT_ENLISTED", "The specified operation could not be performed on this Superior enlistment, because the enlistment was not created with the corresponding completion response in the NotificationMask."),
+	(0x0000_1a44, "ERROR_TRANSACTION_RECORD_TOO_LONG", "The specified operation could not be performed, because the record that would be logged was too long. This can occur because of two conditions: either there are too many Enlistments on this Transaction, or the combined RecoveryInformation being logged on behalf of those Enlistments is too long."),
+	(0x0000_1a45, "ERROR_IMPLICIT_TRANSACTION_NOT_SUPPORTED", "Implicit transaction are not supported."),
+	(0x0000_1a46, "ERROR_TRANSACTION_INTEGRITY_VIOLATED", "The kernel transaction manager had to abort or forget the transaction because it blocked forward progress."),
+	(0x0000_1a47, "ERROR_TRANSACTIONMANAGER_IDENTITY_MISMATCH", "The TransactionManager identity that was supplied did not match the one recorded in the TransactionManager's log file."),
+	(0x0000_1a48, "ERROR_RM_CANNOT_BE_FROZEN_FOR_SNAPSHOT", "This snapshot operation cannot continue because a transactional resource manager cannot be frozen in its current state. Please try again."),
+	(0x0000_1a49, "ERROR_TRANSACTION_MUST_WRITETHROUGH", "The transaction cannot be enlisted on with the specified EnlistmentMask, because the transaction has already completed the PrePrepare phase. In order to ensure correctness, the ResourceManager must switch to a write-through mode and cease caching data within this transaction. Enlisting for only subsequent transaction phases may still succeed."),
+	(0x0000_1a4a, "ERROR_TRANSACTION_NO_SUPERIOR", "The transaction does not have a superior enlistment."),
+	(0x0000_1a4b, "ERROR_HEURISTIC_DAMAGE_POSSIBLE", "The attempt to commit the Transaction completed, but it is possible that some portion of the transaction tree did not commit successfully due to heuristics. Therefore it is possible that some data modified in the transaction may not have committed, resulting in transactional inconsistency. If possible, check the consistency of the associated data."),
+	(0x0000_1a90, "ERROR_TRANSACTIONAL_CONFLICT", "The function attempted to use a name that is reserved for use by another transaction."),
+	(0x0000_1a91, "ERROR_RM_NOT_ACTIVE", "Transaction support within the specified resource manager is not started or was shut down due to an error."),
+	(0x0000_1a92, "ERROR_RM_METADATA_CORRUPT", "The metadata of the RM has been corrupted. The RM will not function."),
+	(0x0000_1a93, "ERROR_DIRECTORY_NOT_RM", "The specified directory does not contain a resource manager."),
+	(0x0000_1a95, "ERROR_TRANSACTIONS_UNSUPPORTED_REMOTE", "The remote server or share does not support transacted file operations."),
+	(0x0000_1a96, "ERROR_LOG_RESIZE_INVALID_SIZE", "The requested log size is invalid."),
+	(0x0000_1a97, "ERROR_OBJECT_NO_LONGER_EXISTS", "The object (file, stream, link) corresponding to the handle has been deleted by a Transaction Savepoint Rollback."),
+	(0x0000_1a98, "ERROR_STREAM_MINIVERSION_NOT_FOUND", "The specified file miniversion was not found for this transacted file open."),
+	(0x0000_1a99, "ERROR_STREAM_MINIVERSION_NOT_VALID", "The specified file miniversion was found but has been invalidated. Most likely cause is a transaction savepoint rollback."),
+	(0x0000_1a9a, "ERROR_MINIVERSION_INACCESSIBLE_FROM_SPECIFIED_TRANSACTION", "A miniversion may only be opened in the context of the transaction that created it."),
+	(0x0000_1a9b, "ERROR_CANT_OPEN_MINIVERSION_WITH_MODIFY_INTENT", "It is not possible to open a miniversion with modify access."),
+	(0x0000_1a9c, "ERROR_CANT_CREATE_MORE_STREAM_MINIVERSIONS", "It is not possible to create any more miniversions for this stream."),
+	(0x0000_1a9e, "ERROR_REMOTE_FILE_VERSION_MISMATCH", "The remote server sent mismatching version number or Fid for a file opened with transactions."),
+	(0x0000_1a9f, "ERROR_HANDLE_NO_LONGER_VALID", "The handle has been invalidated by a transaction. The most likely cause is the presence of memory mapping on a file or an open handle when the transaction ended or rolled back to savepoint."),
+	(0x0000_1aa0, "ERROR_NO_TXF_METADATA", "There is no transaction metadata on the file."),
+	(0x0000_1aa1, "ERROR_LOG_CORRUPTION_DETECTED", "The log data is corrupt."),
+	(0x0000_1aa2, "ERROR_CANT_RECOVER_WITH_HANDLE_OPEN", "The file can't be recovered because there is a handle still open on it."),
+	(0x0000_1aa3, "ERROR_RM_DISCONNECTED", "The transaction outcome is unavailable because the resource manager responsible for it has disconnected."),
+	(0x0000_1aa4, "ERROR_ENLISTMENT_NOT_SUPERIOR", "The request was rejected because the enlistment in question is not a superior enlistment."),
+	(0x0000_1aa5, "ERROR_RECOVERY_NOT_NEEDED", "The transactional resource manager is already consistent. Recovery is not needed."),
+	(0x0000_1aa6, "ERROR_RM_ALREADY_STARTED", "The transactional resource manager has already been started."),
+	(0x0000_1aa7, "ERROR_FILE_IDENTITY_NOT_PERSISTENT", "The file cannot be opened transactionally, because its identity depends on the outcome of an unresolved transaction."),
+	(0x0000_1aa8, "ERROR_CANT_BREAK_TRANSACTIONAL_DEPENDENCY", "The operation cannot be performed because another transaction is depending on the fact that this property will not change."),
+	(0x0000_1aa9, "ERROR_CANT_CROSS_RM_BOUNDARY", "The operation would involve a single file with two transactional resource managers and is therefore not allowed."),
+	(0x0000_1aaa, "ERROR_TXF_DIR_NOT_EMPTY", "The $Txf directory must be empty for this operation to succeed."),
+	(0x0000_1aab, "ERROR_INDOUBT_TRANSACTIONS_EXIST", "The operation would leave a transactional resource manager in an inconsistent state and is therefore not allowed."),
+	(0x0000_1aac, "ERROR_TM_VOLATILE", "The operation could not be completed because the transaction manager does not have a log."),
+	(0x0000_1aad, "ERROR_ROLLBACK_TIMER_EXPIRED", "A rollback could not be scheduled because a previously scheduled rollback has already executed or been queued for execution."),
+	(0x0000_1aae, "ERROR_TXF_ATTRIBUTE_CORRUPT", "The transactional metadata attribute on the file or directory is corrupt and unreadable."),
+	(0x0000_1aaf, "ERROR_EFS_NOT_ALLOWED_IN_TRANSACTION", "The encryption operation could not be completed because a transaction is active."),
+	(0x0000_1ab0, "ERROR_TRANSACTIONAL_OPEN_NOT_ALLOWED", "This object is not allowed to be opened in a transaction."),
+	(0x0000_1ab1, "ERROR_LOG_GROWTH_FAILED", "An attempt to create space in the transactional resource manager's log failed. The failure status has been recorded in the event log."),
+	(0x0000_1ab2, "ERROR_TRANSACTED_MAPPING_UNSUPPORTED_REMOTE", "Memory mapping (creating a mapped section) a remote file under a transaction is not supported."),
+	(0x0000_1ab3, "ERROR_TXF_METADATA_ALREADY_PRESENT", "Transaction metadata is already present on this file and cannot be superseded."),
+	(0x0000_1ab4, "ERROR_TRANSACTION_SCOPE_CALLBACKS_NOT_SET", "A transaction scope could not be entered because the scope handler has not been initialized."),
+	(0x0000_1ab5, "ERROR_TRANSACTION_REQUIRED_PROMOTION", "Promotion was required in order to allow the resource manager to enlist, but the transaction was set to disallow it."),
+	(0x0000_1ab6, "ERROR_CANNOT_EXECUTE_FILE_IN_TRANSACTION", "This file is open for modification in an unresolved transaction and may be opened for execute only by a transacted reader."),
+	(0x0000_1ab7, "ERROR_TRANSACTIONS_NOT_FROZEN", "The request to thaw frozen transactions was ignored because transactions had not previously been frozen."),
+	(0x0000_1ab8, "ERROR_TRANSACTION_FREEZE_IN_PROGRESS", "Transactions cannot be frozen because a freeze is already in progress."),
+	(0x0000_1ab9, "ERROR_NOT_SNAPSHOT_VOLUME", "The target volume is not a snapshot volume. This operation is only valid on a volume mounted as a snapshot."),
+	(0x0000_1aba, "ERROR_NO_SAVEPOINT_WITH_OPEN_FILES", "The savepoint operation failed because files are open on the transaction. This is not permitted."),
+	(0x0000_1abb, "ERROR_DATA_LOST_REPAIR", "Windows has discovered corruption in a file, and that file has since been repaired. Data loss may have occurred."),
+	(0x0000_1abc, "ERROR_SPARSE_NOT_ALLOWED_IN_TRANSACTION", "The sparse operation could not be completed because a transaction is active on the file."),
+	(0x0000_1abd, "ERROR_TM_IDENTITY_MISMATCH", "The call to create a TransactionManager object failed because the Tm Identity stored in the logfile does not match the Tm Identity that was passed in as an argument."),
+	(0x0000_1abe, "ERROR_FLOATED_SECTION", "I/O was attempted on a section object that has been floated as a result of a transaction ending. There is no valid data."),
+	(0x0000_1abf, "ERROR_CANNOT_ACCEPT_TRANSACTED_WORK", "The transactional resource manager cannot currently accept transacted work due to a transient condition such as low resources."),
+	(0x0000_1ac0, "ERROR_CANNOT_ABORT_TRANSACTIONS", "The transactional resource manager had too many transactions outstanding that could not be aborted. The transactional resource manager has been shut down."),
+	(0x0000_1ac1, "ERROR_BAD_CLUSTERS", "The operation could not be completed due to bad clusters on disk."),
+	(0x0000_1ac2, "ERROR_COMPRESSION_NOT_ALLOWED_IN_TRANSACTION", "The compression operation could not be completed because a transaction is active on the file."),
+	(0x0000_1ac3, "ERROR_VOLUME_DIRTY", "The operation could not be completed because the volume is dirty. Please run chkdsk and try again."),
+	(0x0000_1ac4, "ERROR_NO_LINK_TRACKING_IN_TRANSACTION", "The link tracking operation could not be completed because a transaction is active."),
+	(0x0000_1ac5, "ERROR_OPERATION_NOT_SUPPORTED_IN_TRANSACTION", "This operation cannot be performed in a transaction."),
+	(0x0000_1ac6, "ERROR_EXPIRED_HANDLE", "The handle is no longer properly associated with its transaction. It may have been opened in a transactional resource manager that was subsequently forced to restart. Please close the handle and open a new one."),
+	(0x0000_1ac7, "ERROR_TRANSACTION_NOT_ENLISTED", "The specified operation could not be performed because the resource manager is not enlisted in the transaction."),
+	(0x0000_1b59, "ERROR_CTX_WINSTATION_NAME_INVALID", "The specified session name is invalid."),
+	(0x0000_1b5a, "ERROR_CTX_INVALID_PD", "The specified protocol driver is invalid."),
+	(0x0000_1b5b, "ERROR_CTX_PD_NOT_FOUND", "The specified protocol driver was not found in the system path."),
+	(0x0000_1b5c, "ERROR_CTX_WD_NOT_FOUND", "The specified terminal connection driver was not found in the system path."),
+	(0x0000_1b5d, "ERROR_CTX_CANNOT_MAKE_EVENTLOG_ENTRY", "A registry key for event logging could not be created for this session."),
+	(0x0000_1b5e, "ERROR_CTX_SERVICE_NAME_COLLISION", "A service with the same name already exists on the system."),
+	(0x0000_1b5f, "ERROR_CTX_CLOSE_PENDING", "A close operation is pending on the session."),
+	(0x0000_1b60, "ERROR_CTX_NO_OUTBUF", "There are no free output buffers available."),
+	(0x0000_1b61, "ERROR_CTX_MODEM_INF_NOT_FOUND", "The MODEM.INF file was not found."),
+	(0x0000_1b62, "ERROR_CTX_INVALID_MODEMNAME", "The modem name was not found in the MODEM.INF file."),
+	(0x0000_1b63, "ERROR_CTX_MODEM_RESPONSE_ERROR", "The modem did not accept the command sent to it. Verify that the configured modem name matches the attached modem."),
+	(0x0000_1b64, "ERROR_CTX_MODEM_RESPONSE_TIMEOUT", "The modem did not respond to the command sent to it. Verify that the modem is properly cabled and powered on."),
+	(0x0000_1b65, "ERROR_CTX_MODEM_RESPONSE_NO_CARRIER", "Carrier detect has failed or carrier has been dropped due to disconnect."),
+	(0x0000_1b66, "ERROR_CTX_MODEM_RESPONSE_NO_DIALTONE", "Dial tone not detected within the required time. Verify that the phone cable is properly attached and functional."),
+	(0x0000_1b67, "ERROR_CTX_MODEM_RESPONSE_BUSY", "Busy signal detected at remote site on callback."),
+	(0x0000_1b68, "ERROR_CTX_MODEM_RESPONSE_VOICE", "Voice detected at remote site on callback."),
+	(0x0000_1b69, "ERROR_CTX_TD_ERROR", "Transport driver error."),
+	(0x0000_1b6e, "ERROR_CTX_WINSTATION_NOT_FOUND", "The specified session cannot be found."),
+	(0x0000_1b6f, "ERROR_CTX_WINSTATION_ALREADY_EXISTS", "The specified session name is already in use."),
+	(0x0000_1b70, "ERROR_CTX_WINSTATION_BUSY", "The task you are trying to do can't be completed because Remote Desktop Services is currently busy. Please try again in a few minutes. Other users should still be able to log on."),
+	(0x0000_1b71, "ERROR_CTX_BAD_VIDEO_MODE", "An attempt has been made to connect to a session whose video mode is not supported by the current client."),
+	(0x0000_1b7b, "ERROR_CTX_GRAPHICS_INVALID", "The application attempted to enable DOS graphics mode. DOS graphics mode is not supported."),
+	(0x0000_1b7d, "ERROR_CTX_LOGON_DISABLED", "Your interactive logon privilege has been disabled. Please contact your administrator."),
+	(0x0000_1b7e, "ERROR_CTX_NOT_CONSOLE", "The requested operation can be performed only on the system console. This is most often the result of a driver or system DLL requiring direct console access."),
+	(0x0000_1b80, "ERROR_CTX_CLIENT_QUERY_TIMEOUT", "The client failed to respond to the server connect message."),
+	(0x0000_1b81, "ERROR_CTX_CONSOLE_DISCONNECT", "Disconnecting the console session is not supported."),
+	(0x0000_1b82, "ERROR_CTX_CONSOLE_CONNECT", "Reconnecting a disconnected session to the console is not supported."),
+	(0x0000_1b84, "ERROR_CTX_SHADOW_DENIED", "The request to control another session remotely was denied."),
+	(0x0000_1b85, "ERROR_CTX_WINSTATION_ACCESS_DENIED", "The requested session access is denied."),
+	(0x0000_1b89, "ERROR_CTX_INVALID_WD", "The specified terminal connection driver is invalid."),
+	(0x0000_1b8a, "ERROR_CTX_SHADOW_INVALID", "The requested session cannot be controlled remotely. This may be because the session is disconnected or does not currently have a user logged on."),
+	(0x0000_1b8b, "ERROR_CTX_SHADOW_DISABLED", "The requested session is not configured to allow remote control."),
+	(0x0000_1b8c, "ERROR_CTX_CLIENT_LICENSE_IN_USE", "Your request to connect to this Terminal Server has been rejected. Your Terminal Server client license number is currently being used by another user. Please call your system administrator to obtain a unique license number."),
+	(0x0000_1b8d, "ERROR_CTX_CLIENT_LICENSE_NOT_SET", "Your request to connect to this Terminal Server has been rejected. Your Terminal Server client license number has not been entered for this copy of the Terminal Server client. Please contact your system administrator."),
+	(0x0000_1b8e, "ERROR_CTX_LICENSE_NOT_AVAILABLE", "The number of connections to this computer is limited and all connections are in use right now. Try connecting later or contact your system administrator."),
+	(0x0000_1b8f, "ERROR_CTX_LICENSE_CLIENT_INVALID", "The client you are using is not licensed to use this system. Your logon request is denied."),
+	(0x0000_1b90, "ERROR_CTX_LICENSE_EXPIRED", "The system license has expired. Your logon request is denied."),
+	(0x0000_1b91, "ERROR_CTX_SHADOW_NOT_RUNNING", "Remote control could not be terminated because the specified session is not currently being remotely controlled."),
+	(0x0000_1b92, "ERROR_CTX_SHADOW_ENDED_BY_MODE_CHANGE", "The remote control of the console was terminated because the display mode was changed. Changing the display mode in a remote control session is not supported."),
+	(0x0000_1b93, "ERROR_ACTIVATION_COUNT_EXCEEDED", "Activation has already been reset the maximum number of times for this installation. Your activation timer will not be cleared."),
+	(0x0000_1b94, "ERROR_CTX_WINSTATIONS_DISABLED", "Remote logins are currently disabled."),
+	(0x0000_1b95, "ERROR_CTX_ENCRYPTION_LEVEL_REQUIRED", "You do not have the proper encryption level to access this Session."),
+	(0x0000_1b96, "ERROR_CTX_SESSION_IN_USE", "The user %s\\%s is currently logged on to this computer. Only the current user or an administrator can log on to this computer."),
+	(0x0000_1b97, "ERROR_CTX_NO_FORCE_LOGOFF", "The user %s\\%s is already logged on to the console of this computer. You do not have permission to log in at this time. To resolve this issue, contact %s\\%s and have them log off."),
+	(0x0000_1b98, "ERROR_CTX_ACCOUNT_RESTRICTION", "Unable to log you on because of an account restriction."),
+	(0x0000_1b99, "ERROR_RDP_PROTOCOL_ERROR", "The RDP protocol component %2 detected an error in the protocol stream and has disconnected the client."),
+	(0x0000_1b9a, "ERROR_CTX_CDM_CONNECT", "The Client Drive Mapping Service Has Connected on Terminal Connection."),
+	(0x0000_1b9b, "ERROR_CTX_CDM_DISCONNECT", "The Client Drive Mapping Service Has Disconnected on Terminal Connection."),
+	(0x0000_1b9c, "ERROR_CTX_SECURITY_LAYER_ERROR", "The Terminal Server security layer detected an error in the protocol stream and has disconnected the client."),
+	(0x0000_1b9d, "ERROR_TS_INCOMPATIBLE_SESSIONS", "The target session is incompatible with the current session."),
+	(0x0000_1b9e, "ERROR_TS_VIDEO_SUBSYSTEM_ERROR", "Windows can't connect to your session because a problem occurred in the Windows video subsystem. Try connecting again later, or contact the server administrator for assistance."),
+	(0x0000_2008, "ERROR_DS_NOT_INSTALLED", "An error occurred while installing the directory service. For more information, see the event log."),
+	(0x0000_2009, "ERROR_DS_MEMBERSHIP_EVALUATED_LOCALLY", "The directory service evaluated group memberships locally."),
+	(0x0000_200a, "ERROR_DS_NO_ATTRIBUTE_OR_VALUE", "The specified directory service attribute or value does not exist."),
+	(0x0000_200b, "ERROR_DS_INVALID_ATTRIBUTE_SYNTAX", "The attribute syntax specified to the directory service is invalid."),
+	(0x0000_200c, "ERROR_DS_ATTRIBUTE_TYPE_UNDEFINED", "The attribute type specified to the directory service is not defined."),
+	(0x0000_200d, "ERROR_DS_ATTRIBUTE_OR_VALUE_EXISTS", "The specified directory service attribute or value already exists."),
+	(0x0000_200e, "ERROR_DS_BUSY", "The directory service is busy."),
+	(0x0000_200f, "ERROR_DS_UNAVAILABLE", "The directory service is unavailable."),
+	(0x0000_2010, "ERROR_DS_NO_RIDS_ALLOCATED", "The directory service was unable to allocate a relative identifier."),
+	(0x0000_2011, "ERROR_DS_NO_MORE_RIDS", "The directory service has exhausted the pool of relative identifiers."),
+	(0x0000_2012, "ERROR_DS_INCORRECT_ROLE_OWNER", "The requested operation could not be performed because the directory service is not the master for that type of operation."),
+	(0x0000_2013, "ERROR_DS_RIDMGR_INIT_ERROR", "The directory service was unable to initialize the subsystem that allocates relative identifiers."),
+	(0x0000_2014, "ERROR_DS_OBJ_CLASS_VIOLATION", "The requested operation did not satisfy one or more constraints associated with the class of the object."),
+	(0x0000_2015, "ERROR_DS_CANT_ON_NON_LEAF", "The directory service can perform the requested operation only on a leaf object."),
+	(0x0000_2016, "ERROR_DS_CANT_ON_RDN", "The directory service cannot perform the requested operation on the RDN attribute of an object."),
+	(0x0000_2017, "ERROR_DS_CANT_MOD_OBJ_CLASS", "The directory service detected an attempt to modify the object class of an object."),
+	(0x0000_2018, "ERROR_DS_CROSS_DOM_MOVE_ERROR", "The requested cross-domain move operation could not be performed."),
+	(0x0000_2019, "ERROR_DS_GC_NOT_AVAILABLE", "Unable to contact the global catalog server."),
+	(0x0000_201a, "ERROR_SHARED_POLICY", "The policy object is shared and can only be modified at the root."),
+	(0x0000_201b, "ERROR_POLICY_OBJECT_NOT_FOUND", "The policy object does not exist."),
+	(0x0000_201c, "ERROR_POLICY_ONLY_IN_DS", "The requested policy information is only in the directory service."),
+	(0x0000_201d, "ERROR_PROMOTION_ACTIVE", "A domain controller promotion is currently active."),
+	(0x0000_201e, "ERROR_NO_PROMOTION_ACTIVE", "A domain controller promotion is not currently active."),
+	(0x0000_2020, "ERROR_DS_OPERATIONS_ERROR", "An operations error occurred."),
+	(0x0000_2021, "ERROR_DS_PROTOCOL_ERROR", "A protocol error occurred."),
+	(0x0000_2022, "ERROR_DS_TIMELIMIT_EXCEEDED", "The time limit for this request was exceeded."),
+	(0x0000_2023, "ERROR_DS_SIZELIMIT_EXCEEDED", "The size limit for this request was exceeded."),
+	(0x0000_2024, "ERROR_DS_ADMIN_LIMIT_EXCEEDED", "The administrative limit for this request was exceeded."),
+	(0x0000_2025, "ERROR_DS_COMPARE_FALSE", "The compare response was false."),
+	(0x0000_2026, "ERROR_DS_COMPARE_TRUE", "The compare response was true."),
+	(0x0000_2027, "ERROR_DS_AUTH_METHOD_NOT_SUPPORTED", "The requested authentication method is not supported by the server."),
+	(0x0000_2028, "ERROR_DS_STRONG_AUTH_REQUIRED", "A more secure authentication method is required for this server."),
+	(0x0000_2029, "ERROR_DS_INAPPROPRIATE_AUTH", "Inappropriate authentication."),
+	(0x0000_202a, "ERROR_DS_AUTH_UNKNOWN", "The authentication mechanism is unknown."),
+	(0x0000_202b, "ERROR_DS_REFERRAL", "A referral was returned from the server."),
+	(0x0000_202c, "ERROR_DS_UNAVAILABLE_CRIT_EXTENSION", "The server does not support the requested critical extension."),
+	(0x0000_202d, "ERROR_DS_CONFIDENTIALITY_REQUIRED", "This request requires a secure connection."),
+	(0x0000_202e, "ERROR_DS_INAPPROPRIATE_MATCHING", "Inappropriate matching."),
+	(0x0000_202f, "ERROR_DS_CONSTRAINT_VIOLATION", "A constraint violation occurred."),
+	(0x0000_2030, "ERROR_DS_NO_SUCH_OBJECT", "There is no such object on the server."),
+	(0x0000_2031, "ERROR_DS_ALIAS_PROBLEM", "There is an alias problem."),
+	(0x0000_2032, "ERROR_DS_INVALID_DN_SYNTAX", "An invalid dn syntax has been specified."),
+	(0x0000_2033, "ERROR_DS_IS_LEAF", "The object is a leaf object."),
+	(0x0000_2034, "ERROR_DS_ALIAS_DEREF_PROBLEM", "There is an alias dereferencing problem."),
+	(0x0000_2035, "ERROR_DS_UNWILLING_TO_PERFORM", "The server is unwilling to process the request."),
+	(0x0000_2036, "ERROR_DS_LOOP_DETECT", "A loop has been detected."),
+	(0x0000_2037, "ERROR_DS_NAMING_VIOLATION", "There is a naming violation."),
+	(0x0000_2038, "ERROR_DS_OBJECT_RESULTS_TOO_LARGE", "The result set is too large."),
+	(0x0000_2039, "ERROR_DS_AFFECTS_MULTIPLE_DSAS", "The operation affects multiple DSAs."),
+	(0x0000_203a, "ERROR_DS_SERVER_DOWN", "The server is not operational."),
+	(0x0000_203b, "ERROR_DS_LOCAL_ERROR", "A local error has occurred."),
+	(0x0000_203c, "ERROR_DS_ENCODING_ERROR", "An encoding error has occurred."),
+	(0x0000_203d, "ERROR_DS_DECODING_ERROR", "A decoding error has occurred."),
+	(0x0000_203e, "ERROR_DS_FILTER_UNKNOWN", "The search filter cannot be recognized."),
+	(0x0000_203f, "ERROR_DS_PARAM_ERROR", "One or more parameters are illegal."),
+	(0x0000_2040, "ERROR_DS_NOT_SUPPORTED", "The specified method is not supported."),
+	(0x0000_2041, "ERROR_DS_NO_RESULTS_RETURNED", "No results were returned."),
+	(0x0000_2042, "ERROR_DS_CONTROL_NOT_FOUND", "The specified control is not supported by the server."),
+	(0x0000_2043, "ERROR_DS_CLIENT_LOOP", "A referral loop was detected by the client."),
+	(0x0000_2044, "ERROR_DS_REFERRAL_LIMIT_EXCEEDED", "The preset referral limit was exceeded."),
+	(0x0000_2045, "ERROR_DS_SORT_CONTROL_MISSING", "The search requires a SORT control."),
+	(0x0000_2046, "ERROR_DS_OFFSET_RANGE_ERROR", "The search results exceed the offset range specified."),
+	(0x0000_2047, "ERROR_DS_RIDMGR_DISABLED", "The directory service detected the subsystem that allocates relative identifiers is disabled. This can occur as a protective mechanism when the system determines a significant portion of relative identifiers (RIDs) have been exhausted."),
+	(0x0000_206d, "ERROR_DS_ROOT_MUST_BE_NC", "The root object must be the head of a naming context. The root object cannot have an instantiated parent."),
+	(0x0000_206e, "ERROR_DS_ADD_REPLICA_INHIBITED", "The add replica operation cannot be performed. The naming context must be writeable in order to create the replica."),
+	(0x0000_206f, "ERROR_DS_ATT_NOT_DEF_IN_SCHEMA", "A reference to an attribute that is not defined in the schema occurred."),
+	(0x0000_2070, "ERROR_DS_MAX_OBJ_SIZE_EXCEEDED", "The maximum size of an object has been exceeded."),
+	(0x0000_2071, "ERROR_DS_OBJ_STRING_NAME_EXISTS", "An attempt was made to add an object to the directory with a name that is already in use."),
+	(0x0000_2072, "ERROR_DS_NO_RDN_DEFINED_IN_SCHEMA", "An attempt was made to add an object of a class that does not have an RDN defined in the schema."),
+	(0x0000_2073, "ERROR_DS_RDN_DOESNT_MATCH_SCHEMA", "An attempt was made to add an object using an RDN that is not the RDN defined in the schema."),
+	(0x0000_2074, "ERROR_DS_NO_REQUESTED_ATTS_FOUND", "None of the requested attributes were found on the objects."),
+	(0x0000_2075, "ERROR_DS_USER_BUFFER_TO_SMALL", "The user buffer is too small."),
+	(0x0000_2076, "ERROR_DS_ATT_IS_NOT_ON_OBJ", "The attribute specified in the operation is not present on the object."),
+	(0x0000_2077, "ERROR_DS_ILLEGAL_MOD_OPERATION", "Illegal modify operation. Some aspect of the modification is not permitted."),
+	(0x0000_2078, "ERROR_DS_OBJ_TOO_LARGE", "The specified object is too large."),
+	(0x0000_2079, "ERROR_DS_BAD_INSTANCE_TYPE", "The specified instance type is not valid."),
+	(0x0000_207a, "ERROR_DS_MASTERDSA_REQUIRED", "The operation cannot be performed on a replica."),
+	(0x0000_207b, "ERROR_DS_OBJECT_CLASS_REQUIRED", "The object class attribute must be specified."),
+	(0x0000_207c, "ERROR_DS_MISSING_REQUIRED_ATT", "A required attribute is missing."),
+	(0x0000_207d, "ERROR_DS_ATT_NOT_DEF_FOR_CLASS", "An attempt was made to modify an object to include an attribute that is not legal for its class."),
+	(0x0000_207e, "ERROR_DS_ATT_ALREADY_EXISTS", "The specified attribute is already present on the object."),
+	(0x0000_2080, "ERROR_DS_CANT_ADD_ATT_VALUES", "The specified attribute is not present, or has no values."),
+	(0x0000_2081, "ERROR_DS_SINGLE_VALUE_CONSTRAINT", "Multiple values were specified for an attribute that can have only one value."),
+	(0x0000_2082, "ERROR_DS_RANGE_CONSTRAINT", "A value for the attribute was not in the acceptable range of values."),
+	(0x0000_2083, "ERROR_DS_ATT_VAL_ALREADY_EXISTS", "The specified value already exists."),
+	(0x0000_2084, "ERROR_DS_CANT_REM_MISSING_ATT", "The attribute cannot be removed because it is not present on the object."),
+	(0x0000_2085, "ERROR_DS_CANT_REM_MISSING_ATT_VAL", "The attribute value cannot be removed because it is not present on the object."),
+	(0x0000_2086, "ERROR_DS_ROOT_CANT_BE_SUBREF", "The specified root object cannot be a subref."),
+	(0x0000_2087, "ERROR_DS_NO_CHAINING", "Chaining is not permitted."),
+	(0x0000_2088, "ERROR_DS_NO_CHAINED_EVAL", "Chained evaluation is not permitted."),
+	(0x0000_2089, "ERROR_DS_NO_PARENT_OBJECT", "The operation could not be performed because the object's parent is either uninstantiated or deleted."),
+	(0x0000_208a, "ERROR_DS_PARENT_IS_AN_ALIAS", "Having a parent that is an alias is not permitted. Aliases are leaf objects."),
+	(0x0000_208b, "ERROR_DS_CANT_MIX_MASTER_AND_REPS", "The object and parent must be of the same type, either both masters or both replicas."),
+	(0x0000_208c, "ERROR_DS_CHILDREN_EXIST", "The operation cannot be performed because child objects exist. This operation can only be performed on a leaf object."),
+	(0x0000_208d, "ERROR_DS_OBJ_NOT_FOUND", "Directory object not found."),
+	(0x0000_208e, "ERROR_DS_ALIASED_OBJ_MISSING", "The aliased object is missing."),
+	(0x0000_208f, "ERROR_DS_BAD_NAME_SYNTAX", "The object name has bad syntax."),
+	(0x0000_2090, "ERROR_DS_ALIAS_POINTS_TO_ALIAS", "It is not permitted for an alias to refer to another alias."),
+	(0x0000_2091, "ERROR_DS_CANT_DEREF_ALIAS", "The alias cannot be dereferenced."),
+	(0x0000_2092, "ERROR_DS_OUT_OF_SCOPE", "The operation is out of scope."),
+	(0x0000_2093, "ERROR_DS_OBJECT_BEING_REMOVED", "The operation cannot continue because the object is in the process of being removed."),
+	(0x0000_2094, "ERROR_DS_CANT_DELETE_DSA_OBJ", "The DSA object cannot be deleted."),
+	(0x0000_2095, "ERROR_DS_GENERIC_ERROR", "A directory service error has occurred."),
+	(0x0000_2096, "ERROR_DS_DSA_MUST_BE_INT_MASTER", "The operation can only be performed on an internal master DSA object."),
+	(0x0000_2097, "ERROR_DS_CLASS_NOT_DSA", "The object must be of class DSA."),
+	(0x0000_2098, "ERROR_DS_INSUFF_ACCESS_RIGHTS", "Insufficient access rights to perform the operation."),
+	(0x0000_2099, "ERROR_DS_ILLEGAL_SUPERIOR", "The object cannot be added because the parent is not on the list of possible superiors."),
+	(0x0000_209a, "ERROR_DS_ATTRIBUTE_OWNED_BY_SAM", "Access to the attribute is not permitted because the attribute is owned by the Security Accounts Manager (SAM)."),
+	(0x0000_209b, "ERROR_DS_NAME_TOO_MANY_PARTS", "The name has too many parts."),
+	(0x0000_209c, "ERROR_DS_NAME_TOO_LONG", "The name is too long."),
+	(0x0000_209d, "ERROR_DS_NAME_VALUE_TOO_LONG", "The name value is too long."),
+	(0x0000_209e, "ERROR_DS_NAME_UNPARSEABLE", "The directory service encountered an error parsing a name."),
+	(0x0000_209f, "ERROR_DS_NAME_TYPE_UNKNOWN", "The directory service cannot get the attribute type for a name."),
+	(0x0000_20a0, "ERROR_DS_NOT_AN_OBJECT", "The name does not identify an object; the name identifies a phantom."),
+	(0x0000_20a1, "ERROR_DS_SEC_DESC_TOO_SHORT", "The security descriptor is too short."),
+	(0x0000_20a2, "ERROR_DS_SEC_DESC_INVALID", "The security descriptor is invalid."),
+	(0x0000_20a3, "ERROR_DS_NO_DELETED_NAME", "Failed to create name for deleted object."),
+	(0x0000_20a4, "ERROR_DS_SUBREF_MUST_HAVE_PARENT", "The parent of a new subref must exist."),
+	(0x0000_20a5, "ERROR_DS_NCNAME_MUST_BE_NC", "The object must be a naming context."),
+	(0x0000_20a6, "ERROR_DS_CANT_ADD_SYSTEM_ONLY", "It is not permitted to add an attribute which is owned by the system."),
+	(0x0000_20a7, "ERROR_DS_CLASS_MUST_BE_CONCRETE", "The class of the object must be structural; you cannot instantiate an abstract class."),
+	(0x0000_20a8, "ERROR_DS_INVALID_DMD", "The schema object could not be found."),
+	(0x0000_20a9, "ERROR_DS_OBJ_GUID_EXISTS", "A local object with this GUID (dead or alive) already exists."),
+	(0x0000_20aa, "ERROR_DS_NOT_ON_BACKLINK", "The operation cannot be performed on a back link."),
+	(0x0000_20ab, "ERROR_DS_NO_CROSSREF_FOR_NC", "The cross reference for the specified naming context could not be found."),
+	(0x0000_20ac, "ERROR_DS_SHUTTING_DOWN", "The operation could not be performed because the directory service is shutting down."),
+	(0x0000_20ad, "ERROR_DS_UNKNOWN_OPERATION", "The directory service request is invalid."),
+	(0x0000_20ae, "ERROR_DS_INVALID_ROLE_OWNER", "The role owner attribute could not be read."),
+	(0x0000_20af, "ERROR_DS_COULDNT_CONTACT_FSMO", "The requested FSMO operation failed. The current FSMO holder could not be contacted."),
+	(0x0000_20b0, "ERROR_DS_CROSS_NC_DN_RENAME", "Modification of a DN across a naming context is not permitted."),
+	(0x0000_20b1, "ERROR_DS_CANT_MOD_SYSTEM_ONLY", "The attribute cannot be modified because it is owned by the system."),
+	(0x0000_20b2, "ERROR_DS_REPLICATOR_ONLY", "Only the replicator can perform this function."),
+	(0x0000_20b3, "ERROR_DS_OBJ_CLASS_NOT_DEFINED", "The specified class is not defined."),
+	(0x0000_20b4, "ERROR_DS_OBJ_CLASS_NOT_SUBCLASS", "The specified class is not a subclass."),
+	(0x0000_20b5, "ERROR_DS_NAME_REFERENCE_INVALID", "The name reference is invalid."),
+	(0x0000_20b6, "ERROR_DS_CROSS_REF_EXISTS", "A cross reference already exists."),
+	(0x0000_20b7, "ERROR_DS_CANT_DEL_MASTER_CROSSREF", "It is not permitted to delete a master cross reference."),
+	(0x0000_20b8, "ERROR_DS_SUBTREE_NOTIFY_NOT_NC_HEAD", "Subtree notifications are only supported on NC heads."),
+	(0x0000_20b9, "ERROR_DS_NOTIFY_FILTER_TOO_COMPLEX", "Notification filter is too complex."),
+	(0x0000_20ba, "ERROR_DS_DUP_RDN", "Schema update failed: duplicate RDN."),
+	(0x0000_20bb, "ERROR_DS_DUP_OID", "Schema update failed: duplicate OID."),
+	(0x0000_20bc, "ERROR_DS_DUP_MAPI_ID", "Schema update failed: duplicate MAPI identifier."),
+	(0x0000_20bd, "ERROR_DS_DUP_SCHEMA_ID_GUID", "Schema update failed: duplicate schema-id GUID."),
+	(0x0000_20be, "ERROR_DS_DUP_LDAP_DISPLAY_NAME", "Schema update failed: duplicate LDAP display name."),
+	(0x0000_20bf, "ERROR_DS_SEMANTIC_ATT_TEST", "Schema update failed: range-lower less than range upper."),
+	(0x0000_20c0, "ERROR_DS_SYNTAX_MISMATCH", "Schema update failed: syntax mismatch."),
+	(0x0000_20c1, "ERROR_DS_EXISTS_IN_MUST_HAVE", "Schema deletion failed: attribute is used in must-contain."),
+	(0x0000_20c2, "ERROR_DS_EXISTS_IN_MAY_HAVE", "Schema deletion failed: attribute is used in may-contain."),
+	(0x0000_20c3, "ERROR_DS_NONEXISTENT_MAY_HAVE", "Schema update failed: attribute in may-contain does not exist."),
+	(0x0000_20c4, "ERROR_DS_NONEXISTENT_MUST_HAVE", "Schema update failed: attribute in must-contain does not exist."),
+	(0x0000_20c5, "ERROR_DS_AUX_CLS_TEST_FAIL", "Schema update failed: class in aux-class list does not exist or is not an auxiliary class."),
+	(0x0000_20c6, "ERROR_DS_NONEXISTENT_POSS_SUP", "Schema update failed: class in poss-superiors does not exist."),
+	(0x0000_20c7, "ERROR_DS_SUB_CLS_TEST_FAIL", "Schema update failed: class in subclassof list does not exist or does not satisfy hierarchy rules."),
+	(0x0000_20c8, "ERROR_DS_BAD_RDN_ATT_ID_SYNTAX", "Schema update failed: Rdn-Att-Id has wrong syntax."),
+	(0x0000_20c9, "ERROR_DS_EXISTS_IN_AUX_CLS", "Schema deletion failed: class is used as auxiliary class."),
+	(0x0000_20ca, "ERROR_DS_EXISTS_IN_SUB_CLS", "Schema deletion failed: class is used as sub class."),
+	(0x0000_20cb, "ERROR_DS_EXISTS_IN_POSS_SUP", "Schema deletion failed: class is used as poss superior."),
+	(0x0000_20cc, "ERROR_DS_RECALCSCHEMA_FAILED", "Schema update failed in recalculating validation cache."),
+	(0x0000_20cd, "ERROR_DS_TREE_DELETE_NOT_FINISHED", "The tree deletion is not finished. The request must be made again to continue deleting the tree."),
+	(0x0000_20ce, "ERROR_DS_CANT_DELETE", "The requested delete operation could not be performed."),
+	(0x0000_20cf, "ERROR_DS_ATT_SCHEMA_REQ_ID", "Cannot read the governs class identifier for the schema record."),
+	(0x0000_20d0, "ERROR_DS_BAD_ATT_SCHEMA_SYNTAX", "The attribute schema has bad syntax."),
+	(0x0000_20d1, "ERROR_DS_CANT_CACHE_ATT", "The attribute could not be cached."),
+	(0x0000_20d2, "ERROR_DS_CANT_CACHE_CLASS", "The class could not be cached."),
+	(0x0000_20d3, "ERROR_DS_CANT_REMOVE_ATT_CACHE", "The attribute could not be removed from the cache."),
+	(0x0000_20d4, "ERROR_DS_CANT_REMOVE_CLASS_CACHE", "The class could not be removed from the cache."),
+	(0x0000_20d5, "ERROR_DS_CANT_RETRIEVE_DN", "The distinguished name attribute could not be read."),
+	(0x0000_20d6, "ERROR_DS_MISSING_SUPREF", "No superior reference has been configured for the directory service. The directory service is therefore unable to issue referrals to objects outside this forest."),
+	(0x0000_20d7, "ERROR_DS_CANT_RETRIEVE_INSTANCE", "The instance type attribute could not be retrieved."),
+	(0x0000_20d8, "ERROR_DS_CODE_INCONSISTENCY", "An internal error has occurred."),
+	(0x0000_20d9, "ERROR_DS_DATABASE_ERROR", "A database error has occurred."),
+	(0x0000_20da, "ERROR_DS_GOVERNSID_MISSING", "The attribute GOVERNSID is missing."),
+	(0x0000_20db, "ERROR_DS_MISSING_EXPECTED_ATT", "An expected attribute is missing."),
+	(0x0000_20dc, "ERROR_DS_NCNAME_MISSING_CR_REF", "The specified naming context is missing a cross reference."),
+	(0x0000_20dd, "ERROR_DS_SECURITY_CHECKING_ERROR", "A security checking error has occurred."),
+	(0x0000_20de, "ERROR_DS_SCHEMA_NOT_LOADED", "The schema is not loaded."),
+	(0x0000_20df, "ERROR_DS_SCHEMA_ALLOC_FAILED", "Schema allocation failed. Please check if the machine is running low on memory."),
+	(0x0000_20e0, "ERROR_DS_ATT_SCHEMA_REQ_SYNTAX", "Failed to obtain the required syntax for the attribute schema."),
+	(0x0000_20e1, "ERROR_DS_GCVERIFY_ERROR", "The global catalog verification failed. The global catalog is not available or does not support the operation. Some part of the directory is currently not available."),
+	(0x0000_20e2, "ERROR_DS_DRA_SCHEMA_MISMATCH", "The replication operation failed because of a schema mismatch between the servers involved."),
+	(0x0000_20e3, "ERROR_DS_CANT_FIND_DSA_OBJ", "The DSA object could not be found."),
+	(0x0000_20e4, "ERROR_DS_CANT_FIND_EXPECTED_NC", "The naming context could not be found."),
+	(0x0000_20e5, "ERROR_DS_CANT_FIND_NC_IN_CACHE", "The naming context could not be found in the cache."),
+	(0x0000_20e6, "ERROR_DS_CANT_RETRIEVE_CHILD", "The child object could not be retrieved."),
+	(0x0000_20e7, "ERROR_DS_SECURITY_ILLEGAL_MODIFY", "The modification was not permitted for security reasons."),
+	(0x0000_20e8, "ERROR_DS_CANT_REPLACE_HIDDEN_REC", "The operation cannot replace the hidden record."),
+	(0x0000_20e9, "ERROR_DS_BAD_HIERARCHY_FILE", "The hierarchy file is invalid."),
+	(0x0000_20ea, "ERROR_DS_BUILD_HIERARCHY_TABLE_FAILED", "The attempt to build the hierarchy table failed."),
+	(0x0000_20eb, "ERROR_DS_CONFIG_PARAM_MISSING", "The directory configuration parameter is missing from the registry."),
+	(0x0000_20ec, "ERROR_DS_COUNTING_AB_INDICES_FAILED", "The attempt to count the address book indices failed."),
+	(0x0000_20ed, "ERROR_DS_HIERARCHY_TABLE_MALLOC_FAILED", "The allocation of the hierarchy table failed."),
+	(0x0000_20ee, "ERROR_DS_INTERNAL_FAILURE", "The directory service encountered an internal failure."),
+	(0x0000_20ef, "ERROR_DS_UNKNOWN_ERROR", "The directory service encountered an unknown failure."),
+	(0x0000_20f0, "ERROR_DS_ROOT_REQUIRES_CLASS_TOP", "A root object requires a class of 'top'."),
+	(0x0000_20f1, "ERROR_DS_REFUSING_FSMO_ROLES", "This directory server is shutting down, and cannot take ownership of new floating single-master operation roles."),
+	(0x0000_20f2, "ERROR_DS_MISSING_FSMO_SETTINGS", "The directory service is missing mandatory configuration information, and is unable to determine the ownership of floating single-master operation roles."),
+	(0x0000_20f3, "ERROR_DS_UNABLE_TO_SURRENDER_ROLES", "The directory service was unable to transfer ownership of one or more floating single-master operation roles to other servers."),
+	(0x0000_20f4, "ERROR_DS_DRA_GENERIC", "The replication operation failed."),
+	(0x0000_20f5, "ERROR_DS_DRA_INVALID_PARAMETER", "An invalid parameter was specified for this replication operation."),
+	(0x0000_20f6, "ERROR_DS_DRA_BUSY", "The directory service is too busy to complete the replication operation at this time."),
+	(0x0000_20f7, "ERROR_DS_DRA_BAD_DN", "The distinguished name specified for this replication operation is invalid."),
+	(0x0000_20f8, "ERROR_DS_DRA_BAD_NC", "The naming context specified for this replication operation is invalid."),
+	(0x0000_20f9, "ERROR_DS_DRA_DN_EXISTS", "The distinguished name specified for this replication operation already exists."),
+	(0x0000_20fa, "ERROR_DS_DRA_INTERNAL_ERROR", "The replication system encountered an internal error."),
+	(0x0000_20fb, "ERROR_DS_DRA_INCONSISTENT_DIT", "The replication operation encountered a database inconsistency."),
+	(0x0000_20fc, "ERROR_DS_DRA_CONNECTION_FAILED", "The server specified for this replication operation could not be contacted."),
+	(0x0000_20fd, "ERROR_DS_DRA_BAD_INSTANCE_TYPE", "The replication operation encountered an object with an invalid instance type."),
+	(0x0000_20fe, "ERROR_DS_DRA_OUT_OF_MEM", "The replication operation failed to allocate memory."),
+	(0x0000_20ff, "ERROR_DS_DRA_MAIL_PROBLEM", "The replication operation encountered an error with the mail system."),
+	(0x0000_2100, "ERROR_DS_DRA_REF_ALREADY_EXISTS", "The replication reference information for the target server already exists."),
+	(0x0000_2101, "ERROR_DS_DRA_REF_NOT_FOUND", "The replication reference information for the target server does not exist."),
+	(0x0000_2102, "ERROR_DS_DRA_OBJ_IS_REP_SOURCE", "The naming context cannot be removed because it is replicated to another server."),
+	(0x0000_2103, "ERROR_DS_DRA_DB_ERROR", "The replication operation encountered a database error."),
+	(0x0000_2104, "ERROR_DS_DRA_NO_REPLICA", "The naming context is in the process of being removed or is not replicated from the specified server."),
+	(0x0000_2105, "ERROR_DS_DRA_ACCESS_DENIED", "Replication access was denied."),
+	(0x0000_2106, "ERROR_DS_DRA_NOT_SUPPORTED", "The requested operation is not supported by this version of the directory service."),
+	(0x0000_2107, "ERROR_DS_DRA_RPC_CANCELLED", "The replication remote procedure call was cancelled."),
+	(0x0000_2108, "ERROR_DS_DRA_SOURCE_DISABLED", "The source server is currently rejecting replication requests."),
+	(0x0000_2109, "ERROR_DS_DRA_SINK_DISABLED", "The destination server is currently rejecting replication requests."),
+	(0x0000_210a, "ERROR_DS_DRA_NAME_COLLISION", "The replication operation failed due to a collision of object names."),
+	(0x0000_210b, "ERROR_DS_DRA_SOURCE_REINSTALLED", "The replication source has been reinstalled."),
+	(0x0000_210c, "ERROR_DS_DRA_MISSING_PARENT", "The replication operation failed because a required parent object is missing."),
+	(0x0000_210d, "ERROR_DS_DRA_PREEMPTED", "The replication operation was preempted."),
+	(0x0000_210e, "ERROR_DS_DRA_ABANDON_SYNC", "The replication synchronization attempt was abandoned because of a lack of updates."),
+	(0x0000_210f, "ERROR_DS_DRA_SHUTDOWN", "The replication operation was terminated because the system is shutting down."),
+	(0x0000_2110, "ERROR_DS_DRA_INCOMPATIBLE_PARTIAL_SET", "Synchronization attempt failed because the destination DC is currently waiting to synchronize new partial attributes from source. This condition is normal if a recent schema change modified the partial attribute set. The destination partial attribute set is not a subset of source partial attribute set."),
+	(0x0000_2111, "ERROR_DS_DRA_SOURCE_IS_PARTIAL_REPLICA", "The replication synchronization attempt failed because a master replica attempted to sync from a partial replica."),
+	(0x0000_2112, "ERROR_DS_DRA_EXTN_CONNECTION_FAILED", "The server specified for this replication operation was contacted, but that server was unable to contact an additional server needed to complete the operation."),
+	(0x0000_2113, "ERROR_DS_INSTALL_SCHEMA_MISMATCH", "The version of the directory service schema of the source forest is not compatible with the version of directory service on this computer."),
+	(0x0000_2114, "ERROR_DS_DUP_LINK_ID", "Schema update failed: An attribute with the same link identifier already exists."),
+	(0x0000_2115, "ERROR_DS_NAME_ERROR_RESOLVING", "Name translation: Generic processing error."),
+	(0x0000_2116, "ERROR_DS_NAME_ERROR_NOT_FOUND", "Name translation: Could not find the name or insufficient right to see name."),
+	(0x0000_2117, "ERROR_DS_NAME_ERROR_NOT_UNIQUE", "Name translation: Input name mapped to more than one output name."),
+	(0x0000_2118, "ERROR_DS_NAME_ERROR_NO_MAPPING", "Name translation: Input name found, but not the associated output format."),
+	(0x0000_2119, "ERROR_DS_NAME_ERROR_DOMAIN_ONLY", "Name translation: Unable to resolve completely, only the domain was found."),
+	(0x0000_211a, "ERROR_DS_NAME_ERROR_NO_SYNTACTICAL_MAPPING", "Name translation: Unable to perform purely syntactical mapping at the client without going out to the wire."),
+	(0x0000_211b, "ERROR_DS_CONSTRUCTED_ATT_MOD", "Modification of a constructed attribute is not allowed."),
+	(0x0000_211c, "ERROR_DS_WRONG_OM_OBJ_CLASS", "The OM-Object-Class specified is incorrect for an attribute with the specified syntax."),
+	(0x0000_211d, "ERROR_DS_DRA_REPL_PENDING", "The replication request has been posted; waiting for reply."),
+	(0x0000_211e, "ERROR_DS_DS_REQUIRED", "The requested operation requires a directory service, and none was available."),
+	(0x0000_211f, "ERROR_DS_INVALID_LDAP_DISPLAY_NAME", "The LDAP display name of the class or attribute contains non-ASCII characters."),
+	(0x0000_2120, "ERROR_DS_NON_BASE_SEARCH", "The requested search operation is only supported for base searches."),
+	(0x0000_2121, "ERROR_DS_CANT_RETRIEVE_ATTS", "The search failed to retrieve attributes from the database."),
+	(0x0000_2122, "ERROR_DS_BACKLINK_WITHOUT_LINK", "The schema update operation tried to add a backward link attribute that has no corresponding forward link."),
+	(0x0000_2123, "ERROR_DS_EPOCH_MISMATCH", "Source and destination of a cross-domain move do not agree on the object's epoch number. Either source or destination does not have the latest version of the object."),
+	(0x0000_2124, "ERROR_DS_SRC_NAME_MISMATCH", "Source and destination of a cross-domain move do not agree on the object's current name. Either source or destination does not have the latest version of the object."),
+	(0x0000_2125, "ERROR_DS_SRC_AND_DST_NC_IDENTICAL", "Source and destination for the cross-domain move operation are identical. Caller should use local move operation instead of cross-domain move operation."),
+	(0x0000_2126, "ERROR_DS_DST_NC_MISMATCH", "Source and destination for a cross-domain move are not in agreement on the naming contexts in the forest. Either source or destination does not have the latest version of the Partitions container."),
+	(0x0000_2127, "ERROR_DS_NOT_AUTHORITIVE_FOR_DST_NC", "Destination of a cross-domain move is not authoritative for the destination naming context."),
+	(0x0000_2128, "ERROR_DS_SRC_GUID_MISMATCH", "Source and destination of a cross-domain move do not agree on the identity of the source object. Either source or destination does not have the latest version of the source object."),
+	(0x0000_2129, "ERROR_DS_CANT_MOVE_DELETED_OBJECT", "Object being moved across-domains is already known to be deleted by the destination server. The source server does not have the latest version of the source object."),
+	(0x0000_212a, "ERROR_DS_PDC_OPERATION_IN_PROGRESS", "Another operation which requires exclusive access to the PDC FSMO is already in progress."),
+	(0x0000_212b, "ERROR_DS_CROSS_DOMAIN_CLEANUP_REQD", "A cross-domain move operation failed such that two versions of the moved object exist - one each in the source and destination domains. The destination object needs to be removed to restore the system to a consistent state."),
+	(0x0000_212c, "ERROR_DS_ILLEGAL_XDOM_MOVE_OPERATION", "This object may not be moved across domain boundaries either because cross-domain moves for this class are disallowed, or the object has some special characteristics, e.g.: trust account or restricted RID, which prevent its move."),
+	(0x0000_212d, "ERROR_DS_CANT_WITH_ACCT_GROUP_MEMBERSHPS", "Can't move objects with memberships across domain boundaries as once moved, this would violate the membership conditions of the account group. Remove the object from any account group memberships and retry."),
+	(0x0000_212e, "ERROR_DS_NC_MUST_HAVE_NC_PARENT", "A naming context head must be the immediate child of another naming context head, not of an interior node."),
+	(0x0000_212f, "ERROR_DS_CR_IMPOSSIBLE_TO_VALIDATE", "The directory cannot validate the proposed naming context name because it does not hold a replica of the naming context above the proposed naming context. Please ensure that the domain naming master role is held by a server that is configured as a global catalog server, and that the server is up to date with its replication partners."),
+	(0x0000_2130, "ERROR_DS_DST_DOMAIN_NOT_NATIVE", "Destination domain must be in native mode."),
+	(0x0000_2131, "ERROR_DS_MISSING_INFRASTRUCTURE_CONTAINER", "The operation cannot be performed because the server does not have an infrastructure container in the domain of interest."),
+	(0x0000_2132, "ERROR_DS_CANT_MOVE_ACCOUNT_GROUP", "Cross-domain move of non-empty account groups is not allowed."),
+	(0x0000_2133, "ERROR_DS_CANT_MOVE_RESOURCE_GROUP", "Cross-domain move of non-empty resource groups is not allowed."),
+	(0x0000_2134, "ERROR_DS_INVALID_SEARCH_FLAG", "The search flags for the attribute are invalid. The ANR bit is valid only on attributes of Unicode or Teletex strings."),
+	(0x0000_2135, "ERROR_DS_NO_TREE_DELETE_ABOVE_NC", "Tree deletions starting at an object which has an NC head as a descendant are not allowed."),
+	(0x0000_2136, "ERROR_DS_COULDNT_LOCK_TREE_FOR_DELETE", "The directory service failed to lock a tree in preparation for a tree deletion because the tree was in use."),
+	(0x0000_2137, "ERROR_DS_COULDNT_IDENTIFY_OBJECTS_FOR_TREE_DELETE", "The directory service failed to identify the list of objects to delete while attempting a tree deletion."),
+	(0x0000_2138, "ERROR_DS_SAM_INIT_FAILURE", "Security Accounts Manager initialization failed because of the following error: %1. Error Status: 0x%2. Please shutdown this system and reboot into Directory Services Restore Mode, check the event log for more detailed information."),
+	(0x0000_2139, "ERROR_DS_SENSITIVE_GROUP_VIOLATION", "Only an administrator can modify the membership list of an administrative group."),
+	(0x0000_213a, "ERROR_DS_CANT_MOD_PRIMARYGROUPID", "Cannot change the primary group ID of a domain controller account."),
+	(0x0000_213b, "ERROR_DS_ILLEGAL_BASE_SCHEMA_MOD", "An attempt is made to modify the base schema."),
+	(0x0000_213c, "ERROR_DS_NONSAFE_SCHEMA_CHANGE", "Adding a new mandatory attribute to an existing class, deleting a mandatory attribute from an existing class, or adding an optional attribute to the special class Top that is not a backlink attribute (directly or through inheritance, for example, by adding or deleting an auxiliary class) is not allowed."),
+	(0x0000_213d, "ERROR_DS_SCHEMA_UPDATE_DISALLOWED", "Schema update is not allowed on this DC because the DC is not the schema FSMO Role Owner."),
+	(0x0000_213e, "ERROR_DS_CANT_CREATE_UNDER_SCHEMA", "An object of this class cannot be created under the schema container. You can only create attribute-schema and class-schema objects under the schema container."),
+	(0x0000_213f, "ERROR_DS_INSTALL_NO_SRC_SCH_VERSION", "The replica or child install failed to get the objectVersion attribute on the schema container on the source DC. Either the attribute is missing on the schema container or the credentials supplied do not have permission to read it."),
+	(0x0000_2140, "ERROR_DS_INSTALL_NO_SCH_VERSION_IN_INIFILE", "The replica or child install failed to read the objectVersion attribute in the SCHEMA section of the file schema.ini in the system32 directory."),
+	(0x0000_2141, "ERROR_DS_INVALID_GROUP_TYPE", "The specified group type is invalid."),
+	(0x0000_2142, "ERROR_DS_NO_NEST_GLOBALGROUP_IN_MIXEDDOMAIN", "You cannot nest global groups in a mixed domain if the group is security-enabled."),
+	(0x0000_2143, "ERROR_DS_NO_NEST_LOCALGROUP_IN_MIXEDDOMAIN", "You cannot nest local groups in a mixed domain if the group is security-enabled."),
+	(0x0000_2144, "ERROR_DS_GLOBAL_CANT_HAVE_LOCAL_MEMBER", "A global group cannot have a local group as a member."),
+	(0x0000_2145, "ERROR_DS_GLOBAL_CANT_HAVE_UNIVERSAL_MEMBER", "A global group cannot have a universal group as a member."),
+	(0x0000_2146, "ERROR_DS_UNIVERSAL_CANT_HAVE_LOCAL_MEMBER", "A universal group cannot have a local group as a member."),
+	(0x0000_2147, "ERROR_DS_GLOBAL_CANT_HAVE_CROSSDOMAIN_MEMBER", "A global group cannot have a cross-domain member."),
+	(0x0000_2148, "ERROR_DS_LOCAL_CANT_HAVE_CROSSDOMAIN_LOCAL_MEMBER", "A local group cannot have another cross domain local group as a member."),
+	(0x0000_2149, "ERROR_DS_HAVE_PRIMARY_MEMBERS", "A group with primary members cannot change to a security-disabled group."),
+	(0x0000_214a, "ERROR_DS_STRING_SD_CONVERSION_FAILED", "The schema cache load failed to convert the string default SD on a class-schema object."),
+	(0x0000_214b, "ERROR_DS_NAMING_MASTER_GC", "Only DSAs configured to be Global Catalog servers should be allowed to hold the Domain Naming Master FSMO role."),
+	(0x0000_214c, "ERROR_DS_DNS_LOOKUP_FAILURE", "The DSA operation is unable to proceed because of a DNS lookup failure."),
+	(0x0000_214d, "ERROR_DS_COULDNT_UPDATE_SPNS", "While processing a change to the DNS Host Name for an object, the Service Principal Name values could not be kept in sync."),
+	(0x0000_214e, "ERROR_DS_CANT_RETRIEVE_SD", "The Security Descriptor attribute could not be read."),
+	(0x0000_214f, "ERROR_DS_KEY_NOT_UNIQUE", "The object requested was not found, but an object with that key was found."),
+	(0x0000_2150, "ERROR_DS_WRONG_LINKED_ATT_SYNTAX", "The syntax of the linked attribute being added is incorrect. Forward links can only have syntax 2.5.5.1, 2.5.5.7, and 2.5.5.14, and backlinks can only have syntax 2.5.5.1."),
+	(0x0000_2151, "ERROR_DS_SAM_NEED_BOOTKEY_PASSWORD", "Security Account Manager needs to get the boot password."),
+	(0x0000_2152, "ERROR_DS_SAM_NEED_BOOTKEY_FLOPPY", "Security Account Manager needs to get the boot key from floppy disk."),
+	(0x0000_2153, "ERROR_DS_CANT_START", "Directory Service cannot start."),
+	(0x0000_2154, "ERROR_DS_INIT_FAILURE", "Directory Services could not start."),
+	(0x0000_2155, "ERROR_DS_NO_PKT_PRIVACY_ON_CONNECTION", "The connection between client and server requires packet privacy or better."),
+	(0x0000_2156, "ERROR_DS_SOURCE_DOMAIN_IN_FOREST", "The source domain may not be in the same forest as destination."),
+	(0x0000_2157, "ERROR_DS_DESTINATION_DOMAIN_NOT_IN_FOREST", "The destination domain must be in the forest."),
+	(0x0000_2158, "ERROR_DS_DESTINATION_AUDITING_NOT_ENABLED", "The operation requires that destination domain auditing be enabled."),
+	(0x0000_2159, "ERROR_DS_CANT_FIND_DC_FOR_SRC_DOMAIN", "The operation couldn't locate a DC for the source domain."),
+	(0x0000_215a, "ERROR_DS_SRC_OBJ_NOT_GROUP_OR_USER", "The source object must be a group or user."),
+	(0x0000_215b, "ERROR_DS_SRC_SID_EXISTS_IN_FOREST", "The source object's SID already exists in destination forest."),
+	(0x0000_215c, "ERROR_DS_SRC_AND_DST_OBJECT_CLASS_MISMATCH", "The source and destination object must be of the same type."),
+	(0x0000_215d, "ERROR_SAM_INIT_FAILURE", "Security Accounts Manager initialization failed because of the following error: %1. Error Status: 0x%2. Click OK to shut down the system and reboot into Safe Mode. Check the event log for detailed information."),
+	(0x0000_215e, "ERROR_DS_DRA_SCHEMA_INFO_SHIP", "Schema information could not be included in the replication request."),
+	(0x0000_215f, "ERROR_DS_DRA_SCHEMA_CONFLICT", "The replication operation could not be completed due to a schema incompatibility."),
+	(0x0000_2160, "ERROR_DS_DRA_EARLIER_SCHEMA_CONFLICT", "The replication operation could not be completed due to a previous schema incompatibility."),
+	(0x0000_2161, "ERROR_DS_DRA_OBJ_NC_MISMATCH", "The replication update could not be applied because either the source or the destination has not yet received information regarding a recent cross-domain move operation."),
+	(0x0000_2162, "ERROR_DS_NC_STILL_HAS_DSAS", "The requested domain could not be deleted because there exist domain controllers that still host this domain."),
+	(0x0000_2163, "ERROR_DS_GC_REQUIRED", "The requested operation can be performed only on a global catalog server."),
+	(0x0000_2164, "ERROR_DS_LOCAL_MEMBER_OF_LOCAL_ONLY", "A local group can only be a member of other local groups in the same domain."),
+	(0x0000_2165, "ERROR_DS_NO_FPO_IN_UNIVERSAL_GROUPS", "Foreign security principals cannot be members of universal groups."),
+	(0x0000_2166, "ERROR_DS_CANT_ADD_TO_GC", "The attribute is not allowed to be replicated to the GC because of security reasons."),
+	(0x0000_2167, "ERROR_DS_NO_CHECKPOINT_WITH_PDC", "The checkpoint with the PDC could not be taken because there too many modifications being processed currently."),
+	(0x0000_2168, "ERROR_DS_SOURCE_AUDITING_NOT_ENABLED", "The operation requires that source domain auditing be enabled."),
+	(0x0000_2169, "ERROR_DS_CANT_CREATE_IN_NONDOMAIN_NC", "Security principal objects can only be created inside domain naming contexts."),
+	(0x0000_216a, "ERROR_DS_INVALID_NAME_FOR_SPN", "A Service Principal Name (SPN) could not be constructed because the provided hostname is not in the necessary format."),
+	(0x0000_216b, "ERROR_DS_FILTER_USES_CONTRUCTED_ATTRS", "A Filter was passed that uses constructed attributes."),
+	(0x0000_216c, "ERROR_DS_UNICODEPWD_NOT_IN_QUOTES", "The unicodePwd attribute value must be enclosed in double quotes."),
+	(0x0000_216d, "ERROR_DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED", "Your computer could not be joined to the domain. You have exceeded the maximum number of computer accounts you are allowed to create in this domain. Contact your system administrator to have this limit reset or increased."),
+	(0x0000_216e, "ERROR_DS_MUST_BE_RUN_ON_DST_DC", "For security reasons, the operation must be run on the destination DC."),
+	(0x0000_216f, "ERROR_DS_SRC_DC_MUST_BE_SP4_OR_GREATER", "For security reasons, the source DC must be NT4SP4 or greater."),
+	(0x0000_2170, "ERROR_DS_CANT_TREE_DELETE_CRITICAL_OBJ", "Critical Directory Service System objects cannot be deleted during tree delete operations. The tree delete may have been partially performed."),
+	(0x0000_2171, "ERROR_DS_INIT_FAILURE_CONSOLE", "Directory Services could not start because of the following error: %1. Error Status: 0x%2. Please click OK to shutdown the system. You can use the recovery console to diagnose the system further."),
+	(0x0000_2172, "ERROR_DS_SAM_INIT_FAILURE_CONSOLE", "Security Accounts Manager initialization failed because of the following error: %1. Error Status: 0x%2. Please click OK to shutdown the system. You can use the recovery console to diagnose the system further."),
+	(0x0000_2173, "ERROR_DS_FOREST_VERSION_TOO_HIGH", "The version of the operating system is incompatible with the current AD DS forest functional level or AD LDS Configuration Set functional level. You must upgrade to a new version of the operating system before this server can become an AD DS Domain Controller or add an AD LDS Instance in this AD DS Forest or AD LDS Configuration Set."),
+	(0x0000_2174, "ERROR_DS_DOMAIN_VERSION_TOO_HIGH", "The version of the operating system installed is incompatible with the current domain functional level. You must upgrade to a new version of the operating system before this server can become a domain controller in this domain."),
+	(0x0000_2175, "ERROR_DS_FOREST_VERSION_TOO_LOW", "The version of the operating system installed on this server no longer supports the current AD DS Forest functional level or AD LDS Configuration Set functional level. You must raise the AD DS Forest functional level or AD LDS Configuration Set functional level before this server can become an AD DS Domain Controller or an AD LDS Instance in this Forest or Configuration Set."),
+	(0x0000_2176, "ERROR_DS_DOMAIN_VERSION_TOO_LOW", "The version of the operating system installed on this server no longer supports the current domain functional level. You must raise the domain functional level before this server can become a domain controller in this domain."),
+	(0x0000_2177, "ERROR_DS_INCOMPATIBLE_VERSION", "The version of the operating system installed on this server is incompatible with the functional level of the domain or forest."),
+	(0x0000_2178, "ERROR_DS_LOW_DSA_VERSION", "The functional level of the domain (or forest) cannot be raised to the requested value, because there exist one or more domain controllers in the domain (or forest) that are at a lower incompatible functional level."),
+	(0x0000_2179, "ERROR_DS_NO_BEHAVIOR_VERSION_IN_MIXEDDOMAIN", "The forest functional level cannot be raised to the requested value since one or more domains are still in mixed domain mode. All domains in the forest must be in native mode, for you to raise the forest functional level."),
+	(0x0000_217a, "ERROR_DS_NOT_SUPPORTED_SORT_ORDER", "The sort order requested is not supported."),
+	(0x0000_217b, "ERROR_DS_NAME_NOT_UNIQUE", "The requested name already exists as a unique identifier."),
+	(0x0000_217c, "ERROR_DS_MACHINE_ACCOUNT_CREATED_PRENT4", "The machine account was created pre-NT4. The account needs to be recreated."),
+	(0x0000_217d, "ERROR_DS_OUT_OF_VERSION_STORE", "The database is out of version store."),
+	(0x0000_217e, "ERROR_DS_INCOMPATIBLE_CONTROLS_USED", "Unable to continue operation because multiple conflicting controls were used."),
+	(0x0000_217f, "ERROR_DS_NO_REF_DOMAIN", "A reference domain was not specified for the new application partition."),
+	(0x0000_2180, "ERROR_DS_RESERVED_LINK_ID", "Schema update failed: The link identifier is reserved."),
+	(0x0000_2181, "ERROR_DS_LINK_ID_NOT_AVAILABLE", "Schema update failed: There are no link identifiers available."),
+	(0x0000_2182, "ERROR_DS_AG_CANT_HAVE_UNIVERSAL_MEMBER", "An account group cannot have a universal group as a member."),
+	(0x0000_2183, "ERROR_DS_MODIFYDN_DISALLOWED_BY_INSTANCE_TYPE", "Rename or move operations on naming context heads or read-only objects are not allowed."),
+	(0x0000_2184, "ERROR_DS_NO_OBJECT_MOVE_IN_SCHEMA_NC", "Move operations on objects in the schema naming context are not allowed."),
+	(0x0000_2185, "ERROR_DS_MODIFYDN_DISALLOWED_BY_FLAG", "A system flag has been set on the object and does not allow the object to be moved or renamed."),
+	(0x0000_2186, "ERROR_DS_MODIFYDN_WRONG_GRANDPARENT", "This object is not allowed to change its grandparent container. Moves are not forbidden on this object, but are restricted to sibling containers."),
+	(0x0000_2187, "ERROR_DS_NAME_ERROR_TRUST_REFERRAL", "Unable to resolve completely, a referral to another forest is generated."),
+	(0x0000_2188, "ERROR_NOT_SUPPORTED_ON_STANDARD_SERVER", "The requested action is not supported on standard server."),
+	(0x0000_2189, "ERROR_DS_CANT_ACCESS_REMOTE_PART_OF_AD", "Could not access a partition of the directory service located on a remote server. Make sure at least one server is running for the partition in question."),
+	(0x0000_218a, "ERROR_DS_CR_IMPOSSIBLE_TO_VALIDATE_V2", "The directory cannot validate the proposed naming context (or partition) name because it does not hold a replica nor can it contact a replica of the naming context above the proposed naming context. Please ensure that the parent naming context is properly registered in DNS, and at least one replica of this naming context is reachable by the Domain Naming master."),
+	(0x0000_218b, "ERROR_DS_THREAD_LIMIT_EXCEEDED", "The thread limit for this request was exceeded."),
+	(0x0000_218c, "ERROR_DS_NOT_CLOSEST", "The Global catalog server is not in the closest site."),
+	(0x0000_218d, "ERROR_DS_CANT_DERIVE_SPN_WITHOUT_SERVER_REF", "The DS cannot derive a service principal name (SPN) with which to mutually authenticate the target server because the corresponding server object in the local DS database has no serverReference attribute."),
+	(0x0000_218e, "ERROR_DS_SINGLE_USER_MODE_FAILED", "The Directory Service failed to enter single user mode."),
+	(0x0000_218f, "ERROR_DS_NTDSCRIPT_SYNTAX_ERROR", "The Directory Service cannot parse the script because of a syntax error."),
+	(0x0000_2190, "ERROR_DS_NTDSCRIPT_PROCESS_ERROR", "The Directory Service cannot process the script because of an error."),
+	(0x0000_2191, "ERROR_DS_DIFFERENT_REPL_EPOCHS", "The directory service cannot perform the requested operation because the servers involved are of different replication epochs (which is usually related to a domain rename that is in progress)."),
+	(0x0000_2192, "ERROR_DS_DRS_EXTENSIONS_CHANGED", "The directory service binding must be renegotiated due to a change in the server extensions information."),
+	(0x0000_2193, "ERROR_DS_REPLICA_SET_CHANGE_NOT_ALLOWED_ON_DISABLED_CR", "Operation not allowed on a disabled cross ref."),
+	(0x0000_2194, "ERROR_DS_NO_MSDS_INTID", "Schema update failed: No values for msDS-IntId are available."),
+	(0x0000_2195, "ERROR_DS_DUP_MSDS_INTID", "Schema update failed: Duplicate msDS-INtId. Retry the operation."),
+	(0x0000_2196, "ERROR_DS_EXISTS_IN_RDNATTID", "Schema deletion failed: attribute is used in rDNAttID."),
+	(0x0000_2197, "ERROR_DS_AUTHORIZATION_FAILED", "The directory service failed to authorize the request."),
+	(0x0000_2198, "ERROR_DS_INVALID_SCRIPT", "The Directory Service cannot process the script because it is invalid."),
+	(0x0000_2199, "ERROR_DS_REMOTE_CROSSREF_OP_FAILED", "The remote create cross reference operation failed on the Domain Naming Master FSMO. The operation's error is in the extended data."),
+	(0x0000_219a, "ERROR_DS_CROSS_REF_BUSY", "A cross reference is in use locally with the same name."),
+	(0x0000_219b, "ERROR_DS_CANT_DERIVE_SPN_FOR_DELETED_DOMAIN", "The DS cannot derive a service principal name (SPN) with which to mutually authenticate the target server because the server's domain has been deleted from the forest."),
+	(0x0000_219c, "ERROR_DS_CANT_DEMOTE_WITH_WRITEABLE_NC", "Writeable NCs prevent this DC from demoting."),
+	(0x0000_219d, "ERROR_DS_DUPLICATE_ID_FOUND", "The requested object has a non-unique identifier and cannot be retrieved."),
+	(0x0000_219e, "ERROR_DS_INSUFFICIENT_ATTR_TO_CREATE_OBJECT", "Insufficient attributes were given to create an object. This object may not exist because it may have been deleted and already garbage collected."),
+	(0x0000_219f, "ERROR_DS_GROUP_CONVERSION_ERROR", "The group cannot be converted due to attribute restrictions on the requested group type."),
+	(0x0000_21a0, "ERROR_DS_CANT_MOVE_APP_BASIC_GROUP", "Cross-domain move of non-empty basic application groups is not allowed."),
+	(0x0000_21a1, "ERROR_DS_CANT_MOVE_APP_QUERY_GROUP", "Cross-domain move of non-empty query based application groups is not allowed."),
+	(0x0000_21a2, "ERROR_DS_ROLE_NOT_VERIFIED", "The FSMO role ownership could not be verified because its directory partition has not replicated successfully with at least one replication partner."),
+	(0x0000_21a3, "ERROR_DS_WKO_CONTAINER_CANNOT_BE_SPECIAL", "The target container for a redirection of a well known object container cannot already be a special container."),
+	(0x0000_21a4, "ERROR_DS_DOMAIN_RENAME_IN_PROGRESS", "The Directory Service cannot perform the requested operation because a domain rename operation is in progress."),
+	(0x0000_21a5, "ERROR_DS_EXISTING_AD_CHILD_NC", "The directory service detected a child partition below the requested partition name. The partition hierarchy must be created in a top down method."),
+	(0x0000_21a6, "ERROR_DS_REPL_LIFETIME_EXCEEDED", "The directory service cannot replicate with this server because the time since the last replication with this server has exceeded the tombstone lifetime."),
+	(0x0000_21a7, "ERROR_DS_DISALLOWED_IN_SYSTEM_CONTAINER", "The requested operation is not allowed on an object under the system container."),
+	(0x0000_21a8, "ERROR_DS_LDAP_SEND_QUEUE_FULL", "The LDAP servers network send queue has filled up because the client is not processing the results of its requests fast enough. No more requests will be processed until the client catches up. If the client does not catch up then it will be disconnected."),
+	(0x0000_21a9, "ERROR_DS_DRA_OUT_SCHEDULE_WINDOW", "The scheduled replication did not take place because the system was too busy to execute the request within the schedule window. The replication queue is overloaded. Consider reducing the number of partners or decreasing the scheduled replication frequency."),
+	(0x0000_21aa, "ERROR_DS_POLICY_NOT_KNOWN", "At this time, it cannot be determined if the branch replication policy is available on the hub domain controller. Please retry at a later time to account for replication latencies."),
+	(0x0000_21ab, "ERROR_NO_SITE_SETTINGS_OBJECT", "The site settings object for the specified site does not exist."),
+	(0x0000_21ac, "ERROR_NO_SECRETS", "The local account store does not contain secret material for the specified account."),
+	(0x0000_21ad, "ERROR_NO_WRITABLE_DC_FOUND", "Could not find a writable domain controller in the domain."),
+	(0x0000_21ae, "ERROR_DS_NO_SERVER_OBJECT", "The server object for the domain controller does not exist."),
+	(0x0000_21af, "ERROR_DS_NO_NTDSA_OBJECT", "The NTDS Settings object for the domain controller does not exist."),
+	(0x0000_21b0, "ERROR_DS_NON_ASQ_SEARCH", "The requested search operation is not supported for ASQ searches."),
+	(0x0000_21b1, "ERROR_DS_AUDIT_FAILURE", "A required audit event could not be generated for the operation."),
+	(0x0000_21b2, "ERROR_DS_INVALID_SEARCH_FLAG_SUBTREE", "The search flags for the attribute are invalid. The subtree index bit is valid only on single valued attributes."),
+	(0x0000_21b3, "ERROR_DS_INVALID_SEARCH_FLAG_TUPLE", "The search flags for the attribute are invalid. The tuple index bit is valid only on attributes of Unicode strings."),
+	(0x0000_21b4, "ERROR_DS_HIERARCHY_TABLE_TOO_DEEP", "The address books are nested too deeply. Failed to build the hierarchy table."),
+	(0x0000_21b5, "ERROR_DS_DRA_CORRUPT_UTD_VECTOR", "The specified up-to-date-ness vector is corrupt."),
+	(0x0000_21b6, "ERROR_DS_DRA_SECRETS_DENIED", "The request to replicate secrets is denied."),
+	(0x0000_21b7, "ERROR_DS_RESERVED_MAPI_ID", "Schema update failed: The MAPI identifier is reserved."),
+	(0x0000_21b8, "ERROR_DS_MAPI_ID_NOT_AVAILABLE", "Schema update failed: There are no MAPI identifiers available."),
+	(0x0000_21b9, "ERROR_DS_DRA_MISSING_KRBTGT_SECRET", "The replication operation failed because the required attributes of the local krbtgt object are missing."),
+	(0x0000_21ba, "ERROR_DS_DOMAIN_NAME_EXISTS_IN_FOREST", "The domain name of the trusted domain already exists in the forest."),
+	(0x0000_21bb, "ERROR_DS_FLAT_NAME_EXISTS_IN_FOREST", "The flat name of the trusted domain already exists in the forest."),
+	(0x0000_21bc, "ERROR_INVALID_USER_PRINCIPAL_NAME", "The User Principal Name (UPN) is invalid."),
+	(0x0000_21bd, "ERROR_DS_OID_MAPPED_GROUP_CANT_HAVE_MEMBERS", "OID mapped groups cannot have members."),
+	(0x0000_21be, "ERROR_DS_OID_NOT_FOUND", "The specified OID cannot be found."),
+	(0x0000_21bf, "ERROR_DS_DRA_RECYCLED_TARGET", "The replication operation failed because the target object referred by a link value is recycled."),
+	(0x0000_21c0, "ERROR_DS_DISALLOWED_NC_REDIRECT", "The redirect operation failed because the target object is in a NC different from the domain NC of the current domain controller."),
+	(0x0000_21c1, "ERROR_DS_HIGH_ADLDS_FFL", "The functional level of the AD LDS configuration set cannot be lowered to the requested value."),
+	(0x0000_21c2, "ERROR_DS_HIGH_DSA_VERSION", "The functional level of the domain (or forest) cannot be lowered to the requested value."),
+	(0x0000_21c3, "ERROR_DS_LOW_ADLDS_FFL", "The functional level of the AD LDS configuration set cannot be raised to the requested value, because there exist one or more ADLDS instances that are at a lower incompatible functional level."),
+	(0x0000_21c4, "ERROR_DOMAIN_SID_SAME_AS_LOCAL_WORKSTATION", "The domain join cannot be completed because the SID of the domain you attempted to join was identical to the SID of this machine. This is a symptom of an improperly cloned operating system install. You should run sysprep on this machine in order to generate a new machine SID."),
+	(0x0000_21c5, "ERROR_DS_UNDELETE_SAM_VALIDATION_FAILED", "The undelete operation failed because the Sam Account Name or Additional Sam Account Name of the object being undeleted conflicts with an existing live object."),
+	(0x0000_21c6, "ERROR_INCORRECT_ACCOUNT_TYPE", "The system is not authoritative for the specified account and therefore cannot complete the operation. Please retry the operation using the provider associated with this account. If this is an online provider please use the provider's online site."),
+	(0x0000_21c7, "ERROR_DS_SPN_VALUE_NOT_UNIQUE_IN_FOREST", "The operation failed because SPN value provided for addition/modification is not unique forest-wide."),
+	(0x0000_21c8, "ERROR_DS_UPN_VALUE_NOT_UNIQUE_IN_FOREST", "The operation failed because UPN value provided for addition/modification is not unique forest-wide."),
+	(0x0000_21c9, "ERROR_DS_MISSING_FOREST_TRUST", "The operation failed because the addition/modification referenced an inbound forest-wide trust that is not present."),
+	(0x0000_21ca, "ERROR_DS_VALUE_KEY_NOT_UNIQUE", "The link value specified was not found, but a link value with that key was found."),
+	(0x0000_32c8, "ERROR_IPSEC_QM_POLICY_EXISTS", "The specified quick mode policy already exists."),
+	(0x0000_32c9, "ERROR_IPSEC_QM_POLICY_NOT_FOUND", "The specified quick mode policy was not found."),
+	(0x0000_32ca, "ERROR_IPSEC_QM_POLICY_IN_USE", "The specified quick mode policy is being used."),
+	(0x0000_32cb, "ERROR_IPSEC_MM_POLICY_EXISTS", "The specified main mode policy already exists."),
+	(0x0000_32cc, "ERROR_IPSEC_MM_POLICY_NOT_FOUND", "The specified main mode policy was not found."),
+	(0x0000_32cd, "ERROR_IPSEC_MM_POLICY_IN_USE", "The specified main mode policy is being used."),
+	(0x0000_32ce, "ERROR_IPSEC_MM_FILTER_EXISTS", "The specified main mode filter already exists."),
+	(0x0000_32cf, "ERROR_IPSEC_MM_FILTER_NOT_FOUND", "The specified main mode filter was not found."),
+	(0x0000_32d0, "ERROR_IPSEC_TRANSPORT_FILTER_EXISTS", "The specified transport mode filter already exists."),
+	(0x0000_32d1, "ERROR_IPSEC_TRANSPORT_FILTER_NOT_FOUND", "The specified transport mode filter does not exist."),
+	(0x0000_32d2, "ERROR_IPSEC_MM_AUTH_EXISTS", "The specified main mode authentication list exists."),
+	(0x0000_32d3, "ERROR_IPSEC_MM_AUTH_NOT_FOUND", "The specified main mode authentication list was not found."),
+	(0x0000_32d4, "ERROR_IPSEC_MM_AUTH_IN_USE", "The specified main mode authentication list is being used."),
+	(0x0000_32d5, "ERROR_IPSEC_DEFAULT_MM_POLICY_NOT_FOUND", "The specified default main mode policy was not found."),
+	(0x0000_32d6, "ERROR_IPSEC_DEFAULT_MM_AUTH_NOT_FOUND", "The specified default main mode authentication list was not found."),
+	(0x0000_32d7, "ERROR_IPSEC_DEFAULT_QM_POLICY_NOT_FOUND", "The specified default quick mode policy was not found."),
+	(0x0000_32d8, "ERROR_IPSEC_TUNNEL_FILTER_EXISTS", "The specified tunnel mode filter exists."),
+	(0x0000_32d9, "ERROR_IPSEC_TUNNEL_FILTER_NOT_FOUND", "The specified tunnel mode filter was not found."),
+	(0x0000_32da, "ERROR_IPSEC_MM_FILTER_PENDING_DELETION", "The Main Mode filter is pending deletion."),
+	(0x0000_32db, "ERROR_IPSEC_TRANSPORT_FILTER_PENDING_DELETION", "The transport filter is pending deletion."),
+	(0x0000_32dc, "ERROR_IPSEC_TUNNEL_FILTER_PENDING_DELETION", "The tunnel filter is pending deletion."),
+	(0x0000_32dd, "ERROR_IPSEC_MM_POLICY_PENDING_DELETION", "The Main Mode policy is pending deletion."),
+	(0x0000_32de, "ERROR_IPSEC_MM_AUTH_PENDING_DELETION", "The Main Mode authentication bundle is pending deletion."),
+	(0x0000_32df, "ERROR_IPSEC_QM_POLICY_PENDING_DELETION", "The Quick Mode policy is pending deletion."),
+	(0x0000_35e8, "ERROR_IPSEC_IKE_NEG_STATUS_BEGIN", "Marks the beginning of the range of IKE negotiation status codes."),
+	(0x0000_35e9, "ERROR_IPSEC_IKE_AUTH_FAIL", "IKE authentication credentials are unacceptable."),
+	(0x0000_35ea, "ERROR_IPSEC_IKE_ATTRIB_FAIL", "IKE security attributes are unacceptable."),
+	(0x0000_35eb, "ERROR_IPSEC_IKE_NEGOTIATION_PENDING", "IKE Negotiation in progress."),
+	(0x0000_35ec, "ERROR_IPSEC_IKE_GENERAL_PROCESSING_ERROR", "General processing error."),
+	(0x0000_35ed, "ERROR_IPSEC_IKE_TIMED_OUT", "Negotiation timed out."),
+	(0x0000_35ee, "ERROR_IPSEC_IKE_NO_CERT", "IKE failed to find valid machine certificate. Contact your Network Security Administrator about installing a valid certificate in the appropriate Certificate Store."),
+	(0x0000_35ef, "ERROR_IPSEC_IKE_SA_DELETED", "IKE SA deleted by peer before establishment completed."),
+	(0x0000_35f0, "ERROR_IPSEC_IKE_SA_REAPED", "IKE SA deleted before establishment completed."),
+	(0x0000_35f1, "ERROR_IPSEC_IKE_MM_ACQUIRE_DROP", "Negotiation request sat in Queue too long."),
+	(0x0000_35f2, "ERROR_IPSEC_IKE_QM_ACQUIRE_DROP", "Negotiation request sat in Queue too long."),
+	(0x0000_35f3, "ERROR_IPSEC_IKE_QUEUE_DROP_MM", "Negotiation request sat in Queue too long."),
+	(0x0000_35f4, "ERROR_IPSEC_IKE_QUEUE_DROP_NO_MM", "Negotiation request sat in Queue too long."),
+	(0x0000_35f5, "ERROR_IPSEC_IKE_DROP_NO_RESPONSE", "No response from peer."),
+	(0x0000_35f6, "ERROR_IPSEC_IKE_MM_DELAY_DROP", "Negotiation took too long."),
+	(0x0000_35f7, "ERROR_IPSEC_IKE_QM_DELAY_DROP", "Negotiation took too long."),
+	(0x0000_35f8, "ERROR_IPSEC_IKE_ERROR", "Unknown error occurred."),
+	(0x0000_35f9, "ERROR_IPSEC_IKE_CRL_FAILED", "Certificate Revocation Check failed."),
+	(0x0000_35fa, "ERROR_IPSEC_IKE_INVALID_KEY_USAGE", "Invalid certificate key usage."),
+	(0x0000_35fb, "ERROR_IPSEC_IKE_INVALID_CERT_TYPE", "Invalid certificate type."),
+	(0x0000_35fc, "ERROR_IPSEC_IKE_NO_PRIVATE_KEY", "IKE negotiation failed because the machine certificate used does not have a private key. IPsec certificates require a private key. Contact your Network Security administrator about replacing with a certificate that has a private key."),
+	(0x0000_35fd, "ERROR_IPSEC_IKE_SIMULTANEOUS_REKEY", "Simultaneous rekeys were detected."),
+	(0x0000_35fe, "ERROR_IPSEC_IKE_DH_FAIL", "Failure in Diffie-Hellman computation."),
+	(0x0000_35ff, "ERROR_IPSEC_IKE_CRITICAL_PAYLOAD_NOT_RECOGNIZED", "Don't know how to process critical payload."),
+	(0x0000_3600, "ERROR_IPSEC_IKE_INVALID_HEADER", "Invalid header."),
+	(0x0000_3601, "ERROR_IPSEC_IKE_NO_POLICY", "No policy configured."),
+	(0x0000_3602, "ERROR_IPSEC_IKE_INVALID_SIGNATURE", "Failed to verify signature."),
+	(0x0000_3603, "ERROR_IPSEC_IKE_KERBEROS_ERROR", "Failed to authenticate using Kerberos."),
+	(0x0000_3604, "ERROR_IPSEC_IKE_NO_PUBLIC_KEY", "Peer's certificate did not have a public key."),
+	(0x0000_3605, "ERROR_IPSEC_IKE_PROCESS_ERR", "Error processing error payload."),
+	(0x0000_3606, "ERROR_IPSEC_IKE_PROCESS_ERR_SA", "Error processing SA payload."),
+	(0x0000_3607, "ERROR_IPSEC_IKE_PROCESS_ERR_PROP", "Error processing Proposal payload."),
+	(0x0000_3608, "ERROR_IPSEC_IKE_PROCESS_ERR_TRANS", "Error processing Transform payload."),
+	(0x0000_3609, "ERROR_IPSEC_IKE_PROCESS_ERR_KE", "Error processing KE payload."),
+	(0x0000_360a, "ERROR_IPSEC_IKE_PROCESS_ERR_ID", "Error processing ID payload."),
+	(0x0000_360b, "ERROR_IPSEC_IKE_PROCESS_ERR_CERT", "Error processing Cert payload."),
+	(0x0000_360c, "ERROR_IPSEC_IKE_PROCESS_ERR_CERT_REQ", "Error processing Certificate Request payload."),
+	(0x0000_360d, "ERROR_IPSEC_IKE_PROCESS_ERR_HASH", "Error processing Hash payload."),
+	(0x0000_360e, "ERROR_IPSEC_IKE_PROCESS_ERR_SIG", "Error processing Signature payload."),
+	(0x0000_360f, "ERROR_IPSEC_IKE_PROCESS_ERR_NONCE", "Error processing Nonce payload."),
+	(0x0000_3610, "ERROR_IPSEC_IKE_PROCESS_ERR_NOTIFY", "Error processing Notify payload."),
+	(0x0000_3611, "ERROR_IPSEC_IKE_PROCESS_ERR_DELETE", "Error processing Delete Payload."),
+	(0x0000_3612, "ERROR_IPSEC_IKE_PROCESS_ERR_VENDOR", "Error processing VendorId payload."),
+	(0x0000_3613, "ERROR_IPSEC_IKE_INVALID_PAYLOAD", "Invalid payload received."),
+	(0x0000_3614, "ERROR_IPSEC_IKE_LOAD_SOFT_SA", "Soft SA loaded."),
+	(0x0000_3615, "ERROR_IPSEC_IKE_SOFT_SA_TORN_DOWN", "Soft SA torn down."),
+	(0x0000_3616, "ERROR_IPSEC_IKE_INVALID_COOKIE", "Invalid cookie received."),
+	(0x0000_3617, "ERROR_IPSEC_IKE_NO_PEER_CERT", "Peer failed to send valid machine certificate."),
+	(0x0000_3618, "ERROR_IPSEC_IKE_PEER_CRL_FAILED", "Certification Revocation check of peer's certificate failed."),
+	(0x0000_3619, "ERROR_IPSEC_IKE_POLICY_CHANGE", "New policy invalidated SAs formed with old policy."),
+	(0x0000_361a, "ERROR_IPSEC_IKE_NO_MM_POLICY", "There is no available Main Mode IKE policy."),
+	(0x0000_361b, "ERROR_IPSEC_IKE_NOTCBPRIV", "Failed to enabled TCB privilege."),
+	(0x0000_361c, "ERROR_IPSEC_IKE_SECLOADFAIL", "Failed to load SECURITY.DLL."),
+	(0x0000_361d, "ERROR_IPSEC_IKE_FAILSSPINIT", "Failed to obtain security function table dispatch address from SSPI."),
+	(0x0000_361e, "ERROR_IPSEC_IKE_FAILQUERYSSP", "Failed to query Kerberos package to obtain max token size."),
+	(0x0000_361f, "ERROR_IPSEC_IKE_SRVACQFAIL", "Failed to obtain Kerberos server credentials for ISAKMP/ERROR_IPSEC_IKE service. Kerberos authentication will not function. The most likely reason for this is lack of domain membership. This is normal if your computer is a member of a workgroup."),
+	(0x0000_3620, "ERROR_IPSEC_IKE_SRVQUERYCRED", "Failed to determine SSPI principal name for ISAKMP/ERROR_IPSEC_IKE service (QueryCredentialsAttributes)."),
+	(0x0000_3621, "ERROR_IPSEC_IKE_GETSPIFAIL", "Failed to obtain new SPI for the inbound SA from IPsec driver. The most common cause for this is that the driver does not have the correct filter. Check your policy to verify the filters."),
+	(0x0000_3622, "ERROR_IPSEC_IKE_INVALID_FILTER", "Given filter is invalid."),
+	(0x0000_3623, "ERROR_IPSEC_IKE_OUT_OF_MEMORY", "Memory allocation failed."),
+	(0x0000_3624, "ERROR_IPSEC_IKE_ADD_UPDATE_KEY_FAILED", "Failed to add Security Association to IPsec Driver. The most common cause for this is if the IKE negotiation took too long to complete. If the problem persists, reduce the load on the faulting machine."),
+	(0x0000_3625, "ERROR_IPSEC_IKE_INVALID_POLICY", "Invalid policy."),
+	(0x0000_3626, "ERROR_IPSEC_IKE_UNKNOWN_DOI", "Invalid DOI."),
+	(0x0000_3627, "ERROR_IPSEC_IKE_INVALID_SITUATION", "Invalid situation."),
+	(0x0000_3628, "ERROR_IPSEC_IKE_DH_FAILURE", "Diffie-Hellman failure."),
+	(0x0000_3629, "ERROR_IPSEC_IKE_INVALID_GROUP", "Invalid Diffie-Hellman group."),
+	(0x0000_362a, "ERROR_IPSEC_IKE_ENCRYPT", "Error encrypting payload."),
+	(0x0000_362b, "ERROR_IPSEC_IKE_DECRYPT", "Error decrypting payload."),
+	(0x0000_362c, "ERROR_IPSEC_IKE_POLICY_MATCH", "Policy match error."),
+	(0x0000_362d, "ERROR_IPSEC_IKE_UNSUPPORTED_ID", "Unsupported ID."),
+	(0x0000_362e, "ERROR_IPSEC_IKE_INVALID_HASH", "Hash verification failed."),
+	(0x0000_362f, "ERROR_IPSEC_IKE_INVALID_HASH_ALG", "Invalid hash algorithm."),
+	(0x0000_3630, "ERROR_IPSEC_IKE_INVALID_HASH_SIZE", "Invalid hash size."),
+	(0x0000_3631, "ERROR_IPSEC_IKE_INVALID_ENCRYPT_ALG", "Invalid encryption algorithm."),
+	(0x0000_3632, "ERROR_IPSEC_IKE_INVALID_AUTH_ALG", "Invalid authentication algorithm."),
+	(0x0000_3633, "ERROR_IPSEC_IKE_INVALID_SIG", "Invalid certificate signature."),
+	(0x0000_3634, "ERROR_IPSEC_IKE_LOAD_FAILED", "Load failed."),
+	(0x0000_3635, "ERROR_IPSEC_IKE_RPC_DELETE", "Deleted via RPC call."),
+	(0x0000_3636, "ERROR_IPSEC_IKE_BENIGN_REINIT", "Temporary state created to perform reinitialization. This is not a real failure."),
+	(0x0000_3637, "ERROR_IPSEC_IKE_INVALID_RESPONDER_LIFETIME_NOTIFY", "The lifetime value received in the Responder Lifetime Notify is below the Windows 2000 configured minimum value. Please fix the policy on the peer machine."),
+	(0x0000_3638, "ERROR_IPSEC_IKE_INVALID_MAJOR_VERSION", "The recipient cannot handle version of IKE specified in the header."),
+	(0x0000_3639, "ERROR_IPSEC_IKE_INVALID_CERT_KEYLEN", "Key length in certificate is too small for configured security requirements."),
+	(0x0000_363a, "ERROR_IPSEC_IKE_MM_LIMIT", "Max number of established MM SAs to peer exceeded."),
+	(0x0000_363b, "ERROR_IPSEC_IKE_NEGOTIATION_DISABLED", "IKE received a policy that disables negotiation."),
+	(0x0000_363c, "ERROR_IPSEC_IKE_QM_LIMIT", "Reached maximum quick mode limit for the main mode. New main mode will be started."),
+	(0x0000_363d, "ERROR_IPSEC_IKE_MM_EXPIRED", "Main mode SA lifetime expired or peer sent a main mode delete."),
+	(0x0000_363e, "ERROR_IPSEC_IKE_PEER_MM_ASSUMED_INVALID", "Main mode SA assumed to be invalid because peer stopped responding."),
+	(0x0000_363f, "ERROR_IPSEC_IKE_CERT_CHAIN_POLICY_MISMATCH", "Certificate doesn't chain to a trusted root in IPsec policy."),
+	(0x0000_3640, "ERROR_IPSEC_IKE_UNEXPECTED_MESSAGE_ID", "Received unexpected message ID."),
+	(0x0000_3641, "ERROR_IPSEC_IKE_INVALID_AUTH_PAYLOAD", "Received invalid authentication offers."),
+	(0x0000_3642, "ERROR_IPSEC_IKE_DOS_COOKIE_SENT", "Sent DoS cookie notify to initiator."),
+	(0x0000_3643, "ERROR_IPSEC_IKE_SHUTTING_DOWN", "IKE service is shutting down."),
+	(0x0000_3644, "ERROR_IPSEC_IKE_CGA_AUTH_FAILED", "Could not verify binding between CGA address and certificate."),
+	(0x0000_3645, "ERROR_IPSEC_IKE_PROCESS_ERR_NATOA", "Error processing NatOA payload."),
+	(0x0000_3646, "ERROR_IPSEC_IKE_INVALID_MM_FOR_QM", "Parameters of the main mode are invalid for this quick mode."),
+	(0x0000_3647, "ERROR_IPSEC_IKE_QM_EXPIRED", "Quick mode SA was expired by IPsec driver."),
+	(0x0000_3648, "ERROR_IPSEC_IKE_TOO_MANY_FILTERS", "Too many dynamically added IKEEXT filters were detected."),
+	(0x0000_3649, "ERROR_IPSEC_IKE_NEG_STATUS_END", "Marks the end of the range of IKE negotiation status codes."),
+	(0x0000_364a, "ERROR_IPSEC_IKE_KILL_DUMMY_NAP_TUNNEL", "NAP reauth succeeded and must delete the dummy NAP IKEv2 tunnel."),
+	(0x0000_364b, "ERROR_IPSEC_IKE_INNER_IP_ASSIGNMENT_FAILURE", "Error in assigning inner IP address to initiator in tunnel mode."),
+	(0x0000_364c, "ERROR_IPSEC_IKE_REQUIRE_CP_PAYLOAD_MISSING", "Require configuration payload missing."),
+	(0x0000_364d, "ERROR_IPSEC_KEY_MODULE_IMPERSONATION_NEGOTIATION_PENDING", "A negotiation running as the security principle who issued the connection is in progress."),
+	(0x0000_364e, "ERROR_IPSEC_IKE_COEXISTENCE_SUPPRESS", "SA was deleted due to IKEv1/AuthIP co-existence suppress check."),
+	(0x0000_364f, "ERROR_IPSEC_IKE_RATELIMIT_DROP", "Incoming SA request was dropped due to peer IP address rate limiting."),
+	(0x0000_3650, "ERROR_IPSEC_IKE_PEER_DOESNT_SUPPORT_MOBIKE", "Peer does not support MOBIKE."),
+	(0x0000_3651, "ERROR_IPSEC_IKE_AUTHORIZATION_FAILURE", "SA establishment is not authorized."),
+	(0x0000_3652, "ERROR_IPSEC_IKE_STRONG_CRED_AUTHORIZATION_FAILURE", "SA establishment is not authorized because there is not a sufficiently strong PKINIT-based credential."),
+	(0x0000_3653, "ERROR_IPSEC_IKE_AUTHORIZATION_FAILURE_WITH_OPTIONAL_RETRY", "SA establishment is not authorized. You may need to enter updated or different credentials such as a smartcard."),
+	(0x0000_3654, "ERROR_IPSEC_IKE_STRONG_CRED_AUTHORIZATION_AND_CERTMAP_FAILURE", "SA establishment is not authorized because there is not a sufficiently strong PKINIT-based credential. This might be related to certificate-to-account mapping failure for the SA."),
+	(0x0000_3655, "ERROR_IPSEC_IKE_NEG_STATUS_EXTENDED_END", "Marks the end of the extended range of IKE negotiation status codes."),
+	(0x0000_3656, "ERROR_IPSEC_BAD_SPI", "The SPI in the packet does not match a valid IPsec SA."),
+	(0x0000_3657, "ERROR_IPSEC_SA_LIFETIME_EXPIRED", "Packet was received on an IPsec SA whose lifetime has expired."),
+	(0x0000_3658, "ERROR_IPSEC_WRONG_SA", "Packet was received on an IPsec SA that does not match the packet characteristics."),
+	(0x0000_3659, "ERROR_IPSEC_REPLAY_CHECK_FAILED", "Packet sequence number replay check failed."),
+	(0x0000_365a, "ERROR_IPSEC_INVALID_PACKET", "IPsec header and/or trailer in the packet is invalid."),
+	(0x0000_365b, "ERROR_IPSEC_INTEGRITY_CHECK_FAILED", "IPsec integrity check failed."),
+	(0x0000_365c, "ERROR_IPSEC_CLEAR_TEXT_DROP", "IPsec dropped a clear text packet."),
+	(0x0000_365d, "ERROR_IPSEC_AUTH_FIREWALL_DROP", "IPsec dropped an incoming ESP packet in authenticated firewall mode. This drop is benign."),
+	(0x0000_365e, "ERROR_IPSEC_THROTTLE_DROP", "IPsec dropped a packet due to DoS throttling."),
+	(0x0000_3665, "ERROR_IPSEC_DOSP_BLOCK", "IPsec DoS Protection matched an explicit block rule."),
+	(0x0000_3666, "ERROR_IPSEC_DOSP_RECEIVED_MULTICAST", "IPsec DoS Protection received an IPsec specific multicast packet which is not allowed."),
+	(0x0000_3667, "ERROR_IPSEC_DOSP_INVALID_PACKET", "IPsec DoS Protection received an incorrectly formatted packet."),
+	(0x0000_3668, "ERROR_IPSEC_DOSP_STATE_LOOKUP_FAILED", "IPsec DoS Protection failed to look up state."),
+	(0x0000_3669, "ERROR_IPSEC_DOSP_MAX_ENTRIES", "IPsec DoS Protection failed to create state because the maximum number of entries allowed by policy has been reached."),
+	(0x0000_366a, "ERROR_IPSEC_DOSP_KEYMOD_NOT_ALLOWED", "IPsec DoS Protection received an IPsec negotiation packet for a keying module which is not allowed by policy."),
+	(0x0000_366b, "ERROR_IPSEC_DOSP_NOT_INSTALLED", "IPsec DoS Protection has not been enabled."),
+	(0x0000_366c, "ERROR_IPSEC_DOSP_MAX_PER_IP_RATELIMIT_QUEUES", "IPsec DoS Protection failed to create a per internal IP rate limit queue because the maximum number of queues allowed by policy has been reached."),
+	(0x0000_36b0, "ERROR_SXS_SECTION_NOT_FOUND", "The requested section was not present in the activation context."),
+	(0x0000_36b1, "ERROR_SXS_CANT_GEN_ACTCTX", "The application has failed to start because its side-by-side configuration is incorrect. Please see the application event log or use the command-line sxstrace.exe tool for more detail."),
+	(0x0000_36b2, "ERROR_SXS_INVALID_ACTCTXDATA_FORMAT", "The application binding data format is invalid."),
+	(0x0000_36b3, "ERROR_SXS_ASSEMBLY_NOT_FOUND", "The referenced assembly is not installed on your system."),
+	(0x0000_36b4, "ERROR_SXS_MANIFEST_FORMAT_ERROR", "The manifest file does not begin with the required tag and format information."),
+	(0x0000_36b5, "ERROR_SXS_MANIFEST_PARSE_ERROR", "The manifest file contains one or more syntax errors."),
+	(0x0000_36b6, "ERROR_SXS_ACTIVATION_CONTEXT_DISABLED", "The application attempted to activate a disabled activation context."),
+	(0x0000_36b7, "ERROR_SXS_KEY_NOT_FOUND", "The requested lookup key was not found in any active activation context."),
+	(0x0000_36b8, "ERROR_SXS_VERSION_CONFLICT", "A component version required by the application conflicts with another component version already active."),
+	(0x0000_36b9, "ERROR_SXS_WRONG_SECTION_TYPE", "The type requested activation context section does not match the query API used."),
+	(0x0000_36ba, "ERROR_SXS_THREAD_QUERIES_DISABLED", "Lack of system resources has required isolated activation to be disabled for the current thread of execution."),
+	(0x0000_36bb, "ERROR_SXS_PROCESS_DEFAULT_ALREADY_SET", "An attempt to set the process default activation context failed because the process default activation context was already set."),
+	(0x0000_36bc, "ERROR_SXS_UNKNOWN_ENCODING_GROUP", "The encoding group identifier specified is not recognized."),
+	(0x0000_36bd, "ERROR_SXS_UNKNOWN_ENCODING", "The encoding requested is not recognized."),
+	(0x0000_36be, "ERROR_SXS_INVALID_XML_NAMESPACE_URI", "The manifest contains a reference to an invalid URI."),
+	(0x0000_36bf, "ERROR_SXS_ROOT_MANIFEST_DEPENDENCY_NOT_INSTALLED", "The application manifest contains a reference to a dependent assembly which is not installed."),
+	(0x0000_36c0, "ERROR_SXS_LEAF_MANIFEST_DEPENDENCY_NOT_INSTALLED", "The manifest for an assembly used by the application has a reference to a dependent assembly which is not installed."),
+	(0x0000_36c1, "ERROR_SXS_INVALID_ASSEMBLY_IDENTITY_ATTRIBUTE", "The manifest contains an attribute for the assembly identity which is not valid."),
+	(0x0000_36c2, "ERROR_SXS_MANIFEST_MISSING_REQUIRED_DEFAULT_NAMESPACE", "The manifest is missing the required default namespace specification on the assembly element."),
+	(0x0000_36c3, "ERROR_SXS_MANIFEST_INVALID_REQUIRED_DEFAULT_NAMESPACE", "The manifest has a default namespace specified on the assembly element but its value is not \"urn:schemas-microsoft-com:asm.v1\"."),
+	(0x0000_36c4, "ERROR_SXS_PRIVATE_MANIFEST_CROSS_PATH_WITH_REPARSE_POINT", "The private manifest probed has crossed a path with an unsupported reparse point."),
+	(0x0000_36c5, "ERROR_SXS_DUPLICATE_DLL_NAME", "Two or more components referenced directly or indirectly by the application manifest have files by the same name."),
+	(0x0000_36c6, "ERROR_SXS_DUPLICATE_WINDOWCLASS_NAME", "Two or more components referenced directly or indirectly by the application manifest have window classes with the same name."),
+	(0x0000_36c7, "ERROR_SXS_DUPLICATE_CLSID", "Two or more components referenced directly or indirectly by the application manifest have the same COM server CLSIDs."),
+	(0x0000_36c8, "ERROR_SXS_DUPLICATE_IID", "Two or more components referenced directly or indirectly by the application manifest have proxies for the same COM interface IIDs."),
+	(0x0000_36c9, "ERROR_SXS_DUPLICATE_TLBID", "Two or more components referenced directly or indirectly by the application manifest have the same COM type library TLBIDs."),
+	(0x0000_36ca, "ERROR_SXS_DUPLICATE_PROGID", "Two or more components referenced directly or indirectly by the application manifest have the same COM ProgIDs."),
+	(0x0000_36cb, "ERROR_SXS_DUPLICATE_ASSEMBLY_NAME", "Two or more components referenced directly or indirectly by the application manifest are different versions of the same component which is not permitted."),
+	(0x0000_36cc, "ERROR_SXS_FILE_HASH_MISMATCH", "A component's file does not match the verification information present in the component manifest."),
+	(0x0000_36cd, "ERROR_SXS_POLICY_PARSE_ERROR", "The policy manifest contains one or more syntax errors."),
+	(0x0000_36ce, "ERROR_SXS_XML_E_MISSINGQUOTE", "Manifest Parse Error : A string literal was expected, but no opening quote character was found."),
+	(0x0000_36cf, "ERROR_SXS_XML_E_COMMENTSYNTAX", "Manifest Parse Error : Incorrect syntax was used in a comment."),
+	(0x0000_36d0, "ERROR_SXS_XML_E_BADSTARTNAMECHAR", "Manifest Parse Error : A name began with an invalid character."),
+	(0x0000_36d1, "ERROR_SXS_XML_E_BADNAMECHAR", "Manifest Parse Error : A name contained an invalid character."),
+	(0x0000_36d2, "ERROR_SXS_XML_E_BADCHARINSTRING", "Manifest Parse Error : A string literal contained an invalid character."),
+	(0x0000_36d3, "ERROR_SXS_XML_E_XMLDECLSYNTAX", "Manifest Parse Error : Invalid syntax for an xml declaration."),
+	(0x0000_36d4, "ERROR_SXS_XML_E_BADCHARDATA", "Manifest Parse Error : An Invalid character was found in text content."),
+	(0x0000_36d5, "ERROR_SXS_XML_E_MISSINGWHITESPACE", "Manifest Parse Error : Required white space was missing."),
+	(0x0000_36d6, "ERROR_SXS_XML_E_EXPECTINGTAGEND", "Manifest Parse Error : The character '>' was expected."),
+	(0x0000_36d7, "ERROR_SXS_XML_E_MISSINGSEMICOLON", "Manifest Parse Error : A semi colon character was expected."),
+	(0x0000_36d8, "ERROR_SXS_XML_E_UNBALANCEDPAREN", "Manifest Parse Error : Unbalanced parentheses."),
+	(0x0000_36d9, "ERROR_SXS_XML_E_INTERNALERROR", "Manifest Parse Error : Internal error."),
+	(0x0000_36da, "ERROR_SXS_XML_E_UNEXPECTED_WHITESPACE", "Manifest Parse Error : Whitespace is not allowed at this location."),
+	(0x0000_36db, "ERROR_SXS_XML_E_INCOMPLETE_ENCODING", "Manifest Parse Error : End of file reached in invalid state for current encoding."),
+	(0x0000_36dc, "ERROR_SXS_XML_E_MISSING_PAREN", "Manifest Parse Error : Missing parenthesis."),
+	(0x0000_36dd, "ERROR_SXS_XML_E_EXPECTINGCLOSEQUOTE", "Manifest Parse Error : A single (') or double (\") quote character is missing."),
+	(0x0000_36de, "ERROR_SXS_XML_E_MULTIPLE_COLONS", "Manifest Parse Error : Multiple colons are not allowed in a name."),
+	(0x0000_36df, "ERROR_SXS_XML_E_INVALID_DECIMAL", "Manifest Parse Error : Invalid character for decimal digit."),
+	(0x0000_36e0, "ERROR_SXS_XML_E_INVALID_HEXIDECIMAL", "Manifest Parse Error : Invalid character for hexadecimal digit."),
+	(0x0000_36e1, "ERROR_SXS_XML_E_INVALID_UNICODE", "Manifest Parse Error : Invalid unicode character value for this platform."),
+	(0x0000_36e2, "ERROR_SXS_XML_E_WHITESPACEORQUESTIONMARK", "Manifest Parse Error : Expecting whitespace or '?'."),
+	(0x0000_36e3, "ERROR_SXS_XML_E_UNEXPECTEDENDTAG", "Manifest Parse Error : End tag was not expected at this location."),
+	(0x0000_36e4, "ERROR_SXS_XML_E_UNCLOSEDTAG", "Manifest Parse Error : The following tags were not closed: %1."),
+	(0x0000_36e5, "ERROR_SXS_XML_E_DUPLICATEATTRIBUTE", "Manifest Parse Error : Duplicate attribute."),
+	(0x0000_36e6, "ERROR_SXS_XML_E_MULTIPLEROOTS", "Manifest Parse Error : Only one top level element is allowed in an XML document."),
+	(0x0000_36e7, "ERROR_SXS_XML_E_INVALIDATROOTLEVEL", "Manifest Parse Error : Invalid at the top level of the document."),
+	(0x0000_36e8, "ERROR_SXS_XML_E_BADXMLDECL", "Manifest Parse Error : Invalid xml declaration."),
+	(0x0000_36e9, "ERROR_SXS_XML_E_MISSINGROOT", "Manifest Parse Error : XML document must have a top level element."),
+	(0x0000_36ea, "ERROR_SXS_XML_E_UNEXPECTEDEOF", "Manifest Parse Error : Unexpected end of file."),
+	(0x0000_36eb, "ERROR_SXS_XML_E_BADPEREFINSUBSET", "Manifest Parse Error : Parameter entities cannot be used inside markup declarations in an internal subset."),
+	(0x0000_36ec, "ERROR_SXS_XML_E_UNCLOSEDSTARTTAG", "Manifest Parse Error : Element was not closed."),
+	(0x0000_36ed, "ERROR_SXS_XML_E_UNCLOSEDENDTAG", "Manifest Parse Error : End element was missing the character '>'."),
+	(0x0000_36ee, "ERROR_SXS_XML_E_UNCLOSEDSTRING", "Manifest Parse Error : A string literal was not closed."),
+	(0x0000_36ef, "ERROR_SXS_XML_E_UNCLOSEDCOMMENT", "Manifest Parse Error : A comment was not closed."),
+	(0x0000_36f0, "ERROR_SXS_XML_E_UNCLOSEDDECL", "Manifest Parse Error : A declaration was not closed."),
+	(0x0000_36f1, "ERROR_SXS_XML_E_UNCLOSEDCDATA", "Manifest Parse Error : A CDATA section was not closed."),
+	(0x0000_36f2, "ERROR_SXS_XML_E_RESERVEDNAMESPACE", "Manifest Parse Error : The namespace prefix is not allowed to start with the reserved string \"xml\"."),
+	(0x0000_36f3, "ERROR_SXS_XML_E_INVALIDENCODING", "Manifest Parse Error : System does not support the specified encoding."),
+	(0x0000_36f4, "ERROR_SXS_XML_E_INVALIDSWITCH", "Manifest Parse Error : Switch from current encoding to specified encoding not supported."),
+	(0x0000_36f5, "ERROR_SXS_XML_E_BADXMLCASE", "Manifest Parse Error : The name 'xml' is reserved and must be lower case."),
+	(0x0000_36f6, "ERROR_SXS_XML_E_INVALID_STANDALONE", "Manifest Parse Error : The standalone attribute must have the value 'yes' or 'no'."),
+	(0x0000_36f7, "ERROR_SXS_XML_E_UNEXPECTED_STANDALONE", "Manifest Parse Error : The standalone attribute cannot be used in external entities."),
+	(0x0000_36f8, "ERROR_SXS_XML_E_INVALID_VERSION", "Manifest Parse Error : Invalid version number."),
+	(0x0000_36f9, "ERROR_SXS_XML_E_MISSINGEQUALS", "Manifest Parse Error : Missing equals sign between attribute and attribute value."),
+	(0x0000_36fa, "ERROR_SXS_PROTECTION_RECOVERY_FAILED", "Assembly Protection Error : Unable to recover the specified assembly."),
+	(0x0000_36fb, "ERROR_SXS_PROTECTION_PUBLIC_KEY_TOO_SHORT", "Assembly Protection Error : The public key for an assembly was too short to be allowed."),
+	(0x0000_36fc, "ERROR_SXS_PROTECTION_CATALOG_NOT_VALID", "Assembly Protection Error : The catalog for an assembly is not valid, or does not match the assembly's manifest."),
+	(0x0000_36fd, "ERROR_SXS_UNTRANSLATABLE_HRESULT", "An HRESULT could not be translated to a corresponding Win32 error code."),
+	(0x0000_36fe, "ERROR_SXS_PROTECTION_CATALOG_FILE_MISSING", "Assembly Protection Error : The catalog for an assembly is missing."),
+	(0x0000_36ff, "ERROR_SXS_MISSING_ASSEMBLY_IDENTITY_ATTRIBUTE", "The supplied assembly identity is missing one or more attributes which must be present in this context."),
+	(0x0000_3700, "ERROR_SXS_INVALID_ASSEMBLY_IDENTITY_ATTRIBUTE_NAME", "The supplied assembly identity has one or more attribute names that contain characters not permitted in XML names."),
+	(0x0000_3701, "ERROR_SXS_ASSEMBLY_MISSING", "The referenced assembly could not be found."),
+	(0x0000_3702, "ERROR_SXS_CORRUPT_ACTIVATION_STACK", "The activation context activation stack for the running thread of execution is corrupt."),
+	(0x0000_3703, "ERROR_SXS_CORRUPTION", "The application isolation metadata for this process or thread has become corrupt."),
+	(0x0000_3704, "ERROR_SXS_EARLY_DEACTIVATION", "The activation context being deactivated is not the most recently activated one."),
+	(0x0000_3705, "ERROR_SXS_INVALID_DEACTIVATION", "The activation context being deactivated is not active for the current thread of execution."),
+	(0x0000_3706, "ERROR_SXS_MULTIPLE_DEACTIVATION", "The activation context being deactivated has already been deactivated."),
+	(0x0000_3707, "ERROR_SXS_PROCESS_TERMINATION_REQUESTED", "A component used by the isolation facility has requested to terminate the process."),
+	(0x0000_3708, "ERROR_SXS_RELEASE_ACTIVATION_CONTEXT", "A kernel mode component is releasing a reference on an activation context."),
+	(0x0000_3709, "ERROR_SXS_SYSTEM_DEFAULT_ACTIVATION_CONTEXT_EMPTY", "The activation context of system default assembly could not be generated."),
+	(0x0000_370a, "ERROR_SXS_INVALID_IDENTITY_ATTRIBUTE_VALUE", "The value of an attribute in an identity is not within the legal range."),
+	(0x0000_370b, "ERROR_SXS_INVALID_IDENTITY_ATTRIBUTE_NAME", "The name of an attribute in an identity is not within the legal range."),
+	(0x0000_370c, "ERROR_SXS_IDENTITY_DUPLICATE_ATTRIBUTE", "An identity contains two definitions for the same attribute."),
+	(0x0000_370d, "ERROR_SXS_IDENTITY_PARSE_ERROR", "The identity string is malformed. This may be due to a trailing comma, more than two unnamed attributes, missing attribute name or missing attribute value."),
+	(0x0000_370e, "ERROR_MALFORMED_SUBSTITUTION_STRING", "A string containing localized substitutable content was malformed. Either a dollar sign ($) was followed by something other than a left parenthesis or another dollar sign or an substitution's right parenthesis was not found."),
+	(0x0000_370f, "ERROR_SXS_INCORRECT_PUBLIC_KEY_TOKEN", "The public key token does not correspond to the public key specified."),
+	(0x0000_3710, "ERROR_UNMAPPED_SUBSTITUTION_STRING", "A substitution string had no mapping."),
+	(0x0000_3711, "ERROR_SXS_ASSEMBLY_NOT_LOCKED", "The component must be locked before making the request."),
+	(0x0000_3712, "ERROR_SXS_COMPONENT_STORE_CORRUPT", "The component store has been corrupted."),
+	(0x0000_3713, "ERROR_ADVANCED_INSTALLER_FAILED", "An advanced installer failed during setup or servicing."),
+	(0x0000_3714, "ERROR_XML_ENCODING_MISMATCH", "The character encoding in the XML declaration did not match the encoding used in the document."),
+	(0x0000_3715, "ERROR_SXS_MANIFEST_IDENTITY_SAME_BUT_CONTENTS_DIFFERENT", "The identities of the manifests are identical but their contents are different."),
+	(0x0000_3716, "ERROR_SXS_IDENTITIES_DIFFERENT", "The component identities are different."),
+	(0x0000_3717, "ERROR_SXS_ASSEMBLY_IS_NOT_A_DEPLOYMENT", "The assembly is not a deployment."),
+	(0x0000_3718, "ERROR_SXS_FILE_NOT_PART_OF_ASSEMBLY", "The file is not a part of the assembly."),
+	(0x0000_3719, "ERROR_SXS_MANIFEST_TOO_BIG", "The size of the manifest exceeds the maximum allowed."),
+	(0x0000_371a, "ERROR_SXS_SETTING_NOT_REGISTERED", "The setting is not registered."),
+	(0x0000_371b, "ERROR_SXS_TRANSACTION_CLOSURE_INCOMPLETE", "One or more required members of the transaction are not present."),
+	(0x0000_371c, "ERROR_SMI_PRIMITIVE_INSTALLER_FAILED", "The SMI primitive installer failed during setup or servicing."),
+	(0x0000_371d, "ERROR_GENERIC_COMMAND_FAILED", "A generic command executable returned a result that indicates failure."),
+	(0x0000_371e, "ERROR_SXS_FILE_HASH_MISSING", "A component is missing file verification information in its manifest."),
+	(0x0000_371f, "ERROR_SXS_DUPLICATE_ACTIVATABLE_CLASS", "Two or more components referenced directly or indirectly by the application manifest have the same WinRT ActivatableClass IDs."),
+	(0x0000_3a98, "ERROR_EVT_INVALID_CHANNEL_PATH", "The specified channel path is invalid."),
+	(0x0000_3a99, "ERROR_EVT_INVALID_QUERY", "The specified query is invalid."),
+	(0x0000_3a9a, "ERROR_EVT_PUBLISHER_METADATA_NOT_FOUND", "The publisher metadata cannot be found in the resource."),
+	(0x0000_3a9b, "ERROR_EVT_EVENT_TEMPLATE_NOT_FOUND", "The template for an event definition cannot be found in the resource (error = %1)."),
+	(0x0000_3a9c, "ERROR_EVT_INVALID_PUBLISHER_NAME", "The specified publisher name is invalid."),
+	(0x0000_3a9d, "ERROR_EVT_INVALID_EVENT_DATA", "The event data raised by the publisher is not compatible with the event template definition in the publisher's manifest."),
+	(0x0000_3a9f, "ERROR_EVT_CHANNEL_NOT_FOUND", "The specified channel could not be found."),
+	(0x0000_3aa0, "ERROR_EVT_MALFORMED_XML_TEXT", "The specified XML text was not well-formed. See Extended Error for more details."),
+	(0x0000_3aa1, "ERROR_EVT_SUBSCRIPTION_TO_DIRECT_CHANNEL", "The events for a direct channel go directly to a log file and cannot be subscribed to."),
+	(0x0000_3aa2, "ERROR_EVT_CONFIGURATION_ERROR", "Configuration error."),
+	(0x0000_3aa3, "ERROR_EVT_QUERY_RESULT_STALE", "The query result is stale or invalid. This may be due to the log being cleared or rolling over after the query result was created. Users should handle this code by releasing the query result object and reissuing the query."),
+	(0x0000_3aa4, "ERROR_EVT_QUERY_RESULT_INVALID_POSITION", "Query result is currently at an invalid position."),
+	(0x0000_3aa5, "ERROR_EVT_NON_VALIDATING_MSXML", "Registered MSXML doesn't support validation."),
+	(0x0000_3aa6, "ERROR_EVT_FILTER_ALREADYSCOPED", "An expression can only be followed by a change of scope operation if it itself evaluates to a node set and is not already part of some other change of scope operation."),
+	(0x0000_3aa7, "ERROR_EVT_FILTER_NOTELTSET", "Can't perform a step operation from a term that does not represent an element set."),
+	(0x0000_3aa8, "ERROR_EVT_FILTER_INVARG", "Left hand side arguments to binary operators must be either attributes, nodes or variables and right hand side arguments must be constants."),
+	(0x0000_3aa9, "ERROR_EVT_FILTER_INVTEST", "A step operation must involve either a node test or, in the case of a predicate, an algebraic expression against which to test each node in the node set identified by the preceding node set can be evaluated."),
+	(0x0000_3aaa, "ERROR_EVT_FILTER_INVTYPE", "This data type is currently unsupported."),
+	(0x0000_3aab, "ERROR_EVT_FILTER_PARSEERR", "A syntax error occurred at position %1!d!"),
+	(0x0000_3aac, "ERROR_EVT_FILTER_UNSUPPORTEDOP", "This operator is unsupported by this implementation of the filter."),
+	(0x0000_3aad, "ERROR_EVT_FILTER_UNEXPECTEDTOKEN", "The token encountered was unexpected."),
+	(0x0000_3aae, "ERROR_EVT_INVALID_OPERATION_OVER_ENABLED_DIRECT_CHANNEL", "The requested operation cannot be performed over an enabled direct channel. The channel must first be disabled."),
+	(0x0000_3aaf, "ERROR_EVT_INVALID_CHANNEL_PROPERTY_VALUE", "Channel property %1!s! contains an invalid value. The value has an invalid type, is outside of its valid range, cannot be changed, or is not supported by this type of channel."),
+	(0x0000_3ab0, "ERROR_EVT_INVALID_PUBLISHER_PROPERTY_VALUE", "Publisher property %1!s! contains an invalid value. The value has an invalid type, is outside of its valid range, cannot be changed, or is not supported by this type of publisher."),
+	(0x0000_3ab1, "ERROR_EVT_CHANNEL_CANNOT_ACTIVATE", "The channel failed to activate."),
+	(0x0000_3ab2, "ERROR_EVT_FILTER_TOO_COMPLEX", "The XPath expression exceeded the supported complexity. Please simplify it or split it into two or more simple expressions."),
+	(0x0000_3ab3, "ERROR_EVT_MESSAGE_NOT_FOUND", "The message resource is present but the message was not found in the message table."),
+	(0x0000_3ab4, "ERROR_EVT_MESSAGE_ID_NOT_FOUND", "The message ID for the desired message could not be found."),
+	(0x0000_3ab5, "ERROR_EVT_UNRESOLVED_VALUE_INSERT", "The substitution string for the insert index (%1) could not be found."),
+	(0x0000_3ab6, "ERROR_EVT_UNRESOLVED_PARAMETER_INSERT", "The description string for the parameter reference (%1) could not be found."),
+	(0x0000_3ab7, "ERROR_EVT_MAX_INSERTS_REACHED", "The maximum number of replacements has been reached."),
+	(0x0000_3ab8, "ERROR_EVT_EVENT_DEFINITION_NOT_FOUND", "The event definition could not be found for the event ID (%1)."),
+	(0x0000_3ab9, "ERROR_EVT_MESSAGE_LOCALE_NOT_FOUND", "The locale specific resource for the desired message is not present."),
+	(0x0000_3aba, "ERROR_EVT_VERSION_TOO_OLD", "The resource is too old to be compatible."),
+	(0x0000_3abb, "ERROR_EVT_VERSION_TOO_NEW", "The resource is too new to be compatible."),
+	(0x0000_3abc, "ERROR_EVT_CANNOT_OPEN_CHANNEL_OF_QUERY", "The channel at index %1!d! of the query can't be opened."),
+	(0x0000_3abd, "ERROR_EVT_PUBLISHER_DISABLED", "The publisher has been disabled and its resource is not available. This usually occurs when the publisher is in the process of being uninstalled or upgraded."),
+	(0x0000_3abe, "ERROR_EVT_FILTER_OUT_OF_RANGE", "Attempted to create a numeric type that is outside of its valid range."),
+	(0x0000_3ae8, "ERROR_EC_SUBSCRIPTION_CANNOT_ACTIVATE", "The subscription fails to activate."),
+	(0x0000_3ae9, "ERROR_EC_LOG_DISABLED", "The log of the subscription is in disabled state, and can not be used to forward events to. The log must first be enabled before the subscription can be activated."),
+	(0x0000_3aea, "ERROR_EC_CIRCULAR_FORWARDING", "When forwarding events from local machine to itself, the query of the subscription can't contain target log of the subscription."),
+	(0x0000_3aeb, "ERROR_EC_CREDSTORE_FULL", "The credential store that is used to save credentials is full."),
+	(0x0000_3aec, "ERROR_EC_CRED_NOT_FOUND", "The credential used by this subscription can't be found in credential store."),
+	(0x0000_3aed, "ERROR_EC_NO_ACTIVE_CHANNEL", "No active channel is found for the query."),
+	(0x0000_3afc, "ERROR_MUI_FILE_NOT_FOUND", "The resource loader failed to find MUI file."),
+	(0x0000_3afd, "ERROR_MUI_INVALID_FILE", "The resource loader failed to load MUI file because the file fail to pass validation."),
+	(0x0000_3afe, "ERROR_MUI_INVALID_RC_CONFIG", "The RC Manifest is corrupted with garbage data or unsupported version or missing required item."),
+	(0x0000_3aff, "ERROR_MUI_INVALID_LOCALE_NAME", "The RC Manifest has invalid culture name."),
+	(0x0000_3b00, "ERROR_MUI_INVALID_ULTIMATEFALLBACK_NAME", "The RC Manifest has invalid ultimatefallback name."),
+	(0x0000_3b01, "ERROR_MUI_FILE_NOT_LOADED", "The resource loader cache doesn't have loaded MUI entry."),
+	(0x0000_3b02, "ERROR_RESOURCE_ENUM_USER_STOP", "User stopped resource enumeration."),
+	(0x0000_3b03, "ERROR_MUI_INTLSETTINGS_UILANG_NOT_INSTALLED", "UI language installation failed."),
+	(0x0000_3b04, "ERROR_MUI_INTLSETTINGS_INVALID_LOCALE_NAME", "Locale installation failed."),
+	(0x0000_3b06, "ERROR_MRM_RUNTIME_NO_DEFAULT_OR_NEUTRAL_RESOURCE", "A resource does not have default or neutral value."),
+	(0x0000_3b07, "ERROR_MRM_INVALID_PRICONFIG", "Invalid PRI config file."),
+	(0x0000_3b08, "ERROR_MRM_INVALID_FILE_TYPE", "Invalid file type."),
+	(0x0000_3b09, "ERROR_MRM_UNKNOWN_QUALIFIER", "Unknown qualifier."),
+	(0x0000_3b0a, "ERROR_MRM_INVALID_QUALIFIER_VALUE", "Invalid qualifier value."),
+	(0x0000_3b0b, "ERROR_MRM_NO_CANDIDATE", "No Candidate found."),
+	(0x0000_3b0c, "ERROR_MRM_NO_MATCH_OR_DEFAULT_CANDIDATE", "The ResourceMap or NamedResource has an item that does not have default or neutral resource.."),
+	(0x0000_3b0d, "ERROR_MRM_RESOURCE_TYPE_MISMATCH", "Invalid ResourceCandidate type."),
+	(0x0000_3b0e, "ERROR_MRM_DUPLICATE_MAP_NAME", "Duplicate Resource Map."),
+	(0x0000_3b0f, "ERROR_MRM_DUPLICATE_ENTRY", "Duplicate Entry."),
+	(0x0000_3b10, "ERROR_MRM_INVALID_RESOURCE_IDENTIFIER", "Invalid Resource Identifier."),
+	(0x0000_3b11, "ERROR_MRM_FILEPATH_TOO_LONG", "Filepath too long."),
+	(0x0000_3b12, "ERROR_MRM_UNSUPPORTED_DIRECTORY_TYPE", "Unsupported directory type."),
+	(0x0000_3b16, "ERROR_MRM_INVALID_PRI_FILE", "Invalid PRI File."),
+	(0x0000_3b17, "ERROR_MRM_NAMED_RESOURCE_NOT_FOUND", "NamedResource Not Found."),
+	(0x0000_3b1f, "ERROR_MRM_MAP_NOT_FOUND", "ResourceMap Not Found."),
+	(0x0000_3b20, "ERROR_MRM_UNSUPPORTED_PROFILE_TYPE", "Unsupported MRT profile type."),
+	(0x0000_3b21, "ERROR_MRM_INVALID_QUALIFIER_OPERATOR", "Invalid qualifier operator."),
+	(0x0000_3b22, "ERROR_MRM_INDETERMINATE_QUALIFIER_VALUE", "Unable to determine qualifier value or qualifier value has not been set."),
+	(0x0000_3b23, "ERROR_MRM_AUTOMERGE_ENABLED", "Automerge is enabled in the PRI file."),
+	(0x0000_3b24, "ERROR_MRM_TOO_MANY_RESOURCES", "Too many resources defined for package."),
+	(0x0000_3b25, "ERROR_MRM_UNSUPPORTED_FILE_TYPE_FOR_MERGE", "Resource File can not be used for merge operation."),
+	(0x0000_3b26, "ERROR_MRM_UNSUPPORTED_FILE_TYPE_FOR_LOAD_UNLOAD_PRI_FILE", "Load/UnloadPriFiles cannot be used with resource packages."),
+	(0x0000_3b27, "ERROR_MRM_NO_CURRENT_VIEW_ON_THREAD", "Resource Contexts may not be created on threads that do not have a CoreWindow."),
+	(0x0000_3b28, "ERROR_DIFFERENT_PROFILE_RESOURCE_MANAGER_EXIST", "The singleton Resource Manager with different profile is already created."),
+	(0x0000_3b29, "ERROR_OPERATION_NOT_ALLOWED_FROM_SYSTEM_COMPONENT", "The system component cannot operate given API operation."),
+	(0x0000_3b2a, "ERROR_MRM_DIRECT_REF_TO_NON_DEFAULT_RESOURCE", "The resource is a direct reference to a non-default resource candidate."),
+	(0x0000_3b2b, "ERROR_MRM_GENERATION_COUNT_MISMATCH", "Resource Map has been re-generated and the query string is not valid anymore."),
+	(0x0000_3b2c, "ERROR_PRI_MERGE_VERSION_MISMATCH", "The PRI files to be merged have incompatible versions."),
+	(0x0000_3b2d, "ERROR_PRI_MERGE_MISSING_SCHEMA", "The primary PRI files to be merged does not contain a schema."),
+	(0x0000_3b2e, "ERROR_PRI_MERGE_LOAD_FILE_FAILED", "Unable to load one of the PRI files to be merged."),
+	(0x0000_3b2f, "ERROR_PRI_MERGE_ADD_FILE_FAILED", "Unable to add one of the PRI files to the merged file."),
+	(0x0000_3b30, "ERROR_PRI_MERGE_WRITE_FILE_FAILED", "Unable to create the merged PRI file."),
+	(0x0000_3b31, "ERROR_PRI_MERGE_MULTIPLE_PACKAGE_FAMILIES_NOT_ALLOWED", "Packages for a PRI file merge must all be from the same package family."),
+	(0x0000_3b32, "ERROR_PRI_MERGE_MULTIPLE_MAIN_PACKAGES_NOT_ALLOWED", "Packages for a PRI file merge must not include multiple main packages."),
+	(0x0000_3b33, "ERROR_PRI_MERGE_BUNDLE_PACKAGES_NOT_ALLOWED", "Packages for a PRI file merge must not include bundle packages."),
+	(0x0000_3b34, "ERROR_PRI_MERGE_MAIN_PACKAGE_REQUIRED", "Packages for a PRI file merge must include one main package."),
+	(0x0000_3b35, "ERROR_PRI_MERGE_RESOURCE_PACKAGE_REQUIRED", "Packages for a PRI file merge must include at least one resource package."),
+	(0x0000_3b36, "ERROR_PRI_MERGE_INVALID_FILE_NAME", "Invalid name supplied for a canonical merged PRI file."),
+	(0x0000_3b37, "ERROR_MRM_PACKAGE_NOT_FOUND", "Unable to find the specified package."),
+	(0x0000_3b38, "ERROR_MRM_MISSING_DEFAULT_LANGUAGE", "No default value for language was specified."),
+	(0x0000_3b60, "ERROR_MCA_INVALID_CAPABILITIES_STRING", "The monitor returned a DDC/CI capabilities string that did not comply with the ACCESS.bus 3.0, DDC/CI 1.1 or MCCS 2 Revision 1 specification."),
+	(0x0000_3b61, "ERROR_MCA_INVALID_VCP_VERSION", "The monitor's VCP Version (0xDF) VCP code returned an invalid version value."),
+	(0x0000_3b62, "ERROR_MCA_MONITOR_VIOLATES_MCCS_SPECIFICATION", "The monitor does not comply with the MCCS specification it claims to support."),
+	(0x0000_3b63, "ERROR_MCA_MCCS_VERSION_MISMATCH", "The MCCS version in a monitor's mccs_ver capability does not match the MCCS version the monitor reports when the VCP Version (0xDF) VCP code is used."),
+	(0x0000_3b64, "ERROR_MCA_UNSUPPORTED_MCCS_VERSION", "The Monitor Configuration API only works with monitors that support the MCCS 1.0 specification, MCCS 2.0 specification or the MCCS 2.0 Revision 1 specification."),
+	(0x0000_3b65, "ERROR_MCA_INTERNAL_ERROR", "An internal Monitor Configuration API error occurred."),
+	(0x0000_3b66, "ERROR_MCA_INVALID_TECHNOLOGY_TYPE_RETURNED", "The monitor returned an invalid monitor technology type. CRT, Plasma and LCD (TFT) are examples of monitor technology types. This error implies that the monitor violated the MCCS 2.0 or MCCS 2.0 Revision 1 specification."),
+	(0x0000_3b67, "ERROR_MCA_UNSUPPORTED_COLOR_TEMPERATURE", "The caller of SetMonitorColorTemperature specified a color temperature that the current monitor did not support. This error implies that the monitor violated the MCCS 2.0 or MCCS 2.0 Revision 1 specification."),
+	(0x0000_3b92, "ERROR_AMBIGUOUS_SYSTEM_DEVICE", "The requested system device cannot be identified due to multiple indistinguishable devices potentially matching the identification criteria."),
+	(0x0000_3bc3, "ERROR_SYSTEM_DEVICE_NOT_FOUND", "The requested system device cannot be found."),
+	(0x0000_3bc4, "ERROR_HASH_NOT_SUPPORTED", "Hash generation for the specified hash version and hash type is not enabled on the server."),
+	(0x0000_3bc5, "ERROR_HASH_NOT_PRESENT", "The hash requested from the server is not available or no longer valid."),
+	(0x0000_3bd9, "ERROR_SECONDARY_IC_PROVIDER_NOT_REGISTERED", "The secondary interrupt controller instance that manages the specified interrupt is not registered."),
+	(0x0000_3bda, "ERROR_GPIO_CLIENT_INFORMATION_INVALID", "The information supplied by the GPIO client driver is invalid."),
+	(0x0000_3bdb, "ERROR_GPIO_VERSION_NOT_SUPPORTED", "The version specified by the GPIO client driver is not supported."),
+	(0x0000_3bdc, "ERROR_GPIO_INVALID_REGISTRATION_PACKET", "The registration packet supplied by the GPIO client driver is not valid."),
+	(0x0000_3bdd, "ERROR_GPIO_OPERATION_DENIED", "The requested operation is not supported for the specified handle."),
+	(0x0000_3bde, "ERROR_GPIO_INCOMPATIBLE_CONNECT_MODE", "The requested connect mode conflicts with an existing mode on one or more of the specified pins."),
+	(0x0000_3bdf, "ERROR_GPIO_INTERRUPT_ALREADY_UNMASKED", "The interrupt requested to be unmasked is not masked."),
+	(0x0000_3c28, "ERROR_CANNOT_SWITCH_RUNLEVEL", "The requested run level switch cannot be completed successfully."),
+	(0x0000_3c29, "ERROR_INVALID_RUNLEVEL_SETTING", "The service has an invalid run level setting. The run level for a service must not be higher than the run level of its dependent services."),
+	(0x0000_3c2a, "ERROR_RUNLEVEL_SWITCH_TIMEOUT", "The requested run level switch cannot be completed successfully since one or more services will not stop or restart within the specified timeout."),
+	(0x0000_3c2b, "ERROR_RUNLEVEL_SWITCH_AGENT_TIMEOUT", "A run level switch agent did not respond within the specified timeout."),
+	(0x0000_3c2c, "ERROR_RUNLEVEL_SWITCH_IN_PROGRESS", "A run level switch is currently in progress."),
+	(0x0000_3c2d, "ERROR_SERVICES_FAILED_AUTOSTART", "One or more services failed to start during the service startup phase of a run level switch."),
+	(0x0000_3c8d, "ERROR_COM_TASK_STOP_PENDING", "The task stop request cannot be completed immediately since task needs more time to shutdown."),
+	(0x0000_3cf0, "ERROR_INSTALL_OPEN_PACKAGE_FAILED", "Package could not be opened."),
+	(0x0000_3cf1, "ERROR_INSTALL_PACKAGE_NOT_FOUND", "Package was not found."),
+	(0x0000_3cf2, "ERROR_INSTALL_INVALID_PACKAGE", "Package data is invalid."),
+	(0x0000_3cf3, "ERROR_INSTALL_RESOLVE_DEPENDENCY_FAILED", "Package failed updates, dependency or conflict validation."),
+	(0x0000_3cf4, "ERROR_INSTALL_OUT_OF_DISK_SPACE", "There is not enough disk space on your computer. Please free up some space and try again."),
+	(0x0000_3cf5, "ERROR_INSTALL_NETWORK_FAILURE", "There was a problem downloading your product."),
+	(0x0000_3cf6, "ERROR_INSTALL_REGISTRATION_FAILURE", "Package could not be registered."),
+	(0x0000_3cf7, "ERROR_INSTALL_DEREGISTRATION_FAILURE", "Package could not be unregistered."),
+	(0x0000_3cf8, "ERROR_INSTALL_CANCEL", "User cancelled the install request."),
+	(0x0000_3cf9, "ERROR_INSTALL_FAILED", "Install failed. Please contact your software vendor."),
+	(0x0000_3cfa, "ERROR_REMOVE_FAILED", "Removal failed. Please contact your software vendor."),
+	(0x0000_3cfb, "ERROR_PACKAGE_ALREADY_EXISTS", "The provided package is already installed, and reinstallation of the package was blocked. Check the AppXDeployment-Server event log for details."),
+	(0x0000_3cfc, "ERROR_NEEDS_REMEDIATION", "The application cannot be started. Try reinstalling the application to fix the problem."),
+	(0x0000_3cfd, "ERROR_INSTALL_PREREQUISITE_FAILED", "A Prerequisite for an install could not be satisfied."),
+	(0x0000_3cfe, "ERROR_PACKAGE_REPOSITORY_CORRUPTED", "The package repository is corrupted."),
+	(0x0000_3cff, "ERROR_INSTALL_POLICY_FAILURE", "To install this application you need either a Windows developer license or a sideloading-enabled system."),
+	(0x0000_3d00, "ERROR_PACKAGE_UPDATING", "The application cannot be started because it is currently updating."),
+	(0x0000_3d01, "ERROR_DEPLOYMENT_BLOCKED_BY_POLICY", "The package deployment operation is blocked by policy. Please contact your system administrator."),
+	(0x0000_3d02, "ERROR_PACKAGES_IN_USE", "The package could not be installed because resources it modifies are currently in use."),
+	(0x0000_3d03, "ERROR_RECOVERY_FILE_CORRUPT", "The package could not be recovered because necessary data for recovery have been corrupted."),
+	(0x0000_3d04, "ERROR_INVALID_STAGED_SIGNATURE", "The signature is invalid. To register in developer mode, AppxSignature.p7x and AppxBlockMap.xml must be valid or should not be present."),
+	(0x0000_3d05, "ERROR_DELETING_EXISTING_APPLICATIONDATA_STORE_FAILED", "An error occurred while deleting the package's previously existing application data."),
+	(0x0000_3d06, "ERROR_INSTALL_PACKAGE_DOWNGRADE", "The package could not be installed because a higher version of this package is already installed."),
+	(0x0000_3d07, "ERROR_SYSTEM_NEEDS_REMEDIATION", "An error in a system binary was detected. Try refreshing the PC to fix the problem."),
+	(0x0000_3d08, "ERROR_APPX_INTEGRITY_FAILURE_CLR_NGEN", "A corrupted CLR NGEN binary was detected on the system."),
+	(0x0000_3d09, "ERROR_RESILIENCY_FILE_CORRUPT", "The operation could not be resumed because necessary data for recovery have been corrupted."),
+	(0x0000_3d0a, "ERROR_INSTALL_FIREWALL_SERVICE_NOT_RUNNING", "The package could not be installed because the Windows Firewall service is not running. Enable the Windows Firewall service and try again."),
+	(0x0000_3d0b, "ERROR_PACKAGE_MOVE_FAILED", "Package move failed."),
+	(0x0000_3d0c, "ERROR_INSTALL_VOLUME_NOT_EMPTY", "The deployment operation failed because the volume is not empty."),
+	(0x0000_3d0d, "ERROR_INSTALL_VOLUME_OFFLINE", "The deployment operation failed because the volume is offline. For a package update, the volume refers to the installed volume of all package versions."),
+	(0x0000_3d0e, "ERROR_INSTALL_VOLUME_CORRUPT", "The deployment operation failed because the specified volume is corrupt."),
+	(0x0000_3d0f, "ERROR_NEEDS_REGISTRATION", "The deployment operation failed because the specified application needs to be registered first."),
+	(0x0000_3d10, "ERROR_INSTALL_WRONG_PROCESSOR_ARCHITECTURE", "The deployment operation failed because the package targets the wrong processor architecture."),
+	(0x0000_3d11, "ERROR_DEV_SIDELOAD_LIMIT_EXCEEDED", "You have reached the maximum number of developer sideloaded packages allowed on this device. Please uninstall a sideloaded package and try again."),
+	(0x0000_3d12, "ERROR_INSTALL_OPTIONAL_PACKAGE_REQUIRES_MAIN_PACKAGE", "A main app package is required to install this optional package. Install the main package first and try again."),
+	(0x0000_3d13, "ERROR_PACKAGE_NOT_SUPPORTED_ON_FILESYSTEM", "This app package type is not supported on this filesystem."),
+	(0x0000_3d14, "ERROR_PACKAGE_MOVE_BLOCKED_BY_STREAMING", "Package move operation is blocked until the application has finished streaming."),
+	(0x0000_3d15, "ERROR_INSTALL_OPTIONAL_PACKAGE_APPLICATIONID_NOT_UNIQUE", "A main or another optional app package has the same application ID as this optional package. Change the application ID for the optional package to avoid conflicts."),
+	(0x0000_3d16, "ERROR_PACKAGE_STAGING_ONHOLD", "This staging session has been held to allow another staging operation to be prioritized."),
+	(0x0000_3d17, "ERROR_INSTALL_INVALID_RELATED_SET_UPDATE", "A related set cannot be updated because the updated set is invalid. All packages in the related set must be updated at the same time."),
+	(0x0000_3d18, "ERROR_INSTALL_OPTIONAL_PACKAGE_REQUIRES_MAIN_PACKAGE_FULLTRUST_CAPABILITY", "An optional package with a FullTrust entry point requires the main package to have the runFullTrust capability."),
+	(0x0000_3d19, "ERROR_DEPLOYMENT_BLOCKED_BY_USER_LOG_OFF", "An error occurred because a user was logged off."),
+	(0x0000_3d1a, "ERROR_PROVISION_OPTIONAL_PACKAGE_REQUIRES_MAIN_PACKAGE_PROVISIONED", "An optional package provision requires the dependency main package to also be provisioned."),
+	(0x0000_3d1b, "ERROR_PACKAGES_REPUTATION_CHECK_FAILED", "The packages failed the SmartScreen reputation check."),
+	(0x0000_3d1c, "ERROR_PACKAGES_REPUTATION_CHECK_TIMEDOUT", "The SmartScreen reputation check operation timed out."),
+	(0x0000_3d1d, "ERROR_DEPLOYMENT_OPTION_NOT_SUPPORTED", "The current deployment option is not supported."),
+	(0x0000_3d1e, "ERROR_APPINSTALLER_ACTIVATION_BLOCKED", "Activation is blocked due to the .appinstaller update settings for this app."),
+	(0x0000_3d1f, "ERROR_REGISTRATION_FROM_REMOTE_DRIVE_NOT_SUPPORTED", "Remote drives are not supported; use \\\\server\\share to register a remote package."),
+	(0x0000_3d20, "ERROR_APPX_RAW_DATA_WRITE_FAILED", "Failed to process and write downloaded APPX package data to disk."),
+	(0x0000_3d21, "ERROR_DEPLOYMENT_BLOCKED_BY_VOLUME_POLICY_PACKAGE", "The deployment operation was blocked due to a per-package-family policy restricting deployments on a non-system volume. Per policy, this app must be installed to the system drive, but that's not set as the default. In Storage Settings, make the system drive the default location to save new content, then retry the install."),
+	(0x0000_3d22, "ERROR_DEPLOYMENT_BLOCKED_BY_VOLUME_POLICY_MACHINE", "The deployment operation was blocked due to a machine-wide policy restricting deployments on a non-system volume. Per policy, this app must be installed to the system drive, but that's not set as the default. In Storage Settings, make the system drive the default location to save new content, then retry the install."),
+	(0x0000_3d23, "ERROR_DEPLOYMENT_BLOCKED_BY_PROFILE_POLICY", "The deployment operation was blocked because Special profile deployment is not allowed. Please try logging into an account that is not a Special profile. You can try logging out and logging back into the current account, or try logging into a different account."),
+	(0x0000_3d24, "ERROR_DEPLOYMENT_FAILED_CONFLICTING_MUTABLE_PACKAGE_DIRECTORY", "The deployment operation failed due to a conflicting package's mutable package directory. To install this package remove the existing package with the conflicting mutable package directory."),
+	(0x0000_3d25, "ERROR_SINGLETON_RESOURCE_INSTALLED_IN_ACTIVE_USER", "The package installation failed because a singleton resource was specified and another user with that package installed is logged in. Please make sure that all active users with the package installed are logged out and retry installation."),
+	(0x0000_3d26, "ERROR_DIFFERENT_VERSION_OF_PACKAGED_SERVICE_INSTALLED", "The package installation failed because a different version of the service is installed. Try installing a newer version of the package."),
+	(0x0000_3d27, "ERROR_SERVICE_EXISTS_AS_NON_PACKAGED_SERVICE", "The package installation failed because a version of the service exists outside of APPX packaging. Please contact your software vendor."),
+	(0x0000_3d28, "ERROR_PACKAGED_SERVICE_REQUIRES_ADMIN_PRIVILEGES", "The package installation failed because administrator privileges are required. Please contact an administrator to install this package."),
+	(0x0000_3db8, "ERROR_STATE_LOAD_STORE_FAILED", "State Manager failed to load the application data store."),
+	(0x0000_3db9, "ERROR_STATE_GET_VERSION_FAILED", "State Manager failed to retrieve the version of the application data."),
+	(0x0000_3dba, "ERROR_STATE_SET_VERSION_FAILED", "State Manager failed to set the version of the application data."),
+	(0x0000_3dbb, "ERROR_STATE_STRUCTURED_RESET_FAILED", "State Manager failed to reset the structured application data."),
+	(0x0000_3dbc, "ERROR_STATE_OPEN_CONTAINER_FAILED", "State Manager failed to open the container."),
+	(0x0000_3dbd, "ERROR_STATE_CREATE_CONTAINER_FAILED", "State Manager failed to create the container."),
+	(0x0000_3dbe, "ERROR_STATE_DELETE_CONTAINER_FAILED", "State Manager failed to delete the container."),
+	(0x0000_3dbf, "ERROR_STATE_READ_SETTING_FAILED", "State Manager failed to read the setting."),
+	(0x0000_3dc0, "ERROR_STATE_WRITE_SETTING_FAILED", "State Manager failed to write the setting."),
+	(0x0000_3dc1, "ERROR_STATE_DELETE_SETTING_FAILED", "State Manager failed to delete the setting."),
+	(0x0000_3dc2, "ERROR_STATE_QUERY_SETTING_FAILED", "State Manager failed to query the setting."),
+	(0x0000_3dc3, "ERROR_STATE_READ_COMPOSITE_SETTING_FAILED", "State Manager failed to read the composite setting."),
+	(0x0000_3dc4, "ERROR_STATE_WRITE_COMPOSITE_SETTING_FAILED", "State Manager failed to write the composite setting."),
+	(0x0000_3dc5, "ERROR_STATE_ENUMERATE_CONTAINER_FAILED", "State Manager failed to enumerate the containers."),
+	(0x0000_3dc6, "ERROR_STATE_ENUMERATE_SETTINGS_FAILED", "State Manager failed to enumerate the settings."),
+	(0x0000_3dc7, "ERROR_STATE_COMPOSITE_SETTING_VALUE_SIZE_LIMIT_EXCEEDED", "The size of the state manager composite setting value has exceeded the limit."),
+	(0x0000_3dc8, "ERROR_STATE_SETTING_VALUE_SIZE_LIMIT_EXCEEDED", "The size of the state manager setting value has exceeded the limit."),
+	(0x0000_3dc9, "ERROR_STATE_SETTING_NAME_SIZE_LIMIT_EXCEEDED", "The length of the state manager setting name has exceeded the limit."),
+	(0x0000_3dca, "ERROR_STATE_CONTAINER_NAME_SIZE_LIMIT_EXCEEDED", "The length of the state manager container name has exceeded the limit."),
+	(0x0000_3de1, "ERROR_API_UNAVAILABLE", "This API cannot be used in the context of the caller's application type."),
+];
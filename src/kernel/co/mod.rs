@@ -1,5 +1,6 @@
 mod consts;
 mod error;
+#[cfg(feature = "error_messages")] pub(crate) mod error_msg;
 
 pub use consts::*;
 pub use error::*;
@@ -1,6 +1,7 @@
 use crate::co;
 use crate::decl::*;
 use crate::kernel::privs::*;
+use crate::prelude::*;
 
 /// Variable parameter for:
 ///
@@ -12,6 +13,26 @@ pub enum DisabPriv<'a> {
 	Privs(&'a TOKEN_PRIVILEGES)
 }
 
+/// String format of a [`GUID`](crate::GUID).
+///
+/// Variable parameter for:
+///
+/// * [`GUID::to_string_fmt`](crate::GUID::to_string_fmt).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GuidFmt {
+	/// `{43826d1e-e718-42ee-bc55-a1e261c37bfe}`, used by the registry and by
+	/// COM.
+	Braced,
+	/// `43826d1e-e718-42ee-bc55-a1e261c37bfe`.
+	Hyphenated,
+	/// `(43826d1e-e718-42ee-bc55-a1e261c37bfe)`.
+	Parenthesized,
+	/// `43826d1ee71842eebc55a1e261c37bfe`.
+	Simple,
+	/// `urn:uuid:43826d1e-e718-42ee-bc55-a1e261c37bfe`.
+	Urn,
+}
+
 /// A resource identifier.
 ///
 /// Variable parameter for:
@@ -26,7 +47,7 @@ pub enum DisabPriv<'a> {
 /// * [`HUPDATERSRC::UpdateResource`](crate::prelude::kernel_Hupdatersrc::UpdateResource);
 /// * [`BmpIdbRes`](crate::BmpIdbRes);
 /// * [`ResStrs`](crate::ResStrs).
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum IdStr {
 	/// A resource ID.
 	Id(u16),
@@ -74,12 +95,21 @@ impl IdStr {
 ///
 /// This is a high-level abstraction over the [`co::REG`](crate::co::REG)
 /// constants, plus the value they carry.
-#[derive(Clone, Debug)]
+///
+/// Conversion to and from the raw bytes, as stored in the registry, is
+/// lossless: data which is not in the canonical encoding of its type – like a
+/// string without the terminating null – is kept as
+/// [`RegistryValue::Unknown`](crate::RegistryValue::Unknown), so writing back
+/// a retrieved value produces the very same bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum RegistryValue {
 	/// Binary value, defined as [`REG::BINARY`](crate::co::REG::BINARY).
 	Binary(Vec<u8>),
 	/// An `u32` integer value, defined as [`REG::DWORD`](crate::co::REG::DWORD).
 	Dword(u32),
+	/// An `u32` integer value stored in big-endian format, defined as
+	/// [`REG::DWORD_BIG_ENDIAN`](crate::co::REG::DWORD_BIG_ENDIAN).
+	DwordBigEndian(u32),
 	/// An `u64` integer value, defined as [`REG::QWORD`](crate::co::REG::QWORD).
 	Qword(u64),
 	/// String value, defined as [`REG::SZ`](crate::co::REG::SZ).
@@ -89,28 +119,48 @@ pub enum RegistryValue {
 	/// references, use
 	/// [`ExpandEnvironmentStrings`](crate::ExpandEnvironmentStrings).
 	ExpandSz(String),
+	/// Symbolic link to another registry key, defined as
+	/// [`REG::LINK`](crate::co::REG::LINK). Unlike other strings, it's stored
+	/// without a terminating null.
+	Link(String),
 	/// Multiple strings, defined as [`REG::MULTI_SZ`](crate::co::REG::MULTI_SZ).
 	MultiSz(Vec<String>),
-	/// No value, defined as [`REG::NONE`](crate::co::REG::NONE). Also used for
-	/// non-implemented value types.
+	/// Resource list in the resource map, defined as
+	/// [`REG::RESOURCE_LIST`](crate::co::REG::RESOURCE_LIST).
+	ResourceList(Vec<u8>),
+	/// Resource list in the hardware description, defined as
+	/// [`REG::FULL_RESOURCE_DESCRIPTOR`](crate::co::REG::FULL_RESOURCE_DESCRIPTOR).
+	FullResourceDescriptor(Vec<u8>),
+	/// Resource list in the hardware description, defined as
+	/// [`REG::RESOURCE_REQUIREMENTS_LIST`](crate::co::REG::RESOURCE_REQUIREMENTS_LIST).
+	ResourceRequirementsList(Vec<u8>),
+	/// No value, defined as [`REG::NONE`](crate::co::REG::NONE).
 	None,
+	/// A value of an unknown type, or whose data is not in the canonical
+	/// encoding of its type, kept as raw bytes.
+	Unknown {
+		/// The value type.
+		kind: co::REG,
+		/// The raw bytes.
+		data: Vec<u8>,
+	},
 }
 
 impl std::fmt::Display for RegistryValue {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let hex = |b: &[u8]| b.iter()
+			.map(|n| format!("{:02x}", *n))
+			.collect::<Vec<_>>()
+			.join(" ");
+
 		match self {
-			Self::Binary(b) => write!(
-				f,
-				"[REG_BINARY] {}",
-				b.iter()
-					.map(|n| format!("{:02}", *n))
-					.collect::<Vec<_>>()
-					.join(" "),
-			),
+			Self::Binary(b) => write!(f, "[REG_BINARY] {}", hex(b)),
 			Self::Dword(n) => write!(f, "[REG_DWORD] {}", *n),
+			Self::DwordBigEndian(n) => write!(f, "[REG_DWORD_BIG_ENDIAN] {}", *n),
 			Self::Qword(n) => write!(f, "[REG_QWORD] {}", *n),
 			Self::Sz(s) => write!(f, "[REG_SZ] \"{}\"", s),
 			Self::ExpandSz(s) => write!(f, "[REG_EXPAND_SZ] \"{}\"", s),
+			Self::Link(s) => write!(f, "[REG_LINK] \"{}\"", s),
 			Self::MultiSz(v) => write!(
 				f,
 				"[REG_MULTI_SZ] {}",
@@ -119,95 +169,123 @@ impl std::fmt::Display for RegistryValue {
 					.collect::<Vec<_>>()
 					.join(", "),
 			),
+			Self::ResourceList(b) => write!(f, "[REG_RESOURCE_LIST] {}", hex(b)),
+			Self::FullResourceDescriptor(b) => write!(f, "[REG_FULL_RESOURCE_DESCRIPTOR] {}", hex(b)),
+			Self::ResourceRequirementsList(b) => write!(f, "[REG_RESOURCE_REQUIREMENTS_LIST] {}", hex(b)),
 			Self::None => write!(f, "[REG_NONE]"),
+			Self::Unknown { kind, data } => match kind.name() {
+				Some(name) => write!(f, "[REG_{}] {}", name, hex(data)),
+				None => write!(f, "[REG {:#x}] {}", kind.raw(), hex(data)),
+			},
 		}
 	}
 }
 
 impl RegistryValue {
-	/// Parses a binary data block as a `RegistryValue`.
+	/// Parses the raw bytes of a value, as stored in the registry.
+	///
+	/// If the bytes are not in the canonical encoding of the type – for
+	/// example, a [`REG::DWORD`](crate::co::REG::DWORD) whose length is not 4,
+	/// or a string without the terminating null – or if the type is unknown,
+	/// [`RegistryValue::Unknown`](crate::RegistryValue::Unknown) is returned.
+	/// This way, [`to_bytes`](crate::RegistryValue::to_bytes) always gives back
+	/// the original bytes.
 	///
-	/// # Safety
+	/// # Examples
 	///
-	/// Assumes the binary data block has the correct content, according to the
-	/// informed [`co::REG`](crate::co::REG).
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
 	///
-	/// This method is used internally by the library, and not intended to be
-	/// used externally.
+	/// let bytes = [0x2a, 0x00, 0x00, 0x00];
+	/// let val = w::RegistryValue::from_bytes(&bytes, co::REG::DWORD);
+	/// assert_eq!(val, w::RegistryValue::Dword(42));
+	/// assert_eq!(val.to_bytes(), bytes);
+	/// ```
 	#[must_use]
-	pub unsafe fn from_raw(buf: Vec<u8>, reg_type: co::REG) -> RegistryValue {
-		match reg_type {
-			co::REG::NONE => RegistryValue::None,
-			co::REG::DWORD => RegistryValue::Dword(
-				u32::from_ne_bytes(unsafe {
-					*std::mem::transmute::<_, *const [u8; 4]>(buf.as_ptr())
+	pub fn from_bytes(bytes: &[u8], reg_type: co::REG) -> RegistryValue {
+		let parsed = match reg_type {
+			co::REG::NONE if bytes.is_empty() => Some(Self::None),
+			co::REG::BINARY => Some(Self::Binary(bytes.to_vec())),
+			co::REG::DWORD => bytes.try_into().ok()
+				.map(|b| Self::Dword(u32::from_le_bytes(b))),
+			co::REG::DWORD_BIG_ENDIAN => bytes.try_into().ok()
+				.map(|b| Self::DwordBigEndian(u32::from_be_bytes(b))),
+			co::REG::QWORD => bytes.try_into().ok()
+				.map(|b| Self::Qword(u64::from_le_bytes(b))),
+			co::REG::SZ => bytes_to_strs(bytes)
+				.filter(|strs| strs.len() == 2 && strs[1].is_empty()) // "text", ""
+				.map(|mut strs| Self::Sz(strs.swap_remove(0))),
+			co::REG::EXPAND_SZ => bytes_to_strs(bytes)
+				.filter(|strs| strs.len() == 2 && strs[1].is_empty())
+				.map(|mut strs| Self::ExpandSz(strs.swap_remove(0))),
+			co::REG::LINK => bytes_to_strs(bytes)
+				.filter(|strs| strs.len() == 1)
+				.map(|mut strs| Self::Link(strs.swap_remove(0))),
+			co::REG::MULTI_SZ => bytes_to_strs(bytes)
+				.filter(|strs| strs.len() >= 2)
+				.map(|mut strs| {
+					strs.truncate(strs.len() - 2); // "", "" of the list terminator
+					strs
 				})
-			),
-			co::REG::QWORD => RegistryValue::Qword(
-				u64::from_ne_bytes(unsafe {
-					*std::mem::transmute::<_, *const [u8; 8]>(buf.as_ptr())
-				})
-			),
-			co::REG::SZ => {
-				let (_, vec16, _) = unsafe { buf.align_to::<u16>() };
-				RegistryValue::Sz(WString::from_wchars_slice(&vec16).to_string())
-			},
-			co::REG::EXPAND_SZ => {
-				let (_, vec16, _) = unsafe { buf.align_to::<u16>() };
-				RegistryValue::Sz(WString::from_wchars_slice(&vec16).to_string())
-			},
-			co::REG::MULTI_SZ => {
-				let (_, vec16, _) = unsafe { buf.align_to::<u16>() };
-				RegistryValue::MultiSz(parse_multi_z_str(vec16.as_ptr()))
-			},
-			co::REG::BINARY => RegistryValue::Binary(buf),
-			_ => RegistryValue::None, // other types not implemented yet
-		}
-	}
+				.filter(|strs| strs.iter().all(|s| !s.is_empty())) // an empty string would end the list
+				.map(Self::MultiSz),
+			co::REG::RESOURCE_LIST => Some(Self::ResourceList(bytes.to_vec())),
+			co::REG::FULL_RESOURCE_DESCRIPTOR => Some(Self::FullResourceDescriptor(bytes.to_vec())),
+			co::REG::RESOURCE_REQUIREMENTS_LIST => Some(Self::ResourceRequirementsList(bytes.to_vec())),
+			_ => None,
+		};
 
-	/// Returns a pointer to the raw data, along with the raw data length.
-	#[must_use]
-	pub fn as_ptr_with_len(&self,
-		str_buf: &mut WString) -> (*const std::ffi::c_void, u32)
-	{
-		match self {
-			Self::Binary(b) => (b.as_ptr() as _, b.len() as _),
-			Self::Dword(n) => (n as *const _ as _, std::mem::size_of::<u32>() as _),
-			Self::Qword(n) => (n as *const _ as _, std::mem::size_of::<u64>() as _),
-			Self::Sz(s) => {
-				*str_buf = WString::from_str(s);
-				Self::as_ptr_with_len_str(&str_buf)
-			},
-			Self::ExpandSz(s) => {
-				*str_buf = WString::from_str(s);
-				Self::as_ptr_with_len_str(&str_buf)
-			},
-			Self::MultiSz(v) => {
-				*str_buf = WString::from_str_vec(v);
-				Self::as_ptr_with_len_str(&str_buf)
-			},
-			Self::None => (std::ptr::null(), 0),
+		// Make sure the conversion is lossless, otherwise keep the raw bytes.
+		match parsed {
+			Some(val) if val.to_bytes() == bytes => val,
+			_ => Self::Unknown { kind: reg_type, data: bytes.to_vec() },
 		}
 	}
 
-	fn as_ptr_with_len_str(str_buf: &WString) -> (*const std::ffi::c_void, u32) {
-		(
-			str_buf.as_ptr() as _,
-			(str_buf.buf_len() * std::mem::size_of::<u16>()) as _, // will include terminating null
-		)
-	}
-
 	/// Returns the correspondent [`co::REG`](crate::co::REG) constant.
 	#[must_use]
 	pub const fn reg_type(&self) -> co::REG {
 		match self {
 			Self::Binary(_) => co::REG::BINARY,
 			Self::Dword(_) => co::REG::DWORD,
+			Self::DwordBigEndian(_) => co::REG::DWORD_BIG_ENDIAN,
 			Self::Qword(_) => co::REG::QWORD,
 			Self::Sz(_) => co::REG::SZ,
 			Self::ExpandSz(_) => co::REG::EXPAND_SZ,
+			Self::Link(_) => co::REG::LINK,
 			Self::MultiSz(_) => co::REG::MULTI_SZ,
+			Self::ResourceList(_) => co::REG::RESOURCE_LIST,
+			Self::FullResourceDescriptor(_) => co::REG::FULL_RESOURCE_DESCRIPTOR,
+			Self::ResourceRequirementsList(_) => co::REG::RESOURCE_REQUIREMENTS_LIST,
 			Self::None => co::REG::NONE,
+			Self::Unknown { kind, .. } => *kind,
+		}
+	}
+
+	/// Returns the raw bytes of the value, as stored in the registry.
+	///
+	/// Strings are encoded in UTF-16 little-endian with a terminating null,
+	/// except [`RegistryValue::Link`](crate::RegistryValue::Link). Multiple
+	/// strings are followed by an additional null.
+	#[must_use]
+	pub fn to_bytes(&self) -> Vec<u8> {
+		match self {
+			Self::Binary(b)
+				| Self::ResourceList(b)
+				| Self::FullResourceDescriptor(b)
+				| Self::ResourceRequirementsList(b)
+				| Self::Unknown { data: b, .. } => b.clone(),
+			Self::Dword(n) => n.to_le_bytes().to_vec(),
+			Self::DwordBigEndian(n) => n.to_be_bytes().to_vec(),
+			Self::Qword(n) => n.to_le_bytes().to_vec(),
+			Self::Sz(s) | Self::ExpandSz(s) => strs_to_bytes(&[s, ""]),
+			Self::Link(s) => strs_to_bytes(&[s]),
+			Self::MultiSz(v) => {
+				let mut strs = v.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+				strs.extend_from_slice(&["", ""]); // list terminator
+				strs_to_bytes(&strs)
+			},
+			Self::None => Vec::default(),
 		}
 	}
 }
@@ -222,7 +300,7 @@ impl RegistryValue {
 /// * [`HINSTANCE::FindResource`](crate::prelude::kernel_Hinstance::FindResource);
 /// * [`HINSTANCE::FindResourceEx`](crate::prelude::kernel_Hinstance::FindResourceEx);
 /// * [`HUPDATERSRC`](crate::prelude::kernel_Hupdatersrc::UpdateResource).
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum RtStr {
 	/// A predefined resource ID.
 	Rt(co::RT),
@@ -265,3 +343,43 @@ impl RtStr {
 		}
 	}
 }
+
+/// How to handle chars which cannot be represented in the target encoding.
+///
+/// Variable parameter for:
+///
+/// * [`WString::serialize`](crate::WString::serialize).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum UnmappableChar {
+	/// Fails with
+	/// [`co::ERROR::NO_UNICODE_TRANSLATION`](crate::co::ERROR::NO_UNICODE_TRANSLATION).
+	Fail,
+	/// Writes the given byte instead, usually `b'?'`.
+	Replace(u8),
+	/// Skips the char.
+	Skip,
+}
+
+//------------------------------------------------------------------------------
+
+/// Decodes UTF-16 little-endian bytes, splitting the strings at each null.
+/// Returns `None` if the bytes are not valid UTF-16.
+fn bytes_to_strs(bytes: &[u8]) -> Option<Vec<String>> {
+	if bytes.len() % 2 != 0 {
+		return None;
+	}
+	let chars = bytes.chunks_exact(2)
+		.map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+		.collect::<Vec<_>>();
+	chars.split(|ch| *ch == 0)
+		.map(|s| String::from_utf16(s).ok())
+		.collect()
+}
+
+/// Encodes the strings as UTF-16 little-endian bytes, joined by nulls.
+fn strs_to_bytes(strs: &[&str]) -> Vec<u8> {
+	strs.join("\0")
+		.encode_utf16()
+		.flat_map(|ch| ch.to_le_bytes())
+		.collect()
+}
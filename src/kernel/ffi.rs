@@ -90,6 +90,8 @@ extern_sys! { "kernel32";
 	CreateThread(PVOID, usize, PVOID, PVOID, u32, *mut u32) -> HANDLE
 	CreateToolhelp32Snapshot(u32, u32) -> HANDLE
 	DeleteFileW(PCSTR) -> BOOL
+	DeleteProcThreadAttributeList(PVOID)
+	DuplicateHandle(HANDLE, HANDLE, HANDLE, *mut HANDLE, u32, BOOL, u32) -> BOOL
 	EndUpdateResourceW(HANDLE, BOOL) -> BOOL
 	EnumResourceLanguagesW(HANDLE, PCSTR, PCSTR, PFUNC, isize) -> BOOL
 	EnumResourceNamesW(HANDLE, PCSTR, PFUNC, isize) -> BOOL
@@ -184,6 +186,7 @@ extern_sys! { "kernel32";
 	HeapUnlock(HANDLE) -> BOOL
 	HeapValidate(HANDLE, u32, PVOID) -> BOOL
 	HeapWalk(HANDLE, PVOID) -> BOOL
+	InitializeProcThreadAttributeList(PVOID, u32, u32, *mut usize) -> BOOL
 	IsDebuggerPresent() -> BOOL
 	IsNativeVhdBoot(*mut BOOL) -> BOOL
 	IsProcessCritical(HANDLE, *mut BOOL) -> BOOL
@@ -245,6 +248,7 @@ extern_sys! { "kernel32";
 	Thread32Next(HANDLE, PVOID) -> BOOL
 	UnlockFile(HANDLE, u32, u32, u32, u32) -> BOOL
 	UnmapViewOfFile(PCVOID) -> BOOL
+	UpdateProcThreadAttribute(PVOID, u32, usize, PCVOID, usize, PVOID, *mut usize) -> BOOL
 	UpdateResourceW(HANDLE, PCSTR, PCSTR, u16, PVOID, u32) -> BOOL
 	VerifyVersionInfoW(PVOID, u32, u64) -> BOOL
 	VerSetConditionMask(u64, u32, u8) -> u64
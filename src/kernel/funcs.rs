@@ -1,7 +1,5 @@
 #![allow(non_snake_case)]
 
-use std::collections::HashMap;
-
 use crate::co;
 use crate::decl::*;
 use crate::guard::*;
@@ -529,7 +527,7 @@ pub fn GetDiskSpaceInformation(
 /// [`GetEnvironmentStrings`](https://learn.microsoft.com/en-us/windows/win32/api/processenv/nf-processenv-getenvironmentstringsw)
 /// function.
 ///
-/// Returns the parsed strings, and automatically frees the retrieved
+/// Returns the parsed block, and automatically frees the retrieved
 /// environment block with
 /// [`FreeEnvironmentStrings`](https://learn.microsoft.com/en-us/windows/win32/api/processenv/nf-processenv-freeenvironmentstringsw).
 ///
@@ -547,20 +545,17 @@ pub fn GetDiskSpaceInformation(
 /// # Ok::<_, winsafe::co::ERROR>(())
 /// ```
 #[must_use]
-pub fn GetEnvironmentStrings() -> SysResult<HashMap<String, String>> {
-	ptr_to_sysresult(unsafe { ffi::GetEnvironmentStringsW() } as _)
-		.map(|ptr| {
-			let vec_env_strs = parse_multi_z_str(ptr as *mut _ as _);
-			unsafe { ffi::FreeEnvironmentStringsW(ptr); }
-			vec_env_strs.iter()
-				.map(|env_str| {
-					let mut pair = env_str.split("="); // assumes correctly formatted pairs
-					let key = pair.next().unwrap();
-					let val = pair.next().unwrap();
-					(key.to_owned(), val.to_owned())
-				})
-				.collect()
-		})
+pub fn GetEnvironmentStrings() -> SysResult<EnvironmentBlock> {
+	let ptr = ptr_to_sysresult(unsafe { ffi::GetEnvironmentStringsW() } as _)?;
+	let src = ptr as *const u16;
+	let mut len = 0;
+	while unsafe { *src.add(len) } != 0 || unsafe { *src.add(len + 1) } != 0 {
+		len += 1; // find the double null
+	}
+	let block = EnvironmentBlock::parse(
+		unsafe { std::slice::from_raw_parts(src, len + 1) });
+	unsafe { ffi::FreeEnvironmentStringsW(ptr); }
+	block
 }
 
 /// [`GetFirmwareType`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-getfirmwaretype)
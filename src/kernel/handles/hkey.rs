@@ -343,8 +343,7 @@ pub trait kernel_Hkey: Handle {
 	/// function.
 	///
 	/// Note that this method validates some race conditions, returning
-	/// [`co::ERROR::TRANSACTION_REQUEST_NOT_VALID`](crate::co::ERROR::TRANSACTION_REQUEST_NOT_VALID)
-	/// and [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA).
+	/// [`co::ERROR::TRANSACTION_REQUEST_NOT_VALID`](crate::co::ERROR::TRANSACTION_REQUEST_NOT_VALID).
 	///
 	/// # Examples
 	///
@@ -378,6 +377,7 @@ pub trait kernel_Hkey: Handle {
 	///         println!("");
 	///     },
 	///     w::RegistryValue::None => println!("No value"),
+	///     other => println!("Other: {}", other),
 	/// }
 	/// # Ok::<_, winsafe::co::ERROR>(())
 	/// ```
@@ -641,6 +641,7 @@ pub trait kernel_Hkey: Handle {
 	///             println!("");
 	///         },
 	///         w::RegistryValue::None => println!("No value"),
+	///         other => println!("Other: {}", other),
 	///     }
 	/// }
 	///
@@ -710,12 +711,10 @@ pub trait kernel_Hkey: Handle {
 
 		Ok(
 			valents2.iter() // first VALENT array is not filled with len/type values
-				.map(|v2| unsafe {
-					RegistryValue::from_raw(
-						v2.buf_projection(&buf).to_vec(),
-						v2.ve_type,
-					)
-				})
+				.map(|v2| RegistryValue::from_bytes(
+					unsafe { v2.buf_projection(&buf) },
+					v2.ve_type,
+				))
 				.collect::<Vec<_>>()
 		)
 	}
@@ -737,8 +736,7 @@ pub trait kernel_Hkey: Handle {
 	/// [`HKEY::RegQueryMultipleValues`](crate::prelude::kernel_Hkey::RegQueryMultipleValues).
 	///
 	/// Note that this method validates some race conditions, returning
-	/// [`co::ERROR::TRANSACTION_REQUEST_NOT_VALID`](crate::co::ERROR::TRANSACTION_REQUEST_NOT_VALID)
-	/// and [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA).
+	/// [`co::ERROR::TRANSACTION_REQUEST_NOT_VALID`](crate::co::ERROR::TRANSACTION_REQUEST_NOT_VALID).
 	///
 	/// # Examples
 	///
@@ -775,6 +773,7 @@ pub trait kernel_Hkey: Handle {
 	///         println!("");
 	///     },
 	///     w::RegistryValue::None => println!("No value"),
+	///     other => println!("Other: {}", other),
 	/// }
 	/// # Ok::<_, co::ERROR>(())
 	/// ```
@@ -946,8 +945,7 @@ pub trait kernel_Hkey: Handle {
 		data: RegistryValue,
 	) -> SysResult<()>
 	{
-		let mut str_buf = WString::default();
-		let (data_ptr, data_len) = data.as_ptr_with_len(&mut str_buf);
+		let bytes = data.to_bytes();
 
 		error_to_sysresult(
 			unsafe {
@@ -956,8 +954,8 @@ pub trait kernel_Hkey: Handle {
 					WString::from_opt_str(sub_key).as_ptr(),
 					WString::from_opt_str(value_name).as_ptr(),
 					data.reg_type().raw(),
-					data_ptr,
-					data_len,
+					bytes.as_ptr() as _,
+					bytes.len() as _,
 				)
 			},
 		)
@@ -991,8 +989,7 @@ pub trait kernel_Hkey: Handle {
 		data: RegistryValue,
 	) -> SysResult<()>
 	{
-		let mut str_buf = WString::default();
-		let (data_ptr, data_len) = data.as_ptr_with_len(&mut str_buf);
+		let bytes = data.to_bytes();
 
 		error_to_sysresult(
 			unsafe {
@@ -1001,8 +998,8 @@ pub trait kernel_Hkey: Handle {
 					WString::from_opt_str(value_name).as_ptr(),
 					0,
 					data.reg_type().raw(),
-					data_ptr as _,
-					data_len,
+					bytes.as_ptr() as _,
+					bytes.len() as _,
 				)
 			},
 		)
@@ -1036,8 +1033,8 @@ fn validate_retrieved_reg_val(
 	data_type1: co::REG,
 	data_len1: u32,
 	data_type2: co::REG,
-	mut data_len2: u32,
-	buf: Vec<u8>,
+	data_len2: u32,
+	mut buf: Vec<u8>,
 ) -> SysResult<RegistryValue>
 {
 	if data_type1 != data_type2 {
@@ -1045,32 +1042,11 @@ fn validate_retrieved_reg_val(
 		return Err(co::ERROR::TRANSACTION_REQUEST_NOT_VALID);
 	}
 
-	if data_type1 == co::REG::SZ || data_type1 == co::REG::MULTI_SZ {
-		data_len2 += 2; // also count wchar terminating null
-	}
-
-	if data_len1 != data_len2 {
+	if data_len2 > data_len1 {
 		// Race condition: someone modified the data content in between our calls.
 		return Err(co::ERROR::TRANSACTION_REQUEST_NOT_VALID);
 	}
 
-	if data_type1 == co::REG::DWORD && data_len1 != 4
-		|| data_type1 == co::REG::QWORD && data_len1 != 8
-	{
-		// Data length makes no sense, possibly corrupted.
-		return Err(co::ERROR::INVALID_DATA);
-	}
-
-	if data_type1 == co::REG::SZ {
-		if data_len1 == 0 // empty data
-			|| data_len1 % 2 != 0 // odd number of bytes
-			|| buf[data_len1 as usize - 2] != 0 // terminating null
-			|| buf[data_len1 as usize - 1] != 0
-		{
-			// Bad string.
-			return Err(co::ERROR::INVALID_DATA);
-		}
-	}
-
-	Ok(unsafe { RegistryValue::from_raw(buf, data_type1) })
+	buf.truncate(data_len2 as _); // RegGetValue may reserve room for a terminating null
+	Ok(RegistryValue::from_bytes(&buf, data_type1))
 }
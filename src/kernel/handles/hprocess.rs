@@ -35,6 +35,10 @@ pub trait kernel_Hprocess: Handle {
 
 	/// [`CreateProcess`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-createprocessw)
 	/// function.
+	///
+	/// If `environment` is given,
+	/// [`co::CREATE::UNICODE_ENVIRONMENT`](crate::co::CREATE::UNICODE_ENVIRONMENT)
+	/// is automatically added to `creation_flags`.
 	#[must_use]
 	fn CreateProcess(
 		application_name: Option<&str>,
@@ -43,12 +47,18 @@ pub trait kernel_Hprocess: Handle {
 		thread_attrs: Option<&mut SECURITY_ATTRIBUTES>,
 		inherit_handles: bool,
 		creation_flags: co::CREATE,
-		environment: Option<Vec<(&str, &str)>>,
+		environment: Option<&EnvironmentBlock>,
 		current_dir: Option<&str>,
 		si: &mut STARTUPINFO,
 	) -> SysResult<CloseHandlePiGuard>
 	{
 		let mut buf_cmd_line = WString::from_opt_str(command_line);
+		let mut buf_env = environment.map(|env| env.serialize());
+		let creation_flags = if buf_env.is_some() {
+			creation_flags | co::CREATE::UNICODE_ENVIRONMENT
+		} else {
+			creation_flags
+		};
 		let mut pi = PROCESS_INFORMATION::default();
 
 		unsafe {
@@ -60,13 +70,7 @@ pub trait kernel_Hprocess: Handle {
 					thread_attrs.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
 					inherit_handles as _,
 					creation_flags.raw(),
-					environment.map_or(std::ptr::null_mut(), |environment| {
-						WString::from_str_vec(
-							&environment.iter()
-								.map(|(name, val)| format!("{}={}", name, val))
-								.collect::<Vec<_>>()
-						).as_ptr() as _
-					}),
+					buf_env.as_mut().map_or(std::ptr::null_mut(), |buf| buf.as_mut_ptr() as _),
 					WString::from_opt_str(current_dir).as_ptr(),
 					si as *mut _ as _,
 					&mut pi as *mut _ as _,
@@ -75,6 +79,61 @@ pub trait kernel_Hprocess: Handle {
 		}
 	}
 
+	/// [`DuplicateHandle`](https://learn.microsoft.com/en-us/windows/win32/api/handleapi/nf-handleapi-duplicatehandle)
+	/// function.
+	///
+	/// Duplicates `source_handle`, which belongs to this process, into
+	/// `target_process`.
+	///
+	/// If `target_process` is not the current process, the new handle is valid
+	/// only in the target process, so it must not be closed here: call
+	/// [`leak`](crate::guard::CloseHandleGuard::leak) on the returned guard.
+	///
+	/// # Examples
+	///
+	/// Making an inheritable copy of a pipe handle:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let (hread, hwrite) = w::HPIPE::CreatePipe(None, 0)?;
+	///
+	/// let hcur = w::HPROCESS::GetCurrentProcess();
+	/// let hwrite_inherit = hcur.DuplicateHandle(
+	///     &*hwrite,
+	///     &hcur,
+	///     0,
+	///     true,
+	///     co::DUPLICATE::SAME_ACCESS,
+	/// )?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn DuplicateHandle<H>(&self,
+		source_handle: &H,
+		target_process: &HPROCESS,
+		desired_access: u32,
+		inherit_handle: bool,
+		options: co::DUPLICATE,
+	) -> SysResult<CloseHandleGuard<H>>
+		where H: Handle,
+	{
+		let mut hdup = H::NULL;
+		unsafe {
+			bool_to_sysresult(
+				ffi::DuplicateHandle(
+					self.ptr(),
+					source_handle.ptr(),
+					target_process.ptr(),
+					hdup.as_mut(),
+					desired_access,
+					inherit_handle as _,
+					options.raw(),
+				),
+			).map(|_| CloseHandleGuard::new(hdup))
+		}
+	}
+
 	/// [`FlushInstructionCache`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-flushinstructioncache)
 	/// function.
 	fn FlushInstructionCache(&self,
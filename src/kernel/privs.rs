@@ -79,6 +79,19 @@ pub(crate) fn minus1_as_error(dword: u32) -> SysResult<u32> {
 	}
 }
 
+/// Searches an embedded message table, which must be sorted by code, yielding
+/// the name and the description.
+#[cfg(feature = "error_messages")]
+pub(crate) fn find_error_msg(
+	table: &'static [(u32, &'static str, &'static str)],
+	code: u32,
+) -> Option<(&'static str, &'static str)>
+{
+	table.binary_search_by_key(&code, |(c, _, _)| *c)
+		.ok()
+		.map(|idx| (table[idx].1, table[idx].2))
+}
+
 /// Converts a string to an ISO-8859-1 null-terminated byte array.
 pub(crate) fn str_to_iso88591(s: &str) -> Vec<u8> {
 	s.chars().map(|ch| ch as u8)
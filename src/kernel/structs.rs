@@ -72,7 +72,9 @@ pub struct DISK_SPACE_INFORMATION {
 /// struct.
 ///
 /// Can be converted to [`SYSTEMTIME`](crate::SYSTEMTIME) with
-/// [`FileTimeToSystemTime`](crate::FileTimeToSystemTime) function.
+/// [`FileTimeToSystemTime`](crate::FileTimeToSystemTime) function, or with
+/// [`FILETIME::to_systemtime`](crate::FILETIME::to_systemtime), in pure Rust.
+/// Also converts to and from [`SystemTime`](std::time::SystemTime).
 #[repr(C)]
 #[derive(Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct FILETIME {
@@ -85,13 +87,17 @@ pub struct FILETIME {
 ///
 /// The [`Default`](std::default::Default) implementation returns `GUID::NULL`
 /// (all zeros).
+///
+/// Strings from files or user input can be parsed with
+/// [`GUID::parse`](crate::GUID::parse), which accepts the formats of
+/// [`GuidFmt`](crate::GuidFmt) and doesn't panic.
 #[repr(C)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct GUID {
-	data1: u32,
-	data2: u16,
-	data3: u16,
-	data4: u64,
+	pub(in crate::kernel) data1: u32,
+	pub(in crate::kernel) data2: u16,
+	pub(in crate::kernel) data3: u16,
+	pub(in crate::kernel) data4: u64,
 }
 
 impl std::fmt::Display for GUID {
@@ -115,7 +121,8 @@ impl GUID {
 	///
 	/// # Panics
 	///
-	/// Panics if the string has an invalid format.
+	/// Panics if the string has an invalid format. To parse strings at run
+	/// time, use [`GUID::parse`](crate::GUID::parse).
 	///
 	/// # Examples
 	///
@@ -699,6 +706,11 @@ impl<'a, 'b> STARTUPINFO<'a, 'b> {
 	pub fn set_wShowWindow(&mut self, val: co::SW) {
 		self.wShowWindow = val.raw() as _;
 	}
+
+	/// Sets the `cb` field, when the struct is the head of a `STARTUPINFOEX`.
+	pub(in crate::kernel) fn set_cb(&mut self, cb: usize) {
+		self.cb = cb as _;
+	}
 }
 
 /// [`SYSTEM_INFO`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/ns-sysinfoapi-system_info)
@@ -724,7 +736,8 @@ impl_default!(SYSTEM_INFO);
 /// struct.
 ///
 /// Can be converted to [`FILETIME`](crate::FILETIME) with
-/// [`SystemTimeToFileTime`](crate::SystemTimeToFileTime) function.
+/// [`SystemTimeToFileTime`](crate::SystemTimeToFileTime) function, or with
+/// [`SYSTEMTIME::to_filetime`](crate::SYSTEMTIME::to_filetime), in pure Rust.
 #[repr(C)]
 #[derive(Default, Clone, PartialEq, Eq)]
 pub struct SYSTEMTIME {
@@ -0,0 +1,259 @@
+use crate::co;
+use crate::decl::*;
+
+// Constants from the reference implementation in
+// https://www.unicode.org/notes/tn6/
+const ASCII_PREV: i32 = 0x40;
+const MIN: u8 = 0x21;
+const MIDDLE: i32 = 0x90;
+const RESET: u8 = 0xff;
+
+const TRAIL_CONTROLS_COUNT: i32 = 20;
+const TRAIL_BYTE_OFFSET: i32 = MIN as i32 - TRAIL_CONTROLS_COUNT;
+const TRAIL_COUNT: i32 = (0xff - MIN as i32 + 1) + TRAIL_CONTROLS_COUNT;
+
+const SINGLE: i32 = 64;
+const LEAD_2: i32 = 43;
+const LEAD_3: i32 = 3;
+
+const REACH_POS_1: i32 = SINGLE - 1;
+const REACH_NEG_1: i32 = -SINGLE;
+const REACH_POS_2: i32 = REACH_POS_1 + LEAD_2 * TRAIL_COUNT;
+const REACH_NEG_2: i32 = REACH_NEG_1 - LEAD_2 * TRAIL_COUNT;
+const REACH_POS_3: i32 = REACH_POS_2 + LEAD_3 * TRAIL_COUNT * TRAIL_COUNT;
+const REACH_NEG_3: i32 = REACH_NEG_2 - LEAD_3 * TRAIL_COUNT * TRAIL_COUNT;
+
+const START_POS_2: i32 = MIDDLE + REACH_POS_1 + 1;
+const START_POS_3: i32 = START_POS_2 + LEAD_2;
+const START_POS_4: i32 = START_POS_3 + LEAD_3;
+const START_NEG_2: i32 = MIDDLE + REACH_NEG_1;
+const START_NEG_3: i32 = START_NEG_2 - LEAD_2;
+const START_NEG_4: i32 = START_NEG_3 - LEAD_3;
+
+/// Control bytes which are used as trail bytes, in trail value order.
+const TRAIL_CONTROLS: [u8; TRAIL_CONTROLS_COUNT as usize] = [
+	0x01, 0x02, 0x03, 0x04, 0x05, 0x06,
+	0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19,
+	0x1c, 0x1d, 0x1e, 0x1f,
+];
+
+/// Decodes
+/// [BOCU-1](https://www.unicode.org/notes/tn6/)
+/// bytes into UTF-16 chars.
+///
+/// The BOM, if any, must be kept in `data`, because it changes the state of
+/// the decoder; the resulting `U+FEFF` char is removed.
+pub(in crate::kernel) fn decode(data: &[u8]) -> SysResult<Vec<u16>> {
+	let mut buf = Vec::<u16>::with_capacity(data.len());
+	let mut prev = ASCII_PREV;
+	let mut diff = 0i32; // accumulated difference of a multi-byte sequence
+	let mut count = 0; // remaining trail bytes
+
+	for &b in data.iter() {
+		if count == 0 { // lead position
+			if b <= 0x20 {
+				if b != 0x20 {
+					prev = ASCII_PREV; // control chars reset the state, space doesn't
+				}
+				buf.push(b as _);
+			} else if START_NEG_2 <= b as i32 && (b as i32) < START_POS_2 {
+				let code_point = prev + (b as i32 - MIDDLE); // single-byte difference
+				push_code_point(&mut buf, code_point)?;
+				prev = prev_of(code_point);
+			} else if b == RESET {
+				prev = ASCII_PREV;
+			} else {
+				(diff, count) = lead_byte(b);
+			}
+		} else { // trail position
+			let t = trail_value(b)?;
+			if count == 1 {
+				let code_point = prev + diff + t;
+				push_code_point(&mut buf, code_point)?;
+				prev = prev_of(code_point);
+			} else if count == 2 {
+				diff += t * TRAIL_COUNT;
+			} else {
+				diff += t * TRAIL_COUNT * TRAIL_COUNT;
+			}
+			count -= 1;
+		}
+	}
+
+	if count != 0 {
+		return Err(co::ERROR::INVALID_DATA); // truncated multi-byte sequence
+	}
+	if buf.first() == Some(&0xfeff) {
+		buf.remove(0); // BOM
+	}
+	Ok(buf)
+}
+
+/// Returns the initial difference and the number of trail bytes for a lead
+/// byte of a multi-byte sequence.
+fn lead_byte(b: u8) -> (i32, i32) {
+	let b = b as i32;
+	if b >= START_NEG_2 { // positive difference
+		if b < START_POS_3 {
+			((b - START_POS_2) * TRAIL_COUNT + REACH_POS_1 + 1, 1)
+		} else if b < START_POS_4 {
+			((b - START_POS_3) * TRAIL_COUNT * TRAIL_COUNT + REACH_POS_2 + 1, 2)
+		} else {
+			(REACH_POS_3 + 1, 3)
+		}
+	} else { // negative difference
+		if b >= START_NEG_3 {
+			((b - START_NEG_2) * TRAIL_COUNT + REACH_NEG_1, 1)
+		} else if b > MIN as i32 {
+			((b - START_NEG_3) * TRAIL_COUNT * TRAIL_COUNT + REACH_NEG_2, 2)
+		} else {
+			(-TRAIL_COUNT * TRAIL_COUNT * TRAIL_COUNT + REACH_NEG_3, 3)
+		}
+	}
+}
+
+/// Converts a trail byte into its numeric value.
+fn trail_value(b: u8) -> SysResult<i32> {
+	if b >= MIN {
+		Ok(b as i32 - TRAIL_BYTE_OFFSET)
+	} else {
+		TRAIL_CONTROLS.iter()
+			.position(|ctrl| *ctrl == b)
+			.map(|idx| idx as i32)
+			.ok_or(co::ERROR::INVALID_DATA) // control not allowed as trail byte
+	}
+}
+
+/// Returns the state, which is the base for the next difference, after the
+/// given code point.
+const fn prev_of(code_point: i32) -> i32 {
+	if 0x3040 <= code_point && code_point <= 0x309f {
+		0x3070 // Hiragana
+	} else if 0x4e00 <= code_point && code_point <= 0x9fa5 {
+		0x4e00 - REACH_NEG_2 // CJK Unihan
+	} else if 0xac00 <= code_point && code_point <= 0xd7a3 {
+		(0xd7a3 + 0xac00) / 2 // Hangul
+	} else {
+		(code_point & !0x7f) + ASCII_PREV // middle of the 128-char block
+	}
+}
+
+fn push_code_point(buf: &mut Vec<u16>, code_point: i32) -> SysResult<()> {
+	let ch = u32::try_from(code_point).ok()
+		.and_then(char::from_u32)
+		.ok_or(co::ERROR::INVALID_DATA)?;
+	buf.extend_from_slice(ch.encode_utf16(&mut [0; 2]));
+	Ok(())
+}
+
+/// Encodes UTF-16 chars into
+/// [BOCU-1](https://www.unicode.org/notes/tn6/)
+/// bytes, optionally starting with the BOM.
+pub(in crate::kernel) fn encode(chars: &[u16], with_bom: bool) -> SysResult<Vec<u8>> {
+	let mut buf = Vec::<u8>::with_capacity(chars.len());
+	let mut prev = ASCII_PREV;
+
+	let bom = if with_bom { Some(Ok('\u{feff}')) } else { None };
+	for ch in bom.into_iter().chain(char::decode_utf16(chars.iter().copied())) {
+		let code_point = ch.map_err(|_| co::ERROR::NO_UNICODE_TRANSLATION)? as i32;
+
+		if code_point <= 0x20 {
+			if code_point != 0x20 {
+				prev = ASCII_PREV; // control chars reset the state, space doesn't
+			}
+			buf.push(code_point as _);
+			continue;
+		}
+
+		let diff = code_point - prev;
+		prev = prev_of(code_point);
+
+		if (REACH_NEG_1..=REACH_POS_1).contains(&diff) {
+			buf.push((MIDDLE + diff) as _); // single byte
+			continue;
+		}
+
+		let (mut diff, lead, count): (_, _, usize) = if diff >= REACH_NEG_1 {
+			if diff <= REACH_POS_2 {
+				(diff - (REACH_POS_1 + 1), START_POS_2, 1)
+			} else if diff <= REACH_POS_3 {
+				(diff - (REACH_POS_2 + 1), START_POS_3, 2)
+			} else {
+				(diff - (REACH_POS_3 + 1), START_POS_4, 3)
+			}
+		} else {
+			if diff >= REACH_NEG_2 {
+				(diff - REACH_NEG_1, START_NEG_2, 1)
+			} else if diff >= REACH_NEG_3 {
+				(diff - REACH_NEG_2, START_NEG_3, 2)
+			} else {
+				(diff - REACH_NEG_3, START_NEG_4, 3)
+			}
+		};
+
+		let mut trails = [0u8; 3];
+		for trail in trails[..count].iter_mut().rev() {
+			*trail = trail_byte(diff.rem_euclid(TRAIL_COUNT));
+			diff = diff.div_euclid(TRAIL_COUNT); // negative differences round down
+		}
+		buf.push((lead + diff) as _);
+		buf.extend_from_slice(&trails[..count]);
+	}
+
+	Ok(buf)
+}
+
+/// Converts a numeric trail value into its trail byte.
+fn trail_byte(t: i32) -> u8 {
+	if t < TRAIL_CONTROLS_COUNT {
+		TRAIL_CONTROLS[t as usize]
+	} else {
+		(t + TRAIL_BYTE_OFFSET) as _
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Texts with their BOCU-1 bytes, as produced by the ICU converter, which
+	/// follows the reference implementation of
+	/// [UTN #6](https://www.unicode.org/notes/tn6/).
+	fn samples() -> [(&'static str, &'static [u8]); 5] {
+		[
+			("Hello, world!", &[0x98, 0xb5, 0xbc, 0xbc, 0xbf, 0x7c, 0x20, 0xc7, 0xbf, 0xc2, 0xbc, 0xb4, 0x71]),
+			("Öl fließt", &[0xd0, 0x63, 0x4f, 0xec, 0x20, 0xb6, 0xbc, 0xb9, 0xb5, 0xd0, 0x6c, 0x4f, 0xf4]),
+			("Москва", &[0xd3, 0xd0, 0x8e, 0x91, 0x8a, 0x82, 0x80]),
+			("リンゴ可愛いや", &[0xfb, 0x12, 0x02, 0xc3, 0x84, 0xf4, 0xd0, 0x39, 0x2c, 0x24, 0xe0, 0xb1, 0xa4]),
+			("a\u{1d11e}b", &[0xb1, 0xfc, 0xd8, 0x80, 0x23, 0x34, 0x8d]),
+		]
+	}
+
+	#[test]
+	fn decode_samples() {
+		for (text, data) in samples().iter() {
+			assert_eq!(decode(data).unwrap(), text.encode_utf16().collect::<Vec<_>>());
+		}
+	}
+
+	#[test]
+	fn encode_samples() {
+		for (text, data) in samples().iter() {
+			let chars = text.encode_utf16().collect::<Vec<_>>();
+			assert_eq!(encode(&chars, false).unwrap(), *data);
+			assert_eq!(decode(&encode(&chars, true).unwrap()).unwrap(), chars);
+		}
+	}
+
+	#[test]
+	fn bom() {
+		let data = encode(&[0x0061, 0x0062], true).unwrap();
+		assert_eq!(data, [0xfb, 0xee, 0x28, 0x24, 0x1e, 0x52, 0xb2]); // signature FB EE 28
+		assert_eq!(decode(&data).unwrap(), [0x0061, 0x0062]);
+	}
+
+	#[test]
+	fn truncated() {
+		assert_eq!(decode(&[0xd3, 0xd0, 0x8e, 0xfb, 0x12]), Err(co::ERROR::INVALID_DATA));
+	}
+}
@@ -0,0 +1,319 @@
+//! Command line utilities.
+//!
+//! These functions follow the rules of
+//! [`CommandLineToArgv`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-commandlinetoargvw),
+//! but they're implemented in pure Rust, so they don't depend on any Win32
+//! call.
+
+use crate::co;
+use crate::decl::*;
+
+/// Escapes the [cmd.exe](https://learn.microsoft.com/en-us/windows-server/administration/windows-commands/cmd)
+/// metacharacters `()%!^"<>&|` with a caret, so the command line is passed
+/// unchanged to the program when run through `cmd.exe /c`.
+///
+/// This must be applied to the whole command line, after
+/// [`join`](crate::cmd_line::join), because the quotes must be escaped too.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let cmd = w::cmd_line::escape_cmd(
+///     &w::cmd_line::join(&["prog.exe", "a & b"])?, // "a & b"
+/// ); // prog.exe ^"a ^& b^"
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[must_use]
+pub fn escape_cmd(cmd_line: &str) -> String {
+	let mut buf = String::with_capacity(cmd_line.len());
+	for ch in cmd_line.chars() {
+		if "()%!^\"<>&|".contains(ch) {
+			buf.push('^');
+		}
+		buf.push(ch);
+	}
+	buf
+}
+
+/// Builds a command line from the program name and its arguments, quoting them
+/// so that [`split`](crate::cmd_line::split) returns the same strings.
+///
+/// The program name is only enclosed in quotes, if needed, because it's not
+/// parsed with the escaping rules of the arguments.
+///
+/// Returns
+/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER) if
+/// `args` is empty or the program name contains a quote, which cannot be
+/// represented.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let cmd = w::cmd_line::join(&[
+///     "C:\\Program Files\\foo.exe",
+///     "simple",
+///     "with space",
+///     "C:\\dir\\",
+///     "say \"hi\"",
+/// ])?; // "C:\Program Files\foo.exe" simple "with space" C:\dir\ "say \"hi\""
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+pub fn join(args: &[impl AsRef<str>]) -> SysResult<String> {
+	let (prog, args) = args.split_first()
+		.ok_or(co::ERROR::INVALID_PARAMETER)?;
+	let prog = prog.as_ref();
+	if prog.contains('"') {
+		return Err(co::ERROR::INVALID_PARAMETER);
+	}
+
+	let mut buf = if prog.is_empty() || prog.contains([' ', '\t']) {
+		format!("\"{}\"", prog)
+	} else {
+		prog.to_owned()
+	};
+	for arg in args.iter() {
+		buf.push(' ');
+		buf.push_str(&quote(arg.as_ref()));
+	}
+	Ok(buf)
+}
+
+/// Quotes a single argument, if needed, so it's parsed back unchanged by
+/// [`split`](crate::cmd_line::split). Backslashes are doubled only when they
+/// precede a quote.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let a = w::cmd_line::quote("C:\\Temp");       // C:\Temp
+/// let b = w::cmd_line::quote("C:\\My Files\\"); // "C:\My Files\\"
+/// let c = w::cmd_line::quote("a\"b");           // "a\"b"
+/// ```
+#[must_use]
+pub fn quote(arg: &str) -> String {
+	if !arg.is_empty() && !arg.contains([' ', '\t', '\n', '\x0b', '"']) {
+		return arg.to_owned();
+	}
+
+	let mut buf = String::with_capacity(arg.len() + 2);
+	buf.push('"');
+	let mut num_bs = 0; // pending backslashes
+	for ch in arg.chars() {
+		match ch {
+			'\\' => num_bs += 1,
+			'"' => {
+				buf.push_str(&"\\".repeat(num_bs * 2 + 1));
+				buf.push('"');
+				num_bs = 0;
+			},
+			_ => {
+				buf.push_str(&"\\".repeat(num_bs));
+				buf.push(ch);
+				num_bs = 0;
+			},
+		}
+	}
+	buf.push_str(&"\\".repeat(num_bs * 2)); // before the closing quote
+	buf.push('"');
+	buf
+}
+
+/// Splits a command line into the program name and its arguments, exactly like
+/// [`CommandLineToArgv`](crate::CommandLineToArgv):
+///
+/// * the program name ends at the first space or tab, or, if it starts with a
+///   quote, at the next quote, with no escaping;
+/// * arguments are separated by spaces and tabs outside quotes;
+/// * `2n` backslashes followed by a quote produce `n` backslashes, and the
+///   quote opens or closes a quoted section;
+/// * `2n + 1` backslashes followed by a quote produce `n` backslashes and a
+///   literal quote;
+/// * backslashes not followed by a quote are literal;
+/// * a quote right after a closing quote produces a literal quote.
+///
+/// Unlike `CommandLineToArgv`, an empty string returns an empty `Vec`, instead
+/// of the path of the current executable.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let args = w::cmd_line::split(
+///     "\"C:\\Program Files\\foo.exe\" a\\\\\"b c\" \"d\\\"e\"",
+/// ); // [C:\Program Files\foo.exe, a\b c, d"e]
+/// ```
+#[must_use]
+pub fn split(cmd_line: &str) -> Vec<String> {
+	if cmd_line.is_empty() {
+		return Vec::new();
+	}
+
+	let chars = cmd_line.chars().collect::<Vec<_>>();
+	let mut idx = 0;
+
+	// The program name has its own rules.
+	let mut prog = String::new();
+	if chars[0] == '"' {
+		idx += 1;
+		while idx < chars.len() {
+			idx += 1;
+			if chars[idx - 1] == '"' {
+				break;
+			}
+			prog.push(chars[idx - 1]);
+		}
+	} else {
+		while idx < chars.len() && !is_blank(chars[idx]) {
+			prog.push(chars[idx]);
+			idx += 1;
+		}
+	}
+	let mut args = vec![prog];
+
+	let mut cur: Option<String> = None; // argument being parsed, if any
+	let mut num_bs = 0; // pending backslashes
+	let mut num_quotes = 0; // odd if inside a quoted section
+	while idx < chars.len() {
+		let ch = chars[idx];
+		if is_blank(ch) && num_quotes == 0 {
+			if let Some(arg) = cur.take() {
+				args.push(arg);
+			}
+			num_bs = 0;
+			idx += 1;
+			continue;
+		}
+
+		let arg = cur.get_or_insert_with(String::new);
+		match ch {
+			'\\' => {
+				arg.push('\\');
+				num_bs += 1;
+				idx += 1;
+			},
+			'"' => {
+				arg.truncate(arg.len() - num_bs / 2 - num_bs % 2); // backslashes are ASCII
+				if num_bs % 2 == 0 {
+					num_quotes += 1;
+				} else {
+					arg.push('"');
+				}
+				num_bs = 0;
+				idx += 1;
+
+				while idx < chars.len() && chars[idx] == '"' { // a run of quotes
+					num_quotes += 1;
+					if num_quotes == 3 {
+						arg.push('"');
+						num_quotes = 0;
+					}
+					idx += 1;
+				}
+				if num_quotes == 2 {
+					num_quotes = 0;
+				}
+			},
+			_ => {
+				arg.push(ch);
+				num_bs = 0;
+				idx += 1;
+			},
+		}
+	}
+
+	if let Some(arg) = cur {
+		args.push(arg);
+	}
+	args
+}
+
+//------------------------------------------------------------------------------
+
+const fn is_blank(ch: char) -> bool {
+	ch == ' ' || ch == '\t'
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn backslashes() {
+		assert_eq!(split(r#"p a\\b c\"#), ["p", r"a\\b", r"c\"]); // literal
+		assert_eq!(split(r#"p a\\\\"b c" d"#), ["p", r"a\\b c", "d"]); // 2n: quote opens
+		assert_eq!(split(r#"p a\\\"b c"#), ["p", r#"a\"b"#, "c"]); // 2n + 1: literal quote
+		assert_eq!(split(r#"p "a\\" b"#), ["p", r"a\", "b"]); // quote closes
+		assert_eq!(split(r#"p "a\" b""#), ["p", r#"a" b"#]);
+	}
+
+	#[test]
+	fn quotes() {
+		assert_eq!(split(r#"p "a b" c"#), ["p", "a b", "c"]);
+		assert_eq!(split(r#"p "" x"#), ["p", "", "x"]);
+		assert_eq!(split(r#"p a"b"" c d"#), ["p", r#"ab""#, "c", "d"]); // "" closes
+		assert_eq!(split(r#"p "a""b""#), ["p", r#"a"b"#]);
+		assert_eq!(split(r#"p """"#), ["p", r#"""#]);
+		assert_eq!(split(r#"p "a b"#), ["p", "a b"]); // unterminated
+		assert_eq!(split("p \t a\t\tb "), ["p", "a", "b"]);
+	}
+
+	#[test]
+	fn program_name() {
+		assert_eq!(split(r#""C:\Program Files\a.exe" x"#), [r"C:\Program Files\a.exe", "x"]);
+		assert_eq!(split(r#"C:\dir\"a b" x"#), [r#"C:\dir\"a"#, "b x"]); // no escaping
+		assert_eq!(split(r#""a b"c d"#), ["a b", "c", "d"]);
+		assert_eq!(split(r#""C:\a\" x"#), [r"C:\a\", "x"]);
+		assert_eq!(split(r#""unterminated"#), ["unterminated"]);
+		assert_eq!(split(r#""" x"#), ["", "x"]);
+		assert_eq!(split(" a b"), ["", "a", "b"]);
+		assert_eq!(split("\ta"), ["", "a"]);
+		assert!(split("").is_empty());
+	}
+
+	#[test]
+	fn round_trips() {
+		let args = [
+			r"C:\Program Files\a.exe",
+			"",
+			"plain",
+			"a b",
+			r"C:\dir\",
+			r"C:\My Files\",
+			r#"say "hi""#,
+			r#"\"#,
+			r#"\\server\share\"#,
+			r#"a\\"b"#,
+			"tab\there",
+			"\u{e9}\u{1f600}",
+		];
+		let cmd = join(&args).unwrap();
+		assert!(cmd.starts_with(r#""C:\Program Files\a.exe" "" plain "a b" C:\dir\ "C:\My Files\\""#));
+		assert_eq!(split(&cmd), args);
+
+		assert_eq!(join(&["prog.exe"]).unwrap(), "prog.exe");
+		assert_eq!(join(&["", "a"]).unwrap(), r#""" a"#);
+		assert_eq!(split(&join(&["", "a"]).unwrap()), ["", "a"]);
+		assert_eq!(join(&[] as &[&str]), Err(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(join(&[r#"a"b.exe"#]), Err(co::ERROR::INVALID_PARAMETER));
+
+		assert_eq!(quote(r"C:\Temp"), r"C:\Temp");
+		assert_eq!(quote(r#"a"b"#), r#""a\"b""#);
+		assert_eq!(quote(r#"a\"b"#), r#""a\\\"b""#);
+		assert_eq!(quote("\n"), "\"\n\"");
+	}
+
+	#[test]
+	fn cmd_escaping() {
+		assert_eq!(escape_cmd("prog.exe plain"), "prog.exe plain");
+		assert_eq!(escape_cmd(r#"a & b | (c) %PATH% !x! ^ <in >out "q""#),
+			r#"a ^& b ^| ^(c^) ^%PATH^% ^!x^! ^^ ^<in ^>out ^"q^""#);
+		assert_eq!(escape_cmd(&join(&["prog.exe", "a & b"]).unwrap()), r#"prog.exe ^"a ^& b^""#);
+	}
+}
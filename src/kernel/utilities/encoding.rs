@@ -7,6 +7,10 @@ pub enum Encoding {
 	Unknown,
 	/// Common [US_ASCII](https://en.wikipedia.org/wiki/ASCII) encoding.
 	Ansi,
+	/// [Windows-1250](https://en.wikipedia.org/wiki/Windows-1250) encoding.
+	Win1250,
+	/// [Windows-1251](https://en.wikipedia.org/wiki/Windows-1251) encoding.
+	Win1251,
 	/// [Windows-1252](https://en.wikipedia.org/wiki/Windows-1252) encoding.
 	Win1252,
 	/// [UTF-8](https://en.wikipedia.org/wiki/UTF-8) encoding.
@@ -31,6 +35,8 @@ impl std::fmt::Display for Encoding {
 			match self {
 				Self::Unknown => "Unknown",
 				Self::Ansi => "ANSI",
+				Self::Win1250 => "Windows 1250",
+				Self::Win1251 => "Windows 1251",
 				Self::Win1252 => "Windows 1252",
 				Self::Utf8 => "UTF-8",
 				Self::Utf16be => "UTF-16 BE",
@@ -48,21 +54,122 @@ impl std::fmt::Display for Encoding {
 impl Encoding {
 	/// Guesses the encoding of the given raw data, also returning the size of
 	/// its [BOM](https://en.wikipedia.org/wiki/Byte_order_mark), if any.
+	///
+	/// This is the best candidate returned by
+	/// [`Encoding::guess_ranked`](crate::Encoding::guess_ranked).
 	#[must_use]
 	pub fn guess(data: &[u8]) -> (Self, usize) {
-		if let Some((enc, bom_sz)) = Self::guess_bom(data) {
-			return (enc, bom_sz); // BOM found, we already guessed the encoding
+		Self::guess_ranked(data)
+			.first()
+			.map(|g| (g.encoding, g.bom_size))
+			.unwrap_or((Self::Ansi, 0))
+	}
+
+	/// Guesses the possible encodings of the given raw data, returning all
+	/// candidates ranked by decreasing confidence.
+	///
+	/// Besides the [BOM](https://en.wikipedia.org/wiki/Byte_order_mark), the
+	/// detection considers the patterns of null bytes, which reveal UTF-16 and
+	/// UTF-32 text without BOM, and the distribution of the non-ASCII bytes,
+	/// which tells apart the Windows-1250, Windows-1251 and Windows-1252
+	/// code pages. The analysis is heuristic, so ambiguous data yields several
+	/// candidates with close confidences.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let file_in = w::FileMapped::open(
+	///     "C:\\Temp\\foo.txt",
+	///     w::FileAccess::ExistingReadOnly,
+	/// )?;
+	///
+	/// for guess in w::Encoding::guess_ranked(file_in.as_slice()) {
+	///     println!("{}: {:.0}%", guess.encoding, guess.confidence * 100.0);
+	/// }
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	pub fn guess_ranked(data: &[u8]) -> Vec<EncodingGuess> {
+		if let Some((encoding, bom_size)) = Self::guess_bom(data) {
+			let mut guesses = vec![EncodingGuess { encoding, bom_size, confidence: 1.0 }];
+			if encoding == Self::Utf32le { // could also be UTF-16 LE followed by a null
+				guesses[0].confidence = 0.9;
+				guesses.push(EncodingGuess { encoding: Self::Utf16le, bom_size: 2, confidence: 0.1 });
+			}
+			return guesses; // BOM found, we already guessed the encoding
+		}
+
+		if data.is_empty() {
+			return vec![EncodingGuess { encoding: Self::Ansi, bom_size: 0, confidence: 1.0 }];
+		}
+
+		let mut guesses = Vec::<EncodingGuess>::default();
+		let mut push = |encoding: Self, confidence: f32| {
+			if confidence > 0.0 {
+				guesses.push(EncodingGuess { encoding, bom_size: 0, confidence: confidence.min(1.0) });
+			}
+		};
+
+		push(Self::Utf32le, Self::score_utf32(data, false));
+		push(Self::Utf32be, Self::score_utf32(data, true));
+		push(Self::Utf16le, Self::score_utf16(data, false));
+		push(Self::Utf16be, Self::score_utf16(data, true));
+
+		// Text in single-byte encodings and UTF-8 doesn't have nulls, except
+		// for terminating ones. A few stray nulls only lower the confidence;
+		// the dense nulls of UTF-16 and UTF-32 rule these encodings out.
+		let text = &data[..data.iter().rposition(|ch| *ch != 0x00).map_or(0, |idx| idx + 1)];
+		let null_ratio = text.iter().filter(|ch| **ch == 0x00).count() as f32
+			/ text.len().max(1) as f32;
+
+		if null_ratio < 0.25 {
+			let factor = 1.0 - null_ratio;
+			let num_non_ansi = text.iter().filter(|ch| **ch > 0x7f).count();
+			if num_non_ansi == 0 {
+				push(Self::Ansi, factor);
+				push(Self::Utf8, 0.9 * factor); // ASCII is also valid UTF-8
+			} else if text.split(|ch| *ch == 0x00).all(Self::guess_utf8) {
+				push(Self::Utf8, 0.95 * factor);
+			} else {
+				push(Self::Win1250, 0.95 * factor * Self::Win1250.score_code_page(text, &WIN1250_HINTS)); // Windows-1252 wins ties
+				push(Self::Win1251, factor * Self::Win1251.score_code_page(text, &[]));
+				push(Self::Win1252, factor * Self::Win1252.score_code_page(text, &WIN1252_HINTS));
+			}
+		}
+
+		guesses.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap_or(Ordering::Equal));
+		if guesses.is_empty() {
+			guesses.push(EncodingGuess { encoding: Self::Unknown, bom_size: 0, confidence: 0.0 });
 		}
+		guesses
+	}
+
+	/// Returns the char of the given byte in a single-byte Windows code page.
+	fn single_byte_to_char(self, byte: u8) -> Option<u16> {
+		let table = self.single_byte_table()?;
+		Some(if byte < 0x80 { byte as _ } else { table[byte as usize - 0x80] })
+	}
 
-		if Self::guess_utf8(data) {
-			return (Self::Utf8, 0);
+	/// Returns the byte of the given char in a single-byte Windows code page,
+	/// if the char can be represented.
+	pub(in crate::kernel) fn char_to_single_byte(self, ch: u16) -> Option<u8> {
+		if ch < 0x80 {
+			return Some(ch as _);
 		}
+		self.single_byte_table()?
+			.iter()
+			.position(|table_ch| *table_ch == ch)
+			.map(|idx| 0x80 + idx as u8)
+	}
 
-		let has_non_ansi_char = data.iter().find(|ch| **ch > 0x7f).is_some();
-		if has_non_ansi_char {
-			(Self::Win1252, 0) // by exclusion, not assertive
-		} else {
-			(Self::Ansi, 0)
+	const fn single_byte_table(self) -> Option<&'static [u16; 128]> {
+		match self {
+			Self::Win1250 => Some(&WIN1250_HIGH),
+			Self::Win1251 => Some(&WIN1251_HIGH),
+			Self::Win1252 => Some(&WIN1252_HIGH),
+			_ => None,
 		}
 	}
 
@@ -82,6 +189,11 @@ impl Encoding {
 			return Some((Self::Utf16be, UTF16BE.len()));
 		}
 
+		const UTF32LE: [u8; 4] = [0xff, 0xfe, 0x00, 0x00];
+		if has_bom(&UTF32LE) { // must be tested before UTF-16 LE, which has the same prefix
+			return Some((Self::Utf32le, UTF32LE.len()));
+		}
+
 		const UTF16LE: [u8; 2] = [0xff, 0xfe];
 		if has_bom(&UTF16LE) {
 			return Some((Self::Utf16le, UTF16LE.len()));
@@ -92,11 +204,6 @@ impl Encoding {
 			return Some((Self::Utf32be, UTF32BE.len()));
 		}
 
-		const UTF32LE: [u8; 4] = [0xff, 0xfe, 0x00, 0x00];
-		if has_bom(&UTF32LE) {
-			return Some((Self::Utf32le, UTF32LE.len()));
-		}
-
 		const SCSU: [u8; 3] = [0x0e, 0xfe, 0xff];
 		if has_bom(&SCSU) {
 			return Some((Self::Scsu, SCSU.len()));
@@ -195,4 +302,314 @@ impl Encoding {
 		}
 		true // all the conditions accepted through the whole string
 	}
+
+	/// Scores UTF-32 without BOM: all chars must be valid code points, and
+	/// most of them have null high bytes.
+	fn score_utf32(data: &[u8], is_big_endian: bool) -> f32 {
+		if data.len() < 4 || data.len() % 4 != 0 {
+			return 0.0;
+		}
+		let mut num_bmp = 0;
+		for ch4 in data.chunks_exact(4) {
+			let code_point = if is_big_endian {
+				u32::from_be_bytes(ch4.try_into().unwrap())
+			} else {
+				u32::from_le_bytes(ch4.try_into().unwrap())
+			};
+			if char::from_u32(code_point).is_none() {
+				return 0.0;
+			}
+			if code_point <= 0xffff {
+				num_bmp += 1;
+			}
+		}
+		0.98 * num_bmp as f32 / (data.len() / 4) as f32
+	}
+
+	/// Scores UTF-16 without BOM: text in Latin scripts has nulls in the high
+	/// bytes, while other scripts repeat the same high byte, and surrogates
+	/// must be properly paired.
+	fn score_utf16(data: &[u8], is_big_endian: bool) -> f32 {
+		if data.len() < 2 {
+			return 0.0;
+		}
+		let units = data.chunks_exact(2)
+			.map(|ch2| if is_big_endian {
+				u16::from_be_bytes([ch2[0], ch2[1]])
+			} else {
+				u16::from_le_bytes([ch2[0], ch2[1]])
+			})
+			.collect::<Vec<_>>();
+
+		if char::decode_utf16(units.iter().copied()).any(|ch| ch.is_err()) {
+			return 0.0; // unpaired surrogates
+		}
+
+		let num_units = units.len() as f32;
+		let frac_where = |pred: &dyn Fn(u16) -> bool| -> f32 {
+			units.iter().filter(|u| pred(**u)).count() as f32 / num_units
+		};
+		let high_nulls = frac_where(&|u| u >> 8 == 0x00 && u != 0x0000);
+		let low_nulls = frac_where(&|u| u & 0xff == 0x00 && u != 0x0000);
+		if low_nulls > high_nulls {
+			return 0.0; // probably the other byte order
+		}
+
+		let mut high_counts = [0usize; 256];
+		units.iter().for_each(|u| high_counts[(u >> 8) as usize] += 1);
+		let top_high = *high_counts[1..].iter().max().unwrap() as f32 / num_units;
+		let script_score = if top_high >= 0.6 { 0.8 * top_high } else { 0.0 }; // non-Latin scripts
+
+		let score = (high_nulls - low_nulls).max(script_score);
+		if score < 0.3 { 0.0 } else { 0.97 * score }
+	}
+
+	/// Scores a single-byte code page by decoding the non-ASCII bytes: inside
+	/// words they must be letters, whole words of non-ASCII letters are typical
+	/// of Cyrillic, and some letters are characteristic of each code page.
+	fn score_code_page(self, data: &[u8], hints: &[u8]) -> f32 {
+		let is_letter = |byte: u8| -> bool {
+			self.single_byte_to_char(byte)
+				.and_then(|ch| char::from_u32(ch as _))
+				.map(|ch| ch.is_alphabetic())
+				.unwrap_or(false)
+		};
+
+		let mut num_non_ansi = 0;
+		let mut num_plausible = 0;
+		let mut num_in_runs = 0; // bytes in runs of 3 or more non-ASCII letters
+		let mut num_hints = 0;
+		let mut run_len = 0;
+
+		for (idx, byte) in data.iter().copied().enumerate() {
+			if byte > 0x7f && is_letter(byte) {
+				run_len += 1;
+			} else {
+				if run_len >= 3 {
+					num_in_runs += run_len;
+				}
+				run_len = 0;
+			}
+			if byte <= 0x7f {
+				continue;
+			}
+
+			num_non_ansi += 1;
+			if hints.contains(&byte) {
+				num_hints += 1;
+			}
+			let in_word = (idx > 0 && is_letter(data[idx - 1]))
+				&& (idx + 1 < data.len() && is_letter(data[idx + 1]));
+			if is_letter(byte) || !in_word {
+				num_plausible += 1;
+			} else {
+				num_plausible -= 1; // a symbol between letters is suspicious
+			}
+		}
+		if run_len >= 3 {
+			num_in_runs += run_len;
+		}
+
+		let plausible = (num_plausible.max(0) as f32 / num_non_ansi as f32).min(1.0);
+		let runs = num_in_runs as f32 / num_non_ansi as f32;
+		let hinted = num_hints as f32 / num_non_ansi as f32;
+
+		let score = if hints.is_empty() { // Cyrillic
+			plausible * (0.2 + 0.8 * runs)
+		} else { // Latin
+			plausible * (1.0 - 0.8 * runs) * (0.6 + 0.4 * hinted)
+		};
+		0.9 * score // single-byte code pages are never certain
+	}
+}
+
+/// A candidate encoding, returned by
+/// [`Encoding::guess_ranked`](crate::Encoding::guess_ranked).
+#[derive(Clone, Copy, PartialEq)]
+pub struct EncodingGuess {
+	/// The encoding.
+	pub encoding: Encoding,
+	/// Size of the [BOM](https://en.wikipedia.org/wiki/Byte_order_mark), if
+	/// any.
+	pub bom_size: usize,
+	/// How likely the encoding is correct, from `0.0` to `1.0`.
+	pub confidence: f32,
+}
+
+//------------------------------------------------------------------------------
+
+// Chars of the single-byte Windows code pages in the `0x80`-`0xff` range.
+// Unassigned positions map to the same value, like Windows does.
+const WIN1250_HIGH: [u16; 128] = [
+	0x20ac, 0x0081, 0x201a, 0x0083, 0x201e, 0x2026, 0x2020, 0x2021,
+	0x0088, 0x2030, 0x0160, 0x2039, 0x015a, 0x0164, 0x017d, 0x0179,
+	0x0090, 0x2018, 0x2019, 0x201c, 0x201d, 0x2022, 0x2013, 0x2014,
+	0x0098, 0x2122, 0x0161, 0x203a, 0x015b, 0x0165, 0x017e, 0x017a,
+	0x00a0, 0x02c7, 0x02d8, 0x0141, 0x00a4, 0x0104, 0x00a6, 0x00a7,
+	0x00a8, 0x00a9, 0x015e, 0x00ab, 0x00ac, 0x00ad, 0x00ae, 0x017b,
+	0x00b0, 0x00b1, 0x02db, 0x0142, 0x00b4, 0x00b5, 0x00b6, 0x00b7,
+	0x00b8, 0x0105, 0x015f, 0x00bb, 0x013d, 0x02dd, 0x013e, 0x017c,
+	0x0154, 0x00c1, 0x00c2, 0x0102, 0x00c4, 0x0139, 0x0106, 0x00c7,
+	0x010c, 0x00c9, 0x0118, 0x00cb, 0x011a, 0x00cd, 0x00ce, 0x010e,
+	0x0110, 0x0143, 0x0147, 0x00d3, 0x00d4, 0x0150, 0x00d6, 0x00d7,
+	0x0158, 0x016e, 0x00da, 0x0170, 0x00dc, 0x00dd, 0x0162, 0x00df,
+	0x0155, 0x00e1, 0x00e2, 0x0103, 0x00e4, 0x013a, 0x0107, 0x00e7,
+	0x010d, 0x00e9, 0x0119, 0x00eb, 0x011b, 0x00ed, 0x00ee, 0x010f,
+	0x0111, 0x0144, 0x0148, 0x00f3, 0x00f4, 0x0151, 0x00f6, 0x00f7,
+	0x0159, 0x016f, 0x00fa, 0x0171, 0x00fc, 0x00fd, 0x0163, 0x02d9,
+];
+const WIN1251_HIGH: [u16; 128] = [
+	0x0402, 0x0403, 0x201a, 0x0453, 0x201e, 0x2026, 0x2020, 0x2021,
+	0x20ac, 0x2030, 0x0409, 0x2039, 0x040a, 0x040c, 0x040b, 0x040f,
+	0x0452, 0x2018, 0x2019, 0x201c, 0x201d, 0x2022, 0x2013, 0x2014,
+	0x0098, 0x2122, 0x0459, 0x203a, 0x045a, 0x045c, 0x045b, 0x045f,
+	0x00a0, 0x040e, 0x045e, 0x0408, 0x00a4, 0x0490, 0x00a6, 0x00a7,
+	0x0401, 0x00a9, 0x0404, 0x00ab, 0x00ac, 0x00ad, 0x00ae, 0x0407,
+	0x00b0, 0x00b1, 0x0406, 0x0456, 0x0491, 0x00b5, 0x00b6, 0x00b7,
+	0x0451, 0x2116, 0x0454, 0x00bb, 0x0458, 0x0405, 0x0455, 0x0457,
+	0x0410, 0x0411, 0x0412, 0x0413, 0x0414, 0x0415, 0x0416, 0x0417,
+	0x0418, 0x0419, 0x041a, 0x041b, 0x041c, 0x041d, 0x041e, 0x041f,
+	0x0420, 0x0421, 0x0422, 0x0423, 0x0424, 0x0425, 0x0426, 0x0427,
+	0x0428, 0x0429, 0x042a, 0x042b, 0x042c, 0x042d, 0x042e, 0x042f,
+	0x0430, 0x0431, 0x0432, 0x0433, 0x0434, 0x0435, 0x0436, 0x0437,
+	0x0438, 0x0439, 0x043a, 0x043b, 0x043c, 0x043d, 0x043e, 0x043f,
+	0x0440, 0x0441, 0x0442, 0x0443, 0x0444, 0x0445, 0x0446, 0x0447,
+	0x0448, 0x0449, 0x044a, 0x044b, 0x044c, 0x044d, 0x044e, 0x044f,
+];
+const WIN1252_HIGH: [u16; 128] = [
+	0x20ac, 0x0081, 0x201a, 0x0192, 0x201e, 0x2026, 0x2020, 0x2021,
+	0x02c6, 0x2030, 0x0160, 0x2039, 0x0152, 0x008d, 0x017d, 0x008f,
+	0x0090, 0x2018, 0x2019, 0x201c, 0x201d, 0x2022, 0x2013, 0x2014,
+	0x02dc, 0x2122, 0x0161, 0x203a, 0x0153, 0x009d, 0x017e, 0x0178,
+	0x00a0, 0x00a1, 0x00a2, 0x00a3, 0x00a4, 0x00a5, 0x00a6, 0x00a7,
+	0x00a8, 0x00a9, 0x00aa, 0x00ab, 0x00ac, 0x00ad, 0x00ae, 0x00af,
+	0x00b0, 0x00b1, 0x00b2, 0x00b3, 0x00b4, 0x00b5, 0x00b6, 0x00b7,
+	0x00b8, 0x00b9, 0x00ba, 0x00bb, 0x00bc, 0x00bd, 0x00be, 0x00bf,
+	0x00c0, 0x00c1, 0x00c2, 0x00c3, 0x00c4, 0x00c5, 0x00c6, 0x00c7,
+	0x00c8, 0x00c9, 0x00ca, 0x00cb, 0x00cc, 0x00cd, 0x00ce, 0x00cf,
+	0x00d0, 0x00d1, 0x00d2, 0x00d3, 0x00d4, 0x00d5, 0x00d6, 0x00d7,
+	0x00d8, 0x00d9, 0x00da, 0x00db, 0x00dc, 0x00dd, 0x00de, 0x00df,
+	0x00e0, 0x00e1, 0x00e2, 0x00e3, 0x00e4, 0x00e5, 0x00e6, 0x00e7,
+	0x00e8, 0x00e9, 0x00ea, 0x00eb, 0x00ec, 0x00ed, 0x00ee, 0x00ef,
+	0x00f0, 0x00f1, 0x00f2, 0x00f3, 0x00f4, 0x00f5, 0x00f6, 0x00f7,
+	0x00f8, 0x00f9, 0x00fa, 0x00fb, 0x00fc, 0x00fd, 0x00fe, 0x00ff,
+];
+
+// Non-ASCII bytes of letters which are common in languages written with
+// Windows-1250 (Polish, Czech, Slovak, Hungarian...), but rare or not letters
+// in Windows-1252, and vice-versa.
+const WIN1250_HINTS: [u8; 22] = [
+	0x8c, 0x8f, 0x9c, 0x9f, 0xa3, 0xa5, 0xaf, 0xb3, 0xb9, 0xbf, 0xc8, 0xcc,
+	0xd8, 0xd9, 0xe6, 0xe8, 0xea, 0xec, 0xf1, 0xf5, 0xf8, 0xf9,
+];
+const WIN1252_HINTS: [u8; 18] = [
+	0xa1, 0xbf, 0xc0, 0xc7, 0xc9, 0xe0, 0xe2, 0xe3, 0xe5, 0xe7, 0xe8, 0xe9,
+	0xea, 0xee, 0xf1, 0xf4, 0xf5, 0xf8,
+];
+
+#[cfg(test)]
+mod tests {
+	use crate::co;
+	use crate::decl::*;
+	use super::*;
+
+	fn ranked(data: &[u8]) -> Vec<(String, usize)> {
+		Encoding::guess_ranked(data)
+			.iter()
+			.map(|g| (g.encoding.to_string(), g.bom_size))
+			.collect()
+	}
+
+	fn best(data: &[u8]) -> String {
+		Encoding::guess(data).0.to_string()
+	}
+
+	fn utf16(s: &str, is_big_endian: bool) -> Vec<u8> {
+		s.encode_utf16()
+			.flat_map(|u| if is_big_endian { u.to_be_bytes() } else { u.to_le_bytes() })
+			.collect()
+	}
+
+	fn utf32(s: &str, is_big_endian: bool) -> Vec<u8> {
+		s.chars()
+			.flat_map(|ch| if is_big_endian { (ch as u32).to_be_bytes() } else { (ch as u32).to_le_bytes() })
+			.collect()
+	}
+
+	fn code_page(encoding: Encoding, s: &str) -> Vec<u8> {
+		s.encode_utf16()
+			.map(|u| encoding.char_to_single_byte(u).unwrap())
+			.collect()
+	}
+
+	const TEXT: &str = "Hello, world!\r\nThis is a test.\r\n";
+
+	#[test]
+	fn boms() {
+		assert_eq!(ranked(&[0xef, 0xbb, 0xbf, b'a']), [("UTF-8".to_owned(), 3)]);
+		assert_eq!(ranked(&[0xfe, 0xff, 0x00, b'a']), [("UTF-16 BE".to_owned(), 2)]);
+		assert_eq!(ranked(&[0xff, 0xfe, b'a', 0x00]), [("UTF-16 LE".to_owned(), 2)]);
+		assert_eq!(ranked(&[0x00, 0x00, 0xfe, 0xff]), [("UTF-32 BE".to_owned(), 4)]);
+		assert_eq!(ranked(&[0xff, 0xfe, 0x00, 0x00, b'a', 0x00, 0x00, 0x00]), [
+			("UTF-32 LE".to_owned(), 4),
+			("UTF-16 LE".to_owned(), 2), // BOM followed by a null char
+		]);
+		assert_eq!(ranked(&[0x0e, 0xfe, 0xff, b'a']), [("SCSU".to_owned(), 3)]);
+		assert_eq!(ranked(&[0xfb, 0xee, 0x28, b'a']), [("BOCU1".to_owned(), 3)]);
+		assert_eq!(ranked(&[]), [("ANSI".to_owned(), 0)]);
+	}
+
+	#[test]
+	fn utf16_utf32_without_bom() {
+		assert_eq!(ranked(&utf16(TEXT, false)), [("UTF-16 LE".to_owned(), 0)]);
+		assert_eq!(ranked(&utf16(TEXT, true)), [("UTF-16 BE".to_owned(), 0)]);
+		assert_eq!(best(&utf16("Hi", false)[..3]), "UTF-16 LE"); // odd trailing byte
+
+		let guesses = Encoding::guess_ranked(&utf32(TEXT, false));
+		assert!(guesses[0].encoding == Encoding::Utf32le);
+		assert!(guesses[1].encoding == Encoding::Utf16le); // also valid, but half nulls
+		assert!(guesses[0].confidence > guesses[1].confidence);
+		assert_eq!(best(&utf32(TEXT, true)), "UTF-32 BE");
+		assert_eq!(best(&utf32("a\u{1f600}b", false)), "UTF-32 LE");
+
+		// Non-Latin scripts repeat the same high byte.
+		let greek = utf16("\u{39a}\u{3b1}\u{3bb}\u{3b7}\u{3bc}\u{3ad}\u{3c1}\u{3b1} \u{3ba}\u{3cc}\u{3c3}\u{3bc}\u{3b5}", false);
+		assert_eq!(best(&greek), "UTF-16 LE");
+		assert!(Encoding::guess_ranked(&greek).iter().all(|g| g.encoding != Encoding::Utf16be));
+
+		// Unpaired surrogates rule out UTF-16.
+		assert!(Encoding::guess_ranked(&[0x00, 0xd8, b'a', 0x00])
+			.iter()
+			.all(|g| g.encoding != Encoding::Utf16le));
+	}
+
+	#[test]
+	fn code_pages() {
+		let guesses = ranked(&code_page(Encoding::Win1252,
+			"A\u{e7}\u{e3}o e cora\u{e7}\u{e3}o, n\u{e3}o \u{e9}? Tr\u{e8}s bien, gar\u{e7}on."));
+		assert_eq!(guesses[0].0, "Windows 1252");
+		assert_eq!(guesses.len(), 3); // the other code pages are still candidates
+
+		assert_eq!(best(&code_page(Encoding::Win1250,
+			"Za\u{17c}\u{f3}\u{142}\u{107} g\u{119}\u{15b}l\u{105} ja\u{17a}\u{144}. P\u{159}\u{ed}li\u{161} \u{17e}lu\u{165}ou\u{10d}k\u{fd} k\u{16f}\u{148}.")),
+			"Windows 1250");
+		assert_eq!(best(&code_page(Encoding::Win1251,
+			"\u{421}\u{44a}\u{435}\u{448}\u{44c} \u{436}\u{435} \u{435}\u{449}\u{451} \u{44d}\u{442}\u{438}\u{445} \u{43c}\u{44f}\u{433}\u{43a}\u{438}\u{445} \u{431}\u{443}\u{43b}\u{43e}\u{43a}.")),
+			"Windows 1251");
+
+		assert_eq!(ranked("A\u{e7}\u{e3}o \u{e9} cora\u{e7}\u{e3}o".as_bytes()), [("UTF-8".to_owned(), 0)]);
+		assert_eq!(ranked(b"plain text\0"), [("ANSI".to_owned(), 0), ("UTF-8".to_owned(), 0)]);
+
+		assert_eq!(best(b"abc\xc3\xa9\0def"), "UTF-8"); // a stray null doesn't rule out UTF-8
+	}
+
+	#[test]
+	fn binary() {
+		let data = [0x00, 0xdc, 0xdc, 0x00, 0x00, 0xdc, 0xdc, 0x00];
+		let guesses = Encoding::guess_ranked(&data);
+		assert_eq!(guesses.len(), 1);
+		assert!(guesses[0].encoding == Encoding::Unknown);
+		assert_eq!(guesses[0].confidence, 0.0);
+		assert_eq!(WString::parse(&data).err(), Some(co::ERROR::INVALID_DATA));
+	}
 }
@@ -0,0 +1,256 @@
+use std::cmp::Ordering;
+
+use crate::co;
+use crate::decl::*;
+
+/// The environment variables of a process, as a list of name/value pairs.
+///
+/// Variable names are case-insensitive, and the original order is kept. The
+/// hidden per-drive entries, like `=C:=C:\work`, are kept as variables named
+/// `=C:`.
+///
+/// Can be retrieved with [`GetEnvironmentStrings`](crate::GetEnvironmentStrings),
+/// and passed to [`HPROCESS::CreateProcess`](crate::prelude::kernel_Hprocess::CreateProcess).
+///
+/// # Examples
+///
+/// Launching a process with a modified copy of the current environment:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let mut env = w::GetEnvironmentStrings()?;
+/// env.set("MY_VAR", "hello")?;
+/// env.remove("TEMP");
+///
+/// let mut si = w::STARTUPINFO::default();
+/// let pi = w::HPROCESS::CreateProcess(
+///     None,
+///     Some("cmd.exe /c set"),
+///     None,
+///     None,
+///     false,
+///     w::co::CREATE::NoValue,
+///     Some(&env),
+///     None,
+///     &mut si,
+/// )?;
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EnvironmentBlock {
+	vars: Vec<(String, String)>,
+}
+
+impl EnvironmentBlock {
+	/// Creates a new, empty block.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { vars: Vec::new() }
+	}
+
+	/// Parses a double-null-terminated block of `name=value` strings, as
+	/// returned by
+	/// [`GetEnvironmentStrings`](https://learn.microsoft.com/en-us/windows/win32/api/processenv/nf-processenv-getenvironmentstringsw).
+	/// The block ends at the first empty string, or at the end of the slice.
+	///
+	/// The name is split at the first `=` after its first char, so entries
+	/// like `=C:=C:\work` are parsed correctly.
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// an entry has no `=`.
+	pub fn parse(block: &[u16]) -> SysResult<Self> {
+		let mut vars = Vec::<(String, String)>::new();
+		for entry in block.split(|ch| *ch == 0) {
+			if entry.is_empty() {
+				break; // end of block
+			}
+			let entry = String::from_utf16_lossy(entry);
+			let idx_eq = entry.char_indices()
+				.skip(1) // name may start with '='
+				.find(|(_, ch)| *ch == '=')
+				.map(|(idx, _)| idx)
+				.ok_or(co::ERROR::INVALID_DATA)?;
+			vars.push((entry[..idx_eq].to_owned(), entry[idx_eq + 1..].to_owned()));
+		}
+		Ok(Self { vars })
+	}
+
+	/// Serializes the variables into a double-null-terminated block, sorted by
+	/// name, case-insensitive, as required by
+	/// [`CreateProcess`](crate::prelude::kernel_Hprocess::CreateProcess) with
+	/// [`co::CREATE::UNICODE_ENVIRONMENT`](crate::co::CREATE::UNICODE_ENVIRONMENT).
+	#[must_use]
+	pub fn serialize(&self) -> Vec<u16> {
+		let mut sorted = self.vars.iter().collect::<Vec<_>>();
+		sorted.sort_by(|(a, _), (b, _)| cmp_names(a, b)); // stable
+
+		let mut buf = Vec::<u16>::new();
+		for (name, val) in sorted.iter() {
+			buf.extend(name.encode_utf16());
+			buf.push('=' as _);
+			buf.extend(val.encode_utf16());
+			buf.push(0x0000);
+		}
+		if buf.is_empty() {
+			buf.push(0x0000); // empty block still has two nulls
+		}
+		buf.push(0x0000);
+		buf
+	}
+
+	/// Returns the value of the variable, case-insensitive.
+	#[must_use]
+	pub fn get(&self, name: &str) -> Option<&str> {
+		self.position(name)
+			.map(|idx| self.vars[idx].1.as_str())
+	}
+
+	/// Returns an iterator over the name/value pairs, in their original order.
+	#[must_use]
+	pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+		self.vars.iter()
+			.map(|(name, val)| (name.as_str(), val.as_str()))
+	}
+
+	/// Returns `true` if there are no variables.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.vars.is_empty()
+	}
+
+	/// Returns the number of variables.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.vars.len()
+	}
+
+	/// Removes the variable, case-insensitive, returning its value.
+	pub fn remove(&mut self, name: &str) -> Option<String> {
+		self.position(name)
+			.map(|idx| self.vars.remove(idx).1)
+	}
+
+	/// Sets the value of the variable. If it already exists, case-insensitive,
+	/// its name and value are replaced in place; otherwise it's appended.
+	///
+	/// Returns
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if the name is empty or has a `=` after its first char, or if the name
+	/// or the value have a null char.
+	pub fn set(&mut self, name: &str, val: &str) -> SysResult<()> {
+		if name.is_empty()
+			|| name.chars().skip(1).any(|ch| ch == '=')
+			|| name.contains('\0')
+			|| val.contains('\0')
+		{
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+
+		match self.position(name) {
+			Some(idx) => self.vars[idx] = (name.to_owned(), val.to_owned()),
+			None => self.vars.push((name.to_owned(), val.to_owned())),
+		}
+		Ok(())
+	}
+
+	fn position(&self, name: &str) -> Option<usize> {
+		self.vars.iter()
+			.position(|(cur, _)| cmp_names(cur, name) == Ordering::Equal)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Compares variable names case-insensitive, by their uppercase UTF-16 chars,
+/// without regard to locale.
+fn cmp_names(a: &str, b: &str) -> Ordering {
+	a.to_uppercase().encode_utf16()
+		.cmp(b.to_uppercase().encode_utf16())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn block(s: &str) -> Vec<u16> {
+		s.encode_utf16().collect()
+	}
+
+	#[test]
+	fn parse() {
+		let env = EnvironmentBlock::parse(
+			&block("=C:=C:\\work\0=::=::\\\0Path=C:\\a;C:\\b\0EMPTY=\0EQ=a=b\0\0IGNORED=1\0\0"),
+		).unwrap();
+		assert_eq!(env.iter().collect::<Vec<_>>(), [
+			("=C:", "C:\\work"),
+			("=::", "::\\"),
+			("Path", "C:\\a;C:\\b"),
+			("EMPTY", ""),
+			("EQ", "a=b"),
+		]);
+		assert_eq!(env.get("=c:"), Some("C:\\work"));
+
+		let unterminated = EnvironmentBlock::parse(&block("A=1\0B=2")).unwrap();
+		assert_eq!(unterminated.len(), 2);
+		assert_eq!(unterminated.get("B"), Some("2"));
+
+		assert!(EnvironmentBlock::parse(&[]).unwrap().is_empty());
+		assert!(EnvironmentBlock::parse(&[0, 0]).unwrap().is_empty());
+		assert_eq!(EnvironmentBlock::parse(&block("A=1\0NOEQ\0\0")), Err(co::ERROR::INVALID_DATA));
+		assert_eq!(EnvironmentBlock::parse(&block("=\0\0")), Err(co::ERROR::INVALID_DATA));
+	}
+
+	#[test]
+	fn case_insensitive() {
+		let mut env = EnvironmentBlock::new();
+		env.set("Path", "C:\\a").unwrap();
+		env.set("TEMP", "C:\\Temp").unwrap();
+		env.set("\u{e7}\u{e3}o", "1").unwrap();
+		assert_eq!(env.get("PATH"), Some("C:\\a"));
+		assert_eq!(env.get("temp"), Some("C:\\Temp"));
+		assert_eq!(env.get("\u{c7}\u{c3}O"), Some("1"));
+		assert_eq!(env.get("PATHS"), None);
+
+		env.set("PATH", "C:\\b").unwrap(); // replaced in place, with the new name
+		assert_eq!(env.len(), 3);
+		assert_eq!(env.iter().next(), Some(("PATH", "C:\\b")));
+
+		assert_eq!(env.remove("path"), Some("C:\\b".to_owned()));
+		assert_eq!(env.remove("Path"), None);
+		assert_eq!(env.iter().collect::<Vec<_>>(), [("TEMP", "C:\\Temp"), ("\u{e7}\u{e3}o", "1")]);
+	}
+
+	#[test]
+	fn set_invalid() {
+		let mut env = EnvironmentBlock::new();
+		assert_eq!(env.set("", "a"), Err(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(env.set("A=B", "a"), Err(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(env.set("A\0", "a"), Err(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(env.set("A", "a\0b"), Err(co::ERROR::INVALID_PARAMETER));
+		assert!(env.is_empty());
+
+		env.set("=D:", "D:\\").unwrap();
+		env.set("A", "x=y").unwrap();
+		assert_eq!(env.get("=d:"), Some("D:\\"));
+		assert_eq!(env.get("A"), Some("x=y"));
+	}
+
+	#[test]
+	fn serialize() {
+		assert_eq!(EnvironmentBlock::new().serialize(), [0, 0]);
+
+		let mut env = EnvironmentBlock::new();
+		env.set("b", "2").unwrap();
+		env.set("_U", "3").unwrap(); // '_' sorts after the uppercase letters
+		env.set("A", "1").unwrap();
+		env.set("=C:", "C:\\").unwrap();
+		let buf = env.serialize();
+		assert_eq!(buf, block("=C:=C:\\\0A=1\0b=2\0_U=3\0\0"));
+		assert_eq!(EnvironmentBlock::parse(&buf).unwrap().len(), 4);
+
+		let mut env = EnvironmentBlock::new();
+		env.set("x", "").unwrap();
+		assert_eq!(env.serialize(), block("x=\0\0"));
+	}
+}
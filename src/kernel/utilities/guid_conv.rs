@@ -0,0 +1,256 @@
+use std::str::FromStr;
+
+use crate::co;
+use crate::decl::*;
+
+impl FromStr for GUID {
+	type Err = co::ERROR;
+
+	/// Parses the `GUID` with [`GUID::parse`](crate::GUID::parse).
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::parse(s)
+	}
+}
+
+impl TryFrom<&str> for GUID {
+	type Error = co::ERROR;
+
+	/// Parses the `GUID` with [`GUID::parse`](crate::GUID::parse).
+	fn try_from(value: &str) -> Result<Self, Self::Error> {
+		Self::parse(value)
+	}
+}
+
+impl GUID {
+	/// Name space for fully-qualified domain names, to be used with
+	/// [`GUID::new_v5`](crate::GUID::new_v5).
+	pub const NAMESPACE_DNS: Self = Self::new("6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+	/// Name space for ISO OIDs, to be used with
+	/// [`GUID::new_v5`](crate::GUID::new_v5).
+	pub const NAMESPACE_OID: Self = Self::new("6ba7b812-9dad-11d1-80b4-00c04fd430c8");
+	/// Name space for URLs, to be used with
+	/// [`GUID::new_v5`](crate::GUID::new_v5).
+	pub const NAMESPACE_URL: Self = Self::new("6ba7b811-9dad-11d1-80b4-00c04fd430c8");
+	/// Name space for X.500 DNs, to be used with
+	/// [`GUID::new_v5`](crate::GUID::new_v5).
+	pub const NAMESPACE_X500: Self = Self::new("6ba7b814-9dad-11d1-80b4-00c04fd430c8");
+
+	/// Creates a `GUID` from its binary representation in memory, where the
+	/// first three fields are little-endian. This is the layout used by
+	/// Windows, like in registry values and in resource files.
+	#[must_use]
+	pub const fn from_bytes(bytes: &[u8; 16]) -> Self {
+		Self {
+			data1: u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+			data2: u16::from_le_bytes([bytes[4], bytes[5]]),
+			data3: u16::from_le_bytes([bytes[6], bytes[7]]),
+			data4: u64::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11],
+				bytes[12], bytes[13], bytes[14], bytes[15]]),
+		}
+	}
+
+	/// Creates a `GUID` from its binary representation as specified by
+	/// [RFC 4122](https://www.rfc-editor.org/rfc/rfc4122), where all fields
+	/// are big-endian. This is the byte order of the string representation.
+	#[must_use]
+	pub const fn from_bytes_rfc4122(bytes: &[u8; 16]) -> Self {
+		Self {
+			data1: u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+			data2: u16::from_be_bytes([bytes[4], bytes[5]]),
+			data3: u16::from_be_bytes([bytes[6], bytes[7]]),
+			data4: u64::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11],
+				bytes[12], bytes[13], bytes[14], bytes[15]]),
+		}
+	}
+
+	/// Creates a name-based `GUID`, version 5, which is the SHA-1 hash of the
+	/// name space and the name, as specified by
+	/// [RFC 4122](https://www.rfc-editor.org/rfc/rfc4122#section-4.3). The
+	/// same name in the same name space always yields the same `GUID`.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let g = w::GUID::new_v5(&w::GUID::NAMESPACE_DNS, b"www.example.com");
+	/// assert_eq!(g.to_string(), "2ed6657d-e927-568b-95e1-2665a8aea6a2");
+	/// ```
+	#[must_use]
+	pub fn new_v5(namespace: &GUID, name: &[u8]) -> Self {
+		let mut data = namespace.to_bytes_rfc4122().to_vec();
+		data.extend_from_slice(name);
+		let hash = sha1(&data);
+
+		let mut bytes = [0u8; 16];
+		bytes.copy_from_slice(&hash[..16]);
+		bytes[6] = (bytes[6] & 0x0f) | 0x50; // version 5
+		bytes[8] = (bytes[8] & 0x3f) | 0x80; // RFC 4122 variant
+		Self::from_bytes_rfc4122(&bytes)
+	}
+
+	/// Parses a `GUID` from a string, in any of the formats of
+	/// [`GuidFmt`](crate::GuidFmt), case-insensitive. Surrounding whitespaces
+	/// are ignored.
+	///
+	/// Unlike [`GUID::new`](crate::GUID::new), which panics, this function is
+	/// suited to values coming from files or user input.
+	///
+	/// Returns
+	/// [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if the
+	/// string is malformed.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let g1 = w::GUID::parse("{43826D1E-E718-42EE-BC55-A1E261C37BFE}")?;
+	/// let g2: w::GUID = "urn:uuid:43826d1e-e718-42ee-bc55-a1e261c37bfe".parse()?;
+	/// assert_eq!(g1, g2);
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	pub fn parse(guid_str: &str) -> SysResult<Self> {
+		let s = guid_str.trim();
+		let s = if let Some(inner) = s.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+			inner
+		} else if let Some(inner) = s.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+			inner
+		} else if s.get(..9).is_some_and(|prefix| prefix.eq_ignore_ascii_case("urn:uuid:")) {
+			&s[9..]
+		} else {
+			s
+		};
+
+		let hex = match s.len() {
+			32 => s.to_owned(),
+			36 => {
+				let bytes = s.as_bytes();
+				if [8, 13, 18, 23].iter().any(|&idx| bytes[idx] != b'-') {
+					return Err(co::ERROR::INVALID_DATA);
+				}
+				s.replace('-', "")
+			},
+			_ => return Err(co::ERROR::INVALID_DATA),
+		};
+		if hex.len() != 32 || !hex.bytes().all(|ch| ch.is_ascii_hexdigit()) {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+
+		let num = u128::from_str_radix(&hex, 16)
+			.map_err(|_| co::ERROR::INVALID_DATA)?;
+		Ok(Self::from_bytes_rfc4122(&num.to_be_bytes()))
+	}
+
+	/// Returns the binary representation in memory, where the first three
+	/// fields are little-endian. This is the layout used by Windows, like in
+	/// registry values and in resource files.
+	#[must_use]
+	pub const fn to_bytes(&self) -> [u8; 16] {
+		let d1 = self.data1.to_le_bytes();
+		let d2 = self.data2.to_le_bytes();
+		let d3 = self.data3.to_le_bytes();
+		let d4 = self.data4.to_le_bytes();
+		[d1[0], d1[1], d1[2], d1[3], d2[0], d2[1], d3[0], d3[1],
+			d4[0], d4[1], d4[2], d4[3], d4[4], d4[5], d4[6], d4[7]]
+	}
+
+	/// Returns the binary representation as specified by
+	/// [RFC 4122](https://www.rfc-editor.org/rfc/rfc4122), where all fields
+	/// are big-endian. This is the byte order of the string representation.
+	#[must_use]
+	pub const fn to_bytes_rfc4122(&self) -> [u8; 16] {
+		let d1 = self.data1.to_be_bytes();
+		let d2 = self.data2.to_be_bytes();
+		let d3 = self.data3.to_be_bytes();
+		let d4 = self.data4.to_le_bytes();
+		[d1[0], d1[1], d1[2], d1[3], d2[0], d2[1], d3[0], d3[1],
+			d4[0], d4[1], d4[2], d4[3], d4[4], d4[5], d4[6], d4[7]]
+	}
+
+	/// Formats the `GUID` as a lowercase string in the given format. The
+	/// [`Display`](std::fmt::Display) implementation uses
+	/// [`GuidFmt::Hyphenated`](crate::GuidFmt::Hyphenated).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let g = w::GUID::new("43826d1e-e718-42ee-bc55-a1e261c37bfe");
+	/// let braced = g.to_string_fmt(w::GuidFmt::Braced).to_uppercase();
+	/// assert_eq!(braced, "{43826D1E-E718-42EE-BC55-A1E261C37BFE}");
+	/// ```
+	#[must_use]
+	pub fn to_string_fmt(&self, fmt: GuidFmt) -> String {
+		match fmt {
+			GuidFmt::Braced => format!("{{{}}}", self),
+			GuidFmt::Hyphenated => self.to_string(),
+			GuidFmt::Parenthesized => format!("({})", self),
+			GuidFmt::Simple => format!("{:032x}", u128::from_be_bytes(self.to_bytes_rfc4122())),
+			GuidFmt::Urn => format!("urn:uuid:{}", self),
+		}
+	}
+
+	/// Returns the version number, which is meaningful only if the `GUID`
+	/// follows the RFC 4122 variant – for example, 4 for random and 5 for
+	/// name-based ones.
+	#[must_use]
+	pub const fn version(&self) -> u8 {
+		(self.data3 >> 12) as _
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Computes the SHA-1 hash of the data.
+fn sha1(data: &[u8]) -> [u8; 20] {
+	let mut h: [u32; 5] = [0x6745_2301, 0xefcd_ab89, 0x98ba_dcfe, 0x1032_5476, 0xc3d2_e1f0];
+
+	let mut msg = data.to_vec();
+	msg.push(0x80);
+	while msg.len() % 64 != 56 {
+		msg.push(0);
+	}
+	msg.extend_from_slice(&((data.len() as u64) * 8).to_be_bytes());
+
+	for block in msg.chunks_exact(64) {
+		let mut w = [0u32; 80];
+		for (i, word) in block.chunks_exact(4).enumerate() {
+			w[i] = u32::from_be_bytes(word.try_into().unwrap());
+		}
+		for i in 16..80 {
+			w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+		}
+
+		let [mut a, mut b, mut c, mut d, mut e] = h;
+		for (i, wi) in w.iter().enumerate() {
+			let (f, k) = match i {
+				0..=19 => ((b & c) | (!b & d), 0x5a82_7999),
+				20..=39 => (b ^ c ^ d, 0x6ed9_eba1),
+				40..=59 => ((b & c) | (b & d) | (c & d), 0x8f1b_bcdc),
+				_ => (b ^ c ^ d, 0xca62_c1d6),
+			};
+			let temp = a.rotate_left(5)
+				.wrapping_add(f)
+				.wrapping_add(e)
+				.wrapping_add(k)
+				.wrapping_add(*wi);
+			e = d;
+			d = c;
+			c = b.rotate_left(30);
+			b = a;
+			a = temp;
+		}
+
+		for (hi, val) in h.iter_mut().zip([a, b, c, d, e]) {
+			*hi = hi.wrapping_add(val);
+		}
+	}
+
+	let mut hash = [0u8; 20];
+	for (chunk, hi) in hash.chunks_exact_mut(4).zip(h) {
+		chunk.copy_from_slice(&hi.to_be_bytes());
+	}
+	hash
+}
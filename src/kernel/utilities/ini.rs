@@ -3,6 +3,10 @@ use crate::decl::*;
 /// High-level abstraction to load, manage and serialize sections and key/value
 /// pairs of a `.ini` file.
 ///
+/// The document is lossless: comments, blank lines, whitespace, line breaks
+/// and duplicated keys are all kept, so an unchanged `Ini` is serialized back
+/// byte-for-byte. When a value is changed, only its own line is rewritten.
+///
 /// # Examples
 ///
 /// Printing all sections, keys and values:
@@ -34,53 +38,113 @@ use crate::decl::*;
 pub struct Ini {
 	/// All the sections of the file. They can be modified at will.
 	pub sections: Vec<IniSection>,
+	trailing: Vec<String>,
+	eol: &'static str,
+	encoding: Option<(Encoding, bool)>,
+}
+
+impl Default for Ini {
+	fn default() -> Self {
+		Self {
+			sections: Vec::default(),
+			trailing: Vec::default(),
+			eol: "\r\n",
+			encoding: None,
+		}
+	}
 }
 
 impl Ini {
 	/// Parses an `Ini` from a string.
+	///
+	/// Lines which are neither sections nor key/value pairs – like comments
+	/// starting with `;` or `#`, and blank lines – are kept untouched.
 	#[must_use]
 	pub fn parse_str(contents: &str) -> Self {
 		let mut sections = Vec::<IniSection>::default();
 		let mut cur_section = IniSection {
 			name: "".to_owned(),
 			entries: Vec::<IniEntry>::default(),
+			header: IniHeader::Absent,
+			trivia: Vec::<String>::default(),
 		};
+		let mut trivia = Vec::<String>::default(); // lines waiting for the next section or entry
 
-		for line in contents.lines() {
-			let line = line.trim();
+		for raw_line in contents.split_inclusive('\n') {
+			let (text, eol) = if let Some(text) = raw_line.strip_suffix("\r\n") {
+				(text, "\r\n")
+			} else if let Some(text) = raw_line.strip_suffix('\n') {
+				(text, "\n")
+			} else {
+				(raw_line, "") // last line without line break
+			};
+			let line = text.trim();
+			let indent = &text[..text.len() - text.trim_start().len()];
+			let trailing = &text[text.trim_end().len()..];
 
-			if line.starts_with('[') && line.ends_with(']') {
-				if cur_section.name != "" || !cur_section.entries.is_empty() {
+			if line.len() >= 2 && line.starts_with('[') && line.ends_with(']') {
+				if cur_section.header != IniHeader::Absent || !cur_section.entries.is_empty() {
 					sections.push(cur_section);
 				}
 				cur_section = IniSection {
 					name: line[1..line.len() - 1].to_owned(),
 					entries: Vec::<IniEntry>::default(),
+					header: IniHeader::Parsed(IniLineFmt::new(indent, "", trailing, eol)),
+					trivia: std::mem::take(&mut trivia),
 				};
 				continue;
 			}
 
-			if let Some(eq_idx) = line.find('=') {
-				cur_section.entries.push(IniEntry {
-					key: line[..eq_idx].to_owned(),
-					val: line[eq_idx + 1..].to_owned(),
-				});
+			if !line.starts_with(';') && !line.starts_with('#') {
+				if let Some(eq_idx) = line.find('=') {
+					let (key_part, val_part) = (&line[..eq_idx], &line[eq_idx + 1..]);
+					let key = key_part.trim_end();
+					let val = val_part.trim_start();
+					let sep = format!("{}={}",
+						&key_part[key.len()..], &val_part[..val_part.len() - val.len()]);
+
+					cur_section.entries.push(IniEntry {
+						key: key.to_owned(),
+						val: val.to_owned(),
+						fmt: Some(IniLineFmt::new(indent, &sep, trailing, eol)),
+						trivia: std::mem::take(&mut trivia),
+					});
+					continue;
+				}
 			}
+
+			trivia.push(raw_line.to_owned()); // comment, blank or unrecognized line
 		}
-		if cur_section.name != "" || !cur_section.entries.is_empty() {
+		if cur_section.header != IniHeader::Absent || !cur_section.entries.is_empty() {
 			sections.push(cur_section);
 		}
 
-		Self { sections }
+		Self {
+			sections,
+			trailing: trivia,
+			eol: match contents.find('\n') {
+				Some(idx) if idx > 0 && contents.as_bytes()[idx - 1] == b'\r' => "\r\n",
+				Some(_) => "\n",
+				None => "\r\n",
+			},
+			encoding: None,
+		}
 	}
 
 	/// Parses an `Ini` from raw bytes with
 	/// [`WString::parse`](crate::WString::parse).
+	///
+	/// The encoding is stored, and later used by
+	/// [`Ini::serialize_to_bytes`](crate::Ini::serialize_to_bytes).
 	#[must_use]
 	pub fn parse_bytes(bytes: &[u8]) -> SysResult<Self> {
-		Ok(
-			Self::parse_str(&WString::parse(bytes)?.to_string()),
-		)
+		let (encoding, sz_bom) = Encoding::guess(bytes);
+		let mut new_self = Self::parse_str(&WString::parse(bytes)?.to_string());
+		new_self.encoding = match encoding {
+			Encoding::Ansi | Encoding::Utf8 if sz_bom == 0 => None, // UTF-8 is the default
+			_ => Some((encoding, sz_bom > 0)),
+		};
+		Ok(new_self)
 	}
 
 	/// Parses an `Ini` directly from a file with
@@ -118,48 +182,66 @@ impl Ini {
 	}
 
 	/// Serializes the sections and entries to a string.
+	///
+	/// Parsed lines keep their original formatting. New sections and entries
+	/// use the line break of the parsed contents, which defaults to `\r\n`.
 	#[must_use]
 	pub fn serialize_to_str(&self) -> String {
-		let mut tot_size = 0;
-		for (idx, section) in self.sections.iter().enumerate() {
-			tot_size += section.name.len() + 2 + 2;
-			for entry in section.entries.iter() {
-				tot_size += entry.key.len() + 1 + entry.val.len() + 2;
+		let mut buf = String::default();
+		let push_line = |buf: &mut String, line: &str| {
+			if !buf.is_empty() && !buf.ends_with('\n') {
+				buf.push_str(self.eol); // previous last line had no line break
 			}
-			if idx < self.sections.len() - 1 {
-				tot_size += 2;
-			}
-		}
+			buf.push_str(line);
+		};
 
-		let mut buf = String::with_capacity(tot_size);
 		for (idx, section) in self.sections.iter().enumerate() {
-			buf.push('[');
-			buf.push_str(&section.name);
-			buf.push_str("]\r\n");
-
-			for entry in section.entries.iter() {
-				buf.push_str(&entry.key);
-				buf.push('=');
-				buf.push_str(&entry.val);
-				buf.push_str("\r\n");
+			section.trivia.iter().for_each(|line| push_line(&mut buf, line));
+			match &section.header {
+				IniHeader::Absent => {},
+				IniHeader::Parsed(fmt) => push_line(&mut buf,
+					&fmt.format(&format!("[{}]", section.name), "", self.eol)),
+				IniHeader::New => {
+					if idx > 0 {
+						push_line(&mut buf, self.eol); // blank line between sections
+					}
+					push_line(&mut buf, &format!("[{}]{}", section.name, self.eol));
+				},
 			}
 
-			if idx < self.sections.len() - 1 {
-				buf.push_str("\r\n");
+			for entry in section.entries.iter() {
+				entry.trivia.iter().for_each(|line| push_line(&mut buf, line));
+				push_line(&mut buf, &match &entry.fmt {
+					Some(fmt) => fmt.format(&entry.key, &entry.val, self.eol),
+					None => format!("{}={}{}", entry.key, entry.val, self.eol),
+				});
 			}
 		}
+
+		self.trailing.iter().for_each(|line| push_line(&mut buf, line));
 		buf
 	}
 
-	/// Serializes the sections and entries to raw bytes with
+	/// Serializes the sections and entries to raw bytes.
+	///
+	/// If the `Ini` was parsed from bytes, the original encoding and BOM are
+	/// kept with [`WString::serialize`](crate::WString::serialize), and chars
+	/// which cannot be represented are replaced by `?`. Otherwise, the string
+	/// is converted with
 	/// [`String::into_bytes`](std::string::String::into_bytes).
 	#[must_use]
 	pub fn serialize_to_bytes(&self) -> Vec<u8> {
-		self.serialize_to_str().into_bytes()
+		let contents = self.serialize_to_str();
+		match self.encoding {
+			Some((encoding, with_bom)) => WString::from_str(&contents)
+				.serialize(encoding, with_bom, UnmappableChar::Replace(b'?'))
+				.unwrap_or_else(|_| contents.into_bytes()),
+			None => contents.into_bytes(),
+		}
 	}
 
 	/// Serializes the data directly to a file with
-	/// [`String::into_bytes`](std::string::String::into_bytes).
+	/// [`Ini::serialize_to_bytes`](crate::Ini::serialize_to_bytes).
 	pub fn serialize_to_file(&self, ini_path: &str) -> SysResult<()> {
 		let fout = File::open(ini_path, FileAccess::OpenOrCreateRW)?;
 		fout.erase_and_write(&self.serialize_to_bytes())?;
@@ -168,6 +250,9 @@ impl Ini {
 
 	/// If the section/key exists, changes its value, otherwise creates it.
 	///
+	/// Only the line of the value is changed; the rest of the document keeps
+	/// its formatting.
+	///
 	/// # Examples
 	///
 	/// Changing or creating a value:
@@ -184,22 +269,51 @@ impl Ini {
 	/// # Ok::<_, winsafe::co::ERROR>(())
 	/// ```
 	pub fn set_value(&mut self, section: &str, key: &str, new_value: &str) {
-		match self.find_section_mut(section) {
-			Some(sec) => match sec.find_entry_mut(key) {
-				Some(ent) => ent.val = new_value.to_owned(),
-				None => sec.entries.push(IniEntry { // entry does not exist in section
-					key: key.to_owned(),
-					val: new_value.to_owned(),
-				}),
+		self.section_or_new(section).set_value(key, new_value);
+	}
+
+	/// If the section/key exists, changes its value, otherwise creates it. The
+	/// value is formatted with [`Display`](std::fmt::Display).
+	pub fn set_value_as<T: std::fmt::Display>(&mut self,
+		section: &str, key: &str, new_value: T)
+	{
+		self.section_or_new(section).set_value_as(key, new_value);
+	}
+
+	/// If the section/key exists, changes its value, otherwise creates it.
+	///
+	/// If the current value is a boolean, its style is kept (`yes`/`no`,
+	/// `true`/`false` or `on`/`off`), otherwise `1` or `0` is written.
+	pub fn set_value_bool(&mut self, section: &str, key: &str, new_value: bool) {
+		self.section_or_new(section).set_value_bool(key, new_value);
+	}
+
+	/// If the section/key exists, changes its value, otherwise creates it. The
+	/// items are formatted with [`Display`](std::fmt::Display) and joined with
+	/// `sep`.
+	pub fn set_value_list<T: std::fmt::Display>(&mut self,
+		section: &str, key: &str, new_values: &[T], sep: char)
+	{
+		self.section_or_new(section).set_value_list(key, new_values, sep);
+	}
+
+	/// Returns the section with the given name, creating it if needed. The
+	/// section with an empty name is the one before the first header.
+	pub(in crate::kernel) fn section_or_new(&mut self, name: &str) -> &mut IniSection {
+		let idx = match self.sections.iter()
+			.position(|sec| sec.name.to_uppercase() == name.to_uppercase())
+		{
+			Some(idx) => idx,
+			None if name.is_empty() => {
+				self.sections.insert(0, IniSection::new(name)); // entries without header must come first
+				0
 			},
-			None => self.sections.push(IniSection { // section does not exist
-				name: section.to_owned(),
-				entries: vec![IniEntry {
-					key: key.to_owned(),
-					val: new_value.to_owned(),
-				}],
-			}),
-		}
+			None => {
+				self.sections.push(IniSection::new(name));
+				self.sections.len() - 1
+			},
+		};
+		&mut self.sections[idx]
 	}
 
 	/// Returns a reference to the specified value, if any.
@@ -214,6 +328,56 @@ impl Ini {
 			})
 	}
 
+	/// Returns the specified value parsed with
+	/// [`FromStr`](std::str::FromStr), if any.
+	///
+	/// The search is case-insensitive.
+	///
+	/// # Examples
+	///
+	/// Reading an integer, with a default:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let ini = w::Ini::parse_from_file("C:\\Temp\\foo.ini")?;
+	///
+	/// let width = ini.value_as::<u32>("window", "width")?.unwrap_or(800);
+	/// # Ok::<_, Box<dyn std::error::Error>>(())
+	/// ```
+	pub fn value_as<T>(&self, section: &str, key: &str) -> Result<Option<T>, IniError>
+		where T: std::str::FromStr,
+			T::Err: std::fmt::Display,
+	{
+		self.find_section(section)
+			.map_or(Ok(None), |sec| sec.value_as(key))
+	}
+
+	/// Returns the specified value parsed as a boolean, if any.
+	///
+	/// Accepted values are `1`/`0`, `yes`/`no`, `true`/`false` and `on`/`off`,
+	/// all case-insensitive.
+	///
+	/// The search is case-insensitive.
+	pub fn value_bool(&self, section: &str, key: &str) -> Result<Option<bool>, IniError> {
+		self.find_section(section)
+			.map_or(Ok(None), |sec| sec.value_bool(key))
+	}
+
+	/// Returns the specified value split by `sep`, with each item trimmed and
+	/// parsed with [`FromStr`](std::str::FromStr), if any. An empty value
+	/// results in an empty list.
+	///
+	/// The search is case-insensitive.
+	pub fn value_list<T>(&self,
+		section: &str, key: &str, sep: char) -> Result<Option<Vec<T>>, IniError>
+		where T: std::str::FromStr,
+			T::Err: std::fmt::Display,
+	{
+		self.find_section(section)
+			.map_or(Ok(None), |sec| sec.value_list(key, sep))
+	}
+
 	/// Returns a mutable reference to the specified value, if any.
 	///
 	/// The search is case-insensitive.
@@ -253,9 +417,25 @@ pub struct IniSection {
 	pub name: String,
 	/// All key/value pairs of this section. They can be modified at will.
 	pub entries: Vec<IniEntry>,
+	header: IniHeader,
+	trivia: Vec<String>,
 }
 
 impl IniSection {
+	/// Creates a new, empty section.
+	///
+	/// A section with an empty name has no header, and its entries must be at
+	/// the beginning of the file.
+	#[must_use]
+	pub fn new(name: &str) -> Self {
+		Self {
+			name: name.to_owned(),
+			entries: Vec::<IniEntry>::default(),
+			header: if name.is_empty() { IniHeader::Absent } else { IniHeader::New },
+			trivia: Vec::<String>::default(),
+		}
+	}
+
 	/// Returns a reference to the [`IniEntry`](crate::IniEntry) with the given
 	/// key, if any.
 	///
@@ -279,6 +459,102 @@ impl IniSection {
 			.find(|ent| ent.key.to_uppercase() == key_uc)
 			.map(|ent| ent)
 	}
+
+	/// Returns a reference to the value of the given key, if any.
+	///
+	/// The search is case-insensitive.
+	#[must_use]
+	pub fn value(&self, key: &str) -> Option<&str> {
+		self.find_entry(key)
+			.map(|ent| ent.val.as_ref())
+	}
+
+	/// Returns the value of the given key parsed with
+	/// [`FromStr`](std::str::FromStr), if any.
+	///
+	/// The search is case-insensitive.
+	pub fn value_as<T>(&self, key: &str) -> Result<Option<T>, IniError>
+		where T: std::str::FromStr,
+			T::Err: std::fmt::Display,
+	{
+		self.value(key)
+			.map(|val| val.parse::<T>()
+				.map_err(|e| self.error(key, format!("invalid value \"{}\": {}", val, e))))
+			.transpose()
+	}
+
+	/// Returns the value of the given key parsed as a boolean, if any.
+	///
+	/// Accepted values are `1`/`0`, `yes`/`no`, `true`/`false` and `on`/`off`,
+	/// all case-insensitive.
+	///
+	/// The search is case-insensitive.
+	pub fn value_bool(&self, key: &str) -> Result<Option<bool>, IniError> {
+		self.value(key)
+			.map(|val| parse_bool(val)
+				.ok_or_else(|| self.error(key, format!("invalid boolean \"{}\"", val))))
+			.transpose()
+	}
+
+	/// Returns the value of the given key split by `sep`, with each item
+	/// trimmed and parsed with [`FromStr`](std::str::FromStr), if any. An empty
+	/// value results in an empty list.
+	///
+	/// The search is case-insensitive.
+	pub fn value_list<T>(&self, key: &str, sep: char) -> Result<Option<Vec<T>>, IniError>
+		where T: std::str::FromStr,
+			T::Err: std::fmt::Display,
+	{
+		self.value(key)
+			.map(|val| split_list(val, sep)
+				.map(|item| item.parse::<T>()
+					.map_err(|e| self.error(key, format!("invalid item \"{}\": {}", item, e))))
+				.collect::<Result<Vec<_>, _>>())
+			.transpose()
+	}
+
+	/// If the key exists, changes its value, otherwise creates it.
+	pub fn set_value(&mut self, key: &str, new_value: &str) {
+		match self.find_entry_mut(key) {
+			Some(ent) => ent.val = new_value.to_owned(),
+			None => self.entries.push(IniEntry::new(key, new_value)),
+		}
+	}
+
+	/// If the key exists, changes its value, otherwise creates it. The value is
+	/// formatted with [`Display`](std::fmt::Display).
+	pub fn set_value_as<T: std::fmt::Display>(&mut self, key: &str, new_value: T) {
+		self.set_value(key, &new_value.to_string());
+	}
+
+	/// If the key exists, changes its value, otherwise creates it.
+	///
+	/// If the current value is a boolean, its style is kept (`yes`/`no`,
+	/// `true`/`false` or `on`/`off`), otherwise `1` or `0` is written.
+	pub fn set_value_bool(&mut self, key: &str, new_value: bool) {
+		let formatted = format_bool(new_value, self.value(key));
+		self.set_value(key, &formatted);
+	}
+
+	/// If the key exists, changes its value, otherwise creates it. The items
+	/// are formatted with [`Display`](std::fmt::Display) and joined with `sep`.
+	pub fn set_value_list<T: std::fmt::Display>(&mut self,
+		key: &str, new_values: &[T], sep: char)
+	{
+		let formatted = new_values.iter()
+			.map(|val| val.to_string())
+			.collect::<Vec<_>>()
+			.join(&sep.to_string());
+		self.set_value(key, &formatted);
+	}
+
+	fn error(&self, key: &str, msg: String) -> IniError {
+		IniError {
+			section: self.name.clone(),
+			key: key.to_owned(),
+			msg,
+		}
+	}
 }
 
 //------------------------------------------------------------------------------
@@ -290,4 +566,132 @@ pub struct IniEntry {
 	pub key: String,
 	/// Value of this entry.
 	pub val: String,
+	fmt: Option<IniLineFmt>,
+	trivia: Vec<String>,
+}
+
+impl IniEntry {
+	/// Creates a new key/value pair.
+	#[must_use]
+	pub fn new(key: &str, val: &str) -> Self {
+		Self {
+			key: key.to_owned(),
+			val: val.to_owned(),
+			fmt: None,
+			trivia: Vec::<String>::default(),
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Error returned by the typed accessors of [`Ini`](crate::Ini) and
+/// [`IniSection`](crate::IniSection), telling where the offending value is.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IniError {
+	/// Name of the section of the value; empty for entries before the first
+	/// section, or if unknown.
+	pub section: String,
+	/// Key of the value; empty if unknown.
+	pub key: String,
+	/// Description of the error.
+	pub msg: String,
+}
+
+impl std::error::Error for IniError {}
+
+impl std::fmt::Display for IniError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		if self.key.is_empty() {
+			write!(f, "{}", self.msg)
+		} else {
+			write!(f, "[{}] {}: {}", self.section, self.key, self.msg)
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// How the header line of an [`IniSection`](crate::IniSection) is written.
+#[derive(PartialEq, Eq)]
+enum IniHeader {
+	/// Section created programmatically.
+	New,
+	/// Section parsed with its header line.
+	Parsed(IniLineFmt),
+	/// Entries before the first section, which have no header.
+	Absent,
+}
+
+/// The original whitespace and line break of a parsed line.
+#[derive(PartialEq, Eq)]
+struct IniLineFmt {
+	indent: String,
+	sep: String,
+	trailing: String,
+	eol: String,
+}
+
+impl IniLineFmt {
+	fn new(indent: &str, sep: &str, trailing: &str, eol: &str) -> Self {
+		Self {
+			indent: indent.to_owned(),
+			sep: sep.to_owned(),
+			trailing: trailing.to_owned(),
+			eol: eol.to_owned(),
+		}
+	}
+
+	/// Rebuilds the line with the given contents. Since the line may have been
+	/// the last one, without line break, `default_eol` is used if the line now
+	/// has a line break within.
+	fn format(&self, key: &str, val: &str, default_eol: &str) -> String {
+		format!("{}{}{}{}{}{}", self.indent, key, self.sep, val, self.trailing,
+			if self.eol.is_empty() && val.contains('\n') { default_eol } else { self.eol.as_str() })
+	}
+}
+
+/// Boolean values, in the `(true, false)` order.
+const BOOL_WORDS: [(&str, &str); 4] = [
+	("1", "0"), ("yes", "no"), ("true", "false"), ("on", "off"),
+];
+
+pub(in crate::kernel) fn parse_bool(val: &str) -> Option<bool> {
+	let val = val.trim();
+	BOOL_WORDS.iter()
+		.find_map(|(t, f)| {
+			if val.eq_ignore_ascii_case(t) {
+				Some(true)
+			} else if val.eq_ignore_ascii_case(f) {
+				Some(false)
+			} else {
+				None
+			}
+		})
+}
+
+/// Formats the boolean in the same style of the current value, if it's a
+/// boolean too, keeping the case of its first letter.
+pub(in crate::kernel) fn format_bool(val: bool, cur_val: Option<&str>) -> String {
+	let cur_val = cur_val.unwrap_or("").trim();
+	let (t, f) = BOOL_WORDS.iter()
+		.find(|(t, f)| cur_val.eq_ignore_ascii_case(t) || cur_val.eq_ignore_ascii_case(f))
+		.unwrap_or(&BOOL_WORDS[0]);
+	let word = if val { *t } else { *f };
+
+	if cur_val.chars().all(|ch| ch.is_ascii_uppercase()) && !cur_val.is_empty() {
+		word.to_uppercase()
+	} else if cur_val.starts_with(|ch: char| ch.is_ascii_uppercase()) {
+		word[..1].to_uppercase() + &word[1..]
+	} else {
+		word.to_owned()
+	}
+}
+
+/// Splits a list value, trimming the items. An empty value has no items.
+pub(in crate::kernel) fn split_list(val: &str, sep: char) -> impl Iterator<Item = &str> {
+	let val = val.trim();
+	val.split(sep)
+		.map(|item| item.trim())
+		.take(if val.is_empty() { 0 } else { usize::MAX })
 }
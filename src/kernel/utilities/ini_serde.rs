@@ -0,0 +1,1292 @@
+use serde::{de, ser};
+use serde::de::IntoDeserializer;
+
+use crate::decl::*;
+use crate::kernel::utilities::ini::{format_bool, parse_bool, split_list};
+
+impl Ini {
+	/// Loads a whole struct from the `Ini` with
+	/// [`IniDeserializer`](crate::IniDeserializer).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// #[derive(serde::Deserialize)]
+	/// struct Settings {
+	///     window: Window,
+	/// }
+	///
+	/// #[derive(serde::Deserialize)]
+	/// struct Window {
+	///     width: u32,
+	///     maximized: Option<bool>,
+	/// }
+	///
+	/// let ini = w::Ini::parse_from_file("C:\\Temp\\foo.ini")?;
+	/// let settings: Settings = ini.deserialize_struct()?;
+	/// # Ok::<_, Box<dyn std::error::Error>>(())
+	/// ```
+	pub fn deserialize_struct<'de, T>(&'de self) -> Result<T, IniError>
+		where T: de::Deserialize<'de>,
+	{
+		T::deserialize(IniDeserializer::new(self))
+	}
+
+	/// Stores a whole struct into the `Ini` with
+	/// [`IniSerializer`](crate::IniSerializer). Existing values are changed in
+	/// place, keeping the formatting of the file.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// #[derive(serde::Serialize)]
+	/// struct Settings {
+	///     window: Window,
+	/// }
+	///
+	/// #[derive(serde::Serialize)]
+	/// struct Window {
+	///     width: u32,
+	///     maximized: Option<bool>,
+	/// }
+	///
+	/// let ini_path = "C:\\Temp\\foo.ini";
+	/// let mut ini = w::Ini::parse_from_file(ini_path)?;
+	///
+	/// ini.serialize_struct(&Settings {
+	///     window: Window { width: 800, maximized: Some(true) },
+	/// })?;
+	///
+	/// ini.serialize_to_file(ini_path)?;
+	/// # Ok::<_, Box<dyn std::error::Error>>(())
+	/// ```
+	pub fn serialize_struct<T>(&mut self, value: &T) -> Result<(), IniError>
+		where T: ser::Serialize,
+	{
+		value.serialize(IniSerializer::new(self))
+	}
+}
+
+impl de::Error for IniError {
+	fn custom<T: std::fmt::Display>(msg: T) -> Self {
+		Self { section: "".to_owned(), key: "".to_owned(), msg: msg.to_string() }
+	}
+}
+
+impl ser::Error for IniError {
+	fn custom<T: std::fmt::Display>(msg: T) -> Self {
+		<Self as de::Error>::custom(msg)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// [Serde](https://serde.rs/) deserializer which reads a struct or a map from
+/// an [`Ini`](crate::Ini).
+///
+/// Each section is a nested struct or map, while entries before the first
+/// section are read as top-level fields. Values are parsed as:
+///
+/// * booleans: `1`/`0`, `yes`/`no`, `true`/`false` or `on`/`off`;
+/// * numbers and chars: with [`FromStr`](std::str::FromStr);
+/// * options: an empty value or a missing key is `None`;
+/// * sequences and tuples: items separated by commas;
+/// * enums: unit variants only, by name.
+///
+/// Sections and keys are matched case-insensitively to struct fields.
+pub struct IniDeserializer<'a> {
+	ini: &'a Ini,
+}
+
+impl<'a> IniDeserializer<'a> {
+	/// Creates a new deserializer reading from the given `Ini`.
+	#[must_use]
+	pub const fn new(ini: &'a Ini) -> Self {
+		Self { ini }
+	}
+
+	fn headerless(&self) -> Option<&'a IniSection> {
+		self.ini.sections.iter().find(|sec| sec.name.is_empty())
+	}
+}
+
+impl<'de> de::Deserializer<'de> for IniDeserializer<'de> {
+	type Error = IniError;
+
+	fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		self.deserialize_map(visitor)
+	}
+
+	fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		let mut items = Vec::<(&str, Item)>::default();
+		if let Some(headerless) = self.headerless() {
+			push_entries(&mut items, headerless);
+		}
+		for sec in self.ini.sections.iter().filter(|sec| !sec.name.is_empty()) {
+			if !items.iter().any(|(key, _)| key.to_uppercase() == sec.name.to_uppercase()) {
+				items.push((&sec.name, Item::Section(sec)));
+			}
+		}
+		visitor.visit_map(ItemsAccess::new(items))
+	}
+
+	fn deserialize_struct<V>(self,
+		_name: &'static str,
+		fields: &'static [&'static str],
+		visitor: V,
+	) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		let headerless = self.headerless();
+		let items = fields.iter()
+			.filter_map(|field| {
+				self.ini.sections.iter()
+					.find(|sec| !sec.name.is_empty()
+						&& sec.name.to_uppercase() == field.to_uppercase())
+					.map(Item::Section)
+					.or_else(|| headerless.and_then(|headerless| {
+						headerless.find_entry(field)
+							.map(|ent| Item::Value(headerless, ent))
+					}))
+					.map(|item| (*field, item))
+			})
+			.collect::<Vec<_>>();
+		visitor.visit_map(ItemsAccess::new(items))
+	}
+
+	fn deserialize_newtype_struct<V>(self,
+		_name: &'static str, visitor: V) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		visitor.visit_newtype_struct(self)
+	}
+
+	serde::forward_to_deserialize_any! {
+		bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+		bytes byte_buf option unit unit_struct seq tuple tuple_struct enum
+		identifier ignored_any
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// [Serde](https://serde.rs/) serializer which writes a struct or a map into
+/// an [`Ini`](crate::Ini).
+///
+/// Nested structs and maps are written as sections, while other fields are
+/// written as entries before the first section. Existing values are changed
+/// in place, and booleans keep the style of the current value. `None` clears
+/// an existing value, and doesn't create a new one.
+///
+/// Sequences and tuples are written with their items separated by commas.
+pub struct IniSerializer<'a> {
+	ini: &'a mut Ini,
+}
+
+impl<'a> IniSerializer<'a> {
+	/// Creates a new serializer writing to the given `Ini`.
+	#[must_use]
+	pub fn new(ini: &'a mut Ini) -> Self {
+		Self { ini }
+	}
+}
+
+impl<'a> ser::Serializer for IniSerializer<'a> {
+	type Ok = ();
+	type Error = IniError;
+	type SerializeSeq = ser::Impossible<(), IniError>;
+	type SerializeTuple = ser::Impossible<(), IniError>;
+	type SerializeTupleStruct = ser::Impossible<(), IniError>;
+	type SerializeTupleVariant = ser::Impossible<(), IniError>;
+	type SerializeMap = FieldsSerializer<'a>;
+	type SerializeStruct = FieldsSerializer<'a>;
+	type SerializeStructVariant = ser::Impossible<(), IniError>;
+
+	fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, IniError> {
+		Ok(FieldsSerializer::new(self.ini, None))
+	}
+
+	fn serialize_struct(self,
+		_name: &'static str, _len: usize) -> Result<Self::SerializeStruct, IniError>
+	{
+		Ok(FieldsSerializer::new(self.ini, None))
+	}
+
+	fn serialize_some<T>(self, value: &T) -> Result<(), IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		value.serialize(self)
+	}
+
+	fn serialize_newtype_struct<T>(self,
+		_name: &'static str, value: &T) -> Result<(), IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		value.serialize(self)
+	}
+
+	fn serialize_bool(self, _: bool) -> Result<(), IniError> { Err(not_struct()) }
+	fn serialize_i8(self, _: i8) -> Result<(), IniError> { Err(not_struct()) }
+	fn serialize_i16(self, _: i16) -> Result<(), IniError> { Err(not_struct()) }
+	fn serialize_i32(self, _: i32) -> Result<(), IniError> { Err(not_struct()) }
+	fn serialize_i64(self, _: i64) -> Result<(), IniError> { Err(not_struct()) }
+	fn serialize_u8(self, _: u8) -> Result<(), IniError> { Err(not_struct()) }
+	fn serialize_u16(self, _: u16) -> Result<(), IniError> { Err(not_struct()) }
+	fn serialize_u32(self, _: u32) -> Result<(), IniError> { Err(not_struct()) }
+	fn serialize_u64(self, _: u64) -> Result<(), IniError> { Err(not_struct()) }
+	fn serialize_f32(self, _: f32) -> Result<(), IniError> { Err(not_struct()) }
+	fn serialize_f64(self, _: f64) -> Result<(), IniError> { Err(not_struct()) }
+	fn serialize_char(self, _: char) -> Result<(), IniError> { Err(not_struct()) }
+	fn serialize_str(self, _: &str) -> Result<(), IniError> { Err(not_struct()) }
+	fn serialize_bytes(self, _: &[u8]) -> Result<(), IniError> { Err(not_struct()) }
+	fn serialize_none(self) -> Result<(), IniError> { Err(not_struct()) }
+	fn serialize_unit(self) -> Result<(), IniError> { Err(not_struct()) }
+
+	fn serialize_unit_struct(self, _: &'static str) -> Result<(), IniError> {
+		Err(not_struct())
+	}
+
+	fn serialize_unit_variant(self,
+		_: &'static str, _: u32, _: &'static str) -> Result<(), IniError>
+	{
+		Err(not_struct())
+	}
+
+	fn serialize_newtype_variant<T>(self,
+		_: &'static str, _: u32, _: &'static str, _: &T) -> Result<(), IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		Err(not_struct())
+	}
+
+	fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, IniError> {
+		Err(not_struct())
+	}
+
+	fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, IniError> {
+		Err(not_struct())
+	}
+
+	fn serialize_tuple_struct(self,
+		_: &'static str, _: usize) -> Result<Self::SerializeTupleStruct, IniError>
+	{
+		Err(not_struct())
+	}
+
+	fn serialize_tuple_variant(self,
+		_: &'static str, _: u32, _: &'static str,
+		_: usize) -> Result<Self::SerializeTupleVariant, IniError>
+	{
+		Err(not_struct())
+	}
+
+	fn serialize_struct_variant(self,
+		_: &'static str, _: u32, _: &'static str,
+		_: usize) -> Result<Self::SerializeStructVariant, IniError>
+	{
+		Err(not_struct())
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A top-level field or a section entry being deserialized.
+#[derive(Clone, Copy)]
+enum Item<'a> {
+	Section(&'a IniSection),
+	Value(&'a IniSection, &'a IniEntry),
+}
+
+/// Pushes the entries of the section, ignoring duplicated keys.
+fn push_entries<'a>(items: &mut Vec<(&'a str, Item<'a>)>, sec: &'a IniSection) {
+	for ent in sec.entries.iter() {
+		if !items.iter().any(|(key, _)| key.to_uppercase() == ent.key.to_uppercase()) {
+			items.push((&ent.key, Item::Value(sec, ent)));
+		}
+	}
+}
+
+/// Fills the section and key of an error which doesn't have them.
+fn with_context(mut err: IniError, section: &str, key: &str) -> IniError {
+	if err.key.is_empty() {
+		err.section = section.to_owned();
+		err.key = key.to_owned();
+	}
+	err
+}
+
+fn not_struct() -> IniError {
+	<IniError as ser::Error>::custom("an Ini can only hold a struct or a map")
+}
+
+fn not_scalar() -> IniError {
+	<IniError as ser::Error>::custom("nested structs, maps, lists and enum data are not supported in values")
+}
+
+struct ItemsAccess<'a> {
+	items: std::vec::IntoIter<(&'a str, Item<'a>)>,
+	cur: Option<Item<'a>>,
+}
+
+impl<'a> ItemsAccess<'a> {
+	fn new(items: Vec<(&'a str, Item<'a>)>) -> Self {
+		Self { items: items.into_iter(), cur: None }
+	}
+}
+
+impl<'de> de::MapAccess<'de> for ItemsAccess<'de> {
+	type Error = IniError;
+
+	fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, IniError>
+		where K: de::DeserializeSeed<'de>,
+	{
+		match self.items.next() {
+			Some((key, item)) => {
+				self.cur = Some(item);
+				seed.deserialize(key.into_deserializer()).map(Some)
+			},
+			None => Ok(None),
+		}
+	}
+
+	fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, IniError>
+		where V: de::DeserializeSeed<'de>,
+	{
+		match self.cur.take() {
+			Some(Item::Section(sec)) => seed.deserialize(SectionDeserializer { sec })
+				.map_err(|e| with_context(e, &sec.name, "")),
+			Some(Item::Value(sec, ent)) => seed.deserialize(ValueDeserializer::new(sec, ent))
+				.map_err(|e| with_context(e, &sec.name, &ent.key)),
+			None => Err(<IniError as de::Error>::custom("value requested before key")),
+		}
+	}
+}
+
+struct SectionDeserializer<'a> {
+	sec: &'a IniSection,
+}
+
+impl<'de> de::Deserializer<'de> for SectionDeserializer<'de> {
+	type Error = IniError;
+
+	fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		self.deserialize_map(visitor)
+	}
+
+	fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		let mut items = Vec::<(&str, Item)>::default();
+		push_entries(&mut items, self.sec);
+		visitor.visit_map(ItemsAccess::new(items))
+	}
+
+	fn deserialize_struct<V>(self,
+		_name: &'static str,
+		fields: &'static [&'static str],
+		visitor: V,
+	) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		let items = fields.iter()
+			.filter_map(|field| {
+				self.sec.find_entry(field)
+					.map(|ent| (*field, Item::Value(self.sec, ent)))
+			})
+			.collect::<Vec<_>>();
+		visitor.visit_map(ItemsAccess::new(items))
+	}
+
+	fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		visitor.visit_some(self)
+	}
+
+	fn deserialize_newtype_struct<V>(self,
+		_name: &'static str, visitor: V) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		visitor.visit_newtype_struct(self)
+	}
+
+	serde::forward_to_deserialize_any! {
+		bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+		bytes byte_buf unit unit_struct seq tuple tuple_struct enum identifier
+		ignored_any
+	}
+}
+
+struct ValueDeserializer<'a> {
+	sec: &'a IniSection,
+	key: &'a str,
+	val: &'a str,
+}
+
+impl<'a> ValueDeserializer<'a> {
+	fn new(sec: &'a IniSection, ent: &'a IniEntry) -> Self {
+		Self { sec, key: &ent.key, val: &ent.val }
+	}
+
+	fn parse<T>(&self, type_name: &str) -> Result<T, IniError>
+		where T: std::str::FromStr,
+			T::Err: std::fmt::Display,
+	{
+		self.val.trim()
+			.parse::<T>()
+			.map_err(|e| self.error(format!("invalid {} \"{}\": {}", type_name, self.val, e)))
+	}
+
+	fn error(&self, msg: String) -> IniError {
+		IniError {
+			section: self.sec.name.clone(),
+			key: self.key.to_owned(),
+			msg,
+		}
+	}
+}
+
+macro_rules! deserialize_parsed {
+	($($method:ident, $visit:ident, $ty:ty;)*) => {
+		$(
+			fn $method<V>(self, visitor: V) -> Result<V::Value, IniError>
+				where V: de::Visitor<'de>,
+			{
+				visitor.$visit(self.parse::<$ty>(stringify!($ty))?)
+			}
+		)*
+	};
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer<'de> {
+	type Error = IniError;
+
+	fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		visitor.visit_borrowed_str(self.val)
+	}
+
+	fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		match parse_bool(self.val) {
+			Some(val) => visitor.visit_bool(val),
+			None => Err(self.error(format!("invalid boolean \"{}\"", self.val))),
+		}
+	}
+
+	deserialize_parsed! {
+		deserialize_i8, visit_i8, i8;
+		deserialize_i16, visit_i16, i16;
+		deserialize_i32, visit_i32, i32;
+		deserialize_i64, visit_i64, i64;
+		deserialize_i128, visit_i128, i128;
+		deserialize_u8, visit_u8, u8;
+		deserialize_u16, visit_u16, u16;
+		deserialize_u32, visit_u32, u32;
+		deserialize_u64, visit_u64, u64;
+		deserialize_u128, visit_u128, u128;
+		deserialize_f32, visit_f32, f32;
+		deserialize_f64, visit_f64, f64;
+		deserialize_char, visit_char, char;
+	}
+
+	fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		visitor.visit_borrowed_bytes(self.val.as_bytes())
+	}
+
+	fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		self.deserialize_bytes(visitor)
+	}
+
+	fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		if self.val.trim().is_empty() {
+			visitor.visit_none()
+		} else {
+			visitor.visit_some(self)
+		}
+	}
+
+	fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		visitor.visit_unit()
+	}
+
+	fn deserialize_unit_struct<V>(self,
+		_name: &'static str, visitor: V) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		visitor.visit_unit()
+	}
+
+	fn deserialize_newtype_struct<V>(self,
+		_name: &'static str, visitor: V) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		visitor.visit_newtype_struct(self)
+	}
+
+	fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		visitor.visit_seq(ListAccess {
+			sec: self.sec,
+			key: self.key,
+			items: split_list(self.val, ','),
+		})
+	}
+
+	fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		self.deserialize_seq(visitor)
+	}
+
+	fn deserialize_tuple_struct<V>(self,
+		_name: &'static str, _len: usize, visitor: V) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		self.deserialize_seq(visitor)
+	}
+
+	fn deserialize_map<V>(self, _visitor: V) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		Err(self.error("nested maps are not supported in values".to_owned()))
+	}
+
+	fn deserialize_struct<V>(self,
+		_name: &'static str,
+		_fields: &'static [&'static str],
+		_visitor: V,
+	) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		Err(self.error("nested structs are not supported in values".to_owned()))
+	}
+
+	fn deserialize_enum<V>(self,
+		_name: &'static str,
+		_variants: &'static [&'static str],
+		visitor: V,
+	) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		visitor.visit_enum(self.val.trim().into_deserializer())
+	}
+
+	fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, IniError>
+		where V: de::Visitor<'de>,
+	{
+		visitor.visit_unit()
+	}
+
+	serde::forward_to_deserialize_any! {
+		str string identifier
+	}
+}
+
+struct ListAccess<'a, I> {
+	sec: &'a IniSection,
+	key: &'a str,
+	items: I,
+}
+
+impl<'de, I> de::SeqAccess<'de> for ListAccess<'de, I>
+	where I: Iterator<Item = &'de str>,
+{
+	type Error = IniError;
+
+	fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, IniError>
+		where T: de::DeserializeSeed<'de>,
+	{
+		match self.items.next() {
+			Some(item) => seed.deserialize(ValueDeserializer {
+				sec: self.sec,
+				key: self.key,
+				val: item,
+			}).map(Some),
+			None => Ok(None),
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Serializes the fields of the top-level struct, or the entries of a section
+/// if `section` is given.
+pub struct FieldsSerializer<'a> {
+	ini: &'a mut Ini,
+	section: Option<String>,
+	pending_key: Option<String>,
+}
+
+impl<'a> FieldsSerializer<'a> {
+	fn new(ini: &'a mut Ini, section: Option<String>) -> Self {
+		Self { ini, section, pending_key: None }
+	}
+
+	fn write_field<T>(&mut self, key: &str, value: &T) -> Result<(), IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		match &self.section {
+			Some(section) => {
+				let val = value.serialize(ValueSerializer)
+					.map_err(|e| with_context(e, section, key))?;
+				write_value(self.ini.section_or_new(section), key, val);
+				Ok(())
+			},
+			None => value.serialize(FieldSerializer { ini: self.ini, name: key }),
+		}
+	}
+
+	fn key_to_string<T>(key: &T) -> Result<String, IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		match key.serialize(ValueSerializer)? {
+			Value::Text(text) => Ok(text),
+			_ => Err(<IniError as ser::Error>::custom("map keys must be strings")),
+		}
+	}
+}
+
+impl<'a> ser::SerializeStruct for FieldsSerializer<'a> {
+	type Ok = ();
+	type Error = IniError;
+
+	fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		self.write_field(key, value)
+	}
+
+	fn end(self) -> Result<(), IniError> {
+		Ok(())
+	}
+}
+
+impl<'a> ser::SerializeMap for FieldsSerializer<'a> {
+	type Ok = ();
+	type Error = IniError;
+
+	fn serialize_key<T>(&mut self, key: &T) -> Result<(), IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		self.pending_key = Some(Self::key_to_string(key)?);
+		Ok(())
+	}
+
+	fn serialize_value<T>(&mut self, value: &T) -> Result<(), IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		let key = self.pending_key.take()
+			.ok_or_else(|| <IniError as ser::Error>::custom("value serialized before key"))?;
+		self.write_field(&key, value)
+	}
+
+	fn end(self) -> Result<(), IniError> {
+		Ok(())
+	}
+}
+
+/// A value, as produced by [`ValueSerializer`].
+enum Value {
+	Text(String),
+	Bool(bool),
+	List(Vec<String>),
+	None,
+}
+
+/// Writes the value, keeping the style of an existing boolean or list.
+fn write_value(sec: &mut IniSection, key: &str, val: Value) {
+	match val {
+		Value::Text(text) => sec.set_value(key, &text),
+		Value::Bool(b) => sec.set_value_bool(key, b),
+		Value::List(items) => {
+			let unchanged = sec.value(key)
+				.is_some_and(|cur| split_list(cur, ',').eq(items.iter().map(|item| item.as_str())));
+			if !unchanged { // keep the spacing of the current items
+				sec.set_value(key, &items.join(","));
+			}
+		},
+		Value::None => if let Some(ent) = sec.find_entry_mut(key) {
+			ent.val.clear();
+		},
+	}
+}
+
+/// Serializes a top-level field, which is either a section, if it's a struct
+/// or a map, or an entry before the first section.
+struct FieldSerializer<'a, 'k> {
+	ini: &'a mut Ini,
+	name: &'k str,
+}
+
+impl<'a, 'k> FieldSerializer<'a, 'k> {
+	fn write(self, val: Result<Value, IniError>) -> Result<(), IniError> {
+		let val = val.map_err(|e| with_context(e, "", self.name))?;
+		write_value(self.ini.section_or_new(""), self.name, val);
+		Ok(())
+	}
+}
+
+macro_rules! serialize_field_value {
+	($($method:ident, $ty:ty;)*) => {
+		$(
+			fn $method(self, v: $ty) -> Result<(), IniError> {
+				self.write(ser::Serializer::$method(ValueSerializer, v))
+			}
+		)*
+	};
+}
+
+impl<'a, 'k> ser::Serializer for FieldSerializer<'a, 'k> {
+	type Ok = ();
+	type Error = IniError;
+	type SerializeSeq = FieldListSerializer<'a, 'k>;
+	type SerializeTuple = FieldListSerializer<'a, 'k>;
+	type SerializeTupleStruct = FieldListSerializer<'a, 'k>;
+	type SerializeTupleVariant = ser::Impossible<(), IniError>;
+	type SerializeMap = FieldsSerializer<'a>;
+	type SerializeStruct = FieldsSerializer<'a>;
+	type SerializeStructVariant = ser::Impossible<(), IniError>;
+
+	serialize_field_value! {
+		serialize_bool, bool;
+		serialize_i8, i8;
+		serialize_i16, i16;
+		serialize_i32, i32;
+		serialize_i64, i64;
+		serialize_i128, i128;
+		serialize_u8, u8;
+		serialize_u16, u16;
+		serialize_u32, u32;
+		serialize_u64, u64;
+		serialize_u128, u128;
+		serialize_f32, f32;
+		serialize_f64, f64;
+		serialize_char, char;
+		serialize_str, &str;
+		serialize_bytes, &[u8];
+	}
+
+	fn serialize_none(self) -> Result<(), IniError> {
+		self.write(Ok(Value::None))
+	}
+
+	fn serialize_some<T>(self, value: &T) -> Result<(), IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		value.serialize(self)
+	}
+
+	fn serialize_unit(self) -> Result<(), IniError> {
+		self.write(ValueSerializer.serialize_unit())
+	}
+
+	fn serialize_unit_struct(self, name: &'static str) -> Result<(), IniError> {
+		self.write(ser::Serializer::serialize_unit_struct(ValueSerializer, name))
+	}
+
+	fn serialize_unit_variant(self,
+		name: &'static str, variant_index: u32, variant: &'static str) -> Result<(), IniError>
+	{
+		self.write(ser::Serializer::serialize_unit_variant(
+			ValueSerializer, name, variant_index, variant))
+	}
+
+	fn serialize_newtype_struct<T>(self,
+		_name: &'static str, value: &T) -> Result<(), IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		value.serialize(self)
+	}
+
+	fn serialize_newtype_variant<T>(self,
+		_: &'static str, _: u32, _: &'static str, _: &T) -> Result<(), IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		self.write(Err(not_scalar()))
+	}
+
+	fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, IniError> {
+		Ok(FieldListSerializer { field: self, list: ListSerializer::default() })
+	}
+
+	fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, IniError> {
+		self.serialize_seq(Some(len))
+	}
+
+	fn serialize_tuple_struct(self,
+		_name: &'static str, len: usize) -> Result<Self::SerializeTupleStruct, IniError>
+	{
+		self.serialize_seq(Some(len))
+	}
+
+	fn serialize_tuple_variant(self,
+		_: &'static str, _: u32, _: &'static str,
+		_: usize) -> Result<Self::SerializeTupleVariant, IniError>
+	{
+		Err(with_context(not_scalar(), "", self.name))
+	}
+
+	fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, IniError> {
+		Ok(FieldsSerializer::new(self.ini, Some(self.name.to_owned())))
+	}
+
+	fn serialize_struct(self,
+		_name: &'static str, _len: usize) -> Result<Self::SerializeStruct, IniError>
+	{
+		Ok(FieldsSerializer::new(self.ini, Some(self.name.to_owned())))
+	}
+
+	fn serialize_struct_variant(self,
+		_: &'static str, _: u32, _: &'static str,
+		_: usize) -> Result<Self::SerializeStructVariant, IniError>
+	{
+		Err(with_context(not_scalar(), "", self.name))
+	}
+}
+
+/// Serializes a top-level field which is a list.
+struct FieldListSerializer<'a, 'k> {
+	field: FieldSerializer<'a, 'k>,
+	list: ListSerializer,
+}
+
+impl<'a, 'k> FieldListSerializer<'a, 'k> {
+	fn push<T>(&mut self, value: &T) -> Result<(), IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		ser::SerializeSeq::serialize_element(&mut self.list, value)
+			.map_err(|e| with_context(e, "", self.field.name))
+	}
+
+	fn finish(self) -> Result<(), IniError> {
+		self.field.write(ser::SerializeSeq::end(self.list))
+	}
+}
+
+impl<'a, 'k> ser::SerializeSeq for FieldListSerializer<'a, 'k> {
+	type Ok = ();
+	type Error = IniError;
+
+	fn serialize_element<T>(&mut self, value: &T) -> Result<(), IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		self.push(value)
+	}
+
+	fn end(self) -> Result<(), IniError> {
+		self.finish()
+	}
+}
+
+impl<'a, 'k> ser::SerializeTuple for FieldListSerializer<'a, 'k> {
+	type Ok = ();
+	type Error = IniError;
+
+	fn serialize_element<T>(&mut self, value: &T) -> Result<(), IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		self.push(value)
+	}
+
+	fn end(self) -> Result<(), IniError> {
+		self.finish()
+	}
+}
+
+impl<'a, 'k> ser::SerializeTupleStruct for FieldListSerializer<'a, 'k> {
+	type Ok = ();
+	type Error = IniError;
+
+	fn serialize_field<T>(&mut self, value: &T) -> Result<(), IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		self.push(value)
+	}
+
+	fn end(self) -> Result<(), IniError> {
+		self.finish()
+	}
+}
+
+/// Serializes a single value of an entry.
+struct ValueSerializer;
+
+macro_rules! serialize_displayed {
+	($($method:ident, $ty:ty;)*) => {
+		$(
+			fn $method(self, v: $ty) -> Result<Value, IniError> {
+				Ok(Value::Text(v.to_string()))
+			}
+		)*
+	};
+}
+
+impl ser::Serializer for ValueSerializer {
+	type Ok = Value;
+	type Error = IniError;
+	type SerializeSeq = ListSerializer;
+	type SerializeTuple = ListSerializer;
+	type SerializeTupleStruct = ListSerializer;
+	type SerializeTupleVariant = ser::Impossible<Value, IniError>;
+	type SerializeMap = ser::Impossible<Value, IniError>;
+	type SerializeStruct = ser::Impossible<Value, IniError>;
+	type SerializeStructVariant = ser::Impossible<Value, IniError>;
+
+	serialize_displayed! {
+		serialize_i8, i8;
+		serialize_i16, i16;
+		serialize_i32, i32;
+		serialize_i64, i64;
+		serialize_i128, i128;
+		serialize_u8, u8;
+		serialize_u16, u16;
+		serialize_u32, u32;
+		serialize_u64, u64;
+		serialize_u128, u128;
+		serialize_f32, f32;
+		serialize_f64, f64;
+		serialize_char, char;
+		serialize_str, &str;
+	}
+
+	fn serialize_bool(self, v: bool) -> Result<Value, IniError> {
+		Ok(Value::Bool(v))
+	}
+
+	fn serialize_bytes(self, v: &[u8]) -> Result<Value, IniError> {
+		std::str::from_utf8(v)
+			.map(|text| Value::Text(text.to_owned()))
+			.map_err(<IniError as ser::Error>::custom)
+	}
+
+	fn serialize_none(self) -> Result<Value, IniError> {
+		Ok(Value::None)
+	}
+
+	fn serialize_some<T>(self, value: &T) -> Result<Value, IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		value.serialize(self)
+	}
+
+	fn serialize_unit(self) -> Result<Value, IniError> {
+		Ok(Value::Text("".to_owned()))
+	}
+
+	fn serialize_unit_struct(self, _name: &'static str) -> Result<Value, IniError> {
+		self.serialize_unit()
+	}
+
+	fn serialize_unit_variant(self,
+		_name: &'static str, _variant_index: u32, variant: &'static str) -> Result<Value, IniError>
+	{
+		Ok(Value::Text(variant.to_owned()))
+	}
+
+	fn serialize_newtype_struct<T>(self,
+		_name: &'static str, value: &T) -> Result<Value, IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		value.serialize(self)
+	}
+
+	fn serialize_newtype_variant<T>(self,
+		_: &'static str, _: u32, _: &'static str, _: &T) -> Result<Value, IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		Err(not_scalar())
+	}
+
+	fn serialize_seq(self, _len: Option<usize>) -> Result<ListSerializer, IniError> {
+		Ok(ListSerializer::default())
+	}
+
+	fn serialize_tuple(self, _len: usize) -> Result<ListSerializer, IniError> {
+		Ok(ListSerializer::default())
+	}
+
+	fn serialize_tuple_struct(self,
+		_name: &'static str, _len: usize) -> Result<ListSerializer, IniError>
+	{
+		Ok(ListSerializer::default())
+	}
+
+	fn serialize_tuple_variant(self,
+		_: &'static str, _: u32, _: &'static str,
+		_: usize) -> Result<Self::SerializeTupleVariant, IniError>
+	{
+		Err(not_scalar())
+	}
+
+	fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, IniError> {
+		Err(not_scalar())
+	}
+
+	fn serialize_struct(self,
+		_name: &'static str, _len: usize) -> Result<Self::SerializeStruct, IniError>
+	{
+		Err(not_scalar())
+	}
+
+	fn serialize_struct_variant(self,
+		_: &'static str, _: u32, _: &'static str,
+		_: usize) -> Result<Self::SerializeStructVariant, IniError>
+	{
+		Err(not_scalar())
+	}
+}
+
+/// Serializes the items of a list value, separated by commas.
+#[derive(Default)]
+struct ListSerializer {
+	items: Vec<String>,
+}
+
+impl ListSerializer {
+	fn push<T>(&mut self, value: &T) -> Result<(), IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		let item = match value.serialize(ValueSerializer)? {
+			Value::Text(text) => text,
+			Value::Bool(b) => format_bool(b, None),
+			Value::List(_) => return Err(not_scalar()),
+			Value::None => "".to_owned(),
+		};
+		if item.contains(',') {
+			return Err(<IniError as ser::Error>::custom(
+				format!("list item \"{}\" contains the separator", item)));
+		}
+		self.items.push(item);
+		Ok(())
+	}
+
+	fn finish(self) -> Result<Value, IniError> {
+		Ok(Value::List(self.items))
+	}
+}
+
+impl ser::SerializeSeq for ListSerializer {
+	type Ok = Value;
+	type Error = IniError;
+
+	fn serialize_element<T>(&mut self, value: &T) -> Result<(), IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		self.push(value)
+	}
+
+	fn end(self) -> Result<Value, IniError> {
+		self.finish()
+	}
+}
+
+impl ser::SerializeTuple for ListSerializer {
+	type Ok = Value;
+	type Error = IniError;
+
+	fn serialize_element<T>(&mut self, value: &T) -> Result<(), IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		self.push(value)
+	}
+
+	fn end(self) -> Result<Value, IniError> {
+		self.finish()
+	}
+}
+
+impl ser::SerializeTupleStruct for ListSerializer {
+	type Ok = Value;
+	type Error = IniError;
+
+	fn serialize_field<T>(&mut self, value: &T) -> Result<(), IniError>
+		where T: ?Sized + ser::Serialize,
+	{
+		self.push(value)
+	}
+
+	fn end(self) -> Result<Value, IniError> {
+		self.finish()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::BTreeMap;
+
+	use serde::{Deserialize, Serialize};
+
+	use super::*;
+
+	#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+	struct Settings {
+		name: String,
+		count: u32,
+		ratio: f64,
+		tags: Vec<String>,
+		mode: Mode,
+		missing: Option<u8>,
+		window: Window,
+		recent: BTreeMap<String, String>,
+	}
+
+	#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+	struct Window {
+		width: u32,
+		maximized: bool,
+		pos: (i32, i32),
+		title: Option<String>,
+	}
+
+	#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+	enum Mode {
+		#[default]
+		Fast,
+		Slow,
+	}
+
+	fn sample() -> Settings {
+		Settings {
+			name: "foo bar".to_owned(),
+			count: 42,
+			ratio: 0.5,
+			tags: vec!["a".to_owned(), "b c".to_owned()],
+			mode: Mode::Slow,
+			missing: None,
+			window: Window {
+				width: 800,
+				maximized: true,
+				pos: (-10, 20),
+				title: Some("Main".to_owned()),
+			},
+			recent: BTreeMap::from([
+				("file1".to_owned(), "C:\\a.txt".to_owned()),
+				("file2".to_owned(), "C:\\b.txt".to_owned()),
+			]),
+		}
+	}
+
+	#[test]
+	fn round_trip() {
+		let mut ini = Ini::parse_str("");
+		ini.serialize_struct(&sample()).unwrap();
+		let text = ini.serialize_to_str();
+		assert_eq!(text, "name=foo bar\r\n\
+			count=42\r\n\
+			ratio=0.5\r\n\
+			tags=a,b c\r\n\
+			mode=Slow\r\n\
+			\r\n\
+			[window]\r\n\
+			width=800\r\n\
+			maximized=1\r\n\
+			pos=-10,20\r\n\
+			title=Main\r\n\
+			\r\n\
+			[recent]\r\n\
+			file1=C:\\a.txt\r\n\
+			file2=C:\\b.txt\r\n");
+
+		let parsed: Settings = Ini::parse_str(&text).deserialize_struct().unwrap();
+		assert_eq!(parsed, sample());
+	}
+
+	#[test]
+	fn in_place() {
+		let mut ini = Ini::parse_str("; settings\n\
+			Count = 1\n\
+			missing = 7\n\
+			\n\
+			[Window]\n\
+			Maximized = No\n\
+			width=10\n\
+			pos = 1, 2\n");
+		let mut settings = sample();
+		settings.window.maximized = false;
+		settings.window.title = None;
+		settings.window.pos = (1, 2);
+		settings.recent.clear();
+		ini.serialize_struct(&settings).unwrap();
+
+		assert_eq!(ini.serialize_to_str(), "; settings\n\
+			Count = 42\n\
+			missing = \n\
+			name=foo bar\n\
+			ratio=0.5\n\
+			tags=a,b c\n\
+			mode=Slow\n\
+			\n\
+			[Window]\n\
+			Maximized = No\n\
+			width=800\n\
+			pos = 1, 2\n"); // None and empty maps don't create anything
+	}
+
+	#[test]
+	fn case_insensitive() {
+		let ini = Ini::parse_str("NAME=x\nCOUNT=1\nRatio=2\nTAGS=\nMode=Fast\n\
+			[WINDOW]\nWidth=5\nMAXIMIZED=on\nPos=0,0\nTitle=\n[Recent]\n");
+		let settings: Settings = ini.deserialize_struct().unwrap();
+		assert_eq!(settings.name, "x");
+		assert!(settings.tags.is_empty());
+		assert_eq!(settings.mode, Mode::Fast);
+		assert_eq!(settings.window.width, 5);
+		assert!(settings.window.maximized);
+		assert_eq!(settings.window.title, None);
+
+		let map: BTreeMap<String, BTreeMap<String, String>> =
+			Ini::parse_str("[a]\nx=1\n[A]\ny=2\n[b]\n").deserialize_struct().unwrap();
+		assert_eq!(map.len(), 2); // duplicated section ignored
+		assert_eq!(map["a"]["x"], "1");
+		assert!(map["b"].is_empty());
+	}
+
+	#[test]
+	fn errors() {
+		#[derive(Debug, Deserialize)]
+		struct Counts {
+			#[allow(dead_code)]
+			sec: Count,
+		}
+		#[derive(Debug, Deserialize)]
+		struct Count {
+			#[allow(dead_code)]
+			n: u8,
+		}
+
+		let err = Ini::parse_str("[Sec]\nn=300\n").deserialize_struct::<Counts>().unwrap_err();
+		assert_eq!((err.section.as_str(), err.key.as_str()), ("Sec", "n"));
+		assert!(err.msg.starts_with("invalid u8 \"300\""));
+
+		let err = Ini::parse_str("[Sec]\nm=1\n").deserialize_struct::<Counts>().unwrap_err();
+		assert_eq!(err.msg, "missing field `n`");
+
+		let mut ini = Ini::parse_str("");
+		assert!(ini.serialize_struct(&5).is_err());
+		let err = ini.serialize_struct(&BTreeMap::from([("list", vec!["a,b"])])).unwrap_err();
+		assert_eq!(err.key, "list");
+		assert!(ini.serialize_struct(&BTreeMap::from([("sec", BTreeMap::from([("nested", vec![vec![1]])]))])).is_err());
+	}
+}
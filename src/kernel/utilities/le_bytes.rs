@@ -0,0 +1,29 @@
+use crate::co;
+use crate::decl::*;
+
+/// Reads a little-endian `u16` at the given position, failing with
+/// [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if it's out of
+/// bounds.
+pub(in crate::kernel) fn u16_at(buf: &[u8], pos: usize) -> SysResult<u16> {
+	buf.get(pos..pos + 2)
+		.map(|b| u16::from_le_bytes([b[0], b[1]]))
+		.ok_or(co::ERROR::INVALID_DATA)
+}
+
+/// Reads a little-endian `u32` at the given position, failing with
+/// [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if it's out of
+/// bounds.
+pub(in crate::kernel) fn u32_at(buf: &[u8], pos: usize) -> SysResult<u32> {
+	buf.get(pos..pos + 4)
+		.map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+		.ok_or(co::ERROR::INVALID_DATA)
+}
+
+/// Reads a little-endian `u64` at the given position, failing with
+/// [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if it's out of
+/// bounds.
+pub(in crate::kernel) fn u64_at(buf: &[u8], pos: usize) -> SysResult<u64> {
+	buf.get(pos..pos + 8)
+		.map(|b| u64::from_le_bytes(b.try_into().unwrap()))
+		.ok_or(co::ERROR::INVALID_DATA)
+}
@@ -0,0 +1,191 @@
+/// The contents of an `RT_MANIFEST` resource, which is an
+/// [application manifest](https://learn.microsoft.com/en-us/windows/win32/sbscs/application-manifests)
+/// in XML.
+///
+/// The accessor methods perform a simple lookup of the most common settings,
+/// ignoring namespace prefixes; the XML itself is not validated.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let exe_data = std::fs::read("C:\\Temp\\foo.exe").unwrap();
+/// let pe = w::PeImage::from_bytes(&exe_data)?;
+/// let data = pe.find_resource(
+///     &w::RtStr::Rt(co::RT::MANIFEST), &w::IdStr::Id(1), None)?;
+///
+/// let manifest = w::Manifest::from_bytes(data);
+/// if manifest.requested_execution_level() == Some("requireAdministrator") {
+///     println!("Elevation required.");
+/// }
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+	/// The XML text.
+	pub xml: String,
+}
+
+impl Manifest {
+	/// Returns the text of the `dpiAware` element, if any, like `true` or
+	/// `true/pm`.
+	#[must_use]
+	pub fn dpi_aware(&self) -> Option<&str> {
+		self.element_text("dpiAware")
+	}
+
+	/// Returns the text of the `dpiAwareness` element, if any, like
+	/// `PerMonitorV2`.
+	#[must_use]
+	pub fn dpi_awareness(&self) -> Option<&str> {
+		self.element_text("dpiAwareness")
+	}
+
+	/// Decodes the contents of an `RT_MANIFEST` resource. UTF-8 and UTF-16
+	/// byte order marks are honored; otherwise, UTF-8 is assumed.
+	#[must_use]
+	pub fn from_bytes(data: &[u8]) -> Self {
+		let xml = if let Some(data) = data.strip_prefix(b"\xef\xbb\xbf") {
+			String::from_utf8_lossy(data).into_owned()
+		} else if let Some(data) = data.strip_prefix(b"\xff\xfe") {
+			let chars = data.chunks_exact(2)
+				.map(|ch| u16::from_le_bytes([ch[0], ch[1]]))
+				.collect::<Vec<_>>();
+			String::from_utf16_lossy(&chars)
+		} else if let Some(data) = data.strip_prefix(b"\xfe\xff") {
+			let chars = data.chunks_exact(2)
+				.map(|ch| u16::from_be_bytes([ch[0], ch[1]]))
+				.collect::<Vec<_>>();
+			String::from_utf16_lossy(&chars)
+		} else {
+			String::from_utf8_lossy(data).into_owned()
+		};
+		Self { xml }
+	}
+
+	/// Returns the `level` attribute of the `requestedExecutionLevel` element,
+	/// if any, like `asInvoker` or `requireAdministrator`.
+	#[must_use]
+	pub fn requested_execution_level(&self) -> Option<&str> {
+		self.element_attr("requestedExecutionLevel", "level")
+	}
+
+	/// Serializes the XML in UTF-8, without a byte order mark.
+	#[must_use]
+	pub fn to_bytes(&self) -> Vec<u8> {
+		self.xml.as_bytes().to_vec()
+	}
+
+	/// Returns the `uiAccess` attribute of the `requestedExecutionLevel`
+	/// element, if any.
+	#[must_use]
+	pub fn ui_access(&self) -> Option<bool> {
+		self.element_attr("requestedExecutionLevel", "uiAccess")
+			.map(|val| val.eq_ignore_ascii_case("true"))
+	}
+
+	/// Returns the contents of the first tag with the given local name, between
+	/// `<` and `>`, and the position right after it.
+	fn find_tag(&self, name: &str) -> Option<(&str, usize)> {
+		self.xml.match_indices('<')
+			.find_map(|(idx, _)| {
+				let tag = &self.xml[idx + 1..];
+				let tag = &tag[..tag.find('>')?];
+				let tag_name = tag.split(|ch: char| ch.is_whitespace() || ch == '/')
+					.next()?;
+				let local_name = tag_name.rsplit(':').next()?;
+				(local_name == name).then_some((tag, idx + 1 + tag.len() + 1))
+			})
+	}
+
+	fn element_attr(&self, name: &str, attr: &str) -> Option<&str> {
+		let (tag, _) = self.find_tag(name)?;
+		let mut rest = tag;
+		while let Some(idx) = rest.find(attr) {
+			let before = rest[..idx].chars().next_back();
+			let after = rest[idx + attr.len()..].trim_start();
+			rest = &rest[idx + attr.len()..];
+			if !before.is_some_and(char::is_whitespace) {
+				continue;
+			}
+			if let Some(after) = after.strip_prefix('=') {
+				let after = after.trim_start();
+				let quote = after.chars().next().filter(|ch| *ch == '"' || *ch == '\'')?;
+				let val = &after[1..];
+				return Some(&val[..val.find(quote)?]);
+			}
+		}
+		None
+	}
+
+	fn element_text(&self, name: &str) -> Option<&str> {
+		let (tag, end) = self.find_tag(name)?;
+		if tag.ends_with('/') {
+			return Some("");
+		}
+		let text = &self.xml[end..];
+		Some(text[..text.find('<')?].trim())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<assembly xmlns="urn:schemas-microsoft-com:asm.v1" manifestVersion="1.0">
+  <trustInfo xmlns="urn:schemas-microsoft-com:asm.v3">
+    <security>
+      <requestedPrivileges>
+        <requestedExecutionLevel level='requireAdministrator' uiAccess="TRUE"/>
+      </requestedPrivileges>
+    </security>
+  </trustInfo>
+  <asmv3:application xmlns:asmv3="urn:schemas-microsoft-com:asm.v3">
+    <asmv3:windowsSettings>
+      <dpiAware xmlns="http://schemas.microsoft.com/SMI/2005/WindowsSettings"> true/pm </dpiAware>
+      <dpiAwareness xmlns="http://schemas.microsoft.com/SMI/2016/WindowsSettings">PerMonitorV2</dpiAwareness>
+    </asmv3:windowsSettings>
+  </asmv3:application>
+</assembly>"#;
+
+	#[test]
+	fn settings() {
+		let manifest = Manifest { xml: XML.to_owned() };
+		assert_eq!(manifest.requested_execution_level(), Some("requireAdministrator"));
+		assert_eq!(manifest.ui_access(), Some(true));
+		assert_eq!(manifest.dpi_aware(), Some("true/pm"));
+		assert_eq!(manifest.dpi_awareness(), Some("PerMonitorV2"));
+
+		let manifest = Manifest {
+			xml: "<assembly><requestedExecutionLevel xlevel=\"x\" level=\"asInvoker\"/><dpiAware/></assembly>".to_owned(),
+		};
+		assert_eq!(manifest.requested_execution_level(), Some("asInvoker"));
+		assert_eq!(manifest.ui_access(), None);
+		assert_eq!(manifest.dpi_aware(), Some(""));
+		assert_eq!(manifest.dpi_awareness(), None);
+
+		assert_eq!(Manifest::default().requested_execution_level(), None);
+	}
+
+	#[test]
+	fn encodings() {
+		let manifest = Manifest { xml: "<assembly>\u{e9}</assembly>".to_owned() };
+		let utf8 = manifest.to_bytes();
+		assert_eq!(utf8, "<assembly>\u{e9}</assembly>".as_bytes());
+		assert_eq!(Manifest::from_bytes(&utf8), manifest);
+
+		let mut with_bom = b"\xef\xbb\xbf".to_vec();
+		with_bom.extend_from_slice(&utf8);
+		assert_eq!(Manifest::from_bytes(&with_bom), manifest);
+
+		let mut utf16le = b"\xff\xfe".to_vec();
+		utf16le.extend(manifest.xml.encode_utf16().flat_map(|ch| ch.to_le_bytes()));
+		assert_eq!(Manifest::from_bytes(&utf16le), manifest);
+
+		let mut utf16be = b"\xfe\xff".to_vec();
+		utf16be.extend(manifest.xml.encode_utf16().flat_map(|ch| ch.to_be_bytes()));
+		assert_eq!(Manifest::from_bytes(&utf16be), manifest);
+	}
+}
@@ -0,0 +1,211 @@
+use crate::co;
+use crate::decl::*;
+use crate::kernel::utilities::le_bytes::{u16_at, u32_at};
+
+/// The contents of an `RT_MESSAGETABLE` resource, as compiled by the message
+/// compiler, and read by
+/// [`FormatMessage`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-formatmessagew).
+///
+/// Entries stored in the ANSI code page are decoded as Latin-1. When
+/// serialized, all entries are written in UTF-16.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let exe_data = std::fs::read("C:\\Temp\\foo.exe").unwrap();
+/// let pe = w::PeImage::from_bytes(&exe_data)?;
+/// let data = pe.find_resource(
+///     &w::RtStr::Rt(co::RT::MESSAGETABLE), &w::IdStr::Id(1), None)?;
+///
+/// let mut table = w::MessageTable::from_bytes(data)?;
+/// println!("{:?}", table.message(0xc000_0001));
+///
+/// table.messages.push((0xc000_0002, "Something else failed.\r\n".to_owned()));
+/// let data2 = table.to_bytes()?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MessageTable {
+	/// The message IDs and their texts, which usually end with `"\r\n"`.
+	pub messages: Vec<(u32, String)>,
+}
+
+impl MessageTable {
+	/// Parses the contents of an `RT_MESSAGETABLE` resource, which is a
+	/// `MESSAGE_RESOURCE_DATA` struct followed by its blocks and entries.
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the data is malformed.
+	pub fn from_bytes(data: &[u8]) -> SysResult<Self> {
+		let num_blocks = u32_at(data, 0)? as usize;
+		let mut messages = Vec::default();
+
+		for b in 0..num_blocks {
+			let low_id = u32_at(data, 4 + b * 12)?;
+			let high_id = u32_at(data, 4 + b * 12 + 4)?;
+			let mut pos = u32_at(data, 4 + b * 12 + 8)? as usize;
+			if high_id < low_id {
+				return Err(co::ERROR::INVALID_DATA);
+			}
+
+			for id in low_id..=high_id {
+				let len = u16_at(data, pos)? as usize;
+				let flags = u16_at(data, pos + 2)?;
+				if len < 4 {
+					return Err(co::ERROR::INVALID_DATA);
+				}
+				let text = data.get(pos + 4..pos + len)
+					.ok_or(co::ERROR::INVALID_DATA)?;
+
+				let text = match flags {
+					MESSAGE_RESOURCE_UNICODE => {
+						let chars = text.chunks_exact(2)
+							.map(|ch| u16::from_le_bytes([ch[0], ch[1]]))
+							.collect::<Vec<_>>();
+						String::from_utf16_lossy(&chars)
+					},
+					MESSAGE_RESOURCE_UTF8 => String::from_utf8_lossy(text).into_owned(),
+					_ => text.iter().map(|ch| *ch as char).collect(), // ANSI
+				};
+				messages.push((id, text.trim_end_matches('\0').to_owned()));
+				pos += len;
+			}
+		}
+
+		Ok(Self { messages })
+	}
+
+	/// Returns the text of the message with the given ID, if any.
+	#[must_use]
+	pub fn message(&self, id: u32) -> Option<&str> {
+		self.messages.iter()
+			.find(|(msg_id, _)| *msg_id == id)
+			.map(|(_, text)| text.as_str())
+	}
+
+	/// Serializes the messages into the contents of an `RT_MESSAGETABLE`
+	/// resource. The messages are sorted by ID, and consecutive IDs are
+	/// grouped into blocks.
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// an ID is repeated, or if a text is too long.
+	pub fn to_bytes(&self) -> SysResult<Vec<u8>> {
+		let mut messages = self.messages.iter().collect::<Vec<_>>();
+		messages.sort_by_key(|(id, _)| *id);
+		if messages.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+
+		let mut blocks = Vec::<&[&(u32, String)]>::default(); // runs of consecutive IDs
+		let mut rest = messages.as_slice();
+		while !rest.is_empty() {
+			let run_len = rest.windows(2)
+				.position(|pair| pair[0].0.checked_add(1) != Some(pair[1].0))
+				.map_or(rest.len(), |idx| idx + 1);
+			let (run, after) = rest.split_at(run_len);
+			blocks.push(run);
+			rest = after;
+		}
+
+		let mut buf = Vec::<u8>::default();
+		buf.extend_from_slice(&(blocks.len() as u32).to_le_bytes());
+		buf.resize(4 + blocks.len() * 12, 0); // blocks, filled below
+
+		for (b, run) in blocks.iter().enumerate() {
+			let block_pos = 4 + b * 12;
+			let entries_pos = buf.len() as u32;
+			buf[block_pos..block_pos + 4].copy_from_slice(&run[0].0.to_le_bytes());
+			buf[block_pos + 4..block_pos + 8].copy_from_slice(&run[run.len() - 1].0.to_le_bytes());
+			buf[block_pos + 8..block_pos + 12].copy_from_slice(&entries_pos.to_le_bytes());
+
+			for (_, text) in run.iter() {
+				let text_bytes = text.encode_utf16()
+					.chain(std::iter::once(0)) // terminating null
+					.flat_map(|ch| ch.to_le_bytes())
+					.collect::<Vec<_>>();
+				let len = u16::try_from((4 + text_bytes.len()).next_multiple_of(4))
+					.map_err(|_| co::ERROR::INVALID_DATA)?;
+				buf.extend_from_slice(&len.to_le_bytes());
+				buf.extend_from_slice(&MESSAGE_RESOURCE_UNICODE.to_le_bytes());
+				buf.extend_from_slice(&text_bytes);
+				buf.resize(buf.len().next_multiple_of(4), 0);
+			}
+		}
+
+		Ok(buf)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+const MESSAGE_RESOURCE_UNICODE: u16 = 0x0001;
+const MESSAGE_RESOURCE_UTF8: u16 = 0x0002;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn round_trip() {
+		let table = MessageTable {
+			messages: vec![
+				(0xc000_0002, "Second.\r\n".to_owned()),
+				(0xc000_0001, "First.\r\n".to_owned()),
+				(7, "\u{e9}".to_owned()),
+			],
+		};
+		let data = table.to_bytes().unwrap();
+		assert_eq!(&data[..28], [
+			2, 0, 0, 0, // blocks
+			7, 0, 0, 0, 7, 0, 0, 0, 28, 0, 0, 0,
+			1, 0, 0, 0xc0, 2, 0, 0, 0xc0, 36, 0, 0, 0,
+		]);
+		assert_eq!(&data[28..36], [8, 0, 1, 0, 0xe9, 0, 0, 0]); // padded to 4 bytes
+
+		let parsed = MessageTable::from_bytes(&data).unwrap();
+		assert_eq!(parsed.messages, [
+			(7, "\u{e9}".to_owned()),
+			(0xc000_0001, "First.\r\n".to_owned()),
+			(0xc000_0002, "Second.\r\n".to_owned()),
+		]);
+		assert_eq!(parsed.message(0xc000_0001), Some("First.\r\n"));
+		assert_eq!(parsed.message(8), None);
+
+		assert_eq!(MessageTable::default().to_bytes().unwrap(), [0, 0, 0, 0]);
+	}
+
+	#[test]
+	fn ansi_and_utf8() {
+		let mut data = vec![1, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 16, 0, 0, 0];
+		data.extend_from_slice(&[8, 0, 0, 0, b'A', 0xe9, 0, 0]); // Latin-1
+		data.extend_from_slice(&[8, 0, 2, 0, b'B', 0xc3, 0xa9, 0]); // UTF-8
+		let table = MessageTable::from_bytes(&data).unwrap();
+		assert_eq!(table.messages, [
+			(1, "A\u{e9}".to_owned()),
+			(2, "B\u{e9}".to_owned()),
+		]);
+	}
+
+	#[test]
+	fn malformed() {
+		let table = MessageTable {
+			messages: vec![(1, "a".to_owned()), (1, "b".to_owned())],
+		};
+		assert_eq!(table.to_bytes(), Err(co::ERROR::INVALID_DATA));
+
+		let data = MessageTable {
+			messages: vec![(1, "a".to_owned())],
+		}.to_bytes().unwrap();
+		assert_eq!(MessageTable::from_bytes(&data[..data.len() - 1]), Err(co::ERROR::INVALID_DATA));
+
+		let mut reversed = data.clone();
+		reversed[4] = 2; // low ID above high ID
+		assert_eq!(MessageTable::from_bytes(&reversed), Err(co::ERROR::INVALID_DATA));
+
+		let mut short = data.clone();
+		short[16] = 2; // entry length below its header
+		assert_eq!(MessageTable::from_bytes(&short), Err(co::ERROR::INVALID_DATA));
+	}
+}
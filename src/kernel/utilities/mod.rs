@@ -1,15 +1,49 @@
+mod bocu1;
 mod encoding;
+mod environment_block;
 mod file_mapped;
 mod file;
+mod guid_conv;
 mod heap_block;
 mod ini;
+#[cfg(feature = "serde")] mod ini_serde;
+mod le_bytes;
+mod manifest;
+mod message_table;
+mod pe_image;
+mod pe_res_editor;
+mod process;
+mod reg_file;
+mod reg_hive;
+mod res_file;
+mod scsu;
+mod sddl;
+mod sid;
+mod string_bundle;
+mod time_conv;
+#[cfg(any(feature = "chrono", feature = "time"))] mod time_interop;
 mod w_string;
+mod win_path;
 
+pub mod cmd_line;
 pub mod path;
 
-pub use encoding::Encoding;
+pub use encoding::{Encoding, EncodingGuess};
+pub use environment_block::EnvironmentBlock;
 pub use file_mapped::FileMapped;
 pub use file::{File, FileAccess};
 pub use heap_block::HeapBlock;
-pub use ini::{Ini, IniEntry, IniSection};
+#[cfg(feature = "serde")] pub use ini_serde::{IniDeserializer, IniSerializer};
+pub use ini::{Ini, IniEntry, IniError, IniSection};
+pub use manifest::Manifest;
+pub use message_table::MessageTable;
+pub use pe_image::{PeExport, PeImage, PeImport, PeImportSymbol, PeResource, PeSection};
+pub use pe_res_editor::PeResourceEditor;
+pub use process::{ChildProcess, ProcessBuilder, ProcessOutput, ProcessStdio};
+pub use reg_file::{RegFile, RegFileKey, RegFileValue};
+pub use reg_hive::{RegHive, RegHiveKey};
+pub use res_file::{ResFile, ResFileEntry};
+pub use sddl::{Ace, Acl, SecurityDescriptor};
+pub use sid::Sid;
+pub use string_bundle::StringBundle;
 pub use w_string::WString;
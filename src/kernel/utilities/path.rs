@@ -8,6 +8,8 @@ use crate::decl::*;
 use crate::guard::*;
 use crate::prelude::*;
 
+pub use super::win_path::{WinPath, WinPathPrefix};
+
 /// Returns an iterator over the files and folders within a directory.
 /// Optionally, a wildcard can be specified to filter files by name.
 ///
@@ -0,0 +1,1015 @@
+use std::collections::HashSet;
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::utilities::le_bytes::{u16_at, u32_at, u64_at};
+
+/// Read-only parser of
+/// [PE/COFF](https://learn.microsoft.com/en-us/windows/win32/debug/pe-format)
+/// images, like EXE and DLL files.
+///
+/// The parsing is made entirely in Rust over a byte slice, so the image is
+/// never loaded into the process and none of its code is run. This allows the
+/// inspection of untrusted binaries, and also binaries of other architectures,
+/// which [`HINSTANCE::LoadLibrary`](crate::prelude::kernel_Hinstance::LoadLibrary)
+/// can't load.
+///
+/// # Examples
+///
+/// Listing the resources and the imports of an executable, which is
+/// memory-mapped:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let f = w::FileMapped::open(
+///     "C:\\Temp\\foo.exe",
+///     w::FileAccess::ExistingReadOnly,
+/// )?;
+/// let pe = w::PeImage::from_bytes(f.as_slice())?;
+///
+/// println!("Machine: {:?}", pe.machine());
+///
+/// for res in pe.resources()? {
+///     println!("{} {} {:?} - {} bytes",
+///         res.rt, res.name, res.lang, res.data.len());
+/// }
+///
+/// for imp in pe.imports()? {
+///     println!("{}: {} symbols", imp.dll_name, imp.symbols.len());
+/// }
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+pub struct PeImage<'a> {
+	data: &'a [u8],
+	opt_hdr: usize, // offset of the optional header
+	sections: Vec<PeSection>,
+}
+
+impl<'a> PeImage<'a> {
+	/// Parses the headers of an image from its raw bytes, which can come from
+	/// a [`FileMapped`](crate::FileMapped).
+	///
+	/// Fails with
+	/// [`co::ERROR::BAD_EXE_FORMAT`](crate::co::ERROR::BAD_EXE_FORMAT) if the
+	/// bytes are not a PE image, or
+	/// [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if the
+	/// headers are truncated.
+	#[must_use]
+	pub fn from_bytes(data: &'a [u8]) -> SysResult<Self> {
+		if data.get(..2) != Some(b"MZ") {
+			return Err(co::ERROR::BAD_EXE_FORMAT);
+		}
+		let pe_hdr = u32_at(data, 0x3c)? as usize;
+		if data.get(pe_hdr..pe_hdr + 4) != Some(b"PE\0\0") {
+			return Err(co::ERROR::BAD_EXE_FORMAT);
+		}
+
+		let coff_hdr = pe_hdr + 4;
+		let num_sections = u16_at(data, coff_hdr + 2)? as usize;
+		let opt_hdr_size = u16_at(data, coff_hdr + 16)? as usize;
+		let opt_hdr = coff_hdr + COFF_HEADER_SIZE;
+		match u16_at(data, opt_hdr)? {
+			PE32_MAGIC | PE32_PLUS_MAGIC => {},
+			_ => return Err(co::ERROR::BAD_EXE_FORMAT),
+		}
+
+		let sections = (0..num_sections)
+			.map(|idx| PeSection::parse(
+				data,
+				opt_hdr + opt_hdr_size + idx * SECTION_HEADER_SIZE,
+			))
+			.collect::<SysResult<Vec<_>>>()?;
+
+		Ok(Self { data, opt_hdr, sections })
+	}
+
+	/// Returns the relative virtual address of the entry point, or zero if
+	/// there is none.
+	#[must_use]
+	pub fn entry_point(&self) -> u32 {
+		u32_at(self.data, self.opt_hdr + 16).unwrap_or_default()
+	}
+
+	/// Returns the exported functions.
+	#[must_use]
+	pub fn exports(&self) -> SysResult<Vec<PeExport>> {
+		let (dir_rva, dir_size) = match self.data_directory(DIR_EXPORT)? {
+			Some(dir) => dir,
+			None => return Ok(Vec::default()),
+		};
+		let dir = self.rva_slice(dir_rva, EXPORT_DIR_SIZE)?;
+		let base = u32_at(dir, 16)?;
+		let num_funcs = u32_at(dir, 20)? as usize;
+		let num_names = u32_at(dir, 24)? as usize;
+		let funcs = self.rva_slice(u32_at(dir, 28)?, array_len(num_funcs, 4)?)?;
+		let names = self.rva_slice(u32_at(dir, 32)?, array_len(num_names, 4)?)?;
+		let ordinals = self.rva_slice(u32_at(dir, 36)?, array_len(num_names, 2)?)?;
+
+		let mut func_names: Vec<Option<String>> = vec![None; num_funcs];
+		for idx in 0..num_names {
+			let func_idx = u16_at(ordinals, idx * 2)? as usize;
+			*func_names.get_mut(func_idx).ok_or(co::ERROR::INVALID_DATA)? =
+				Some(self.rva_str(u32_at(names, idx * 4)?)?);
+		}
+
+		let mut exports = Vec::with_capacity(num_funcs);
+		for (idx, name) in func_names.into_iter().enumerate() {
+			let rva = u32_at(funcs, idx * 4)?;
+			if rva == 0 {
+				continue; // unused slot
+			}
+			let forwarder = if rva >= dir_rva && rva - dir_rva < dir_size {
+				Some(self.rva_str(rva)?) // points to a string like "NTDLL.RtlAllocateHeap"
+			} else {
+				None
+			};
+			exports.push(PeExport {
+				ordinal: base.wrapping_add(idx as _),
+				name,
+				rva,
+				forwarder,
+			});
+		}
+		Ok(exports)
+	}
+
+	/// Returns the resource of the given type and name, like
+	/// [`HINSTANCE::FindResourceEx`](crate::prelude::kernel_Hinstance::FindResourceEx)
+	/// followed by
+	/// [`HINSTANCE::LoadResource`](crate::prelude::kernel_Hinstance::LoadResource).
+	/// If `lang` is `None`, the first language found is returned.
+	///
+	/// String identifiers are compared case-insensitively. Fails with
+	/// [`co::ERROR::RESOURCE_TYPE_NOT_FOUND`](crate::co::ERROR::RESOURCE_TYPE_NOT_FOUND),
+	/// [`co::ERROR::RESOURCE_NAME_NOT_FOUND`](crate::co::ERROR::RESOURCE_NAME_NOT_FOUND)
+	/// or
+	/// [`co::ERROR::RESOURCE_LANG_NOT_FOUND`](crate::co::ERROR::RESOURCE_LANG_NOT_FOUND).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let f = w::FileMapped::open(
+	///     "C:\\Temp\\foo.dll",
+	///     w::FileAccess::ExistingReadOnly,
+	/// )?;
+	/// let pe = w::PeImage::from_bytes(f.as_slice())?;
+	///
+	/// let ver_data = pe.find_resource(
+	///     &w::RtStr::Rt(co::RT::VERSION),
+	///     &w::IdStr::Id(1),
+	///     None,
+	/// )?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	pub fn find_resource(&self,
+		rt: &RtStr,
+		name: &IdStr,
+		lang: Option<LANGID>,
+	) -> SysResult<&'a [u8]>
+	{
+		let ress = self.resources()?;
+		let of_type = ress.iter()
+			.filter(|res| same_rt(&res.rt, rt))
+			.collect::<Vec<_>>();
+		if of_type.is_empty() {
+			return Err(co::ERROR::RESOURCE_TYPE_NOT_FOUND);
+		}
+
+		let of_name = of_type.into_iter()
+			.filter(|res| same_id(&res.name, name))
+			.collect::<Vec<_>>();
+		if of_name.is_empty() {
+			return Err(co::ERROR::RESOURCE_NAME_NOT_FOUND);
+		}
+
+		of_name.into_iter()
+			.find(|res| lang.is_none_or(|lang| res.lang == lang))
+			.map(|res| res.data)
+			.ok_or(co::ERROR::RESOURCE_LANG_NOT_FOUND)
+	}
+
+	/// Returns the string with the given ID from the
+	/// [`co::RT::STRING`](crate::co::RT::STRING) resources, like
+	/// [`LoadString`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-loadstringw),
+	/// but in the given language. If `lang` is `None`, the first language
+	/// found is used.
+	///
+	/// If the bundle holding the string exists but the string is absent, an
+	/// empty string is returned. If the bundle doesn't exist, fails like
+	/// [`PeImage::find_resource`](crate::PeImage::find_resource).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let exe_data = std::fs::read("C:\\Temp\\foo.exe").unwrap();
+	/// let pe = w::PeImage::from_bytes(&exe_data)?;
+	///
+	/// let title = pe.find_string(
+	///     40,
+	///     Some(w::LANGID::new(co::LANG::ENGLISH, co::SUBLANG::ENGLISH_US)),
+	/// )?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	pub fn find_string(&self, string_id: u16, lang: Option<LANGID>) -> SysResult<String> {
+		let data = self.find_resource(
+			&RtStr::Rt(co::RT::STRING),
+			&StringBundle::bundle_name(string_id),
+			lang,
+		)?;
+		Ok(StringBundle::from_bytes(data)?.string(string_id).to_owned())
+	}
+
+	/// Returns the imported DLLs, along with their imported symbols.
+	#[must_use]
+	pub fn imports(&self) -> SysResult<Vec<PeImport>> {
+		let dir_rva = match self.data_directory(DIR_IMPORT)? {
+			Some((dir_rva, _)) => dir_rva,
+			None => return Ok(Vec::default()),
+		};
+		let thunk_size = if self.is_64bit() { 8 } else { 4 };
+		let mut imports = Vec::default();
+
+		for idx in 0.. {
+			let desc = self.rva_slice(
+				dir_rva.checked_add(idx * IMPORT_DESC_SIZE as u32).ok_or(co::ERROR::INVALID_DATA)?,
+				IMPORT_DESC_SIZE,
+			)?;
+			if desc.iter().all(|b| *b == 0) {
+				break; // null descriptor terminates the array
+			}
+
+			let lookup_rva = match u32_at(desc, 0)? { // OriginalFirstThunk
+				0 => u32_at(desc, 16)?, // FirstThunk, if there's no lookup table
+				rva => rva,
+			};
+			let mut symbols = Vec::default();
+
+			for thunk_idx in 0.. {
+				let thunk_rva = lookup_rva.checked_add(thunk_idx * thunk_size as u32)
+					.ok_or(co::ERROR::INVALID_DATA)?;
+				let thunk = self.rva_slice(thunk_rva, thunk_size)?;
+				let (by_ordinal, value) = if self.is_64bit() {
+					let val = u64_at(thunk, 0)?;
+					(val & (1 << 63) != 0, val as u32)
+				} else {
+					let val = u32_at(thunk, 0)?;
+					(val & (1 << 31) != 0, val)
+				};
+
+				if by_ordinal {
+					symbols.push(PeImportSymbol::Ordinal(value as _));
+				} else if value == 0 {
+					break; // null thunk terminates the array
+				} else {
+					let hint_name_rva = value & 0x7fff_ffff;
+					symbols.push(PeImportSymbol::Name {
+						hint: u16_at(self.rva_slice(hint_name_rva, 2)?, 0)?,
+						name: self.rva_str(hint_name_rva + 2)?,
+					});
+				}
+			}
+
+			imports.push(PeImport {
+				dll_name: self.rva_str(u32_at(desc, 12)?)?,
+				symbols,
+			});
+		}
+		Ok(imports)
+	}
+
+	/// Returns the preferred address of the image when loaded into memory.
+	#[must_use]
+	pub fn image_base(&self) -> u64 {
+		if self.is_64bit() {
+			u64_at(self.data, self.opt_hdr + 24).unwrap_or_default()
+		} else {
+			u32_at(self.data, self.opt_hdr + 28).unwrap_or_default() as _
+		}
+	}
+
+	/// Returns `true` if the image is 64-bit (PE32+).
+	#[must_use]
+	pub fn is_64bit(&self) -> bool {
+		u16_at(self.data, self.opt_hdr).ok() == Some(PE32_PLUS_MAGIC)
+	}
+
+	/// Returns `true` if the image is a DLL.
+	#[must_use]
+	pub fn is_dll(&self) -> bool {
+		let characteristics = u16_at(self.data, self.opt_hdr - COFF_HEADER_SIZE + 18)
+			.unwrap_or_default();
+		characteristics & IMAGE_FILE_DLL != 0
+	}
+
+	/// Returns the target architecture of the image.
+	#[must_use]
+	pub fn machine(&self) -> co::IMAGE_FILE_MACHINE {
+		unsafe {
+			co::IMAGE_FILE_MACHINE::from_raw(
+				u16_at(self.data, self.opt_hdr - COFF_HEADER_SIZE).unwrap_or_default(),
+			)
+		}
+	}
+
+	/// Returns the application manifest embedded as a
+	/// [`co::RT::MANIFEST`](crate::co::RT::MANIFEST) resource, if any. If there
+	/// are many, the first one is returned.
+	#[must_use]
+	pub fn manifest(&self) -> SysResult<Option<String>> {
+		Ok(
+			self.resources()?
+				.into_iter()
+				.find(|res| res.rt == RtStr::Rt(co::RT::MANIFEST))
+				.map(|res| Manifest::from_bytes(res.data).xml),
+		)
+	}
+
+	/// Returns the language identifiers of the resources of the given type and
+	/// name, like
+	/// [`HINSTANCE::EnumResourceLanguages`](crate::prelude::kernel_Hinstance::EnumResourceLanguages).
+	#[must_use]
+	pub fn resource_langs(&self, rt: &RtStr, name: &IdStr) -> SysResult<Vec<LANGID>> {
+		Ok(
+			self.resources()?
+				.into_iter()
+				.filter(|res| same_rt(&res.rt, rt) && same_id(&res.name, name))
+				.map(|res| res.lang)
+				.collect(),
+		)
+	}
+
+	/// Returns the names of the resources of the given type, like
+	/// [`HINSTANCE::EnumResourceNames`](crate::prelude::kernel_Hinstance::EnumResourceNames).
+	#[must_use]
+	pub fn resource_names(&self, rt: &RtStr) -> SysResult<Vec<IdStr>> {
+		let mut names = Vec::<IdStr>::default();
+		for res in self.resources()?.into_iter().filter(|res| same_rt(&res.rt, rt)) {
+			if names.last() != Some(&res.name) { // languages of a name are contiguous
+				names.push(res.name);
+			}
+		}
+		Ok(names)
+	}
+
+	/// Returns the resource types, like
+	/// [`HINSTANCE::EnumResourceTypes`](crate::prelude::kernel_Hinstance::EnumResourceTypes).
+	#[must_use]
+	pub fn resource_types(&self) -> SysResult<Vec<RtStr>> {
+		let mut rts = Vec::<RtStr>::default();
+		for res in self.resources()? {
+			if rts.last() != Some(&res.rt) { // names of a type are contiguous
+				rts.push(res.rt);
+			}
+		}
+		Ok(rts)
+	}
+
+	/// Returns all the resources, walking the type, name and language levels of
+	/// the resource directory, in the order they are stored.
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the directory is malformed, if a subdirectory is referenced more than
+	/// once, or if the tree is unreasonably large.
+	#[must_use]
+	pub fn resources(&self) -> SysResult<Vec<PeResource<'a>>> {
+		let (dir_rva, _) = match self.data_directory(DIR_RESOURCE)? {
+			Some(dir) => dir,
+			None => return Ok(Vec::default()),
+		};
+		let mut walker = ResDirWalker {
+			rsrc: self.rva_slice_to_end(dir_rva)?,
+			visited: HashSet::default(),
+			budget: RES_MAX_ITEMS,
+		};
+		let mut ress = Vec::default();
+
+		for (type_id, type_dir) in walker.entries(0, true)? {
+			let rt = match type_id {
+				IdStr::Id(id) => RtStr::Rt(unsafe { co::RT::from_raw(id) }),
+				IdStr::Str(s) => RtStr::Str(s),
+			};
+
+			for (name, name_dir) in walker.entries(type_dir, true)? {
+				let clone_len = match &rt { RtStr::Str(s) => s.str_len(), _ => 0 }
+					+ match &name { IdStr::Str(s) => s.str_len(), _ => 0 };
+
+				for (lang_id, data_entry) in walker.entries(name_dir, false)? {
+					walker.charge(clone_len)?; // type and name are cloned for each language
+					let lang = match lang_id {
+						IdStr::Id(id) => unsafe { LANGID::from_raw(id) },
+						IdStr::Str(_) => return Err(co::ERROR::INVALID_DATA),
+					};
+					let entry = walker.rsrc.get(data_entry as usize..)
+						.ok_or(co::ERROR::INVALID_DATA)?;
+
+					ress.push(PeResource {
+						rt: rt.clone(),
+						name: name.clone(),
+						lang,
+						code_page: u32_at(entry, 8)?,
+						data: self.rva_slice(u32_at(entry, 0)?, u32_at(entry, 4)? as _)?,
+					});
+				}
+			}
+		}
+		Ok(ress)
+	}
+
+	/// Returns the raw bytes of a section, as stored in the file.
+	#[must_use]
+	pub fn section_data(&self, section: &PeSection) -> SysResult<&'a [u8]> {
+		let start = section.raw_offset as usize;
+		self.data.get(start..start + section.raw_size as usize)
+			.ok_or(co::ERROR::INVALID_DATA)
+	}
+
+	/// Returns the section headers.
+	#[must_use]
+	pub fn sections(&self) -> &[PeSection] {
+		&self.sections
+	}
+
+	/// Returns the offset of the optional header, which follows the COFF
+	/// header.
+	pub(in crate::kernel) fn opt_hdr(&self) -> usize {
+		self.opt_hdr
+	}
+
+	/// Returns the relative virtual address and the size of the given data
+	/// directory, if present.
+	pub(in crate::kernel) fn data_directory(&self, idx: usize) -> SysResult<Option<(u32, u32)>> {
+		let (count_pos, dirs_pos) = if self.is_64bit() { (108, 112) } else { (92, 96) };
+		if idx >= u32_at(self.data, self.opt_hdr + count_pos)? as usize {
+			return Ok(None);
+		}
+		let rva = u32_at(self.data, self.opt_hdr + dirs_pos + idx * 8)?;
+		let size = u32_at(self.data, self.opt_hdr + dirs_pos + idx * 8 + 4)?;
+		Ok(if rva == 0 { None } else { Some((rva, size)) })
+	}
+
+	/// Converts a relative virtual address into a file offset, returning also
+	/// the end of the raw data which contains it.
+	pub(in crate::kernel) fn rva_to_offset(&self, rva: u32) -> SysResult<(usize, usize)> {
+		let size_of_headers = u32_at(self.data, self.opt_hdr + 60)?;
+		if rva < size_of_headers {
+			return Ok((rva as _, size_of_headers as _));
+		}
+		self.sections.iter()
+			.find(|sec| rva >= sec.virtual_address && rva - sec.virtual_address < sec.raw_size)
+			.map(|sec| (
+				sec.raw_offset as usize + (rva - sec.virtual_address) as usize,
+				sec.raw_offset as usize + sec.raw_size as usize,
+			))
+			.ok_or(co::ERROR::INVALID_DATA)
+	}
+
+	fn rva_slice(&self, rva: u32, len: usize) -> SysResult<&'a [u8]> {
+		let (start, end) = self.rva_to_offset(rva)?;
+		if start.checked_add(len).is_none_or(|stop| stop > end) {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		self.data.get(start..start + len)
+			.ok_or(co::ERROR::INVALID_DATA)
+	}
+
+	fn rva_slice_to_end(&self, rva: u32) -> SysResult<&'a [u8]> {
+		let (start, end) = self.rva_to_offset(rva)?;
+		self.data.get(start..end.min(self.data.len()))
+			.ok_or(co::ERROR::INVALID_DATA)
+	}
+
+	/// Reads a null-terminated ANSI string.
+	fn rva_str(&self, rva: u32) -> SysResult<String> {
+		let buf = self.rva_slice_to_end(rva)?;
+		let len = buf.iter()
+			.position(|b| *b == 0)
+			.ok_or(co::ERROR::INVALID_DATA)?;
+		Ok(String::from_utf8_lossy(&buf[..len]).into_owned())
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A function exported by a [`PeImage`](crate::PeImage).
+#[derive(Clone, Debug)]
+pub struct PeExport {
+	/// Ordinal of the function, already added to the base ordinal.
+	pub ordinal: u32,
+	/// Name of the function, if exported by name.
+	pub name: Option<String>,
+	/// Relative virtual address of the function, or of the forwarder string.
+	pub rva: u32,
+	/// The function this one is forwarded to, like `NTDLL.RtlAllocateHeap`.
+	pub forwarder: Option<String>,
+}
+
+/// A DLL imported by a [`PeImage`](crate::PeImage).
+#[derive(Clone, Debug)]
+pub struct PeImport {
+	/// Name of the DLL, like `KERNEL32.dll`.
+	pub dll_name: String,
+	/// The imported symbols.
+	pub symbols: Vec<PeImportSymbol>,
+}
+
+/// A symbol imported from a DLL, in a [`PeImport`](crate::PeImport).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PeImportSymbol {
+	/// Symbol imported by name.
+	Name {
+		/// Index into the export name table of the DLL, used to speed up the
+		/// lookup.
+		hint: u16,
+		/// Name of the symbol.
+		name: String,
+	},
+	/// Symbol imported by ordinal.
+	Ordinal(u16),
+}
+
+/// A resource of a [`PeImage`](crate::PeImage).
+#[derive(Clone, Debug)]
+pub struct PeResource<'a> {
+	/// Resource type.
+	pub rt: RtStr,
+	/// Resource name.
+	pub name: IdStr,
+	/// Resource language.
+	pub lang: LANGID,
+	/// Code page used to decode the code point values in the data. Usually
+	/// zero.
+	pub code_page: u32,
+	/// The raw data, as returned by
+	/// [`HINSTANCE::LoadResource`](crate::prelude::kernel_Hinstance::LoadResource).
+	pub data: &'a [u8],
+}
+
+/// A section header of a [`PeImage`](crate::PeImage).
+#[derive(Clone, Debug)]
+pub struct PeSection {
+	/// Name of the section, like `.text`.
+	pub name: String,
+	/// Size of the section when loaded into memory.
+	pub virtual_size: u32,
+	/// Relative virtual address of the section when loaded into memory.
+	pub virtual_address: u32,
+	/// Size of the section data in the file.
+	pub raw_size: u32,
+	/// Offset of the section data in the file.
+	pub raw_offset: u32,
+	/// Characteristics of the section.
+	pub characteristics: co::IMAGE_SCN,
+}
+
+impl PeSection {
+	fn parse(data: &[u8], pos: usize) -> SysResult<Self> {
+		let hdr = data.get(pos..pos + SECTION_HEADER_SIZE)
+			.ok_or(co::ERROR::INVALID_DATA)?;
+		let name_len = hdr[..8].iter().position(|b| *b == 0).unwrap_or(8);
+		Ok(Self {
+			name: String::from_utf8_lossy(&hdr[..name_len]).into_owned(),
+			virtual_size: u32_at(hdr, 8)?,
+			virtual_address: u32_at(hdr, 12)?,
+			raw_size: u32_at(hdr, 16)?,
+			raw_offset: u32_at(hdr, 20)?,
+			characteristics: unsafe { co::IMAGE_SCN::from_raw(u32_at(hdr, 36)?) },
+		})
+	}
+}
+
+const PE32_MAGIC: u16 = 0x10b;
+const PE32_PLUS_MAGIC: u16 = 0x20b;
+const IMAGE_FILE_DLL: u16 = 0x2000;
+pub(in crate::kernel) const COFF_HEADER_SIZE: usize = 20;
+pub(in crate::kernel) const SECTION_HEADER_SIZE: usize = 40;
+const EXPORT_DIR_SIZE: usize = 40;
+const IMPORT_DESC_SIZE: usize = 20;
+const DIR_EXPORT: usize = 0;
+const DIR_IMPORT: usize = 1;
+pub(in crate::kernel) const DIR_RESOURCE: usize = 2;
+pub(in crate::kernel) const RES_SUBDIR: u32 = 0x8000_0000; // also flags a string name
+
+/// Limit of resource directory entries plus chars of string identifiers, so a
+/// malformed tree, with directories fanning out to the same data, can't
+/// exhaust the memory.
+const RES_MAX_ITEMS: usize = 1 << 20;
+
+/// Reads the resource directory tree, refusing subdirectories which are
+/// referenced more than once, and charging each entry and each char of the
+/// string identifiers to a budget.
+struct ResDirWalker<'a> {
+	rsrc: &'a [u8],
+	visited: HashSet<u32>,
+	budget: usize,
+}
+
+impl<'a> ResDirWalker<'a> {
+	fn charge(&mut self, cost: usize) -> SysResult<()> {
+		self.budget = self.budget.checked_sub(cost).ok_or(co::ERROR::INVALID_DATA)?;
+		Ok(())
+	}
+
+	/// Reads the entries of a resource directory, returning their identifiers
+	/// and the offsets of their subdirectories or data entries.
+	fn entries(&mut self, dir: u32, subdirs: bool) -> SysResult<Vec<(IdStr, u32)>> {
+		if !self.visited.insert(dir) {
+			return Err(co::ERROR::INVALID_DATA); // cycle or shared subdirectory
+		}
+		let rsrc = self.rsrc;
+		let dir = dir as usize;
+		let count = u16_at(rsrc, dir + 12)? as usize + u16_at(rsrc, dir + 14)? as usize;
+		self.charge(count)?;
+
+		let mut entries = Vec::with_capacity(count);
+		for idx in 0..count {
+			let entry = dir + 16 + idx * 8;
+			let raw_name = u32_at(rsrc, entry)?;
+			let raw_offset = u32_at(rsrc, entry + 4)?;
+			if (raw_offset & RES_SUBDIR != 0) != subdirs {
+				return Err(co::ERROR::INVALID_DATA); // unexpected tree depth
+			}
+
+			let id = if raw_name & RES_SUBDIR != 0 {
+				let pos = (raw_name & !RES_SUBDIR) as usize;
+				let len = u16_at(rsrc, pos)? as usize;
+				self.charge(len)?;
+				let chars = (0..len)
+					.map(|ch_idx| u16_at(rsrc, pos + 2 + ch_idx * 2))
+					.collect::<SysResult<Vec<_>>>()?;
+				IdStr::Str(WString::from_wchars_slice(&chars))
+			} else {
+				IdStr::Id(raw_name as _)
+			};
+			entries.push((id, raw_offset & !RES_SUBDIR));
+		}
+		Ok(entries)
+	}
+}
+
+/// Compares resource identifiers; strings are case-insensitive.
+pub(in crate::kernel) fn same_id(a: &IdStr, b: &IdStr) -> bool {
+	match (a, b) {
+		(IdStr::Id(a), IdStr::Id(b)) => a == b,
+		(IdStr::Str(a), IdStr::Str(b)) => a.to_string().to_uppercase() == b.to_string().to_uppercase(),
+		_ => false,
+	}
+}
+
+/// Compares resource types; strings are case-insensitive.
+pub(in crate::kernel) fn same_rt(a: &RtStr, b: &RtStr) -> bool {
+	match (a, b) {
+		(RtStr::Rt(a), RtStr::Rt(b)) => a == b,
+		(RtStr::Str(a), RtStr::Str(b)) => a.to_string().to_uppercase() == b.to_string().to_uppercase(),
+		_ => false,
+	}
+}
+
+fn array_len(count: usize, elem_size: usize) -> SysResult<usize> {
+	count.checked_mul(elem_size).ok_or(co::ERROR::INVALID_DATA)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const SECTION_VA: u32 = 0x1000;
+
+	fn set_u16(buf: &mut [u8], pos: usize, val: u16) {
+		buf[pos..pos + 2].copy_from_slice(&val.to_le_bytes());
+	}
+
+	fn set_u32(buf: &mut [u8], pos: usize, val: u32) {
+		buf[pos..pos + 4].copy_from_slice(&val.to_le_bytes());
+	}
+
+	fn set_u64(buf: &mut [u8], pos: usize, val: u64) {
+		buf[pos..pos + 8].copy_from_slice(&val.to_le_bytes());
+	}
+
+	/// Builds a PE32 or PE32+ image with a single `.data` section at
+	/// `SECTION_VA`, and the given data directories as (index, RVA, size).
+	fn minimal_pe(is64: bool, section: &[u8], dirs: &[(usize, u32, u32)]) -> Vec<u8> {
+		let raw_size = section.len().next_multiple_of(0x200);
+		let mut buf = vec![0u8; 0x200 + raw_size];
+		buf[..2].copy_from_slice(b"MZ");
+		set_u32(&mut buf, 0x3c, 0x40);
+		buf[0x40..0x44].copy_from_slice(b"PE\0\0");
+
+		let coff_hdr = 0x44;
+		let opt_hdr_size = if is64 { 240 } else { 224 };
+		set_u16(&mut buf, coff_hdr, if is64 { 0x8664 } else { 0x14c });
+		set_u16(&mut buf, coff_hdr + 2, 1); // NumberOfSections
+		set_u16(&mut buf, coff_hdr + 16, opt_hdr_size as _);
+		set_u16(&mut buf, coff_hdr + 18, if is64 { 0x2022 } else { 0x0102 }); // Characteristics
+
+		let opt_hdr = coff_hdr + COFF_HEADER_SIZE;
+		set_u32(&mut buf, opt_hdr + 16, SECTION_VA + 0x10); // AddressOfEntryPoint
+		set_u32(&mut buf, opt_hdr + 60, 0x200); // SizeOfHeaders
+		let dirs_pos = if is64 {
+			set_u16(&mut buf, opt_hdr, PE32_PLUS_MAGIC);
+			set_u64(&mut buf, opt_hdr + 24, 0x1_8000_0000);
+			set_u32(&mut buf, opt_hdr + 108, 16);
+			opt_hdr + 112
+		} else {
+			set_u16(&mut buf, opt_hdr, PE32_MAGIC);
+			set_u32(&mut buf, opt_hdr + 28, 0x40_0000);
+			set_u32(&mut buf, opt_hdr + 92, 16);
+			opt_hdr + 96
+		};
+		for (idx, rva, size) in dirs.iter() {
+			set_u32(&mut buf, dirs_pos + idx * 8, *rva);
+			set_u32(&mut buf, dirs_pos + idx * 8 + 4, *size);
+		}
+
+		let sec_hdr = opt_hdr + opt_hdr_size;
+		buf[sec_hdr..sec_hdr + 8].copy_from_slice(b".data\0\0\0");
+		set_u32(&mut buf, sec_hdr + 8, section.len() as _);
+		set_u32(&mut buf, sec_hdr + 12, SECTION_VA);
+		set_u32(&mut buf, sec_hdr + 16, raw_size as _);
+		set_u32(&mut buf, sec_hdr + 20, 0x200);
+		set_u32(&mut buf, sec_hdr + 36, 0xc000_0040); // initialized data, read, write
+		buf[0x200..0x200 + section.len()].copy_from_slice(section);
+		buf
+	}
+
+	/// Writes a resource directory header, returning the position of its
+	/// first entry.
+	fn res_dir(rsrc: &mut [u8], pos: usize, named: u16, ids: u16) -> usize {
+		set_u16(rsrc, pos + 12, named);
+		set_u16(rsrc, pos + 14, ids);
+		pos + 16
+	}
+
+	fn res_entry(rsrc: &mut [u8], pos: usize, name: u32, offset: u32) {
+		set_u32(rsrc, pos, name);
+		set_u32(rsrc, pos + 4, offset);
+	}
+
+	fn res_data(rsrc: &mut [u8], pos: usize, data_pos: usize, data: &[u8]) {
+		set_u32(rsrc, pos, SECTION_VA + data_pos as u32);
+		set_u32(rsrc, pos + 4, data.len() as _);
+		set_u32(rsrc, pos + 8, 1252); // CodePage
+		rsrc[data_pos..data_pos + data.len()].copy_from_slice(data);
+	}
+
+	/// A resource section with a `MYTYPE` type holding name 7 in the neutral
+	/// language, and a manifest type holding name 1 in two languages.
+	fn rsrc_section() -> Vec<u8> {
+		let mut rsrc = vec![0u8; 0x100];
+		let e = res_dir(&mut rsrc, 0x00, 1, 1); // root
+		res_entry(&mut rsrc, e, RES_SUBDIR | 0xb8, RES_SUBDIR | 0x20);
+		res_entry(&mut rsrc, e + 8, co::RT::MANIFEST.raw() as _, RES_SUBDIR | 0x50);
+
+		let e = res_dir(&mut rsrc, 0x20, 0, 1); // MYTYPE
+		res_entry(&mut rsrc, e, 7, RES_SUBDIR | 0x38);
+		let e = res_dir(&mut rsrc, 0x38, 0, 1);
+		res_entry(&mut rsrc, e, 0x0000, 0x88);
+
+		let e = res_dir(&mut rsrc, 0x50, 0, 1); // RT_MANIFEST
+		res_entry(&mut rsrc, e, 1, RES_SUBDIR | 0x68);
+		let e = res_dir(&mut rsrc, 0x68, 0, 2);
+		res_entry(&mut rsrc, e, 0x0409, 0x98);
+		res_entry(&mut rsrc, e + 8, 0x0416, 0xa8);
+
+		res_data(&mut rsrc, 0x88, 0xc8, &[1, 2, 3]);
+		res_data(&mut rsrc, 0x98, 0xd0, b"<assembly/>");
+		res_data(&mut rsrc, 0xa8, 0xe0, b"<a/>");
+
+		set_u16(&mut rsrc, 0xb8, 6);
+		for (idx, ch) in "MYTYPE".encode_utf16().enumerate() {
+			set_u16(&mut rsrc, 0xba + idx * 2, ch);
+		}
+		rsrc
+	}
+
+	fn rsrc_pe(rsrc: &[u8]) -> Vec<u8> {
+		minimal_pe(false, rsrc, &[(DIR_RESOURCE, SECTION_VA, rsrc.len() as _)])
+	}
+
+	#[test]
+	fn headers() {
+		let image = minimal_pe(false, &[0xcc; 0x20], &[]);
+		let pe = PeImage::from_bytes(&image).unwrap();
+		assert!(!pe.is_64bit());
+		assert!(!pe.is_dll());
+		assert_eq!(pe.machine(), co::IMAGE_FILE_MACHINE::I386);
+		assert_eq!(pe.entry_point(), 0x1010);
+		assert_eq!(pe.image_base(), 0x40_0000);
+		assert_eq!(pe.sections().len(), 1);
+		assert_eq!(pe.sections()[0].name, ".data");
+		assert_eq!(pe.sections()[0].virtual_address, SECTION_VA);
+		assert_eq!(pe.sections()[0].raw_size, 0x200);
+		assert_eq!(pe.section_data(&pe.sections()[0]).unwrap()[..0x20], [0xcc; 0x20]);
+		assert!(pe.resources().unwrap().is_empty());
+		assert!(pe.imports().unwrap().is_empty());
+		assert!(pe.exports().unwrap().is_empty());
+		assert_eq!(pe.manifest().unwrap(), None);
+
+		let image = minimal_pe(true, &[0xcc; 0x20], &[]);
+		let pe = PeImage::from_bytes(&image).unwrap();
+		assert!(pe.is_64bit());
+		assert!(pe.is_dll());
+		assert_eq!(pe.machine(), co::IMAGE_FILE_MACHINE::AMD64);
+		assert_eq!(pe.image_base(), 0x1_8000_0000);
+	}
+
+	#[test]
+	fn malformed_headers() {
+		let image = minimal_pe(false, &[], &[]);
+		let from_err = |image: &[u8]| PeImage::from_bytes(image).err();
+
+		assert_eq!(from_err(&[]), Some(co::ERROR::BAD_EXE_FORMAT));
+		assert_eq!(from_err(&image[..0x3c]), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(from_err(&image[..0x100]), Some(co::ERROR::INVALID_DATA)); // section header cut
+
+		let mut bad = image.clone();
+		bad[..2].copy_from_slice(b"ZM");
+		assert_eq!(from_err(&bad), Some(co::ERROR::BAD_EXE_FORMAT));
+		let mut bad = image.clone();
+		bad[0x42] = b'X';
+		assert_eq!(from_err(&bad), Some(co::ERROR::BAD_EXE_FORMAT));
+		let mut bad = image.clone();
+		set_u16(&mut bad, 0x58, 0x107); // ROM image
+		assert_eq!(from_err(&bad), Some(co::ERROR::BAD_EXE_FORMAT));
+	}
+
+	#[test]
+	fn resources() {
+		let image = rsrc_pe(&rsrc_section());
+		let pe = PeImage::from_bytes(&image).unwrap();
+		let my_type = RtStr::Str(WString::from_str("MYTYPE"));
+		let manifest = RtStr::Rt(co::RT::MANIFEST);
+		let en_us = LANGID::new(co::LANG::ENGLISH, co::SUBLANG::ENGLISH_US);
+		let pt_br = LANGID::new(co::LANG::PORTUGUESE, co::SUBLANG::PORTUGUESE_BRAZILIAN);
+
+		let ress = pe.resources().unwrap();
+		assert_eq!(ress.len(), 3);
+		assert_eq!((&ress[0].rt, &ress[0].name, ress[0].lang.raw()), (&my_type, &IdStr::Id(7), 0));
+		assert_eq!(ress[0].code_page, 1252);
+		assert_eq!(ress[0].data, [1, 2, 3]);
+		assert_eq!((&ress[1].rt, &ress[1].name, ress[1].lang), (&manifest, &IdStr::Id(1), en_us));
+		assert_eq!(ress[1].data, b"<assembly/>");
+		assert_eq!(ress[2].lang, pt_br);
+		assert_eq!(ress[2].data, b"<a/>");
+
+		assert_eq!(pe.resource_types().unwrap(), [my_type.clone(), manifest.clone()]);
+		assert_eq!(pe.resource_names(&manifest).unwrap(), [IdStr::Id(1)]);
+		assert_eq!(pe.resource_langs(&manifest, &IdStr::Id(1)).unwrap(), [en_us, pt_br]);
+		assert_eq!(pe.manifest().unwrap().as_deref(), Some("<assembly/>"));
+
+		let my_type_lower = RtStr::Str(WString::from_str("mytype"));
+		assert_eq!(pe.find_resource(&my_type_lower, &IdStr::Id(7), None).unwrap(), [1, 2, 3]);
+		assert_eq!(pe.find_resource(&manifest, &IdStr::Id(1), Some(pt_br)).unwrap(), b"<a/>");
+		assert_eq!(pe.find_resource(&RtStr::Rt(co::RT::ICON), &IdStr::Id(1), None).err(),
+			Some(co::ERROR::RESOURCE_TYPE_NOT_FOUND));
+		assert_eq!(pe.find_resource(&manifest, &IdStr::Id(2), None).err(),
+			Some(co::ERROR::RESOURCE_NAME_NOT_FOUND));
+		assert_eq!(pe.find_resource(&manifest, &IdStr::Id(1), Some(LANGID::USER_DEFAULT)).err(),
+			Some(co::ERROR::RESOURCE_LANG_NOT_FOUND));
+	}
+
+	#[test]
+	fn malformed_resources() {
+		let res_err = |rsrc: &[u8]| {
+			let image = rsrc_pe(rsrc);
+			PeImage::from_bytes(&image).unwrap().resources().err()
+		};
+
+		let mut shared = rsrc_section(); // both names point to the same language directory
+		res_entry(&mut shared, 0x60, 1, RES_SUBDIR | 0x38);
+		assert_eq!(res_err(&shared), Some(co::ERROR::INVALID_DATA));
+
+		let mut cycle = rsrc_section(); // a name entry points back to the root
+		res_entry(&mut cycle, 0x30, 7, RES_SUBDIR);
+		assert_eq!(res_err(&cycle), Some(co::ERROR::INVALID_DATA));
+
+		let mut shallow = rsrc_section(); // a name entry points to a data entry
+		res_entry(&mut shallow, 0x30, 7, 0x88);
+		assert_eq!(res_err(&shallow), Some(co::ERROR::INVALID_DATA));
+
+		let mut deep = rsrc_section(); // a language entry points to a directory
+		res_entry(&mut deep, 0x48, 0, RES_SUBDIR | 0x50);
+		assert_eq!(res_err(&deep), Some(co::ERROR::INVALID_DATA));
+
+		let mut named_lang = rsrc_section();
+		res_entry(&mut named_lang, 0x48, RES_SUBDIR | 0xb8, 0x88);
+		assert_eq!(res_err(&named_lang), Some(co::ERROR::INVALID_DATA));
+
+		let mut huge = rsrc_section(); // entries beyond the section
+		res_dir(&mut huge, 0x00, 0xffff, 0xffff);
+		assert_eq!(res_err(&huge), Some(co::ERROR::INVALID_DATA));
+
+		let mut out_of_bounds = rsrc_section(); // data beyond the section
+		set_u32(&mut out_of_bounds, 0x88 + 4, 0x1000);
+		assert_eq!(res_err(&out_of_bounds), Some(co::ERROR::INVALID_DATA));
+	}
+
+	/// An import section with `KERNEL32.dll`, which has a lookup table, and
+	/// `USER32.dll`, which has only the address table.
+	fn import_section(is64: bool) -> Vec<u8> {
+		let mut sec = vec![0u8; 0xc0];
+		let rva = |pos: u32| SECTION_VA + pos;
+		set_u32(&mut sec, 0x00, rva(0x40)); // OriginalFirstThunk
+		set_u32(&mut sec, 0x0c, rva(0x80)); // Name
+		set_u32(&mut sec, 0x10, rva(0x60)); // FirstThunk
+		set_u32(&mut sec, 0x14 + 0x0c, rva(0x90));
+		set_u32(&mut sec, 0x14 + 0x10, rva(0x70));
+
+		if is64 {
+			set_u64(&mut sec, 0x40, rva(0xa0) as _);
+			set_u64(&mut sec, 0x48, 1 << 63 | 5);
+			set_u64(&mut sec, 0x60, 0xdead_beef_dead_beef); // bound address, ignored
+			set_u64(&mut sec, 0x70, 1 << 63 | 0x10);
+		} else {
+			set_u32(&mut sec, 0x40, rva(0xa0));
+			set_u32(&mut sec, 0x44, 1 << 31 | 5);
+			set_u32(&mut sec, 0x60, 0xdead_beef);
+			set_u32(&mut sec, 0x70, 1 << 31 | 0x10);
+		}
+
+		sec[0x80..0x8d].copy_from_slice(b"KERNEL32.dll\0");
+		sec[0x90..0x9b].copy_from_slice(b"USER32.dll\0");
+		set_u16(&mut sec, 0xa0, 0x123);
+		sec[0xa2..0xae].copy_from_slice(b"ExitProcess\0");
+		sec
+	}
+
+	#[test]
+	fn imports() {
+		for is64 in [false, true] {
+			let image = minimal_pe(is64, &import_section(is64), &[(DIR_IMPORT, SECTION_VA, 0x3c)]);
+			let imports = PeImage::from_bytes(&image).unwrap().imports().unwrap();
+			assert_eq!(imports.len(), 2);
+			assert_eq!(imports[0].dll_name, "KERNEL32.dll");
+			assert_eq!(imports[0].symbols, [
+				PeImportSymbol::Name { hint: 0x123, name: "ExitProcess".to_owned() },
+				PeImportSymbol::Ordinal(5),
+			]);
+			assert_eq!(imports[1].dll_name, "USER32.dll");
+			assert_eq!(imports[1].symbols, [PeImportSymbol::Ordinal(0x10)]);
+		}
+
+		let mut unterminated = import_section(false);
+		unterminated[0x28..0x3c].fill(0xff); // no null descriptor
+		let image = minimal_pe(false, &unterminated, &[(DIR_IMPORT, SECTION_VA, 0x3c)]);
+		assert_eq!(PeImage::from_bytes(&image).unwrap().imports().err(), Some(co::ERROR::INVALID_DATA));
+	}
+
+	/// An export section with base ordinal 10: `Beta` by name, an unused slot,
+	/// a function without name, and `Alpha` forwarded to NTDLL.
+	fn export_section() -> Vec<u8> {
+		let mut sec = vec![0u8; 0x80];
+		set_u32(&mut sec, 16, 10); // Base
+		set_u32(&mut sec, 20, 4); // NumberOfFunctions
+		set_u32(&mut sec, 24, 2); // NumberOfNames
+		set_u32(&mut sec, 28, SECTION_VA + 0x28);
+		set_u32(&mut sec, 32, SECTION_VA + 0x38);
+		set_u32(&mut sec, 36, SECTION_VA + 0x40);
+
+		for (idx, rva) in [0x2000, 0, 0x3000, SECTION_VA + 0x50].iter().enumerate() {
+			set_u32(&mut sec, 0x28 + idx * 4, *rva);
+		}
+		set_u32(&mut sec, 0x38, SECTION_VA + 0x70);
+		set_u32(&mut sec, 0x3c, SECTION_VA + 0x78);
+		set_u16(&mut sec, 0x40, 3);
+		set_u16(&mut sec, 0x42, 0);
+
+		sec[0x50..0x66].copy_from_slice(b"NTDLL.RtlAllocateHeap\0");
+		sec[0x70..0x76].copy_from_slice(b"Alpha\0");
+		sec[0x78..0x7d].copy_from_slice(b"Beta\0");
+		sec
+	}
+
+	#[test]
+	fn exports() {
+		for is64 in [false, true] {
+			let image = minimal_pe(is64, &export_section(), &[(DIR_EXPORT, SECTION_VA, 0x70)]);
+			let exports = PeImage::from_bytes(&image).unwrap().exports().unwrap();
+			let summary = exports.iter()
+				.map(|exp| (exp.ordinal, exp.name.as_deref(), exp.rva, exp.forwarder.as_deref()))
+				.collect::<Vec<_>>();
+			assert_eq!(summary, [
+				(10, Some("Beta"), 0x2000, None),
+				(12, None, 0x3000, None),
+				(13, Some("Alpha"), 0x1050, Some("NTDLL.RtlAllocateHeap")),
+			]);
+		}
+
+		let mut bad_ordinal = export_section();
+		set_u16(&mut bad_ordinal, 0x40, 4); // beyond NumberOfFunctions
+		let image = minimal_pe(false, &bad_ordinal, &[(DIR_EXPORT, SECTION_VA, 0x70)]);
+		assert_eq!(PeImage::from_bytes(&image).unwrap().exports().err(), Some(co::ERROR::INVALID_DATA));
+
+		let mut huge = export_section();
+		set_u32(&mut huge, 20, u32::MAX);
+		let image = minimal_pe(false, &huge, &[(DIR_EXPORT, SECTION_VA, 0x70)]);
+		assert_eq!(PeImage::from_bytes(&image).unwrap().exports().err(), Some(co::ERROR::INVALID_DATA));
+	}
+}
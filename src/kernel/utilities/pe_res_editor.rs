@@ -0,0 +1,638 @@
+use std::collections::BTreeMap;
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::utilities::le_bytes::{u16_at, u32_at};
+use crate::kernel::utilities::pe_image::{
+	COFF_HEADER_SIZE, DIR_RESOURCE, RES_SUBDIR, SECTION_HEADER_SIZE,
+};
+
+/// Offline editor of the resources of a PE image, like EXE and DLL files.
+///
+/// This is a pure Rust alternative to
+/// [`HUPDATERSRC::BeginUpdateResource`](crate::prelude::kernel_Hupdatersrc::BeginUpdateResource),
+/// with the same type, name and language addressing. It works on any platform
+/// and never loads the image. The whole resource section is rebuilt, and the
+/// section sizes, the image size and the checksum are fixed.
+///
+/// The resource section is rewritten in place when the new resources fit in
+/// it, or when it's the last section. Otherwise, a new section is appended to
+/// the image.
+///
+/// Since any change invalidates the digital signature, the certificate table
+/// is removed; sign the image again after editing it.
+///
+/// # Examples
+///
+/// Stamping a manifest and replacing the version info:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let manifest = std::fs::read("C:\\Temp\\foo.manifest").unwrap();
+/// let ver_data: Vec<u8> = vec![]; // VS_VERSIONINFO block
+///
+/// let mut ed = w::PeResourceEditor::parse_from_file("C:\\Temp\\foo.exe", false)?;
+/// ed.update_resource(
+///     w::RtStr::Rt(co::RT::MANIFEST),
+///     w::IdStr::Id(1),
+///     w::LANGID::new(co::LANG::ENGLISH, co::SUBLANG::ENGLISH_US),
+///     &manifest,
+/// );
+/// ed.update_resource(
+///     w::RtStr::Rt(co::RT::VERSION),
+///     w::IdStr::Id(1),
+///     w::LANGID::new(co::LANG::NEUTRAL, co::SUBLANG::NEUTRAL),
+///     &ver_data,
+/// );
+/// ed.serialize_to_file("C:\\Temp\\foo.exe")?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub struct PeResourceEditor {
+	image: Vec<u8>,
+	ress: Vec<ResEntry>,
+}
+
+impl PeResourceEditor {
+	/// Parses the image and its current resources from raw bytes. If
+	/// `delete_existing_resources` is `true`, the current resources are
+	/// discarded.
+	///
+	/// Fails with
+	/// [`co::ERROR::BAD_EXE_FORMAT`](crate::co::ERROR::BAD_EXE_FORMAT) if the
+	/// bytes are not a PE image, or
+	/// [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if it's
+	/// corrupted.
+	#[must_use]
+	pub fn parse_bytes(bytes: &[u8], delete_existing_resources: bool) -> SysResult<Self> {
+		let pe = PeImage::from_bytes(bytes)?;
+		let ress = if delete_existing_resources {
+			Vec::default()
+		} else {
+			pe.resources()?
+				.into_iter()
+				.map(|res| ResEntry {
+					rt: res.rt,
+					name: res.name,
+					lang: res.lang,
+					code_page: res.code_page,
+					data: res.data.to_vec(),
+				})
+				.collect()
+		};
+		Ok(Self { image: bytes.to_vec(), ress })
+	}
+
+	/// Parses the image directly from a file with
+	/// [`PeResourceEditor::parse_bytes`](crate::PeResourceEditor::parse_bytes).
+	#[must_use]
+	pub fn parse_from_file(file_path: &str, delete_existing_resources: bool) -> SysResult<Self> {
+		let fin = FileMapped::open(file_path, FileAccess::ExistingReadOnly)?;
+		Self::parse_bytes(fin.as_slice(), delete_existing_resources)
+	}
+
+	/// Removes a resource, like
+	/// [`HUPDATERSRC::UpdateResource`](crate::prelude::kernel_Hupdatersrc::UpdateResource)
+	/// with no data. String identifiers are compared case-insensitively.
+	///
+	/// Fails with
+	/// [`co::ERROR::RESOURCE_NOT_FOUND`](crate::co::ERROR::RESOURCE_NOT_FOUND)
+	/// if the resource doesn't exist.
+	pub fn delete_resource(&mut self,
+		resource_type: &RtStr,
+		resource_id: &IdStr,
+		language: LANGID,
+	) -> SysResult<()>
+	{
+		let idx = self.find(resource_type, resource_id, language)
+			.ok_or(co::ERROR::RESOURCE_NOT_FOUND)?;
+		self.ress.remove(idx);
+		Ok(())
+	}
+
+	/// Builds the image with the new resources.
+	///
+	/// Fails with
+	/// [`co::ERROR::NOT_SUPPORTED`](crate::co::ERROR::NOT_SUPPORTED) if a new
+	/// section is needed, but there is no room for its header, or if the
+	/// optional header has no resource data directory entry. Fails with
+	/// [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if the
+	/// section addresses or sizes overflow.
+	#[must_use]
+	pub fn serialize_to_bytes(&self) -> SysResult<Vec<u8>> {
+		let pe = PeImage::from_bytes(&self.image)?;
+		let opt_hdr = pe.opt_hdr();
+		let coff_hdr = opt_hdr - COFF_HEADER_SIZE;
+		let is_64 = pe.is_64bit();
+		let dirs = opt_hdr + if is_64 { 112 } else { 96 };
+		let sect_align = u32_at(&self.image, opt_hdr + 32)?.max(1);
+		let file_align = u32_at(&self.image, opt_hdr + 36)?.max(1);
+		let sections = pe.sections();
+		let sect_table = opt_hdr + u16_at(&self.image, coff_hdr + 16)? as usize;
+
+		// The certificate table is at the end of the file, and will be removed.
+		let file_end = match pe.data_directory(DIR_SECURITY)? {
+			Some((cert_off, cert_size)) if cert_off as usize + cert_size as usize == self.image.len() =>
+				cert_off as usize,
+			_ => self.image.len(),
+		};
+		if self.ress.is_empty() && pe.data_directory(DIR_RESOURCE)?.is_none() {
+			let mut buf = self.image[..file_end].to_vec(); // nothing to write
+			finish_image(&mut buf, opt_hdr, dirs)?;
+			return Ok(buf);
+		}
+		if u32_at(&self.image, dirs - 4)? as usize <= DIR_RESOURCE { // NumberOfRvaAndSizes
+			return Err(co::ERROR::NOT_SUPPORTED); // nowhere to point to the resources
+		}
+
+		let sections_end = sections.iter()
+			.map(|sec| sec.raw_offset as usize + sec.raw_size as usize)
+			.max()
+			.unwrap_or_default()
+			.min(file_end);
+
+		// Which section receives the resources, and which bytes are replaced.
+		let cur_rsrc = pe.data_directory(DIR_RESOURCE)?
+			.and_then(|(rva, _)| sections.iter().position(|sec| sec.virtual_address == rva));
+		let mut bound_import_clash = false;
+		let mut placement = None;
+		if let Some(idx) = cur_rsrc {
+			let sec = &sections[idx];
+			let tree = build_tree(&self.ress, sec.virtual_address)?;
+			let va_room = sections.iter()
+				.map(|other| other.virtual_address)
+				.filter(|va| *va > sec.virtual_address)
+				.min()
+				.map_or(u32::MAX, |va| va - sec.virtual_address);
+			let is_last = va_room == u32::MAX
+				&& sec.raw_offset as usize + sec.raw_size as usize == sections_end;
+
+			if tree.len() <= sec.raw_size as usize && tree.len() <= va_room as usize {
+				let raw = padded(tree.clone(), sec.raw_size);
+				placement = Some((idx, sec.virtual_address, sec.raw_offset as usize, sec.raw_size as usize, raw, tree.len()));
+			} else if is_last {
+				let raw = padded(tree.clone(), align(tree.len() as _, file_align)?);
+				placement = Some((idx, sec.virtual_address, sec.raw_offset as usize, sec.raw_size as usize, raw, tree.len()));
+			}
+		}
+
+		let (sec_idx, va, splice_pos, splice_len, raw, tree_len) = match placement {
+			Some(placement) => placement,
+			None => { // append a new section
+				let idx = sections.len();
+				let first_raw = sections.iter()
+					.filter(|sec| sec.raw_size != 0)
+					.map(|sec| sec.raw_offset as usize)
+					.min()
+					.unwrap_or(file_end);
+				let size_of_headers = u32_at(&self.image, opt_hdr + 60)? as usize;
+				if sect_table + (idx + 1) * SECTION_HEADER_SIZE > size_of_headers.min(first_raw) {
+					return Err(co::ERROR::NOT_SUPPORTED); // no room for another section header
+				}
+				bound_import_clash = pe.data_directory(DIR_BOUND_IMPORT)?
+					.is_some_and(|(off, _)| (off as usize) < sect_table + (idx + 1) * SECTION_HEADER_SIZE);
+
+				let va = align(
+					sections.iter()
+						.map(|sec| sec.virtual_address.checked_add(sec.virtual_size.max(sec.raw_size)))
+						.collect::<Option<Vec<_>>>()
+						.ok_or(co::ERROR::INVALID_DATA)?
+						.into_iter()
+						.max()
+						.unwrap_or(size_of_headers as _),
+					sect_align,
+				)?;
+				let raw_offset = align(sections_end as _, file_align)? as usize;
+				let tree = build_tree(&self.ress, va)?;
+				let tree_len = tree.len();
+				let mut raw = vec![0u8; raw_offset - sections_end]; // alignment gap
+				raw.extend_from_slice(&padded(tree, align(tree_len as _, file_align)?));
+				(idx, va, sections_end, 0, raw, tree_len)
+			},
+		};
+
+		let mut buf = Vec::with_capacity(file_end + raw.len());
+		buf.extend_from_slice(&self.image[..splice_pos]);
+		buf.extend_from_slice(&raw);
+		buf.extend_from_slice(&self.image[(splice_pos + splice_len).min(file_end)..file_end]);
+		let shift = raw.len() as i64 - splice_len as i64;
+		let old_splice_end = (splice_pos + splice_len) as u32;
+
+		// Section header.
+		let sec_hdr = sect_table + sec_idx * SECTION_HEADER_SIZE;
+		let (raw_offset, raw_size) = if sec_idx == sections.len() {
+			let gap = raw.len() - align(tree_len as _, file_align)? as usize;
+			let hdr = &mut buf[sec_hdr..sec_hdr + SECTION_HEADER_SIZE];
+			hdr.fill(0);
+			hdr[..8].copy_from_slice(b".rsrc\0\0\0");
+			set_u32(&mut buf, sec_hdr + 36,
+				(co::IMAGE_SCN::CNT_INITIALIZED_DATA | co::IMAGE_SCN::MEM_READ).raw());
+			let num_sections = u16_at(&buf, coff_hdr + 2)? + 1;
+			set_u16(&mut buf, coff_hdr + 2, num_sections);
+			((splice_pos + gap) as u32, (raw.len() - gap) as u32)
+		} else {
+			(splice_pos as u32, raw.len() as u32)
+		};
+		set_u32(&mut buf, sec_hdr + 8, tree_len as _);
+		set_u32(&mut buf, sec_hdr + 12, va);
+		set_u32(&mut buf, sec_hdr + 16, raw_size);
+		set_u32(&mut buf, sec_hdr + 20, raw_offset);
+
+		// Sections whose data come after the replaced bytes.
+		for (idx, sec) in sections.iter().enumerate() {
+			if idx != sec_idx && sec.raw_size != 0 && sec.raw_offset >= old_splice_end {
+				set_u32(&mut buf, sect_table + idx * SECTION_HEADER_SIZE + 20,
+					shifted(sec.raw_offset, shift));
+			}
+		}
+
+		// Other file pointers after the replaced bytes.
+		let sym_table = u32_at(&buf, coff_hdr + 8)?;
+		if sym_table != 0 && sym_table >= old_splice_end {
+			set_u32(&mut buf, coff_hdr + 8, shifted(sym_table, shift));
+		}
+		if let Some((dbg_rva, dbg_size)) = pe.data_directory(DIR_DEBUG)? {
+			let (dbg_off, _) = pe.rva_to_offset(dbg_rva)?;
+			let dbg_off = if dbg_off >= old_splice_end as usize {
+				shifted(dbg_off as _, shift) as usize
+			} else {
+				dbg_off
+			};
+			for entry in (0..dbg_size as usize / DEBUG_DIR_SIZE).map(|idx| dbg_off + idx * DEBUG_DIR_SIZE) {
+				let ptr = u32_at(&buf, entry + 24)?; // PointerToRawData
+				if ptr != 0 && ptr >= old_splice_end {
+					set_u32(&mut buf, entry + 24, shifted(ptr, shift));
+				}
+			}
+		}
+
+		// Optional header.
+		let init_data = u32_at(&buf, opt_hdr + 8)?;
+		set_u32(&mut buf, opt_hdr + 8, shifted(init_data, raw_size as i64 - splice_len as i64));
+		let image_end = (0..u16_at(&buf, coff_hdr + 2)? as usize)
+			.map(|idx| {
+				let hdr = sect_table + idx * SECTION_HEADER_SIZE;
+				u32_at(&buf, hdr + 12)?
+					.checked_add(u32_at(&buf, hdr + 8)?.max(u32_at(&buf, hdr + 16)?))
+					.ok_or(co::ERROR::INVALID_DATA)
+			})
+			.collect::<SysResult<Vec<_>>>()?
+			.into_iter()
+			.max()
+			.unwrap_or_default();
+		set_u32(&mut buf, opt_hdr + 56, align(image_end, sect_align)?); // SizeOfImage
+		set_u32(&mut buf, dirs + DIR_RESOURCE * 8, va);
+		set_u32(&mut buf, dirs + DIR_RESOURCE * 8 + 4, tree_len as _);
+		if bound_import_clash { // overwritten by the new section header, not needed anyway
+			set_u32(&mut buf, dirs + DIR_BOUND_IMPORT * 8, 0);
+			set_u32(&mut buf, dirs + DIR_BOUND_IMPORT * 8 + 4, 0);
+		}
+		finish_image(&mut buf, opt_hdr, dirs)?;
+
+		Ok(buf)
+	}
+
+	/// Writes the image directly to a file with
+	/// [`PeResourceEditor::serialize_to_bytes`](crate::PeResourceEditor::serialize_to_bytes).
+	pub fn serialize_to_file(&self, file_path: &str) -> SysResult<()> {
+		let bytes = self.serialize_to_bytes()?;
+		let fout = File::open(file_path, FileAccess::OpenOrCreateRW)?;
+		fout.erase_and_write(&bytes)?;
+		Ok(())
+	}
+
+	/// Adds a resource, or replaces its data if it already exists, like
+	/// [`HUPDATERSRC::UpdateResource`](crate::prelude::kernel_Hupdatersrc::UpdateResource).
+	/// String identifiers are compared case-insensitively.
+	pub fn update_resource(&mut self,
+		resource_type: RtStr,
+		resource_id: IdStr,
+		language: LANGID,
+		data: &[u8],
+	)
+	{
+		match self.find(&resource_type, &resource_id, language) {
+			Some(idx) => self.ress[idx].data = data.to_vec(),
+			None => self.ress.push(ResEntry {
+				rt: resource_type,
+				name: resource_id,
+				lang: language,
+				code_page: 0,
+				data: data.to_vec(),
+			}),
+		}
+	}
+
+	fn find(&self, rt: &RtStr, name: &IdStr, lang: LANGID) -> Option<usize> {
+		self.ress.iter()
+			.position(|res| ResKey::from_rt(&res.rt) == ResKey::from_rt(rt)
+				&& ResKey::from_id(&res.name) == ResKey::from_id(name)
+				&& res.lang == lang)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+const DIR_SECURITY: usize = 4;
+const DIR_DEBUG: usize = 6;
+const DIR_BOUND_IMPORT: usize = 11;
+const DEBUG_DIR_SIZE: usize = 28;
+const RES_DIR_SIZE: usize = 16;
+const RES_DIR_ENTRY_SIZE: usize = 8;
+const RES_DATA_ENTRY_SIZE: usize = 16;
+
+/// A resource being edited.
+struct ResEntry {
+	rt: RtStr,
+	name: IdStr,
+	lang: LANGID,
+	code_page: u32,
+	data: Vec<u8>,
+}
+
+/// Identifier of a resource directory entry. Strings are stored uppercase, so
+/// they are compared case-insensitively, and they come before the numbers,
+/// which is the order required in the directory.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum ResKey {
+	Str(Vec<u16>),
+	Id(u16),
+}
+
+impl ResKey {
+	fn from_id(id: &IdStr) -> Self {
+		match id {
+			IdStr::Id(id) => Self::Id(*id),
+			IdStr::Str(s) => Self::Str(s.to_string().to_uppercase().encode_utf16().collect()),
+		}
+	}
+
+	fn from_rt(rt: &RtStr) -> Self {
+		match rt {
+			RtStr::Rt(rt) => Self::Id(rt.raw()),
+			RtStr::Str(s) => Self::Str(s.to_string().to_uppercase().encode_utf16().collect()),
+		}
+	}
+}
+
+/// Builds the resource directory tree and the resource data, to be placed at
+/// the given relative virtual address.
+fn build_tree(ress: &[ResEntry], base_rva: u32) -> SysResult<Vec<u8>> {
+	let mut tree = BTreeMap::<ResKey, BTreeMap<ResKey, BTreeMap<u16, &ResEntry>>>::new();
+	for res in ress.iter() {
+		tree.entry(ResKey::from_rt(&res.rt))
+			.or_default()
+			.entry(ResKey::from_id(&res.name))
+			.or_default()
+			.insert(res.lang.raw(), res);
+	}
+
+	// Layout: all directories, data entries, strings, then the data itself.
+	let dir_size = |count: usize| RES_DIR_SIZE + count * RES_DIR_ENTRY_SIZE;
+	let names_dirs_pos = dir_size(tree.len());
+	let langs_dirs_pos = names_dirs_pos + tree.values()
+		.map(|names| dir_size(names.len()))
+		.sum::<usize>();
+	let data_entries_pos = langs_dirs_pos + tree.values()
+		.flat_map(|names| names.values())
+		.map(|langs| dir_size(langs.len()))
+		.sum::<usize>();
+	let num_ress = tree.values()
+		.flat_map(|names| names.values())
+		.map(|langs| langs.len())
+		.sum::<usize>();
+	let strs_pos = data_entries_pos + num_ress * RES_DATA_ENTRY_SIZE;
+
+	let mut buf = vec![0u8; strs_pos];
+	let mut str_offs = BTreeMap::<Vec<u16>, usize>::new();
+	let mut add_str = |buf: &mut Vec<u8>, key: &ResKey| -> u32 {
+		match key {
+			ResKey::Id(id) => *id as _,
+			ResKey::Str(chars) => {
+				let off = *str_offs.entry(chars.clone()).or_insert_with(|| {
+					let off = buf.len();
+					buf.extend_from_slice(&(chars.len() as u16).to_le_bytes());
+					buf.extend(chars.iter().flat_map(|ch| ch.to_le_bytes()));
+					off
+				});
+				off as u32 | RES_SUBDIR
+			},
+		}
+	};
+
+	let mut name_dir = names_dirs_pos;
+	let mut lang_dir = langs_dirs_pos;
+	let mut data_entry = data_entries_pos;
+	let mut data_entries = Vec::<(usize, &ResEntry)>::with_capacity(num_ress);
+
+	write_dir_header(&mut buf, 0, &tree.keys().collect::<Vec<_>>());
+	for (type_idx, (type_key, names)) in tree.iter().enumerate() {
+		let entry = RES_DIR_SIZE + type_idx * RES_DIR_ENTRY_SIZE;
+		let id = add_str(&mut buf, type_key);
+		set_u32(&mut buf, entry, id);
+		set_u32(&mut buf, entry + 4, name_dir as u32 | RES_SUBDIR);
+
+		write_dir_header(&mut buf, name_dir, &names.keys().collect::<Vec<_>>());
+		for (name_idx, (name_key, langs)) in names.iter().enumerate() {
+			let entry = name_dir + RES_DIR_SIZE + name_idx * RES_DIR_ENTRY_SIZE;
+			let id = add_str(&mut buf, name_key);
+			set_u32(&mut buf, entry, id);
+			set_u32(&mut buf, entry + 4, lang_dir as u32 | RES_SUBDIR);
+
+			set_u16(&mut buf, lang_dir + 14, langs.len() as _);
+			for (lang_idx, (lang, res)) in langs.iter().enumerate() {
+				let entry = lang_dir + RES_DIR_SIZE + lang_idx * RES_DIR_ENTRY_SIZE;
+				set_u32(&mut buf, entry, *lang as _);
+				set_u32(&mut buf, entry + 4, data_entry as _);
+				data_entries.push((data_entry, *res));
+				data_entry += RES_DATA_ENTRY_SIZE;
+			}
+			lang_dir += dir_size(langs.len());
+		}
+		name_dir += dir_size(names.len());
+	}
+
+	for (entry, res) in data_entries {
+		buf.resize(buf.len().next_multiple_of(8), 0);
+		let data_rva = u32::try_from(buf.len()).ok()
+			.and_then(|len| base_rva.checked_add(len))
+			.ok_or(co::ERROR::INVALID_DATA)?;
+		set_u32(&mut buf, entry, data_rva);
+		set_u32(&mut buf, entry + 4, res.data.len() as _);
+		set_u32(&mut buf, entry + 8, res.code_page);
+		buf.extend_from_slice(&res.data);
+	}
+	Ok(buf)
+}
+
+/// Writes the number of named and ID entries of a resource directory.
+fn write_dir_header(buf: &mut [u8], pos: usize, keys: &[&ResKey]) {
+	let num_named = keys.iter()
+		.filter(|key| matches!(key, ResKey::Str(_)))
+		.count();
+	set_u16(buf, pos + 12, num_named as _);
+	set_u16(buf, pos + 14, (keys.len() - num_named) as _);
+}
+
+/// Removes the certificate table, which is no longer valid, and updates the
+/// checksum.
+fn finish_image(buf: &mut [u8], opt_hdr: usize, dirs: usize) -> SysResult<()> {
+	let num_dirs = u32_at(buf, dirs - 4)? as usize; // NumberOfRvaAndSizes
+	if num_dirs > DIR_SECURITY {
+		set_u32(buf, dirs + DIR_SECURITY * 8, 0);
+		set_u32(buf, dirs + DIR_SECURITY * 8 + 4, 0);
+	}
+	set_u32(buf, opt_hdr + 64, 0);
+	let checksum = pe_checksum(buf, opt_hdr + 64);
+	set_u32(buf, opt_hdr + 64, checksum);
+	Ok(())
+}
+
+/// Computes the image checksum, as
+/// [`CheckSumMappedFile`](https://learn.microsoft.com/en-us/windows/win32/api/imagehlp/nf-imagehlp-checksummappedfile)
+/// does, skipping the checksum field itself.
+fn pe_checksum(buf: &[u8], checksum_pos: usize) -> u32 {
+	let mut sum = 0u64;
+	for (idx, word) in buf.chunks(2).enumerate() {
+		if idx * 2 == checksum_pos || idx * 2 == checksum_pos + 2 {
+			continue;
+		}
+		sum += u16::from_le_bytes([word[0], *word.get(1).unwrap_or(&0)]) as u64;
+		sum = (sum & 0xffff) + (sum >> 16);
+	}
+	sum = (sum & 0xffff) + (sum >> 16);
+	(sum as u32).wrapping_add(buf.len() as _)
+}
+
+fn align(val: u32, alignment: u32) -> SysResult<u32> {
+	val.div_ceil(alignment)
+		.checked_mul(alignment)
+		.ok_or(co::ERROR::INVALID_DATA)
+}
+
+fn padded(mut bytes: Vec<u8>, len: u32) -> Vec<u8> {
+	bytes.resize(len as _, 0);
+	bytes
+}
+
+fn shifted(val: u32, shift: i64) -> u32 {
+	(val as i64 + shift) as _
+}
+
+fn set_u16(buf: &mut [u8], pos: usize, val: u16) {
+	buf[pos..pos + 2].copy_from_slice(&val.to_le_bytes());
+}
+
+fn set_u32(buf: &mut [u8], pos: usize, val: u32) {
+	buf[pos..pos + 4].copy_from_slice(&val.to_le_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Builds a PE32 image with a single `.text` section.
+	fn minimal_pe(num_dirs: u32, text_va: u32) -> Vec<u8> {
+		let mut buf = vec![0u8; 0x400];
+		buf[..2].copy_from_slice(b"MZ");
+		set_u32(&mut buf, 0x3c, 0x40);
+		buf[0x40..0x44].copy_from_slice(b"PE\0\0");
+		let coff_hdr = 0x44;
+		set_u16(&mut buf, coff_hdr, 0x14c); // i386
+		set_u16(&mut buf, coff_hdr + 2, 1); // NumberOfSections
+		set_u16(&mut buf, coff_hdr + 16, 224); // SizeOfOptionalHeader
+		let opt_hdr = coff_hdr + COFF_HEADER_SIZE;
+		set_u16(&mut buf, opt_hdr, 0x10b); // PE32
+		set_u32(&mut buf, opt_hdr + 32, 0x1000); // SectionAlignment
+		set_u32(&mut buf, opt_hdr + 36, 0x200); // FileAlignment
+		set_u32(&mut buf, opt_hdr + 56, 0x2000); // SizeOfImage
+		set_u32(&mut buf, opt_hdr + 60, 0x200); // SizeOfHeaders
+		set_u32(&mut buf, opt_hdr + 92, num_dirs);
+		let sec_hdr = opt_hdr + 224;
+		buf[sec_hdr..sec_hdr + 8].copy_from_slice(b".text\0\0\0");
+		set_u32(&mut buf, sec_hdr + 8, 0x10);
+		set_u32(&mut buf, sec_hdr + 12, text_va);
+		set_u32(&mut buf, sec_hdr + 16, 0x200);
+		set_u32(&mut buf, sec_hdr + 20, 0x200);
+		buf[0x200..0x210].fill(0xcc);
+		buf
+	}
+
+	fn manifest_id() -> (RtStr, IdStr, LANGID) {
+		(
+			RtStr::Rt(co::RT::MANIFEST),
+			IdStr::Id(1),
+			LANGID::new(co::LANG::ENGLISH, co::SUBLANG::ENGLISH_US),
+		)
+	}
+
+	#[test]
+	fn round_trip() {
+		let (rt, id, lang) = manifest_id();
+		let custom_rt = RtStr::Str(WString::from_str("MyType"));
+		let custom_id = IdStr::Str(WString::from_str("Foo"));
+		let neutral = LANGID::new(co::LANG::NEUTRAL, co::SUBLANG::NEUTRAL);
+
+		let mut ed = PeResourceEditor::parse_bytes(&minimal_pe(16, 0x1000), false).unwrap();
+		ed.update_resource(rt.clone(), id.clone(), lang, b"<assembly/>");
+		ed.update_resource(custom_rt.clone(), custom_id.clone(), neutral, &[1, 2, 3]);
+		let image = ed.serialize_to_bytes().unwrap();
+
+		// A new section was appended after .text.
+		let pe = PeImage::from_bytes(&image).unwrap();
+		assert_eq!(pe.sections().len(), 2);
+		assert_eq!(pe.sections()[1].name, ".rsrc");
+		assert_eq!(pe.sections()[1].virtual_address, 0x2000);
+		assert_eq!(pe.sections()[1].raw_offset, 0x400);
+		assert_eq!(u32_at(&image, pe.opt_hdr() + 56).unwrap(), 0x3000); // SizeOfImage
+		assert_eq!(u32_at(&image, pe.opt_hdr() + 64).unwrap(),
+			pe_checksum(&image, pe.opt_hdr() + 64));
+		assert_eq!(pe.section_data(&pe.sections()[0]).unwrap()[..0x10], [0xcc; 0x10]);
+		assert_eq!(pe.find_resource(&rt, &id, Some(lang)).unwrap(), b"<assembly/>");
+		assert_eq!(pe.find_resource(&custom_rt, &custom_id, None).unwrap(), [1, 2, 3]);
+
+		// Edited again, the resources are rewritten in place.
+		let mut ed = PeResourceEditor::parse_bytes(&image, false).unwrap();
+		ed.delete_resource(&rt, &id, lang).unwrap();
+		assert_eq!(ed.delete_resource(&rt, &id, lang), Err(co::ERROR::RESOURCE_NOT_FOUND));
+		ed.update_resource(RtStr::Str(WString::from_str("MYTYPE")),
+			IdStr::Str(WString::from_str("foo")), neutral, &[4, 5]);
+		let image2 = ed.serialize_to_bytes().unwrap();
+		assert_eq!(image2.len(), image.len());
+
+		let pe = PeImage::from_bytes(&image2).unwrap();
+		assert_eq!(pe.sections().len(), 2);
+		assert_eq!(pe.find_resource(&rt, &id, None), Err(co::ERROR::RESOURCE_TYPE_NOT_FOUND));
+		assert_eq!(pe.find_resource(&custom_rt, &custom_id, None).unwrap(), [4, 5]);
+		assert_eq!(pe.resources().unwrap().len(), 1);
+
+		// Deleting everything leaves an empty tree.
+		let ed = PeResourceEditor::parse_bytes(&image2, true).unwrap();
+		let image3 = ed.serialize_to_bytes().unwrap();
+		assert!(PeImage::from_bytes(&image3).unwrap().resources().unwrap().is_empty());
+	}
+
+	#[test]
+	fn no_resource_directory() {
+		let (rt, id, lang) = manifest_id();
+		let image = minimal_pe(2, 0x1000); // no room for DIR_RESOURCE
+
+		let ed = PeResourceEditor::parse_bytes(&image, false).unwrap();
+		assert_eq!(ed.serialize_to_bytes().unwrap().len(), image.len());
+
+		let mut ed = PeResourceEditor::parse_bytes(&image, false).unwrap();
+		ed.update_resource(rt, id, lang, b"<assembly/>");
+		assert_eq!(ed.serialize_to_bytes(), Err(co::ERROR::NOT_SUPPORTED));
+	}
+
+	#[test]
+	fn address_overflow() {
+		let (rt, id, lang) = manifest_id();
+		let mut ed = PeResourceEditor::parse_bytes(&minimal_pe(16, 0xffff_f000), false).unwrap();
+		ed.update_resource(rt, id, lang, b"<assembly/>");
+		assert_eq!(ed.serialize_to_bytes(), Err(co::ERROR::INVALID_DATA));
+
+		assert_eq!(align(0xffff_fff0, 0x1000), Err(co::ERROR::INVALID_DATA));
+		assert_eq!(align(0x1001, 0x1000), Ok(0x2000));
+	}
+}
@@ -0,0 +1,461 @@
+use std::sync::Mutex;
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::{ffi, ffi_types::*, privs::*};
+use crate::prelude::*;
+
+/// Serializes the window between creating the inheritable handles and closing
+/// them, so a process being spawned by another thread won't inherit them.
+static SPAWN_LOCK: Mutex<()> = Mutex::new(());
+
+/// What a standard stream of a [`ProcessBuilder`](crate::ProcessBuilder) child
+/// process is connected to.
+pub enum ProcessStdio {
+	/// The stream of the current process. This is the default.
+	Inherit,
+	/// The `NUL` device: reads return nothing, writes are discarded.
+	Null,
+	/// An anonymous pipe, whose other end is retrieved from the
+	/// [`ChildProcess`](crate::ChildProcess).
+	Pipe,
+	/// An open file.
+	File(File),
+}
+
+/// Launches child processes with
+/// [`HPROCESS::CreateProcess`](crate::prelude::kernel_Hprocess::CreateProcess),
+/// taking care of the command line quoting and of the standard streams
+/// redirection.
+///
+/// # Examples
+///
+/// Running a program and capturing its output:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let out = w::ProcessBuilder::new("git")
+///     .args(&["log", "-1", "--format=%s"])
+///     .current_dir("C:\\Temp\\repo")
+///     .output()?;
+///
+/// println!("Exit code: {}", out.exit_code);
+/// println!("{}", String::from_utf8_lossy(&out.stdout));
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+///
+/// Writing to the standard input, and waiting up to 5 seconds:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let mut child = w::ProcessBuilder::new("sort")
+///     .stdin(w::ProcessStdio::Pipe)
+///     .stdout(w::ProcessStdio::Null)
+///     .spawn()?;
+///
+/// child.stdin().unwrap().WriteFile(b"b\r\na\r\n", None)?;
+/// child.close_stdin();
+///
+/// match child.wait(Some(5000))? {
+///     Some(exit_code) => println!("Exit code: {}", exit_code),
+///     None => child.kill()?,
+/// }
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+pub struct ProcessBuilder {
+	program: String,
+	args: Vec<String>,
+	current_dir: Option<String>,
+	env: Option<EnvironmentBlock>,
+	creation_flags: co::CREATE,
+	stdin: ProcessStdio,
+	stdout: ProcessStdio,
+	stderr: ProcessStdio,
+}
+
+impl ProcessBuilder {
+	/// Creates a new builder for the given program, which is searched in the
+	/// same way as
+	/// [`HPROCESS::CreateProcess`](crate::prelude::kernel_Hprocess::CreateProcess)
+	/// does with the command line.
+	#[must_use]
+	pub fn new(program: &str) -> Self {
+		Self {
+			program: program.to_owned(),
+			args: Vec::new(),
+			current_dir: None,
+			env: None,
+			creation_flags: co::CREATE::NoValue,
+			stdin: ProcessStdio::Inherit,
+			stdout: ProcessStdio::Inherit,
+			stderr: ProcessStdio::Inherit,
+		}
+	}
+
+	/// Appends an argument, which will be quoted as needed.
+	pub fn arg(&mut self, arg: &str) -> &mut Self {
+		self.args.push(arg.to_owned());
+		self
+	}
+
+	/// Appends arguments, which will be quoted as needed.
+	pub fn args(&mut self, args: &[impl AsRef<str>]) -> &mut Self {
+		self.args.extend(args.iter().map(|arg| arg.as_ref().to_owned()));
+		self
+	}
+
+	/// Sets additional process creation flags.
+	pub fn creation_flags(&mut self, flags: co::CREATE) -> &mut Self {
+		self.creation_flags = flags;
+		self
+	}
+
+	/// Sets the working directory of the child process. By default, it's the
+	/// same of the current process.
+	pub fn current_dir(&mut self, dir: &str) -> &mut Self {
+		self.current_dir = Some(dir.to_owned());
+		self
+	}
+
+	/// Sets the environment variables of the child process. By default, the
+	/// environment of the current process is inherited.
+	pub fn env(&mut self, env: EnvironmentBlock) -> &mut Self {
+		self.env = Some(env);
+		self
+	}
+
+	/// Sets the standard error stream.
+	pub fn stderr(&mut self, stdio: ProcessStdio) -> &mut Self {
+		self.stderr = stdio;
+		self
+	}
+
+	/// Sets the standard input stream.
+	pub fn stdin(&mut self, stdio: ProcessStdio) -> &mut Self {
+		self.stdin = stdio;
+		self
+	}
+
+	/// Sets the standard output stream.
+	pub fn stdout(&mut self, stdio: ProcessStdio) -> &mut Self {
+		self.stdout = stdio;
+		self
+	}
+
+	/// Returns the command line which will be passed to the child process,
+	/// built with [`cmd_line::join`](crate::cmd_line::join).
+	pub fn command_line(&self) -> SysResult<String> {
+		let mut argv = Vec::with_capacity(self.args.len() + 1);
+		argv.push(self.program.as_str());
+		argv.extend(self.args.iter().map(|arg| arg.as_str()));
+		cmd_line::join(&argv)
+	}
+
+	/// Runs the child process with the standard output and error streams
+	/// redirected to pipes, and the standard input redirected to `NUL`, unless
+	/// set otherwise. Then waits for the process to finish, collecting all its
+	/// output.
+	pub fn output(&self) -> SysResult<ProcessOutput> {
+		let stdin = match self.stdin {
+			ProcessStdio::Inherit => &ProcessStdio::Null,
+			_ => &self.stdin,
+		};
+		self.spawn_with([stdin, &ProcessStdio::Pipe, &ProcessStdio::Pipe])?
+			.wait_with_output()
+	}
+
+	/// Launches the child process, returning immediately.
+	pub fn spawn(&self) -> SysResult<ChildProcess> {
+		self.spawn_with([&self.stdin, &self.stdout, &self.stderr])
+	}
+
+	fn spawn_with(&self, stdios: [&ProcessStdio; 3]) -> SysResult<ChildProcess> {
+		let cmd_line = self.command_line()?;
+		let redirect = stdios.iter()
+			.any(|stdio| !matches!(stdio, ProcessStdio::Inherit));
+
+		let _lock = SPAWN_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+		let mut child_ends = Vec::<CloseHandleGuard<HPIPE>>::with_capacity(3); // closed after spawning
+		let mut parent_ends = [None, None, None];
+		let mut hinherits = [HPIPE::NULL, HPIPE::NULL, HPIPE::NULL];
+
+		if redirect {
+			let hcur = HPROCESS::GetCurrentProcess();
+			let std_handles = [co::STD_HANDLE::INPUT, co::STD_HANDLE::OUTPUT, co::STD_HANDLE::ERROR];
+
+			for (idx, stdio) in stdios.iter().enumerate() {
+				let hchild = match stdio {
+					ProcessStdio::Inherit => {
+						let hstd = HSTD::GetStdHandle(std_handles[idx])
+							.map(|mut hstd| hstd.leak()) // not ours to close
+							.unwrap_or(HSTD::NULL);
+						match hstd.as_opt() {
+							None => continue, // no console: child gets a null handle too
+							Some(hstd) => hcur.DuplicateHandle(
+								&unsafe { HPIPE::from_ptr(hstd.ptr()) },
+								&hcur, 0, true, co::DUPLICATE::SAME_ACCESS)?,
+						}
+					},
+					ProcessStdio::Null => {
+						let mut sa = SECURITY_ATTRIBUTES::default();
+						sa.set_bInheritHandle(true);
+						let (mut hnul, _) = HFILE::CreateFile(
+							"NUL",
+							co::GENERIC::READ | co::GENERIC::WRITE,
+							Some(co::FILE_SHARE::READ | co::FILE_SHARE::WRITE),
+							Some(&mut sa),
+							co::DISPOSITION::OPEN_EXISTING,
+							co::FILE_ATTRIBUTE::NORMAL,
+							None,
+							None,
+							None,
+						)?;
+						unsafe { CloseHandleGuard::new(HPIPE::from_ptr(hnul.leak().ptr())) }
+					},
+					ProcessStdio::Pipe => {
+						let (hread, hwrite) = HPIPE::CreatePipe(None, 0)?;
+						let (hchild, hparent) = if idx == 0 { (hread, hwrite) } else { (hwrite, hread) };
+						parent_ends[idx] = Some(hparent); // only the child end is inherited
+						hcur.DuplicateHandle(&*hchild, &hcur, 0, true, co::DUPLICATE::SAME_ACCESS)?
+					},
+					ProcessStdio::File(file) => hcur.DuplicateHandle(
+						&unsafe { HPIPE::from_ptr(file.hfile().ptr()) },
+						&hcur, 0, true, co::DUPLICATE::SAME_ACCESS)?,
+				};
+				hinherits[idx] = unsafe { hchild.raw_copy() };
+				child_ends.push(hchild);
+			}
+		}
+
+		let mut siex = STARTUPINFOEX {
+			StartupInfo: STARTUPINFO::default(),
+			lpAttributeList: std::ptr::null_mut(),
+		};
+		let mut creation_flags = self.creation_flags;
+		let mut handle_list = None; // must outlive CreateProcess
+
+		if redirect {
+			let si = &mut siex.StartupInfo;
+			si.dwFlags |= co::STARTF::USESTDHANDLES;
+			let [hin, hout, herr] = hinherits;
+			si.hStdInput = hin;
+			si.hStdOutput = hout;
+			si.hStdError = herr;
+
+			if !child_ends.is_empty() { // the child inherits only these handles
+				let mut attr = HandleListAttr::new(
+					child_ends.iter().map(|hchild| hchild.ptr()).collect())?;
+				siex.lpAttributeList = attr.list_ptr();
+				siex.StartupInfo.set_cb(std::mem::size_of::<STARTUPINFOEX>());
+				creation_flags |= co::CREATE::EXTENDED_STARTUPINFO_PRESENT;
+				handle_list = Some(attr);
+			}
+		}
+
+		let pi = HPROCESS::CreateProcess(
+			None,
+			Some(&cmd_line),
+			None,
+			None,
+			handle_list.is_some(),
+			creation_flags,
+			self.env.as_ref(),
+			self.current_dir.as_deref(),
+			unsafe { &mut *(&mut siex as *mut STARTUPINFOEX as *mut STARTUPINFO) }, // first field
+		)?;
+		drop(handle_list);
+		drop(child_ends); // only the child must have them open now
+
+		let [stdin, stdout, stderr] = parent_ends;
+		Ok(ChildProcess { pi, stdin, stdout, stderr })
+	}
+}
+
+/// A child process launched by [`ProcessBuilder`](crate::ProcessBuilder).
+///
+/// Dropping this object closes the handles, but doesn't terminate the process.
+pub struct ChildProcess {
+	pi: CloseHandlePiGuard,
+	stdin: Option<CloseHandleGuard<HPIPE>>,
+	stdout: Option<CloseHandleGuard<HPIPE>>,
+	stderr: Option<CloseHandleGuard<HPIPE>>,
+}
+
+impl ChildProcess {
+	/// Closes the write end of the standard input pipe, if any, so the child
+	/// process will read end-of-file.
+	pub fn close_stdin(&mut self) {
+		self.stdin = None;
+	}
+
+	/// Returns the process handle.
+	#[must_use]
+	pub fn hprocess(&self) -> &HPROCESS {
+		&self.pi.hProcess
+	}
+
+	/// Terminates the process, with exit code 1.
+	pub fn kill(&self) -> SysResult<()> {
+		self.pi.hProcess.TerminateProcess(1)
+	}
+
+	/// Returns the process ID.
+	#[must_use]
+	pub fn process_id(&self) -> u32 {
+		self.pi.dwProcessId
+	}
+
+	/// Returns the write end of the standard input pipe, if
+	/// [`ProcessStdio::Pipe`](crate::ProcessStdio::Pipe) was set.
+	#[must_use]
+	pub fn stdin(&self) -> Option<&HPIPE> {
+		self.stdin.as_deref()
+	}
+
+	/// Returns the read end of the standard output pipe, if
+	/// [`ProcessStdio::Pipe`](crate::ProcessStdio::Pipe) was set.
+	#[must_use]
+	pub fn stdout(&self) -> Option<&HPIPE> {
+		self.stdout.as_deref()
+	}
+
+	/// Returns the read end of the standard error pipe, if
+	/// [`ProcessStdio::Pipe`](crate::ProcessStdio::Pipe) was set.
+	#[must_use]
+	pub fn stderr(&self) -> Option<&HPIPE> {
+		self.stderr.as_deref()
+	}
+
+	/// Waits for the process to finish, returning its exit code, or `None` if
+	/// the timeout, in milliseconds, elapsed first.
+	///
+	/// Note that a process which writes more than the pipe buffer to an
+	/// unread [`ProcessStdio::Pipe`](crate::ProcessStdio::Pipe) will block
+	/// forever; in this case, use
+	/// [`wait_with_output`](crate::ChildProcess::wait_with_output).
+	pub fn wait(&self, milliseconds: Option<u32>) -> SysResult<Option<u32>> {
+		match self.pi.hProcess.WaitForSingleObject(milliseconds)? {
+			co::WAIT::TIMEOUT => Ok(None),
+			_ => self.pi.hProcess.GetExitCodeProcess().map(Some),
+		}
+	}
+
+	/// Closes the standard input pipe, then reads the standard output and
+	/// error pipes until the process closes them, and waits for the process to
+	/// finish.
+	///
+	/// Both pipes are read simultaneously, so the process won't block when
+	/// filling either of them.
+	pub fn wait_with_output(mut self) -> SysResult<ProcessOutput> {
+		self.close_stdin();
+
+		let stderr_thread = self.stderr.take()
+			.map(|hpipe| std::thread::spawn(move || read_to_end(&hpipe)));
+		let stdout = match self.stdout.take() {
+			Some(hpipe) => read_to_end(&hpipe)?,
+			None => Vec::new(),
+		};
+		let stderr = match stderr_thread {
+			Some(thread) => thread.join().unwrap()?,
+			None => Vec::new(),
+		};
+
+		let exit_code = self.wait(None)?.unwrap();
+		Ok(ProcessOutput { exit_code, stdout, stderr })
+	}
+}
+
+/// Result of [`ProcessBuilder::output`](crate::ProcessBuilder::output) and
+/// [`ChildProcess::wait_with_output`](crate::ChildProcess::wait_with_output).
+pub struct ProcessOutput {
+	/// Exit code of the process.
+	pub exit_code: u32,
+	/// Everything the process wrote to the standard output, if redirected to
+	/// a pipe.
+	pub stdout: Vec<u8>,
+	/// Everything the process wrote to the standard error, if redirected to a
+	/// pipe.
+	pub stderr: Vec<u8>,
+}
+
+//------------------------------------------------------------------------------
+
+const PROC_THREAD_ATTRIBUTE_HANDLE_LIST: usize = 0x0002_0002;
+
+/// [`STARTUPINFOEX`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/ns-winbase-startupinfoexw)
+/// struct, passed to `CreateProcess` along with
+/// [`co::CREATE::EXTENDED_STARTUPINFO_PRESENT`](crate::co::CREATE::EXTENDED_STARTUPINFO_PRESENT).
+#[allow(non_snake_case)]
+#[repr(C)]
+struct STARTUPINFOEX<'a, 'b> {
+	StartupInfo: STARTUPINFO<'a, 'b>,
+	lpAttributeList: PVOID,
+}
+
+/// A `PROC_THREAD_ATTRIBUTE_LIST` with a single
+/// `PROC_THREAD_ATTRIBUTE_HANDLE_LIST` attribute, which restricts the handles
+/// inherited by the child process. Deleted on drop.
+struct HandleListAttr {
+	buf: Vec<usize>, // opaque list, pointer-aligned
+	handles: Vec<HANDLE>, // referenced by the list until it's deleted
+}
+
+impl Drop for HandleListAttr {
+	fn drop(&mut self) {
+		unsafe { ffi::DeleteProcThreadAttributeList(self.list_ptr()); }
+	}
+}
+
+impl HandleListAttr {
+	/// Initializes the list with the given handles, which must be unique and
+	/// inheritable.
+	fn new(handles: Vec<HANDLE>) -> SysResult<Self> {
+		let mut sz = 0usize;
+		unsafe { // fails with ERROR_INSUFFICIENT_BUFFER, retrieving the size
+			ffi::InitializeProcThreadAttributeList(std::ptr::null_mut(), 1, 0, &mut sz);
+		}
+		let mut buf = vec![0usize; sz.div_ceil(std::mem::size_of::<usize>())];
+		bool_to_sysresult(
+			unsafe {
+				ffi::InitializeProcThreadAttributeList(buf.as_mut_ptr() as _, 1, 0, &mut sz)
+			},
+		)?;
+
+		let mut attr = Self { buf, handles }; // from now on, deleted on drop
+		bool_to_sysresult(
+			unsafe {
+				ffi::UpdateProcThreadAttribute(
+					attr.list_ptr(),
+					0,
+					PROC_THREAD_ATTRIBUTE_HANDLE_LIST,
+					attr.handles.as_ptr() as _,
+					attr.handles.len() * std::mem::size_of::<HANDLE>(),
+					std::ptr::null_mut(),
+					std::ptr::null_mut(),
+				)
+			},
+		)?;
+		Ok(attr)
+	}
+
+	fn list_ptr(&mut self) -> PVOID {
+		self.buf.as_mut_ptr() as _
+	}
+}
+
+/// Reads the pipe until the other end is closed.
+fn read_to_end(hpipe: &HPIPE) -> SysResult<Vec<u8>> {
+	let mut data = Vec::<u8>::new();
+	let mut buf = [0u8; 4096];
+	loop {
+		match hpipe.ReadFile(&mut buf, None) {
+			Ok(0) | Err(co::ERROR::BROKEN_PIPE) => break,
+			Ok(num_read) => data.extend_from_slice(&buf[..num_read as usize]),
+			Err(e) => return Err(e),
+		}
+	}
+	Ok(data)
+}
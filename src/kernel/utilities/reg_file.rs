@@ -0,0 +1,592 @@
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+/// High-level abstraction to load, manage and serialize the contents of a
+/// `.reg` registry export file, as written by the Registry Editor with the
+/// `Windows Registry Editor Version 5.00` header.
+///
+/// Parsing and serializing are pure text operations, which don't touch the
+/// registry; only [`RegFile::apply`](crate::RegFile::apply) does.
+///
+/// # Examples
+///
+/// Printing all keys and values:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let reg = w::RegFile::parse_from_file("C:\\Temp\\foo.reg")?;
+///
+/// for key in reg.keys.iter() {
+///     println!("Key: {}", key.path);
+///     for val in key.values.iter() {
+///         println!("Value: {:?}", val.name);
+///     }
+/// }
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+///
+/// Creating a file from scratch:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let mut key = w::RegFileKey::new("HKEY_CURRENT_USER\\Software\\My Company");
+/// key.values.push(w::RegFileValue::new(
+///     Some("Color"),
+///     Some(w::RegistryValue::Sz("blue".to_owned())),
+/// ));
+///
+/// let reg = w::RegFile { keys: vec![key] };
+/// reg.serialize_to_file("C:\\Temp\\foo.reg")?;
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RegFile {
+	/// All the keys of the file, in order. They can be modified at will.
+	pub keys: Vec<RegFileKey>,
+}
+
+impl RegFile {
+	/// The header of the `.reg` files handled by `RegFile`.
+	pub const HEADER: &'static str = "Windows Registry Editor Version 5.00";
+
+	/// Parses a `RegFile` from a string.
+	///
+	/// Fails with [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA)
+	/// if the header is missing, or if any line is malformed.
+	#[must_use]
+	pub fn parse_str(contents: &str) -> SysResult<Self> {
+		let mut lines = contents.lines()
+			.map(|line| line.trim())
+			.filter(|line| !line.is_empty() && !line.starts_with(';'));
+
+		if lines.next() != Some(Self::HEADER) {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+
+		let mut keys = Vec::<RegFileKey>::default();
+		while let Some(line) = lines.next() {
+			if let Some(path) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+				keys.push(match path.strip_prefix('-') {
+					Some(path) => RegFileKey {
+						path: path.to_owned(),
+						deleted: true,
+						values: Vec::<RegFileValue>::default(),
+					},
+					None => RegFileKey::new(path),
+				});
+				continue;
+			}
+
+			let mut line = line.to_owned();
+			while line.ends_with('\\') { // value continues on the next line
+				line.pop();
+				line.push_str(lines.next().ok_or(co::ERROR::INVALID_DATA)?);
+			}
+
+			let key = keys.last_mut().ok_or(co::ERROR::INVALID_DATA)?; // value before any key
+			key.values.push(RegFileValue::parse(&line)?);
+		}
+
+		Ok(Self { keys })
+	}
+
+	/// Parses a `RegFile` from raw bytes with
+	/// [`WString::parse`](crate::WString::parse).
+	#[must_use]
+	pub fn parse_bytes(bytes: &[u8]) -> SysResult<Self> {
+		Self::parse_str(&WString::parse(bytes)?.to_string())
+	}
+
+	/// Parses a `RegFile` directly from a file with
+	/// [`WString::parse`](crate::WString::parse). The file will be
+	/// [mapped in memory](crate::FileMapped) during reading for maximum
+	/// performance.
+	#[must_use]
+	pub fn parse_from_file(reg_path: &str) -> SysResult<Self> {
+		let fin = FileMapped::open(reg_path, FileAccess::ExistingReadOnly)?;
+		Self::parse_bytes(fin.as_slice())
+	}
+
+	/// Returns a reference to the [`RegFileKey`](crate::RegFileKey) with the
+	/// given path, if any.
+	///
+	/// The search is case-insensitive.
+	#[must_use]
+	pub fn find_key(&self, path: &str) -> Option<&RegFileKey> {
+		let path_uc = path.to_uppercase();
+		self.keys.iter()
+			.find(|key| key.path.to_uppercase() == path_uc)
+	}
+
+	/// Returns a mutable reference to the [`RegFileKey`](crate::RegFileKey)
+	/// with the given path, if any.
+	///
+	/// The search is case-insensitive.
+	#[must_use]
+	pub fn find_key_mut(&mut self, path: &str) -> Option<&mut RegFileKey> {
+		let path_uc = path.to_uppercase();
+		self.keys.iter_mut()
+			.find(|key| key.path.to_uppercase() == path_uc)
+	}
+
+	/// Serializes the keys and values to a string, in the same format of the
+	/// Registry Editor. Values other than strings, `u32` and binary are written
+	/// as their raw bytes, like `hex(7):`.
+	#[must_use]
+	pub fn serialize_to_str(&self) -> String {
+		let mut buf = String::from(Self::HEADER);
+		buf.push_str("\r\n\r\n");
+
+		for key in self.keys.iter() {
+			buf.push('[');
+			if key.deleted {
+				buf.push('-');
+			}
+			buf.push_str(&key.path);
+			buf.push_str("]\r\n");
+
+			if !key.deleted {
+				for val in key.values.iter() {
+					val.serialize(&mut buf);
+					buf.push_str("\r\n");
+				}
+			}
+			buf.push_str("\r\n");
+		}
+
+		buf
+	}
+
+	/// Serializes the keys and values to raw bytes, encoded as UTF-16 little
+	/// endian with BOM, which is the encoding used by the Registry Editor.
+	#[must_use]
+	pub fn serialize_to_bytes(&self) -> Vec<u8> {
+		[0xfeff].into_iter()
+			.chain(self.serialize_to_str().encode_utf16())
+			.flat_map(|ch| ch.to_le_bytes())
+			.collect()
+	}
+
+	/// Serializes the data directly to a file with
+	/// [`RegFile::serialize_to_bytes`](crate::RegFile::serialize_to_bytes).
+	pub fn serialize_to_file(&self, reg_path: &str) -> SysResult<()> {
+		let fout = File::open(reg_path, FileAccess::OpenOrCreateRW)?;
+		fout.erase_and_write(&self.serialize_to_bytes())?;
+		Ok(())
+	}
+
+	/// Applies all keys and values to the registry, in order, like the
+	/// Registry Editor does when importing a `.reg` file.
+	///
+	/// Deleting keys and values which don't exist is not an error.
+	///
+	/// Fails with
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if a key doesn't start with a known root, like `HKEY_CURRENT_USER`.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let reg = w::RegFile::parse_from_file("C:\\Temp\\foo.reg")?;
+	/// reg.apply()?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	pub fn apply(&self) -> SysResult<()> {
+		for key in self.keys.iter() {
+			let (root, sub_key) = key.split_root().ok_or(co::ERROR::INVALID_PARAMETER)?;
+
+			if key.deleted {
+				match root.RegDeleteTree(Some(sub_key)) {
+					Ok(_) | Err(co::ERROR::FILE_NOT_FOUND) => {},
+					Err(e) => return Err(e),
+				}
+				continue;
+			}
+
+			let (hkey, _) = root.RegCreateKeyEx(sub_key, None,
+				co::REG_OPTION::NON_VOLATILE, co::KEY::WRITE | co::KEY::QUERY_VALUE, None)?;
+
+			for val in key.values.iter() {
+				match &val.data {
+					Some(data) => hkey.RegSetValueEx(val.name.as_deref(), data.clone())?,
+					None => match hkey.RegDeleteValue(val.name.as_deref()) {
+						Ok(_) | Err(co::ERROR::FILE_NOT_FOUND) => {},
+						Err(e) => return Err(e),
+					},
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A single key of a [`RegFile`](crate::RegFile).
+#[derive(Clone, Debug)]
+pub struct RegFileKey {
+	/// Full path of the key, starting with the root, like
+	/// `HKEY_CURRENT_USER\Software`.
+	pub path: String,
+	/// If true, the key and all its subkeys are deleted, and the values are
+	/// ignored. Written as `[-path]`.
+	pub deleted: bool,
+	/// All the values of this key. They can be modified at will.
+	pub values: Vec<RegFileValue>,
+}
+
+impl RegFileKey {
+	/// Creates a new key, without values.
+	#[must_use]
+	pub fn new(path: &str) -> Self {
+		Self {
+			path: path.to_owned(),
+			deleted: false,
+			values: Vec::<RegFileValue>::default(),
+		}
+	}
+
+	/// Returns a reference to the [`RegFileValue`](crate::RegFileValue) with
+	/// the given name, if any. `None` is the default value, written as `@`.
+	///
+	/// The search is case-insensitive.
+	#[must_use]
+	pub fn find_value(&self, name: Option<&str>) -> Option<&RegFileValue> {
+		let name_uc = name.map(|name| name.to_uppercase());
+		self.values.iter()
+			.find(|val| val.name.as_ref().map(|name| name.to_uppercase()) == name_uc)
+	}
+
+	/// Returns the predefined root key, and the remaining path.
+	fn split_root(&self) -> Option<(HKEY, &str)> {
+		let (root, sub_key) = self.path.split_once('\\').unwrap_or((&self.path, ""));
+		let root = match root.to_uppercase().as_str() {
+			"HKEY_CLASSES_ROOT" | "HKCR" => HKEY::CLASSES_ROOT,
+			"HKEY_CURRENT_USER" | "HKCU" => HKEY::CURRENT_USER,
+			"HKEY_LOCAL_MACHINE" | "HKLM" => HKEY::LOCAL_MACHINE,
+			"HKEY_USERS" | "HKU" => HKEY::USERS,
+			"HKEY_CURRENT_CONFIG" | "HKCC" => HKEY::CURRENT_CONFIG,
+			_ => return None,
+		};
+		Some((root, sub_key))
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A single value of a [`RegFileKey`](crate::RegFileKey).
+#[derive(Clone, Debug)]
+pub struct RegFileValue {
+	/// Name of the value. `None` is the default value, written as `@`.
+	pub name: Option<String>,
+	/// Data of the value. `None` means the value is deleted, written as `-`.
+	pub data: Option<RegistryValue>,
+}
+
+impl RegFileValue {
+	/// Creates a new value.
+	#[must_use]
+	pub fn new(name: Option<&str>, data: Option<RegistryValue>) -> Self {
+		Self {
+			name: name.map(|name| name.to_owned()),
+			data,
+		}
+	}
+
+	/// Parses a whole value line, with continuations already joined.
+	fn parse(line: &str) -> SysResult<Self> {
+		let (name, rest) = match line.strip_prefix('@') {
+			Some(rest) => (None, rest),
+			None => {
+				let (name, rest) = parse_quoted(line)?;
+				(Some(name), rest)
+			},
+		};
+		let rest = rest.trim_start()
+			.strip_prefix('=')
+			.ok_or(co::ERROR::INVALID_DATA)?
+			.trim();
+
+		let data = if rest == "-" {
+			None
+		} else if rest.starts_with('"') {
+			let (text, after) = parse_quoted(rest)?;
+			if !after.trim().is_empty() {
+				return Err(co::ERROR::INVALID_DATA);
+			}
+			Some(RegistryValue::Sz(text))
+		} else if let Some(hex) = rest.strip_prefix("dword:") {
+			if hex.is_empty() || hex.len() > 8 {
+				return Err(co::ERROR::INVALID_DATA);
+			}
+			Some(RegistryValue::Dword(
+				u32::from_str_radix(hex, 16).map_err(|_| co::ERROR::INVALID_DATA)?,
+			))
+		} else if let Some(bytes) = rest.strip_prefix("hex:") {
+			Some(RegistryValue::Binary(parse_hex_bytes(bytes)?))
+		} else if let Some(rest) = rest.strip_prefix("hex(") {
+			let (reg_type, bytes) = rest.split_once("):").ok_or(co::ERROR::INVALID_DATA)?;
+			let reg_type = u32::from_str_radix(reg_type, 16).map_err(|_| co::ERROR::INVALID_DATA)?;
+			Some(RegistryValue::from_bytes(
+				&parse_hex_bytes(bytes)?,
+				unsafe { co::REG::from_raw(reg_type) },
+			))
+		} else {
+			return Err(co::ERROR::INVALID_DATA);
+		};
+
+		Ok(Self { name, data })
+	}
+
+	/// Appends the value line, without the line break.
+	fn serialize(&self, buf: &mut String) {
+		match &self.name {
+			Some(name) => push_quoted(buf, name),
+			None => buf.push('@'),
+		}
+		buf.push('=');
+
+		let data = match &self.data {
+			Some(data) => data,
+			None => {
+				buf.push('-');
+				return;
+			},
+		};
+
+		match data {
+			RegistryValue::Sz(s) if !s.contains(['\r', '\n', '\0']) => push_quoted(buf, s),
+			RegistryValue::Dword(n) => buf.push_str(&format!("dword:{:08x}", n)),
+			RegistryValue::Binary(b) => push_hex(buf, "hex:", b),
+			other => push_hex( // can't be written in a friendlier way
+				buf,
+				&format!("hex({:x}):", other.reg_type().raw()),
+				&other.to_bytes(),
+			),
+		}
+	}
+}
+
+/// Parses a string between double quotes, with `\\` and `\"` escapes,
+/// returning it along with the rest of the line.
+fn parse_quoted(line: &str) -> SysResult<(String, &str)> {
+	let mut chars = line.strip_prefix('"')
+		.ok_or(co::ERROR::INVALID_DATA)?
+		.char_indices();
+	let mut buf = String::default();
+
+	while let Some((_, ch)) = chars.next() {
+		match ch {
+			'"' => return Ok((buf, chars.as_str())),
+			'\\' => match chars.next() {
+				Some((_, esc @ ('\\' | '"'))) => buf.push(esc),
+				Some((_, other)) => { // unknown escape, kept verbatim
+					buf.push('\\');
+					buf.push(other);
+				},
+				None => break,
+			},
+			ch => buf.push(ch),
+		}
+	}
+	Err(co::ERROR::INVALID_DATA) // unterminated string
+}
+
+fn push_quoted(buf: &mut String, s: &str) {
+	buf.push('"');
+	for ch in s.chars() {
+		if ch == '\\' || ch == '"' {
+			buf.push('\\');
+		}
+		buf.push(ch);
+	}
+	buf.push('"');
+}
+
+/// Parses comma-separated hex bytes, like `0a,ff,00`.
+fn parse_hex_bytes(s: &str) -> SysResult<Vec<u8>> {
+	let s = s.trim();
+	if s.is_empty() {
+		return Ok(Vec::default());
+	}
+	s.split(',')
+		.map(|byte| {
+			let byte = byte.trim();
+			if byte.is_empty() || byte.len() > 2 {
+				Err(co::ERROR::INVALID_DATA)
+			} else {
+				u8::from_str_radix(byte, 16).map_err(|_| co::ERROR::INVALID_DATA)
+			}
+		})
+		.collect()
+}
+
+/// Appends the prefix and the hex bytes, wrapping the lines at 80 columns like
+/// the Registry Editor.
+fn push_hex(buf: &mut String, prefix: &str, bytes: &[u8]) {
+	let line_start = buf.rfind('\n').map_or(0, |idx| idx + 1);
+	let mut col = buf.len() - line_start + prefix.len();
+	buf.push_str(prefix);
+
+	for (idx, byte) in bytes.iter().enumerate() {
+		buf.push_str(&format!("{:02x}", byte));
+		col += 2;
+		if idx + 1 < bytes.len() {
+			buf.push(',');
+			col += 1;
+			if col > 76 {
+				buf.push_str("\\\r\n  ");
+				col = 2;
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// As exported by the Registry Editor.
+	const EXPORT: &str = "Windows Registry Editor Version 5.00\r\n\
+		\r\n\
+		[HKEY_CURRENT_USER\\Software\\Foo]\r\n\
+		@=\"default\"\r\n\
+		\"Path\"=\"C:\\\\Program Files\\\\Foo\"\r\n\
+		\"Say \\\"hi\\\"\"=\"\\\"hi\\\"\"\r\n\
+		\"Count\"=dword:0000002a\r\n\
+		\"Data\"=hex:01,02,ff\r\n\
+		\"Expand\"=hex(2):25,00,41,00,25,00,00,00\r\n\
+		\"Multi\"=hex(7):61,00,00,00,62,00,00,00,00,00\r\n\
+		\"Big\"=hex(b):2a,00,00,00,00,00,00,00\r\n\
+		\"Old\"=-\r\n\
+		\r\n\
+		[-HKEY_CURRENT_USER\\Software\\Bar]\r\n\
+		\r\n";
+
+	#[test]
+	fn parse_export() {
+		let reg = RegFile::parse_str(EXPORT).unwrap();
+		assert_eq!(reg.keys.len(), 2);
+
+		let foo = &reg.keys[0];
+		assert_eq!(foo.path, "HKEY_CURRENT_USER\\Software\\Foo");
+		assert!(!foo.deleted);
+		let data = |name: Option<&str>| foo.find_value(name).unwrap().data.clone();
+		assert_eq!(data(None), Some(RegistryValue::Sz("default".to_owned())));
+		assert_eq!(data(Some("path")), Some(RegistryValue::Sz("C:\\Program Files\\Foo".to_owned())));
+		assert_eq!(data(Some("Say \"hi\"")), Some(RegistryValue::Sz("\"hi\"".to_owned())));
+		assert_eq!(data(Some("Count")), Some(RegistryValue::Dword(42)));
+		assert_eq!(data(Some("Data")), Some(RegistryValue::Binary(vec![0x01, 0x02, 0xff])));
+		assert_eq!(data(Some("Expand")), Some(RegistryValue::ExpandSz("%A%".to_owned())));
+		assert_eq!(data(Some("Multi")), Some(RegistryValue::MultiSz(vec!["a".to_owned(), "b".to_owned()])));
+		assert_eq!(data(Some("Big")), Some(RegistryValue::Qword(42)));
+		assert_eq!(data(Some("Old")), None); // deleted value
+		assert!(foo.find_value(Some("Missing")).is_none());
+
+		let bar = reg.find_key("hkey_current_user\\software\\bar").unwrap();
+		assert!(bar.deleted);
+		assert!(bar.values.is_empty());
+
+		assert_eq!(reg.serialize_to_str(), EXPORT);
+	}
+
+	#[test]
+	fn continuations() {
+		let reg = RegFile::parse_str("Windows Registry Editor Version 5.00\n\
+			; comment\n\
+			[HKEY_LOCAL_MACHINE\\Foo]\n\
+			\"Multi\"=hex(7):61,00,\\\n  \
+			  62,00,00,00,\\\n\
+			00,00\n\
+			\"Odd\"=hex(2):\\\n  \
+			  41,00,00,00\n\
+			\"Escapes\"=\"a\\nb\\\\\"\n").unwrap();
+		let key = &reg.keys[0];
+		assert_eq!(key.values[0].data, Some(RegistryValue::MultiSz(vec!["ab".to_owned()])));
+		assert_eq!(key.values[1].data, Some(RegistryValue::ExpandSz("A".to_owned())));
+		assert_eq!(key.values[2].data, Some(RegistryValue::Sz("a\\nb\\".to_owned()))); // unknown escape kept
+
+		let mut key = RegFileKey::new("HKEY_CURRENT_USER\\Long");
+		key.values.push(RegFileValue::new(Some("Data"), Some(RegistryValue::Binary((0..=99).collect()))));
+		key.values.push(RegFileValue::new(Some("Lines"), Some(RegistryValue::Sz("a\r\nb".to_owned()))));
+		let reg = RegFile { keys: vec![key] };
+		let text = reg.serialize_to_str();
+		assert!(text.lines().all(|line| line.len() <= 80));
+		assert!(text.contains("\"Data\"=hex:00,01,02,03,04,05,06,07,08,09,0a,0b,0c,0d,0e,0f,10,11,12,13,14,15,\\\r\n  16,"));
+		assert!(text.contains("\"Lines\"=hex(1):61,00,0d,00,0a,00,62,00,00,00\r\n"));
+
+		let parsed = RegFile::parse_str(&text).unwrap();
+		assert_eq!(parsed.keys[0].values[0].data, reg.keys[0].values[0].data);
+		assert_eq!(parsed.keys[0].values[1].data, reg.keys[0].values[1].data);
+	}
+
+	#[test]
+	fn serialize() {
+		let mut key = RegFileKey::new("HKEY_CURRENT_USER\\Software\\Baz");
+		key.values.push(RegFileValue::new(None, Some(RegistryValue::Dword(0xffff_ffff))));
+		key.values.push(RegFileValue::new(Some("a\\\"b"), Some(RegistryValue::Sz("".to_owned()))));
+		key.values.push(RegFileValue::new(Some("Gone"), None));
+		key.values.push(RegFileValue::new(Some("Empty"), Some(RegistryValue::Binary(Vec::default()))));
+		key.values.push(RegFileValue::new(Some("None"), Some(RegistryValue::None)));
+		let mut deleted = RegFileKey::new("HKEY_CURRENT_USER\\Software\\Qux");
+		deleted.deleted = true;
+		deleted.values.push(RegFileValue::new(Some("Ignored"), None));
+
+		let reg = RegFile { keys: vec![key, deleted] };
+		let text = "Windows Registry Editor Version 5.00\r\n\
+			\r\n\
+			[HKEY_CURRENT_USER\\Software\\Baz]\r\n\
+			@=dword:ffffffff\r\n\
+			\"a\\\\\\\"b\"=\"\"\r\n\
+			\"Gone\"=-\r\n\
+			\"Empty\"=hex:\r\n\
+			\"None\"=hex(0):\r\n\
+			\r\n\
+			[-HKEY_CURRENT_USER\\Software\\Qux]\r\n\
+			\r\n";
+		assert_eq!(reg.serialize_to_str(), text);
+
+		let bytes = reg.serialize_to_bytes();
+		assert_eq!(bytes[..4], [0xff, 0xfe, b'W', 0x00]);
+		let chars = bytes[2..].chunks_exact(2)
+			.map(|ch2| u16::from_le_bytes([ch2[0], ch2[1]]))
+			.collect::<Vec<_>>();
+		assert_eq!(String::from_utf16(&chars).unwrap(), text);
+
+		let parsed = RegFile::parse_str(text).unwrap();
+		assert_eq!(parsed.keys[0].values[1].name.as_deref(), Some("a\\\"b"));
+		assert_eq!(parsed.keys[0].values[4].data, Some(RegistryValue::None));
+	}
+
+	#[test]
+	fn malformed() {
+		let parse_err = |body: &str| {
+			RegFile::parse_str(&format!("{}\r\n[HKEY_CURRENT_USER\\Foo]\r\n{}", RegFile::HEADER, body)).err()
+		};
+
+		assert_eq!(RegFile::parse_str("").err(), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(RegFile::parse_str("REGEDIT4\r\n").err(), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(RegFile::parse_str(&format!("{}\r\n\"A\"=\"b\"\r\n", RegFile::HEADER)).err(),
+			Some(co::ERROR::INVALID_DATA)); // value before any key
+
+		assert!(parse_err("").is_none());
+		assert_eq!(parse_err("\"A\"=\"unterminated"), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(parse_err("\"A\"=\"b\" trailing"), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(parse_err("\"A\"\"b\""), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(parse_err("A=\"b\""), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(parse_err("\"A\"=dword:"), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(parse_err("\"A\"=dword:123456789"), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(parse_err("\"A\"=dword:xyz"), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(parse_err("\"A\"=hex:1ff"), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(parse_err("\"A\"=hex:01,,02"), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(parse_err("\"A\"=hex(7:00"), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(parse_err("\"A\"=hex(zz):00"), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(parse_err("\"A\"=hex:01,\\"), Some(co::ERROR::INVALID_DATA)); // continuation at the end
+		assert_eq!(parse_err("\"A\"=qword:1"), Some(co::ERROR::INVALID_DATA));
+	}
+}
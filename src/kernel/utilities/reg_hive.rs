@@ -0,0 +1,556 @@
+use crate::co;
+use crate::decl::*;
+use crate::kernel::utilities::le_bytes::{u16_at, u32_at};
+
+/// Read-only parser of registry hive files, in the
+/// [regf format](https://github.com/msuhanov/regf/blob/master/Windows%20registry%20file%20format%20specification.md),
+/// as written by
+/// [`HKEY::RegSaveKey`](crate::prelude::kernel_Hkey::RegSaveKey).
+///
+/// The parsing is made entirely in Rust, so no registry access or privileges
+/// are needed. Transaction logs are not replayed, so the data of a hive which
+/// wasn't properly flushed may be stale.
+///
+/// # Examples
+///
+/// Printing the subkeys and values of a key:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let hive = w::RegHive::read_from("C:\\Temp\\NTUSER.DAT")?;
+/// let key = hive.root_key()?.open_key("Control Panel\\Desktop")?;
+///
+/// for key_name in key.enum_keys()? {
+///     println!("Key: {}", key_name?);
+/// }
+///
+/// for value_and_type in key.enum_values()? {
+///     let (value_name, reg_type) = value_and_type?;
+///     println!("Value: {} ({}) = {}",
+///         value_name, reg_type, key.query_value(Some(&value_name))?);
+/// }
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+pub struct RegHive {
+	data: Vec<u8>,
+}
+
+impl RegHive {
+	/// Loads a hive from its raw bytes.
+	///
+	/// Fails with [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA)
+	/// if the base block is not valid.
+	#[must_use]
+	pub fn from_bytes(data: Vec<u8>) -> SysResult<Self> {
+		if data.len() < BASE_BLOCK_SIZE || &data[..4] != b"regf" {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		let new_self = Self { data };
+		new_self.root_key()?; // validate the root cell
+		Ok(new_self)
+	}
+
+	/// Loads a hive from a file.
+	#[must_use]
+	pub fn read_from(hive_path: &str) -> SysResult<Self> {
+		let fin = File::open(hive_path, FileAccess::ExistingReadOnly)?;
+		Self::from_bytes(fin.read_all()?)
+	}
+
+	/// Returns the last time the hive was written, as stored in the base block.
+	#[must_use]
+	pub fn last_write_time(&self) -> FILETIME {
+		filetime_at(&self.data, 12).unwrap_or_default()
+	}
+
+	/// Returns the root key of the hive, which is the starting point to open
+	/// the other keys.
+	#[must_use]
+	pub fn root_key(&self) -> SysResult<RegHiveKey<'_>> {
+		RegHiveKey::new(self, u32_at(&self.data, 36)?)
+	}
+
+	/// Returns the data of the cell at the given offset, which is relative to
+	/// the first hive bin.
+	fn cell(&self, offset: u32) -> SysResult<&[u8]> {
+		let pos = BASE_BLOCK_SIZE.checked_add(offset as _).ok_or(co::ERROR::INVALID_DATA)?;
+		let size = (u32_at(&self.data, pos)? as i32).unsigned_abs() as usize; // allocated cells have negative sizes
+		if size < 4 {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		self.data.get(pos + 4..pos + size).ok_or(co::ERROR::INVALID_DATA)
+	}
+
+	/// Returns the minor version of the format.
+	fn minor_version(&self) -> u32 {
+		u32_at(&self.data, 24).unwrap_or_default()
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A key of a [`RegHive`](crate::RegHive).
+///
+/// Its methods are modeled after the native registry functions used with
+/// [`HKEY`](crate::HKEY).
+#[derive(Clone, Copy)]
+pub struct RegHiveKey<'a> {
+	hive: &'a RegHive,
+	cell: &'a [u8],
+}
+
+impl<'a> RegHiveKey<'a> {
+	fn new(hive: &'a RegHive, offset: u32) -> SysResult<Self> {
+		let cell = hive.cell(offset)?;
+		if cell.len() < NK_NAME || &cell[..2] != b"nk" {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		Ok(Self { hive, cell })
+	}
+
+	/// Returns the name of this key. The root key usually has a name assigned
+	/// when the hive was saved.
+	#[must_use]
+	pub fn name(&self) -> SysResult<String> {
+		let len = u16_at(self.cell, 72)? as usize;
+		let raw_name = self.cell.get(NK_NAME..NK_NAME + len).ok_or(co::ERROR::INVALID_DATA)?;
+		decode_name(raw_name, u16_at(self.cell, 2)? & NK_COMP_NAME != 0)
+	}
+
+	/// Returns the class name of this key, if any, like the `class` parameter
+	/// of [`HKEY::RegQueryInfoKey`](crate::prelude::kernel_Hkey::RegQueryInfoKey).
+	#[must_use]
+	pub fn class(&self) -> SysResult<Option<String>> {
+		let offset = u32_at(self.cell, 48)?;
+		let len = u16_at(self.cell, 74)? as usize;
+		if offset == NO_CELL || len == 0 {
+			return Ok(None);
+		}
+		let raw_class = self.hive.cell(offset)?
+			.get(..len)
+			.ok_or(co::ERROR::INVALID_DATA)?;
+		decode_name(raw_class, false).map(Some)
+	}
+
+	/// Returns the last time this key was written, like the `last_write_time`
+	/// parameter of
+	/// [`HKEY::RegQueryInfoKey`](crate::prelude::kernel_Hkey::RegQueryInfoKey).
+	#[must_use]
+	pub fn last_write_time(&self) -> FILETIME {
+		filetime_at(self.cell, 4).unwrap_or_default()
+	}
+
+	/// Returns an iterator over the names of the subkeys, like
+	/// [`HKEY::RegEnumKeyEx`](crate::prelude::kernel_Hkey::RegEnumKeyEx).
+	#[must_use]
+	pub fn enum_keys(&self,
+	) -> SysResult<Box<dyn Iterator<Item = SysResult<String>> + 'a>>
+	{
+		Ok(Box::new(
+			self.sub_keys()?
+				.map(|key| key.and_then(|key| key.name())),
+		))
+	}
+
+	/// Returns an iterator over the subkeys.
+	#[must_use]
+	pub fn sub_keys(&self,
+	) -> SysResult<Box<dyn Iterator<Item = SysResult<RegHiveKey<'a>>> + 'a>>
+	{
+		let mut offsets = Vec::<u32>::default();
+		let list_offset = u32_at(self.cell, 28)?;
+		if list_offset != NO_CELL {
+			self.collect_sub_key_offsets(list_offset, &mut offsets, 0)?;
+		}
+
+		let hive = self.hive;
+		Ok(Box::new(
+			offsets.into_iter()
+				.map(move |offset| RegHiveKey::new(hive, offset)),
+		))
+	}
+
+	/// Opens a subkey, like
+	/// [`HKEY::RegOpenKeyEx`](crate::prelude::kernel_Hkey::RegOpenKeyEx). The
+	/// path can have many levels, separated by backslashes.
+	///
+	/// The search is case-insensitive. Fails with
+	/// [`co::ERROR::FILE_NOT_FOUND`](crate::co::ERROR::FILE_NOT_FOUND) if the
+	/// key doesn't exist.
+	#[must_use]
+	pub fn open_key(&self, sub_key: &str) -> SysResult<RegHiveKey<'a>> {
+		let mut cur_key = *self;
+		for name in sub_key.split('\\').filter(|name| !name.is_empty()) {
+			let name_uc = name.to_uppercase();
+			let mut found = None;
+			for key in cur_key.sub_keys()? {
+				let key = key?;
+				if key.name()?.to_uppercase() == name_uc {
+					found = Some(key);
+					break;
+				}
+			}
+			cur_key = found.ok_or(co::ERROR::FILE_NOT_FOUND)?;
+		}
+		Ok(cur_key)
+	}
+
+	/// Returns an iterator of the names and types of the values, like
+	/// [`HKEY::RegEnumValue`](crate::prelude::kernel_Hkey::RegEnumValue). The
+	/// default value has an empty name.
+	#[must_use]
+	pub fn enum_values(&self,
+	) -> SysResult<Box<dyn Iterator<Item = SysResult<(String, co::REG)>> + 'a>>
+	{
+		let hive = self.hive;
+		Ok(Box::new(
+			self.value_offsets()?
+				.into_iter()
+				.map(move |offset| {
+					let vk = HiveValue::new(hive, offset)?;
+					Ok((vk.name()?, vk.reg_type()?))
+				}),
+		))
+	}
+
+	/// Retrieves the data of a value, like
+	/// [`HKEY::RegQueryValueEx`](crate::prelude::kernel_Hkey::RegQueryValueEx).
+	/// `None` is the default value.
+	///
+	/// The search is case-insensitive. Fails with
+	/// [`co::ERROR::FILE_NOT_FOUND`](crate::co::ERROR::FILE_NOT_FOUND) if the
+	/// value doesn't exist.
+	#[must_use]
+	pub fn query_value(&self, value_name: Option<&str>) -> SysResult<RegistryValue> {
+		let name_uc = value_name.unwrap_or_default().to_uppercase();
+		for offset in self.value_offsets()? {
+			let vk = HiveValue::new(self.hive, offset)?;
+			if vk.name()?.to_uppercase() == name_uc {
+				return Ok(RegistryValue::from_bytes(&vk.data()?, vk.reg_type()?));
+			}
+		}
+		Err(co::ERROR::FILE_NOT_FOUND)
+	}
+
+	/// Appends the offsets of the key nodes in a subkey list, which can be an
+	/// index root pointing to other lists.
+	fn collect_sub_key_offsets(&self,
+		list_offset: u32, offsets: &mut Vec<u32>, depth: u32) -> SysResult<()>
+	{
+		if depth > 1 {
+			return Err(co::ERROR::INVALID_DATA); // index roots can't be nested
+		}
+
+		let list = self.hive.cell(list_offset)?;
+		let count = u16_at(list, 2)? as usize;
+		let stride = match list.get(..2) {
+			Some(b"li") | Some(b"ri") => 4,
+			Some(b"lf") | Some(b"lh") => 8, // each offset is followed by a hash
+			_ => return Err(co::ERROR::INVALID_DATA),
+		};
+
+		for idx in 0..count {
+			let offset = u32_at(list, 4 + idx * stride)?;
+			if &list[..2] == b"ri" {
+				self.collect_sub_key_offsets(offset, offsets, depth + 1)?;
+			} else {
+				offsets.push(offset);
+			}
+		}
+		Ok(())
+	}
+
+	fn value_offsets(&self) -> SysResult<Vec<u32>> {
+		let count = u32_at(self.cell, 36)? as usize;
+		let list_offset = u32_at(self.cell, 40)?;
+		if count == 0 || list_offset == NO_CELL {
+			return Ok(Vec::default());
+		}
+
+		let list = self.hive.cell(list_offset)?;
+		(0..count)
+			.map(|idx| u32_at(list, idx * 4))
+			.collect()
+	}
+}
+
+//------------------------------------------------------------------------------
+
+const BASE_BLOCK_SIZE: usize = 4096;
+const NO_CELL: u32 = 0xffff_ffff;
+const NK_NAME: usize = 76; // offset of the name in a key node
+const NK_COMP_NAME: u16 = 0x0020;
+const VK_NAME: usize = 20; // offset of the name in a key value
+const VK_COMP_NAME: u16 = 0x0001;
+const VK_DATA_INLINE: u32 = 0x8000_0000;
+const BIG_DATA_SEGMENT: usize = 16344;
+
+/// A key value cell.
+struct HiveValue<'a> {
+	hive: &'a RegHive,
+	cell: &'a [u8],
+}
+
+impl<'a> HiveValue<'a> {
+	fn new(hive: &'a RegHive, offset: u32) -> SysResult<Self> {
+		let cell = hive.cell(offset)?;
+		if cell.len() < VK_NAME || &cell[..2] != b"vk" {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		Ok(Self { hive, cell })
+	}
+
+	fn name(&self) -> SysResult<String> {
+		let len = u16_at(self.cell, 2)? as usize;
+		let raw_name = self.cell.get(VK_NAME..VK_NAME + len).ok_or(co::ERROR::INVALID_DATA)?;
+		decode_name(raw_name, u16_at(self.cell, 16)? & VK_COMP_NAME != 0)
+	}
+
+	fn reg_type(&self) -> SysResult<co::REG> {
+		Ok(unsafe { co::REG::from_raw(u32_at(self.cell, 12)?) })
+	}
+
+	fn data(&self) -> SysResult<Vec<u8>> {
+		let raw_size = u32_at(self.cell, 4)?;
+		let size = (raw_size & !VK_DATA_INLINE) as usize;
+
+		if raw_size & VK_DATA_INLINE != 0 { // up to 4 bytes, stored in the offset field
+			return self.cell.get(8..8 + size.min(4))
+				.map(|data| data.to_vec())
+				.ok_or(co::ERROR::INVALID_DATA);
+		}
+		if size == 0 {
+			return Ok(Vec::default());
+		}
+
+		let data = self.hive.cell(u32_at(self.cell, 8)?)?;
+		if size > BIG_DATA_SEGMENT && self.hive.minor_version() >= 4 && data.get(..2) == Some(b"db") {
+			let count = u16_at(data, 2)? as usize;
+			let segments = self.hive.cell(u32_at(data, 4)?)?;
+			if size > count * BIG_DATA_SEGMENT || segments.len() < count * 4 {
+				return Err(co::ERROR::INVALID_DATA); // validate before allocating
+			}
+			let mut buf = Vec::<u8>::with_capacity(size);
+			for idx in 0..count {
+				let segment = self.hive.cell(u32_at(segments, idx * 4)?)?;
+				let needed = (size - buf.len()).min(BIG_DATA_SEGMENT).min(segment.len());
+				buf.extend_from_slice(&segment[..needed]);
+			}
+			if buf.len() != size {
+				return Err(co::ERROR::INVALID_DATA);
+			}
+			Ok(buf)
+		} else {
+			data.get(..size)
+				.map(|data| data.to_vec())
+				.ok_or(co::ERROR::INVALID_DATA)
+		}
+	}
+}
+
+/// Decodes a name, which is either Latin-1 (compressed) or UTF-16.
+fn decode_name(raw: &[u8], compressed: bool) -> SysResult<String> {
+	if compressed {
+		Ok(raw.iter().map(|b| *b as char).collect())
+	} else if raw.len() % 2 != 0 {
+		Err(co::ERROR::INVALID_DATA)
+	} else {
+		let chars = raw.chunks_exact(2)
+			.map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+			.collect::<Vec<_>>();
+		Ok(String::from_utf16_lossy(&chars))
+	}
+}
+
+fn filetime_at(buf: &[u8], pos: usize) -> SysResult<FILETIME> {
+	Ok(FILETIME {
+		dwLowDateTime: u32_at(buf, pos)?,
+		dwHighDateTime: u32_at(buf, pos + 4)?,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Builds a hive by appending cells to a single hive bin.
+	struct HiveBuilder {
+		data: Vec<u8>,
+	}
+
+	impl HiveBuilder {
+		fn new() -> Self {
+			let mut data = vec![0u8; BASE_BLOCK_SIZE];
+			data[..4].copy_from_slice(b"regf");
+			data[24..28].copy_from_slice(&5u32.to_le_bytes()); // minor version
+			data.extend_from_slice(b"hbin");
+			data.resize(BASE_BLOCK_SIZE + 32, 0);
+			Self { data }
+		}
+
+		fn cell(&mut self, body: &[u8]) -> u32 {
+			let offset = (self.data.len() - BASE_BLOCK_SIZE) as u32;
+			let size = (body.len() + 4 + 7) & !7;
+			self.data.extend_from_slice(&(-(size as i32)).to_le_bytes());
+			self.data.extend_from_slice(body);
+			self.data.resize(BASE_BLOCK_SIZE + offset as usize + size, 0);
+			offset
+		}
+
+		fn key(&mut self, name: &str, sub_keys: &[u32], values: &[u32]) -> u32 {
+			let sub_list = if sub_keys.is_empty() {
+				NO_CELL
+			} else {
+				let mut list = b"lf".to_vec();
+				list.extend_from_slice(&(sub_keys.len() as u16).to_le_bytes());
+				for offset in sub_keys {
+					list.extend_from_slice(&offset.to_le_bytes());
+					list.extend_from_slice(&[0; 4]); // hash, not checked
+				}
+				self.cell(&list)
+			};
+			let value_list = if values.is_empty() {
+				NO_CELL
+			} else {
+				let list = values.iter()
+					.flat_map(|offset| offset.to_le_bytes())
+					.collect::<Vec<_>>();
+				self.cell(&list)
+			};
+
+			let mut nk = vec![0u8; NK_NAME];
+			nk[..2].copy_from_slice(b"nk");
+			nk[2..4].copy_from_slice(&NK_COMP_NAME.to_le_bytes());
+			nk[20..24].copy_from_slice(&(sub_keys.len() as u32).to_le_bytes());
+			nk[28..32].copy_from_slice(&sub_list.to_le_bytes());
+			nk[36..40].copy_from_slice(&(values.len() as u32).to_le_bytes());
+			nk[40..44].copy_from_slice(&value_list.to_le_bytes());
+			nk[48..52].copy_from_slice(&NO_CELL.to_le_bytes());
+			nk[72..74].copy_from_slice(&(name.len() as u16).to_le_bytes());
+			nk.extend_from_slice(name.as_bytes());
+			self.cell(&nk)
+		}
+
+		fn value(&mut self, name: &str, reg_type: co::REG, raw_size: u32, data_offset: u32) -> u32 {
+			let mut vk = vec![0u8; VK_NAME];
+			vk[..2].copy_from_slice(b"vk");
+			vk[2..4].copy_from_slice(&(name.len() as u16).to_le_bytes());
+			vk[4..8].copy_from_slice(&raw_size.to_le_bytes());
+			vk[8..12].copy_from_slice(&data_offset.to_le_bytes());
+			vk[12..16].copy_from_slice(&reg_type.raw().to_le_bytes());
+			vk[16..18].copy_from_slice(&VK_COMP_NAME.to_le_bytes());
+			vk.extend_from_slice(name.as_bytes());
+			self.cell(&vk)
+		}
+
+		fn big_data(&mut self, segments: &[&[u8]]) -> u32 {
+			let list = segments.iter()
+				.map(|segment| self.cell(segment))
+				.flat_map(|offset| offset.to_le_bytes())
+				.collect::<Vec<_>>();
+			let list_offset = self.cell(&list);
+			let mut db = b"db".to_vec();
+			db.extend_from_slice(&(segments.len() as u16).to_le_bytes());
+			db.extend_from_slice(&list_offset.to_le_bytes());
+			self.cell(&db)
+		}
+
+		fn build(mut self, root: u32) -> RegHive {
+			self.data[36..40].copy_from_slice(&root.to_le_bytes());
+			RegHive::from_bytes(self.data).unwrap()
+		}
+	}
+
+	fn utf16z(s: &str) -> Vec<u8> {
+		s.encode_utf16()
+			.chain(std::iter::once(0))
+			.flat_map(|ch| ch.to_le_bytes())
+			.collect()
+	}
+
+	#[test]
+	fn keys_and_values() {
+		let mut b = HiveBuilder::new();
+		let text = utf16z("hello");
+		let text_cell = b.cell(&text);
+		let values = [
+			b.value("Count", co::REG::DWORD, 4 | VK_DATA_INLINE, 42),
+			b.value("Text", co::REG::SZ, text.len() as _, text_cell),
+			b.value("", co::REG::SZ, text.len() as _, text_cell),
+		];
+		let leaf = b.key("Leaf", &[], &values);
+		let software = b.key("Software", &[leaf], &[]);
+		let root = b.key("ROOT", &[software], &[]);
+		let hive = b.build(root);
+
+		let root = hive.root_key().unwrap();
+		assert_eq!(root.name().unwrap(), "ROOT");
+		assert_eq!(root.class().unwrap(), None);
+		assert_eq!(root.enum_keys().unwrap().collect::<SysResult<Vec<_>>>().unwrap(),
+			["Software"]);
+
+		let leaf = root.open_key("software\\LEAF").unwrap();
+		assert_eq!(leaf.name().unwrap(), "Leaf");
+		assert_eq!(leaf.enum_values().unwrap().collect::<SysResult<Vec<_>>>().unwrap(), [
+			("Count".to_owned(), co::REG::DWORD),
+			("Text".to_owned(), co::REG::SZ),
+			("".to_owned(), co::REG::SZ),
+		]);
+		assert_eq!(leaf.query_value(Some("COUNT")).unwrap(), RegistryValue::Dword(42));
+		assert_eq!(leaf.query_value(Some("text")).unwrap(), RegistryValue::Sz("hello".to_owned()));
+		assert_eq!(leaf.query_value(None).unwrap(), RegistryValue::Sz("hello".to_owned()));
+
+		assert_eq!(root.open_key("Software\\Missing").err(), Some(co::ERROR::FILE_NOT_FOUND));
+		assert_eq!(leaf.query_value(Some("Missing")).err(), Some(co::ERROR::FILE_NOT_FOUND));
+	}
+
+	#[test]
+	fn big_data() {
+		let first = vec![0xaa; BIG_DATA_SEGMENT];
+		let second = vec![0xbb; 100];
+		let mut b = HiveBuilder::new();
+		let db = b.big_data(&[&first, &second]);
+		let big = b.value("Big", co::REG::BINARY, (BIG_DATA_SEGMENT + 10) as _, db);
+		let short = b.value("Short", co::REG::BINARY, (BIG_DATA_SEGMENT * 3) as _, db);
+		let huge = b.value("Huge", co::REG::BINARY, 0x7fff_ffff, db);
+		let root = b.key("ROOT", &[], &[big, short, huge]);
+		let hive = b.build(root);
+		let root = hive.root_key().unwrap();
+
+		let mut expected = first.clone();
+		expected.extend_from_slice(&second[..10]);
+		assert_eq!(root.query_value(Some("Big")).unwrap(), RegistryValue::Binary(expected));
+
+		// Declared sizes beyond what the segments can hold.
+		assert_eq!(root.query_value(Some("Short")).err(), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(root.query_value(Some("Huge")).err(), Some(co::ERROR::INVALID_DATA));
+	}
+
+	#[test]
+	fn malformed() {
+		assert!(RegHive::from_bytes(b"regf".to_vec()).is_err());
+
+		let mut b = HiveBuilder::new();
+		let root = b.key("ROOT", &[], &[]);
+		let mut data = b.data.clone();
+		data[..4].copy_from_slice(b"xxxx");
+		data[36..40].copy_from_slice(&root.to_le_bytes());
+		assert_eq!(RegHive::from_bytes(data).err(), Some(co::ERROR::INVALID_DATA));
+
+		let b = HiveBuilder::new();
+		let mut data = b.data.clone();
+		data[36..40].copy_from_slice(&0x1000_0000u32.to_le_bytes()); // root beyond the end
+		assert_eq!(RegHive::from_bytes(data).err(), Some(co::ERROR::INVALID_DATA));
+
+		let mut b = HiveBuilder::new();
+		let bogus = b.cell(b"lf\xff\xff");
+		let mut nk = vec![0u8; NK_NAME];
+		nk[..2].copy_from_slice(b"nk");
+		nk[28..32].copy_from_slice(&bogus.to_le_bytes()); // list claims 65535 subkeys
+		nk[40..44].copy_from_slice(&NO_CELL.to_le_bytes());
+		let root = b.cell(&nk);
+		let hive = b.build(root);
+		assert_eq!(hive.root_key().unwrap().sub_keys().err(), Some(co::ERROR::INVALID_DATA));
+	}
+}
@@ -0,0 +1,353 @@
+use crate::co;
+use crate::decl::*;
+use crate::kernel::utilities::le_bytes::{u16_at, u32_at};
+use crate::kernel::utilities::pe_image::{same_id, same_rt};
+
+/// High-level abstraction to load, manage and serialize the contents of a
+/// 32-bit compiled resource file (`.res`), as written by the resource compiler
+/// and consumed by the linker.
+///
+/// The file is a sequence of
+/// [`RESOURCEHEADER`](https://learn.microsoft.com/en-us/windows/win32/menurc/resourceheader)
+/// records, each one followed by the resource data.
+///
+/// # Examples
+///
+/// Checking the dialogs of a `.res` file:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let res = w::ResFile::parse_from_file("C:\\Temp\\app.res")?;
+///
+/// let dlg_ids = res.entries.iter()
+///     .filter(|entry| entry.rt == w::RtStr::Rt(co::RT::DIALOG))
+///     .map(|entry| entry.name.clone())
+///     .collect::<Vec<_>>();
+/// assert!(dlg_ids.contains(&w::IdStr::Id(101)));
+/// # Ok::<_, co::ERROR>(())
+/// ```
+///
+/// Creating a file from scratch:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let manifest = std::fs::read("C:\\Temp\\app.manifest").unwrap();
+///
+/// let res = w::ResFile {
+///     entries: vec![
+///         w::ResFileEntry::new(
+///             w::RtStr::Rt(co::RT::MANIFEST),
+///             w::IdStr::Id(1),
+///             w::LANGID::new(co::LANG::ENGLISH, co::SUBLANG::ENGLISH_US),
+///             manifest,
+///         ),
+///     ],
+/// };
+/// res.serialize_to_file("C:\\Temp\\app.res")?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ResFile {
+	/// All the resources of the file, in order. They can be modified at will.
+	pub entries: Vec<ResFileEntry>,
+}
+
+impl ResFile {
+	/// Parses a `ResFile` from raw bytes.
+	///
+	/// Fails with [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA)
+	/// if the bytes don't start with the empty resource which marks a 32-bit
+	/// `.res` file, or if any record is truncated.
+	#[must_use]
+	pub fn parse_bytes(bytes: &[u8]) -> SysResult<Self> {
+		if bytes.get(..EMPTY_HEADER.len()) != Some(&EMPTY_HEADER) {
+			return Err(co::ERROR::INVALID_DATA); // possibly a 16-bit .res file
+		}
+
+		let mut entries = Vec::default();
+		let mut pos = EMPTY_HEADER.len();
+		while pos < bytes.len() {
+			let data_size = u32_at(bytes, pos)? as usize;
+			let header_size = u32_at(bytes, pos + 4)? as usize;
+			let (rt, after_type) = read_id(bytes, pos + 8)?;
+			let (name, after_name) = read_id(bytes, after_type)?;
+			let fixed = after_name.next_multiple_of(4); // DWORD-aligned
+			if fixed + 16 > pos + header_size {
+				return Err(co::ERROR::INVALID_DATA);
+			}
+
+			let data_start = pos + header_size;
+			let data = bytes.get(data_start..data_start + data_size)
+				.ok_or(co::ERROR::INVALID_DATA)?;
+
+			entries.push(ResFileEntry {
+				rt: match rt {
+					IdStr::Id(id) => RtStr::Rt(unsafe { co::RT::from_raw(id) }),
+					IdStr::Str(s) => RtStr::Str(s),
+				},
+				name,
+				lang: unsafe { LANGID::from_raw(u16_at(bytes, fixed + 6)?) },
+				memory_flags: unsafe { co::MEMORYFLAGS::from_raw(u16_at(bytes, fixed + 4)?) },
+				data_version: u32_at(bytes, fixed)?,
+				version: u32_at(bytes, fixed + 8)?,
+				characteristics: u32_at(bytes, fixed + 12)?,
+				data: data.to_vec(),
+			});
+			pos = (data_start + data_size).next_multiple_of(4);
+		}
+
+		Ok(Self { entries })
+	}
+
+	/// Parses a `ResFile` directly from a file. The file will be
+	/// [mapped in memory](crate::FileMapped) during reading for maximum
+	/// performance.
+	#[must_use]
+	pub fn parse_from_file(res_path: &str) -> SysResult<Self> {
+		let fin = FileMapped::open(res_path, FileAccess::ExistingReadOnly)?;
+		Self::parse_bytes(fin.as_slice())
+	}
+
+	/// Returns a reference to the [`ResFileEntry`](crate::ResFileEntry) with
+	/// the given type, name and language, if any. If `lang` is `None`, the
+	/// first language found is returned.
+	///
+	/// String identifiers are compared case-insensitively.
+	#[must_use]
+	pub fn find_entry(&self,
+		rt: &RtStr,
+		name: &IdStr,
+		lang: Option<LANGID>,
+	) -> Option<&ResFileEntry>
+	{
+		self.entries.iter()
+			.find(|entry| same_rt(&entry.rt, rt)
+				&& same_id(&entry.name, name)
+				&& lang.is_none_or(|lang| entry.lang == lang))
+	}
+
+	/// Serializes the resources to raw bytes, starting with the empty resource
+	/// which marks a 32-bit `.res` file.
+	#[must_use]
+	pub fn serialize_to_bytes(&self) -> Vec<u8> {
+		let mut buf = EMPTY_HEADER.to_vec();
+		for entry in self.entries.iter() {
+			let hdr_start = buf.len();
+			buf.extend_from_slice(&(entry.data.len() as u32).to_le_bytes());
+			buf.extend_from_slice(&[0; 4]); // header size, filled below
+			match &entry.rt {
+				RtStr::Rt(rt) => write_id(&mut buf, &IdStr::Id(rt.raw())),
+				RtStr::Str(s) => write_id(&mut buf, &IdStr::Str(s.clone())),
+			}
+			write_id(&mut buf, &entry.name);
+			buf.resize(buf.len().next_multiple_of(4), 0);
+
+			buf.extend_from_slice(&entry.data_version.to_le_bytes());
+			buf.extend_from_slice(&entry.memory_flags.raw().to_le_bytes());
+			buf.extend_from_slice(&entry.lang.raw().to_le_bytes());
+			buf.extend_from_slice(&entry.version.to_le_bytes());
+			buf.extend_from_slice(&entry.characteristics.to_le_bytes());
+
+			let header_size = (buf.len() - hdr_start) as u32;
+			buf[hdr_start + 4..hdr_start + 8].copy_from_slice(&header_size.to_le_bytes());
+			buf.extend_from_slice(&entry.data);
+			buf.resize(buf.len().next_multiple_of(4), 0);
+		}
+		buf
+	}
+
+	/// Serializes the resources directly to a file with
+	/// [`ResFile::serialize_to_bytes`](crate::ResFile::serialize_to_bytes).
+	pub fn serialize_to_file(&self, res_path: &str) -> SysResult<()> {
+		let fout = File::open(res_path, FileAccess::OpenOrCreateRW)?;
+		fout.erase_and_write(&self.serialize_to_bytes())?;
+		Ok(())
+	}
+}
+
+/// A single resource of a [`ResFile`](crate::ResFile).
+#[derive(Clone, Debug)]
+pub struct ResFileEntry {
+	/// Resource type.
+	pub rt: RtStr,
+	/// Resource name.
+	pub name: IdStr,
+	/// Resource language.
+	pub lang: LANGID,
+	/// Memory flags.
+	pub memory_flags: co::MEMORYFLAGS,
+	/// Version of the data, reserved for the application.
+	pub data_version: u32,
+	/// Version of the resource, reserved for the application.
+	pub version: u32,
+	/// Characteristics of the resource, reserved for the application.
+	pub characteristics: u32,
+	/// The raw data.
+	pub data: Vec<u8>,
+}
+
+impl ResFileEntry {
+	/// Creates a new resource with the memory flags used by the resource
+	/// compiler: [`MOVEABLE`](crate::co::MEMORYFLAGS::MOVEABLE),
+	/// [`PURE`](crate::co::MEMORYFLAGS::PURE) and
+	/// [`DISCARDABLE`](crate::co::MEMORYFLAGS::DISCARDABLE).
+	#[must_use]
+	pub fn new(rt: RtStr, name: IdStr, lang: LANGID, data: Vec<u8>) -> Self {
+		Self {
+			rt,
+			name,
+			lang,
+			memory_flags: co::MEMORYFLAGS::MOVEABLE
+				| co::MEMORYFLAGS::PURE
+				| co::MEMORYFLAGS::DISCARDABLE,
+			data_version: 0,
+			version: 0,
+			characteristics: 0,
+			data,
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// The empty resource at the beginning of 32-bit `.res` files.
+const EMPTY_HEADER: [u8; 32] = [
+	0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, // DataSize, HeaderSize
+	0xff, 0xff, 0x00, 0x00, 0xff, 0xff, 0x00, 0x00, // TYPE, NAME
+	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // DataVersion, MemoryFlags, LanguageId
+	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Version, Characteristics
+];
+
+/// Reads a type or name, which is either `0xffff` followed by the ID, or a
+/// null-terminated UTF-16 string. Returns the position after it.
+fn read_id(buf: &[u8], pos: usize) -> SysResult<(IdStr, usize)> {
+	if u16_at(buf, pos)? == 0xffff {
+		return Ok((IdStr::Id(u16_at(buf, pos + 2)?), pos + 4));
+	}
+	let mut chars = Vec::<u16>::default();
+	let mut cur = pos;
+	loop {
+		let ch = u16_at(buf, cur)?;
+		cur += 2;
+		if ch == 0 {
+			break;
+		}
+		chars.push(ch);
+	}
+	Ok((IdStr::Str(WString::from_wchars_slice(&chars)), cur))
+}
+
+fn write_id(buf: &mut Vec<u8>, id: &IdStr) {
+	match id {
+		IdStr::Id(id) => {
+			buf.extend_from_slice(&0xffffu16.to_le_bytes());
+			buf.extend_from_slice(&id.to_le_bytes());
+		},
+		IdStr::Str(s) => {
+			buf.extend(
+				s.to_string()
+					.encode_utf16()
+					.chain([0])
+					.flat_map(|ch| ch.to_le_bytes()),
+			);
+		},
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// As written by the resource compiler: an empty resource, a manifest and
+	/// a resource with string type and name.
+	const FIXTURE: [u8; 108] = [
+		0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, // empty resource
+		0xff, 0xff, 0x00, 0x00, 0xff, 0xff, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+
+		0x03, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, // DataSize, HeaderSize
+		0xff, 0xff, 0x18, 0x00, 0xff, 0xff, 0x01, 0x00, // RT_MANIFEST, 1
+		0x00, 0x00, 0x00, 0x00, 0x30, 0x10, 0x09, 0x04, // DataVersion, MemoryFlags, LanguageId
+		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Version, Characteristics
+		b'a', b'b', b'c', 0x00, // data, padding
+
+		0x02, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, // DataSize, HeaderSize
+		b'A', 0x00, b'B', 0x00, 0x00, 0x00, // "AB"
+		b'X', 0x00, 0x00, 0x00, 0x00, 0x00, // "X", padding
+		0x05, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00,
+		0x07, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00,
+		0x01, 0x02, 0x00, 0x00, // data, padding
+	];
+
+	#[test]
+	fn parse_fixture() {
+		let res = ResFile::parse_bytes(&FIXTURE).unwrap();
+		assert_eq!(res.entries.len(), 2);
+
+		let e = &res.entries[0];
+		assert_eq!(e.rt, RtStr::Rt(co::RT::MANIFEST));
+		assert_eq!(e.name, IdStr::Id(1));
+		assert_eq!(e.lang, LANGID::new(co::LANG::ENGLISH, co::SUBLANG::ENGLISH_US));
+		assert_eq!(e.memory_flags, co::MEMORYFLAGS::MOVEABLE
+			| co::MEMORYFLAGS::PURE | co::MEMORYFLAGS::DISCARDABLE);
+		assert_eq!((e.data_version, e.version, e.characteristics), (0, 0, 0));
+		assert_eq!(e.data, b"abc");
+
+		let e = &res.entries[1];
+		assert_eq!(e.rt, RtStr::Str(WString::from_str("AB")));
+		assert_eq!(e.name, IdStr::Str(WString::from_str("X")));
+		assert_eq!(e.lang.raw(), 0);
+		assert_eq!(e.memory_flags.raw(), 0x0030);
+		assert_eq!((e.data_version, e.version, e.characteristics), (5, 7, 9));
+		assert_eq!(e.data, [0x01, 0x02]);
+
+		assert!(res.find_entry(&RtStr::Str(WString::from_str("ab")), &IdStr::Str(WString::from_str("x")), None).is_some());
+		assert!(res.find_entry(&RtStr::Rt(co::RT::MANIFEST), &IdStr::Id(1), Some(unsafe { LANGID::from_raw(0) })).is_none());
+
+		assert_eq!(res.serialize_to_bytes(), FIXTURE);
+	}
+
+	#[test]
+	fn round_trip() {
+		let lang = LANGID::new(co::LANG::PORTUGUESE, co::SUBLANG::PORTUGUESE_BRAZILIAN);
+		let mut custom = ResFileEntry::new(
+			RtStr::Str(WString::from_str("CUSTOM")), IdStr::Id(7), lang, vec![1, 2, 3, 4, 5]);
+		custom.data_version = 1;
+		custom.version = 2;
+		custom.characteristics = 3;
+		let res = ResFile {
+			entries: vec![
+				ResFileEntry::new(RtStr::Rt(co::RT::RCDATA), IdStr::Str(WString::from_str("NAME")), lang, Vec::default()),
+				custom,
+			],
+		};
+
+		let bytes = res.serialize_to_bytes();
+		assert_eq!(bytes.len() % 4, 0);
+		let parsed = ResFile::parse_bytes(&bytes).unwrap();
+		assert_eq!(parsed.entries.len(), res.entries.len());
+		for (a, b) in parsed.entries.iter().zip(res.entries.iter()) {
+			assert_eq!((&a.rt, &a.name, a.lang, a.memory_flags), (&b.rt, &b.name, b.lang, b.memory_flags));
+			assert_eq!((a.data_version, a.version, a.characteristics), (b.data_version, b.version, b.characteristics));
+			assert_eq!(a.data, b.data);
+		}
+		assert_eq!(ResFile::default().serialize_to_bytes(), EMPTY_HEADER);
+	}
+
+	#[test]
+	fn malformed() {
+		let parse_err = |bytes: &[u8]| ResFile::parse_bytes(bytes).err();
+
+		assert_eq!(parse_err(&[]), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(parse_err(&[0xff, 0x18, 0x00]), Some(co::ERROR::INVALID_DATA)); // 16-bit
+		assert_eq!(parse_err(&FIXTURE[..FIXTURE.len() - 4]), Some(co::ERROR::INVALID_DATA)); // truncated data
+		assert_eq!(parse_err(&FIXTURE[..40]), Some(co::ERROR::INVALID_DATA)); // truncated header
+
+		let mut short = FIXTURE;
+		short[36] = 0x10; // HeaderSize doesn't cover the fixed fields
+		assert_eq!(parse_err(&short), Some(co::ERROR::INVALID_DATA));
+	}
+}
@@ -0,0 +1,296 @@
+use crate::co;
+use crate::decl::*;
+
+/// Offsets of the 8 static windows, which cannot be redefined.
+const STATIC_WINDOWS: [u32; 8] = [
+	0x0000, 0x0080, 0x0100, 0x0300, 0x2000, 0x2080, 0x2100, 0x3000,
+];
+
+/// Initial offsets of the 8 dynamic windows.
+const DYNAMIC_WINDOWS: [u32; 8] = [
+	0x0080, 0x00c0, 0x0400, 0x0600, 0x0900, 0x3040, 0x30a0, 0xff00,
+];
+
+// Tags used in single-byte mode.
+const SQ0: u8 = 0x01; // quote from window 0..7
+const SQ7: u8 = 0x08;
+const SDX: u8 = 0x0b; // define extended window
+const SQU: u8 = 0x0e; // quote Unicode
+const SCU: u8 = 0x0f; // change to Unicode mode
+const SC0: u8 = 0x10; // change to window 0..7
+const SC7: u8 = 0x17;
+const SD0: u8 = 0x18; // define window 0..7
+const SD7: u8 = 0x1f;
+
+// Tags used in Unicode mode.
+const UC0: u8 = 0xe0; // change to window 0..7
+const UC7: u8 = 0xe7;
+const UD0: u8 = 0xe8; // define window 0..7
+const UD7: u8 = 0xef;
+const UQU: u8 = 0xf0; // quote Unicode
+const UDX: u8 = 0xf1; // define extended window
+const URS: u8 = 0xf2; // reserved
+
+/// Decodes
+/// [SCSU](https://www.unicode.org/reports/tr6/)
+/// bytes into UTF-16 chars.
+pub(in crate::kernel) fn decode(data: &[u8]) -> SysResult<Vec<u16>> {
+	let mut buf = Vec::<u16>::with_capacity(data.len());
+	let mut windows = DYNAMIC_WINDOWS;
+	let mut active = 0usize;
+	let mut unicode_mode = false;
+	let mut bytes = data.iter().copied();
+	let mut next = || bytes.next().ok_or(co::ERROR::INVALID_DATA); // truncated tag arguments
+
+	while let Ok(b) = next() {
+		if unicode_mode {
+			match b {
+				UC0..=UC7 => {
+					active = (b - UC0) as usize;
+					unicode_mode = false;
+				},
+				UD0..=UD7 => {
+					active = (b - UD0) as usize;
+					windows[active] = window_offset(next()?)?;
+					unicode_mode = false;
+				},
+				UQU => buf.push(u16::from_be_bytes([next()?, next()?])),
+				UDX => {
+					let (idx, offset) = extended_window(next()?, next()?);
+					active = idx;
+					windows[active] = offset;
+					unicode_mode = false;
+				},
+				URS => return Err(co::ERROR::INVALID_DATA),
+				_ => buf.push(u16::from_be_bytes([b, next()?])),
+			}
+		} else {
+			match b {
+				0x00 | 0x09 | 0x0a | 0x0d | 0x20..=0x7f => buf.push(b as _),
+				0x80..=0xff => push_code_point(&mut buf, windows[active] + (b - 0x80) as u32)?,
+				SQ0..=SQ7 => {
+					let idx = (b - SQ0) as usize;
+					let quoted = next()?;
+					push_code_point(&mut buf, if quoted < 0x80 {
+						STATIC_WINDOWS[idx] + quoted as u32
+					} else {
+						windows[idx] + (quoted - 0x80) as u32
+					})?;
+				},
+				SDX => {
+					let (idx, offset) = extended_window(next()?, next()?);
+					active = idx;
+					windows[active] = offset;
+				},
+				SQU => buf.push(u16::from_be_bytes([next()?, next()?])),
+				SCU => unicode_mode = true,
+				SC0..=SC7 => active = (b - SC0) as usize,
+				SD0..=SD7 => {
+					active = (b - SD0) as usize;
+					windows[active] = window_offset(next()?)?;
+				},
+				_ => return Err(co::ERROR::INVALID_DATA), // reserved tag 0x0c
+			}
+		}
+	}
+
+	Ok(buf)
+}
+
+/// Returns the window offset for the argument byte of an `SDn` or `UDn` tag.
+fn window_offset(b: u8) -> SysResult<u32> {
+	match b {
+		0x01..=0x67 => Ok(b as u32 * 0x80),
+		0x68..=0xa7 => Ok(b as u32 * 0x80 + 0xac00),
+		0xf9 => Ok(0x00c0),
+		0xfa => Ok(0x0250),
+		0xfb => Ok(0x0370),
+		0xfc => Ok(0x0530),
+		0xfd => Ok(0x3040),
+		0xfe => Ok(0x30a0),
+		0xff => Ok(0xff60),
+		_ => Err(co::ERROR::INVALID_DATA), // reserved
+	}
+}
+
+/// Returns the window index and offset for the argument bytes of an `SDX` or
+/// `UDX` tag, which define windows in the supplementary planes.
+fn extended_window(hi: u8, lo: u8) -> (usize, u32) {
+	let idx = (hi >> 5) as usize;
+	let offset = 0x1_0000 + ((((hi & 0x1f) as u32) << 8 | lo as u32) << 7);
+	(idx, offset)
+}
+
+fn push_code_point(buf: &mut Vec<u16>, code_point: u32) -> SysResult<()> {
+	let ch = char::from_u32(code_point).ok_or(co::ERROR::INVALID_DATA)?;
+	buf.extend_from_slice(ch.encode_utf16(&mut [0; 2]));
+	Ok(())
+}
+
+/// Encodes UTF-16 chars into
+/// [SCSU](https://www.unicode.org/reports/tr6/)
+/// bytes.
+///
+/// This is a simple encoder, which doesn't look ahead to choose the most
+/// compact output, but which always produces valid SCSU.
+pub(in crate::kernel) fn encode(chars: &[u16]) -> SysResult<Vec<u8>> {
+	let mut buf = Vec::<u8>::with_capacity(chars.len());
+	let mut windows = DYNAMIC_WINDOWS;
+	let mut active = 0usize;
+	let mut next_redefined = 0usize; // windows are redefined in round-robin
+	let mut unicode_mode = false;
+
+	for ch in char::decode_utf16(chars.iter().copied()) {
+		let code_point = ch.map_err(|_| co::ERROR::NO_UNICODE_TRANSLATION)? as u32;
+		let in_window = windows.iter()
+			.position(|off| *off <= code_point && code_point < *off + 0x80);
+		let is_ascii = code_point < 0x80;
+
+		if unicode_mode {
+			if let Some(idx) = in_window {
+				active = idx;
+				buf.push(UC0 + idx as u8);
+				unicode_mode = false;
+			} else if is_ascii {
+				buf.push(UC0 + active as u8);
+				unicode_mode = false;
+			} else if let Some(offset_byte) = offset_byte(code_point) {
+				active = next_redefined;
+				next_redefined = (next_redefined + 1) % windows.len();
+				windows[active] = window_offset(offset_byte)?;
+				buf.extend_from_slice(&[UD0 + active as u8, offset_byte]);
+				unicode_mode = false;
+			} else {
+				let mut units = [0u16; 2];
+				for unit in char::from_u32(code_point).unwrap().encode_utf16(&mut units) {
+					let [hi, lo] = unit.to_be_bytes();
+					if (UC0..=URS).contains(&hi) {
+						buf.push(UQU); // would be mistaken by a tag
+					}
+					buf.extend_from_slice(&[hi, lo]);
+				}
+				continue;
+			}
+		}
+
+		// From here on, we're in single-byte mode.
+		if is_ascii {
+			match code_point as u8 {
+				b @ (0x00 | 0x09 | 0x0a | 0x0d | 0x20..=0x7f) => buf.push(b),
+				b => buf.extend_from_slice(&[SQ0, b]), // other control chars
+			}
+		} else if let Some(idx) = in_window {
+			if idx != active {
+				active = idx;
+				buf.push(SC0 + idx as u8);
+			}
+			buf.push(0x80 + (code_point - windows[active]) as u8);
+		} else if let Some(idx) = STATIC_WINDOWS.iter()
+			.position(|off| *off <= code_point && code_point < *off + 0x80)
+		{
+			buf.extend_from_slice(&[SQ0 + idx as u8, (code_point - STATIC_WINDOWS[idx]) as u8]);
+		} else if let Some(offset_byte) = offset_byte(code_point) {
+			active = next_redefined;
+			next_redefined = (next_redefined + 1) % windows.len();
+			windows[active] = window_offset(offset_byte)?;
+			buf.extend_from_slice(&[SD0 + active as u8, offset_byte,
+				0x80 + (code_point - windows[active]) as u8]);
+		} else if code_point >= 0x1_0000 {
+			active = next_redefined;
+			next_redefined = (next_redefined + 1) % windows.len();
+			let x = (code_point - 0x1_0000) >> 7;
+			let (hi, lo) = (((active as u32) << 5 | x >> 8) as u8, x as u8);
+			windows[active] = extended_window(hi, lo).1;
+			buf.extend_from_slice(&[SDX, hi, lo,
+				0x80 + (code_point - windows[active]) as u8]);
+		} else {
+			buf.push(SCU); // CJK and Hangul are better represented in Unicode mode
+			unicode_mode = true;
+			let [hi, lo] = (code_point as u16).to_be_bytes();
+			if (UC0..=URS).contains(&hi) {
+				buf.push(UQU);
+			}
+			buf.extend_from_slice(&[hi, lo]);
+		}
+	}
+
+	Ok(buf)
+}
+
+/// Returns the argument byte of an `SDn` or `UDn` tag which defines a window
+/// containing the given BMP code point, if any.
+fn offset_byte(code_point: u32) -> Option<u8> {
+	match code_point {
+		0x0080..=0x33ff => Some((code_point >> 7) as u8),
+		0xe000..=0xffff => Some(((code_point - 0xac00) >> 7) as u8),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Sample texts of [UTS #6](https://www.unicode.org/reports/tr6/), section
+	/// 9, with their compressed bytes.
+	fn samples() -> [(Vec<u16>, &'static [u8]); 4] {
+		[
+			( // 9.1 German
+				"Öl fließt".encode_utf16().collect(),
+				&[0xd6, 0x6c, 0x20, 0x66, 0x6c, 0x69, 0x65, 0xdf, 0x74],
+			),
+			( // 9.2 Russian
+				"Москва".encode_utf16().collect(),
+				&[0x12, 0x9c, 0xbe, 0xc1, 0xba, 0xb2, 0xb0],
+			),
+			( // 9.3 Japanese
+				"\u{3000}♪リンゴ可愛いや可愛いやリンゴ。半世紀も前に流行した「リンゴの歌」がぴったりするかもしれない。米アップルコンピュータ社のパソコン「マック（マッキントッシュ）」を、こよなく愛する人たちのことだ。「アップル信者」なんて言い方まである。"
+					.encode_utf16().collect(),
+				&[
+					0x08, 0x00, 0x1b, 0x4c, 0xea, 0x16, 0xca, 0xd3, 0x94, 0x0f, 0x53, 0xef, 0x61, 0x1b, 0xe5, 0x84,
+					0xc4, 0x0f, 0x53, 0xef, 0x61, 0x1b, 0xe5, 0x84, 0xc4, 0x16, 0xca, 0xd3, 0x94, 0x08, 0x02, 0x0f,
+					0x53, 0x4a, 0x4e, 0x16, 0x7d, 0x00, 0x30, 0x82, 0x52, 0x4d, 0x30, 0x6b, 0x6d, 0x41, 0x88, 0x4c,
+					0xe5, 0x97, 0x9f, 0x08, 0x0c, 0x16, 0xca, 0xd3, 0x94, 0x15, 0xae, 0x0e, 0x6b, 0x4c, 0x08, 0x0d,
+					0x8c, 0xb4, 0xa3, 0x9f, 0xca, 0x99, 0xcb, 0x8b, 0xc2, 0x97, 0xcc, 0xaa, 0x84, 0x08, 0x02, 0x0e,
+					0x7c, 0x73, 0xe2, 0x16, 0xa3, 0xb7, 0xcb, 0x93, 0xd3, 0xb4, 0xc5, 0xdc, 0x9f, 0x0e, 0x79, 0x3e,
+					0x06, 0xae, 0xb1, 0x9d, 0x93, 0xd3, 0x08, 0x0c, 0xbe, 0xa3, 0x8f, 0x08, 0x88, 0xbe, 0xa3, 0x8d,
+					0xd3, 0xa8, 0xa3, 0x97, 0xc5, 0x17, 0x89, 0x08, 0x0d, 0x15, 0xd2, 0x08, 0x01, 0x93, 0xc8, 0xaa,
+					0x8f, 0x0e, 0x61, 0x1b, 0x99, 0xcb, 0x0e, 0x4e, 0xba, 0x9f, 0xa1, 0xae, 0x93, 0xa8, 0xa0, 0x08,
+					0x02, 0x08, 0x0c, 0xe2, 0x16, 0xa3, 0xb7, 0xcb, 0x0f, 0x4f, 0xe1, 0x80, 0x05, 0xec, 0x60, 0x8d,
+					0xea, 0x06, 0xd3, 0xe6, 0x0f, 0x8a, 0x00, 0x30, 0x44, 0x65, 0xb9, 0xe4, 0xfe, 0xe7, 0xc2, 0x06,
+					0xcb, 0x82,
+				],
+			),
+			( // 9.4 all features
+				vec![
+					0x0041, 0x00df, 0x0401, 0x015f, 0x00df, 0x01df, 0xf000, 0xdbff, 0xdfff, 0x000d,
+					0x000a, 0x0041, 0x00df, 0x0401, 0x015f, 0x00df, 0x01df, 0xf000, 0xdbff, 0xdfff,
+				],
+				&[
+					0x41, 0xdf, 0x12, 0x81, 0x03, 0x5f, 0x10, 0xdf, 0x1b, 0x03, 0xdf, 0x1c, 0x88, 0x80, 0x0b, 0xbf,
+					0xff, 0xff, 0x0d, 0x0a, 0x41, 0x10, 0xdf, 0x12, 0x81, 0x03, 0x5f, 0x10, 0xdf, 0x13, 0xdf, 0x14,
+					0x80, 0x15, 0xff,
+				],
+			),
+		]
+	}
+
+	#[test]
+	fn decode_samples() {
+		for (chars, data) in samples().iter() {
+			assert_eq!(decode(data).unwrap(), *chars);
+		}
+	}
+
+	#[test]
+	fn encode_round_trip() {
+		for (chars, _) in samples().iter() {
+			assert_eq!(decode(&encode(chars).unwrap()).unwrap(), *chars);
+		}
+	}
+
+	#[test]
+	fn encode_lone_surrogate() {
+		assert_eq!(encode(&[0x0041, 0xd800]), Err(co::ERROR::NO_UNICODE_TRANSLATION));
+	}
+}
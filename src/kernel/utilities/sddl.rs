@@ -0,0 +1,809 @@
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+/// An owned access control entry, part of an [`Acl`](crate::Acl).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ace {
+	/// Type of the entry.
+	pub ace_type: co::ACE_TYPE,
+	/// Inheritance and audit flags.
+	pub flags: co::ACE,
+	/// Access mask.
+	pub mask: u32,
+	/// Object type, only for the object ACE types.
+	pub object_type: Option<GUID>,
+	/// Inherited object type, only for the object ACE types.
+	pub inherited_object_type: Option<GUID>,
+	/// The trustee.
+	pub sid: Sid,
+}
+
+impl Ace {
+	/// Creates a new entry, without object types.
+	#[must_use]
+	pub fn new(ace_type: co::ACE_TYPE, flags: co::ACE, mask: u32, sid: Sid) -> Self {
+		Self {
+			ace_type,
+			flags,
+			mask,
+			object_type: None,
+			inherited_object_type: None,
+			sid,
+		}
+	}
+
+	fn is_object(&self) -> bool {
+		matches!(self.ace_type,
+			co::ACE_TYPE::ACCESS_ALLOWED_OBJECT
+			| co::ACE_TYPE::ACCESS_DENIED_OBJECT
+			| co::ACE_TYPE::SYSTEM_AUDIT_OBJECT
+			| co::ACE_TYPE::SYSTEM_ALARM_OBJECT)
+	}
+}
+
+/// An owned access control list, part of a
+/// [`SecurityDescriptor`](crate::SecurityDescriptor).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Acl {
+	/// The entries, in order.
+	pub aces: Vec<Ace>,
+}
+
+impl Acl {
+	/// Creates a new, empty list, which denies all access when used as a DACL.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { aces: Vec::new() }
+	}
+
+	/// Appends an [`co::ACE_TYPE::ACCESS_ALLOWED`](crate::co::ACE_TYPE::ACCESS_ALLOWED)
+	/// entry.
+	pub fn allow(&mut self, sid: Sid, mask: u32, flags: co::ACE) -> &mut Self {
+		self.aces.push(Ace::new(co::ACE_TYPE::ACCESS_ALLOWED, flags, mask, sid));
+		self
+	}
+
+	/// Appends an [`co::ACE_TYPE::ACCESS_DENIED`](crate::co::ACE_TYPE::ACCESS_DENIED)
+	/// entry.
+	pub fn deny(&mut self, sid: Sid, mask: u32, flags: co::ACE) -> &mut Self {
+		self.aces.push(Ace::new(co::ACE_TYPE::ACCESS_DENIED, flags, mask, sid));
+		self
+	}
+}
+
+/// An owned security descriptor, which can be converted to and from
+/// [SDDL](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-definition-language)
+/// strings and the self-relative binary layout, in pure Rust.
+///
+/// The [`co::SE::DACL_PRESENT`](crate::co::SE::DACL_PRESENT) and
+/// [`co::SE::SACL_PRESENT`](crate::co::SE::SACL_PRESENT) flags are implied when
+/// `dacl` and `sacl` are `Some`. Setting `DACL_PRESENT` with `dacl` as `None`
+/// means a null DACL, which grants full access to everyone.
+///
+/// # Examples
+///
+/// Building a descriptor which gives full access to SYSTEM and read access to
+/// authenticated users:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut dacl = w::Acl::new();
+/// dacl.allow(w::Sid::parse("S-1-5-18")?, co::GENERIC::ALL.raw(), co::ACE::NoValue)
+///     .allow(w::Sid::parse("S-1-5-11")?, co::GENERIC::READ.raw(), co::ACE::NoValue);
+///
+/// let sd = w::SecurityDescriptor {
+///     control: co::SE::DACL_PROTECTED,
+///     dacl: Some(dacl),
+///     ..Default::default()
+/// };
+/// println!("{}", sd.to_sddl()); // D:P(A;;GA;;;SY)(A;;GR;;;AU)
+///
+/// let raw = sd.to_bytes(); // self-relative layout
+/// # Ok::<_, co::ERROR>(())
+/// ```
+///
+/// Parsing an SDDL string:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let sd = w::SecurityDescriptor::parse_sddl("O:BAG:SYD:(A;OICI;FA;;;BA)")?;
+/// for ace in sd.dacl.unwrap().aces.iter() {
+///     println!("{} {:#x}", ace.sid, ace.mask);
+/// }
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SecurityDescriptor {
+	/// Control flags.
+	pub control: co::SE,
+	/// Owner SID.
+	pub owner: Option<Sid>,
+	/// Primary group SID.
+	pub group: Option<Sid>,
+	/// Discretionary ACL, which controls the access.
+	pub dacl: Option<Acl>,
+	/// System ACL, which controls the auditing.
+	pub sacl: Option<Acl>,
+}
+
+impl SecurityDescriptor {
+	/// Parses a descriptor in the self-relative binary layout, like the ones
+	/// returned by
+	/// [`GetFileSecurity`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getfilesecurityw).
+	///
+	/// Returns
+	/// [`co::ERROR::INVALID_SECURITY_DESCR`](crate::co::ERROR::INVALID_SECURITY_DESCR),
+	/// [`co::ERROR::INVALID_ACL`](crate::co::ERROR::INVALID_ACL) or
+	/// [`co::ERROR::INVALID_SID`](crate::co::ERROR::INVALID_SID) if the data is
+	/// malformed, or has unsupported ACE types.
+	pub fn from_bytes(data: &[u8]) -> SysResult<Self> {
+		if data.len() < 20 || data[0] != 1 {
+			return Err(co::ERROR::INVALID_SECURITY_DESCR);
+		}
+		let control = unsafe { co::SE::from_raw(u16::from_le_bytes([data[2], data[3]])) };
+		if !control.has(co::SE::SELF_RELATIVE) {
+			return Err(co::ERROR::INVALID_SECURITY_DESCR);
+		}
+
+		let block = |field: usize| -> SysResult<Option<&[u8]>> {
+			match u32::from_le_bytes(data[field..field + 4].try_into().unwrap()) as usize {
+				0 => Ok(None),
+				off => data.get(off..).map(Some).ok_or(co::ERROR::INVALID_SECURITY_DESCR),
+			}
+		};
+		let sid_at = |field: usize| -> SysResult<Option<Sid>> {
+			block(field)?.map(|data| Sid::from_bytes_prefix(data).map(|(sid, _)| sid))
+				.transpose()
+		};
+		let acl_at = |field: usize, flag: co::SE| -> SysResult<Option<Acl>> {
+			if control.has(flag) {
+				block(field)?.map(acl_from_bytes).transpose()
+			} else {
+				Ok(None)
+			}
+		};
+
+		Ok(Self {
+			control: control & !co::SE::SELF_RELATIVE,
+			owner: sid_at(4)?,
+			group: sid_at(8)?,
+			sacl: acl_at(12, co::SE::SACL_PRESENT)?,
+			dacl: acl_at(16, co::SE::DACL_PRESENT)?,
+		})
+	}
+
+	/// Parses an
+	/// [SDDL](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-string-format)
+	/// string, like `O:BAG:BAD:P(A;OICI;FA;;;SY)`.
+	///
+	/// SIDs can be written as `S-1-...` strings or as the well-known
+	/// two-letter aliases which don't depend on a domain, like `SY` or `BA`.
+	/// Access rights can be written in hex or as the two-letter codes, like
+	/// `FA` or `GRGW`.
+	///
+	/// Returns
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if the string is malformed,
+	/// [`co::ERROR::NONE_MAPPED`](crate::co::ERROR::NONE_MAPPED) if a SID alias
+	/// is unknown, or [`co::ERROR::INVALID_SID`](crate::co::ERROR::INVALID_SID)
+	/// if a SID is malformed.
+	pub fn parse_sddl(sddl: &str) -> SysResult<Self> {
+		let mut sd = Self::default();
+		let mut rest = sddl.trim();
+
+		while !rest.is_empty() {
+			let tag = rest.get(..2).ok_or(co::ERROR::INVALID_PARAMETER)?;
+			let after = &rest[2..];
+			rest = match tag {
+				"O:" | "G:" => {
+					let (sid_str, after) = split_sid_token(after)?;
+					let sid = sid_from_sddl(sid_str)?;
+					if tag == "O:" { sd.owner = Some(sid) } else { sd.group = Some(sid) }
+					after
+				},
+				"D:" => {
+					let (acl, after) = parse_sddl_acl(after, &mut sd.control, &DACL_FLAGS)?;
+					sd.control |= co::SE::DACL_PRESENT;
+					sd.dacl = acl;
+					after
+				},
+				"S:" => {
+					let (acl, after) = parse_sddl_acl(after, &mut sd.control, &SACL_FLAGS)?;
+					sd.control |= co::SE::SACL_PRESENT;
+					sd.sacl = acl;
+					after
+				},
+				_ => return Err(co::ERROR::INVALID_PARAMETER),
+			};
+		}
+		Ok(sd)
+	}
+
+	/// Serializes the descriptor into the self-relative binary layout, which
+	/// can be passed to
+	/// [`SECURITY_ATTRIBUTES`](crate::SECURITY_ATTRIBUTES) and the security
+	/// functions.
+	///
+	/// The SACL, the DACL, the owner and the group are written in this order,
+	/// as Windows does.
+	#[must_use]
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let mut control = self.control | co::SE::SELF_RELATIVE;
+		if self.dacl.is_some() {
+			control |= co::SE::DACL_PRESENT;
+		}
+		if self.sacl.is_some() {
+			control |= co::SE::SACL_PRESENT;
+		}
+
+		let mut buf = vec![0u8; 20];
+		buf[0] = 1; // revision
+		buf[2..4].copy_from_slice(&control.raw().to_le_bytes());
+
+		let blocks = [
+			(12, self.sacl.as_ref().map(acl_to_bytes)),
+			(16, self.dacl.as_ref().map(acl_to_bytes)),
+			(4, self.owner.as_ref().map(|sid| sid.to_bytes())),
+			(8, self.group.as_ref().map(|sid| sid.to_bytes())),
+		];
+		for (field, block) in blocks.into_iter() {
+			if let Some(block) = block {
+				let off = buf.len() as u32;
+				buf[field..field + 4].copy_from_slice(&off.to_le_bytes());
+				buf.extend_from_slice(&block);
+			}
+		}
+		buf
+	}
+
+	/// Serializes the descriptor into an
+	/// [SDDL](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-string-format)
+	/// string, using the well-known SID aliases and the access right codes
+	/// when possible.
+	#[must_use]
+	pub fn to_sddl(&self) -> String {
+		let mut buf = String::new();
+		if let Some(owner) = &self.owner {
+			buf.push_str("O:");
+			buf.push_str(&sid_to_sddl(owner));
+		}
+		if let Some(group) = &self.group {
+			buf.push_str("G:");
+			buf.push_str(&sid_to_sddl(group));
+		}
+		if self.dacl.is_some() || self.control.has(co::SE::DACL_PRESENT) {
+			buf.push_str("D:");
+			push_sddl_acl(&mut buf, self.dacl.as_ref(), self.control, &DACL_FLAGS);
+		}
+		if self.sacl.is_some() || self.control.has(co::SE::SACL_PRESENT) {
+			buf.push_str("S:");
+			push_sddl_acl(&mut buf, self.sacl.as_ref(), self.control, &SACL_FLAGS);
+		}
+		buf
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// SDDL flags of the DACL: protected, auto-inherit required, auto-inherited.
+const DACL_FLAGS: [(&str, co::SE); 3] = [
+	("P", co::SE::DACL_PROTECTED),
+	("AR", co::SE::DACL_AUTO_INHERIT_REQ),
+	("AI", co::SE::DACL_AUTO_INHERITED),
+];
+
+/// SDDL flags of the SACL: protected, auto-inherit required, auto-inherited.
+const SACL_FLAGS: [(&str, co::SE); 3] = [
+	("P", co::SE::SACL_PROTECTED),
+	("AR", co::SE::SACL_AUTO_INHERIT_REQ),
+	("AI", co::SE::SACL_AUTO_INHERITED),
+];
+
+const ACE_TYPES: [(&str, co::ACE_TYPE); 9] = [
+	("A", co::ACE_TYPE::ACCESS_ALLOWED),
+	("D", co::ACE_TYPE::ACCESS_DENIED),
+	("AU", co::ACE_TYPE::SYSTEM_AUDIT),
+	("AL", co::ACE_TYPE::SYSTEM_ALARM),
+	("OA", co::ACE_TYPE::ACCESS_ALLOWED_OBJECT),
+	("OD", co::ACE_TYPE::ACCESS_DENIED_OBJECT),
+	("OU", co::ACE_TYPE::SYSTEM_AUDIT_OBJECT),
+	("OL", co::ACE_TYPE::SYSTEM_ALARM_OBJECT),
+	("ML", co::ACE_TYPE::SYSTEM_MANDATORY_LABEL),
+];
+
+const ACE_FLAGS: [(&str, co::ACE); 7] = [
+	("OI", co::ACE::OBJECT_INHERIT),
+	("CI", co::ACE::CONTAINER_INHERIT),
+	("NP", co::ACE::NO_PROPAGATE_INHERIT),
+	("IO", co::ACE::INHERIT_ONLY),
+	("ID", co::ACE::INHERITED),
+	("SA", co::ACE::SUCCESSFUL_ACCESS),
+	("FA", co::ACE::FAILED_ACCESS),
+];
+
+/// Access rights which are written only on an exact match.
+const RIGHTS_EXACT: [(&str, u32); 8] = [
+	("FA", 0x001f_01ff),
+	("FR", 0x0012_0089),
+	("FW", 0x0012_0116),
+	("FX", 0x0012_00a0),
+	("KA", 0x000f_003f),
+	("KR", 0x0002_0019),
+	("KW", 0x0002_0006),
+	("KX", 0x0002_0019),
+];
+
+/// Access rights which are combined, in the order they are written.
+const RIGHTS_BITS: [(&str, u32); 17] = [
+	("GA", 0x1000_0000),
+	("GR", 0x8000_0000),
+	("GW", 0x4000_0000),
+	("GX", 0x2000_0000),
+	("CC", 0x0000_0001),
+	("DC", 0x0000_0002),
+	("LC", 0x0000_0004),
+	("SW", 0x0000_0008),
+	("RP", 0x0000_0010),
+	("WP", 0x0000_0020),
+	("DT", 0x0000_0040),
+	("LO", 0x0000_0080),
+	("CR", 0x0000_0100),
+	("SD", 0x0001_0000),
+	("RC", 0x0002_0000),
+	("WD", 0x0004_0000),
+	("WO", 0x0008_0000),
+];
+
+/// Mandatory label policy rights, only for
+/// [`co::ACE_TYPE::SYSTEM_MANDATORY_LABEL`](crate::co::ACE_TYPE::SYSTEM_MANDATORY_LABEL).
+const RIGHTS_LABEL: [(&str, u32); 3] = [
+	("NR", 0x0000_0002), // no read up
+	("NW", 0x0000_0001), // no write up
+	("NX", 0x0000_0004), // no execute up
+];
+
+/// Well-known SID aliases which don't depend on a domain, with their
+/// identifier authority and sub-authorities.
+const SID_ALIASES: [(&str, u64, &[u32]); 48] = [
+	("AA", 5, &[32, 579]),
+	("AC", 15, &[2, 1]),
+	("AN", 5, &[7]),
+	("AO", 5, &[32, 548]),
+	("AS", 18, &[1]),
+	("AU", 5, &[11]),
+	("BA", 5, &[32, 544]),
+	("BG", 5, &[32, 546]),
+	("BO", 5, &[32, 551]),
+	("BU", 5, &[32, 545]),
+	("CD", 5, &[32, 574]),
+	("CG", 3, &[1]),
+	("CO", 3, &[0]),
+	("CY", 5, &[32, 569]),
+	("ED", 5, &[9]),
+	("ER", 5, &[32, 573]),
+	("ES", 5, &[32, 576]),
+	("HA", 5, &[32, 578]),
+	("HI", 16, &[12288]),
+	("IS", 5, &[32, 568]),
+	("IU", 5, &[4]),
+	("LS", 5, &[19]),
+	("LU", 5, &[32, 559]),
+	("LW", 16, &[4096]),
+	("ME", 16, &[8192]),
+	("MP", 16, &[8448]),
+	("MU", 5, &[32, 558]),
+	("NO", 5, &[32, 556]),
+	("NS", 5, &[20]),
+	("NU", 5, &[2]),
+	("OW", 3, &[4]),
+	("PO", 5, &[32, 550]),
+	("PS", 5, &[10]),
+	("PU", 5, &[32, 547]),
+	("RA", 5, &[32, 575]),
+	("RC", 5, &[12]),
+	("RD", 5, &[32, 555]),
+	("RE", 5, &[32, 552]),
+	("RM", 5, &[32, 580]),
+	("RU", 5, &[32, 554]),
+	("SI", 16, &[16384]),
+	("SO", 5, &[32, 549]),
+	("SS", 18, &[2]),
+	("SU", 5, &[6]),
+	("SY", 5, &[18]),
+	("UD", 5, &[84, 0, 0, 0, 0, 0]),
+	("WD", 1, &[0]),
+	("WR", 5, &[33]),
+];
+
+/// Returns the SID of an alias, or parses an `S-1-...` string.
+fn sid_from_sddl(s: &str) -> SysResult<Sid> {
+	if s.len() > 2 {
+		return Sid::parse(s);
+	}
+	let alias = s.to_ascii_uppercase();
+	SID_ALIASES.iter()
+		.find(|(name, _, _)| *name == alias)
+		.map(|(_, auth, subs)| Sid::from_parts(*auth, subs))
+		.ok_or(co::ERROR::NONE_MAPPED)
+}
+
+/// Returns the alias of the SID, if any, or its `S-1-...` string.
+fn sid_to_sddl(sid: &Sid) -> String {
+	SID_ALIASES.iter()
+		.find(|(_, auth, subs)| Sid::from_parts(*auth, subs) == *sid)
+		.map(|(name, _, _)| (*name).to_owned())
+		.unwrap_or_else(|| sid.to_string())
+}
+
+/// Splits the owner or group SID from the rest of the string, which goes on
+/// with another `X:` tag.
+fn split_sid_token(s: &str) -> SysResult<(&str, &str)> {
+	let bytes = s.as_bytes();
+	if bytes.len() < 2 || !(bytes[0] == b'S' || bytes[0] == b's') || bytes[1] != b'-' {
+		let alias = s.get(..2) // two-letter alias
+			.filter(|alias| alias.is_ascii())
+			.ok_or(co::ERROR::INVALID_PARAMETER)?;
+		return Ok((alias, &s[2..]));
+	}
+
+	let mut idx = 1;
+	while idx < bytes.len() && bytes[idx] == b'-' {
+		let start = idx + 1;
+		let mut end = start;
+		if bytes[start..].starts_with(b"0x") || bytes[start..].starts_with(b"0X") {
+			end += 2;
+			while end < bytes.len() && bytes[end].is_ascii_hexdigit() {
+				end += 1;
+			}
+		} else {
+			while end < bytes.len() && bytes[end].is_ascii_digit() {
+				end += 1;
+			}
+		}
+		if end == start {
+			break; // dash not followed by a number
+		}
+		idx = end;
+	}
+	Ok(s.split_at(idx))
+}
+
+/// Parses the flags and the entries of an ACL, until the next tag.
+fn parse_sddl_acl<'a>(
+	s: &'a str,
+	control: &mut co::SE,
+	flags: &[(&str, co::SE); 3],
+) -> SysResult<(Option<Acl>, &'a str)>
+{
+	let mut rest = s;
+	let mut null_acl = false;
+	'flags: loop {
+		if let Some(after) = rest.strip_prefix("NO_ACCESS_CONTROL") {
+			null_acl = true;
+			rest = after;
+			continue;
+		}
+		for (code, flag) in flags.iter() {
+			if let Some(after) = rest.strip_prefix(code) {
+				*control |= *flag;
+				rest = after;
+				continue 'flags;
+			}
+		}
+		break;
+	}
+
+	let mut acl = Acl::new();
+	while let Some(after) = rest.strip_prefix('(') {
+		let idx_end = after.find(')').ok_or(co::ERROR::INVALID_PARAMETER)?;
+		acl.aces.push(parse_sddl_ace(&after[..idx_end])?);
+		rest = &after[idx_end + 1..];
+	}
+
+	if null_acl {
+		if !acl.aces.is_empty() {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+		Ok((None, rest))
+	} else {
+		Ok((Some(acl), rest))
+	}
+}
+
+/// Parses the contents of an ACE between parentheses.
+fn parse_sddl_ace(s: &str) -> SysResult<Ace> {
+	let fields = s.split(';').collect::<Vec<_>>();
+	if fields.len() != 6 { // resource attributes are not supported
+		return Err(co::ERROR::INVALID_PARAMETER);
+	}
+
+	let ace_type = ACE_TYPES.iter()
+		.find(|(code, _)| code.eq_ignore_ascii_case(fields[0]))
+		.map(|(_, ace_type)| *ace_type)
+		.ok_or(co::ERROR::INVALID_PARAMETER)?;
+
+	let mut flags = co::ACE::NoValue;
+	for code in split_codes(fields[1])? {
+		flags |= ACE_FLAGS.iter()
+			.find(|(name, _)| name.eq_ignore_ascii_case(code))
+			.map(|(_, flag)| *flag)
+			.ok_or(co::ERROR::INVALID_PARAMETER)?;
+	}
+
+	let mut ace = Ace::new(ace_type, flags, 0, sid_from_sddl(fields[5])?);
+	ace.mask = parse_sddl_rights(fields[2], ace_type == co::ACE_TYPE::SYSTEM_MANDATORY_LABEL)?;
+
+	if ace.is_object() {
+		ace.object_type = parse_guid(fields[3])?;
+		ace.inherited_object_type = parse_guid(fields[4])?;
+	} else if !fields[3].is_empty() || !fields[4].is_empty() {
+		return Err(co::ERROR::INVALID_PARAMETER);
+	}
+	Ok(ace)
+}
+
+/// Parses an access mask, either a number or a sequence of codes.
+fn parse_sddl_rights(s: &str, is_label: bool) -> SysResult<u32> {
+	if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+		return u32::from_str_radix(hex, 16).map_err(|_| co::ERROR::INVALID_PARAMETER);
+	}
+	if !s.is_empty() && s.bytes().all(|ch| ch.is_ascii_digit()) {
+		return s.parse::<u32>().map_err(|_| co::ERROR::INVALID_PARAMETER);
+	}
+
+	let table: &[(&str, u32)] = if is_label { &RIGHTS_LABEL } else { &RIGHTS_BITS };
+	let mut mask = 0;
+	for code in split_codes(s)? {
+		mask |= table.iter()
+			.chain(if is_label { [].iter() } else { RIGHTS_EXACT.iter() })
+			.find(|(name, _)| name.eq_ignore_ascii_case(code))
+			.map(|(_, bits)| *bits)
+			.ok_or(co::ERROR::INVALID_PARAMETER)?;
+	}
+	Ok(mask)
+}
+
+/// Splits a sequence of two-letter codes.
+fn split_codes(s: &str) -> SysResult<impl Iterator<Item = &str>> {
+	if s.len() % 2 != 0 || !s.is_ascii() {
+		return Err(co::ERROR::INVALID_PARAMETER);
+	}
+	Ok((0..s.len()).step_by(2).map(move |idx| &s[idx..idx + 2]))
+}
+
+/// Parses an optional GUID, in the `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`
+/// format.
+fn parse_guid(s: &str) -> SysResult<Option<GUID>> {
+	if s.is_empty() {
+		return Ok(None);
+	}
+	let valid = s.len() == 36
+		&& s.bytes().enumerate().all(|(idx, ch)| match idx {
+			8 | 13 | 18 | 23 => ch == b'-',
+			_ => ch.is_ascii_hexdigit(),
+		});
+	if valid {
+		Ok(Some(GUID::new(s))) // format already validated, won't panic
+	} else {
+		Err(co::ERROR::INVALID_PARAMETER)
+	}
+}
+
+fn push_sddl_acl(
+	buf: &mut String,
+	acl: Option<&Acl>,
+	control: co::SE,
+	flags: &[(&str, co::SE); 3],
+) {
+	for (code, flag) in flags.iter() {
+		if control.has(*flag) {
+			buf.push_str(code);
+		}
+	}
+
+	match acl {
+		None => buf.push_str("NO_ACCESS_CONTROL"),
+		Some(acl) => for ace in acl.aces.iter() {
+			buf.push('(');
+			buf.push_str(ACE_TYPES.iter()
+				.find(|(_, ace_type)| *ace_type == ace.ace_type)
+				.map_or("A", |(code, _)| code));
+			buf.push(';');
+			for (code, flag) in ACE_FLAGS.iter() {
+				if ace.flags.has(*flag) {
+					buf.push_str(code);
+				}
+			}
+			buf.push(';');
+			push_sddl_rights(buf, ace.mask, ace.ace_type == co::ACE_TYPE::SYSTEM_MANDATORY_LABEL);
+			buf.push(';');
+			if let Some(guid) = &ace.object_type {
+				buf.push_str(&guid.to_string());
+			}
+			buf.push(';');
+			if let Some(guid) = &ace.inherited_object_type {
+				buf.push_str(&guid.to_string());
+			}
+			buf.push(';');
+			buf.push_str(&sid_to_sddl(&ace.sid));
+			buf.push(')');
+		},
+	}
+}
+
+fn push_sddl_rights(buf: &mut String, mask: u32, is_label: bool) {
+	if !is_label {
+		if let Some((code, _)) = RIGHTS_EXACT.iter().find(|(_, bits)| *bits == mask) {
+			buf.push_str(code);
+			return;
+		}
+	}
+
+	let table: &[(&str, u32)] = if is_label { &RIGHTS_LABEL } else { &RIGHTS_BITS };
+	let known = table.iter().fold(0, |acc, (_, bits)| acc | bits);
+	if mask == 0 || mask & !known != 0 {
+		buf.push_str(&format!("0x{:x}", mask));
+	} else {
+		for (code, bits) in table.iter() {
+			if mask & bits != 0 {
+				buf.push_str(code);
+			}
+		}
+	}
+}
+
+fn acl_from_bytes(data: &[u8]) -> SysResult<Acl> {
+	if data.len() < 8 || (data[0] != 2 && data[0] != 4) {
+		return Err(co::ERROR::INVALID_ACL);
+	}
+	let acl_size = u16::from_le_bytes([data[2], data[3]]) as usize;
+	let ace_count = u16::from_le_bytes([data[4], data[5]]) as usize;
+	let data = data.get(..acl_size).ok_or(co::ERROR::INVALID_ACL)?;
+
+	let mut acl = Acl::new();
+	let mut off = 8;
+	for _ in 0..ace_count {
+		let header = data.get(off..off + 4).ok_or(co::ERROR::INVALID_ACL)?;
+		let ace_size = u16::from_le_bytes([header[2], header[3]]) as usize;
+		let body = data.get(off + 4..off + ace_size).ok_or(co::ERROR::INVALID_ACL)?;
+		acl.aces.push(ace_from_bytes(header[0], header[1], body)?);
+		off += ace_size;
+	}
+	Ok(acl)
+}
+
+fn ace_from_bytes(ace_type: u8, flags: u8, body: &[u8]) -> SysResult<Ace> {
+	let ace_type = unsafe { co::ACE_TYPE::from_raw(ace_type) };
+	if !ACE_TYPES.iter().any(|(_, t)| *t == ace_type) || body.len() < 4 {
+		return Err(co::ERROR::INVALID_ACL);
+	}
+	let mask = u32::from_le_bytes(body[..4].try_into().unwrap());
+	let mut ace = Ace::new(ace_type, unsafe { co::ACE::from_raw(flags) }, mask, Sid::from_parts(0, &[]));
+
+	let mut off = 4;
+	if ace.is_object() {
+		let obj_flags = u32::from_le_bytes(
+			body.get(4..8).ok_or(co::ERROR::INVALID_ACL)?.try_into().unwrap());
+		off = 8;
+		let mut read_guid = |present: bool| -> SysResult<Option<GUID>> {
+			if present {
+				let raw = body.get(off..off + 16).ok_or(co::ERROR::INVALID_ACL)?;
+				off += 16;
+				Ok(Some(unsafe { std::ptr::read_unaligned(raw.as_ptr() as *const GUID) }))
+			} else {
+				Ok(None)
+			}
+		};
+		ace.object_type = read_guid(obj_flags & 0x1 != 0)?; // ACE_OBJECT_TYPE_PRESENT
+		ace.inherited_object_type = read_guid(obj_flags & 0x2 != 0)?; // ACE_INHERITED_OBJECT_TYPE_PRESENT
+	}
+
+	ace.sid = Sid::from_bytes_prefix(&body[off..])?.0;
+	Ok(ace)
+}
+
+fn acl_to_bytes(acl: &Acl) -> Vec<u8> {
+	let has_object = acl.aces.iter().any(|ace| ace.is_object());
+	let mut buf = vec![
+		if has_object { 4 } else { 2 }, // ACL_REVISION_DS or ACL_REVISION
+		0, 0, 0,
+	];
+	buf.extend_from_slice(&(acl.aces.len() as u16).to_le_bytes());
+	buf.extend_from_slice(&[0, 0]);
+
+	for ace in acl.aces.iter() {
+		let idx_ace = buf.len();
+		buf.extend_from_slice(&[ace.ace_type.raw(), ace.flags.raw(), 0, 0]);
+		buf.extend_from_slice(&ace.mask.to_le_bytes());
+		if ace.is_object() {
+			let obj_flags = ace.object_type.map_or(0, |_| 0x1)
+				| ace.inherited_object_type.map_or(0, |_| 0x2);
+			buf.extend_from_slice(&(obj_flags as u32).to_le_bytes());
+			for guid in [&ace.object_type, &ace.inherited_object_type].into_iter().flatten() {
+				buf.extend_from_slice(unsafe {
+					std::slice::from_raw_parts(guid as *const GUID as *const u8, 16)
+				});
+			}
+		}
+		buf.extend_from_slice(&ace.sid.to_bytes());
+		let ace_size = (buf.len() - idx_ace) as u16;
+		buf[idx_ace + 2..idx_ace + 4].copy_from_slice(&ace_size.to_le_bytes());
+	}
+
+	let acl_size = buf.len() as u16;
+	buf[2..4].copy_from_slice(&acl_size.to_le_bytes());
+	buf
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn sddl_round_trip() {
+		let sddl = "O:BAG:SYD:PAI(A;OICI;FA;;;SY)(D;;GRGW;;;S-1-5-21-1-2-3-500)S:(ML;;NW;;;LW)";
+		let sd = SecurityDescriptor::parse_sddl(sddl).unwrap();
+		assert_eq!(sd.owner, Some(Sid::from_parts(5, &[32, 544])));
+		assert_eq!(sd.group, Some(Sid::from_parts(5, &[18])));
+		assert!(sd.control.has(co::SE::DACL_PROTECTED | co::SE::DACL_AUTO_INHERITED));
+
+		let dacl = sd.dacl.as_ref().unwrap();
+		assert_eq!(dacl.aces.len(), 2);
+		assert_eq!(dacl.aces[0].flags, co::ACE::OBJECT_INHERIT | co::ACE::CONTAINER_INHERIT);
+		assert_eq!(dacl.aces[0].mask, 0x001f_01ff);
+		assert_eq!(dacl.aces[1].ace_type, co::ACE_TYPE::ACCESS_DENIED);
+		assert_eq!(dacl.aces[1].mask, 0x8000_0000 | 0x4000_0000);
+		assert_eq!(dacl.aces[1].sid, Sid::from_parts(5, &[21, 1, 2, 3, 500]));
+		assert_eq!(sd.sacl.as_ref().unwrap().aces[0].mask, 0x1);
+
+		assert_eq!(sd.to_sddl(), sddl);
+		assert_eq!(SecurityDescriptor::from_bytes(&sd.to_bytes()).unwrap(), sd);
+	}
+
+	#[test]
+	fn object_aces_and_null_dacl() {
+		let sddl = "D:(OA;CI;RPWP;bf967aba-0de6-11d0-a285-00aa003049e2;;AU)";
+		let sd = SecurityDescriptor::parse_sddl(sddl).unwrap();
+		let ace = &sd.dacl.as_ref().unwrap().aces[0];
+		assert!(ace.object_type.is_some());
+		assert_eq!(ace.inherited_object_type, None);
+		assert_eq!(SecurityDescriptor::parse_sddl(&sd.to_sddl()).unwrap(), sd);
+		assert_eq!(SecurityDescriptor::from_bytes(&sd.to_bytes()).unwrap(), sd);
+
+		let sd = SecurityDescriptor::parse_sddl("D:NO_ACCESS_CONTROL").unwrap();
+		assert_eq!(sd.dacl, None);
+		assert!(sd.control.has(co::SE::DACL_PRESENT));
+		assert_eq!(sd.to_sddl(), "D:NO_ACCESS_CONTROL");
+	}
+
+	#[test]
+	fn malformed_sddl() {
+		for sddl in [
+			"O:a\u{e9}", "O:\u{e9}a", "O:", "O:B", "O", "X:BA", "O:S-1-5-x",
+			"D:(A;;FA;;SY)", "D:(A;;FA;;;SY", "D:(Q;;FA;;;SY)", "D:(A;XX;FA;;;SY)",
+			"D:(A;;F;;;SY)", "D:(A;;FA\u{e9};;;SY)", "D:(A;;0xZZ;;;SY)",
+			"D:NO_ACCESS_CONTROL(A;;FA;;;SY)",
+			"D:(A;;FA;bf967aba-0de6-11d0-a285-00aa003049e2;;SY)",
+			"D:(OA;;FA;bf967aba-0de6-11d0;;SY)",
+		] {
+			assert_eq!(SecurityDescriptor::parse_sddl(sddl), Err(co::ERROR::INVALID_PARAMETER), "{}", sddl);
+		}
+		assert_eq!(SecurityDescriptor::parse_sddl("O:ZZ"), Err(co::ERROR::NONE_MAPPED));
+		assert_eq!(SecurityDescriptor::parse_sddl("D:(A;;FA;;;S-1-x)"), Err(co::ERROR::INVALID_SID));
+	}
+
+	#[test]
+	fn malformed_bytes() {
+		let sd = SecurityDescriptor::parse_sddl("O:SYD:(A;;FA;;;BA)").unwrap();
+		let data = sd.to_bytes();
+		for len in 0..data.len() {
+			assert!(SecurityDescriptor::from_bytes(&data[..len]).is_err(), "{}", len);
+		}
+		let mut bad_offset = data.clone();
+		bad_offset[4..8].copy_from_slice(&0xffff_u32.to_le_bytes());
+		assert_eq!(SecurityDescriptor::from_bytes(&bad_offset), Err(co::ERROR::INVALID_SECURITY_DESCR));
+	}
+}
@@ -0,0 +1,352 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::Deref;
+
+use crate::co;
+use crate::decl::*;
+
+/// An owned security identifier, with its string and binary conversions
+/// implemented in pure Rust.
+///
+/// Unlike [`SID`](crate::SID), which must be allocated by the OS or by a
+/// guard, this is an ordinary value type, which can be compared, sorted and
+/// hashed. It dereferences to [`SID`](crate::SID), so it can be passed to any
+/// function which takes a `&SID`.
+///
+/// SIDs are ordered by their identifier authority, then by their
+/// sub-authorities.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let sid = w::Sid::parse("S-1-5-21-1004336348-1177238915-682003330-512")?;
+/// println!("{} {:?}", sid.authority(), sid.rid()); // 5 Some(512)
+///
+/// let raw = sid.to_bytes();
+/// let back = w::Sid::from_bytes(&raw)?;
+/// assert_eq!(sid, back);
+///
+/// let admins = w::Sid::new(5, &[32, 544])?; // BUILTIN\Administrators
+/// let same = w::EqualSid(&admins, &w::ConvertStringSidToSid("BA")?)?;
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Sid {
+	buf: Vec<u32>, // memory layout of a SID struct, aligned
+}
+
+impl Deref for Sid {
+	type Target = SID;
+
+	fn deref(&self) -> &Self::Target {
+		unsafe { &*(self.buf.as_ptr() as *const SID) }
+	}
+}
+
+impl fmt::Debug for Sid {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "Sid({})", self)
+	}
+}
+
+impl fmt::Display for Sid {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let auth = self.authority();
+		if auth >= 1 << 32 {
+			write!(f, "S-1-0x{:012X}", auth)?; // same format of ConvertSidToStringSid
+		} else {
+			write!(f, "S-1-{}", auth)?;
+		}
+		for sub_auth in self.sub_authorities().iter() {
+			write!(f, "-{}", sub_auth)?;
+		}
+		Ok(())
+	}
+}
+
+impl Ord for Sid {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.authority().cmp(&other.authority())
+			.then_with(|| self.sub_authorities().cmp(other.sub_authorities()))
+	}
+}
+
+impl PartialOrd for Sid {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl From<&SID> for Sid {
+	fn from(sid: &SID) -> Self {
+		let mut auth_buf = [0u8; 8];
+		auth_buf[2..].copy_from_slice(&sid.IdentifierAuthority.Value);
+		let sub_authorities = sid.SubAuthority().iter()
+			.map(|rid| rid.raw())
+			.collect::<Vec<_>>();
+		Self::from_parts(u64::from_be_bytes(auth_buf), &sub_authorities)
+	}
+}
+
+impl Sid {
+	/// Maximum number of sub-authorities in a SID.
+	pub const MAX_SUB_AUTHORITIES: usize = 15;
+
+	/// Creates a new SID from its identifier authority and sub-authorities.
+	///
+	/// Returns [`co::ERROR::INVALID_SID`](crate::co::ERROR::INVALID_SID) if
+	/// the authority doesn't fit in 48 bits, or if there are more than
+	/// [`MAX_SUB_AUTHORITIES`](crate::Sid::MAX_SUB_AUTHORITIES).
+	pub fn new(authority: u64, sub_authorities: &[u32]) -> SysResult<Self> {
+		if authority >= 1 << 48 || sub_authorities.len() > Self::MAX_SUB_AUTHORITIES {
+			Err(co::ERROR::INVALID_SID)
+		} else {
+			Ok(Self::from_parts(authority, sub_authorities))
+		}
+	}
+
+	/// Returns the identifier authority, which has 48 bits.
+	#[must_use]
+	pub fn authority(&self) -> u64 {
+		let mut auth_buf = [0u8; 8];
+		auth_buf[2..].copy_from_slice(&self.IdentifierAuthority.Value);
+		u64::from_be_bytes(auth_buf)
+	}
+
+	/// Parses a SID from its binary representation, which must be exactly
+	/// the size declared by its sub-authority count.
+	///
+	/// Returns [`co::ERROR::INVALID_SID`](crate::co::ERROR::INVALID_SID) if
+	/// the data is malformed.
+	pub fn from_bytes(data: &[u8]) -> SysResult<Self> {
+		match Self::from_bytes_prefix(data)? {
+			(sid, len) if len == data.len() => Ok(sid),
+			_ => Err(co::ERROR::INVALID_SID),
+		}
+	}
+
+	/// Parses a SID from a string like `S-1-5-32-544`. The identifier
+	/// authority may also be written in hex, like `S-1-0x000000000005-18`.
+	///
+	/// Unlike [`ConvertStringSidToSid`](crate::ConvertStringSidToSid), this
+	/// function doesn't accept the two-letter aliases, like `BA`.
+	///
+	/// Returns [`co::ERROR::INVALID_SID`](crate::co::ERROR::INVALID_SID) if
+	/// the string is malformed.
+	pub fn parse(sid_str: &str) -> SysResult<Self> {
+		let mut parts = sid_str.split('-');
+		if !parts.next().is_some_and(|s| s.eq_ignore_ascii_case("S"))
+			|| parts.next() != Some("1") // revision
+		{
+			return Err(co::ERROR::INVALID_SID);
+		}
+
+		let auth = parts.next()
+			.and_then(|s| match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+				Some(hex) if hex.bytes().all(|ch| ch.is_ascii_hexdigit()) =>
+					u64::from_str_radix(hex, 16).ok(),
+				None if s.bytes().all(|ch| ch.is_ascii_digit()) => s.parse::<u64>().ok(),
+				_ => None, // signs are not accepted
+			})
+			.ok_or(co::ERROR::INVALID_SID)?;
+
+		let sub_authorities = parts
+			.map(|s| if s.bytes().all(|ch| ch.is_ascii_digit()) { s.parse::<u32>().ok() } else { None })
+			.collect::<Option<Vec<_>>>()
+			.ok_or(co::ERROR::INVALID_SID)?;
+
+		Self::new(auth, &sub_authorities)
+	}
+
+	/// Returns the relative identifier, which is the last sub-authority, if
+	/// any.
+	#[must_use]
+	pub fn rid(&self) -> Option<u32> {
+		self.sub_authorities().last().copied()
+	}
+
+	/// Returns the sub-authorities.
+	#[must_use]
+	pub fn sub_authorities(&self) -> &[u32] {
+		let count = self.buf[0].to_ne_bytes()[1] as usize;
+		&self.buf[2..2 + count]
+	}
+
+	/// Serializes the SID into its binary representation.
+	#[must_use]
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let mut buf = Vec::with_capacity(self.buf.len() * 4);
+		buf.push(1); // revision
+		buf.push(self.sub_authorities().len() as _);
+		buf.extend_from_slice(&self.IdentifierAuthority.Value);
+		for sub_auth in self.sub_authorities().iter() {
+			buf.extend_from_slice(&sub_auth.to_le_bytes());
+		}
+		buf
+	}
+
+	/// Creates a SID from an authority and sub-authorities already validated.
+	pub(in crate::kernel) fn from_parts(authority: u64, sub_authorities: &[u32]) -> Self {
+		let auth = authority.to_be_bytes();
+		let mut buf = Vec::with_capacity(3 + sub_authorities.len());
+		buf.push(u32::from_ne_bytes([1, sub_authorities.len() as _, auth[2], auth[3]])); // revision 1
+		buf.push(u32::from_ne_bytes([auth[4], auth[5], auth[6], auth[7]]));
+		buf.extend_from_slice(sub_authorities);
+		if sub_authorities.is_empty() {
+			buf.push(0); // SID struct declares 1 sub-authority
+		}
+		Self { buf }
+	}
+
+	/// Parses a SID at the beginning of `data`, returning it and its length.
+	pub(in crate::kernel) fn from_bytes_prefix(data: &[u8]) -> SysResult<(Self, usize)> {
+		if data.len() < 8 || data[0] != 1 || data[1] as usize > Self::MAX_SUB_AUTHORITIES {
+			return Err(co::ERROR::INVALID_SID);
+		}
+		let len = 8 + data[1] as usize * 4;
+		let subs_data = data.get(8..len).ok_or(co::ERROR::INVALID_SID)?;
+
+		let mut auth_buf = [0u8; 8];
+		auth_buf[2..].copy_from_slice(&data[2..8]);
+		let sub_authorities = subs_data.chunks_exact(4)
+			.map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+			.collect::<Vec<_>>();
+		Ok((Self::from_parts(u64::from_be_bytes(auth_buf), &sub_authorities), len))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_and_display() {
+		for s in [
+			"S-1-5-21-1004336348-1177238915-682003330-512",
+			"S-1-5-32-544",
+			"S-1-0-0",
+			"S-1-16-12288",
+			"S-1-4294967295-4294967295",
+			"S-1-0x000100000000-1", // authorities above 32 bits are written in hex
+			"S-1-0xFFFFFFFFFFFF",
+		] {
+			assert_eq!(Sid::parse(s).unwrap().to_string(), s);
+		}
+
+		let sid = Sid::parse("s-1-0x000000000005-18").unwrap();
+		assert_eq!(sid.to_string(), "S-1-5-18");
+		assert_eq!(sid.authority(), 5);
+		assert_eq!(sid.sub_authorities(), [18]);
+		assert_eq!(sid.rid(), Some(18));
+		assert_eq!(format!("{:?}", sid), "Sid(S-1-5-18)");
+		assert_eq!(Sid::parse("S-1-0X0a-1").unwrap().authority(), 10);
+		assert_eq!(Sid::parse("S-1-005-00018").unwrap(), sid);
+	}
+
+	#[test]
+	fn malformed_strings() {
+		for s in [
+			"",
+			"S",
+			"S-1",
+			"S-2-5-18", // revision
+			"X-1-5-18",
+			"S-1-+5-18",
+			"S-1--5-18",
+			"S-1-5-+18",
+			"S-1-5--18",
+			"S-1-5-18-",
+			"S-1-5--",
+			"S-1--",
+			"S-1-0x",
+			"S-1-0x-1",
+			"S-1-0x1G",
+			"S-1-0x+1",
+			"S-1-5-4294967296", // sub-authority above 32 bits
+			"S-1-281474976710656", // authority above 48 bits
+			"S-1-0x1000000000000",
+			"S-1-5-1-2-3-4-5-6-7-8-9-10-11-12-13-14-15-16",
+			"S-1-5- 18",
+			"BA",
+		] {
+			assert_eq!(Sid::parse(s), Err(co::ERROR::INVALID_SID), "{}", s);
+		}
+		assert!(Sid::parse("S-1-5-1-2-3-4-5-6-7-8-9-10-11-12-13-14-15").is_ok());
+	}
+
+	#[test]
+	fn bytes() {
+		let sid = Sid::parse("S-1-5-32-544").unwrap();
+		let raw = sid.to_bytes();
+		assert_eq!(raw, [1, 2, 0, 0, 0, 0, 0, 5, 32, 0, 0, 0, 0x20, 0x02, 0, 0]);
+		assert_eq!(Sid::from_bytes(&raw).unwrap(), sid);
+		assert_eq!(Sid::from(&*sid), sid);
+
+		let big = Sid::parse("S-1-0x123456789ABC-1").unwrap();
+		assert_eq!(big.to_bytes()[2..8], [0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc]);
+		assert_eq!(Sid::from_bytes(&big.to_bytes()).unwrap(), big);
+
+		let mut trailing = raw.clone();
+		trailing.push(0);
+		assert_eq!(Sid::from_bytes(&trailing), Err(co::ERROR::INVALID_SID));
+		assert_eq!(Sid::from_bytes(&raw[..raw.len() - 1]), Err(co::ERROR::INVALID_SID));
+		assert_eq!(Sid::from_bytes(&raw[..7]), Err(co::ERROR::INVALID_SID));
+
+		let mut bad_revision = raw.clone();
+		bad_revision[0] = 2;
+		assert_eq!(Sid::from_bytes(&bad_revision), Err(co::ERROR::INVALID_SID));
+
+		let mut too_many = vec![1, 16, 0, 0, 0, 0, 0, 5];
+		too_many.resize(8 + 16 * 4, 0);
+		assert_eq!(Sid::from_bytes(&too_many), Err(co::ERROR::INVALID_SID));
+
+		let (prefix, len) = Sid::from_bytes_prefix(&trailing).unwrap();
+		assert_eq!((prefix, len), (sid, raw.len()));
+	}
+
+	#[test]
+	fn no_sub_authorities() {
+		let sid = Sid::new(5, &[]).unwrap();
+		assert_eq!(sid.sub_authorities(), []);
+		assert_eq!(sid.rid(), None);
+		assert_eq!(sid.to_string(), "S-1-5");
+		assert_eq!(Sid::parse("S-1-5").unwrap(), sid);
+
+		let raw = sid.to_bytes();
+		assert_eq!(raw, [1, 0, 0, 0, 0, 0, 0, 5]);
+		assert_eq!(Sid::from_bytes(&raw).unwrap(), sid);
+		assert_eq!(Sid::from(&*sid), sid);
+	}
+
+	#[test]
+	fn ordering() {
+		let mut sids = [
+			"S-1-16-12288",
+			"S-1-5-32-545",
+			"S-1-5-10",
+			"S-1-5-32",
+			"S-1-0x000100000000",
+			"S-1-5-9",
+			"S-1-5-32-544",
+			"S-1-5",
+			"S-1-1-0",
+		].map(|s| Sid::parse(s).unwrap());
+		sids.sort();
+		assert_eq!(sids.map(|sid| sid.to_string()), [
+			"S-1-1-0",
+			"S-1-5",
+			"S-1-5-9", // numeric, not lexicographic
+			"S-1-5-10",
+			"S-1-5-32",
+			"S-1-5-32-544",
+			"S-1-5-32-545",
+			"S-1-16-12288",
+			"S-1-0x000100000000",
+		]);
+
+		assert_eq!(Sid::new(1 << 48, &[]), Err(co::ERROR::INVALID_SID));
+		assert_eq!(Sid::new(5, &[0; 16]), Err(co::ERROR::INVALID_SID));
+	}
+}
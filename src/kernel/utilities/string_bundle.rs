@@ -0,0 +1,165 @@
+use crate::co;
+use crate::decl::*;
+use crate::kernel::utilities::le_bytes::u16_at;
+
+/// The contents of an `RT_STRING` resource, which is a bundle of 16 strings.
+///
+/// String IDs are grouped by 16: the bundle named `n` holds the strings with
+/// IDs from `(n - 1) * 16` to `(n - 1) * 16 + 15`. The resource name of the
+/// bundle which holds a given string is returned by
+/// [`StringBundle::bundle_name`](crate::StringBundle::bundle_name).
+///
+/// To load a single string from an executable, see
+/// [`PeImage::find_string`](crate::PeImage::find_string).
+///
+/// # Examples
+///
+/// Loading a string in a specific language:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// const IDS_TITLE: u16 = 40;
+///
+/// let hinst = w::HINSTANCE::GetModuleHandle(None)?;
+/// let hres = hinst.FindResourceEx(
+///     w::StringBundle::bundle_name(IDS_TITLE),
+///     w::RtStr::Rt(co::RT::STRING),
+///     Some(w::LANGID::new(co::LANG::PORTUGUESE, co::SUBLANG::PORTUGUESE_BRAZILIAN)),
+/// )?;
+/// let hres_load = hinst.LoadResource(&hres)?;
+/// let data = hinst.LockResource(&hres, &hres_load)?;
+///
+/// let bundle = w::StringBundle::from_bytes(data)?;
+/// println!("{}", bundle.string(IDS_TITLE));
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StringBundle {
+	/// The 16 strings of the bundle. Absent strings are empty.
+	pub strings: [String; 16],
+}
+
+impl StringBundle {
+	/// Returns the resource name of the bundle which holds the given string
+	/// ID.
+	#[must_use]
+	pub const fn bundle_name(string_id: u16) -> IdStr {
+		IdStr::Id((string_id >> 4) + 1)
+	}
+
+	/// Parses the contents of an `RT_STRING` resource.
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the data is malformed.
+	pub fn from_bytes(data: &[u8]) -> SysResult<Self> {
+		let mut bundle = Self::default();
+		let mut pos = 0;
+		for s in bundle.strings.iter_mut() {
+			let len = u16_at(data, pos)? as usize;
+			let chars = data.get(pos + 2..pos + 2 + len * 2)
+				.ok_or(co::ERROR::INVALID_DATA)?
+				.chunks_exact(2)
+				.map(|ch| u16::from_le_bytes([ch[0], ch[1]]))
+				.collect::<Vec<_>>();
+			*s = String::from_utf16_lossy(&chars);
+			pos += 2 + len * 2;
+		}
+		Ok(bundle)
+	}
+
+	/// Groups the given strings into bundles, returning each one along with
+	/// its resource name, sorted by name.
+	///
+	/// If an ID is repeated, the last string prevails.
+	#[must_use]
+	pub fn from_strings(strings: &[(u16, &str)]) -> Vec<(IdStr, Self)> {
+		let mut bundles = Vec::<(u16, Self)>::default();
+		for (string_id, text) in strings.iter() {
+			let name = (*string_id >> 4) + 1;
+			let idx = match bundles.binary_search_by_key(&name, |(n, _)| *n) {
+				Ok(idx) => idx,
+				Err(idx) => {
+					bundles.insert(idx, (name, Self::default()));
+					idx
+				},
+			};
+			bundles[idx].1.set_string(*string_id, text);
+		}
+		bundles.into_iter()
+			.map(|(name, bundle)| (IdStr::Id(name), bundle))
+			.collect()
+	}
+
+	/// Replaces the string with the given ID. Only the lower 4 bits of the ID
+	/// are used, since the bundle itself is not aware of its resource name.
+	pub fn set_string(&mut self, string_id: u16, text: &str) {
+		self.strings[(string_id & 0xf) as usize] = text.to_owned();
+	}
+
+	/// Returns the string with the given ID. Only the lower 4 bits of the ID
+	/// are used, since the bundle itself is not aware of its resource name.
+	#[must_use]
+	pub fn string(&self, string_id: u16) -> &str {
+		&self.strings[(string_id & 0xf) as usize]
+	}
+
+	/// Serializes the strings into the contents of an `RT_STRING` resource.
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// a string exceeds 65535 UTF-16 code units.
+	pub fn to_bytes(&self) -> SysResult<Vec<u8>> {
+		let mut buf = Vec::<u8>::default();
+		for s in self.strings.iter() {
+			let chars = s.encode_utf16().collect::<Vec<_>>();
+			let len = u16::try_from(chars.len())
+				.map_err(|_| co::ERROR::INVALID_DATA)?;
+			buf.extend_from_slice(&len.to_le_bytes());
+			buf.extend(chars.iter().flat_map(|ch| ch.to_le_bytes()));
+		}
+		Ok(buf)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn bundle_names() {
+		assert_eq!(StringBundle::bundle_name(0), IdStr::Id(1));
+		assert_eq!(StringBundle::bundle_name(15), IdStr::Id(1));
+		assert_eq!(StringBundle::bundle_name(40), IdStr::Id(3));
+		assert_eq!(StringBundle::bundle_name(0xffff), IdStr::Id(0x1000));
+
+		let bundles = StringBundle::from_strings(&[(40, "b"), (1, "a"), (41, "c"), (40, "d")]);
+		assert_eq!(bundles.len(), 2);
+		assert_eq!(bundles[0].0, IdStr::Id(1));
+		assert_eq!(bundles[0].1.string(1), "a");
+		assert_eq!(bundles[1].0, IdStr::Id(3));
+		assert_eq!(bundles[1].1.string(40), "d"); // last one prevails
+		assert_eq!(bundles[1].1.string(41), "c");
+		assert_eq!(bundles[1].1.string(42), "");
+	}
+
+	#[test]
+	fn round_trip() {
+		let mut bundle = StringBundle::default();
+		bundle.set_string(0x21, "Hi");
+		bundle.set_string(0x2f, "\u{e9}\u{1f600}");
+
+		let data = bundle.to_bytes().unwrap();
+		let mut expected = vec![0u8; 2]; // string 0
+		expected.extend_from_slice(&[2, 0, b'H', 0, b'i', 0]);
+		expected.extend_from_slice(&[0; 13 * 2]); // strings 2 to 14
+		expected.extend_from_slice(&[3, 0, 0xe9, 0, 0x3d, 0xd8, 0x00, 0xde]);
+		assert_eq!(data, expected);
+		assert_eq!(StringBundle::from_bytes(&data).unwrap(), bundle);
+
+		assert_eq!(StringBundle::from_bytes(&data[..data.len() - 1]), Err(co::ERROR::INVALID_DATA));
+		assert_eq!(StringBundle::from_bytes(&[]), Err(co::ERROR::INVALID_DATA));
+
+		bundle.set_string(0, &"x".repeat(0x1_0000));
+		assert_eq!(bundle.to_bytes(), Err(co::ERROR::INVALID_DATA));
+	}
+}
@@ -0,0 +1,416 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::co;
+use crate::decl::*;
+
+impl From<SystemTime> for FILETIME {
+	/// Converts the time into 100-nanosecond intervals since January 1, 1601
+	/// (UTC), truncating to the interval. Times out of the `FILETIME` range are
+	/// saturated.
+	fn from(time: SystemTime) -> Self {
+		let intervals = match time.duration_since(UNIX_EPOCH) {
+			Ok(after) => (after.as_nanos() / 100) as i128,
+			Err(before) => -(before.duration().as_nanos().div_ceil(100) as i128),
+		};
+		Self::from_u64((intervals + EPOCH_DIFF as i128).clamp(0, u64::MAX as _) as _)
+	}
+}
+
+impl From<FILETIME> for SystemTime {
+	/// Converts the 100-nanosecond intervals since January 1, 1601 (UTC).
+	/// Values beyond `i64::MAX`, which are rejected by Windows, are saturated.
+	fn from(ft: FILETIME) -> Self {
+		let intervals = ft.as_u64().min(i64::MAX as _) as i64 - EPOCH_DIFF as i64;
+		let dur = intervals_to_duration(intervals.unsigned_abs());
+		if intervals >= 0 { UNIX_EPOCH + dur } else { UNIX_EPOCH - dur }
+	}
+}
+
+impl FILETIME {
+	/// Creates a relative `FILETIME`, like the ones used by
+	/// [`HPROCESS::GetProcessTimes`](crate::prelude::kernel_Hprocess::GetProcessTimes).
+	/// Durations beyond the `FILETIME` range are saturated.
+	#[must_use]
+	pub fn from_duration(dur: Duration) -> Self {
+		Self::from_u64((dur.as_nanos() / 100).min(u64::MAX as _) as _)
+	}
+
+	/// Creates a `FILETIME` from the number of 100-nanosecond intervals.
+	#[must_use]
+	pub const fn from_u64(intervals: u64) -> Self {
+		Self {
+			dwLowDateTime: intervals as u32,
+			dwHighDateTime: (intervals >> 32) as u32,
+		}
+	}
+
+	/// Creates a `FILETIME` from a Unix timestamp, in seconds. Times out of the
+	/// `FILETIME` range are saturated.
+	#[must_use]
+	pub fn from_unix_time(secs: i64) -> Self {
+		let intervals = secs as i128 * INTERVALS_PER_SEC as i128 + EPOCH_DIFF as i128;
+		Self::from_u64(intervals.clamp(0, u64::MAX as _) as _)
+	}
+
+	/// Returns the value as a relative duration, like the ones returned by
+	/// [`HPROCESS::GetProcessTimes`](crate::prelude::kernel_Hprocess::GetProcessTimes).
+	#[must_use]
+	pub const fn as_duration(&self) -> Duration {
+		intervals_to_duration(self.as_u64())
+	}
+
+	/// Returns the number of 100-nanosecond intervals.
+	#[must_use]
+	pub const fn as_u64(&self) -> u64 {
+		((self.dwHighDateTime as u64) << 32) | self.dwLowDateTime as u64
+	}
+
+	/// Converts the time into a [`SYSTEMTIME`](crate::SYSTEMTIME), in pure
+	/// Rust, with the same results of
+	/// [`FileTimeToSystemTime`](crate::FileTimeToSystemTime): the
+	/// milliseconds are truncated, and `wDayOfWeek` is filled.
+	///
+	/// Returns
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if the value is greater than `i64::MAX`.
+	pub fn to_systemtime(&self) -> SysResult<SYSTEMTIME> {
+		let intervals = self.as_u64();
+		if intervals > i64::MAX as _ {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+		let millis = intervals / (INTERVALS_PER_SEC / 1000);
+		let days = (millis / MILLIS_PER_DAY) as i64 + DAYS_1601;
+		Ok(systemtime_from_parts(days, millis % MILLIS_PER_DAY))
+	}
+
+	/// Returns the Unix timestamp, in seconds, rounded down.
+	#[must_use]
+	pub const fn unix_time(&self) -> i64 {
+		(self.as_u64() as i128 - EPOCH_DIFF as i128).div_euclid(INTERVALS_PER_SEC as _) as _
+	}
+}
+
+impl SYSTEMTIME {
+	/// Converts an OLE Automation date, in pure Rust. Unlike
+	/// [`VariantTimeToSystemTime`](crate::VariantTimeToSystemTime), the time is
+	/// rounded to the nearest millisecond, not to the second. The
+	/// `wDayOfWeek` field is filled.
+	///
+	/// Returns
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if the date is not between January 1, 100 and December 31, 9999.
+	pub fn from_variant_time(var_time: f64) -> SysResult<Self> {
+		if !var_time.is_finite() || var_time.trunc() < VARIANT_MIN || var_time >= VARIANT_MAX {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+
+		// Negative dates still have a positive time of the day.
+		let mut days = var_time.trunc() as i64;
+		let mut millis = (var_time.fract().abs() * MILLIS_PER_DAY as f64).round() as u64;
+		if millis == MILLIS_PER_DAY {
+			days += 1;
+			millis = 0;
+		}
+		if days >= VARIANT_MAX as _ {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+		Ok(systemtime_from_parts(days + DAYS_1899, millis))
+	}
+
+	/// Converts the time into a [`FILETIME`](crate::FILETIME), in pure Rust,
+	/// like [`SystemTimeToFileTime`](crate::SystemTimeToFileTime). The
+	/// `wDayOfWeek` field is ignored.
+	///
+	/// Returns
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if a field is out of range, or if the year is not between 1601 and
+	/// 30827.
+	pub fn to_filetime(&self) -> SysResult<FILETIME> {
+		self.validate()?;
+		if !(1601..=30827).contains(&self.wYear) {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+		let days = (days_from_civil(self.wYear as _, self.wMonth as _, self.wDay as _) - DAYS_1601) as u64;
+		let millis = days * MILLIS_PER_DAY + self.millis_of_day();
+		Ok(FILETIME::from_u64(millis * (INTERVALS_PER_SEC / 1000)))
+	}
+
+	/// Converts the time into an OLE Automation date, in pure Rust. Unlike
+	/// [`SystemTimeToVariantTime`](crate::SystemTimeToVariantTime), the
+	/// milliseconds are kept. The `wDayOfWeek` field is ignored.
+	///
+	/// Returns
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if a field is out of range, or if the year is not between 100 and
+	/// 9999.
+	pub fn to_variant_time(&self) -> SysResult<f64> {
+		self.validate()?;
+		if !(100..=9999).contains(&self.wYear) {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+		let days = days_from_civil(self.wYear as _, self.wMonth as _, self.wDay as _) - DAYS_1899;
+		let fract = self.millis_of_day() as f64 / MILLIS_PER_DAY as f64;
+		Ok(if days >= 0 { days as f64 + fract } else { days as f64 - fract })
+	}
+
+	/// Checks whether the month, the day, and the time fields hold a valid
+	/// date. The year is checked by each conversion, since the ranges differ;
+	/// `wDayOfWeek` is ignored.
+	///
+	/// Returns
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if a field is out of range.
+	pub fn validate(&self) -> SysResult<()> {
+		if !(1..=12).contains(&self.wMonth)
+			|| self.wDay == 0
+			|| self.wDay > days_in_month(self.wYear as _, self.wMonth as _)
+			|| self.wHour > 23
+			|| self.wMinute > 59
+			|| self.wSecond > 59
+			|| self.wMilliseconds > 999
+		{
+			Err(co::ERROR::INVALID_PARAMETER)
+		} else {
+			Ok(())
+		}
+	}
+
+	const fn millis_of_day(&self) -> u64 {
+		((self.wHour as u64 * 60 + self.wMinute as u64) * 60 + self.wSecond as u64) * 1000
+			+ self.wMilliseconds as u64
+	}
+}
+
+//------------------------------------------------------------------------------
+
+const INTERVALS_PER_SEC: u64 = 10_000_000;
+const MILLIS_PER_DAY: u64 = 86_400_000;
+
+/// 100-nanosecond intervals between January 1, 1601 and January 1, 1970.
+pub(in crate::kernel) const EPOCH_DIFF: u64 = 11_644_473_600 * INTERVALS_PER_SEC;
+
+/// Days from January 1, 1970 to January 1, 1601.
+const DAYS_1601: i64 = -134_774;
+
+/// Days from January 1, 1970 to December 30, 1899, the OLE Automation epoch.
+const DAYS_1899: i64 = -25_569;
+
+/// OLE Automation date of January 1, 100.
+const VARIANT_MIN: f64 = -657_434.0;
+
+/// OLE Automation date of January 1, 10000.
+const VARIANT_MAX: f64 = 2_958_466.0;
+
+const fn intervals_to_duration(intervals: u64) -> Duration {
+	Duration::new(
+		intervals / INTERVALS_PER_SEC,
+		(intervals % INTERVALS_PER_SEC) as u32 * 100,
+	)
+}
+
+const fn is_leap_year(year: i64) -> bool {
+	(year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+const fn days_in_month(year: i64, month: u16) -> u16 {
+	match month {
+		2 => if is_leap_year(year) { 29 } else { 28 },
+		4 | 6 | 9 | 11 => 30,
+		_ => 31,
+	}
+}
+
+/// Days since January 1, 1970, in the proleptic Gregorian calendar.
+const fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+	let year = if month <= 2 { year - 1 } else { year };
+	let era = year.div_euclid(400);
+	let yoe = year - era * 400;
+	let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+	let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+	era * 146_097 + doe - 719_468
+}
+
+/// Year, month and day of the days since January 1, 1970.
+const fn civil_from_days(days: i64) -> (i64, i64, i64) {
+	let days = days + 719_468;
+	let era = days.div_euclid(146_097);
+	let doe = days - era * 146_097;
+	let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let day = doy - (153 * mp + 2) / 5 + 1;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 };
+	(yoe + era * 400 + if month <= 2 { 1 } else { 0 }, month, day)
+}
+
+/// Builds a `SYSTEMTIME` from the days since January 1, 1970 and the
+/// milliseconds of the day.
+fn systemtime_from_parts(days: i64, millis: u64) -> SYSTEMTIME {
+	let (year, month, day) = civil_from_days(days);
+	SYSTEMTIME {
+		wYear: year as _,
+		wMonth: month as _,
+		wDayOfWeek: (days + 4).rem_euclid(7) as _, // January 1, 1970 was a Thursday
+		wDay: day as _,
+		wHour: (millis / 3_600_000) as _,
+		wMinute: (millis / 60_000 % 60) as _,
+		wSecond: (millis / 1000 % 60) as _,
+		wMilliseconds: (millis % 1000) as _,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Year, month, day, hour, minute, second, milliseconds and day of week.
+	type Parts = (u16, u16, u16, u16, u16, u16, u16, u16);
+
+	fn parts(st: &SYSTEMTIME) -> Parts {
+		(st.wYear, st.wMonth, st.wDay, st.wHour, st.wMinute, st.wSecond,
+			st.wMilliseconds, st.wDayOfWeek)
+	}
+
+	fn st(year: u16, month: u16, day: u16, hour: u16, minute: u16, second: u16, millis: u16) -> SYSTEMTIME {
+		SYSTEMTIME {
+			wYear: year,
+			wMonth: month,
+			wDayOfWeek: 0,
+			wDay: day,
+			wHour: hour,
+			wMinute: minute,
+			wSecond: second,
+			wMilliseconds: millis,
+		}
+	}
+
+	fn variant(var_time: f64) -> SysResult<Parts> {
+		SYSTEMTIME::from_variant_time(var_time).map(|st| parts(&st))
+	}
+
+	#[test]
+	fn epochs() {
+		let ft_1601 = FILETIME::from_u64(0);
+		assert_eq!(parts(&ft_1601.to_systemtime().unwrap()), (1601, 1, 1, 0, 0, 0, 0, 1)); // Monday
+		assert_eq!(st(1601, 1, 1, 0, 0, 0, 0).to_filetime().unwrap().as_u64(), 0);
+		assert_eq!(ft_1601.unix_time(), -11_644_473_600);
+
+		let ft_1970 = FILETIME::from_u64(EPOCH_DIFF);
+		assert_eq!(parts(&ft_1970.to_systemtime().unwrap()), (1970, 1, 1, 0, 0, 0, 0, 4)); // Thursday
+		assert_eq!(st(1970, 1, 1, 0, 0, 0, 0).to_filetime().unwrap().as_u64(), EPOCH_DIFF);
+		assert_eq!(ft_1970.unix_time(), 0);
+		assert_eq!(FILETIME::from_unix_time(0).as_u64(), EPOCH_DIFF);
+		assert_eq!(SystemTime::from(ft_1970), UNIX_EPOCH);
+		assert_eq!(FILETIME::from(UNIX_EPOCH).as_u64(), EPOCH_DIFF);
+
+		// Before 1970, and before 1601, which is saturated.
+		let before = UNIX_EPOCH - Duration::new(1, 50);
+		assert_eq!(FILETIME::from(before).as_u64(), EPOCH_DIFF - INTERVALS_PER_SEC - 1);
+		assert_eq!(FILETIME::from(before).unix_time(), -2); // rounded down
+		assert_eq!(SystemTime::from(FILETIME::from_u64(EPOCH_DIFF - 1)), UNIX_EPOCH - Duration::from_nanos(100));
+		assert_eq!(FILETIME::from(UNIX_EPOCH - Duration::from_secs(11_644_473_601)).as_u64(), 0);
+		assert_eq!(FILETIME::from_unix_time(-11_644_473_601).as_u64(), 0);
+		assert_eq!(FILETIME::from_unix_time(i64::MAX).as_u64(), u64::MAX);
+
+		let dur = Duration::new(1, 500_000_099);
+		assert_eq!(FILETIME::from_duration(dur).as_u64(), 15_000_000);
+		assert_eq!(FILETIME::from_duration(dur).as_duration(), Duration::from_millis(1500));
+	}
+
+	#[test]
+	fn filetime_bounds() {
+		let max = FILETIME::from_u64(i64::MAX as _);
+		assert_eq!(parts(&max.to_systemtime().unwrap()), (30828, 9, 14, 2, 48, 5, 477, 4));
+		assert_eq!(FILETIME::from_u64(i64::MAX as u64 + 1).to_systemtime().err(),
+			Some(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(SystemTime::from(FILETIME::from_u64(u64::MAX)), SystemTime::from(max));
+
+		// Milliseconds are truncated.
+		assert_eq!(parts(&FILETIME::from_u64(EPOCH_DIFF + 9_999).to_systemtime().unwrap()),
+			(1970, 1, 1, 0, 0, 0, 0, 4));
+
+		let last = st(30827, 12, 31, 23, 59, 59, 999);
+		let ft = last.to_filetime().unwrap();
+		assert_eq!(parts(&ft.to_systemtime().unwrap()), (30827, 12, 31, 23, 59, 59, 999, 5));
+		assert_eq!(st(30828, 1, 1, 0, 0, 0, 0).to_filetime().err(), Some(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(st(1600, 12, 31, 23, 59, 59, 999).to_filetime().err(), Some(co::ERROR::INVALID_PARAMETER));
+	}
+
+	#[test]
+	fn leap_years() {
+		for (year, leap) in [(1900, false), (2000, true), (2023, false), (2024, true), (2100, false)] {
+			assert_eq!(st(year, 2, 29, 0, 0, 0, 0).validate().is_ok(), leap, "{}", year);
+			assert!(st(year, 2, 28, 0, 0, 0, 0).validate().is_ok());
+			assert!(st(year, 2, 30, 0, 0, 0, 0).validate().is_err());
+		}
+
+		let ft = st(2024, 2, 29, 12, 30, 15, 250).to_filetime().unwrap();
+		assert_eq!(ft.unix_time(), 1_709_209_815);
+		assert_eq!(parts(&ft.to_systemtime().unwrap()), (2024, 2, 29, 12, 30, 15, 250, 4));
+		let next = FILETIME::from_u64(ft.as_u64() + 12 * 3600 * INTERVALS_PER_SEC);
+		assert_eq!(parts(&next.to_systemtime().unwrap()), (2024, 3, 1, 0, 30, 15, 250, 5));
+
+		assert_eq!(variant(-36_464.0), Ok((1800, 2, 28, 0, 0, 0, 0, 5)));
+		assert_eq!(variant(-36_463.0), Ok((1800, 3, 1, 0, 0, 0, 0, 6))); // 1800 is not a leap year
+		assert_eq!(variant(60.0), Ok((1900, 2, 28, 0, 0, 0, 0, 3))); // unlike Excel's 1900
+		assert_eq!(variant(61.0), Ok((1900, 3, 1, 0, 0, 0, 0, 4)));
+	}
+
+	#[test]
+	fn field_ranges() {
+		for bad in [
+			st(2024, 0, 1, 0, 0, 0, 0),
+			st(2024, 13, 1, 0, 0, 0, 0),
+			st(2024, 1, 0, 0, 0, 0, 0),
+			st(2024, 4, 31, 0, 0, 0, 0),
+			st(2024, 1, 1, 24, 0, 0, 0),
+			st(2024, 1, 1, 0, 60, 0, 0),
+			st(2024, 1, 1, 0, 0, 60, 0),
+			st(2024, 1, 1, 0, 0, 0, 1000),
+		] {
+			assert_eq!(bad.validate(), Err(co::ERROR::INVALID_PARAMETER));
+			assert_eq!(bad.to_filetime().err(), Some(co::ERROR::INVALID_PARAMETER));
+			assert_eq!(bad.to_variant_time(), Err(co::ERROR::INVALID_PARAMETER));
+		}
+	}
+
+	#[test]
+	fn variant_times() {
+		assert_eq!(variant(0.0), Ok((1899, 12, 30, 0, 0, 0, 0, 6)));
+		assert_eq!(variant(2.5), Ok((1900, 1, 1, 12, 0, 0, 0, 1)));
+		assert_eq!(variant(25_569.0), Ok((1970, 1, 1, 0, 0, 0, 0, 4)));
+
+		// Negative dates have a positive time of the day.
+		assert_eq!(variant(-1.25), Ok((1899, 12, 29, 6, 0, 0, 0, 5)));
+		assert_eq!(variant(-0.5), Ok((1899, 12, 30, 12, 0, 0, 0, 6)));
+		assert_eq!(st(1899, 12, 29, 6, 0, 0, 0).to_variant_time(), Ok(-1.25));
+		assert_eq!(st(1899, 12, 30, 12, 0, 0, 0).to_variant_time(), Ok(0.5));
+
+		// Milliseconds are rounded, possibly into the next day.
+		assert_eq!(variant(1.0 + 1.5 / 86_400_000.0), Ok((1899, 12, 31, 0, 0, 0, 2, 0)));
+		assert_eq!(variant(0.999_999_999_9), Ok((1899, 12, 31, 0, 0, 0, 0, 0)));
+		assert_eq!(variant(-1.999_999_999_9), Ok((1899, 12, 30, 0, 0, 0, 0, 6)));
+		let st_millis = st(2024, 2, 29, 23, 59, 59, 999);
+		let var_time = st_millis.to_variant_time().unwrap();
+		assert_eq!(variant(var_time), Ok(parts(&SYSTEMTIME { wDayOfWeek: 4, ..st_millis })));
+
+		for bad in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+			assert_eq!(variant(bad), Err(co::ERROR::INVALID_PARAMETER));
+		}
+	}
+
+	#[test]
+	fn variant_bounds() {
+		assert_eq!(variant(-657_434.0), Ok((100, 1, 1, 0, 0, 0, 0, 5)));
+		assert_eq!(variant(-657_434.5), Ok((100, 1, 1, 12, 0, 0, 0, 5)));
+		assert_eq!(variant(-657_435.0), Err(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(variant(2_958_465.5), Ok((9999, 12, 31, 12, 0, 0, 0, 5)));
+		assert_eq!(variant(2_958_465.999_999_999), Err(co::ERROR::INVALID_PARAMETER)); // rounds to 10000
+		assert_eq!(variant(2_958_466.0), Err(co::ERROR::INVALID_PARAMETER));
+
+		assert_eq!(st(100, 1, 1, 0, 0, 0, 0).to_variant_time(), Ok(-657_434.0));
+		assert_eq!(st(9999, 12, 31, 0, 0, 0, 0).to_variant_time(), Ok(2_958_465.0));
+		assert_eq!(st(99, 12, 31, 0, 0, 0, 0).to_variant_time(), Err(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(st(10000, 1, 1, 0, 0, 0, 0).to_variant_time(), Err(co::ERROR::INVALID_PARAMETER));
+	}
+}
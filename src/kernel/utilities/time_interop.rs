@@ -0,0 +1,270 @@
+//! Conversions between the native time structs and the
+//! [`chrono`](https://docs.rs/chrono) and [`time`](https://docs.rs/time)
+//! crates. All of them are UTC, without time zone conversions.
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::utilities::time_conv::EPOCH_DIFF;
+
+#[cfg(feature = "chrono")]
+impl From<FILETIME> for chrono::DateTime<chrono::Utc> {
+	fn from(ft: FILETIME) -> Self {
+		let nanos = (ft.as_u64() as i128 - EPOCH_DIFF as i128) * 100;
+		Self::from_timestamp(
+			nanos.div_euclid(1_000_000_000) as _,
+			nanos.rem_euclid(1_000_000_000) as _,
+		).unwrap() // FILETIME range is much smaller than chrono's
+	}
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::DateTime<chrono::Utc>> for FILETIME {
+	type Error = co::ERROR;
+
+	/// Returns
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if the time is out of the `FILETIME` range.
+	fn try_from(dt: chrono::DateTime<chrono::Utc>) -> SysResult<Self> {
+		nanos_to_filetime(dt.timestamp() as i128 * 1_000_000_000
+			+ dt.timestamp_subsec_nanos() as i128)
+	}
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<&SYSTEMTIME> for chrono::NaiveDateTime {
+	type Error = co::ERROR;
+
+	/// Returns
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if a field is out of range.
+	fn try_from(st: &SYSTEMTIME) -> SysResult<Self> {
+		st.validate()?;
+		chrono::NaiveDate::from_ymd_opt(st.wYear as _, st.wMonth as _, st.wDay as _)
+			.and_then(|date| date.and_hms_milli_opt(
+				st.wHour as _, st.wMinute as _, st.wSecond as _, st.wMilliseconds as _))
+			.ok_or(co::ERROR::INVALID_PARAMETER)
+	}
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::NaiveDateTime> for SYSTEMTIME {
+	type Error = co::ERROR;
+
+	/// Returns
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if the year doesn't fit in `wYear`, or if the time is a leap second.
+	fn try_from(dt: chrono::NaiveDateTime) -> SysResult<Self> {
+		use chrono::{Datelike, Timelike};
+		let millis = dt.nanosecond() / 1_000_000;
+		if millis > 999 {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+		Ok(Self {
+			wYear: u16::try_from(dt.year()).map_err(|_| co::ERROR::INVALID_PARAMETER)?,
+			wMonth: dt.month() as _,
+			wDayOfWeek: dt.weekday().num_days_from_sunday() as _,
+			wDay: dt.day() as _,
+			wHour: dt.hour() as _,
+			wMinute: dt.minute() as _,
+			wSecond: dt.second() as _,
+			wMilliseconds: millis as _,
+		})
+	}
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<FILETIME> for time::OffsetDateTime {
+	type Error = co::ERROR;
+
+	/// Returns
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if the time is out of the `OffsetDateTime` range.
+	fn try_from(ft: FILETIME) -> SysResult<Self> {
+		Self::from_unix_timestamp_nanos((ft.as_u64() as i128 - EPOCH_DIFF as i128) * 100)
+			.map_err(|_| co::ERROR::INVALID_PARAMETER)
+	}
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::OffsetDateTime> for FILETIME {
+	type Error = co::ERROR;
+
+	/// The offset is taken into account, so the result is UTC.
+	///
+	/// Returns
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if the time is out of the `FILETIME` range.
+	fn try_from(dt: time::OffsetDateTime) -> SysResult<Self> {
+		nanos_to_filetime(dt.unix_timestamp_nanos())
+	}
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<&SYSTEMTIME> for time::PrimitiveDateTime {
+	type Error = co::ERROR;
+
+	/// Returns
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if a field is out of range.
+	fn try_from(st: &SYSTEMTIME) -> SysResult<Self> {
+		st.validate()?;
+		let month = time::Month::try_from(st.wMonth as u8)
+			.map_err(|_| co::ERROR::INVALID_PARAMETER)?;
+		let date = time::Date::from_calendar_date(st.wYear as _, month, st.wDay as _)
+			.map_err(|_| co::ERROR::INVALID_PARAMETER)?;
+		let time = time::Time::from_hms_milli(
+			st.wHour as _, st.wMinute as _, st.wSecond as _, st.wMilliseconds)
+			.map_err(|_| co::ERROR::INVALID_PARAMETER)?;
+		Ok(Self::new(date, time))
+	}
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::PrimitiveDateTime> for SYSTEMTIME {
+	type Error = co::ERROR;
+
+	/// Returns
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if the year doesn't fit in `wYear`.
+	fn try_from(dt: time::PrimitiveDateTime) -> SysResult<Self> {
+		Ok(Self {
+			wYear: u16::try_from(dt.year()).map_err(|_| co::ERROR::INVALID_PARAMETER)?,
+			wMonth: dt.month() as u8 as _,
+			wDayOfWeek: dt.weekday().number_days_from_sunday() as _,
+			wDay: dt.day() as _,
+			wHour: dt.hour() as _,
+			wMinute: dt.minute() as _,
+			wSecond: dt.second() as _,
+			wMilliseconds: dt.millisecond(),
+		})
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Converts nanoseconds since January 1, 1970 into a `FILETIME`, truncating to
+/// the 100-nanosecond interval.
+fn nanos_to_filetime(nanos: i128) -> SysResult<FILETIME> {
+	let intervals = nanos.div_euclid(100) + EPOCH_DIFF as i128;
+	u64::try_from(intervals)
+		.map(FILETIME::from_u64)
+		.map_err(|_| co::ERROR::INVALID_PARAMETER)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn st(year: u16, month: u16, day: u16, hour: u16, minute: u16, second: u16, millis: u16) -> SYSTEMTIME {
+		SYSTEMTIME {
+			wYear: year,
+			wMonth: month,
+			wDayOfWeek: 0,
+			wDay: day,
+			wHour: hour,
+			wMinute: minute,
+			wSecond: second,
+			wMilliseconds: millis,
+		}
+	}
+
+	#[test]
+	fn nanos() {
+		assert_eq!(nanos_to_filetime(0).unwrap().as_u64(), EPOCH_DIFF);
+		assert_eq!(nanos_to_filetime(199).unwrap().as_u64(), EPOCH_DIFF + 1); // truncated
+		assert_eq!(nanos_to_filetime(-1).unwrap().as_u64(), EPOCH_DIFF - 1); // rounded down
+		assert_eq!(nanos_to_filetime(-(EPOCH_DIFF as i128) * 100).unwrap().as_u64(), 0);
+		assert_eq!(nanos_to_filetime(-(EPOCH_DIFF as i128) * 100 - 1).err(),
+			Some(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(nanos_to_filetime((u64::MAX - EPOCH_DIFF) as i128 * 100 + 99).unwrap().as_u64(),
+			u64::MAX);
+		assert_eq!(nanos_to_filetime((u64::MAX - EPOCH_DIFF + 1) as i128 * 100).err(),
+			Some(co::ERROR::INVALID_PARAMETER));
+	}
+
+	#[cfg(feature = "chrono")]
+	#[test]
+	fn chrono_conversions() {
+		use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+
+		let epoch_1601 = NaiveDate::from_ymd_opt(1601, 1, 1).unwrap()
+			.and_hms_opt(0, 0, 0).unwrap()
+			.and_utc();
+		assert_eq!(DateTime::<Utc>::from(FILETIME::from_u64(0)), epoch_1601);
+		assert_eq!(FILETIME::try_from(epoch_1601).unwrap().as_u64(), 0);
+		assert_eq!(FILETIME::try_from(epoch_1601 - chrono::Duration::nanoseconds(1)).err(),
+			Some(co::ERROR::INVALID_PARAMETER));
+
+		assert_eq!(DateTime::<Utc>::from(FILETIME::from_u64(EPOCH_DIFF)), DateTime::UNIX_EPOCH);
+		assert_eq!(FILETIME::try_from(DateTime::UNIX_EPOCH).unwrap().as_u64(), EPOCH_DIFF);
+		let before = DateTime::UNIX_EPOCH - chrono::Duration::nanoseconds(150);
+		assert_eq!(FILETIME::try_from(before).unwrap().as_u64(), EPOCH_DIFF - 2);
+		assert_eq!(DateTime::<Utc>::from(FILETIME::from_u64(EPOCH_DIFF - 2)),
+			DateTime::UNIX_EPOCH - chrono::Duration::nanoseconds(200));
+
+		let max = DateTime::<Utc>::from(FILETIME::from_u64(u64::MAX)); // beyond SYSTEMTIME
+		assert_eq!(FILETIME::try_from(max).unwrap().as_u64(), u64::MAX);
+
+		let leap_day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+			.and_hms_milli_opt(23, 59, 59, 999).unwrap();
+		let sys = SYSTEMTIME::try_from(leap_day).unwrap();
+		assert_eq!((sys.wYear, sys.wMonth, sys.wDay, sys.wDayOfWeek, sys.wMilliseconds),
+			(2024, 2, 29, 4, 999));
+		assert_eq!(NaiveDateTime::try_from(&sys).unwrap(), leap_day);
+		assert_eq!(NaiveDateTime::try_from(&st(2023, 2, 29, 0, 0, 0, 0)).err(),
+			Some(co::ERROR::INVALID_PARAMETER));
+
+		let last = st(30827, 12, 31, 23, 59, 59, 999);
+		let dt = NaiveDateTime::try_from(&last).unwrap();
+		assert_eq!(SYSTEMTIME::try_from(dt).unwrap().wYear, 30827);
+		assert_eq!(FILETIME::try_from(dt.and_utc()).unwrap().as_u64(),
+			last.to_filetime().unwrap().as_u64());
+
+		let leap_second = NaiveDate::from_ymd_opt(2016, 12, 31).unwrap()
+			.and_hms_milli_opt(23, 59, 59, 1_500).unwrap();
+		assert_eq!(SYSTEMTIME::try_from(leap_second).err(), Some(co::ERROR::INVALID_PARAMETER));
+		let too_large = NaiveDate::from_ymd_opt(65536, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+		assert_eq!(SYSTEMTIME::try_from(too_large).err(), Some(co::ERROR::INVALID_PARAMETER));
+		let negative = NaiveDate::from_ymd_opt(-1, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+		assert_eq!(SYSTEMTIME::try_from(negative).err(), Some(co::ERROR::INVALID_PARAMETER));
+	}
+
+	#[cfg(feature = "time")]
+	#[test]
+	fn time_conversions() {
+		use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+		let epoch_1601 = Date::from_calendar_date(1601, Month::January, 1).unwrap()
+			.midnight()
+			.assume_utc();
+		assert_eq!(OffsetDateTime::try_from(FILETIME::from_u64(0)).unwrap(), epoch_1601);
+		assert_eq!(FILETIME::try_from(epoch_1601).unwrap().as_u64(), 0);
+		assert_eq!(FILETIME::try_from(epoch_1601 - time::Duration::nanoseconds(1)).err(),
+			Some(co::ERROR::INVALID_PARAMETER));
+
+		assert_eq!(OffsetDateTime::try_from(FILETIME::from_u64(EPOCH_DIFF)).unwrap(),
+			OffsetDateTime::UNIX_EPOCH);
+		assert_eq!(FILETIME::try_from(OffsetDateTime::UNIX_EPOCH).unwrap().as_u64(), EPOCH_DIFF);
+		let offset = OffsetDateTime::UNIX_EPOCH.to_offset(UtcOffset::from_hms(-3, 0, 0).unwrap());
+		assert_eq!(FILETIME::try_from(offset).unwrap().as_u64(), EPOCH_DIFF); // same instant
+
+		// Beyond the year 9999, which is the limit of the time crate.
+		let ft_10000 = st(10000, 1, 1, 0, 0, 0, 0).to_filetime().unwrap();
+		assert_eq!(OffsetDateTime::try_from(ft_10000).err(), Some(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(PrimitiveDateTime::try_from(&st(30827, 1, 1, 0, 0, 0, 0)).err(),
+			Some(co::ERROR::INVALID_PARAMETER));
+
+		let last = st(9999, 12, 31, 23, 59, 59, 999);
+		let dt = PrimitiveDateTime::try_from(&last).unwrap();
+		let sys = SYSTEMTIME::try_from(dt).unwrap();
+		assert_eq!((sys.wYear, sys.wMonth, sys.wDay, sys.wDayOfWeek, sys.wMilliseconds),
+			(9999, 12, 31, 5, 999));
+		assert_eq!(FILETIME::try_from(dt.assume_utc()).unwrap().as_u64(),
+			last.to_filetime().unwrap().as_u64());
+
+		let leap_day = PrimitiveDateTime::try_from(&st(2024, 2, 29, 12, 0, 0, 0)).unwrap();
+		assert_eq!(leap_day.date(), Date::from_calendar_date(2024, Month::February, 29).unwrap());
+		assert_eq!(PrimitiveDateTime::try_from(&st(2100, 2, 29, 0, 0, 0, 0)).err(),
+			Some(co::ERROR::INVALID_PARAMETER));
+	}
+}
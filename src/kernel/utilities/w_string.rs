@@ -3,6 +3,7 @@ use std::cmp::Ordering;
 use crate::co;
 use crate::decl::*;
 use crate::kernel::ffi;
+use crate::kernel::utilities::{bocu1, scsu};
 
 pub const SSO_LEN: usize = 20;
 
@@ -240,6 +241,15 @@ impl WString {
 	/// To serialize the string back into UTF-8 bytes, use the built-in
 	/// [`String::into_bytes`](std::string::String::into_bytes).
 	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the data is malformed for the guessed encoding.
+	///
+	/// Binary data, with too many nulls for a single-byte encoding but which
+	/// isn't valid UTF-16 or UTF-32, is guessed as
+	/// [`Encoding::Unknown`](crate::Encoding::Unknown), so it also fails with
+	/// [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA). Previous
+	/// versions decoded it anyway, usually as Windows-1252.
+	///
 	/// # Examples
 	///
 	/// Usually the fastest way to read the text from a file is by mapping its
@@ -264,24 +274,129 @@ impl WString {
 		}
 
 		let (encoding, sz_bom) = Encoding::guess(data);
+		let data_with_bom = data;
 		data = &data[sz_bom..]; // skip BOM, if any
 
 		Ok(Self::from_wchars_slice(
 			&match encoding {
 				Encoding::Ansi => Self::parse_ansi(data),
+				Encoding::Win1250 => MultiByteToWideChar(co::CP::WINDOWS_1250, co::MBC::NoValue, data)?,
+				Encoding::Win1251 => MultiByteToWideChar(co::CP::WINDOWS_1251, co::MBC::NoValue, data)?,
 				Encoding::Win1252 => MultiByteToWideChar(co::CP::WINDOWS_1252, co::MBC::NoValue, data)?,
 				Encoding::Utf8 => MultiByteToWideChar(co::CP::UTF8, co::MBC::NoValue, data)?,
 				Encoding::Utf16be => Self::parse_utf16(data, true),
 				Encoding::Utf16le => Self::parse_utf16(data, false),
-				Encoding::Utf32be
-				| Encoding::Utf32le
-				| Encoding::Scsu
-				| Encoding::Bocu1
-				| Encoding::Unknown => panic!("Encoding {} not implemented.", encoding),
+				Encoding::Utf32be => Self::parse_utf32(data, true)?,
+				Encoding::Utf32le => Self::parse_utf32(data, false)?,
+				Encoding::Scsu => scsu::decode(data)?,
+				Encoding::Bocu1 => bocu1::decode(data_with_bom)?, // BOM changes the decoder state
+				Encoding::Unknown => return Err(co::ERROR::INVALID_DATA),
 			}
 		))
 	}
 
+	/// Serializes the string into raw bytes of the given encoding, which can
+	/// be written to a file. This is the inverse of
+	/// [`WString::parse`](crate::WString::parse).
+	///
+	/// If `with_bom` is `true`, the
+	/// [BOM](https://en.wikipedia.org/wiki/Byte_order_mark) of the encoding is
+	/// prepended. [`Encoding::Ansi`](crate::Encoding::Ansi) and the Windows
+	/// code pages, like [`Encoding::Win1252`](crate::Encoding::Win1252), have
+	/// no BOM, so the flag is ignored for them; chars which cannot be
+	/// represented in these encodings are handled according to `unmappable`.
+	///
+	/// Returns
+	/// [`co::ERROR::NO_UNICODE_TRANSLATION`](crate::co::ERROR::NO_UNICODE_TRANSLATION)
+	/// if the string has unpaired surrogates, and
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// for [`Encoding::Unknown`](crate::Encoding::Unknown).
+	///
+	/// # Examples
+	///
+	/// Saving a file with the same encoding it was loaded:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let file_path = "C:\\Temp\\foo.txt";
+	///
+	/// let file_in = w::FileMapped::open(file_path, w::FileAccess::ExistingReadOnly)?;
+	/// let (encoding, sz_bom) = w::Encoding::guess(file_in.as_slice());
+	/// let wstr = w::WString::parse(file_in.as_slice())?;
+	/// drop(file_in);
+	///
+	/// let new_contents = w::WString::from_str(wstr.to_string() + "\r\nfoo");
+	/// let bytes = new_contents.serialize(
+	///     encoding, sz_bom > 0, w::UnmappableChar::Replace(b'?'))?;
+	///
+	/// let file_out = w::File::open(file_path, w::FileAccess::OpenOrCreateRW)?;
+	/// file_out.erase_and_write(&bytes)?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	pub fn serialize(&self,
+		encoding: Encoding,
+		with_bom: bool,
+		unmappable: UnmappableChar,
+	) -> SysResult<Vec<u8>>
+	{
+		let chars = self.as_slice()
+			.iter()
+			.position(|ch| *ch == 0x0000)
+			.map(|idx| &self.as_slice()[..idx]) // ignore terminating null
+			.unwrap_or(self.as_slice());
+
+		Ok(match encoding {
+			Encoding::Ansi => Self::serialize_single_byte(chars, unmappable,
+				|ch| if ch < 0x80 { Some(ch as _) } else { None })?,
+			Encoding::Win1250 | Encoding::Win1251 | Encoding::Win1252 =>
+				Self::serialize_single_byte(chars, unmappable,
+					|ch| encoding.char_to_single_byte(ch))?,
+			Encoding::Utf8 => {
+				let mut buf = if with_bom { vec![0xef, 0xbb, 0xbf] } else { Vec::default() };
+				buf.extend_from_slice(
+					String::from_utf16(chars)
+						.map_err(|_| co::ERROR::NO_UNICODE_TRANSLATION)?
+						.as_bytes(),
+				);
+				buf
+			},
+			Encoding::Utf16be | Encoding::Utf16le => {
+				if char::decode_utf16(chars.iter().copied()).any(|ch| ch.is_err()) {
+					return Err(co::ERROR::NO_UNICODE_TRANSLATION); // unpaired surrogate
+				}
+				let is_big_endian = encoding == Encoding::Utf16be;
+				(if with_bom { Some(0xfeff) } else { None }).into_iter()
+					.chain(chars.iter().copied())
+					.flat_map(|ch| if is_big_endian { ch.to_be_bytes() } else { ch.to_le_bytes() })
+					.collect()
+			},
+			Encoding::Utf32be | Encoding::Utf32le => {
+				let is_big_endian = encoding == Encoding::Utf32be;
+				let mut buf = Vec::<u8>::with_capacity((chars.len() + 1) * 4);
+				for ch in (if with_bom { Some(Ok('\u{feff}')) } else { None }).into_iter()
+					.chain(char::decode_utf16(chars.iter().copied()))
+				{
+					let code_point = ch.map_err(|_| co::ERROR::NO_UNICODE_TRANSLATION)? as u32;
+					buf.extend_from_slice(&if is_big_endian {
+						code_point.to_be_bytes()
+					} else {
+						code_point.to_le_bytes()
+					});
+				}
+				buf
+			},
+			Encoding::Scsu => {
+				let mut buf = if with_bom { vec![0x0e, 0xfe, 0xff] } else { Vec::default() };
+				buf.append(&mut scsu::encode(chars)?);
+				buf
+			},
+			Encoding::Bocu1 => bocu1::encode(chars, with_bom)?,
+			Encoding::Unknown => return Err(co::ERROR::INVALID_PARAMETER),
+		})
+	}
+
 	fn parse_ansi(data: &[u8]) -> Vec<u16> {
 		data.iter()
 			.take_while(|ch| **ch != 0x0000) // ignore terminating null, if any
@@ -307,6 +422,44 @@ impl WString {
 			})
 			.collect()
 	}
+
+	fn parse_utf32(data: &[u8], is_big_endian: bool) -> SysResult<Vec<u16>> {
+		let mut buf = Vec::<u16>::with_capacity(data.len() / 4);
+		for ch4 in data.chunks_exact(4) { // if incomplete last char, discard it
+			let code_point = if is_big_endian {
+				u32::from_be_bytes(ch4.try_into().unwrap())
+			} else {
+				u32::from_le_bytes(ch4.try_into().unwrap())
+			};
+			if code_point == 0x0000_0000 {
+				break; // ignore terminating null, if any
+			}
+			let ch = char::from_u32(code_point).ok_or(co::ERROR::INVALID_DATA)?;
+			buf.extend_from_slice(ch.encode_utf16(&mut [0; 2]));
+		}
+		Ok(buf)
+	}
+
+	fn serialize_single_byte(
+		chars: &[u16],
+		unmappable: UnmappableChar,
+		to_byte: impl Fn(u16) -> Option<u8>,
+	) -> SysResult<Vec<u8>>
+	{
+		let mut buf = Vec::<u8>::with_capacity(chars.len());
+		for ch in char::decode_utf16(chars.iter().copied()) {
+			let ch = ch.map_err(|_| co::ERROR::NO_UNICODE_TRANSLATION)?; // unpaired surrogate
+			let byte = u16::try_from(ch as u32).ok() // only BMP chars can be mapped
+				.and_then(&to_byte);
+			match (byte, unmappable) {
+				(Some(byte), _) => buf.push(byte),
+				(None, UnmappableChar::Fail) => return Err(co::ERROR::NO_UNICODE_TRANSLATION),
+				(None, UnmappableChar::Replace(repl)) => buf.push(repl),
+				(None, UnmappableChar::Skip) => {},
+			}
+		}
+		Ok(buf)
+	}
 }
 
 //------------------------------------------------------------------------------
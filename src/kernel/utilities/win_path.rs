@@ -0,0 +1,642 @@
+use std::fmt;
+
+use crate::co;
+use crate::decl::*;
+
+/// The prefix of a [`WinPath`], which determines how the remaining components
+/// are resolved.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WinPathPrefix {
+	/// No prefix, like `foo\bar` or the rooted `\foo\bar`.
+	None,
+	/// Drive letter, like `C:\foo` or the drive-relative `C:foo`.
+	Drive(char),
+	/// UNC share, like `\\server\share\foo`.
+	Unc {
+		/// Server name.
+		server: String,
+		/// Share name.
+		share: String,
+	},
+	/// Drive letter with the long-path prefix, like `\\?\C:\foo`.
+	VerbatimDrive(char),
+	/// UNC share with the long-path prefix, like `\\?\UNC\server\share\foo`.
+	VerbatimUnc {
+		/// Server name.
+		server: String,
+		/// Share name.
+		share: String,
+	},
+	/// Any other long-path prefixed path, like `\\?\Volume{...}\foo`; holds the
+	/// first component.
+	Verbatim(String),
+	/// Device namespace path, like `\\.\COM1` or `\\.\PhysicalDrive0`; holds
+	/// the device name.
+	Device(String),
+}
+
+/// A Windows path parsed into its prefix and components.
+///
+/// All Win32 path forms are recognized: drive-absolute (`C:\foo`),
+/// drive-relative (`C:foo`), rooted (`\foo`), relative (`foo`), UNC
+/// (`\\server\share\foo`), long-path (`\\?\C:\foo` and `\\?\UNC\server\share`)
+/// and device (`\\.\COM1`) paths. Forward slashes are accepted as separators,
+/// except in long-path prefixed paths, which Windows passes verbatim to the
+/// file system.
+///
+/// All operations are lexical: the file system is never touched.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let p = w::path::WinPath::parse("C:\\Temp\\.\\foo\\..\\bar.txt")
+///     .normalize(); // C:\Temp\bar.txt
+///
+/// let r = w::path::WinPath::parse("C:\\Temp\\a\\b.txt")
+///     .relative_to(&w::path::WinPath::parse("C:\\Temp\\c"))
+///     .unwrap(); // ..\a\b.txt
+///
+/// let l = w::path::WinPath::parse("\\\\server\\share\\foo")
+///     .to_verbatim()
+///     .unwrap(); // \\?\UNC\server\share\foo
+///
+/// println!("{} {} {}", p, r, l);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WinPath {
+	prefix: WinPathPrefix,
+	rooted: bool,
+	parts: Vec<String>,
+}
+
+impl fmt::Display for WinPath {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match &self.prefix {
+			WinPathPrefix::None => {},
+			WinPathPrefix::Drive(letter) => write!(f, "{}:", letter)?,
+			WinPathPrefix::Unc { server, share } => write!(f, "\\\\{}\\{}", server, share)?,
+			WinPathPrefix::VerbatimDrive(letter) => write!(f, "\\\\?\\{}:", letter)?,
+			WinPathPrefix::VerbatimUnc { server, share } => write!(f, "\\\\?\\UNC\\{}\\{}", server, share)?,
+			WinPathPrefix::Verbatim(first) => write!(f, "\\\\?\\{}", first)?,
+			WinPathPrefix::Device(name) => write!(f, "\\\\.\\{}", name)?,
+		}
+
+		let root = match self.prefix {
+			WinPathPrefix::None | WinPathPrefix::Drive(_) => self.rooted,
+			WinPathPrefix::VerbatimDrive(_) => true, // \\?\C: must be followed by a backslash
+			_ => !self.parts.is_empty(),
+		};
+		if root {
+			write!(f, "\\")?;
+		}
+		write!(f, "{}", self.parts.join("\\"))
+	}
+}
+
+impl WinPath {
+	/// Parses a path string. Any string is accepted; use
+	/// [`validate`](crate::path::WinPath::validate) to check the names.
+	#[must_use]
+	pub fn parse(path: &str) -> WinPath {
+		if let Some(rest) = path.strip_prefix("\\\\?\\")
+			.or_else(|| path.strip_prefix("\\??\\"))
+		{
+			let mut parts = split_parts(rest);
+			let first = if parts.is_empty() { String::new() } else { parts.remove(0) };
+			let prefix = if first.to_uppercase() == "UNC" {
+				let (server, share) = take_server_share(&mut parts);
+				WinPathPrefix::VerbatimUnc { server, share }
+			} else if first.len() == 2 && drive_letter(&first).is_some() {
+				WinPathPrefix::VerbatimDrive(drive_letter(&first).unwrap())
+			} else {
+				WinPathPrefix::Verbatim(first)
+			};
+			return WinPath { prefix, rooted: true, parts };
+		}
+
+		let path = path.replace('/', "\\");
+		if let Some(rest) = path.strip_prefix("\\\\.\\")
+			.or_else(|| path.strip_prefix("\\\\?\\")) // was //?/, which is not verbatim
+		{
+			let mut parts = split_parts(rest);
+			let name = if parts.is_empty() { String::new() } else { parts.remove(0) };
+			WinPath { prefix: WinPathPrefix::Device(name), rooted: true, parts }
+		} else if let Some(rest) = path.strip_prefix("\\\\") {
+			let mut parts = split_parts(rest);
+			let (server, share) = take_server_share(&mut parts);
+			WinPath { prefix: WinPathPrefix::Unc { server, share }, rooted: true, parts }
+		} else if let Some(letter) = drive_letter(&path) {
+			let rest = &path[2..];
+			WinPath {
+				prefix: WinPathPrefix::Drive(letter),
+				rooted: rest.starts_with('\\'),
+				parts: split_parts(rest),
+			}
+		} else {
+			WinPath {
+				prefix: WinPathPrefix::None,
+				rooted: path.starts_with('\\'),
+				parts: split_parts(&path),
+			}
+		}
+	}
+
+	/// Tells whether the name, which may have an extension, is reserved by
+	/// Windows for a device, like `CON`, `NUL.txt` or `com1 `, case-insensitive.
+	#[must_use]
+	pub fn is_reserved_name(name: &str) -> bool {
+		let base = name.split('.').next().unwrap()
+			.trim_end_matches(' ')
+			.to_uppercase();
+		match base.as_str() {
+			"CON" | "PRN" | "AUX" | "NUL" | "CONIN$" | "CONOUT$" => true,
+			_ => ["COM", "LPT"].iter()
+				.filter_map(|dev| base.strip_prefix(dev))
+				.any(|num| {
+					let mut chars = num.chars();
+					matches!((chars.next(), chars.next()),
+						(Some('0'..='9' | '¹' | '²' | '³'), None))
+				}),
+		}
+	}
+
+	/// Returns the components after the prefix, without separators.
+	#[must_use]
+	pub fn components(&self) -> &[String] {
+		&self.parts
+	}
+
+	/// Returns the last component, if any.
+	#[must_use]
+	pub fn file_name(&self) -> Option<&str> {
+		self.parts.last().map(|p| p.as_str())
+	}
+
+	/// Tells whether the path is fully qualified, thus not depending on the
+	/// current directory or drive.
+	#[must_use]
+	pub fn is_absolute(&self) -> bool {
+		match self.prefix {
+			WinPathPrefix::None => false,
+			WinPathPrefix::Drive(_) => self.rooted,
+			_ => true,
+		}
+	}
+
+	/// Tells whether the path starts with a backslash after the prefix, if
+	/// any; that is, whether it does not depend on the current directory.
+	#[must_use]
+	pub const fn is_rooted(&self) -> bool {
+		self.rooted
+	}
+
+	/// Tells whether the path has the `\\?\` long-path prefix.
+	#[must_use]
+	pub const fn is_verbatim(&self) -> bool {
+		matches!(self.prefix,
+			WinPathPrefix::VerbatimDrive(_)
+			| WinPathPrefix::VerbatimUnc { .. }
+			| WinPathPrefix::Verbatim(_))
+	}
+
+	/// Appends `other` to this path, the way Windows resolves `other` relative
+	/// to this path:
+	///
+	/// * absolute paths replace this path;
+	/// * rooted paths, like `\foo`, keep only the prefix of this path;
+	/// * drive-relative paths, like `C:foo`, are appended only if the drive is
+	///   the same, otherwise they replace this path;
+	/// * relative paths are appended.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let p = w::path::WinPath::parse("C:\\Temp")
+	///     .join("foo\\bar.txt"); // C:\Temp\foo\bar.txt
+	/// ```
+	#[must_use]
+	pub fn join(&self, other: &str) -> WinPath {
+		let other = WinPath::parse(other);
+		match other.prefix {
+			WinPathPrefix::None => if other.rooted {
+				WinPath { prefix: self.prefix.clone(), rooted: true, parts: other.parts }
+			} else {
+				self.append(&other.parts)
+			},
+			WinPathPrefix::Drive(letter) if !other.rooted
+				&& self.drive().is_some_and(|cur| cur.eq_ignore_ascii_case(&letter)) =>
+			{
+				self.append(&other.parts)
+			},
+			_ => other,
+		}
+	}
+
+	/// Lexically resolves `.` and `..` components and collapses repeated
+	/// separators. Like
+	/// [`GetFullPathName`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getfullpathnamew),
+	/// trailing dots and spaces are removed from the last component, and `..`
+	/// cannot climb above the root.
+	///
+	/// Long-path prefixed paths are returned unchanged, because Windows passes
+	/// them verbatim to the file system.
+	#[must_use]
+	pub fn normalize(&self) -> WinPath {
+		if self.is_verbatim() {
+			return self.clone();
+		}
+
+		let mut parts = Vec::<String>::with_capacity(self.parts.len());
+		for (idx, part) in self.parts.iter().enumerate() {
+			match part.as_str() {
+				"." => {},
+				".." => if parts.last().is_some_and(|last| last != "..") {
+					parts.pop();
+				} else if !self.rooted {
+					parts.push(part.clone()); // relative path climbing above its start
+				},
+				_ => if idx == self.parts.len() - 1 {
+					let trimmed = part.trim_end_matches(['.', ' ']);
+					if !trimmed.is_empty() {
+						parts.push(trimmed.to_owned());
+					}
+				} else {
+					parts.push(part.clone());
+				},
+			}
+		}
+
+		WinPath { prefix: self.prefix.clone(), rooted: self.rooted, parts }
+	}
+
+	/// Returns the path without its last component, or `None` if there are no
+	/// components.
+	#[must_use]
+	pub fn parent(&self) -> Option<WinPath> {
+		if self.parts.is_empty() {
+			None
+		} else {
+			Some(WinPath {
+				prefix: self.prefix.clone(),
+				rooted: self.rooted,
+				parts: self.parts[..self.parts.len() - 1].to_vec(),
+			})
+		}
+	}
+
+	/// Computes the relative path which leads from `base` to this path. Both
+	/// paths are normalized first, and components are compared
+	/// case-insensitive.
+	///
+	/// Returns `None` if the paths have different prefixes or roots, or if
+	/// `base` climbs above its start with `..`.
+	#[must_use]
+	pub fn relative_to(&self, base: &WinPath) -> Option<WinPath> {
+		let (path, base) = (self.normalize(), base.normalize());
+		if path.rooted != base.rooted
+			|| !same_prefix(&path.prefix, &base.prefix)
+			|| base.parts.first().is_some_and(|p| p == "..")
+		{
+			return None;
+		}
+
+		let common = path.parts.iter()
+			.zip(base.parts.iter())
+			.take_while(|(a, b)| a.to_uppercase() == b.to_uppercase())
+			.count();
+
+		let mut parts = vec!["..".to_owned(); base.parts.len() - common];
+		parts.extend_from_slice(&path.parts[common..]);
+		Some(WinPath { prefix: WinPathPrefix::None, rooted: false, parts })
+	}
+
+	/// Converts the path to its `\\?\` long-path form, which bypasses the
+	/// `MAX_PATH` limit. The path is normalized first.
+	///
+	/// Returns `None` if the path is not absolute, because long-path prefixed
+	/// paths cannot be relative.
+	#[must_use]
+	pub fn to_verbatim(&self) -> Option<WinPath> {
+		if !self.is_absolute() {
+			return None;
+		}
+
+		let norm = self.normalize();
+		let prefix = match norm.prefix {
+			WinPathPrefix::None => return None,
+			WinPathPrefix::Drive(letter) => WinPathPrefix::VerbatimDrive(letter),
+			WinPathPrefix::Unc { server, share } => WinPathPrefix::VerbatimUnc { server, share },
+			WinPathPrefix::Device(name) => WinPathPrefix::Verbatim(name),
+			WinPathPrefix::VerbatimDrive(_)
+				| WinPathPrefix::VerbatimUnc { .. }
+				| WinPathPrefix::Verbatim(_) => return Some(norm), // normalize() keeps them unchanged
+		};
+		Some(WinPath { prefix, rooted: true, parts: norm.parts })
+	}
+
+	/// Converts a `\\?\` long-path prefixed path to its ordinary form; other
+	/// paths are returned unchanged.
+	///
+	/// Returns `None` if the path has no ordinary form, or if a component would
+	/// be changed by ordinary path parsing – like `..`, trailing dots or
+	/// reserved names – so the resulting path would point somewhere else.
+	#[must_use]
+	pub fn to_non_verbatim(&self) -> Option<WinPath> {
+		let prefix = match &self.prefix {
+			WinPathPrefix::VerbatimDrive(letter) => WinPathPrefix::Drive(*letter),
+			WinPathPrefix::VerbatimUnc { server, share } => WinPathPrefix::Unc {
+				server: server.clone(),
+				share: share.clone(),
+			},
+			WinPathPrefix::Verbatim(_) => return None,
+			_ => return Some(self.clone()),
+		};
+
+		if self.parts.iter().any(|p| p == "." || p == ".."
+			|| p.ends_with(['.', ' ']) || p.contains('/') || WinPath::is_reserved_name(p))
+		{
+			return None;
+		}
+		Some(WinPath { prefix, rooted: true, parts: self.parts.clone() })
+	}
+
+	/// Checks whether all names can be used to create files:
+	///
+	/// * names can't contain control chars, neither any of `<>:"/\|?*`;
+	/// * names can't end with a dot or a space, except `.` and `..`;
+	/// * names can't be reserved, as in
+	///   [`is_reserved_name`](crate::path::WinPath::is_reserved_name); this is
+	///   not checked for long-path prefixed paths.
+	///
+	/// Returns
+	/// [`co::ERROR::BAD_PATHNAME`](crate::co::ERROR::BAD_PATHNAME) if an UNC
+	/// path has no server or share name, or
+	/// [`co::ERROR::INVALID_NAME`](crate::co::ERROR::INVALID_NAME) for invalid
+	/// names.
+	pub fn validate(&self) -> SysResult<()> {
+		if let WinPathPrefix::Unc { server, share }
+			| WinPathPrefix::VerbatimUnc { server, share } = &self.prefix
+		{
+			if server.is_empty() || share.is_empty() {
+				return Err(co::ERROR::BAD_PATHNAME);
+			}
+		}
+
+		let verbatim = self.is_verbatim();
+		for part in self.parts.iter() {
+			if part.chars().any(|ch| ch < ' ' || "<>:\"/\\|?*".contains(ch))
+				|| (part != "." && part != ".." && part.ends_with(['.', ' ']))
+				|| (!verbatim && WinPath::is_reserved_name(part))
+			{
+				return Err(co::ERROR::INVALID_NAME);
+			}
+		}
+		Ok(())
+	}
+
+	fn append(&self, parts: &[String]) -> WinPath {
+		let mut new_path = self.clone();
+		new_path.parts.extend_from_slice(parts);
+		new_path
+	}
+
+	fn drive(&self) -> Option<char> {
+		match self.prefix {
+			WinPathPrefix::Drive(letter) | WinPathPrefix::VerbatimDrive(letter) => Some(letter),
+			_ => None,
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Returns the drive letter if the string starts with `X:`.
+fn drive_letter(s: &str) -> Option<char> {
+	let mut chars = s.chars();
+	match (chars.next(), chars.next()) {
+		(Some(letter), Some(':')) if letter.is_ascii_alphabetic() => Some(letter),
+		_ => None,
+	}
+}
+
+/// Splits the components at backslashes, skipping empty ones.
+fn split_parts(s: &str) -> Vec<String> {
+	s.split('\\')
+		.filter(|p| !p.is_empty())
+		.map(|p| p.to_owned())
+		.collect()
+}
+
+/// Removes the server and share names from the start of the components.
+fn take_server_share(parts: &mut Vec<String>) -> (String, String) {
+	let mut names = parts.drain(..parts.len().min(2));
+	let server = names.next().unwrap_or_default();
+	let share = names.next().unwrap_or_default();
+	(server, share)
+}
+
+/// Compares two prefixes case-insensitive.
+fn same_prefix(a: &WinPathPrefix, b: &WinPathPrefix) -> bool {
+	use WinPathPrefix as P;
+	let eq = |a: &str, b: &str| a.to_uppercase() == b.to_uppercase();
+	match (a, b) {
+		(P::None, P::None) => true,
+		(P::Drive(a), P::Drive(b))
+			| (P::VerbatimDrive(a), P::VerbatimDrive(b)) => a.eq_ignore_ascii_case(b),
+		(P::Unc { server: sa, share: ha }, P::Unc { server: sb, share: hb })
+			| (P::VerbatimUnc { server: sa, share: ha }, P::VerbatimUnc { server: sb, share: hb }) =>
+			eq(sa, sb) && eq(ha, hb),
+		(P::Verbatim(a), P::Verbatim(b))
+			| (P::Device(a), P::Device(b)) => eq(a, b),
+		_ => false,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn prefix_of(path: &str) -> WinPathPrefix {
+		WinPath::parse(path).prefix
+	}
+
+	fn normalized(path: &str) -> String {
+		WinPath::parse(path).normalize().to_string()
+	}
+
+	fn relative(path: &str, base: &str) -> Option<String> {
+		WinPath::parse(path)
+			.relative_to(&WinPath::parse(base))
+			.map(|p| p.to_string())
+	}
+
+	fn verbatim(path: &str) -> Option<String> {
+		WinPath::parse(path).to_verbatim().map(|p| p.to_string())
+	}
+
+	fn non_verbatim(path: &str) -> Option<String> {
+		WinPath::parse(path).to_non_verbatim().map(|p| p.to_string())
+	}
+
+	#[test]
+	fn parse() {
+		let unc = |server: &str, share: &str| WinPathPrefix::Unc {
+			server: server.to_owned(),
+			share: share.to_owned(),
+		};
+
+		assert_eq!(prefix_of(r"C:\foo"), WinPathPrefix::Drive('C'));
+		assert_eq!(prefix_of("c:foo"), WinPathPrefix::Drive('c'));
+		assert_eq!(prefix_of(r"\foo"), WinPathPrefix::None);
+		assert_eq!(prefix_of("foo"), WinPathPrefix::None);
+		assert_eq!(prefix_of(r"\\server\share\foo"), unc("server", "share"));
+		assert_eq!(prefix_of("//server/share/foo"), unc("server", "share"));
+		assert_eq!(prefix_of(r"\\server"), unc("server", ""));
+		assert_eq!(prefix_of(r"\\?\C:\foo"), WinPathPrefix::VerbatimDrive('C'));
+		assert_eq!(prefix_of(r"\??\C:\foo"), WinPathPrefix::VerbatimDrive('C'));
+		assert_eq!(prefix_of(r"\\?\unc\server\share"), WinPathPrefix::VerbatimUnc {
+			server: "server".to_owned(),
+			share: "share".to_owned(),
+		});
+		assert_eq!(prefix_of(r"\\?\Volume{1234}\foo"), WinPathPrefix::Verbatim("Volume{1234}".to_owned()));
+		assert_eq!(prefix_of(r"\\.\COM1"), WinPathPrefix::Device("COM1".to_owned()));
+		assert_eq!(prefix_of("//?/C:/foo"), WinPathPrefix::Device("C:".to_owned())); // not verbatim
+		assert_eq!(prefix_of("1:foo"), WinPathPrefix::None);
+
+		let p = WinPath::parse(r"C:\\foo/bar\\");
+		assert!(p.is_absolute() && p.is_rooted() && !p.is_verbatim());
+		assert_eq!(p.components(), ["foo", "bar"]);
+		assert_eq!(p.file_name(), Some("bar"));
+		assert_eq!(p.parent().unwrap().to_string(), r"C:\foo");
+		assert_eq!(WinPath::parse(r"C:\").parent(), None);
+
+		let p = WinPath::parse("C:foo");
+		assert!(!p.is_absolute() && !p.is_rooted());
+		let p = WinPath::parse(r"\foo");
+		assert!(!p.is_absolute() && p.is_rooted());
+		let p = WinPath::parse(r"\\?\C:\a/b");
+		assert!(p.is_verbatim());
+		assert_eq!(p.components(), ["a/b"]); // no slash conversion
+		assert_eq!(WinPath::parse("").components(), [] as [String; 0]);
+
+		for s in [
+			r"C:\foo\bar",
+			r"C:\",
+			"C:",
+			"C:foo",
+			r"\foo",
+			r"foo\bar",
+			"",
+			r"\\server\share",
+			r"\\server\share\foo",
+			r"\\?\C:\foo",
+			r"\\?\C:\",
+			r"\\?\UNC\server\share\foo",
+			r"\\?\Volume{1234}\foo",
+			r"\\.\COM1",
+			r"\\.\PhysicalDrive0",
+		] {
+			assert_eq!(WinPath::parse(s).to_string(), s);
+		}
+		assert_eq!(WinPath::parse("//server/share/a/b").to_string(), r"\\server\share\a\b");
+		assert_eq!(WinPath::parse(r"\??\C:\foo").to_string(), r"\\?\C:\foo");
+		assert_eq!(WinPath::parse(r"\\?\C:").to_string(), r"\\?\C:\");
+	}
+
+	#[test]
+	fn join() {
+		let base = WinPath::parse(r"C:\Temp");
+		assert_eq!(base.join(r"foo\bar.txt").to_string(), r"C:\Temp\foo\bar.txt");
+		assert_eq!(base.join(r"\foo").to_string(), r"C:\foo");
+		assert_eq!(base.join(r"D:\foo").to_string(), r"D:\foo");
+		assert_eq!(base.join("c:foo").to_string(), r"C:\Temp\foo");
+		assert_eq!(base.join("D:foo").to_string(), "D:foo");
+		assert_eq!(base.join(r"\\server\share").to_string(), r"\\server\share");
+		assert_eq!(WinPath::parse(r"\\server\share").join(r"\foo").to_string(), r"\\server\share\foo");
+	}
+
+	#[test]
+	fn normalize() {
+		assert_eq!(normalized(r"C:\Temp\.\foo\..\bar.txt"), r"C:\Temp\bar.txt");
+		assert_eq!(normalized(r"C:\..\..\a"), r"C:\a"); // can't climb above the root
+		assert_eq!(normalized(r"\\server\share\..\a"), r"\\server\share\a");
+		assert_eq!(normalized(r"..\a\..\..\b"), r"..\..\b");
+		assert_eq!(normalized(r"a\.."), "");
+		assert_eq!(normalized(r"a\\\b\"), r"a\b");
+		assert_eq!(normalized("C:/a/b"), r"C:\a\b");
+		assert_eq!(normalized(r"C:\foo\bar. . "), r"C:\foo\bar");
+		assert_eq!(normalized(r"C:\foo.\bar"), r"C:\foo.\bar"); // only the last one
+		assert_eq!(normalized(r"C:\a\..."), r"C:\a");
+		assert_eq!(normalized(r"\\?\C:\a\..\b."), r"\\?\C:\a\..\b."); // verbatim
+	}
+
+	#[test]
+	fn relative_to() {
+		assert_eq!(relative(r"C:\Temp\a\b.txt", r"C:\Temp\c"), Some(r"..\a\b.txt".to_owned()));
+		assert_eq!(relative(r"c:\TEMP\A", r"C:\temp"), Some("A".to_owned()));
+		assert_eq!(relative(r"C:\Temp", r"C:\Temp\"), Some("".to_owned()));
+		assert_eq!(relative(r"C:\a", r"C:\b\.\c\..\d"), Some(r"..\..\a".to_owned()));
+		assert_eq!(relative(r"a\b", r"a\c\d"), Some(r"..\..\b".to_owned()));
+		assert_eq!(relative(r"\\SERVER\Share\a", r"\\server\share"), Some("a".to_owned()));
+
+		assert_eq!(relative(r"C:\a", r"D:\a"), None);
+		assert_eq!(relative(r"C:\a", "C:a"), None);
+		assert_eq!(relative(r"\a", "a"), None);
+		assert_eq!(relative(r"\\server\share1\a", r"\\server\share2"), None);
+		assert_eq!(relative(r"a", r"..\b"), None);
+		assert_eq!(relative(r"\\?\C:\a", r"C:\a"), None);
+	}
+
+	#[test]
+	fn to_verbatim() {
+		assert_eq!(verbatim(r"\\server\share\foo"), Some(r"\\?\UNC\server\share\foo".to_owned()));
+		assert_eq!(verbatim(r"C:\a\..\b"), Some(r"\\?\C:\b".to_owned()));
+		assert_eq!(verbatim("C:/"), Some(r"\\?\C:\".to_owned()));
+		assert_eq!(verbatim(r"\\.\COM1"), Some(r"\\?\COM1".to_owned()));
+		assert_eq!(verbatim(r"\\?\C:\a\..\b"), Some(r"\\?\C:\a\..\b".to_owned()));
+		assert_eq!(verbatim("C:foo"), None);
+		assert_eq!(verbatim(r"\foo"), None);
+		assert_eq!(verbatim("foo"), None);
+
+		assert_eq!(non_verbatim(r"\\?\C:\a"), Some(r"C:\a".to_owned()));
+		assert_eq!(non_verbatim(r"\\?\UNC\server\share\a"), Some(r"\\server\share\a".to_owned()));
+		assert_eq!(non_verbatim(r"C:\a\.."), Some(r"C:\a\..".to_owned())); // unchanged
+		assert_eq!(non_verbatim(r"\\?\Volume{1234}\a"), None);
+		assert_eq!(non_verbatim(r"\\?\C:\a."), None);
+		assert_eq!(non_verbatim(r"\\?\C:\a\..\b"), None);
+		assert_eq!(non_verbatim(r"\\?\C:\a/b"), None);
+		assert_eq!(non_verbatim(r"\\?\C:\nul.txt"), None);
+	}
+
+	#[test]
+	fn reserved_names() {
+		for name in [
+			"CON", "con", "PRN", "AUX", "NUL", "nul.txt", "NUL .txt", "com1 ", "COM1.tar.gz",
+			"COM0", "LPT9", "COM\u{b9}", "lpt\u{b3}", "CONIN$", "conout$",
+		] {
+			assert!(WinPath::is_reserved_name(name), "{}", name);
+		}
+		for name in [
+			"", "CONSOLE", "COM", "LPT", "COM10", "COM\u{b9}0", "LPTx", "aux_", "file.con", " CON",
+			"COM\u{2074}",
+		] {
+			assert!(!WinPath::is_reserved_name(name), "{}", name);
+		}
+	}
+
+	#[test]
+	fn validate() {
+		assert!(WinPath::parse(r"C:\Temp\foo.txt").validate().is_ok());
+		assert!(WinPath::parse(r"C:\.\..\a").validate().is_ok());
+		assert!(WinPath::parse(r"\\?\C:\CON").validate().is_ok()); // verbatim
+		assert_eq!(WinPath::parse(r"\\server").validate(), Err(co::ERROR::BAD_PATHNAME));
+		assert_eq!(WinPath::parse(r"\\?\UNC\server").validate(), Err(co::ERROR::BAD_PATHNAME));
+		for bad in [r"C:\a<b", r"C:\a?", "C:\\a\x01", r"C:\CON", r"C:\a.", r"C:\a \b", r"C:\a:b"] {
+			assert_eq!(WinPath::parse(bad).validate(), Err(co::ERROR::INVALID_NAME), "{}", bad);
+		}
+	}
+}
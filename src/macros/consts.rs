@@ -28,7 +28,7 @@ macro_rules! const_values {
 }
 
 /// Declares the type of a constant, along with private and public values. Won't
-/// include `Debug` and `Display` impls.
+/// include `Debug`, `Display` and `NativeConst` impls.
 macro_rules! const_no_debug_display {
 	(
 		$name:ident : $ntype:ty;
@@ -41,8 +41,6 @@ macro_rules! const_no_debug_display {
 
 		impl_intunderlying!($name, $ntype);
 
-		impl crate::prelude::NativeConst for $name {}
-
 		impl std::fmt::LowerHex for $name {
 			fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
 				std::fmt::LowerHex::fmt(&self.0, f)
@@ -66,9 +64,9 @@ macro_rules! const_no_debug_display {
 	};
 }
 
-/// Declares the type of an ordinary constant, along with private and public
-/// values.
-macro_rules! const_ordinary {
+/// Declares the type of a constant, along with private and public values, and
+/// the table with the names of the public values. Won't include `Debug` impl.
+macro_rules! const_named {
 	(
 		$name:ident : $ntype:ty;
 		$( #[$doc:meta] )*
@@ -88,13 +86,21 @@ macro_rules! const_ordinary {
 			$( #[$doc] )*
 		}
 
+		impl crate::prelude::NativeConst for $name {
+			fn named_values() -> &'static [(&'static str, Self)] {
+				&[ $( (stringify!($pubvalname), Self::$pubvalname), )* ]
+			}
+		}
+
 		impl std::fmt::Display for $name {
 			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 				write!(f, "{:#010x}", self.0 as usize)
 			}
 		}
-		impl std::fmt::Debug for $name {
-			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+
+		impl $name {
+			/// Writes the value as its raw number, along with the type name.
+			fn fmt_raw(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 				if self.0 as usize > 0xffff {
 					write!(f, "[{:#010x} {}] {}",
 						self.0, self.0, stringify!($name))
@@ -120,8 +126,56 @@ macro_rules! const_ordinary {
 	};
 }
 
+/// Declares the type of an ordinary constant, along with private and public
+/// values.
+///
+/// The `Debug` impl shows the name of the value, if it's a public one.
+macro_rules! const_ordinary {
+	(
+		$name:ident : $ntype:ty;
+		$( #[$doc:meta] )*
+		=>
+		$(
+			$( #[$privvaldoc:meta] )*
+			$privvalname:ident $privval:expr
+		)*
+		=>
+		$(
+			$( #[$pubvaldoc:meta] )*
+			$pubvalname:ident $pubval:expr
+		)*
+	) => {
+		const_named! {
+			$name: $ntype;
+			$( #[$doc] )*
+			=>
+			$(
+				$( #[$privvaldoc] )*
+				$privvalname $privval
+			)*
+			=>
+			$(
+				$( #[$pubvaldoc] )*
+				$pubvalname $pubval
+			)*
+		}
+
+		impl std::fmt::Debug for $name {
+			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				match crate::prelude::NativeConst::name(self) {
+					Some(name) => write!(f, "{}::{}", stringify!($name), name),
+					None => self.fmt_raw(f),
+				}
+			}
+		}
+	};
+}
+
 /// Declares the type of an ordinary bitflag constant, along with private and
 /// public values.
+///
+/// The `Debug` impl shows the public values which compose the flags, like
+/// `WS::CAPTION | WS::VISIBLE`.
 macro_rules! const_bitflag {
 	(
 		$name:ident : $ntype:ty;
@@ -137,7 +191,7 @@ macro_rules! const_bitflag {
 			$pubvalname:ident $pubval:expr
 		)*
 	) => {
-		const_ordinary! {
+		const_named! {
 			$name: $ntype;
 			$( #[$doc] )*
 			///
@@ -161,6 +215,32 @@ macro_rules! const_bitflag {
 			}
 		}
 
+		impl std::fmt::Debug for $name {
+			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				let flags = crate::prelude::NativeBitflag::flags(self);
+				if flags.is_empty() {
+					return match crate::prelude::NativeConst::name(self) {
+						Some(name) => write!(f, "{}::{}", stringify!($name), name),
+						None => self.fmt_raw(f),
+					};
+				}
+
+				let mut rest = self.0;
+				for (idx, flag) in flags.iter().enumerate() {
+					if idx > 0 {
+						write!(f, " | ")?;
+					}
+					write!(f, "{}::{}", stringify!($name),
+						crate::prelude::NativeConst::name(flag).unwrap_or_default())?;
+					rest &= !flag.0;
+				}
+				if rest != 0 {
+					write!(f, " | {:#x}", rest)?; // bits without a name
+				}
+				Ok(())
+			}
+		}
+
 		// Bitflag operations.
 		impl std::ops::BitAnd for $name {
 			type Output = $name;
@@ -0,0 +1,354 @@
+/// Names and English descriptions of Media Foundation
+/// [`HRESULT`](crate::co::HRESULT) constants.
+///
+/// Sorted by code, so it can be binary-searched.
+pub(crate) const MF_HRESULT_MSGS: &[(u32, &str, &str)] = &[
+	(0x000d_36d8, "MF_S_MULTIPLE_BEGIN", "This callback and state had already been passed in to this event generator earlier."),
+	(0x000d_36fd, "MF_S_ACTIVATE_REPLACED", "The activate could not be created in the remote process for some reason; it was replaced with an empty one."),
+	(0x000d_4a42, "MF_S_SINK_NOT_FINALIZED", "The sink has not been finalized before shut down. This may cause the sink to generate corrupt content."),
+	(0x000d_61ad, "MF_S_SEQUENCER_CONTEXT_CANCELED", "The context was canceled."),
+	(0x000d_61af, "MF_S_SEQUENCER_SEGMENT_AT_END_OF_STREAM", "Cannot update the topology flags."),
+	(0x000d_6d75, "MF_S_TRANSFORM_DO_NOT_PROPAGATE_EVENT", "The caller should not propagate this event to downstream components."),
+	(0x000d_7150, "MF_S_PROTECTION_NOT_REQUIRED", "Protection for this content is not required."),
+	(0x000d_7168, "MF_S_WAIT_FOR_POLICY_SET", "Protection for this content is pending the policy being set."),
+	(0x000d_7169, "MF_S_VIDEO_DISABLED_WITH_UNKNOWN_SOFTWARE_OUTPUT", "Video playback is disabled because an unknown software output is connected."),
+	(0x000d_7173, "MF_S_PE_TRUSTED", "The Protected Environment is trusted."),
+	(0x000d_9c44, "MF_S_CLOCK_STOPPED", "Timer::SetTimer returns this success code if called when the clock is stopped. The timer will not fire until the clock is started again."),
+	(0x000d_9c45, "MF_E_CLOCK_AUDIO_DEVICE_POSITION_UNEXPECTED", "MF clock received an unexpected audio device position."),
+	(0x000d_9c46, "MF_E_CLOCK_AUDIO_RENDER_POSITION_UNEXPECTED", "MF clock received an unexpected audio render position."),
+	(0x000d_9c47, "MF_E_CLOCK_AUDIO_RENDER_TIME_UNEXPECTED", "MF clock received an unexpected audio render time."),
+	(0x400d_3a98, "MF_S_ASF_PARSEINPROGRESS", "Parsing is still in progress and is not yet complete."),
+	(0x400d_4272, "MF_I_MANUAL_PROXY", "The proxy setting is manual."),
+	(0xc00d_36b0, "MF_E_PLATFORM_NOT_INITIALIZED", "Platform not initialized. Please call MFStartup()."),
+	(0xc00d_36b1, "MF_E_BUFFERTOOSMALL", "The buffer was too small to carry out the requested action."),
+	(0xc00d_36b2, "MF_E_INVALIDREQUEST", "The request is invalid in the current state."),
+	(0xc00d_36b3, "MF_E_INVALIDSTREAMNUMBER", "The stream number provided was invalid."),
+	(0xc00d_36b4, "MF_E_INVALIDMEDIATYPE", "The data specified for the media type is invalid, inconsistent, or not supported by this object."),
+	(0xc00d_36b5, "MF_E_NOTACCEPTING", "The callee is currently not accepting further input."),
+	(0xc00d_36b6, "MF_E_NOT_INITIALIZED", "This object needs to be initialized before the requested operation can be carried out."),
+	(0xc00d_36b7, "MF_E_UNSUPPORTED_REPRESENTATION", "The data specified for the media type is invalid, inconsistent, or not supported by this object."),
+	(0xc00d_36b9, "MF_E_NO_MORE_TYPES", "The object does not have any more media types."),
+	(0xc00d_36ba, "MF_E_UNSUPPORTED_SERVICE", "The object does not support the specified service."),
+	(0xc00d_36bb, "MF_E_UNEXPECTED", "An unexpected error has occurred in the operation requested."),
+	(0xc00d_36bc, "MF_E_INVALIDNAME", "The file name is invalid."),
+	(0xc00d_36bd, "MF_E_INVALIDTYPE", "The data specified for the media type is invalid, inconsistent, or not supported by this object."),
+	(0xc00d_36be, "MF_E_INVALID_FILE_FORMAT", "The file structure is invalid."),
+	(0xc00d_36bf, "MF_E_INVALIDINDEX", "The index is invalid."),
+	(0xc00d_36c0, "MF_E_INVALID_TIMESTAMP", "An invalid timestamp was given."),
+	(0xc00d_36c3, "MF_E_UNSUPPORTED_SCHEME", "The scheme of the given URL is unsupported."),
+	(0xc00d_36c4, "MF_E_UNSUPPORTED_BYTESTREAM_TYPE", "The byte stream type of the given URL is unsupported."),
+	(0xc00d_36c5, "MF_E_UNSUPPORTED_TIME_FORMAT", "The given time format is unsupported."),
+	(0xc00d_36c8, "MF_E_NO_SAMPLE_TIMESTAMP", "The Media Sample does not have a timestamp."),
+	(0xc00d_36c9, "MF_E_NO_SAMPLE_DURATION", "The Media Sample does not have a duration."),
+	(0xc00d_36cb, "MF_E_INVALID_STREAM_DATA", "The request failed because the data in the stream is corrupt."),
+	(0xc00d_36cf, "MF_E_RT_UNAVAILABLE", "Real time services are not available."),
+	(0xc00d_36d0, "MF_E_UNSUPPORTED_RATE", "The specified rate is not supported."),
+	(0xc00d_36d1, "MF_E_THINNING_UNSUPPORTED", "This component does not support stream-thinning."),
+	(0xc00d_36d2, "MF_E_REVERSE_UNSUPPORTED", "The call failed because no reverse playback rates are available."),
+	(0xc00d_36d3, "MF_E_UNSUPPORTED_RATE_TRANSITION", "The requested rate transition cannot occur in the current state."),
+	(0xc00d_36d4, "MF_E_RATE_CHANGE_PREEMPTED", "The requested rate change has been pre-empted and will not occur."),
+	(0xc00d_36d5, "MF_E_NOT_FOUND", "The specified object or value does not exist."),
+	(0xc00d_36d6, "MF_E_NOT_AVAILABLE", "The requested value is not available."),
+	(0xc00d_36d7, "MF_E_NO_CLOCK", "The specified operation requires a clock and no clock is available."),
+	(0xc00d_36d9, "MF_E_MULTIPLE_BEGIN", "This callback has already been passed in to this event generator."),
+	(0xc00d_36da, "MF_E_MULTIPLE_SUBSCRIBERS", "Some component is already listening to events on this event generator."),
+	(0xc00d_36db, "MF_E_TIMER_ORPHANED", "This timer was orphaned before its callback time arrived."),
+	(0xc00d_36dc, "MF_E_STATE_TRANSITION_PENDING", "A state transition is already pending."),
+	(0xc00d_36dd, "MF_E_UNSUPPORTED_STATE_TRANSITION", "The requested state transition is unsupported."),
+	(0xc00d_36de, "MF_E_UNRECOVERABLE_ERROR_OCCURRED", "An unrecoverable error has occurred."),
+	(0xc00d_36df, "MF_E_SAMPLE_HAS_TOO_MANY_BUFFERS", "The provided sample has too many buffers."),
+	(0xc00d_36e0, "MF_E_SAMPLE_NOT_WRITABLE", "The provided sample is not writable."),
+	(0xc00d_36e2, "MF_E_INVALID_KEY", "The specified key is not valid."),
+	(0xc00d_36e3, "MF_E_BAD_STARTUP_VERSION", "You are calling MFStartup with the wrong MF_VERSION. Mismatched bits?"),
+	(0xc00d_36e4, "MF_E_UNSUPPORTED_CAPTION", "The caption of the given URL is unsupported."),
+	(0xc00d_36e5, "MF_E_INVALID_POSITION", "The operation on the current offset is not permitted."),
+	(0xc00d_36e6, "MF_E_ATTRIBUTENOTFOUND", "The requested attribute was not found."),
+	(0xc00d_36e7, "MF_E_PROPERTY_TYPE_NOT_ALLOWED", "The requested property type is not allowed."),
+	(0xc00d_36e8, "MF_E_PROPERTY_TYPE_NOT_SUPPORTED", "The requested property type is not supported."),
+	(0xc00d_36e9, "MF_E_PROPERTY_EMPTY", "The requested property is empty."),
+	(0xc00d_36ea, "MF_E_PROPERTY_NOT_EMPTY", "The requested property is not empty."),
+	(0xc00d_36eb, "MF_E_PROPERTY_VECTOR_NOT_ALLOWED", "The vector property specified is not allowed in this context."),
+	(0xc00d_36ec, "MF_E_PROPERTY_VECTOR_REQUIRED", "A vector property is required in this context."),
+	(0xc00d_36ed, "MF_E_OPERATION_CANCELLED", "The operation was cancelled."),
+	(0xc00d_36ee, "MF_E_BYTESTREAM_NOT_SEEKABLE", "The provided byte stream was expected to be seekable and it was not."),
+	(0xc00d_36ef, "MF_E_DISABLED_IN_SAFEMODE", "The Media Foundation platform is disabled when the system is running in Safe Mode."),
+	(0xc00d_36f0, "MF_E_CANNOT_PARSE_BYTESTREAM", "The media source could not parse the byte stream."),
+	(0xc00d_36f1, "MF_E_SOURCERESOLVER_MUTUALLY_EXCLUSIVE_FLAGS", "Mutually exclusive flags have been specified to the source resolver. This flag combination is invalid."),
+	(0xc00d_36f2, "MF_E_MEDIAPROC_WRONGSTATE", "MediaProc is in the wrong state."),
+	(0xc00d_36f3, "MF_E_RT_THROUGHPUT_NOT_AVAILABLE", "Real time I/O service can not provide requested throughput."),
+	(0xc00d_36f4, "MF_E_RT_TOO_MANY_CLASSES", "The work queue cannot be registered with more classes."),
+	(0xc00d_36f5, "MF_E_RT_WOULDBLOCK", "This operation cannot succeed because another thread owns this object."),
+	(0xc00d_36f6, "MF_E_NO_BITPUMP", "Internal. Bitpump not found."),
+	(0xc00d_36f7, "MF_E_RT_OUTOFMEMORY", "No more RT memory available."),
+	(0xc00d_36f8, "MF_E_RT_WORKQUEUE_CLASS_NOT_SPECIFIED", "An MMCSS class has not been set for this work queue."),
+	(0xc00d_36fa, "MF_E_CANNOT_CREATE_SINK", "The media sink could not be created for the specified format."),
+	(0xc00d_36fb, "MF_E_BYTESTREAM_UNKNOWN_LENGTH", "The length of the provided byte stream is unknown."),
+	(0xc00d_36fc, "MF_E_SESSION_PAUSEWHILESTOPPED", "The media session cannot pause from a stopped state."),
+	(0xc00d_36fe, "MF_E_FORMAT_CHANGE_NOT_SUPPORTED", "The data specified for the media type is supported, but would require a format change, which is not supported by this object."),
+	(0xc00d_36ff, "MF_E_INVALID_WORKQUEUE", "The operation failed because an invalid combination of work queue ID and flags was specified."),
+	(0xc00d_3700, "MF_E_DRM_UNSUPPORTED", "No DRM support is available."),
+	(0xc00d_3701, "MF_E_UNAUTHORIZED", "This operation is not authorized."),
+	(0xc00d_3702, "MF_E_OUT_OF_RANGE", "The value is not in the specified or valid range."),
+	(0xc00d_3703, "MF_E_INVALID_CODEC_MERIT", "The registered codec merit is not valid."),
+	(0xc00d_3704, "MF_E_HW_MFT_FAILED_START_STREAMING", "Hardware MFT failed to start streaming due to lack of hardware resources."),
+	(0xc00d_3705, "MF_E_OPERATION_IN_PROGRESS", "The operation is already in progress."),
+	(0xc00d_3706, "MF_E_HARDWARE_DRM_UNSUPPORTED", "No Hardware DRM support is available."),
+	(0xc00d_3707, "MF_E_DURATION_TOO_LONG", "The specified duration is too long."),
+	(0xc00d_3708, "MF_E_OPERATION_UNSUPPORTED_AT_D3D_FEATURE_LEVEL", "The attempted operation is not supported at the current D3D feature level."),
+	(0xc00d_3709, "MF_E_UNSUPPORTED_MEDIATYPE_AT_D3D_FEATURE_LEVEL", "The media type is not supported at the current D3D feature level."),
+	(0xc00d_3a98, "MF_E_ASF_PARSINGINCOMPLETE", "Not enough data has been parsed to carry out the requested action."),
+	(0xc00d_3a99, "MF_E_ASF_MISSINGDATA", "There is a gap in the ASF data provided."),
+	(0xc00d_3a9a, "MF_E_ASF_INVALIDDATA", "The data provided are not valid ASF."),
+	(0xc00d_3a9b, "MF_E_ASF_OPAQUEPACKET", "The packet is opaque, so the requested information cannot be returned."),
+	(0xc00d_3a9c, "MF_E_ASF_NOINDEX", "The requested operation failed since there is no appropriate ASF index."),
+	(0xc00d_3a9d, "MF_E_ASF_OUTOFRANGE", "The value supplied is out of range for this operation."),
+	(0xc00d_3a9e, "MF_E_ASF_INDEXNOTLOADED", "The index entry requested needs to be loaded before it can be available."),
+	(0xc00d_3a9f, "MF_E_ASF_TOO_MANY_PAYLOADS", "The packet has reached the maximum number of payloads."),
+	(0xc00d_3aa0, "MF_E_ASF_UNSUPPORTED_STREAM_TYPE", "Stream type is not supported."),
+	(0xc00d_3aa1, "MF_E_ASF_DROPPED_PACKET", "One or more ASF packets were dropped."),
+	(0xc00d_3e80, "MF_E_NO_EVENTS_AVAILABLE", "There are no events available in the queue."),
+	(0xc00d_3e82, "MF_E_INVALID_STATE_TRANSITION", "A media source cannot go from the stopped state to the paused state."),
+	(0xc00d_3e84, "MF_E_END_OF_STREAM", "The media stream cannot process any more samples because there are no more samples in the stream."),
+	(0xc00d_3e85, "MF_E_SHUTDOWN", "The request is invalid because Shutdown() has been called."),
+	(0xc00d_3e86, "MF_E_MP3_NOTFOUND", "The MP3 object was not found."),
+	(0xc00d_3e87, "MF_E_MP3_OUTOFDATA", "The MP3 parser ran out of data before finding the MP3 object."),
+	(0xc00d_3e88, "MF_E_MP3_NOTMP3", "The file is not really a MP3 file."),
+	(0xc00d_3e89, "MF_E_MP3_NOTSUPPORTED", "The MP3 file is not supported."),
+	(0xc00d_3e8a, "MF_E_NO_DURATION", "The Media stream has no duration."),
+	(0xc00d_3e8c, "MF_E_INVALID_FORMAT", "The Media format is recognized but is invalid."),
+	(0xc00d_3e8d, "MF_E_PROPERTY_NOT_FOUND", "The property requested was not found."),
+	(0xc00d_3e8e, "MF_E_PROPERTY_READ_ONLY", "The property is read only."),
+	(0xc00d_3e8f, "MF_E_PROPERTY_NOT_ALLOWED", "The specified property is not allowed in this context."),
+	(0xc00d_3e91, "MF_E_MEDIA_SOURCE_NOT_STARTED", "The media source is not started."),
+	(0xc00d_3e98, "MF_E_UNSUPPORTED_FORMAT", "The Media format is recognized but not supported."),
+	(0xc00d_3e99, "MF_E_MP3_BAD_CRC", "The MPEG frame has bad CRC."),
+	(0xc00d_3e9a, "MF_E_NOT_PROTECTED", "The file is not protected."),
+	(0xc00d_3e9b, "MF_E_MEDIA_SOURCE_WRONGSTATE", "The media source is in the wrong state."),
+	(0xc00d_3e9c, "MF_E_MEDIA_SOURCE_NO_STREAMS_SELECTED", "The media source does not have any streams selected."),
+	(0xc00d_3e9d, "MF_E_CANNOT_FIND_KEYFRAME_SAMPLE", "Cannot find the keyframe sample."),
+	(0xc00d_3e9e, "MF_E_UNSUPPORTED_CHARACTERISTICS", "The characteristics of the media source are not supported."),
+	(0xc00d_3e9f, "MF_E_NO_AUDIO_RECORDING_DEVICE", "No audio recording device was found."),
+	(0xc00d_3ea0, "MF_E_AUDIO_RECORDING_DEVICE_IN_USE", "The requested audio recording device is currently in use."),
+	(0xc00d_3ea1, "MF_E_AUDIO_RECORDING_DEVICE_INVALIDATED", "The audio recording device is no longer present."),
+	(0xc00d_3ea2, "MF_E_VIDEO_RECORDING_DEVICE_INVALIDATED", "The video recording device is no longer present."),
+	(0xc00d_3ea3, "MF_E_VIDEO_RECORDING_DEVICE_PREEMPTED", "The video recording device is preempted by another immersive application."),
+	(0xc00d_4268, "MF_E_NETWORK_RESOURCE_FAILURE", "An attempt to acquire a network resource failed."),
+	(0xc00d_4269, "MF_E_NET_WRITE", "Error writing to the network."),
+	(0xc00d_426a, "MF_E_NET_READ", "Error reading from the network."),
+	(0xc00d_426b, "MF_E_NET_REQUIRE_NETWORK", "Internal. Entry cannot complete operation without network."),
+	(0xc00d_426c, "MF_E_NET_REQUIRE_ASYNC", "Internal. Async op is required."),
+	(0xc00d_426d, "MF_E_NET_BWLEVEL_NOT_SUPPORTED", "Internal. Bandwidth levels are not supported."),
+	(0xc00d_426e, "MF_E_NET_STREAMGROUPS_NOT_SUPPORTED", "Internal. Stream groups are not supported."),
+	(0xc00d_426f, "MF_E_NET_MANUALSS_NOT_SUPPORTED", "Manual stream selection is not supported."),
+	(0xc00d_4270, "MF_E_NET_INVALID_PRESENTATION_DESCRIPTOR", "Invalid presentation descriptor."),
+	(0xc00d_4271, "MF_E_NET_CACHESTREAM_NOT_FOUND", "Cannot find the cache stream."),
+	(0xc00d_4274, "MF_E_NET_REQUIRE_INPUT", "Internal. Entry cannot complete operation without input."),
+	(0xc00d_4275, "MF_E_NET_REDIRECT", "The client was redirected to another server."),
+	(0xc00d_4276, "MF_E_NET_REDIRECT_TO_PROXY", "The client was redirected to a proxy server."),
+	(0xc00d_4277, "MF_E_NET_TOO_MANY_REDIRECTS", "The client reached the maximum redirection limit."),
+	(0xc00d_4278, "MF_E_NET_TIMEOUT", "The server, a computer set up to offer multimedia content to other computers, could not handle your request for multimedia content in a timely manner. Please try again later."),
+	(0xc00d_4279, "MF_E_NET_CLIENT_CLOSE", "The control socket is closed by the client."),
+	(0xc00d_427a, "MF_E_NET_BAD_CONTROL_DATA", "The server received invalid data from the client on the control connection."),
+	(0xc00d_427b, "MF_E_NET_INCOMPATIBLE_SERVER", "The server is not a compatible streaming media server."),
+	(0xc00d_427c, "MF_E_NET_UNSAFE_URL", "The URL is unsafe."),
+	(0xc00d_427d, "MF_E_NET_CACHE_NO_DATA", "The network cache does not contain any data."),
+	(0xc00d_427e, "MF_E_NET_EOL", "The end of the line has been reached."),
+	(0xc00d_427f, "MF_E_NET_BAD_REQUEST", "The request could not be understood by the server."),
+	(0xc00d_4280, "MF_E_NET_INTERNAL_SERVER_ERROR", "The server encountered an unexpected condition which prevented it from fulfilling the request."),
+	(0xc00d_4281, "MF_E_NET_SESSION_NOT_FOUND", "Session not found."),
+	(0xc00d_4282, "MF_E_NET_NOCONNECTION", "There is no connection established with the Windows Media server. The operation failed."),
+	(0xc00d_4283, "MF_E_NET_CONNECTION_FAILURE", "The network connection has failed."),
+	(0xc00d_4284, "MF_E_NET_INCOMPATIBLE_PUSHSERVER", "The Server service that received the HTTP push request is not a compatible version of Windows Media Services (WMS)."),
+	(0xc00d_4285, "MF_E_NET_SERVER_ACCESSDENIED", "The Windows Media server is denying access. The username and/or password might be incorrect."),
+	(0xc00d_4286, "MF_E_NET_PROXY_ACCESSDENIED", "The proxy server is denying access. The username and/or password might be incorrect."),
+	(0xc00d_4287, "MF_E_NET_CANNOTCONNECT", "Unable to establish a connection to the server."),
+	(0xc00d_4288, "MF_E_NET_INVALID_PUSH_TEMPLATE", "The specified push template is invalid."),
+	(0xc00d_4289, "MF_E_NET_INVALID_PUSH_PUBLISHING_POINT", "The specified push publishing point is invalid."),
+	(0xc00d_428a, "MF_E_NET_BUSY", "The requested resource is in use."),
+	(0xc00d_428b, "MF_E_NET_RESOURCE_GONE", "The Publishing Point or file on the Windows Media Server is no longer available."),
+	(0xc00d_428c, "MF_E_NET_ERROR_FROM_PROXY", "The proxy experienced an error while attempting to contact the media server."),
+	(0xc00d_428d, "MF_E_NET_PROXY_TIMEOUT", "The proxy did not receive a timely response while attempting to contact the media server."),
+	(0xc00d_428e, "MF_E_NET_SERVER_UNAVAILABLE", "The server is currently unable to handle the request due to a temporary overloading or maintenance of the server."),
+	(0xc00d_428f, "MF_E_NET_TOO_MUCH_DATA", "The encoding process was unable to keep up with the amount of supplied data."),
+	(0xc00d_4290, "MF_E_NET_SESSION_INVALID", "Session not found."),
+	(0xc00d_4291, "MF_E_OFFLINE_MODE", "The requested URL is not available in offline mode."),
+	(0xc00d_4292, "MF_E_NET_UDP_BLOCKED", "A device in the network is blocking UDP traffic."),
+	(0xc00d_4293, "MF_E_NET_UNSUPPORTED_CONFIGURATION", "The specified configuration value is not supported."),
+	(0xc00d_4294, "MF_E_NET_PROTOCOL_DISABLED", "The networking protocol is disabled."),
+	(0xc00d_4295, "MF_E_NET_COMPANION_DRIVER_DISCONNECT", "The companion driver asked the OS to disconnect from the receiver."),
+	(0xc00d_4650, "MF_E_ALREADY_INITIALIZED", "This object has already been initialized and cannot be re-initialized at this time."),
+	(0xc00d_4651, "MF_E_BANDWIDTH_OVERRUN", "The amount of data passed in exceeds the given bitrate and buffer window."),
+	(0xc00d_4652, "MF_E_LATE_SAMPLE", "The sample was passed in too late to be correctly processed."),
+	(0xc00d_4653, "MF_E_FLUSH_NEEDED", "The requested action cannot be carried out until the object is flushed and the queue is emptied."),
+	(0xc00d_4654, "MF_E_INVALID_PROFILE", "The profile is invalid."),
+	(0xc00d_4655, "MF_E_INDEX_NOT_COMMITTED", "The index that is being generated needs to be committed before the requested action can be carried out."),
+	(0xc00d_4656, "MF_E_NO_INDEX", "The index that is necessary for the requested action is not found."),
+	(0xc00d_4657, "MF_E_CANNOT_INDEX_IN_PLACE", "The requested index cannot be added in-place to the specified ASF content."),
+	(0xc00d_4658, "MF_E_MISSING_ASF_LEAKYBUCKET", "The ASF leaky bucket parameters must be specified in order to carry out this request."),
+	(0xc00d_4659, "MF_E_INVALID_ASF_STREAMID", "The stream id is invalid. The valid range for ASF stream id is from 1 to 127."),
+	(0xc00d_4a38, "MF_E_STREAMSINK_REMOVED", "The requested Stream Sink has been removed and cannot be used."),
+	(0xc00d_4a3a, "MF_E_STREAMSINKS_OUT_OF_SYNC", "The various Stream Sinks in this Media Sink are too far out of sync for the requested action to take place."),
+	(0xc00d_4a3b, "MF_E_STREAMSINKS_FIXED", "Stream Sinks cannot be added to or removed from this Media Sink because its set of streams is fixed."),
+	(0xc00d_4a3c, "MF_E_STREAMSINK_EXISTS", "The given Stream Sink already exists."),
+	(0xc00d_4a3d, "MF_E_SAMPLEALLOCATOR_CANCELED", "Sample allocations have been canceled."),
+	(0xc00d_4a3e, "MF_E_SAMPLEALLOCATOR_EMPTY", "The sample allocator is currently empty, due to outstanding requests."),
+	(0xc00d_4a3f, "MF_E_SINK_ALREADYSTOPPED", "When we try to stop a stream sink, it is already stopped."),
+	(0xc00d_4a40, "MF_E_ASF_FILESINK_BITRATE_UNKNOWN", "The ASF file sink could not reserve AVIO because the bitrate is unknown."),
+	(0xc00d_4a41, "MF_E_SINK_NO_STREAMS", "No streams are selected in the sink presentation descriptor."),
+	(0xc00d_4a43, "MF_E_METADATA_TOO_LONG", "A metadata item was too long to write to the output container."),
+	(0xc00d_4a44, "MF_E_SINK_NO_SAMPLES_PROCESSED", "The operation failed because no samples were processed by the sink."),
+	(0xc00d_4a45, "MF_E_SINK_HEADERS_NOT_FOUND", "Sink could not create valid output file because required headers were not provided to the sink."),
+	(0xc00d_4e20, "MF_E_VIDEO_REN_NO_PROCAMP_HW", "There is no available procamp hardware with which to perform color correction."),
+	(0xc00d_4e21, "MF_E_VIDEO_REN_NO_DEINTERLACE_HW", "There is no available deinterlacing hardware with which to deinterlace the video stream."),
+	(0xc00d_4e22, "MF_E_VIDEO_REN_COPYPROT_FAILED", "A video stream requires copy protection to be enabled, but there was a failure in attempting to enable copy protection."),
+	(0xc00d_4e23, "MF_E_VIDEO_REN_SURFACE_NOT_SHARED", "A component is attempting to access a surface for sharing that is not shared."),
+	(0xc00d_4e24, "MF_E_VIDEO_DEVICE_LOCKED", "A component is attempting to access a shared device that is already locked by another component."),
+	(0xc00d_4e25, "MF_E_NEW_VIDEO_DEVICE", "The device is no longer available. The handle should be closed and a new one opened."),
+	(0xc00d_4e26, "MF_E_NO_VIDEO_SAMPLE_AVAILABLE", "A video sample is not currently queued on a stream that is required for mixing."),
+	(0xc00d_4e84, "MF_E_NO_AUDIO_PLAYBACK_DEVICE", "No audio playback device was found."),
+	(0xc00d_4e85, "MF_E_AUDIO_PLAYBACK_DEVICE_IN_USE", "The requested audio playback device is currently in use."),
+	(0xc00d_4e86, "MF_E_AUDIO_PLAYBACK_DEVICE_INVALIDATED", "The audio playback device is no longer present."),
+	(0xc00d_4e87, "MF_E_AUDIO_SERVICE_NOT_RUNNING", "The audio service is not running."),
+	(0xc00d_4e88, "MF_E_AUDIO_BUFFER_SIZE_ERROR", "The audio renderer failed to allocate a buffer of the required size."),
+	(0xc00d_4e89, "MF_E_AUDIO_CLIENT_WRAPPER_SPOOF_ERROR", "The audio client wrapper failed to spoof the audio client."),
+	(0xc00d_520e, "MF_E_TOPO_INVALID_OPTIONAL_NODE", "The topology contains an invalid optional node. Possible reasons are incorrect number of outputs and inputs or optional node is at the beginning or end of a segment."),
+	(0xc00d_5211, "MF_E_TOPO_CANNOT_FIND_DECRYPTOR", "No suitable transform was found to decrypt the content."),
+	(0xc00d_5212, "MF_E_TOPO_CODEC_NOT_FOUND", "No suitable transform was found to encode or decode the content."),
+	(0xc00d_5213, "MF_E_TOPO_CANNOT_CONNECT", "Unable to find a way to connect nodes."),
+	(0xc00d_5214, "MF_E_TOPO_UNSUPPORTED", "Unsupported operations in the topoloader."),
+	(0xc00d_5215, "MF_E_TOPO_INVALID_TIME_ATTRIBUTES", "The topology or its nodes contain incorrectly set time attributes."),
+	(0xc00d_5216, "MF_E_TOPO_LOOPS_IN_TOPOLOGY", "The topology contains loops, which are unsupported in media foundation topologies."),
+	(0xc00d_5217, "MF_E_TOPO_MISSING_PRESENTATION_DESCRIPTOR", "A source stream node in the topology does not have a presentation descriptor."),
+	(0xc00d_5218, "MF_E_TOPO_MISSING_STREAM_DESCRIPTOR", "A source stream node in the topology does not have a stream descriptor."),
+	(0xc00d_5219, "MF_E_TOPO_STREAM_DESCRIPTOR_NOT_SELECTED", "A stream descriptor was set on a source stream node but it was not selected on the presentation descriptor."),
+	(0xc00d_521a, "MF_E_TOPO_MISSING_SOURCE", "A source stream node in the topology does not have a source."),
+	(0xc00d_521b, "MF_E_TOPO_SINK_ACTIVATES_UNSUPPORTED", "The topology loader does not support sink activates on output nodes."),
+	(0xc00d_61ac, "MF_E_SEQUENCER_UNKNOWN_SEGMENT_ID", "The sequencer cannot find a segment with the given ID."),
+	(0xc00d_61ae, "MF_E_NO_SOURCE_IN_CACHE", "Cannot find source in the source cache."),
+	(0xc00d_6d60, "MF_E_TRANSFORM_TYPE_NOT_SET", "A valid type has not been set for this stream or a stream that it depends on."),
+	(0xc00d_6d61, "MF_E_TRANSFORM_STREAM_CHANGE", "A stream change has occurred. Output cannot be produced until the streams have been renegotiated."),
+	(0xc00d_6d62, "MF_E_TRANSFORM_INPUT_REMAINING", "The transform cannot produce output until it gets more input samples."),
+	(0xc00d_6d63, "MF_E_TRANSFORM_PROFILE_MISSING", "The requested operation requires a profile, but none was set."),
+	(0xc00d_6d64, "MF_E_TRANSFORM_PROFILE_INVALID_OR_CORRUPT", "The transform profile is invalid or corrupted."),
+	(0xc00d_6d65, "MF_E_TRANSFORM_PROFILE_TRUNCATED", "The transform profile is truncated."),
+	(0xc00d_6d66, "MF_E_TRANSFORM_PROPERTY_PID_NOT_RECOGNIZED", "The property ID does not match any property supported by the transform."),
+	(0xc00d_6d67, "MF_E_TRANSFORM_PROPERTY_VARIANT_TYPE_WRONG", "The variant does not have the type expected for this property ID."),
+	(0xc00d_6d68, "MF_E_TRANSFORM_PROPERTY_NOT_WRITEABLE", "An attempt was made to set the value on a read-only property."),
+	(0xc00d_6d69, "MF_E_TRANSFORM_PROPERTY_ARRAY_VALUE_WRONG_NUM_DIM", "The array property value has an unexpected number of dimensions."),
+	(0xc00d_6d6a, "MF_E_TRANSFORM_PROPERTY_VALUE_SIZE_WRONG", "The array or blob property value has an unexpected size."),
+	(0xc00d_6d6b, "MF_E_TRANSFORM_PROPERTY_VALUE_OUT_OF_RANGE", "The property value is out of range for this transform."),
+	(0xc00d_6d6c, "MF_E_TRANSFORM_PROPERTY_VALUE_INCOMPATIBLE", "The property value is incompatible with some other property or media type set on the transform."),
+	(0xc00d_6d6d, "MF_E_TRANSFORM_NOT_POSSIBLE_FOR_CURRENT_OUTPUT_MEDIATYPE", "The requested operation is not supported for the currently set output media type."),
+	(0xc00d_6d6e, "MF_E_TRANSFORM_NOT_POSSIBLE_FOR_CURRENT_INPUT_MEDIATYPE", "The requested operation is not supported for the currently set input media type."),
+	(0xc00d_6d6f, "MF_E_TRANSFORM_NOT_POSSIBLE_FOR_CURRENT_MEDIATYPE_COMBINATION", "The requested operation is not supported for the currently set combination of media types."),
+	(0xc00d_6d70, "MF_E_TRANSFORM_CONFLICTS_WITH_OTHER_CURRENTLY_ENABLED_FEATURES", "The requested feature is not supported in combination with some other currently enabled feature."),
+	(0xc00d_6d72, "MF_E_TRANSFORM_NEED_MORE_INPUT", "The transform cannot produce output until it gets more input samples."),
+	(0xc00d_6d73, "MF_E_TRANSFORM_NOT_POSSIBLE_FOR_CURRENT_SPKR_CONFIG", "The requested operation is not supported for the current speaker configuration."),
+	(0xc00d_6d74, "MF_E_TRANSFORM_CANNOT_CHANGE_MEDIATYPE_WHILE_PROCESSING", "The transform cannot accept media type changes in the middle of processing."),
+	(0xc00d_6d76, "MF_E_UNSUPPORTED_D3D_TYPE", "The input type is not supported for D3D device."),
+	(0xc00d_6d77, "MF_E_TRANSFORM_ASYNC_LOCKED", "The caller does not appear to support this transform's asynchronous capabilities."),
+	(0xc00d_6d78, "MF_E_TRANSFORM_CANNOT_INITIALIZE_ACM_DRIVER", "An audio compression manager driver could not be initialized by the transform."),
+	(0xc00d_6d79, "MF_E_TRANSFORM_STREAM_INVALID_RESOLUTION", "The input stream has invalid and illegal resolution. Output should stop on next ProcessOutput call after the invalid and illegal resolution is detected."),
+	(0xc00d_6d7a, "MF_E_TRANSFORM_ASYNC_MFT_NOT_SUPPORTED", "The transform cannot be asynchronous in the current context."),
+	(0xc00d_6d7c, "MF_E_TRANSFORM_EXATTRIBUTE_NOT_SUPPORTED", "It is not supported in the current context to have the transform copy attributes from an input sample to an output sample."),
+	(0xc00d_7148, "MF_E_LICENSE_INCORRECT_RIGHTS", "You are not allowed to open this file. Contact the content provider for further assistance."),
+	(0xc00d_7149, "MF_E_LICENSE_OUTOFDATE", "The license for this media content has expired. Contact the content provider for further assistance."),
+	(0xc00d_714a, "MF_E_LICENSE_REQUIRED", "You need a license to perform the requested operation on this media file."),
+	(0xc00d_714b, "MF_E_DRM_HARDWARE_INCONSISTENT", "The licenses for your media files are corrupted. Contact Microsoft product support."),
+	(0xc00d_714c, "MF_E_NO_CONTENT_PROTECTION_MANAGER", "The APP needs to provide IMFContentProtectionManager callback to access the protected media file."),
+	(0xc00d_714d, "MF_E_LICENSE_RESTORE_NO_RIGHTS", "Client does not have rights to restore licenses."),
+	(0xc00d_714e, "MF_E_BACKUP_RESTRICTED_LICENSE", "Licenses are restricted and hence cannot be backed up."),
+	(0xc00d_714f, "MF_E_LICENSE_RESTORE_NEEDS_INDIVIDUALIZATION", "License restore requires the machine to be individualized."),
+	(0xc00d_7151, "MF_E_COMPONENT_REVOKED", "Component is revoked."),
+	(0xc00d_7152, "MF_E_TRUST_DISABLED", "Trusted functionality is currently disabled on this component."),
+	(0xc00d_7153, "MF_E_WMDRMOTA_NO_ACTION", "No Action is set on the WMDRM Output Trust Authority."),
+	(0xc00d_7154, "MF_E_WMDRMOTA_ACTION_ALREADY_SET", "Action is already set on the WMDRM Output Trust Authority."),
+	(0xc00d_7155, "MF_E_WMDRMOTA_DRM_HEADER_NOT_AVAILABLE", "DRM Header is not available."),
+	(0xc00d_7156, "MF_E_WMDRMOTA_DRM_ENCRYPTION_SCHEME_NOT_SUPPORTED", "Current encryption scheme is not supported."),
+	(0xc00d_7157, "MF_E_WMDRMOTA_ACTION_MISMATCH", "Action does not match with current configuration."),
+	(0xc00d_7158, "MF_E_WMDRMOTA_INVALID_POLICY", "Invalid policy for WMDRM Output Trust Authority."),
+	(0xc00d_7159, "MF_E_POLICY_UNSUPPORTED", "The policies that the Input Trust Authority requires to be enforced are unsupported by the outputs."),
+	(0xc00d_715a, "MF_E_OPL_NOT_SUPPORTED", "The OPL that the license requires to be enforced are not supported by the Input Trust Authority."),
+	(0xc00d_715b, "MF_E_TOPOLOGY_VERIFICATION_FAILED", "The topology could not be successfully verified."),
+	(0xc00d_715c, "MF_E_SIGNATURE_VERIFICATION_FAILED", "Signature verification could not be completed successfully for this component."),
+	(0xc00d_715d, "MF_E_DEBUGGING_NOT_ALLOWED", "Running this process under a debugger while using protected content is not allowed."),
+	(0xc00d_715e, "MF_E_CODE_EXPIRED", "MF component has expired."),
+	(0xc00d_715f, "MF_E_GRL_VERSION_TOO_LOW", "The current GRL on the machine does not meet the minimum version requirements."),
+	(0xc00d_7160, "MF_E_GRL_RENEWAL_NOT_FOUND", "The current GRL on the machine does not contain any renewal entries for the specified revocation."),
+	(0xc00d_7161, "MF_E_GRL_EXTENSIBLE_ENTRY_NOT_FOUND", "The current GRL on the machine does not contain any extensible entries for the specified extension GUID."),
+	(0xc00d_7162, "MF_E_KERNEL_UNTRUSTED", "The kernel isn't secure for high security level content."),
+	(0xc00d_7163, "MF_E_PEAUTH_UNTRUSTED", "The response from the protected environment driver isn't valid."),
+	(0xc00d_7165, "MF_E_NON_PE_PROCESS", "A non-PE process tried to talk to PEAuth."),
+	(0xc00d_7167, "MF_E_REBOOT_REQUIRED", "We need to reboot the machine."),
+	(0xc00d_716a, "MF_E_GRL_INVALID_FORMAT", "The GRL file is not correctly formed; it may have been corrupted or overwritten."),
+	(0xc00d_716b, "MF_E_GRL_UNRECOGNIZED_FORMAT", "The GRL file is in a format newer than those recognized by this GRL Reader."),
+	(0xc00d_716c, "MF_E_ALL_PROCESS_RESTART_REQUIRED", "The GRL was reloaded and required all processes that can run protected media to restart."),
+	(0xc00d_716d, "MF_E_PROCESS_RESTART_REQUIRED", "The GRL was reloaded and the current process needs to restart."),
+	(0xc00d_716e, "MF_E_USERMODE_UNTRUSTED", "The user space is untrusted for protected content play."),
+	(0xc00d_716f, "MF_E_PEAUTH_SESSION_NOT_STARTED", "PEAuth communication session hasn't been started."),
+	(0xc00d_7170, "MF_E_INSUFFICIENT_BUFFER", "Insufficient memory for response."),
+	(0xc00d_7171, "MF_E_PEAUTH_PUBLICKEY_REVOKED", "PEAuth's public key is revoked."),
+	(0xc00d_7172, "MF_E_GRL_ABSENT", "The GRL is absent."),
+	(0xc00d_7174, "MF_E_PE_UNTRUSTED", "The Protected Environment is untrusted."),
+	(0xc00d_7175, "MF_E_PEAUTH_NOT_STARTED", "The Protected Environment Authorization service (PEAUTH) has not been started."),
+	(0xc00d_7176, "MF_E_INCOMPATIBLE_SAMPLE_PROTECTION", "The sample protection algorithms supported by components are not compatible."),
+	(0xc00d_7177, "MF_E_PE_SESSIONS_MAXED", "No more protected environment sessions can be supported."),
+	(0xc00d_7178, "MF_E_HIGH_SECURITY_LEVEL_CONTENT_NOT_ALLOWED", "WMDRM ITA does not allow protected content with high security level for this release."),
+	(0xc00d_7179, "MF_E_TEST_SIGNED_COMPONENTS_NOT_ALLOWED", "WMDRM ITA cannot allow the requested action for the content as one or more components is not properly signed."),
+	(0xc00d_717a, "MF_E_ITA_UNSUPPORTED_ACTION", "WMDRM ITA does not support the requested action."),
+	(0xc00d_717b, "MF_E_ITA_ERROR_PARSING_SAP_PARAMETERS", "WMDRM ITA encountered an error in parsing the Secure Audio Path parameters."),
+	(0xc00d_717c, "MF_E_POLICY_MGR_ACTION_OUTOFBOUNDS", "The Policy Manager action passed in is invalid."),
+	(0xc00d_717d, "MF_E_BAD_OPL_STRUCTURE_FORMAT", "The structure specifying Output Protection Level is not the correct format."),
+	(0xc00d_717e, "MF_E_ITA_UNRECOGNIZED_ANALOG_VIDEO_PROTECTION_GUID", "WMDRM ITA does not recognize the Explicit Analog Video Output Protection guid specified in the license."),
+	(0xc00d_717f, "MF_E_NO_PMP_HOST", "IMFPMPHost object not available."),
+	(0xc00d_7180, "MF_E_ITA_OPL_DATA_NOT_INITIALIZED", "WMDRM ITA could not initialize the Output Protection Level data."),
+	(0xc00d_7181, "MF_E_ITA_UNRECOGNIZED_ANALOG_VIDEO_OUTPUT", "WMDRM ITA does not recognize the Analog Video Output specified by the OTA."),
+	(0xc00d_7182, "MF_E_ITA_UNRECOGNIZED_DIGITAL_VIDEO_OUTPUT", "WMDRM ITA does not recognize the Digital Video Output specified by the OTA."),
+	(0xc00d_7183, "MF_E_RESOLUTION_REQUIRES_PMP_CREATION_CALLBACK", "The protected stream cannot be resolved without the callback PKEY_PMP_Creation_Callback in the configuration property store."),
+	(0xc00d_7184, "MF_E_INVALID_AKE_CHANNEL_PARAMETERS", "A valid hostname and port number could not be found in the DTCP-IP parameters."),
+	(0xc00d_7185, "MF_E_CONTENT_PROTECTION_SYSTEM_NOT_ENABLED", "The content protection system was not enabled by the application."),
+	(0xc00d_7186, "MF_E_UNSUPPORTED_CONTENT_PROTECTION_SYSTEM", "The content protection system is not supported."),
+	(0xc00d_7187, "MF_E_DRM_MIGRATION_NOT_SUPPORTED", "DRM migration is not supported for the content."),
+	(0xc00d_7188, "MF_E_HDCP_AUTHENTICATION_FAILURE", "Authentication of the HDCP link failed."),
+	(0xc00d_7189, "MF_E_HDCP_LINK_FAILURE", "The HDCP link failed."),
+	(0xc00d_9c40, "MF_E_CLOCK_INVALID_CONTINUITY_KEY", "The continuity key supplied is not currently valid."),
+	(0xc00d_9c41, "MF_E_CLOCK_NO_TIME_SOURCE", "No Presentation Time Source has been specified."),
+	(0xc00d_9c42, "MF_E_CLOCK_STATE_ALREADY_SET", "The clock is already in the requested state."),
+	(0xc00d_9c43, "MF_E_CLOCK_NOT_SIMPLE", "The clock has too many advanced features to carry out the request."),
+	(0xc00d_a028, "MF_E_NO_MORE_DROP_MODES", "The component does not support any more drop modes."),
+	(0xc00d_a029, "MF_E_NO_MORE_QUALITY_LEVELS", "The component does not support any more quality levels."),
+	(0xc00d_a02a, "MF_E_DROPTIME_NOT_SUPPORTED", "The component does not support drop time functionality."),
+	(0xc00d_a02b, "MF_E_QUALITYKNOB_WAIT_LONGER", "The Quality Manager needs to wait longer before bumping the Quality Level up."),
+	(0xc00d_a02c, "MF_E_QM_INVALIDSTATE", "The Quality Manager is in an invalid state. Quality Management is off at this moment."),
+	(0xc00d_a410, "MF_E_TRANSCODE_NO_CONTAINERTYPE", "No transcode output container type is specified."),
+	(0xc00d_a411, "MF_E_TRANSCODE_PROFILE_NO_MATCHING_STREAMS", "The profile does not have a media type configuration for any selected source streams."),
+	(0xc00d_a412, "MF_E_TRANSCODE_NO_MATCHING_ENCODER", "Cannot find an encoder MFT that accepts the user preferred output type."),
+	(0xc00d_a413, "MF_E_TRANSCODE_INVALID_PROFILE", "The profile is invalid."),
+	(0xc00d_a7f8, "MF_E_ALLOCATOR_NOT_INITIALIZED", "Memory allocator is not initialized."),
+	(0xc00d_a7f9, "MF_E_ALLOCATOR_NOT_COMMITED", "Memory allocator is not committed yet."),
+	(0xc00d_a7fa, "MF_E_ALLOCATOR_ALREADY_COMMITED", "Memory allocator has already been committed."),
+	(0xc00d_a7fb, "MF_E_STREAM_ERROR", "An error occurred in the media stream."),
+	(0xc00d_a7fc, "MF_E_INVALID_STREAM_STATE", "Stream is not in a state to handle the request."),
+	(0xc00d_a7fd, "MF_E_HW_STREAM_NOT_CONNECTED", "Hardware stream is not connected yet."),
+	(0xc00d_abe0, "MF_E_NO_CAPTURE_DEVICES_AVAILABLE", "No capture devices are available."),
+	(0xc00d_abe1, "MF_E_CAPTURE_SINK_OUTPUT_NOT_SET", "No output was set for recording."),
+	(0xc00d_abe2, "MF_E_CAPTURE_SINK_MIRROR_ERROR", "The current capture sink configuration does not support mirroring."),
+	(0xc00d_abe3, "MF_E_CAPTURE_SINK_ROTATE_ERROR", "The current capture sink configuration does not support rotation."),
+	(0xc00d_abe4, "MF_E_CAPTURE_ENGINE_INVALID_OP", "The op is invalid."),
+	(0xc00d_abe5, "MF_E_CAPTURE_ENGINE_ALL_EFFECTS_REMOVED", "The effects previously added were incompatible with the new topology which caused all effects to be removed."),
+	(0xc00d_abe6, "MF_E_CAPTURE_SOURCE_NO_INDEPENDENT_PHOTO_STREAM_PRESENT", "The current capture source does not have an independent photo stream."),
+	(0xc00d_abe7, "MF_E_CAPTURE_SOURCE_NO_VIDEO_STREAM_PRESENT", "The current capture source does not have a video stream."),
+	(0xc00d_abe8, "MF_E_CAPTURE_SOURCE_NO_AUDIO_STREAM_PRESENT", "The current capture source does not have an audio stream."),
+	(0xc00d_abe9, "MF_E_CAPTURE_SOURCE_DEVICE_EXTENDEDPROP_OP_IN_PROGRESS", "The capture source device has an asynchronous extended property operation in progress."),
+	(0xc00d_abea, "MF_E_CAPTURE_PROPERTY_SET_DURING_PHOTO", "A property cannot be set because a photo or photo sequence is in progress."),
+	(0xc00d_abeb, "MF_E_CAPTURE_NO_SAMPLES_IN_QUEUE", "No more samples in queue."),
+	(0xc00d_abec, "MF_E_HW_ACCELERATED_THUMBNAIL_NOT_SUPPORTED", "Hardware accelerated thumbnail generation is not supported for the currently selected mediatype on the mediacapture stream."),
+	(0xc00d_abed, "MF_E_UNSUPPORTED_CAPTURE_DEVICE_PRESENT", "Capture device that is present on the system is not supported by Media Foundation."),
+	(0xc00d_afc8, "MF_E_TIMELINECONTROLLER_UNSUPPORTED_SOURCE_TYPE", "Media Source type is not supported in Media Foundation Timeline Controller scenarios."),
+	(0xc00d_afc9, "MF_E_TIMELINECONTROLLER_NOT_ALLOWED", "Operation is not allowed when Media Foundation Timeline Controller is attached to the Media Player."),
+	(0xc00d_afca, "MF_E_TIMELINECONTROLLER_CANNOT_ATTACH", "Media Foundation Timeline Controller cannot be attached when the Media Player is in the Closed state."),
+	(0xc00d_b3b0, "MF_E_MEDIA_EXTENSION_APPSERVICE_CONNECTION_FAILED", "Connection to app service providing a media extension failed."),
+	(0xc00d_b3b1, "MF_E_MEDIA_EXTENSION_APPSERVICE_REQUEST_FAILED", "App service providing a media extension failed to process the request."),
+	(0xc00d_b3b2, "MF_E_MEDIA_EXTENSION_PACKAGE_INTEGRITY_CHECK_FAILED", "Package integrity check for app failed."),
+	(0xc00d_b3b3, "MF_E_MEDIA_EXTENSION_PACKAGE_LICENSE_INVALID", "License check for app failed."),
+];
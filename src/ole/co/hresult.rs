@@ -1,4 +1,7 @@
+#[cfg(feature = "error_messages")] use std::sync::OnceLock;
+
 use crate::co;
+#[cfg(feature = "error_messages")] use crate::kernel::privs::*;
 use crate::prelude::*;
 
 const_no_debug_display! { HRESULT: u32;
@@ -45,6 +48,20 @@ const_no_debug_display! { HRESULT: u32;
 	///
 	/// let hr = err.to_hresult();
 	/// ```
+	///
+	/// With the `error_messages` feature, the symbolic names and the English
+	/// descriptions are embedded in the binary, so the output doesn't depend on
+	/// the OS language, and it can be parsed by tools:
+	///
+	/// ```text
+	/// E_INVALIDARG (0x80070057): The parameter is incorrect.
+	/// ```
+	///
+	/// Codes with [`FACILITY::WIN32`](crate::co::FACILITY::WIN32) and without
+	/// an `HRESULT` constant are named after the wrapped
+	/// [`ERROR`](crate::co::ERROR), like
+	/// `HRESULT_FROM_WIN32(ERROR_FILE_NOT_FOUND) (0x80070002)`. Codes without
+	/// a constant keep the bracketed format.
 }
 
 impl std::error::Error for HRESULT {
@@ -55,6 +72,16 @@ impl std::error::Error for HRESULT {
 
 impl std::fmt::Display for HRESULT {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		#[cfg(feature = "error_messages")]
+		if let Some((name, desc)) = self.find_msg().map(|(name, desc)| (name.to_owned(), desc))
+			.or_else(|| {
+				let err = self.win32_error()?;
+				Some((format!("HRESULT_FROM_WIN32({})", err.symbolic_name()?), err.description()?))
+			})
+		{
+			return write!(f, "{} ({:#x}): {}", name, self.0, desc);
+		}
+
 		if self.0 > 0xffff {
 			write!(f, "[{:#010x} {}] {}",
 				self.0, self.0, self.FormatMessage())
@@ -72,6 +99,69 @@ impl std::fmt::Debug for HRESULT {
 
 impl FormattedError for HRESULT {}
 
+#[cfg(not(feature = "error_messages"))]
+impl NativeConst for HRESULT {}
+
+#[cfg(feature = "error_messages")]
+impl NativeConst for HRESULT {
+	fn named_values() -> &'static [(&'static str, Self)] {
+		static NAMED: OnceLock<Vec<(&str, HRESULT)>> = OnceLock::new();
+		NAMED.get_or_init(|| {
+			let tables: &[&[(u32, &str, &str)]] = &[
+				super::hresult_msg::HRESULT_MSGS,
+				#[cfg(feature = "mf")] crate::mf::co::hresult_msg::MF_HRESULT_MSGS,
+			];
+			tables.iter()
+				.flat_map(|table| table.iter())
+				.map(|(code, name, _)| (*name, unsafe { Self::from_raw(*code) }))
+				.collect()
+		})
+	}
+}
+
+#[cfg(feature = "error_messages")]
+impl HRESULT {
+	/// Returns the embedded English description of the error code, if any.
+	/// Codes with [`FACILITY::WIN32`](crate::co::FACILITY::WIN32) fall back to
+	/// the description of the wrapped [`ERROR`](crate::co::ERROR).
+	///
+	/// Available with the `error_messages` feature.
+	#[must_use]
+	pub fn description(self) -> Option<&'static str> {
+		self.find_msg()
+			.map(|(_, desc)| desc)
+			.or_else(|| self.win32_error().and_then(|err| err.description()))
+	}
+
+	/// Returns the symbolic name of the error code, like `E_INVALIDARG`, if
+	/// it's a known constant in the embedded message tables.
+	///
+	/// Available with the `error_messages` feature.
+	#[must_use]
+	pub fn symbolic_name(self) -> Option<&'static str> {
+		self.find_msg().map(|(name, _)| name)
+	}
+
+	fn find_msg(self) -> Option<(&'static str, &'static str)> {
+		let found = find_error_msg(super::hresult_msg::HRESULT_MSGS, self.0);
+		#[cfg(feature = "mf")]
+		let found = found.or_else(|| {
+			find_error_msg(crate::mf::co::hresult_msg::MF_HRESULT_MSGS, self.0)
+		});
+		found
+	}
+
+	/// The wrapped `ERROR`, if this is a failure code from
+	/// [`HRESULT_FROM_WIN32`](crate::co::ERROR::to_hresult).
+	fn win32_error(self) -> Option<co::ERROR> {
+		if self.0 & 0xffff_0000 == 0x8007_0000 {
+			Some(unsafe { co::ERROR::from_raw(self.0 & 0xffff) })
+		} else {
+			None
+		}
+	}
+}
+
 impl co::ERROR {
 	/// [`HRESULT_FROM_WIN32`](https://learn.microsoft.com/en-us/windows/win32/api/winerror/nf-winerror-hresult_from_win32)
 	/// macro.
@@ -0,0 +1,3225 @@
+/// Names and English descriptions of [`HRESULT`](crate::co::HRESULT)
+/// constants.
+///
+/// Sorted by code, so it can be binary-searched.
+pub(crate) const HRESULT_MSGS: &[(u32, &str, &str)] = &[
+	(0x0000_0000, "S_OK", "The operation completed successfully."),
+	(0x0000_0001, "S_FALSE", "The operation completed successfully, but returned a false or partial result."),
+	(0x0003_0200, "STG_S_CONVERTED", "The underlying file was converted to compound file format."),
+	(0x0003_0201, "STG_S_BLOCK", "The storage operation should block until more data is available."),
+	(0x0003_0202, "STG_S_RETRYNOW", "The storage operation should retry immediately."),
+	(0x0003_0203, "STG_S_MONITORING", "The notified event sink will not influence the storage operation."),
+	(0x0003_0204, "STG_S_MULTIPLEOPENS", "Multiple opens prevent consolidated (commit succeeded)."),
+	(0x0003_0205, "STG_S_CONSOLIDATIONFAILED", "Consolidation of the storage file failed (commit succeeded)."),
+	(0x0003_0206, "STG_S_CANNOTCONSOLIDATE", "Consolidation of the storage file is inappropriate (commit succeeded)."),
+	(0x0003_0207, "STG_S_POWER_CYCLE_REQUIRED", "The device needs to be power cycled (commit succeeded)."),
+	(0x0004_0000, "OLE_S_USEREG", "Use the registry database to provide the requested information."),
+	(0x0004_0001, "OLE_S_STATIC", "Success, but static."),
+	(0x0004_0002, "OLE_S_MAC_CLIPFORMAT", "Macintosh clipboard format."),
+	(0x0004_0100, "DRAGDROP_S_DROP", "Successful drop took place."),
+	(0x0004_0101, "DRAGDROP_S_CANCEL", "Drag-drop operation canceled."),
+	(0x0004_0102, "DRAGDROP_S_USEDEFAULTCURSORS", "Use the default cursor."),
+	(0x0004_0130, "DATA_S_SAMEFORMATETC", "Data has same FORMATETC."),
+	(0x0004_0140, "VIEW_S_ALREADY_FROZEN", "View is already frozen."),
+	(0x0004_0170, "CACHE_S_FORMATETC_NOTSUPPORTED", "FORMATETC not supported."),
+	(0x0004_0171, "CACHE_S_SAMECACHE", "Same cache."),
+	(0x0004_0172, "CACHE_S_SOMECACHES_NOTUPDATED", "Some cache(s) not updated."),
+	(0x0004_0180, "OLEOBJ_S_INVALIDVERB", "Invalid verb for OLE object."),
+	(0x0004_0181, "OLEOBJ_S_CANNOT_DOVERB_NOW", "Verb number is valid but verb cannot be done now."),
+	(0x0004_0182, "OLEOBJ_S_INVALIDHWND", "Invalid window handle passed."),
+	(0x0004_01a0, "INPLACE_S_TRUNCATED", "Message is too long; some of it had to be truncated before displaying."),
+	(0x0004_01c0, "CONVERT10_S_NO_PRESENTATION", "Unable to convert OLESTREAM to IStorage."),
+	(0x0004_01e2, "MK_S_REDUCED_TO_SELF", "Moniker reduced to itself."),
+	(0x0004_01e4, "MK_S_ME", "Common prefix is this moniker."),
+	(0x0004_01e5, "MK_S_HIM", "Common prefix is input moniker."),
+	(0x0004_01e6, "MK_S_US", "Common prefix is both monikers."),
+	(0x0004_01e7, "MK_S_MONIKERALREADYREGISTERED", "Moniker is already registered in running object table."),
+	(0x0004_0200, "EVENT_S_SOME_SUBSCRIBERS_FAILED", "An event was able to invoke some but not all of the subscribers."),
+	(0x0004_0202, "EVENT_S_NOSUBSCRIBERS", "An event was delivered but there were no subscribers."),
+	(0x0004_0252, "TPC_S_TRUNCATED", "The Tablet PC recognizer result was truncated."),
+	(0x0004_0253, "TPC_S_INTERRUPTED", "The Tablet PC recognition process was interrupted."),
+	(0x0004_0254, "TPC_S_NO_DATA_TO_PROCESS", "The Tablet PC recognizer had no data to process."),
+	(0x0004_1300, "SCHED_S_TASK_READY", "The task is ready to run at its next scheduled time."),
+	(0x0004_1301, "SCHED_S_TASK_RUNNING", "The task is currently running."),
+	(0x0004_1302, "SCHED_S_TASK_DISABLED", "The task will not run at the scheduled times because it has been disabled."),
+	(0x0004_1303, "SCHED_S_TASK_HAS_NOT_RUN", "The task has not yet run."),
+	(0x0004_1304, "SCHED_S_TASK_NO_MORE_RUNS", "There are no more runs scheduled for this task."),
+	(0x0004_1305, "SCHED_S_TASK_NOT_SCHEDULED", "One or more of the properties that are needed to run this task on a schedule have not been set."),
+	(0x0004_1306, "SCHED_S_TASK_TERMINATED", "The last run of the task was terminated by the user."),
+	(0x0004_1307, "SCHED_S_TASK_NO_VALID_TRIGGERS", "Either the task has no triggers or the existing triggers are disabled or not set."),
+	(0x0004_1308, "SCHED_S_EVENT_TRIGGER", "Event triggers do not have set run times."),
+	(0x0004_131b, "SCHED_S_SOME_TRIGGERS_FAILED", "Not all the specified triggers will start the task."),
+	(0x0004_131c, "SCHED_S_BATCH_LOGON_PROBLEM", "The task is registered, but may fail to start. Batch logon privilege needs to be enabled for the task principal."),
+	(0x0004_1325, "SCHED_S_TASK_QUEUED", "The Task Scheduler service has asked the task to run."),
+	(0x0004_d000, "XACT_S_ASYNC", "An asynchronous operation was specified. The operation has begun, but its outcome is not known yet."),
+	(0x0004_d001, "XACT_S_DEFECT", "The method call succeeded, but the transaction was defected."),
+	(0x0004_d002, "XACT_S_READONLY", "The method call succeeded because the transaction was read-only."),
+	(0x0004_d003, "XACT_S_SOMENORETAIN", "The transaction was successfully aborted. However, this is a coordinated transaction, and some number of enlisted resources were aborted outright because they could not support abort-retaining semantics."),
+	(0x0004_d004, "XACT_S_OKINFORM", "No changes were made during this call, but the sink wants another chance to look if any other sinks make further changes."),
+	(0x0004_d005, "XACT_S_MADECHANGESCONTENT", "The sink is content and wants the transaction to proceed. Changes were made to one or more resources during this call."),
+	(0x0004_d006, "XACT_S_MADECHANGESINFORM", "The sink is for the moment and wants the transaction to proceed, but if other changes are made following this return by other event sinks then this sink wants another chance to look."),
+	(0x0004_d007, "XACT_S_ALLNORETAIN", "The transaction was successfully aborted. However, the abort was non-retaining."),
+	(0x0004_d008, "XACT_S_ABORTING", "An abort operation was already in progress."),
+	(0x0004_d009, "XACT_S_SINGLEPHASE", "The resource manager has performed a single-phase commit of the transaction."),
+	(0x0004_d00a, "XACT_S_LOCALLY_OK", "The local transaction has not aborted."),
+	(0x0004_d010, "XACT_S_LASTRESOURCEMANAGER", "The resource manager has requested to be the coordinator (last resource manager) for the transaction."),
+	(0x0008_0012, "CO_S_NOTALLINTERFACES", "Not all the requested interfaces were available."),
+	(0x0008_0013, "CO_S_MACHINENAMENOTFOUND", "The specified machine name was not found in the cache."),
+	(0x0009_0312, "SEC_I_CONTINUE_NEEDED", "The function completed successfully, but must be called again to complete the context."),
+	(0x0009_0313, "SEC_I_COMPLETE_NEEDED", "The function completed successfully, but CompleteToken must be called."),
+	(0x0009_0314, "SEC_I_COMPLETE_AND_CONTINUE", "The function completed successfully, but both CompleteToken and this function must be called to complete the context."),
+	(0x0009_0315, "SEC_I_LOCAL_LOGON", "The logon was completed, but no network authority was available. The logon was made using locally known information."),
+	(0x0009_0316, "SEC_I_GENERIC_EXTENSION_RECEIVED", "Schannel has received a TLS extension the SSPI caller subscribed to."),
+	(0x0009_0317, "SEC_I_CONTEXT_EXPIRED", "The context has expired and can no longer be used."),
+	(0x0009_0320, "SEC_I_INCOMPLETE_CREDENTIALS", "The credentials supplied were not complete, and could not be verified. Additional information can be returned from the context."),
+	(0x0009_0321, "SEC_I_RENEGOTIATE", "The context data must be renegotiated with the peer."),
+	(0x0009_0323, "SEC_I_NO_LSA_CONTEXT", "There is no LSA mode context associated with this context."),
+	(0x0009_035c, "SEC_I_SIGNATURE_NEEDED", "A signature operation must be performed before the user can authenticate."),
+	(0x0009_0360, "SEC_I_NO_RENEGOTIATION", "The recipient rejected the renegotiation request."),
+	(0x0009_0364, "SEC_I_MESSAGE_FRAGMENT", "The returned buffer is only a fragment of the message. More fragments need to be returned."),
+	(0x0009_0366, "SEC_I_CONTINUE_NEEDED_MESSAGE_OK", "The function completed successfully, but must be called again to complete the context. Early start can be used."),
+	(0x0009_0368, "SEC_I_ASYNC_CALL_PENDING", "An asynchronous SSPI routine has been called and the work is pending completion."),
+	(0x0009_1012, "CRYPT_I_NEW_PROTECTION_REQUIRED", "Protected data needs to be re-protected."),
+	(0x001b_0000, "WER_S_REPORT_DEBUG", "Debugger was attached."),
+	(0x001b_0001, "WER_S_REPORT_UPLOADED", "Report was uploaded."),
+	(0x001b_0002, "WER_S_REPORT_QUEUED", "Report was queued."),
+	(0x001b_0003, "WER_S_DISABLED", "Reporting was disabled."),
+	(0x001b_0004, "WER_S_SUSPENDED_UPLOAD", "Reporting was temporarily suspended."),
+	(0x001b_0005, "WER_S_DISABLED_QUEUE", "Report was not queued to queuing being disabled."),
+	(0x001b_0006, "WER_S_DISABLED_ARCHIVE", "Report was uploaded, but not archived due to archiving being disabled."),
+	(0x001b_0007, "WER_S_REPORT_ASYNC", "Reporting was successfully spun off as an asynchronous operation."),
+	(0x001b_0008, "WER_S_IGNORE_ASSERT_INSTANCE", "The assertion was handled."),
+	(0x001b_0009, "WER_S_IGNORE_ALL_ASSERTS", "The assertion was handled and added to a permanent ignore list."),
+	(0x001b_000a, "WER_S_ASSERT_CONTINUE", "The assertion was resumed as unhandled."),
+	(0x001b_000b, "WER_S_THROTTLED", "Report was throttled."),
+	(0x001b_000c, "WER_S_REPORT_UPLOADED_CAB", "Report was uploaded with cab."),
+	(0x001f_0001, "ERROR_FLT_IO_COMPLETE", "The IO was completed by a filter."),
+	(0x0026_1001, "ERROR_MONITOR_NO_DESCRIPTOR", "Monitor descriptor could not be obtained."),
+	(0x0026_1002, "ERROR_MONITOR_UNKNOWN_DESCRIPTOR_FORMAT", "Format of the obtained monitor descriptor is not supported by this release."),
+	(0x0026_2307, "ERROR_GRAPHICS_MODE_NOT_PINNED", "No mode is pinned on the specified VidPN source/target."),
+	(0x0026_231e, "ERROR_GRAPHICS_NO_PREFERRED_MODE", "Specified mode set does not specify preference for one of its modes."),
+	(0x0026_234b, "ERROR_GRAPHICS_DATASET_IS_EMPTY", "Specified data set (e.g. mode set, frequency range set, descriptor set, topology, etc.) is empty."),
+	(0x0026_234c, "ERROR_GRAPHICS_NO_MORE_ELEMENTS_IN_DATASET", "Specified data set (e.g. mode set, frequency range set, descriptor set, topology, etc.) does not contain any more elements."),
+	(0x0026_2351, "ERROR_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_PINNED", "Specified content transformation is not pinned on the specified VidPN present path."),
+	(0x0026_3005, "DWM_S_GDI_REDIRECTION_SURFACE", "The window is redirected to a GDI surface."),
+	(0x0026_3008, "DWM_S_GDI_REDIRECTION_SURFACE_BLT_VIA_GDI", "DWM is composing the window using GDI redirection."),
+	(0x0027_000d, "NAP_S_CERT_ALREADY_PRESENT", "The health certificate is already present."),
+	(0x0027_0258, "S_STORE_LAUNCHED_FOR_REMEDIATION", "The Store was launched instead of the specified app because the app's package was in an invalid state."),
+	(0x0027_0259, "S_APPLICATION_ACTIVATION_ERROR_HANDLED_BY_DIALOG", "This app failed to launch, but the error was handled with a dialog."),
+	(0x0030_0100, "PLA_S_PROPERTY_IGNORED", "Property value will be ignored."),
+	(0x003d_0000, "WS_S_ASYNC", "The function call is completing asynchronously."),
+	(0x003d_0001, "WS_S_END", "There are no more messages available on the channel."),
+	(0x0063_0001, "PEER_S_GRAPH_DATA_CREATED", "The graph data was created."),
+	(0x0063_0002, "PEER_S_NO_EVENT_DATA", "There is not more event data."),
+	(0x0063_0005, "PEER_S_NO_CONNECTIVITY", "The graph is in use but there is no connection to any other peer."),
+	(0x0063_0006, "PEER_S_ALREADY_A_MEMBER", "The graph is already connected."),
+	(0x0063_2000, "PEER_S_ALREADY_CONNECTED", "The peer is already connected."),
+	(0x0063_6000, "PEER_S_SUBSCRIPTION_EXISTS", "The subscription already exists."),
+	(0x0067_0013, "STATEREPOSITORY_TRANSACTION_CALLER_ID_CHANGED", "The transaction caller ID has changed."),
+	(0x0067_0014, "STATEREPOSITORY_TRANSACTION_IN_PROGRESS", "A transaction is in progress for this connection."),
+	(0x00e7_0001, "ERROR_SPACES_POOL_WAS_DELETED", "The storage pool was deleted by the driver. The object cache should be updated."),
+	(0x4026_200a, "ERROR_GRAPHICS_PARTIAL_DATA_POPULATED", "The data provided by the driver was only partially populated."),
+	(0x4026_2201, "ERROR_GRAPHICS_SKIP_ALLOCATION_PREPARATION", "Skip preparation of allocations referenced by the DMA buffer."),
+	(0x4026_242f, "ERROR_GRAPHICS_UNKNOWN_CHILD_STATUS", "Child device presence was not reliably detected."),
+	(0x4026_2437, "ERROR_GRAPHICS_LEADLINK_START_DEFERRED", "Starting the leadlink adapter has been deferred temporarily."),
+	(0x4026_2439, "ERROR_GRAPHICS_POLLING_TOO_FREQUENTLY", "The display adapter is being polled for children too frequently at the same polling level."),
+	(0x4026_243a, "ERROR_GRAPHICS_START_DEFERRED", "Starting the adapter has been deferred temporarily."),
+	(0x4026_243c, "ERROR_GRAPHICS_DEPENDABLE_CHILD_STATUS", "Child device presence was reliably detected."),
+	(0x4029_0423, "TPM_E_PCP_UNSUPPORTED_PSS_SALT", "The Platform Crypto Device does not support the requested PSS salt length."),
+	(0x8000_000a, "E_PENDING", "The data necessary to complete this operation is not yet available."),
+	(0x8000_000b, "E_BOUNDS", "The operation attempted to access data outside the valid range"),
+	(0x8000_000c, "E_CHANGED_STATE", "A concurrent or interleaved operation changed the state of the object, invalidating this operation."),
+	(0x8000_000d, "E_ILLEGAL_STATE_CHANGE", "An illegal state change was requested."),
+	(0x8000_000e, "E_ILLEGAL_METHOD_CALL", "A method was called at an unexpected time."),
+	(0x8000_000f, "RO_E_METADATA_NAME_NOT_FOUND", "Typename or Namespace was not found in metadata file."),
+	(0x8000_0010, "RO_E_METADATA_NAME_IS_NAMESPACE", "Name is an existing namespace rather than a typename."),
+	(0x8000_0011, "RO_E_METADATA_INVALID_TYPE_FORMAT", "Typename has an invalid format."),
+	(0x8000_0012, "RO_E_INVALID_METADATA_FILE", "Metadata file is invalid or corrupted."),
+	(0x8000_0013, "RO_E_CLOSED", "The object has been closed."),
+	(0x8000_0014, "RO_E_EXCLUSIVE_WRITE", "Only one thread may access the object during a write operation."),
+	(0x8000_0015, "RO_E_CHANGE_NOTIFICATION_IN_PROGRESS", "Operation is prohibited during change notification."),
+	(0x8000_0016, "RO_E_ERROR_STRING_NOT_FOUND", "The text associated with this error code could not be found."),
+	(0x8000_0017, "E_STRING_NOT_NULL_TERMINATED", "String not null terminated."),
+	(0x8000_0018, "E_ILLEGAL_DELEGATE_ASSIGNMENT", "A delegate was assigned when not allowed."),
+	(0x8000_0019, "E_ASYNC_OPERATION_NOT_STARTED", "An async operation was not properly started."),
+	(0x8000_001a, "E_APPLICATION_EXITING", "The application is exiting and cannot service this request."),
+	(0x8000_001b, "E_APPLICATION_VIEW_EXITING", "The application view is exiting and cannot service this request."),
+	(0x8000_001c, "RO_E_MUST_BE_AGILE", "The object must support the IAgileObject interface."),
+	(0x8000_001d, "RO_E_UNSUPPORTED_FROM_MTA", "Activating a single-threaded class from MTA is not supported."),
+	(0x8000_001e, "RO_E_COMMITTED", "The object has been committed."),
+	(0x8000_001f, "RO_E_BLOCKED_CROSS_ASTA_CALL", "A COM call to an ASTA was blocked because the call chain originated in or passed through another ASTA. This pattern is deadlock-prone and disallowed by apartment call control."),
+	(0x8000_0020, "RO_E_CANNOT_ACTIVATE_FULL_TRUST_SERVER", "A universal application process cannot activate a packaged WinRT server that is declared to run full trust."),
+	(0x8000_0021, "RO_E_CANNOT_ACTIVATE_UNIVERSAL_APPLICATION_SERVER", "A full trust packaged application process cannot activate a packaged WinRT server unless it is also declared to run full trust."),
+	(0x8000_4001, "E_NOTIMPL", "Not implemented"),
+	(0x8000_4002, "E_NOINTERFACE", "No such interface supported"),
+	(0x8000_4003, "E_POINTER", "Invalid pointer"),
+	(0x8000_4004, "E_ABORT", "Operation aborted"),
+	(0x8000_4005, "E_FAIL", "Unspecified error"),
+	(0x8000_4006, "CO_E_INIT_TLS", "Thread local storage failure."),
+	(0x8000_4007, "CO_E_INIT_SHARED_ALLOCATOR", "Get shared memory allocator failure."),
+	(0x8000_4008, "CO_E_INIT_MEMORY_ALLOCATOR", "Get memory allocator failure."),
+	(0x8000_4009, "CO_E_INIT_CLASS_CACHE", "Unable to initialize class cache."),
+	(0x8000_400a, "CO_E_INIT_RPC_CHANNEL", "Unable to initialize RPC services."),
+	(0x8000_400b, "CO_E_INIT_TLS_SET_CHANNEL_CONTROL", "Cannot set thread local storage channel control."),
+	(0x8000_400c, "CO_E_INIT_TLS_CHANNEL_CONTROL", "Could not allocate thread local storage channel control."),
+	(0x8000_400d, "CO_E_INIT_UNACCEPTED_USER_ALLOCATOR", "The user-supplied memory allocator is unacceptable."),
+	(0x8000_400e, "CO_E_INIT_SCM_MUTEX_EXISTS", "The OLE service mutex already exists."),
+	(0x8000_400f, "CO_E_INIT_SCM_FILE_MAPPING_EXISTS", "The OLE service file mapping already exists."),
+	(0x8000_4010, "CO_E_INIT_SCM_MAP_VIEW_OF_FILE", "Unable to map view of file for OLE service."),
+	(0x8000_4011, "CO_E_INIT_SCM_EXEC_FAILURE", "Failure attempting to launch OLE service."),
+	(0x8000_4012, "CO_E_INIT_ONLY_SINGLE_THREADED", "There was an attempt to call CoInitialize a second time while single threaded."),
+	(0x8000_4013, "CO_E_CANT_REMOTE", "A Remote activation was necessary but was not allowed."),
+	(0x8000_4014, "CO_E_BAD_SERVER_NAME", "A Remote activation was necessary but the server name provided was invalid."),
+	(0x8000_4015, "CO_E_WRONG_SERVER_IDENTITY", "The class is configured to run as a security id different from the caller."),
+	(0x8000_4016, "CO_E_OLE1DDE_DISABLED", "Use of Ole1 services requiring DDE windows is disabled."),
+	(0x8000_4017, "CO_E_RUNAS_SYNTAX", "A RunAs specification must be <domain name>\\<user name> or simply <user name>."),
+	(0x8000_4018, "CO_E_CREATEPROCESS_FAILURE", "The server process could not be started. The pathname may be incorrect."),
+	(0x8000_4019, "CO_E_RUNAS_CREATEPROCESS_FAILURE", "The server process could not be started as the configured identity. The pathname may be incorrect or unavailable."),
+	(0x8000_401a, "CO_E_RUNAS_LOGON_FAILURE", "The server process could not be started because the configured identity is incorrect. Check the user name and password."),
+	(0x8000_401b, "CO_E_LAUNCH_PERMSSION_DENIED", "The client is not allowed to launch this server."),
+	(0x8000_401c, "CO_E_START_SERVICE_FAILURE", "The service providing this server could not be started."),
+	(0x8000_401d, "CO_E_REMOTE_COMMUNICATION_FAILURE", "This computer was unable to communicate with the computer providing the server."),
+	(0x8000_401e, "CO_E_SERVER_START_TIMEOUT", "The server did not respond after being launched."),
+	(0x8000_401f, "CO_E_CLSREG_INCONSISTENT", "The registration information for this server is inconsistent or incomplete."),
+	(0x8000_4020, "CO_E_IIDREG_INCONSISTENT", "The registration information for this interface is inconsistent or incomplete."),
+	(0x8000_4021, "CO_E_NOT_SUPPORTED", "The operation attempted is not supported."),
+	(0x8000_4022, "CO_E_RELOAD_DLL", "A dll must be loaded."),
+	(0x8000_4023, "CO_E_MSI_ERROR", "A Microsoft Software Installer error was encountered."),
+	(0x8000_4024, "CO_E_ATTEMPT_TO_CREATE_OUTSIDE_CLIENT_CONTEXT", "The specified activation could not occur in the client context as specified."),
+	(0x8000_4025, "CO_E_SERVER_PAUSED", "Activations on the server are paused."),
+	(0x8000_4026, "CO_E_SERVER_NOT_PAUSED", "Activations on the server are not paused."),
+	(0x8000_4027, "CO_E_CLASS_DISABLED", "The component or application containing the component has been disabled."),
+	(0x8000_4028, "CO_E_CLRNOTAVAILABLE", "The common language runtime is not available."),
+	(0x8000_4029, "CO_E_ASYNC_WORK_REJECTED", "The thread-pool rejected the submitted asynchronous work."),
+	(0x8000_402a, "CO_E_SERVER_INIT_TIMEOUT", "The server started, but did not finish initializing in a timely fashion."),
+	(0x8000_402b, "CO_E_NO_SECCTX_IN_ACTIVATE", "Unable to complete the call since there is no COM+ security context inside IObjectControl.Activate."),
+	(0x8000_4030, "CO_E_TRACKER_CONFIG", "The provided tracker configuration is invalid."),
+	(0x8000_4031, "CO_E_THREADPOOL_CONFIG", "The provided thread pool configuration is invalid."),
+	(0x8000_4032, "CO_E_SXS_CONFIG", "The provided side-by-side configuration is invalid."),
+	(0x8000_4033, "CO_E_MALFORMED_SPN", "The server principal name (SPN) obtained during security negotiation is malformed."),
+	(0x8000_4034, "CO_E_UNREVOKED_REGISTRATION_ON_APARTMENT_SHUTDOWN", "The caller failed to revoke a per-apartment registration before apartment shutdown."),
+	(0x8000_4035, "CO_E_PREMATURE_STUB_RUNDOWN", "The object has been rundown by the stub manager while there are external clients."),
+	(0x8000_ffff, "E_UNEXPECTED", "Catastrophic failure"),
+	(0x8001_0001, "RPC_E_CALL_REJECTED", "Call was rejected by callee."),
+	(0x8001_0002, "RPC_E_CALL_CANCELED", "Call was canceled by the message filter."),
+	(0x8001_0003, "RPC_E_CANTPOST_INSENDCALL", "The caller is dispatching an intertask SendMessage call and cannot call out via PostMessage."),
+	(0x8001_0004, "RPC_E_CANTCALLOUT_INASYNCCALL", "The caller is dispatching an asynchronous call and cannot make an outgoing call on behalf of this call."),
+	(0x8001_0005, "RPC_E_CANTCALLOUT_INEXTERNALCALL", "It is illegal to call out while inside message filter."),
+	(0x8001_0006, "RPC_E_CONNECTION_TERMINATED", "The connection terminated or is in a bogus state and cannot be used any more. Other connections are still valid."),
+	(0x8001_0007, "RPC_E_SERVER_DIED", "The callee (server [not server application]) is not available and disappeared; all connections are invalid. The call may have executed."),
+	(0x8001_0008, "RPC_E_CLIENT_DIED", "The caller (client) disappeared while the callee (server) was processing a call."),
+	(0x8001_0009, "RPC_E_INVALID_DATAPACKET", "The data packet with the marshalled parameter data is incorrect."),
+	(0x8001_000a, "RPC_E_CANTTRANSMIT_CALL", "The call was not transmitted properly; the message queue was full and was not emptied after yielding."),
+	(0x8001_000b, "RPC_E_CLIENT_CANTMARSHAL_DATA", "The client (caller) cannot marshall the parameter data - low memory, etc."),
+	(0x8001_000c, "RPC_E_CLIENT_CANTUNMARSHAL_DATA", "The client (caller) cannot unmarshall the return data - low memory, etc."),
+	(0x8001_000d, "RPC_E_SERVER_CANTMARSHAL_DATA", "The server (callee) cannot marshall the return data - low memory, etc."),
+	(0x8001_000e, "RPC_E_SERVER_CANTUNMARSHAL_DATA", "The server (callee) cannot unmarshall the parameter data - low memory, etc."),
+	(0x8001_000f, "RPC_E_INVALID_DATA", "Received data is invalid; could be server or client data."),
+	(0x8001_0010, "RPC_E_INVALID_PARAMETER", "A particular parameter is invalid and cannot be (un)marshalled."),
+	(0x8001_0011, "RPC_E_CANTCALLOUT_AGAIN", "There is no second outgoing call on same channel in DDE conversation."),
+	(0x8001_0012, "RPC_E_SERVER_DIED_DNE", "The callee (server [not server application]) is not available and disappeared; all connections are invalid. The call did not execute."),
+	(0x8001_0100, "RPC_E_SYS_CALL_FAILED", "System call failed."),
+	(0x8001_0101, "RPC_E_OUT_OF_RESOURCES", "Could not allocate some required resource (memory, events, ...)."),
+	(0x8001_0102, "RPC_E_ATTEMPTED_MULTITHREAD", "Attempted to make calls on more than one thread in single threaded mode."),
+	(0x8001_0103, "RPC_E_NOT_REGISTERED", "The requested interface is not registered on the server object."),
+	(0x8001_0104, "RPC_E_FAULT", "RPC could not call the server or could not return the results of calling the server."),
+	(0x8001_0105, "RPC_E_SERVERFAULT", "The server threw an exception."),
+	(0x8001_0106, "RPC_E_CHANGED_MODE", "Cannot change thread mode after it is set."),
+	(0x8001_0107, "RPC_E_INVALIDMETHOD", "The requested method is not registered for the interface on the server object."),
+	(0x8001_0108, "RPC_E_DISCONNECTED", "The object invoked has disconnected from its clients."),
+	(0x8001_0109, "RPC_E_RETRY", "The object invoked chose not to process the call now. Try again later."),
+	(0x8001_010a, "RPC_E_SERVERCALL_RETRYLATER", "The message filter indicated that the application is busy."),
+	(0x8001_010b, "RPC_E_SERVERCALL_REJECTED", "The message filter rejected the call."),
+	(0x8001_010c, "RPC_E_INVALID_CALLDATA", "A call control interfaces was called with invalid data."),
+	(0x8001_010d, "RPC_E_CANTCALLOUT_ININPUTSYNCCALL", "An outgoing call cannot be made since the application is dispatching an input-synchronous call."),
+	(0x8001_010e, "RPC_E_WRONG_THREAD", "The application called an interface that was marshalled for a different thread."),
+	(0x8001_010f, "RPC_E_THREAD_NOT_INIT", "CoInitialize has not been called on the current thread."),
+	(0x8001_0110, "RPC_E_VERSION_MISMATCH", "The version of OLE on the client and server machines does not match."),
+	(0x8001_0111, "RPC_E_INVALID_HEADER", "OLE received a packet with an invalid header."),
+	(0x8001_0112, "RPC_E_INVALID_EXTENSION", "OLE received a packet with an invalid extension."),
+	(0x8001_0113, "RPC_E_INVALID_IPID", "The requested object or interface does not exist."),
+	(0x8001_0114, "RPC_E_INVALID_OBJECT", "The requested object does not exist."),
+	(0x8001_0115, "RPC_S_CALLPENDING", "OLE has sent a request and is waiting for a reply."),
+	(0x8001_0116, "RPC_S_WAITONTIMER", "OLE is waiting before retrying a request."),
+	(0x8001_0117, "RPC_E_CALL_COMPLETE", "Call context cannot be accessed after call completed."),
+	(0x8001_0118, "RPC_E_UNSECURE_CALL", "Impersonate on unsecure calls is not supported."),
+	(0x8001_0119, "RPC_E_TOO_LATE", "Security must be initialized before any interfaces are marshalled or unmarshalled. It cannot be changed once initialized."),
+	(0x8001_011a, "RPC_E_NO_GOOD_SECURITY_PACKAGES", "No security packages are installed on this machine or the user is not logged on or there are no compatible security packages between the client and server."),
+	(0x8001_011b, "RPC_E_ACCESS_DENIED", "Access is denied."),
+	(0x8001_011c, "RPC_E_REMOTE_DISABLED", "Remote calls are not allowed for this process."),
+	(0x8001_011d, "RPC_E_INVALID_OBJREF", "The marshaled interface data packet (OBJREF) has an invalid or unknown format."),
+	(0x8001_011e, "RPC_E_NO_CONTEXT", "No context is associated with this call. This happens for some custom marshalled calls and on the client side of the call."),
+	(0x8001_011f, "RPC_E_TIMEOUT", "This operation returned because the timeout period expired."),
+	(0x8001_0120, "RPC_E_NO_SYNC", "There are no synchronize objects to wait on."),
+	(0x8001_0121, "RPC_E_FULLSIC_REQUIRED", "Full subject issuer chain SSL principal name expected from the server."),
+	(0x8001_0122, "RPC_E_INVALID_STD_NAME", "Principal name is not a valid MSSTD name."),
+	(0x8001_0123, "CO_E_FAILEDTOIMPERSONATE", "Unable to impersonate DCOM client."),
+	(0x8001_0124, "CO_E_FAILEDTOGETSECCTX", "Unable to obtain server's security context."),
+	(0x8001_0125, "CO_E_FAILEDTOOPENTHREADTOKEN", "Unable to open the access token of the current thread."),
+	(0x8001_0126, "CO_E_FAILEDTOGETTOKENINFO", "Unable to obtain user info from an access token."),
+	(0x8001_0127, "CO_E_TRUSTEEDOESNTMATCHCLIENT", "The client who called IAccessControl::IsAccessPermitted was not the trustee provided to the method."),
+	(0x8001_0128, "CO_E_FAILEDTOQUERYCLIENTBLANKET", "Unable to obtain the client's security blanket."),
+	(0x8001_0129, "CO_E_FAILEDTOSETDACL", "Unable to set a discretionary ACL into a security descriptor."),
+	(0x8001_012a, "CO_E_ACCESSCHECKFAILED", "The system function, AccessCheck, returned false."),
+	(0x8001_012b, "CO_E_NETACCESSAPIFAILED", "Either NetAccessDel or NetAccessAdd returned an error code."),
+	(0x8001_012c, "CO_E_WRONGTRUSTEENAMESYNTAX", "One of the trustee strings provided by the user did not conform to the <Domain>\\<Name> syntax and it was not the \"*\" string."),
+	(0x8001_012d, "CO_E_INVALIDSID", "One of the security identifiers provided by the user was invalid."),
+	(0x8001_012e, "CO_E_CONVERSIONFAILED", "Unable to convert a wide character trustee string to a multibyte trustee string."),
+	(0x8001_012f, "CO_E_NOMATCHINGSIDFOUND", "Unable to find a security identifier that corresponds to a trustee string provided by the user."),
+	(0x8001_0130, "CO_E_LOOKUPACCSIDFAILED", "The system function, LookupAccountSID, failed."),
+	(0x8001_0131, "CO_E_NOMATCHINGNAMEFOUND", "Unable to find a trustee name that corresponds to a security identifier provided by the user."),
+	(0x8001_0132, "CO_E_LOOKUPACCNAMEFAILED", "The system function, LookupAccountName, failed."),
+	(0x8001_0133, "CO_E_SETSERLHNDLFAILED", "Unable to set or reset a serialization handle."),
+	(0x8001_0134, "CO_E_FAILEDTOGETWINDIR", "Unable to obtain the Windows directory."),
+	(0x8001_0135, "CO_E_PATHTOOLONG", "Path too long."),
+	(0x8001_0136, "CO_E_FAILEDTOGENUUID", "Unable to generate a uuid."),
+	(0x8001_0137, "CO_E_FAILEDTOCREATEFILE", "Unable to create file."),
+	(0x8001_0138, "CO_E_FAILEDTOCLOSEHANDLE", "Unable to close a serialization handle or a file handle."),
+	(0x8001_0139, "CO_E_EXCEEDSYSACLLIMIT", "The number of ACEs in an ACL exceeds the system limit."),
+	(0x8001_013a, "CO_E_ACESINWRONGORDER", "Not all the DENY_ACCESS ACEs are arranged in front of the GRANT_ACCESS ACEs in the stream."),
+	(0x8001_013b, "CO_E_INCOMPATIBLESTREAMVERSION", "The version of ACL format in the stream is not supported by this implementation of IAccessControl."),
+	(0x8001_013c, "CO_E_FAILEDTOOPENPROCESSTOKEN", "Unable to open the access token of the server process."),
+	(0x8001_013d, "CO_E_DECODEFAILED", "Unable to decode the ACL in the stream provided by the user."),
+	(0x8001_013f, "CO_E_ACNOTINITIALIZED", "The COM IAccessControl object is not initialized."),
+	(0x8001_0140, "CO_E_CANCEL_DISABLED", "Call Cancellation is disabled."),
+	(0x8001_ffff, "RPC_E_UNEXPECTED", "An internal error occurred."),
+	(0x8002_0001, "DISP_E_UNKNOWNINTERFACE", "Unknown interface."),
+	(0x8002_0003, "DISP_E_MEMBERNOTFOUND", "Member not found."),
+	(0x8002_0004, "DISP_E_PARAMNOTFOUND", "Parameter not found."),
+	(0x8002_0005, "DISP_E_TYPEMISMATCH", "Type mismatch."),
+	(0x8002_0006, "DISP_E_UNKNOWNNAME", "Unknown name."),
+	(0x8002_0007, "DISP_E_NONAMEDARGS", "No named arguments."),
+	(0x8002_0008, "DISP_E_BADVARTYPE", "Bad variable type."),
+	(0x8002_0009, "DISP_E_EXCEPTION", "Exception occurred."),
+	(0x8002_000a, "DISP_E_OVERFLOW", "Out of present range."),
+	(0x8002_000b, "DISP_E_BADINDEX", "Invalid index."),
+	(0x8002_000c, "DISP_E_UNKNOWNLCID", "The locale ID is unknown."),
+	(0x8002_000d, "DISP_E_ARRAYISLOCKED", "Memory is locked."),
+	(0x8002_000e, "DISP_E_BADPARAMCOUNT", "Invalid number of parameters."),
+	(0x8002_000f, "DISP_E_PARAMNOTOPTIONAL", "Parameter not optional."),
+	(0x8002_0010, "DISP_E_BADCALLEE", "Invalid callee."),
+	(0x8002_0011, "DISP_E_NOTACOLLECTION", "Does not support a collection."),
+	(0x8002_0012, "DISP_E_DIVBYZERO", "Division by zero."),
+	(0x8002_0013, "DISP_E_BUFFERTOOSMALL", "Buffer too small."),
+	(0x8002_8016, "TYPE_E_BUFFERTOOSMALL", "Buffer too small."),
+	(0x8002_8017, "TYPE_E_FIELDNOTFOUND", "Field name not defined in the record."),
+	(0x8002_8018, "TYPE_E_INVDATAREAD", "Old format or invalid type library."),
+	(0x8002_8019, "TYPE_E_UNSUPFORMAT", "Old format or invalid type library."),
+	(0x8002_801c, "TYPE_E_REGISTRYACCESS", "Error accessing the OLE registry."),
+	(0x8002_801d, "TYPE_E_LIBNOTREGISTERED", "Library not registered."),
+	(0x8002_8027, "TYPE_E_UNDEFINEDTYPE", "Bound to unknown type."),
+	(0x8002_8028, "TYPE_E_QUALIFIEDNAMEDISALLOWED", "Qualified name disallowed."),
+	(0x8002_8029, "TYPE_E_INVALIDSTATE", "Invalid forward reference, or reference to uncompiled type."),
+	(0x8002_802a, "TYPE_E_WRONGTYPEKIND", "Type mismatch."),
+	(0x8002_802b, "TYPE_E_ELEMENTNOTFOUND", "Element not found."),
+	(0x8002_802c, "TYPE_E_AMBIGUOUSNAME", "Ambiguous name."),
+	(0x8002_802d, "TYPE_E_NAMECONFLICT", "Name already exists in the library."),
+	(0x8002_802e, "TYPE_E_UNKNOWNLCID", "Unknown LCID."),
+	(0x8002_802f, "TYPE_E_DLLFUNCTIONNOTFOUND", "Function not defined in specified DLL."),
+	(0x8002_88bd, "TYPE_E_BADMODULEKIND", "Wrong module kind for the operation."),
+	(0x8002_88c5, "TYPE_E_SIZETOOBIG", "Size may not exceed 64K."),
+	(0x8002_88c6, "TYPE_E_DUPLICATEID", "Duplicate ID in inheritance hierarchy."),
+	(0x8002_88cf, "TYPE_E_INVALIDID", "Incorrect inheritance depth in standard OLE hmember."),
+	(0x8002_8ca0, "TYPE_E_TYPEMISMATCH", "Type mismatch."),
+	(0x8002_8ca1, "TYPE_E_OUTOFBOUNDS", "Invalid number of arguments."),
+	(0x8002_8ca2, "TYPE_E_IOERROR", "I/O Error."),
+	(0x8002_8ca3, "TYPE_E_CANTCREATETMPFILE", "Error creating unique tmp file."),
+	(0x8002_9c4a, "TYPE_E_CANTLOADLIBRARY", "Error loading type library/DLL."),
+	(0x8002_9c83, "TYPE_E_INCONSISTENTPROPFUNCS", "Inconsistent property functions."),
+	(0x8002_9c84, "TYPE_E_CIRCULARTYPE", "Circular dependency between types/modules."),
+	(0x8003_0001, "STG_E_INVALIDFUNCTION", "Unable to perform requested operation."),
+	(0x8003_0002, "STG_E_FILENOTFOUND", "%1 could not be found."),
+	(0x8003_0003, "STG_E_PATHNOTFOUND", "The path %1 could not be found."),
+	(0x8003_0004, "STG_E_TOOMANYOPENFILES", "There are insufficient resources to open another file."),
+	(0x8003_0005, "STG_E_ACCESSDENIED", "Access Denied."),
+	(0x8003_0006, "STG_E_INVALIDHANDLE", "Attempted an operation on an invalid object."),
+	(0x8003_0008, "STG_E_INSUFFICIENTMEMORY", "There is insufficient memory available to complete operation."),
+	(0x8003_0009, "STG_E_INVALIDPOINTER", "Invalid pointer error."),
+	(0x8003_0012, "STG_E_NOMOREFILES", "There are no more entries to return."),
+	(0x8003_0013, "STG_E_DISKISWRITEPROTECTED", "Disk is write-protected."),
+	(0x8003_0019, "STG_E_SEEKERROR", "An error occurred during a seek operation."),
+	(0x8003_001d, "STG_E_WRITEFAULT", "A disk error occurred during a write operation."),
+	(0x8003_001e, "STG_E_READFAULT", "A disk error occurred during a read operation."),
+	(0x8003_0020, "STG_E_SHAREVIOLATION", "A share violation has occurred."),
+	(0x8003_0021, "STG_E_LOCKVIOLATION", "A lock violation has occurred."),
+	(0x8003_0050, "STG_E_FILEALREADYEXISTS", "%1 already exists."),
+	(0x8003_0057, "STG_E_INVALIDPARAMETER", "Invalid parameter error."),
+	(0x8003_0070, "STG_E_MEDIUMFULL", "There is insufficient disk space to complete operation."),
+	(0x8003_00f0, "STG_E_PROPSETMISMATCHED", "Illegal write of non-simple property to simple property set."),
+	(0x8003_00fa, "STG_E_ABNORMALAPIEXIT", "An API call exited abnormally."),
+	(0x8003_00fb, "STG_E_INVALIDHEADER", "The file %1 is not a valid compound file."),
+	(0x8003_00fc, "STG_E_INVALIDNAME", "The name %1 is not valid."),
+	(0x8003_00fd, "STG_E_UNKNOWN", "An unexpected error occurred."),
+	(0x8003_00fe, "STG_E_UNIMPLEMENTEDFUNCTION", "That function is not implemented."),
+	(0x8003_00ff, "STG_E_INVALIDFLAG", "Invalid flag error."),
+	(0x8003_0100, "STG_E_INUSE", "Attempted to use an object that is busy."),
+	(0x8003_0101, "STG_E_NOTCURRENT", "The storage has been changed since the last commit."),
+	(0x8003_0102, "STG_E_REVERTED", "Attempted to use an object that has ceased to exist."),
+	(0x8003_0103, "STG_E_CANTSAVE", "Can't save."),
+	(0x8003_0104, "STG_E_OLDFORMAT", "The compound file %1 was produced with an incompatible version of storage."),
+	(0x8003_0105, "STG_E_OLDDLL", "The compound file %1 was produced with a newer version of storage."),
+	(0x8003_0106, "STG_E_SHAREREQUIRED", "Share.exe or equivalent is required for operation."),
+	(0x8003_0107, "STG_E_NOTFILEBASEDSTORAGE", "Illegal operation called on non-file based storage."),
+	(0x8003_0108, "STG_E_EXTANTMARSHALLINGS", "Illegal operation called on object with extant marshallings."),
+	(0x8003_0109, "STG_E_DOCFILECORRUPT", "The docfile has been corrupted."),
+	(0x8003_0110, "STG_E_BADBASEADDRESS", "OLE32.DLL has been loaded at the wrong address."),
+	(0x8003_0111, "STG_E_DOCFILETOOLARGE", "The compound file is too large for the current implementation."),
+	(0x8003_0112, "STG_E_NOTSIMPLEFORMAT", "The compound file was not created with the STGM_SIMPLE flag."),
+	(0x8003_0201, "STG_E_INCOMPLETE", "The file download was aborted abnormally. The file is incomplete."),
+	(0x8003_0202, "STG_E_TERMINATED", "The file download has been terminated."),
+	(0x8003_0208, "STG_E_FIRMWARE_SLOT_INVALID", "The specified firmware slot is invalid."),
+	(0x8003_0209, "STG_E_FIRMWARE_IMAGE_INVALID", "The specified firmware image is invalid."),
+	(0x8003_020a, "STG_E_DEVICE_UNRESPONSIVE", "The storage device is unresponsive."),
+	(0x8003_0305, "STG_E_STATUS_COPY_PROTECTION_FAILURE", "Generic Copy Protection Error."),
+	(0x8003_0306, "STG_E_CSS_AUTHENTICATION_FAILURE", "Copy Protection Error - DVD CSS Authentication failed."),
+	(0x8003_0307, "STG_E_CSS_KEY_NOT_PRESENT", "Copy Protection Error - The given sector does not have a valid CSS key."),
+	(0x8003_0308, "STG_E_CSS_KEY_NOT_ESTABLISHED", "Copy Protection Error - DVD session key not established."),
+	(0x8003_0309, "STG_E_CSS_SCRAMBLED_SECTOR", "Copy Protection Error - The read failed because the sector is encrypted."),
+	(0x8003_030a, "STG_E_CSS_REGION_MISMATCH", "Copy Protection Error - The current DVD's region does not correspond to the region setting of the drive."),
+	(0x8003_030b, "STG_E_RESETS_EXHAUSTED", "Copy Protection Error - The drive's region setting may be permanent or the number of user resets has been exhausted."),
+	(0x8004_0000, "OLE_E_OLEVERB", "Invalid OLEVERB structure."),
+	(0x8004_0001, "OLE_E_ADVF", "Invalid advise flags."),
+	(0x8004_0002, "OLE_E_ENUM_NOMORE", "Can't enumerate any more, because the associated data is missing."),
+	(0x8004_0003, "OLE_E_ADVISENOTSUPPORTED", "This implementation doesn't take advises."),
+	(0x8004_0004, "OLE_E_NOCONNECTION", "There is no connection for this connection ID."),
+	(0x8004_0005, "OLE_E_NOTRUNNING", "Need to run the object to perform this operation."),
+	(0x8004_0006, "OLE_E_NOCACHE", "There is no cache to operate on."),
+	(0x8004_0007, "OLE_E_BLANK", "Uninitialized object."),
+	(0x8004_0008, "OLE_E_CLASSDIFF", "Linked object's source class has changed."),
+	(0x8004_0009, "OLE_E_CANT_GETMONIKER", "Not able to get the moniker of the object."),
+	(0x8004_000a, "OLE_E_CANT_BINDTOSOURCE", "Not able to bind to the source."),
+	(0x8004_000b, "OLE_E_STATIC", "Object is static; operation not allowed."),
+	(0x8004_000c, "OLE_E_PROMPTSAVECANCELLED", "User canceled out of save dialog."),
+	(0x8004_000d, "OLE_E_INVALIDRECT", "Invalid rectangle."),
+	(0x8004_000e, "OLE_E_WRONGCOMPOBJ", "compobj.dll is too old for the ole2.dll initialized."),
+	(0x8004_000f, "OLE_E_INVALIDHWND", "Invalid window handle."),
+	(0x8004_0010, "OLE_E_NOT_INPLACEACTIVE", "Object is not in any of the inplace active states."),
+	(0x8004_0011, "OLE_E_CANTCONVERT", "Not able to convert object."),
+	(0x8004_0012, "OLE_E_NOSTORAGE", "Not able to perform the operation because object is not given storage yet."),
+	(0x8004_0064, "DV_E_FORMATETC", "Invalid FORMATETC structure."),
+	(0x8004_0065, "DV_E_DVTARGETDEVICE", "Invalid DVTARGETDEVICE structure."),
+	(0x8004_0066, "DV_E_STGMEDIUM", "Invalid STDGMEDIUM structure."),
+	(0x8004_0067, "DV_E_STATDATA", "Invalid STATDATA structure."),
+	(0x8004_0068, "DV_E_LINDEX", "Invalid lindex."),
+	(0x8004_0069, "DV_E_TYMED", "Invalid tymed."),
+	(0x8004_006a, "DV_E_CLIPFORMAT", "Invalid clipboard format."),
+	(0x8004_006b, "DV_E_DVASPECT", "Invalid aspect(s)."),
+	(0x8004_006c, "DV_E_DVTARGETDEVICE_SIZE", "tdSize parameter of the DVTARGETDEVICE structure is invalid."),
+	(0x8004_006d, "DV_E_NOIVIEWOBJECT", "Object doesn't support IViewObject interface."),
+	(0x8004_0100, "DRAGDROP_E_NOTREGISTERED", "Trying to revoke a drop target that has not been registered."),
+	(0x8004_0101, "DRAGDROP_E_ALREADYREGISTERED", "This window has already been registered as a drop target."),
+	(0x8004_0102, "DRAGDROP_E_INVALIDHWND", "Invalid window handle."),
+	(0x8004_0103, "DRAGDROP_E_CONCURRENT_DRAG_ATTEMPTED", "A drag operation is already in progress."),
+	(0x8004_0110, "CLASS_E_NOAGGREGATION", "Class does not support aggregation (or class object is remote)"),
+	(0x8004_0111, "CLASS_E_CLASSNOTAVAILABLE", "ClassFactory cannot supply requested class"),
+	(0x8004_0112, "CLASS_E_NOTLICENSED", "Class is not licensed for use."),
+	(0x8004_0140, "VIEW_E_DRAW", "Error drawing view."),
+	(0x8004_0150, "REGDB_E_READREGDB", "Could not read key from registry."),
+	(0x8004_0151, "REGDB_E_WRITEREGDB", "Could not write key to registry."),
+	(0x8004_0152, "REGDB_E_KEYMISSING", "Could not find the key in the registry."),
+	(0x8004_0153, "REGDB_E_INVALIDVALUE", "Invalid value for registry."),
+	(0x8004_0154, "REGDB_E_CLASSNOTREG", "Class not registered"),
+	(0x8004_0155, "REGDB_E_IIDNOTREG", "Interface not registered."),
+	(0x8004_0156, "REGDB_E_BADTHREADINGMODEL", "Threading model entry is not valid."),
+	(0x8004_0157, "REGDB_E_PACKAGEPOLICYVIOLATION", "A registration in a package violates package-specific policies."),
+	(0x8004_0160, "CAT_E_CATIDNOEXIST", "CATID does not exist."),
+	(0x8004_0161, "CAT_E_NODESCRIPTION", "Description not found."),
+	(0x8004_0164, "CS_E_PACKAGE_NOTFOUND", "No package in the software installation data in the Active Directory meets this criteria."),
+	(0x8004_0165, "CS_E_NOT_DELETABLE", "Deleting this will break the referential integrity of the software installation data in the Active Directory."),
+	(0x8004_0166, "CS_E_CLASS_NOTFOUND", "The CLSID was not found in the software installation data in the Active Directory."),
+	(0x8004_0167, "CS_E_INVALID_VERSION", "The software installation data in the Active Directory is corrupt."),
+	(0x8004_0168, "CS_E_NO_CLASSSTORE", "There is no software installation data in the Active Directory."),
+	(0x8004_0169, "CS_E_OBJECT_NOTFOUND", "There is no software installation data object in the Active Directory."),
+	(0x8004_016a, "CS_E_OBJECT_ALREADY_EXISTS", "The software installation data object in the Active Directory already exists."),
+	(0x8004_016b, "CS_E_INVALID_PATH", "The path to the software installation data in the Active Directory is not correct."),
+	(0x8004_016c, "CS_E_NETWORK_ERROR", "A network error interrupted the operation."),
+	(0x8004_016d, "CS_E_ADMIN_LIMIT_EXCEEDED", "The size of this object exceeds the maximum size set by the Administrator."),
+	(0x8004_016e, "CS_E_SCHEMA_MISMATCH", "The schema for the software installation data in the Active Directory does not match the required schema."),
+	(0x8004_016f, "CS_E_INTERNAL_ERROR", "An error occurred in the software installation data in the Active Directory."),
+	(0x8004_0170, "CACHE_E_NOCACHE_UPDATED", "Cache not updated."),
+	(0x8004_0180, "OLEOBJ_E_NOVERBS", "No verbs for OLE object."),
+	(0x8004_0181, "OLEOBJ_E_INVALIDVERB", "Invalid verb for OLE object."),
+	(0x8004_01a0, "INPLACE_E_NOTUNDOABLE", "Undo is not available."),
+	(0x8004_01a1, "INPLACE_E_NOTOOLSPACE", "Space for tools is not available."),
+	(0x8004_01c0, "CONVERT10_E_OLESTREAM_GET", "OLESTREAM Get method failed."),
+	(0x8004_01c1, "CONVERT10_E_OLESTREAM_PUT", "OLESTREAM Put method failed."),
+	(0x8004_01c2, "CONVERT10_E_OLESTREAM_FMT", "Contents of the OLESTREAM not in correct format."),
+	(0x8004_01c3, "CONVERT10_E_OLESTREAM_BITMAP_TO_DIB", "There was an error in a Windows GDI call while converting the bitmap to a DIB."),
+	(0x8004_01c4, "CONVERT10_E_STG_FMT", "Contents of the IStorage not in correct format."),
+	(0x8004_01c5, "CONVERT10_E_STG_NO_STD_STREAM", "Contents of IStorage is missing one of the standard streams."),
+	(0x8004_01c6, "CONVERT10_E_STG_DIB_TO_BITMAP", "There was an error in a Windows GDI call while converting the DIB to a bitmap."),
+	(0x8004_01d0, "CLIPBRD_E_CANT_OPEN", "OpenClipboard Failed."),
+	(0x8004_01d1, "CLIPBRD_E_CANT_EMPTY", "EmptyClipboard Failed."),
+	(0x8004_01d2, "CLIPBRD_E_CANT_SET", "SetClipboard Failed."),
+	(0x8004_01d3, "CLIPBRD_E_BAD_DATA", "Data on clipboard is invalid."),
+	(0x8004_01d4, "CLIPBRD_E_CANT_CLOSE", "CloseClipboard Failed."),
+	(0x8004_01e0, "MK_E_CONNECTMANUALLY", "Moniker needs to be connected manually."),
+	(0x8004_01e1, "MK_E_EXCEEDEDDEADLINE", "Operation exceeded deadline."),
+	(0x8004_01e2, "MK_E_NEEDGENERIC", "Moniker needs to be generic."),
+	(0x8004_01e3, "MK_E_UNAVAILABLE", "Operation unavailable."),
+	(0x8004_01e4, "MK_E_SYNTAX", "Invalid syntax."),
+	(0x8004_01e5, "MK_E_NOOBJECT", "No object for moniker."),
+	(0x8004_01e6, "MK_E_INVALIDEXTENSION", "Bad extension for file."),
+	(0x8004_01e7, "MK_E_INTERMEDIATEINTERFACENOTSUPPORTED", "Intermediate operation failed."),
+	(0x8004_01e8, "MK_E_NOTBINDABLE", "Moniker is not bindable."),
+	(0x8004_01e9, "MK_E_NOTBOUND", "Moniker is not bound."),
+	(0x8004_01ea, "MK_E_CANTOPENFILE", "Moniker cannot open file."),
+	(0x8004_01eb, "MK_E_MUSTBOTHERUSER", "User input required for operation to succeed."),
+	(0x8004_01ec, "MK_E_NOINVERSE", "Moniker class has no inverse."),
+	(0x8004_01ed, "MK_E_NOSTORAGE", "Moniker does not refer to storage."),
+	(0x8004_01ee, "MK_E_NOPREFIX", "No common prefix."),
+	(0x8004_01ef, "MK_E_ENUMERATION_FAILED", "Moniker could not be enumerated."),
+	(0x8004_01f0, "CO_E_NOTINITIALIZED", "CoInitialize has not been called."),
+	(0x8004_01f1, "CO_E_ALREADYINITIALIZED", "CoInitialize has already been called."),
+	(0x8004_01f2, "CO_E_CANTDETERMINECLASS", "Could not determine the class of the object."),
+	(0x8004_01f3, "CO_E_CLASSSTRING", "Invalid class string."),
+	(0x8004_01f4, "CO_E_IIDSTRING", "Invalid interface string."),
+	(0x8004_01f5, "CO_E_APPNOTFOUND", "Application not found."),
+	(0x8004_01f6, "CO_E_APPSINGLEUSE", "Application cannot be run more than once."),
+	(0x8004_01f7, "CO_E_ERRORINAPP", "Some error in application program."),
+	(0x8004_01f8, "CO_E_DLLNOTFOUND", "DLL for class not found."),
+	(0x8004_01f9, "CO_E_ERRORINDLL", "Error in the DLL."),
+	(0x8004_01fa, "CO_E_WRONGOSFORAPP", "Wrong operating system or operating system version for application."),
+	(0x8004_01fb, "CO_E_OBJNOTREG", "Object is not registered."),
+	(0x8004_01fc, "CO_E_OBJISREG", "Object is already registered."),
+	(0x8004_01fd, "CO_E_OBJNOTCONNECTED", "Object is not connected to server."),
+	(0x8004_01fe, "CO_E_APPDIDNTREG", "Application was launched, but it didn't register a class factory."),
+	(0x8004_01ff, "CO_E_RELEASED", "Object has been released."),
+	(0x8004_0201, "EVENT_E_ALL_SUBSCRIBERS_FAILED", "An event was unable to invoke any of the subscribers."),
+	(0x8004_0203, "EVENT_E_QUERYSYNTAX", "A syntax error occurred trying to evaluate a query string."),
+	(0x8004_0204, "EVENT_E_QUERYFIELD", "An invalid field name was used in a query string."),
+	(0x8004_0205, "EVENT_E_INTERNALEXCEPTION", "An unexpected exception was raised."),
+	(0x8004_0206, "EVENT_E_INTERNALERROR", "An unexpected internal error was detected."),
+	(0x8004_0207, "EVENT_E_INVALID_PER_USER_SID", "The owner SID on a per-user subscription doesn't exist."),
+	(0x8004_0208, "EVENT_E_USER_EXCEPTION", "A user-supplied component or subscriber raised an exception."),
+	(0x8004_0209, "EVENT_E_TOO_MANY_METHODS", "An interface has too many methods to fire events from."),
+	(0x8004_020a, "EVENT_E_MISSING_EVENTCLASS", "A subscription cannot be stored unless its event class already exists."),
+	(0x8004_020b, "EVENT_E_NOT_ALL_REMOVED", "Not all the objects requested could be removed."),
+	(0x8004_020c, "EVENT_E_COMPLUS_NOT_INSTALLED", "COM+ is required for this operation, but is not installed."),
+	(0x8004_020d, "EVENT_E_CANT_MODIFY_OR_DELETE_UNCONFIGURED_OBJECT", "Cannot modify or delete an object that was not added using the COM+ Admin SDK."),
+	(0x8004_020e, "EVENT_E_CANT_MODIFY_OR_DELETE_CONFIGURED_OBJECT", "Cannot modify or delete an object that was added using the COM+ Admin SDK."),
+	(0x8004_020f, "EVENT_E_INVALID_EVENT_CLASS_PARTITION", "The event class for this subscription is in an invalid partition."),
+	(0x8004_0210, "EVENT_E_PER_USER_SID_NOT_LOGGED_ON", "The owner of the PerUser subscription is not logged on to the system specified."),
+	(0x8004_0212, "TPC_E_NO_DEFAULT_TABLET", "No default tablet."),
+	(0x8004_0219, "TPC_E_INVALID_INPUT_RECT", "Invalid input rectangle."),
+	(0x8004_021b, "TPC_E_UNKNOWN_PROPERTY", "Unknown property."),
+	(0x8004_0222, "TPC_E_INVALID_STROKE", "Invalid stroke."),
+	(0x8004_0223, "TPC_E_INITIALIZE_FAIL", "Tablet PC initialization failed."),
+	(0x8004_0232, "TPC_E_NOT_RELEVANT", "The data required for the operation was not supplied."),
+	(0x8004_0233, "TPC_E_INVALID_PACKET_DESCRIPTION", "Invalid packet description."),
+	(0x8004_0235, "TPC_E_RECOGNIZER_NOT_REGISTERED", "There are no handwriting recognizers registered."),
+	(0x8004_0236, "TPC_E_INVALID_RIGHTS", "User does not have the necessary rights to read recognizer information."),
+	(0x8004_0237, "TPC_E_OUT_OF_ORDER_CALL", "API calls were made in an incorrect order."),
+	(0x8004_0238, "TPC_E_QUEUE_FULL", "Queue is full."),
+	(0x8004_0239, "TPC_E_INVALID_CONFIGURATION", "RtpEnabled called multiple times."),
+	(0x8004_023a, "TPC_E_INVALID_DATA_FROM_RECOGNIZER", "A recognizer returned invalid data."),
+	(0x8004_0241, "TPC_E_INVALID_PROPERTY", "Invalid property."),
+	(0x8004_1309, "SCHED_E_TRIGGER_NOT_FOUND", "Trigger not found."),
+	(0x8004_130a, "SCHED_E_TASK_NOT_READY", "One or more of the properties that are needed to run this task have not been set."),
+	(0x8004_130b, "SCHED_E_TASK_NOT_RUNNING", "There is no running instance of the task."),
+	(0x8004_130c, "SCHED_E_SERVICE_NOT_INSTALLED", "The Task Scheduler Service is not installed on this computer."),
+	(0x8004_130d, "SCHED_E_CANNOT_OPEN_TASK", "The task object could not be opened."),
+	(0x8004_130e, "SCHED_E_INVALID_TASK", "The object is either an invalid task object or is not a task object."),
+	(0x8004_130f, "SCHED_E_ACCOUNT_INFORMATION_NOT_SET", "No account information could be found in the Task Scheduler security database for the task indicated."),
+	(0x8004_1310, "SCHED_E_ACCOUNT_NAME_NOT_FOUND", "Unable to establish existence of the account specified."),
+	(0x8004_1311, "SCHED_E_ACCOUNT_DBASE_CORRUPT", "Corruption was detected in the Task Scheduler security database; the database has been reset."),
+	(0x8004_1312, "SCHED_E_NO_SECURITY_SERVICES", "Task Scheduler security services are available only on Windows NT."),
+	(0x8004_1313, "SCHED_E_UNKNOWN_OBJECT_VERSION", "The task object version is either unsupported or invalid."),
+	(0x8004_1314, "SCHED_E_UNSUPPORTED_ACCOUNT_OPTION", "The task has been configured with an unsupported combination of account settings and run time options."),
+	(0x8004_1315, "SCHED_E_SERVICE_NOT_RUNNING", "The Task Scheduler Service is not running."),
+	(0x8004_1316, "SCHED_E_UNEXPECTEDNODE", "The task XML contains an unexpected node."),
+	(0x8004_1317, "SCHED_E_NAMESPACE", "The task XML contains an element or attribute from an unexpected namespace."),
+	(0x8004_1318, "SCHED_E_INVALIDVALUE", "The task XML contains a value which is incorrectly formatted or out of range."),
+	(0x8004_1319, "SCHED_E_MISSINGNODE", "The task XML is missing a required element or attribute."),
+	(0x8004_131a, "SCHED_E_MALFORMEDXML", "The task XML is malformed."),
+	(0x8004_131d, "SCHED_E_TOO_MANY_NODES", "The task XML contains too many nodes of the same type."),
+	(0x8004_131e, "SCHED_E_PAST_END_BOUNDARY", "The task cannot be started after the trigger end boundary."),
+	(0x8004_131f, "SCHED_E_ALREADY_RUNNING", "An instance of this task is already running."),
+	(0x8004_1320, "SCHED_E_USER_NOT_LOGGED_ON", "The task will not run because the user is not logged on."),
+	(0x8004_1321, "SCHED_E_INVALID_TASK_HASH", "The task image is corrupt or has been tampered with."),
+	(0x8004_1322, "SCHED_E_SERVICE_NOT_AVAILABLE", "The Task Scheduler service is not available."),
+	(0x8004_1323, "SCHED_E_SERVICE_TOO_BUSY", "The Task Scheduler service is too busy to handle your request. Please try again later."),
+	(0x8004_1324, "SCHED_E_TASK_ATTEMPTED", "The Task Scheduler service attempted to run the task, but the task did not run due to one of the constraints in the task definition."),
+	(0x8004_1326, "SCHED_E_TASK_DISABLED", "The task is disabled."),
+	(0x8004_1327, "SCHED_E_TASK_NOT_V1_COMPAT", "The task has properties that are not compatible with previous versions of Windows."),
+	(0x8004_1328, "SCHED_E_START_ON_DEMAND", "The task settings do not allow the task to start on demand."),
+	(0x8004_1329, "SCHED_E_TASK_NOT_UBPM_COMPAT", "The combination of properties that task is using is not compatible with the scheduling engine."),
+	(0x8004_1330, "SCHED_E_DEPRECATED_FEATURE_USED", "The task definition uses a deprecated feature."),
+	(0x8004_d000, "XACT_E_ALREADYOTHERSINGLEPHASE", "Another single phase resource manager has already been enlisted in this transaction."),
+	(0x8004_d001, "XACT_E_CANTRETAIN", "A retaining commit or abort is not supported."),
+	(0x8004_d002, "XACT_E_COMMITFAILED", "The transaction failed to commit for an unknown reason. The transaction was aborted."),
+	(0x8004_d003, "XACT_E_COMMITPREVENTED", "Cannot call commit on this transaction object because the calling application did not initiate the transaction."),
+	(0x8004_d004, "XACT_E_HEURISTICABORT", "Instead of committing, the resource heuristically aborted."),
+	(0x8004_d005, "XACT_E_HEURISTICCOMMIT", "Instead of aborting, the resource heuristically committed."),
+	(0x8004_d006, "XACT_E_HEURISTICDAMAGE", "Some of the states of the resource were committed while others were aborted, likely because of heuristic decisions."),
+	(0x8004_d007, "XACT_E_HEURISTICDANGER", "Some of the states of the resource may have been committed while others may have been aborted, likely because of heuristic decisions."),
+	(0x8004_d008, "XACT_E_ISOLATIONLEVEL", "The requested isolation level is not valid or supported."),
+	(0x8004_d009, "XACT_E_NOASYNC", "The transaction manager doesn't support an asynchronous operation for this method."),
+	(0x8004_d00a, "XACT_E_NOENLIST", "Unable to enlist in the transaction."),
+	(0x8004_d00b, "XACT_E_NOISORETAIN", "The requested semantics of retention of isolation across retaining commit and abort boundaries cannot be supported by this transaction implementation, or isoFlags was not equal to zero."),
+	(0x8004_d00c, "XACT_E_NORESOURCE", "There is no resource presently associated with this enlistment."),
+	(0x8004_d00d, "XACT_E_NOTCURRENT", "The transaction failed to commit due to the failure of optimistic concurrency control in at least one of the resource managers."),
+	(0x8004_d00e, "XACT_E_NOTRANSACTION", "The transaction has already been implicitly or explicitly committed or aborted."),
+	(0x8004_d00f, "XACT_E_NOTSUPPORTED", "An invalid combination of flags was specified."),
+	(0x8004_d010, "XACT_E_UNKNOWNRMGRID", "The resource manager id is not associated with this transaction or the transaction manager."),
+	(0x8004_d011, "XACT_E_WRONGSTATE", "This method was called in the wrong state."),
+	(0x8004_d012, "XACT_E_WRONGUOW", "The indicated unit of work does not match the unit of work expected by the resource manager."),
+	(0x8004_d013, "XACT_E_XTIONEXISTS", "An enlistment in a transaction already exists."),
+	(0x8004_d014, "XACT_E_NOIMPORTOBJECT", "An import object for the transaction could not be found."),
+	(0x8004_d015, "XACT_E_INVALIDCOOKIE", "The transaction cookie is invalid."),
+	(0x8004_d016, "XACT_E_INDOUBT", "The transaction status is in doubt. A communication failure occurred, or a transaction manager or resource manager has failed."),
+	(0x8004_d017, "XACT_E_NOTIMEOUT", "A time-out was specified, but time-outs are not supported."),
+	(0x8004_d018, "XACT_E_ALREADYINPROGRESS", "The requested operation is already in progress for the transaction."),
+	(0x8004_d019, "XACT_E_ABORTED", "The transaction has already been aborted."),
+	(0x8004_d01a, "XACT_E_LOGFULL", "The Transaction Manager returned a log full error."),
+	(0x8004_d01b, "XACT_E_TMNOTAVAILABLE", "The Transaction Manager is not available."),
+	(0x8004_d01c, "XACT_E_CONNECTION_DOWN", "A connection with the transaction manager was lost."),
+	(0x8004_d01d, "XACT_E_CONNECTION_DENIED", "A request to establish a connection with the transaction manager was denied."),
+	(0x8004_d01e, "XACT_E_REENLISTTIMEOUT", "Resource manager reenlistment to determine transaction status timed out."),
+	(0x8004_d01f, "XACT_E_TIP_CONNECT_FAILED", "The transaction manager failed to establish a connection with another Transaction Internet Protocol (TIP) transaction manager."),
+	(0x8004_d020, "XACT_E_TIP_PROTOCOL_ERROR", "The transaction manager encountered a protocol error with another TIP transaction manager."),
+	(0x8004_d021, "XACT_E_TIP_PULL_FAILED", "The transaction manager could not propagate a transaction from another TIP transaction manager."),
+	(0x8004_d022, "XACT_E_DEST_TMNOTAVAILABLE", "The Transaction Manager on the destination machine is not available."),
+	(0x8004_d023, "XACT_E_TIP_DISABLED", "The Transaction Manager has disabled its support for TIP."),
+	(0x8004_d024, "XACT_E_NETWORK_TX_DISABLED", "The transaction manager has disabled its support for remote/network transactions."),
+	(0x8004_d025, "XACT_E_PARTNER_NETWORK_TX_DISABLED", "The partner transaction manager has disabled its support for remote/network transactions."),
+	(0x8004_d026, "XACT_E_XA_TX_DISABLED", "The transaction manager has disabled its support for XA transactions."),
+	(0x8004_d027, "XACT_E_UNABLE_TO_READ_DTC_CONFIG", "MSDTC was unable to read its configuration information."),
+	(0x8004_d028, "XACT_E_UNABLE_TO_LOAD_DTC_PROXY", "MSDTC was unable to load the dtc proxy dll."),
+	(0x8004_d029, "XACT_E_ABORTING", "The local transaction has aborted."),
+	(0x8004_d02a, "XACT_E_PUSH_COMM_FAILURE", "The MSDTC transaction manager was unable to push the transaction to the destination transaction manager due to communication problems."),
+	(0x8004_d02b, "XACT_E_PULL_COMM_FAILURE", "The MSDTC transaction manager was unable to pull the transaction from the source transaction manager due to communication problems."),
+	(0x8004_d02c, "XACT_E_LU_TX_DISABLED", "The MSDTC transaction manager has disabled its support for SNA LU 6.2 transactions."),
+	(0x8004_d080, "XACT_E_CLERKNOTFOUND", "The CRM clerk was not found."),
+	(0x8004_d081, "XACT_E_CLERKEXISTS", "The CRM clerk already exists."),
+	(0x8004_d082, "XACT_E_RECOVERYINPROGRESS", "Recovery is still in progress."),
+	(0x8004_d083, "XACT_E_TRANSACTIONCLOSED", "The transaction has been closed."),
+	(0x8004_d084, "XACT_E_INVALIDLSN", "The log sequence number is invalid."),
+	(0x8004_d085, "XACT_E_REPLAYREQUEST", "A replay request was received."),
+	(0x8004_e002, "CONTEXT_E_ABORTED", "The root transaction wanted to commit, but transaction aborted."),
+	(0x8004_e003, "CONTEXT_E_ABORTING", "You made a method call on a COM+ component that has a transaction that has already aborted or in the process of aborting."),
+	(0x8004_e004, "CONTEXT_E_NOCONTEXT", "There is no MTS object context."),
+	(0x8004_e005, "CONTEXT_E_WOULD_DEADLOCK", "The component is configured to use synchronization and this method call would cause a deadlock to occur."),
+	(0x8004_e006, "CONTEXT_E_SYNCH_TIMEOUT", "The component is configured to use synchronization and a thread has timed out waiting to enter the context."),
+	(0x8004_e007, "CONTEXT_E_OLDREF", "You made a method call on a COM+ component that has a transaction that has already committed or aborted."),
+	(0x8004_e00c, "CONTEXT_E_ROLENOTFOUND", "The specified role was not configured for the application."),
+	(0x8004_e00f, "CONTEXT_E_TMNOTAVAILABLE", "COM+ was unable to talk to the Microsoft Distributed Transaction Coordinator."),
+	(0x8004_e021, "CO_E_ACTIVATIONFAILED", "An unexpected error occurred during COM+ Activation."),
+	(0x8004_e022, "CO_E_ACTIVATIONFAILED_EVENTLOGGED", "COM+ Activation failed. Check the event log for more information."),
+	(0x8004_e023, "CO_E_ACTIVATIONFAILED_CATALOGERROR", "COM+ Activation failed due to a catalog or configuration error."),
+	(0x8004_e024, "CO_E_ACTIVATIONFAILED_TIMEOUT", "COM+ activation failed because the activation could not be completed in the specified amount of time."),
+	(0x8004_e025, "CO_E_INITIALIZATIONFAILED", "COM+ Activation failed because an initialization function failed. Check the event log for more information."),
+	(0x8004_e026, "CONTEXT_E_NOJIT", "The requested operation requires that JIT be in the current context and it is not."),
+	(0x8004_e027, "CONTEXT_E_NOTRANSACTION", "The requested operation requires that the current context have a Transaction, and it does not."),
+	(0x8004_e028, "CO_E_THREADINGMODEL_CHANGED", "The components threading model has changed after install into a COM+ Application. Please re-install component."),
+	(0x8004_e029, "CO_E_NOIISINTRINSICS", "IIS intrinsics not available. Start your work with IIS."),
+	(0x8004_e02a, "CO_E_NOCOOKIES", "An attempt to write a cookie failed."),
+	(0x8004_e02b, "CO_E_DBERROR", "An attempt to use a database generated a database specific error."),
+	(0x8004_e02c, "CO_E_NOTPOOLED", "The COM+ component you created must use object pooling to work."),
+	(0x8004_e02d, "CO_E_NOTCONSTRUCTED", "The COM+ component you created must use object construction to work correctly."),
+	(0x8004_e02e, "CO_E_NOSYNCHRONIZATION", "The COM+ component requires synchronization, and it is not configured for it."),
+	(0x8004_e02f, "CO_E_ISOLEVELMISMATCH", "The TxIsolation Level property for the COM+ component being created is stronger than the TxIsolationLevel for the \"root\" component for the transaction. The creation failed."),
+	(0x8004_e030, "CO_E_CALL_OUT_OF_TX_SCOPE_NOT_ALLOWED", "The component attempted to make a cross-context call between invocations of EnterTransactionScope and ExitTransactionScope. This is not allowed."),
+	(0x8004_e031, "CO_E_EXIT_TRANSACTION_SCOPE_NOT_CALLED", "The component made a call to EnterTransactionScope, but did not make a corresponding call to ExitTransactionScope before returning."),
+	(0x8007_0005, "E_ACCESSDENIED", "Access is denied."),
+	(0x8007_0006, "E_HANDLE", "The handle is invalid."),
+	(0x8007_000e, "E_OUTOFMEMORY", "Not enough memory resources are available to complete this operation."),
+	(0x8007_0057, "E_INVALIDARG", "The parameter is incorrect."),
+	(0x8007_2ee1, "WININET_E_OUT_OF_HANDLES", "No more Internet handles can be allocated."),
+	(0x8007_2ee2, "WININET_E_TIMEOUT", "The operation timed out."),
+	(0x8007_2ee3, "WININET_E_EXTENDED_ERROR", "The server returned extended information."),
+	(0x8007_2ee4, "WININET_E_INTERNAL_ERROR", "An internal error occurred in the Microsoft Internet extensions."),
+	(0x8007_2ee5, "WININET_E_INVALID_URL", "The URL is invalid."),
+	(0x8007_2ee6, "WININET_E_UNRECOGNIZED_SCHEME", "The URL does not use a recognized protocol."),
+	(0x8007_2ee7, "WININET_E_NAME_NOT_RESOLVED", "The server name or address could not be resolved."),
+	(0x8007_2ee8, "WININET_E_PROTOCOL_NOT_FOUND", "A protocol with the required capabilities was not found."),
+	(0x8007_2ee9, "WININET_E_INVALID_OPTION", "The option is invalid."),
+	(0x8007_2eea, "WININET_E_BAD_OPTION_LENGTH", "The length is incorrect for the option type."),
+	(0x8007_2eeb, "WININET_E_OPTION_NOT_SETTABLE", "The option value cannot be set."),
+	(0x8007_2eec, "WININET_E_SHUTDOWN", "Microsoft Internet Extension support has been shut down."),
+	(0x8007_2eed, "WININET_E_INCORRECT_USER_NAME", "The user name was not allowed."),
+	(0x8007_2eee, "WININET_E_INCORRECT_PASSWORD", "The password was not allowed."),
+	(0x8007_2eef, "WININET_E_LOGIN_FAILURE", "The login request was denied."),
+	(0x8007_2ef0, "WININET_E_INVALID_OPERATION", "The requested operation is invalid."),
+	(0x8007_2ef1, "WININET_E_OPERATION_CANCELLED", "The operation has been canceled."),
+	(0x8007_2ef2, "WININET_E_INCORRECT_HANDLE_TYPE", "The supplied handle is the wrong type for the requested operation."),
+	(0x8007_2ef3, "WININET_E_INCORRECT_HANDLE_STATE", "The requested operation cannot be carried out because the handle supplied is not in the correct state."),
+	(0x8007_2ef4, "WININET_E_NOT_PROXY_REQUEST", "The request cannot be made on a Proxy session."),
+	(0x8007_2ef5, "WININET_E_REGISTRY_VALUE_NOT_FOUND", "The registry value could not be found."),
+	(0x8007_2ef6, "WININET_E_BAD_REGISTRY_PARAMETER", "The registry parameter is incorrect."),
+	(0x8007_2ef7, "WININET_E_NO_DIRECT_ACCESS", "Direct Internet access is not available."),
+	(0x8007_2ef8, "WININET_E_NO_CONTEXT", "No context value was supplied."),
+	(0x8007_2ef9, "WININET_E_NO_CALLBACK", "No status callback was supplied."),
+	(0x8007_2efa, "WININET_E_REQUEST_PENDING", "There are outstanding requests."),
+	(0x8007_2efb, "WININET_E_INCORRECT_FORMAT", "The information format is incorrect."),
+	(0x8007_2efc, "WININET_E_ITEM_NOT_FOUND", "The requested item could not be found."),
+	(0x8007_2efd, "WININET_E_CANNOT_CONNECT", "A connection with the server could not be established."),
+	(0x8007_2efe, "WININET_E_CONNECTION_ABORTED", "The connection with the server was terminated abnormally."),
+	(0x8007_2eff, "WININET_E_CONNECTION_RESET", "The connection with the server was reset."),
+	(0x8007_2f00, "WININET_E_FORCE_RETRY", "The action must be retried."),
+	(0x8007_2f01, "WININET_E_INVALID_PROXY_REQUEST", "The proxy request is invalid."),
+	(0x8007_2f02, "WININET_E_NEED_UI", "User interaction is required to complete the operation."),
+	(0x8007_2f04, "WININET_E_HANDLE_EXISTS", "The handle already exists."),
+	(0x8007_2f05, "WININET_E_SEC_CERT_DATE_INVALID", "The date in the certificate is invalid or has expired."),
+	(0x8007_2f06, "WININET_E_SEC_CERT_CN_INVALID", "The host name in the certificate is invalid or does not match."),
+	(0x8007_2f07, "WININET_E_HTTP_TO_HTTPS_ON_REDIR", "A redirect request will change a non-secure to a secure connection."),
+	(0x8007_2f08, "WININET_E_HTTPS_TO_HTTP_ON_REDIR", "A redirect request will change a secure to a non-secure connection."),
+	(0x8007_2f09, "WININET_E_MIXED_SECURITY", "Mixed secure and non-secure connections."),
+	(0x8007_2f0a, "WININET_E_CHG_POST_IS_NON_SECURE", "Changing to non-secure post."),
+	(0x8007_2f0b, "WININET_E_POST_IS_NON_SECURE", "Data is being posted on a non-secure connection."),
+	(0x8007_2f0c, "WININET_E_CLIENT_AUTH_CERT_NEEDED", "A certificate is required to complete client authentication."),
+	(0x8007_2f0d, "WININET_E_INVALID_CA", "The certificate authority is invalid or incorrect."),
+	(0x8007_2f0e, "WININET_E_CLIENT_AUTH_NOT_SETUP", "Client authentication has not been correctly installed."),
+	(0x8007_2f0f, "WININET_E_ASYNC_THREAD_FAILED", "An error has occurred in a Wininet asynchronous thread. You may need to restart."),
+	(0x8007_2f10, "WININET_E_REDIRECT_SCHEME_CHANGE", "The protocol scheme has changed during a redirect operation."),
+	(0x8007_2f11, "WININET_E_DIALOG_PENDING", "There are operations awaiting retry."),
+	(0x8007_2f12, "WININET_E_RETRY_DIALOG", "The operation must be retried."),
+	(0x8007_2f13, "WININET_E_NO_NEW_CONTAINERS", "There are no new cache containers."),
+	(0x8007_2f14, "WININET_E_HTTPS_HTTP_SUBMIT_REDIR", "A security zone check indicates the operation must be retried."),
+	(0x8007_2f17, "WININET_E_SEC_CERT_ERRORS", "The SSL certificate contains errors."),
+	(0x8007_2f19, "WININET_E_SEC_CERT_REV_FAILED", "It was not possible to connect to the revocation server or a definitive response could not be obtained."),
+	(0x8007_2f76, "WININET_E_HEADER_NOT_FOUND", "The requested header was not found."),
+	(0x8007_2f77, "WININET_E_DOWNLEVEL_SERVER", "The server does not support the requested protocol level."),
+	(0x8007_2f78, "WININET_E_INVALID_SERVER_RESPONSE", "The server returned an invalid or unrecognized response."),
+	(0x8007_2f79, "WININET_E_INVALID_HEADER", "The supplied HTTP header is invalid."),
+	(0x8007_2f7a, "WININET_E_INVALID_QUERY_REQUEST", "The request for a HTTP header is invalid."),
+	(0x8007_2f7b, "WININET_E_HEADER_ALREADY_EXISTS", "The HTTP header already exists."),
+	(0x8007_2f7c, "WININET_E_REDIRECT_FAILED", "The HTTP redirect request failed."),
+	(0x8007_2f7d, "WININET_E_SECURITY_CHANNEL_ERROR", "An error occurred in the secure channel support."),
+	(0x8007_2f7e, "WININET_E_UNABLE_TO_CACHE_FILE", "The file could not be written to the cache."),
+	(0x8007_2f7f, "WININET_E_TCPIP_NOT_INSTALLED", "The TCP/IP protocol is not installed properly."),
+	(0x8007_2f80, "WININET_E_NOT_REDIRECTED", "The HTTP request was not redirected."),
+	(0x8007_2f81, "WININET_E_COOKIE_NEEDS_CONFIRMATION", "A cookie from the server must be confirmed by the user."),
+	(0x8007_2f82, "WININET_E_COOKIE_DECLINED", "A cookie from the server has been declined acceptance."),
+	(0x8007_2f83, "WININET_E_DISCONNECTED", "The computer is disconnected from the network."),
+	(0x8007_2f84, "WININET_E_SERVER_UNREACHABLE", "The server is unreachable."),
+	(0x8007_2f85, "WININET_E_PROXY_SERVER_UNREACHABLE", "The proxy server is unreachable."),
+	(0x8007_2f86, "WININET_E_BAD_AUTO_PROXY_SCRIPT", "The proxy auto-configuration script is in error."),
+	(0x8007_2f87, "WININET_E_UNABLE_TO_DOWNLOAD_SCRIPT", "Could not download the proxy auto-configuration script file."),
+	(0x8007_2f88, "WININET_E_REDIRECT_NEEDS_CONFIRMATION", "The HTTP redirect request must be confirmed by the user."),
+	(0x8007_2f89, "WININET_E_SEC_INVALID_CERT", "The SSL certificate is invalid."),
+	(0x8007_2f8a, "WININET_E_SEC_CERT_REVOKED", "The SSL certificate was revoked."),
+	(0x8007_2f8b, "WININET_E_FAILED_DUETOSECURITYCHECK", "The Dialup failed because file sharing was turned on and a failure was requested if security check was needed."),
+	(0x8007_2f8c, "WININET_E_NOT_INITIALIZED", "Initialization of the WinINet API has not occurred."),
+	(0x8007_2f8e, "WININET_E_LOGIN_FAILURE_DISPLAY_ENTITY_BODY", "Login failed and the client should display the entity body to the user."),
+	(0x8007_2f8f, "WININET_E_DECODING_FAILED", "Content decoding has failed."),
+	(0x8008_0001, "CO_E_CLASS_CREATE_FAILED", "Attempt to create a class object failed."),
+	(0x8008_0002, "CO_E_SCM_ERROR", "OLE service could not bind object."),
+	(0x8008_0003, "CO_E_SCM_RPC_FAILURE", "RPC communication failed with OLE service."),
+	(0x8008_0004, "CO_E_BAD_PATH", "Bad path to object."),
+	(0x8008_0005, "CO_E_SERVER_EXEC_FAILURE", "Server execution failed."),
+	(0x8008_0006, "CO_E_OBJSRV_RPC_FAILURE", "OLE service could not communicate with the object server."),
+	(0x8008_0007, "MK_E_NO_NORMALIZED", "Moniker path could not be normalized."),
+	(0x8008_0008, "CO_E_SERVER_STOPPING", "Object server is stopping when OLE service contacts it."),
+	(0x8008_0009, "MEM_E_INVALID_ROOT", "An invalid root block pointer was specified."),
+	(0x8008_0010, "MEM_E_INVALID_LINK", "An allocation chain contained an invalid link pointer."),
+	(0x8008_0011, "MEM_E_INVALID_SIZE", "The requested allocation size was too large."),
+	(0x8008_0015, "CO_E_MISSING_DISPLAYNAME", "The activation requires a display name to be present under the class key."),
+	(0x8008_0016, "CO_E_RUNAS_VALUE_MUST_BE_AAA", "The activation requires that the RunAs value for the application is Activate As Activator."),
+	(0x8008_0017, "CO_E_ELEVATION_DISABLED", "The class is not configured to support Elevated activation."),
+	(0x8008_0200, "APPX_E_PACKAGING_INTERNAL", "Appx packaging API has encountered an internal error."),
+	(0x8008_0201, "APPX_E_INTERLEAVING_NOT_ALLOWED", "The file is not a valid Appx package because its contents are interleaved."),
+	(0x8008_0202, "APPX_E_RELATIONSHIPS_NOT_ALLOWED", "The file is not a valid Appx package because it contains OPC relationships."),
+	(0x8008_0203, "APPX_E_MISSING_REQUIRED_FILE", "The file is not a valid Appx package because it is missing a manifest or block map, or missing a signature file when the code integrity file is present."),
+	(0x8008_0204, "APPX_E_INVALID_MANIFEST", "The Appx package's manifest is invalid."),
+	(0x8008_0205, "APPX_E_INVALID_BLOCKMAP", "The Appx package's block map is invalid."),
+	(0x8008_0206, "APPX_E_CORRUPT_CONTENT", "The Appx package's content cannot be read because it is corrupt."),
+	(0x8008_0207, "APPX_E_BLOCK_HASH_INVALID", "The computed hash value of the block does not match the one stored in the block map."),
+	(0x8008_0208, "APPX_E_REQUESTED_RANGE_TOO_LARGE", "The requested byte range is over 4GB when translated to byte range of blocks."),
+	(0x8008_0209, "APPX_E_INVALID_SIP_CLIENT_DATA", "The SIP_SUBJECTINFO structure used to sign the package didn't contain the required data."),
+	(0x8008_020a, "APPX_E_INVALID_KEY_INFO", "The APPX_KEY_INFO structure used to encrypt or decrypt the package contains invalid data."),
+	(0x8008_020b, "APPX_E_INVALID_CONTENTGROUPMAP", "The Appx package's content group map is invalid."),
+	(0x8008_020c, "APPX_E_INVALID_APPINSTALLER", "The .appinstaller file is invalid."),
+	(0x8008_020d, "APPX_E_DELTA_BASELINE_VERSION_MISMATCH", "The baseline package version in delta package does not match the version in the baseline package to be updated."),
+	(0x8008_020e, "APPX_E_DELTA_PACKAGE_MISSING_FILE", "The delta package is missing a file from the updated package."),
+	(0x8008_020f, "APPX_E_INVALID_DELTA_PACKAGE", "The delta package is invalid."),
+	(0x8008_0210, "APPX_E_DELTA_APPENDED_PACKAGE_NOT_ALLOWED", "The delta appended package is not allowed for the current operation."),
+	(0x8008_0211, "APPX_E_INVALID_PACKAGING_LAYOUT", "The packaging layout file is invalid."),
+	(0x8008_0212, "APPX_E_INVALID_PACKAGESIGNCONFIG", "The packageSignConfig file is invalid."),
+	(0x8008_0213, "APPX_E_RESOURCESPRI_NOT_ALLOWED", "The resources.pri file is not allowed when there are no resource elements in the package manifest."),
+	(0x8008_0214, "APPX_E_FILE_COMPRESSION_MISMATCH", "The compression state of a file in the payload package does not match the compression state of the file in the bundle."),
+	(0x8008_0215, "APPX_E_INVALID_PAYLOAD_PACKAGE_EXTENSION", "Non-appx extensions are not allowed for payload packages targeting older platforms."),
+	(0x8008_0216, "APPX_E_INVALID_ENCRYPTION_EXCLUSION_FILE_LIST", "The encryptionExclusionFileList file is invalid."),
+	(0x8008_0300, "BT_E_SPURIOUS_ACTIVATION", "The background task activation is spurious."),
+	(0x8009_0001, "NTE_BAD_UID", "Bad UID."),
+	(0x8009_0002, "NTE_BAD_HASH", "Bad Hash."),
+	(0x8009_0003, "NTE_BAD_KEY", "Bad Key."),
+	(0x8009_0004, "NTE_BAD_LEN", "Bad Length."),
+	(0x8009_0005, "NTE_BAD_DATA", "Bad Data."),
+	(0x8009_0006, "NTE_BAD_SIGNATURE", "Invalid Signature."),
+	(0x8009_0007, "NTE_BAD_VER", "Bad Version of provider."),
+	(0x8009_0008, "NTE_BAD_ALGID", "Invalid algorithm specified."),
+	(0x8009_0009, "NTE_BAD_FLAGS", "Invalid flags specified."),
+	(0x8009_000a, "NTE_BAD_TYPE", "Invalid type specified."),
+	(0x8009_000b, "NTE_BAD_KEY_STATE", "Key not valid for use in specified state."),
+	(0x8009_000c, "NTE_BAD_HASH_STATE", "Hash not valid for use in specified state."),
+	(0x8009_000d, "NTE_NO_KEY", "Key does not exist."),
+	(0x8009_000e, "NTE_NO_MEMORY", "Insufficient memory available for the operation."),
+	(0x8009_000f, "NTE_EXISTS", "Object already exists."),
+	(0x8009_0010, "NTE_PERM", "Access denied."),
+	(0x8009_0011, "NTE_NOT_FOUND", "Object was not found."),
+	(0x8009_0012, "NTE_DOUBLE_ENCRYPT", "Data already encrypted."),
+	(0x8009_0013, "NTE_BAD_PROVIDER", "Invalid provider specified."),
+	(0x8009_0014, "NTE_BAD_PROV_TYPE", "Invalid provider type specified."),
+	(0x8009_0015, "NTE_BAD_PUBLIC_KEY", "Provider's public key is invalid."),
+	(0x8009_0016, "NTE_BAD_KEYSET", "Keyset does not exist."),
+	(0x8009_0017, "NTE_PROV_TYPE_NOT_DEF", "Provider type not defined."),
+	(0x8009_0018, "NTE_PROV_TYPE_ENTRY_BAD", "Provider type as registered is invalid."),
+	(0x8009_0019, "NTE_KEYSET_NOT_DEF", "The keyset is not defined."),
+	(0x8009_001a, "NTE_KEYSET_ENTRY_BAD", "Keyset as registered is invalid."),
+	(0x8009_001b, "NTE_PROV_TYPE_NO_MATCH", "Provider type does not match registered value."),
+	(0x8009_001c, "NTE_SIGNATURE_FILE_BAD", "The digital signature file is corrupt."),
+	(0x8009_001d, "NTE_PROVIDER_DLL_FAIL", "Provider DLL failed to initialize correctly."),
+	(0x8009_001e, "NTE_PROV_DLL_NOT_FOUND", "Provider DLL could not be found."),
+	(0x8009_001f, "NTE_BAD_KEYSET_PARAM", "The Keyset parameter is invalid."),
+	(0x8009_0020, "NTE_FAIL", "An internal error occurred."),
+	(0x8009_0021, "NTE_SYS_ERR", "A base error occurred."),
+	(0x8009_0022, "NTE_SILENT_CONTEXT", "Provider could not perform the action since the context was acquired as silent."),
+	(0x8009_0023, "NTE_TOKEN_KEYSET_STORAGE_FULL", "The security token does not have storage space available for an additional container."),
+	(0x8009_0024, "NTE_TEMPORARY_PROFILE", "The profile for the user is a temporary profile."),
+	(0x8009_0025, "NTE_FIXEDPARAMETER", "The key parameters could not be set because the CSP uses fixed parameters."),
+	(0x8009_0026, "NTE_INVALID_HANDLE", "The supplied handle is invalid."),
+	(0x8009_0027, "NTE_INVALID_PARAMETER", "The parameter is incorrect."),
+	(0x8009_0028, "NTE_BUFFER_TOO_SMALL", "The buffer supplied to a function was too small."),
+	(0x8009_0029, "NTE_NOT_SUPPORTED", "The requested operation is not supported."),
+	(0x8009_002a, "NTE_NO_MORE_ITEMS", "No more data is available."),
+	(0x8009_002b, "NTE_BUFFERS_OVERLAP", "The supplied buffers overlap incorrectly."),
+	(0x8009_002c, "NTE_DECRYPTION_FAILURE", "The specified data could not be decrypted."),
+	(0x8009_002d, "NTE_INTERNAL_ERROR", "An internal consistency check failed."),
+	(0x8009_002e, "NTE_UI_REQUIRED", "This operation requires input from the user."),
+	(0x8009_002f, "NTE_HMAC_NOT_SUPPORTED", "The cryptographic provider does not support HMAC."),
+	(0x8009_0030, "NTE_DEVICE_NOT_READY", "The device that is required by this cryptographic provider is not ready for use."),
+	(0x8009_0031, "NTE_AUTHENTICATION_IGNORED", "The dictionary attack mitigation is triggered and the provided authorization was ignored by the provider."),
+	(0x8009_0032, "NTE_VALIDATION_FAILED", "The validation of the provided data failed the integrity or signature validation."),
+	(0x8009_0033, "NTE_INCORRECT_PASSWORD", "Incorrect password."),
+	(0x8009_0034, "NTE_ENCRYPTION_FAILURE", "Encryption failed."),
+	(0x8009_0035, "NTE_DEVICE_NOT_FOUND", "The device that is required by this cryptographic provider is not found on this platform."),
+	(0x8009_0036, "NTE_USER_CANCELLED", "The action was cancelled by the user."),
+	(0x8009_0037, "NTE_PASSWORD_CHANGE_REQUIRED", "The password is no longer valid and must be changed."),
+	(0x8009_0038, "NTE_NOT_ACTIVE_CONSOLE", "The operation cannot be completed from Terminal Server client sessions."),
+	(0x8009_0300, "SEC_E_INSUFFICIENT_MEMORY", "Not enough memory is available to complete this request."),
+	(0x8009_0301, "SEC_E_INVALID_HANDLE", "The handle specified is invalid."),
+	(0x8009_0302, "SEC_E_UNSUPPORTED_FUNCTION", "The function requested is not supported."),
+	(0x8009_0303, "SEC_E_TARGET_UNKNOWN", "The specified target is unknown or unreachable."),
+	(0x8009_0304, "SEC_E_INTERNAL_ERROR", "The Local Security Authority cannot be contacted."),
+	(0x8009_0305, "SEC_E_SECPKG_NOT_FOUND", "The requested security package does not exist."),
+	(0x8009_0306, "SEC_E_NOT_OWNER", "The caller is not the owner of the desired credentials."),
+	(0x8009_0307, "SEC_E_CANNOT_INSTALL", "The security package failed to initialize, and cannot be installed."),
+	(0x8009_0308, "SEC_E_INVALID_TOKEN", "The token supplied to the function is invalid."),
+	(0x8009_0309, "SEC_E_CANNOT_PACK", "The security package is not able to marshall the logon buffer, so the logon attempt has failed."),
+	(0x8009_030a, "SEC_E_QOP_NOT_SUPPORTED", "The per-message Quality of Protection is not supported by the security package."),
+	(0x8009_030b, "SEC_E_NO_IMPERSONATION", "The security context does not allow impersonation of the client."),
+	(0x8009_030c, "SEC_E_LOGON_DENIED", "The logon attempt failed."),
+	(0x8009_030d, "SEC_E_UNKNOWN_CREDENTIALS", "The credentials supplied to the package were not recognized."),
+	(0x8009_030e, "SEC_E_NO_CREDENTIALS", "No credentials are available in the security package."),
+	(0x8009_030f, "SEC_E_MESSAGE_ALTERED", "The message or signature supplied for verification has been altered."),
+	(0x8009_0310, "SEC_E_OUT_OF_SEQUENCE", "The message supplied for verification is out of sequence."),
+	(0x8009_0311, "SEC_E_NO_AUTHENTICATING_AUTHORITY", "No authority could be contacted for authentication."),
+	(0x8009_0316, "SEC_E_BAD_PKGID", "The requested security package does not exist."),
+	(0x8009_0317, "SEC_E_CONTEXT_EXPIRED", "The context has expired and can no longer be used."),
+	(0x8009_0318, "SEC_E_INCOMPLETE_MESSAGE", "The supplied message is incomplete. The signature was not verified."),
+	(0x8009_0320, "SEC_E_INCOMPLETE_CREDENTIALS", "The credentials supplied were not complete, and could not be verified. The context could not be initialized."),
+	(0x8009_0321, "SEC_E_BUFFER_TOO_SMALL", "The buffers supplied to a function was too small."),
+	(0x8009_0322, "SEC_E_WRONG_PRINCIPAL", "The target principal name is incorrect."),
+	(0x8009_0324, "SEC_E_TIME_SKEW", "The clocks on the client and server machines are skewed."),
+	(0x8009_0325, "SEC_E_UNTRUSTED_ROOT", "The certificate chain was issued by an authority that is not trusted."),
+	(0x8009_0326, "SEC_E_ILLEGAL_MESSAGE", "The message received was unexpected or badly formatted."),
+	(0x8009_0327, "SEC_E_CERT_UNKNOWN", "An unknown error occurred while processing the certificate."),
+	(0x8009_0328, "SEC_E_CERT_EXPIRED", "The received certificate has expired."),
+	(0x8009_0329, "SEC_E_ENCRYPT_FAILURE", "The specified data could not be encrypted."),
+	(0x8009_0330, "SEC_E_DECRYPT_FAILURE", "The specified data could not be decrypted."),
+	(0x8009_0331, "SEC_E_ALGORITHM_MISMATCH", "The client and server cannot communicate, because they do not possess a common algorithm."),
+	(0x8009_0332, "SEC_E_SECURITY_QOS_FAILED", "The security context could not be established due to a failure in the requested quality of service (e.g. mutual authentication or delegation)."),
+	(0x8009_0333, "SEC_E_UNFINISHED_CONTEXT_DELETED", "A security context was deleted before the context was completed. This is considered a logon failure."),
+	(0x8009_0334, "SEC_E_NO_TGT_REPLY", "The client is trying to negotiate a context and the server requires user-to-user but didn't send a TGT reply."),
+	(0x8009_0335, "SEC_E_NO_IP_ADDRESSES", "Unable to accomplish the requested task because the local machine does not have any IP addresses."),
+	(0x8009_0336, "SEC_E_WRONG_CREDENTIAL_HANDLE", "The supplied credential handle does not match the credential associated with the security context."),
+	(0x8009_0337, "SEC_E_CRYPTO_SYSTEM_INVALID", "The crypto system or checksum function is invalid because a required function is unavailable."),
+	(0x8009_0338, "SEC_E_MAX_REFERRALS_EXCEEDED", "The number of maximum ticket referrals has been exceeded."),
+	(0x8009_0339, "SEC_E_MUST_BE_KDC", "The local machine must be a Kerberos KDC (domain controller) and it is not."),
+	(0x8009_033a, "SEC_E_STRONG_CRYPTO_NOT_SUPPORTED", "The other end of the security negotiation is requires strong crypto but it is not supported on the local machine."),
+	(0x8009_033b, "SEC_E_TOO_MANY_PRINCIPALS", "The KDC reply contained more than one principal name."),
+	(0x8009_033c, "SEC_E_NO_PA_DATA", "Expected to find PA data for a hint of what etype to use, but it was not found."),
+	(0x8009_033d, "SEC_E_PKINIT_NAME_MISMATCH", "The client certificate does not contain a valid UPN, or does not match the client name in the logon request. Please contact your administrator."),
+	(0x8009_033e, "SEC_E_SMARTCARD_LOGON_REQUIRED", "Smartcard logon is required and was not used."),
+	(0x8009_033f, "SEC_E_SHUTDOWN_IN_PROGRESS", "A system shutdown is in progress."),
+	(0x8009_0340, "SEC_E_KDC_INVALID_REQUEST", "An invalid request was sent to the KDC."),
+	(0x8009_0341, "SEC_E_KDC_UNABLE_TO_REFER", "The KDC was unable to generate a referral for the service requested."),
+	(0x8009_0342, "SEC_E_KDC_UNKNOWN_ETYPE", "The encryption type requested is not supported by the KDC."),
+	(0x8009_0343, "SEC_E_UNSUPPORTED_PREAUTH", "An unsupported preauthentication mechanism was presented to the Kerberos package."),
+	(0x8009_0345, "SEC_E_DELEGATION_REQUIRED", "The requested operation cannot be completed. The computer must be trusted for delegation and the current user account must be configured to allow delegation."),
+	(0x8009_0346, "SEC_E_BAD_BINDINGS", "Client's supplied SSPI channel bindings were incorrect."),
+	(0x8009_0347, "SEC_E_MULTIPLE_ACCOUNTS", "The received certificate was mapped to multiple accounts."),
+	(0x8009_0348, "SEC_E_NO_KERB_KEY", "No Kerberos key was found."),
+	(0x8009_0349, "SEC_E_CERT_WRONG_USAGE", "The certificate is not valid for the requested usage."),
+	(0x8009_0350, "SEC_E_DOWNGRADE_DETECTED", "The system cannot contact a domain controller to service the authentication request. Please try again later."),
+	(0x8009_0351, "SEC_E_SMARTCARD_CERT_REVOKED", "The smartcard certificate used for authentication has been revoked. Please contact your system administrator."),
+	(0x8009_0352, "SEC_E_ISSUING_CA_UNTRUSTED", "An untrusted certificate authority was detected while processing the smartcard certificate used for authentication. Please contact your system administrator."),
+	(0x8009_0353, "SEC_E_REVOCATION_OFFLINE_C", "The revocation status of the smartcard certificate used for authentication could not be determined. Please contact your system administrator."),
+	(0x8009_0354, "SEC_E_PKINIT_CLIENT_FAILURE", "The smartcard certificate used for authentication was not trusted. Please contact your system administrator."),
+	(0x8009_0355, "SEC_E_SMARTCARD_CERT_EXPIRED", "The smartcard certificate used for authentication has expired. Please contact your system administrator."),
+	(0x8009_0356, "SEC_E_NO_S4U_PROT_SUPPORT", "The Kerberos subsystem encountered an error. A service for user protocol request was made against a domain controller which does not support service for user."),
+	(0x8009_0357, "SEC_E_CROSSREALM_DELEGATION_FAILURE", "An attempt was made by this server to make a Kerberos constrained delegation request for a target outside of the server's realm. This is not supported."),
+	(0x8009_0358, "SEC_E_REVOCATION_OFFLINE_KDC", "The revocation status of the domain controller certificate used for smartcard authentication could not be determined."),
+	(0x8009_0359, "SEC_E_ISSUING_CA_UNTRUSTED_KDC", "An untrusted certificate authority was detected while processing the domain controller certificate used for authentication."),
+	(0x8009_035a, "SEC_E_KDC_CERT_EXPIRED", "The domain controller certificate used for smartcard logon has expired."),
+	(0x8009_035b, "SEC_E_KDC_CERT_REVOKED", "The domain controller certificate used for smartcard logon has been revoked."),
+	(0x8009_035d, "SEC_E_INVALID_PARAMETER", "One or more of the parameters passed to the function was invalid."),
+	(0x8009_035e, "SEC_E_DELEGATION_POLICY", "Client policy does not allow credential delegation to target server."),
+	(0x8009_035f, "SEC_E_POLICY_NLTM_ONLY", "Client policy does not allow credential delegation to target server with NLTM only authentication."),
+	(0x8009_0361, "SEC_E_NO_CONTEXT", "The required security context does not exist."),
+	(0x8009_0362, "SEC_E_PKU2U_CERT_FAILURE", "The PKU2U protocol encountered an error while attempting to utilize the associated certificates."),
+	(0x8009_0363, "SEC_E_MUTUAL_AUTH_FAILED", "The identity of the server computer could not be verified."),
+	(0x8009_0365, "SEC_E_ONLY_HTTPS_ALLOWED", "Only https scheme is allowed."),
+	(0x8009_0367, "SEC_E_APPLICATION_PROTOCOL_MISMATCH", "No common application protocol exists between the client and the server. Application protocol negotiation failed."),
+	(0x8009_0369, "SEC_E_INVALID_UPN_NAME", "You can't sign in with a user ID in this format. Try using your email address instead."),
+	(0x8009_036a, "SEC_E_EXT_BUFFER_TOO_SMALL", "The buffer supplied by the SSPI caller to receive generic extensions is too small."),
+	(0x8009_036b, "SEC_E_INSUFFICIENT_BUFFERS", "Not enough secbuffers were supplied to generate a token."),
+	(0x8009_1001, "CRYPT_E_MSG_ERROR", "An error occurred while performing an operation on a cryptographic message."),
+	(0x8009_1002, "CRYPT_E_UNKNOWN_ALGO", "Unknown cryptographic algorithm."),
+	(0x8009_1003, "CRYPT_E_OID_FORMAT", "The object identifier is poorly formatted."),
+	(0x8009_1004, "CRYPT_E_INVALID_MSG_TYPE", "Invalid cryptographic message type."),
+	(0x8009_1005, "CRYPT_E_UNEXPECTED_ENCODING", "Unexpected cryptographic message encoding."),
+	(0x8009_1006, "CRYPT_E_AUTH_ATTR_MISSING", "The cryptographic message does not contain an expected authenticated attribute."),
+	(0x8009_1007, "CRYPT_E_HASH_VALUE", "The hash value is not correct."),
+	(0x8009_1008, "CRYPT_E_INVALID_INDEX", "The index value is not valid."),
+	(0x8009_1009, "CRYPT_E_ALREADY_DECRYPTED", "The content of the cryptographic message has already been decrypted."),
+	(0x8009_100a, "CRYPT_E_NOT_DECRYPTED", "The content of the cryptographic message has not been decrypted yet."),
+	(0x8009_100b, "CRYPT_E_RECIPIENT_NOT_FOUND", "The enveloped-data message does not contain the specified recipient."),
+	(0x8009_100c, "CRYPT_E_CONTROL_TYPE", "Invalid control type."),
+	(0x8009_100d, "CRYPT_E_ISSUER_SERIALNUMBER", "Invalid issuer and/or serial number."),
+	(0x8009_100e, "CRYPT_E_SIGNER_NOT_FOUND", "Cannot find the original signer."),
+	(0x8009_100f, "CRYPT_E_ATTRIBUTES_MISSING", "The cryptographic message does not contain all of the requested attributes."),
+	(0x8009_1010, "CRYPT_E_STREAM_MSG_NOT_READY", "The streamed cryptographic message is not ready to return data."),
+	(0x8009_1011, "CRYPT_E_STREAM_INSUFFICIENT_DATA", "The streamed cryptographic message requires more data to complete the decode operation."),
+	(0x8009_2001, "CRYPT_E_BAD_LEN", "The length specified for the output data was insufficient."),
+	(0x8009_2002, "CRYPT_E_BAD_ENCODE", "An error occurred during encode or decode operation."),
+	(0x8009_2003, "CRYPT_E_FILE_ERROR", "An error occurred while reading or writing to a file."),
+	(0x8009_2004, "CRYPT_E_NOT_FOUND", "Cannot find object or property."),
+	(0x8009_2005, "CRYPT_E_EXISTS", "The object or property already exists."),
+	(0x8009_2006, "CRYPT_E_NO_PROVIDER", "No provider was specified for the store or object."),
+	(0x8009_2007, "CRYPT_E_SELF_SIGNED", "The specified certificate is self signed."),
+	(0x8009_2008, "CRYPT_E_DELETED_PREV", "The previous certificate or CRL context was deleted."),
+	(0x8009_2009, "CRYPT_E_NO_MATCH", "Cannot find the requested object."),
+	(0x8009_200a, "CRYPT_E_UNEXPECTED_MSG_TYPE", "The certificate does not have a property that references a private key."),
+	(0x8009_200b, "CRYPT_E_NO_KEY_PROPERTY", "Cannot find the certificate and private key for decryption."),
+	(0x8009_200c, "CRYPT_E_NO_DECRYPT_CERT", "Cannot find the certificate and private key to use for decryption."),
+	(0x8009_200d, "CRYPT_E_BAD_MSG", "Not a cryptographic message or the cryptographic message is not formatted correctly."),
+	(0x8009_200e, "CRYPT_E_NO_SIGNER", "The signed cryptographic message does not have a signer for the specified signer index."),
+	(0x8009_200f, "CRYPT_E_PENDING_CLOSE", "Final closure is pending until additional frees or closes."),
+	(0x8009_2010, "CRYPT_E_REVOKED", "The certificate is revoked."),
+	(0x8009_2011, "CRYPT_E_NO_REVOCATION_DLL", "No Dll or exported function was found to verify revocation."),
+	(0x8009_2012, "CRYPT_E_NO_REVOCATION_CHECK", "The revocation function was unable to check revocation for the certificate."),
+	(0x8009_2013, "CRYPT_E_REVOCATION_OFFLINE", "The revocation function was unable to check revocation because the revocation server was offline."),
+	(0x8009_2014, "CRYPT_E_NOT_IN_REVOCATION_DATABASE", "The certificate is not in the revocation server's database."),
+	(0x8009_2020, "CRYPT_E_INVALID_NUMERIC_STRING", "The string contains a non-numeric character."),
+	(0x8009_2021, "CRYPT_E_INVALID_PRINTABLE_STRING", "The string contains a non-printable character."),
+	(0x8009_2022, "CRYPT_E_INVALID_IA5_STRING", "The string contains a character not in the 7 bit ASCII character set."),
+	(0x8009_2023, "CRYPT_E_INVALID_X500_STRING", "The string contains an invalid X500 name attribute key, oid, value or delimiter."),
+	(0x8009_2024, "CRYPT_E_NOT_CHAR_STRING", "The dwValueType for the CERT_NAME_VALUE is not one of the character strings. Most likely it is either a CERT_RDN_ENCODED_BLOB or CERT_RDN_OCTET_STRING."),
+	(0x8009_2025, "CRYPT_E_FILERESIZED", "The Put operation cannot continue. The file needs to be resized. However, there is already a signature present. A complete signing operation must be done."),
+	(0x8009_2026, "CRYPT_E_SECURITY_SETTINGS", "The cryptographic operation failed due to a local security option setting."),
+	(0x8009_2027, "CRYPT_E_NO_VERIFY_USAGE_DLL", "No DLL or exported function was found to verify subject usage."),
+	(0x8009_2028, "CRYPT_E_NO_VERIFY_USAGE_CHECK", "The called function was unable to do a usage check on the subject."),
+	(0x8009_2029, "CRYPT_E_VERIFY_USAGE_OFFLINE", "Since the server was offline, the called function was unable to complete the usage check."),
+	(0x8009_202a, "CRYPT_E_NOT_IN_CTL", "The subject was not found in a Certificate Trust List (CTL)."),
+	(0x8009_202b, "CRYPT_E_NO_TRUSTED_SIGNER", "None of the signers of the cryptographic message or certificate trust list is trusted."),
+	(0x8009_202c, "CRYPT_E_MISSING_PUBKEY_PARA", "The public key's algorithm parameters are missing."),
+	(0x8009_202d, "CRYPT_E_OBJECT_LOCATOR_OBJECT_NOT_FOUND", "An object could not be located using the object locator infrastructure with the given name."),
+	(0x8009_3000, "CRYPT_E_OSS_ERROR", "OSS Certificate encode/decode error code base."),
+	(0x8009_3001, "OSS_MORE_BUF", "OSS ASN.1 Error: Output Buffer is too small."),
+	(0x8009_3002, "OSS_NEGATIVE_UINTEGER", "OSS ASN.1 Error: Signed integer is encoded as a unsigned integer."),
+	(0x8009_3003, "OSS_PDU_RANGE", "OSS ASN.1 Error: Unknown ASN.1 data type."),
+	(0x8009_3004, "OSS_MORE_INPUT", "OSS ASN.1 Error: Output buffer is too small, the decoded data has been truncated."),
+	(0x8009_3005, "OSS_DATA_ERROR", "OSS ASN.1 Error: Invalid data."),
+	(0x8009_3006, "OSS_BAD_ARG", "OSS ASN.1 Error: Invalid argument."),
+	(0x8009_3007, "OSS_BAD_VERSION", "OSS ASN.1 Error: Encode/Decode version mismatch."),
+	(0x8009_3008, "OSS_OUT_MEMORY", "OSS ASN.1 Error: Out of memory."),
+	(0x8009_3009, "OSS_PDU_MISMATCH", "OSS ASN.1 Error: Encode/Decode Error."),
+	(0x8009_300a, "OSS_LIMITED", "OSS ASN.1 Error: Internal Error."),
+	(0x8009_300b, "OSS_BAD_PTR", "OSS ASN.1 Error: Invalid data."),
+	(0x8009_300c, "OSS_BAD_TIME", "OSS ASN.1 Error: Invalid data."),
+	(0x8009_300d, "OSS_INDEFINITE_NOT_SUPPORTED", "OSS ASN.1 Error: Unsupported BER indefinite-length encoding."),
+	(0x8009_300e, "OSS_MEM_ERROR", "OSS ASN.1 Error: Access violation."),
+	(0x8009_300f, "OSS_BAD_TABLE", "OSS ASN.1 Error: Invalid data."),
+	(0x8009_3010, "OSS_TOO_LONG", "OSS ASN.1 Error: Invalid data."),
+	(0x8009_3011, "OSS_CONSTRAINT_VIOLATED", "OSS ASN.1 Error: Invalid data."),
+	(0x8009_3012, "OSS_FATAL_ERROR", "OSS ASN.1 Error: Internal Error."),
+	(0x8009_3013, "OSS_ACCESS_SERIALIZATION_ERROR", "OSS ASN.1 Error: Multi-threading conflict."),
+	(0x8009_3014, "OSS_NULL_TBL", "OSS ASN.1 Error: Invalid data."),
+	(0x8009_3015, "OSS_NULL_FCN", "OSS ASN.1 Error: Invalid data."),
+	(0x8009_3016, "OSS_BAD_ENCRULES", "OSS ASN.1 Error: Invalid data."),
+	(0x8009_3017, "OSS_UNAVAIL_ENCRULES", "OSS ASN.1 Error: Encode/Decode function not implemented."),
+	(0x8009_3018, "OSS_CANT_OPEN_TRACE_WINDOW", "OSS ASN.1 Error: Trace file error."),
+	(0x8009_3019, "OSS_UNIMPLEMENTED", "OSS ASN.1 Error: Function not implemented."),
+	(0x8009_301a, "OSS_OID_DLL_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x8009_301b, "OSS_CANT_OPEN_TRACE_FILE", "OSS ASN.1 Error: Trace file error."),
+	(0x8009_301c, "OSS_TRACE_FILE_ALREADY_OPEN", "OSS ASN.1 Error: Trace file error."),
+	(0x8009_301d, "OSS_TABLE_MISMATCH", "OSS ASN.1 Error: Invalid data."),
+	(0x8009_301e, "OSS_TYPE_NOT_SUPPORTED", "OSS ASN.1 Error: Invalid data."),
+	(0x8009_301f, "OSS_REAL_DLL_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x8009_3020, "OSS_REAL_CODE_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x8009_3021, "OSS_OUT_OF_RANGE", "OSS ASN.1 Error: Program link error."),
+	(0x8009_3022, "OSS_COPIER_DLL_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x8009_3023, "OSS_CONSTRAINT_DLL_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x8009_3024, "OSS_COMPARATOR_DLL_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x8009_3025, "OSS_COMPARATOR_CODE_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x8009_3026, "OSS_MEM_MGR_DLL_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x8009_3027, "OSS_PDV_DLL_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x8009_3028, "OSS_PDV_CODE_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x8009_3029, "OSS_API_DLL_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x8009_302a, "OSS_BERDER_DLL_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x8009_302b, "OSS_PER_DLL_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x8009_302c, "OSS_OPEN_TYPE_ERROR", "OSS ASN.1 Error: Program link error."),
+	(0x8009_302d, "OSS_MUTEX_NOT_CREATED", "OSS ASN.1 Error: System resource error."),
+	(0x8009_302e, "OSS_CANT_CLOSE_TRACE_FILE", "OSS ASN.1 Error: Trace file error."),
+	(0x8009_3100, "CRYPT_E_ASN1_ERROR", "ASN1 Certificate encode/decode error code base."),
+	(0x8009_3101, "CRYPT_E_ASN1_INTERNAL", "ASN1 internal encode or decode error."),
+	(0x8009_3102, "CRYPT_E_ASN1_EOD", "ASN1 unexpected end of data."),
+	(0x8009_3103, "CRYPT_E_ASN1_CORRUPT", "ASN1 corrupted data."),
+	(0x8009_3104, "CRYPT_E_ASN1_LARGE", "ASN1 value too large."),
+	(0x8009_3105, "CRYPT_E_ASN1_CONSTRAINT", "ASN1 constraint violated."),
+	(0x8009_3106, "CRYPT_E_ASN1_MEMORY", "ASN1 out of memory."),
+	(0x8009_3107, "CRYPT_E_ASN1_OVERFLOW", "ASN1 buffer overflow."),
+	(0x8009_3108, "CRYPT_E_ASN1_BADPDU", "ASN1 function not supported for this PDU."),
+	(0x8009_3109, "CRYPT_E_ASN1_BADARGS", "ASN1 bad arguments to function call."),
+	(0x8009_310a, "CRYPT_E_ASN1_BADREAL", "ASN1 bad real value."),
+	(0x8009_310b, "CRYPT_E_ASN1_BADTAG", "ASN1 bad tag value met."),
+	(0x8009_310c, "CRYPT_E_ASN1_CHOICE", "ASN1 bad choice value."),
+	(0x8009_310d, "CRYPT_E_ASN1_RULE", "ASN1 bad encoding rule."),
+	(0x8009_310e, "CRYPT_E_ASN1_UTF8", "ASN1 bad unicode (UTF8)."),
+	(0x8009_3133, "CRYPT_E_ASN1_PDU_TYPE", "ASN1 bad PDU type."),
+	(0x8009_3134, "CRYPT_E_ASN1_NYI", "ASN1 not yet implemented."),
+	(0x8009_3201, "CRYPT_E_ASN1_EXTENDED", "ASN1 skipped unknown extension(s)."),
+	(0x8009_3202, "CRYPT_E_ASN1_NOEOD", "ASN1 end of data expected."),
+	(0x8009_4001, "CERTSRV_E_BAD_REQUESTSUBJECT", "The request subject name is invalid or too long."),
+	(0x8009_4002, "CERTSRV_E_NO_REQUEST", "The request does not exist."),
+	(0x8009_4003, "CERTSRV_E_BAD_REQUESTSTATUS", "The request's current status does not allow this operation."),
+	(0x8009_4004, "CERTSRV_E_PROPERTY_EMPTY", "The requested property value is empty."),
+	(0x8009_4005, "CERTSRV_E_INVALID_CA_CERTIFICATE", "The certification authority's certificate contains invalid data."),
+	(0x8009_4006, "CERTSRV_E_SERVER_SUSPENDED", "Certificate service has been suspended for a database restore operation."),
+	(0x8009_4007, "CERTSRV_E_ENCODING_LENGTH", "The certificate contains an encoded length that is potentially incompatible with older enrollment software."),
+	(0x8009_4008, "CERTSRV_E_ROLECONFLICT", "The operation is denied. The user has multiple roles assigned and the certification authority is configured to enforce role separation."),
+	(0x8009_4009, "CERTSRV_E_RESTRICTEDOFFICER", "The operation is denied. It can only be performed by a certificate manager that is allowed to manage certificates for the current requester."),
+	(0x8009_400a, "CERTSRV_E_KEY_ARCHIVAL_NOT_CONFIGURED", "Cannot archive private key. The certification authority is not configured for key archival."),
+	(0x8009_400b, "CERTSRV_E_NO_VALID_KRA", "Cannot archive private key. The certification authority could not verify one or more key recovery certificates."),
+	(0x8009_400c, "CERTSRV_E_BAD_REQUEST_KEY_ARCHIVAL", "The request is incorrectly formatted. The encrypted private key must be in an unauthenticated attribute in an outermost signature."),
+	(0x8009_400d, "CERTSRV_E_NO_CAADMIN_DEFINED", "At least one security principal must have the permission to manage this CA."),
+	(0x8009_400e, "CERTSRV_E_BAD_RENEWAL_CERT_ATTRIBUTE", "The request contains an invalid renewal certificate attribute."),
+	(0x8009_400f, "CERTSRV_E_NO_DB_SESSIONS", "An attempt was made to open a Certification Authority database session, but there are already too many active sessions. The server may need to be configured to allow additional sessions."),
+	(0x8009_4010, "CERTSRV_E_ALIGNMENT_FAULT", "A memory reference caused a data alignment fault."),
+	(0x8009_4011, "CERTSRV_E_ENROLL_DENIED", "The permissions on this certification authority do not allow the current user to enroll for certificates."),
+	(0x8009_4012, "CERTSRV_E_TEMPLATE_DENIED", "The permissions on the certificate template do not allow the current user to enroll for this type of certificate."),
+	(0x8009_4013, "CERTSRV_E_DOWNLEVEL_DC_SSL_OR_UPGRADE", "The contacted domain controller cannot support signed LDAP traffic. Update the domain controller or configure Certificate Services to use SSL for Active Directory access."),
+	(0x8009_4014, "CERTSRV_E_ADMIN_DENIED_REQUEST", "The request was denied by a certificate manager or CA administrator."),
+	(0x8009_4015, "CERTSRV_E_NO_POLICY_SERVER", "An enrollment policy server cannot be located."),
+	(0x8009_4016, "CERTSRV_E_WEAK_SIGNATURE_OR_KEY", "A signature algorithm or public key length does not meet the system's minimum required strength."),
+	(0x8009_4017, "CERTSRV_E_KEY_ATTESTATION_NOT_SUPPORTED", "Failed to create an attested key. This computer or the cryptographic provider may not meet the hardware requirements to support key attestation."),
+	(0x8009_4018, "CERTSRV_E_ENCRYPTION_CERT_REQUIRED", "No encryption certificate was specified."),
+	(0x8009_4800, "CERTSRV_E_UNSUPPORTED_CERT_TYPE", "The requested certificate template is not supported by this CA."),
+	(0x8009_4801, "CERTSRV_E_NO_CERT_TYPE", "The request contains no certificate template information."),
+	(0x8009_4802, "CERTSRV_E_TEMPLATE_CONFLICT", "The request contains conflicting template information."),
+	(0x8009_4803, "CERTSRV_E_SUBJECT_ALT_NAME_REQUIRED", "The request is missing a required Subject Alternate name extension."),
+	(0x8009_4804, "CERTSRV_E_ARCHIVED_KEY_REQUIRED", "The request is missing a required private key for archival by the server."),
+	(0x8009_4805, "CERTSRV_E_SMIME_REQUIRED", "The request is missing a required SMIME capabilities extension."),
+	(0x8009_4806, "CERTSRV_E_BAD_RENEWAL_SUBJECT", "The request was made on behalf of a subject other than the caller. The certificate template must be configured to require at least one signature to authorize the request."),
+	(0x8009_4807, "CERTSRV_E_BAD_TEMPLATE_VERSION", "The request template version is newer than the supported template version."),
+	(0x8009_4808, "CERTSRV_E_TEMPLATE_POLICY_REQUIRED", "The template is missing a required signature policy attribute."),
+	(0x8009_4809, "CERTSRV_E_SIGNATURE_POLICY_REQUIRED", "The request is missing required signature policy information."),
+	(0x8009_480a, "CERTSRV_E_SIGNATURE_COUNT", "The request is missing one or more required signatures."),
+	(0x8009_480b, "CERTSRV_E_SIGNATURE_REJECTED", "One or more signatures did not include the required application or issuance policies. The request is missing one or more required valid signatures."),
+	(0x8009_480c, "CERTSRV_E_ISSUANCE_POLICY_REQUIRED", "The request is missing one or more required signature issuance policies."),
+	(0x8009_480d, "CERTSRV_E_SUBJECT_UPN_REQUIRED", "The UPN is unavailable and cannot be added to the Subject Alternate name."),
+	(0x8009_480e, "CERTSRV_E_SUBJECT_DIRECTORY_GUID_REQUIRED", "The Active Directory GUID is unavailable and cannot be added to the Subject Alternate name."),
+	(0x8009_480f, "CERTSRV_E_SUBJECT_DNS_REQUIRED", "The DNS name is unavailable and cannot be added to the Subject Alternate name."),
+	(0x8009_4810, "CERTSRV_E_ARCHIVED_KEY_UNEXPECTED", "The request includes a private key for archival by the server, but key archival is not enabled for the specified certificate template."),
+	(0x8009_4811, "CERTSRV_E_KEY_LENGTH", "The public key does not meet the minimum size required by the specified certificate template."),
+	(0x8009_4812, "CERTSRV_E_SUBJECT_EMAIL_REQUIRED", "The EMail name is unavailable and cannot be added to the Subject or Subject Alternate name."),
+	(0x8009_4813, "CERTSRV_E_UNKNOWN_CERT_TYPE", "One or more certificate templates to be enabled on this certification authority could not be found."),
+	(0x8009_4814, "CERTSRV_E_CERT_TYPE_OVERLAP", "The certificate template renewal period is longer than the certificate validity period. The template should be reconfigured or the CA certificate renewed."),
+	(0x8009_4815, "CERTSRV_E_TOO_MANY_SIGNATURES", "The certificate template requires too many RA signatures. Only one RA signature is allowed."),
+	(0x8009_4816, "CERTSRV_E_RENEWAL_BAD_PUBLIC_KEY", "The certificate template requires renewal with the same public key, but the request uses a different public key."),
+	(0x8009_4817, "CERTSRV_E_INVALID_EK", "The certification authority cannot interpret or verify the endorsement key information supplied in the request, or the information is inconsistent."),
+	(0x8009_4818, "CERTSRV_E_INVALID_IDBINDING", "The certification authority cannot validate the Attestation Identity Key Id Binding."),
+	(0x8009_4819, "CERTSRV_E_INVALID_ATTESTATION", "The certification authority cannot validate the private key attestation data."),
+	(0x8009_481a, "CERTSRV_E_KEY_ATTESTATION", "The request does not support private key attestation as defined in the certificate template."),
+	(0x8009_481b, "CERTSRV_E_CORRUPT_KEY_ATTESTATION", "The request public key is not consistent with the private key attestation data."),
+	(0x8009_481c, "CERTSRV_E_EXPIRED_CHALLENGE", "The private key attestation challenge cannot be validated because the encryption certificate has expired, or the certificate or key is unavailable."),
+	(0x8009_481d, "CERTSRV_E_INVALID_RESPONSE", "The attestation response could not be validated. It is either unexpected or incorrect."),
+	(0x8009_481e, "CERTSRV_E_INVALID_REQUESTID", "A valid Request ID was not detected in the request attributes, or an invalid one was submitted."),
+	(0x8009_481f, "CERTSRV_E_REQUEST_PRECERTIFICATE_MISMATCH", "The request is not consistent with the previously generated precertificate."),
+	(0x8009_4820, "CERTSRV_E_PENDING_CLIENT_RESPONSE", "The request is locked by another request with the same ID, pending a client response."),
+	(0x8009_5000, "XENROLL_E_KEY_NOT_EXPORTABLE", "The key is not exportable."),
+	(0x8009_5001, "XENROLL_E_CANNOT_ADD_ROOT_CERT", "You cannot add the root CA certificate into your local store."),
+	(0x8009_5002, "XENROLL_E_RESPONSE_KA_HASH_NOT_FOUND", "The key archival hash attribute was not found in the response."),
+	(0x8009_5003, "XENROLL_E_RESPONSE_UNEXPECTED_KA_HASH", "An unexpected key archival hash attribute was found in the response."),
+	(0x8009_5004, "XENROLL_E_RESPONSE_KA_HASH_MISMATCH", "There is a key archival hash mismatch between the request and the response."),
+	(0x8009_5005, "XENROLL_E_KEYSPEC_SMIME_MISMATCH", "Signing certificate cannot include SMIME extension."),
+	(0x8009_6001, "TRUST_E_SYSTEM_ERROR", "A system-level error occurred while verifying trust."),
+	(0x8009_6002, "TRUST_E_NO_SIGNER_CERT", "The certificate for the signer of the message is invalid or not found."),
+	(0x8009_6003, "TRUST_E_COUNTER_SIGNER", "One of the counter signatures was invalid."),
+	(0x8009_6004, "TRUST_E_CERT_SIGNATURE", "The signature of the certificate cannot be verified."),
+	(0x8009_6005, "TRUST_E_TIME_STAMP", "The timestamp signature and/or certificate could not be verified or is malformed."),
+	(0x8009_6010, "TRUST_E_BAD_DIGEST", "The digital signature of the object did not verify."),
+	(0x8009_6011, "TRUST_E_MALFORMED_SIGNATURE", "The digital signature of the object is malformed."),
+	(0x8009_6019, "TRUST_E_BASIC_CONSTRAINTS", "A certificate's basic constraint extension has not been observed."),
+	(0x8009_601e, "TRUST_E_FINANCIAL_CRITERIA", "The certificate does not meet or contain the Authenticode financial extensions."),
+	(0x8009_7001, "MSSIPOTF_E_OUTOFMEMRANGE", "Tried to reference a part of the file outside the proper range."),
+	(0x8009_7002, "MSSIPOTF_E_CANTGETOBJECT", "Could not retrieve an object from the file."),
+	(0x8009_7003, "MSSIPOTF_E_NOHEADTABLE", "Could not find the head table in the file."),
+	(0x8009_7004, "MSSIPOTF_E_BAD_MAGICNUMBER", "The magic number in the head table is incorrect."),
+	(0x8009_7005, "MSSIPOTF_E_BAD_OFFSET_TABLE", "The offset table has incorrect values."),
+	(0x8009_7006, "MSSIPOTF_E_TABLE_TAGORDER", "Duplicate table tags or tags out of alphabetical order."),
+	(0x8009_7007, "MSSIPOTF_E_TABLE_LONGWORD", "A table does not start on a long word boundary."),
+	(0x8009_7008, "MSSIPOTF_E_BAD_FIRST_TABLE_PLACEMENT", "First table does not appear after header information."),
+	(0x8009_7009, "MSSIPOTF_E_TABLES_OVERLAP", "Two or more tables overlap."),
+	(0x8009_700a, "MSSIPOTF_E_TABLE_PADBYTES", "Too many pad bytes between tables or pad bytes are not 0."),
+	(0x8009_700b, "MSSIPOTF_E_FILETOOSMALL", "File is too small to contain the last table."),
+	(0x8009_700c, "MSSIPOTF_E_TABLE_CHECKSUM", "A table checksum is incorrect."),
+	(0x8009_700d, "MSSIPOTF_E_FILE_CHECKSUM", "The file checksum is incorrect."),
+	(0x8009_7010, "MSSIPOTF_E_FAILED_POLICY", "The signature does not have the correct attributes for the policy."),
+	(0x8009_7011, "MSSIPOTF_E_FAILED_HINTS_CHECK", "The file did not pass the hints check."),
+	(0x8009_7012, "MSSIPOTF_E_NOT_OPENTYPE", "The file is not an OpenType file."),
+	(0x8009_7013, "MSSIPOTF_E_FILE", "Failed on a file operation (open, map, read, write)."),
+	(0x8009_7014, "MSSIPOTF_E_CRYPT", "A call to a CryptoAPI function failed."),
+	(0x8009_7015, "MSSIPOTF_E_BADVERSION", "There is a bad version number in the file."),
+	(0x8009_7016, "MSSIPOTF_E_DSIG_STRUCTURE", "The structure of the DSIG table is incorrect."),
+	(0x8009_7017, "MSSIPOTF_E_PCONST_CHECK", "A check failed in a partially constant table."),
+	(0x8009_7018, "MSSIPOTF_E_STRUCTURE", "Some kind of structural error."),
+	(0x8009_7019, "ERROR_CRED_REQUIRES_CONFIRMATION", "The requested credential requires confirmation."),
+	(0x800b_0001, "TRUST_E_PROVIDER_UNKNOWN", "Unknown trust provider."),
+	(0x800b_0002, "TRUST_E_ACTION_UNKNOWN", "The trust verification action specified is not supported by the specified trust provider."),
+	(0x800b_0003, "TRUST_E_SUBJECT_FORM_UNKNOWN", "The form specified for the subject is not one supported or known by the specified trust provider."),
+	(0x800b_0004, "TRUST_E_SUBJECT_NOT_TRUSTED", "The subject is not trusted for the specified action."),
+	(0x800b_0005, "DIGSIG_E_ENCODE", "Error due to problem in ASN.1 encoding process."),
+	(0x800b_0006, "DIGSIG_E_DECODE", "Error due to problem in ASN.1 decoding process."),
+	(0x800b_0007, "DIGSIG_E_EXTENSIBILITY", "Reading / writing Extensions where Attributes are appropriate, and vice versa."),
+	(0x800b_0008, "DIGSIG_E_CRYPTO", "Unspecified cryptographic failure."),
+	(0x800b_0009, "PERSIST_E_SIZEDEFINITE", "The size of the data could not be determined."),
+	(0x800b_000a, "PERSIST_E_SIZEINDEFINITE", "The size of the indefinite-sized data could not be determined."),
+	(0x800b_000b, "PERSIST_E_NOTSELFSIZING", "This object does not read and write self-sizing data."),
+	(0x800b_0100, "TRUST_E_NOSIGNATURE", "No signature was present in the subject."),
+	(0x800b_0101, "CERT_E_EXPIRED", "A required certificate is not within its validity period when verifying against the current system clock or the timestamp in the signed file."),
+	(0x800b_0102, "CERT_E_VALIDITYPERIODNESTING", "The validity periods of the certification chain do not nest correctly."),
+	(0x800b_0103, "CERT_E_ROLE", "A certificate that can only be used as an end-entity is being used as a CA or vice versa."),
+	(0x800b_0104, "CERT_E_PATHLENCONST", "A path length constraint in the certification chain has been violated."),
+	(0x800b_0105, "CERT_E_CRITICAL", "A certificate contains an unknown extension that is marked 'critical'."),
+	(0x800b_0106, "CERT_E_PURPOSE", "A certificate being used for a purpose other than the ones specified by its CA."),
+	(0x800b_0107, "CERT_E_ISSUERCHAINING", "A parent of a given certificate in fact did not issue that child certificate."),
+	(0x800b_0108, "CERT_E_MALFORMED", "A certificate is missing or has an empty value for an important field, such as a subject or issuer name."),
+	(0x800b_0109, "CERT_E_UNTRUSTEDROOT", "A certificate chain processed, but terminated in a root certificate which is not trusted by the trust provider."),
+	(0x800b_010a, "CERT_E_CHAINING", "A certificate chain could not be built to a trusted root authority."),
+	(0x800b_010b, "TRUST_E_FAIL", "Generic trust failure."),
+	(0x800b_010c, "CERT_E_REVOKED", "A certificate was explicitly revoked by its issuer."),
+	(0x800b_010d, "CERT_E_UNTRUSTEDTESTROOT", "The certification path terminates with the test root which is not trusted with the current policy settings."),
+	(0x800b_010e, "CERT_E_REVOCATION_FAILURE", "The revocation process could not continue - the certificate(s) could not be checked."),
+	(0x800b_010f, "CERT_E_CN_NO_MATCH", "The certificate's CN name does not match the passed value."),
+	(0x800b_0110, "CERT_E_WRONG_USAGE", "The certificate is not valid for the requested usage."),
+	(0x800b_0111, "TRUST_E_EXPLICIT_DISTRUST", "The certificate was explicitly marked as untrusted by the user."),
+	(0x800b_0112, "CERT_E_UNTRUSTEDCA", "A certification chain processed correctly, but one of the CA certificates is not trusted by the policy provider."),
+	(0x800b_0113, "CERT_E_INVALID_POLICY", "The certificate has invalid policy."),
+	(0x800b_0114, "CERT_E_INVALID_NAME", "The certificate has an invalid name. The name is not included in the permitted list or is explicitly excluded."),
+	(0x800c_0002, "INET_E_INVALID_URL", "The URL is invalid."),
+	(0x800c_0003, "INET_E_NO_SESSION", "No Internet session has been established."),
+	(0x800c_0004, "INET_E_CANNOT_CONNECT", "Unable to connect to the target server."),
+	(0x800c_0005, "INET_E_RESOURCE_NOT_FOUND", "The system cannot locate the resource specified."),
+	(0x800c_0006, "INET_E_OBJECT_NOT_FOUND", "The system cannot locate the object specified."),
+	(0x800c_0007, "INET_E_DATA_NOT_AVAILABLE", "No data is available for the requested resource."),
+	(0x800c_0008, "INET_E_DOWNLOAD_FAILURE", "The download of the specified resource has failed."),
+	(0x800c_0009, "INET_E_AUTHENTICATION_REQUIRED", "Authentication is required to access this resource."),
+	(0x800c_000a, "INET_E_NO_VALID_MEDIA", "The server could not recognize the provided mime type."),
+	(0x800c_000b, "INET_E_CONNECTION_TIMEOUT", "The operation was timed out."),
+	(0x800c_000c, "INET_E_INVALID_REQUEST", "The server did not understand the request, or the request was invalid."),
+	(0x800c_000d, "INET_E_UNKNOWN_PROTOCOL", "The specified protocol is unknown."),
+	(0x800c_000e, "INET_E_SECURITY_PROBLEM", "A security problem occurred."),
+	(0x800c_000f, "INET_E_CANNOT_LOAD_DATA", "The system could not load the persisted data."),
+	(0x800c_0010, "INET_E_CANNOT_INSTANTIATE_OBJECT", "Unable to instantiate the object."),
+	(0x800c_0014, "INET_E_REDIRECT_FAILED", "Redirection failed."),
+	(0x800c_0015, "INET_E_REDIRECT_TO_DIR", "The request is being redirected to a directory."),
+	(0x800c_0019, "INET_E_INVALID_CERTIFICATE", "The SSL certificate is invalid."),
+	(0x800f_0000, "SPAPI_E_EXPECTED_SECTION_NAME", "A non-empty line was encountered in the INF before the start of a section."),
+	(0x800f_0001, "SPAPI_E_BAD_SECTION_NAME_LINE", "A section name marker in the INF is not complete, or does not exist on a line by itself."),
+	(0x800f_0002, "SPAPI_E_SECTION_NAME_TOO_LONG", "An INF section was encountered whose name exceeds the maximum section name length."),
+	(0x800f_0003, "SPAPI_E_GENERAL_SYNTAX", "The syntax of the INF is invalid."),
+	(0x800f_0100, "SPAPI_E_WRONG_INF_STYLE", "The style of the INF is different than what was requested."),
+	(0x800f_0101, "SPAPI_E_SECTION_NOT_FOUND", "The required section was not found in the INF."),
+	(0x800f_0102, "SPAPI_E_LINE_NOT_FOUND", "The required line was not found in the INF."),
+	(0x800f_0103, "SPAPI_E_NO_BACKUP", "The files affected by the installation of this file queue have not been backed up for uninstall."),
+	(0x800f_0200, "SPAPI_E_NO_ASSOCIATED_CLASS", "The INF or the device information set or element does not have an associated install class."),
+	(0x800f_0201, "SPAPI_E_CLASS_MISMATCH", "The INF or the device information set or element does not match the specified install class."),
+	(0x800f_0202, "SPAPI_E_DUPLICATE_FOUND", "An existing device was found that is a duplicate of the device being manually installed."),
+	(0x800f_0203, "SPAPI_E_NO_DRIVER_SELECTED", "There is no driver selected for the device information set or element."),
+	(0x800f_0204, "SPAPI_E_KEY_DOES_NOT_EXIST", "The requested device registry key does not exist."),
+	(0x800f_0205, "SPAPI_E_INVALID_DEVINST_NAME", "The device instance name is invalid."),
+	(0x800f_0206, "SPAPI_E_INVALID_CLASS", "The install class is not present or is invalid."),
+	(0x800f_0207, "SPAPI_E_DEVINST_ALREADY_EXISTS", "The device instance cannot be created because it already exists."),
+	(0x800f_0208, "SPAPI_E_DEVINFO_NOT_REGISTERED", "The operation cannot be performed on a device information element that has not been registered."),
+	(0x800f_0209, "SPAPI_E_INVALID_REG_PROPERTY", "The device property code is invalid."),
+	(0x800f_020a, "SPAPI_E_NO_INF", "The INF from which a driver list is to be built does not exist."),
+	(0x800f_020b, "SPAPI_E_NO_SUCH_DEVINST", "The device instance does not exist in the hardware tree."),
+	(0x800f_020c, "SPAPI_E_CANT_LOAD_CLASS_ICON", "The icon representing this install class cannot be loaded."),
+	(0x800f_020d, "SPAPI_E_INVALID_CLASS_INSTALLER", "The class installer registry entry is invalid."),
+	(0x800f_020e, "SPAPI_E_DI_DO_DEFAULT", "The class installer has indicated that the default action should be performed for this installation request."),
+	(0x800f_020f, "SPAPI_E_DI_NOFILECOPY", "The operation does not require any files to be copied."),
+	(0x800f_0210, "SPAPI_E_INVALID_HWPROFILE", "The specified hardware profile does not exist."),
+	(0x800f_0211, "SPAPI_E_NO_DEVICE_SELECTED", "There is no device information element currently selected for this device information set."),
+	(0x800f_0212, "SPAPI_E_DEVINFO_LIST_LOCKED", "The operation cannot be performed because the device information set is locked."),
+	(0x800f_0213, "SPAPI_E_DEVINFO_DATA_LOCKED", "The operation cannot be performed because the device information element is locked."),
+	(0x800f_0214, "SPAPI_E_DI_BAD_PATH", "The specified path does not contain any applicable device INFs."),
+	(0x800f_0215, "SPAPI_E_NO_CLASSINSTALL_PARAMS", "No class installer parameters have been set for the device information set or element."),
+	(0x800f_0216, "SPAPI_E_FILEQUEUE_LOCKED", "The operation cannot be performed because the file queue is locked."),
+	(0x800f_0217, "SPAPI_E_BAD_SERVICE_INSTALLSECT", "A service installation section in this INF is invalid."),
+	(0x800f_0218, "SPAPI_E_NO_CLASS_DRIVER_LIST", "There is no class driver list for the device information element."),
+	(0x800f_0219, "SPAPI_E_NO_ASSOCIATED_SERVICE", "The installation failed because a function driver was not specified for this device instance."),
+	(0x800f_021a, "SPAPI_E_NO_DEFAULT_DEVICE_INTERFACE", "There is presently no default device interface designated for this interface class."),
+	(0x800f_021b, "SPAPI_E_DEVICE_INTERFACE_ACTIVE", "The operation cannot be performed because the device interface is currently active."),
+	(0x800f_021c, "SPAPI_E_DEVICE_INTERFACE_REMOVED", "The operation cannot be performed because the device interface has been removed from the system."),
+	(0x800f_021d, "SPAPI_E_BAD_INTERFACE_INSTALLSECT", "An interface installation section in this INF is invalid."),
+	(0x800f_021e, "SPAPI_E_NO_SUCH_INTERFACE_CLASS", "This interface class does not exist in the system."),
+	(0x800f_021f, "SPAPI_E_INVALID_REFERENCE_STRING", "The reference string supplied for this interface device is invalid."),
+	(0x800f_0220, "SPAPI_E_INVALID_MACHINENAME", "The specified machine name does not conform to UNC naming conventions."),
+	(0x800f_0221, "SPAPI_E_REMOTE_COMM_FAILURE", "A general remote communication error occurred."),
+	(0x800f_0222, "SPAPI_E_MACHINE_UNAVAILABLE", "The machine selected for remote communication is not available at this time."),
+	(0x800f_0223, "SPAPI_E_NO_CONFIGMGR_SERVICES", "The Plug and Play service is not available on the remote machine."),
+	(0x800f_0224, "SPAPI_E_INVALID_PROPPAGE_PROVIDER", "The property page provider registry entry is invalid."),
+	(0x800f_0225, "SPAPI_E_NO_SUCH_DEVICE_INTERFACE", "The requested device interface is not present in the system."),
+	(0x800f_0226, "SPAPI_E_DI_POSTPROCESSING_REQUIRED", "The device's co-installer has additional work to perform after installation is complete."),
+	(0x800f_0227, "SPAPI_E_INVALID_COINSTALLER", "The device's co-installer is invalid."),
+	(0x800f_0228, "SPAPI_E_NO_COMPAT_DRIVERS", "There are no compatible drivers for this device."),
+	(0x800f_0229, "SPAPI_E_NO_DEVICE_ICON", "There is no icon that represents this device or device type."),
+	(0x800f_022a, "SPAPI_E_INVALID_INF_LOGCONFIG", "A logical configuration specified in this INF is invalid."),
+	(0x800f_022b, "SPAPI_E_DI_DONT_INSTALL", "The class installer has denied the request to install or upgrade this device."),
+	(0x800f_022c, "SPAPI_E_INVALID_FILTER_DRIVER", "One of the filter drivers installed for this device is invalid."),
+	(0x800f_022d, "SPAPI_E_NON_WINDOWS_NT_DRIVER", "The driver selected for this device does not support this version of Windows."),
+	(0x800f_022e, "SPAPI_E_NON_WINDOWS_DRIVER", "The driver selected for this device does not support Windows."),
+	(0x800f_022f, "SPAPI_E_NO_CATALOG_FOR_OEM_INF", "The third-party INF does not contain digital signature information."),
+	(0x800f_0230, "SPAPI_E_DEVINSTALL_QUEUE_NONNATIVE", "An invalid attempt was made to use a device installation file queue for verification of digital signatures relative to other platforms."),
+	(0x800f_0231, "SPAPI_E_NOT_DISABLEABLE", "The device cannot be disabled."),
+	(0x800f_0232, "SPAPI_E_CANT_REMOVE_DEVINST", "The device could not be dynamically removed."),
+	(0x800f_0233, "SPAPI_E_INVALID_TARGET", "Cannot copy to specified target."),
+	(0x800f_0234, "SPAPI_E_DRIVER_NONNATIVE", "Driver is not intended for this platform."),
+	(0x800f_0235, "SPAPI_E_IN_WOW64", "Operation not allowed in WOW64."),
+	(0x800f_0236, "SPAPI_E_SET_SYSTEM_RESTORE_POINT", "The operation involving unsigned file copying was rolled back, so that a system restore point could be set."),
+	(0x800f_0237, "SPAPI_E_INCORRECTLY_COPIED_INF", "An INF was copied into the Windows INF directory in an improper manner."),
+	(0x800f_0238, "SPAPI_E_SCE_DISABLED", "The Security Configuration Editor (SCE) APIs have been disabled on this Embedded product."),
+	(0x800f_0239, "SPAPI_E_UNKNOWN_EXCEPTION", "An unknown exception was encountered."),
+	(0x800f_023a, "SPAPI_E_PNP_REGISTRY_ERROR", "A problem was encountered when accessing the Plug and Play registry database."),
+	(0x800f_023b, "SPAPI_E_REMOTE_REQUEST_UNSUPPORTED", "The requested operation is not supported for a remote machine."),
+	(0x800f_023c, "SPAPI_E_NOT_AN_INSTALLED_OEM_INF", "The specified file is not an installed OEM INF."),
+	(0x800f_023d, "SPAPI_E_INF_IN_USE_BY_DEVICES", "One or more devices are presently installed using the specified INF."),
+	(0x800f_023e, "SPAPI_E_DI_FUNCTION_OBSOLETE", "The requested device install operation is obsolete."),
+	(0x800f_023f, "SPAPI_E_NO_AUTHENTICODE_CATALOG", "A file could not be verified because it does not have an associated catalog signed via Authenticode(tm)."),
+	(0x800f_0240, "SPAPI_E_AUTHENTICODE_DISALLOWED", "Authenticode(tm) signature verification is not supported for the specified INF."),
+	(0x800f_0241, "SPAPI_E_AUTHENTICODE_TRUSTED_PUBLISHER", "The INF was signed with an Authenticode(tm) catalog from a trusted publisher."),
+	(0x800f_0242, "SPAPI_E_AUTHENTICODE_TRUST_NOT_ESTABLISHED", "The publisher of an Authenticode(tm) signed catalog has not yet been established as trusted."),
+	(0x800f_0243, "SPAPI_E_AUTHENTICODE_PUBLISHER_NOT_TRUSTED", "The publisher of an Authenticode(tm) signed catalog was not established as trusted."),
+	(0x800f_0244, "SPAPI_E_SIGNATURE_OSATTRIBUTE_MISMATCH", "The software was tested for compliance with Windows Logo requirements on a different version of Windows, and may not be compatible with this version."),
+	(0x800f_0245, "SPAPI_E_ONLY_VALIDATE_VIA_AUTHENTICODE", "The file may only be validated by a catalog signed via Authenticode(tm)."),
+	(0x800f_0246, "SPAPI_E_DEVICE_INSTALLER_NOT_READY", "One of the installers for this device cannot perform the installation at this time."),
+	(0x800f_0247, "SPAPI_E_DRIVER_STORE_ADD_FAILED", "A problem was encountered while attempting to add the driver to the store."),
+	(0x800f_0248, "SPAPI_E_DEVICE_INSTALL_BLOCKED", "The installation of this device is forbidden by system policy. Contact your system administrator."),
+	(0x800f_0249, "SPAPI_E_DRIVER_INSTALL_BLOCKED", "The installation of this driver is forbidden by system policy. Contact your system administrator."),
+	(0x800f_024a, "SPAPI_E_WRONG_INF_TYPE", "The specified INF is the wrong type for this operation."),
+	(0x800f_024b, "SPAPI_E_FILE_HASH_NOT_IN_CATALOG", "The hash for the file is not present in the specified catalog file. The file is likely corrupt or the victim of tampering."),
+	(0x800f_024c, "SPAPI_E_DRIVER_STORE_DELETE_FAILED", "A problem was encountered while attempting to delete the driver from the store."),
+	(0x800f_0300, "SPAPI_E_UNRECOVERABLE_STACK_OVERFLOW", "An unrecoverable stack overflow was encountered."),
+	(0x800f_1000, "SPAPI_E_ERROR_NOT_INSTALLED", "No installed components were detected."),
+	(0x8010_0001, "SCARD_F_INTERNAL_ERROR", "An internal consistency check failed."),
+	(0x8010_0002, "SCARD_E_CANCELLED", "The action was cancelled by an SCardCancel request."),
+	(0x8010_0003, "SCARD_E_INVALID_HANDLE", "The supplied handle was invalid."),
+	(0x8010_0004, "SCARD_E_INVALID_PARAMETER", "One or more of the supplied parameters could not be properly interpreted."),
+	(0x8010_0005, "SCARD_E_INVALID_TARGET", "Registry startup information is missing or invalid."),
+	(0x8010_0006, "SCARD_E_NO_MEMORY", "Not enough memory available to complete this command."),
+	(0x8010_0007, "SCARD_F_WAITED_TOO_LONG", "An internal consistency timer has expired."),
+	(0x8010_0008, "SCARD_E_INSUFFICIENT_BUFFER", "The data buffer to receive returned data is too small for the returned data."),
+	(0x8010_0009, "SCARD_E_UNKNOWN_READER", "The specified reader name is not recognized."),
+	(0x8010_000a, "SCARD_E_TIMEOUT", "The user-specified timeout value has expired."),
+	(0x8010_000b, "SCARD_E_SHARING_VIOLATION", "The smart card cannot be accessed because of other connections outstanding."),
+	(0x8010_000c, "SCARD_E_NO_SMARTCARD", "The operation requires a Smart Card, but no Smart Card is currently in the device."),
+	(0x8010_000d, "SCARD_E_UNKNOWN_CARD", "The specified smart card name is not recognized."),
+	(0x8010_000e, "SCARD_E_CANT_DISPOSE", "The system could not dispose of the media in the requested manner."),
+	(0x8010_000f, "SCARD_E_PROTO_MISMATCH", "The requested protocols are incompatible with the protocol currently in use with the smart card."),
+	(0x8010_0010, "SCARD_E_NOT_READY", "The reader or smart card is not ready to accept commands."),
+	(0x8010_0011, "SCARD_E_INVALID_VALUE", "One or more of the supplied parameters values could not be properly interpreted."),
+	(0x8010_0012, "SCARD_E_SYSTEM_CANCELLED", "The action was cancelled by the system, presumably to log off or shut down."),
+	(0x8010_0013, "SCARD_F_COMM_ERROR", "An internal communications error has been detected."),
+	(0x8010_0014, "SCARD_F_UNKNOWN_ERROR", "An internal error has been detected, but the source is unknown."),
+	(0x8010_0015, "SCARD_E_INVALID_ATR", "An ATR obtained from the registry is not a valid ATR string."),
+	(0x8010_0016, "SCARD_E_NOT_TRANSACTED", "An attempt was made to end a non-existent transaction."),
+	(0x8010_0017, "SCARD_E_READER_UNAVAILABLE", "The specified reader is not currently available for use."),
+	(0x8010_0018, "SCARD_P_SHUTDOWN", "The operation has been aborted to allow the server application to exit."),
+	(0x8010_0019, "SCARD_E_PCI_TOO_SMALL", "The PCI Receive buffer was too small."),
+	(0x8010_001a, "SCARD_E_READER_UNSUPPORTED", "The reader driver does not meet minimal requirements for support."),
+	(0x8010_001b, "SCARD_E_DUPLICATE_READER", "The reader driver did not produce a unique reader name."),
+	(0x8010_001c, "SCARD_E_CARD_UNSUPPORTED", "The smart card does not meet minimal requirements for support."),
+	(0x8010_001d, "SCARD_E_NO_SERVICE", "The Smart Card Resource Manager is not running."),
+	(0x8010_001e, "SCARD_E_SERVICE_STOPPED", "The Smart Card Resource Manager has shut down."),
+	(0x8010_001f, "SCARD_E_UNEXPECTED", "An unexpected card error has occurred."),
+	(0x8010_0020, "SCARD_E_ICC_INSTALLATION", "No Primary Provider can be found for the smart card."),
+	(0x8010_0021, "SCARD_E_ICC_CREATEORDER", "The requested order of object creation is not supported."),
+	(0x8010_0022, "SCARD_E_UNSUPPORTED_FEATURE", "This smart card does not support the requested feature."),
+	(0x8010_0023, "SCARD_E_DIR_NOT_FOUND", "The identified directory does not exist in the smart card."),
+	(0x8010_0024, "SCARD_E_FILE_NOT_FOUND", "The identified file does not exist in the smart card."),
+	(0x8010_0025, "SCARD_E_NO_DIR", "The supplied path does not represent a smart card directory."),
+	(0x8010_0026, "SCARD_E_NO_FILE", "The supplied path does not represent a smart card file."),
+	(0x8010_0027, "SCARD_E_NO_ACCESS", "Access is denied to this file."),
+	(0x8010_0028, "SCARD_E_WRITE_TOO_MANY", "The smart card does not have enough memory to store the information."),
+	(0x8010_0029, "SCARD_E_BAD_SEEK", "There was an error trying to set the smart card file object pointer."),
+	(0x8010_002a, "SCARD_E_INVALID_CHV", "The supplied PIN is incorrect."),
+	(0x8010_002b, "SCARD_E_UNKNOWN_RES_MNG", "An unrecognized error code was returned from a layered component."),
+	(0x8010_002c, "SCARD_E_NO_SUCH_CERTIFICATE", "The requested certificate does not exist."),
+	(0x8010_002d, "SCARD_E_CERTIFICATE_UNAVAILABLE", "The requested certificate could not be obtained."),
+	(0x8010_002e, "SCARD_E_NO_READERS_AVAILABLE", "Cannot find a smart card reader."),
+	(0x8010_002f, "SCARD_E_COMM_DATA_LOST", "A communications error with the smart card has been detected. Retry the operation."),
+	(0x8010_0030, "SCARD_E_NO_KEY_CONTAINER", "The requested key container does not exist on the smart card."),
+	(0x8010_0031, "SCARD_E_SERVER_TOO_BUSY", "The Smart Card Resource Manager is too busy to complete this operation."),
+	(0x8010_0032, "SCARD_E_PIN_CACHE_EXPIRED", "The smart card PIN cache has expired."),
+	(0x8010_0033, "SCARD_E_NO_PIN_CACHE", "The smart card PIN cannot be cached."),
+	(0x8010_0034, "SCARD_E_READ_ONLY_CARD", "The smart card is read only and cannot be written to."),
+	(0x8010_0065, "SCARD_W_UNSUPPORTED_CARD", "The reader cannot communicate with the smart card, due to ATR configuration conflicts."),
+	(0x8010_0066, "SCARD_W_UNRESPONSIVE_CARD", "The smart card is not responding to a reset."),
+	(0x8010_0067, "SCARD_W_UNPOWERED_CARD", "Power has been removed from the smart card, so that further communication is not possible."),
+	(0x8010_0068, "SCARD_W_RESET_CARD", "The smart card has been reset, so any shared state information is invalid."),
+	(0x8010_0069, "SCARD_W_REMOVED_CARD", "The smart card has been removed, so that further communication is not possible."),
+	(0x8010_006a, "SCARD_W_SECURITY_VIOLATION", "Access was denied because of a security violation."),
+	(0x8010_006b, "SCARD_W_WRONG_CHV", "The card cannot be accessed because the wrong PIN was presented."),
+	(0x8010_006c, "SCARD_W_CHV_BLOCKED", "The card cannot be accessed because the maximum number of PIN entry attempts has been reached."),
+	(0x8010_006d, "SCARD_W_EOF", "The end of the smart card file has been reached."),
+	(0x8010_006e, "SCARD_W_CANCELLED_BY_USER", "The action was cancelled by the user."),
+	(0x8010_006f, "SCARD_W_CARD_NOT_AUTHENTICATED", "No PIN was presented to the smart card."),
+	(0x8010_0070, "SCARD_W_CACHE_ITEM_NOT_FOUND", "The requested item could not be found in the cache."),
+	(0x8010_0071, "SCARD_W_CACHE_ITEM_STALE", "The requested cache item is too old and was deleted from the cache."),
+	(0x8010_0072, "SCARD_W_CACHE_ITEM_TOO_BIG", "The new cache item exceeds the maximum per-item size defined for the cache."),
+	(0x8011_0401, "COMADMIN_E_OBJECTERRORS", "Errors occurred accessing one or more objects - the ErrorInfo collection may have more detail."),
+	(0x8011_0402, "COMADMIN_E_OBJECTINVALID", "One or more of the object's properties are missing or invalid."),
+	(0x8011_0403, "COMADMIN_E_KEYMISSING", "The object was not found in the catalog."),
+	(0x8011_0404, "COMADMIN_E_ALREADYINSTALLED", "The object is already registered."),
+	(0x8011_0407, "COMADMIN_E_APP_FILE_WRITEFAIL", "An error occurred writing to the application file."),
+	(0x8011_0408, "COMADMIN_E_APP_FILE_READFAIL", "An error occurred reading the application file."),
+	(0x8011_0409, "COMADMIN_E_APP_FILE_VERSION", "Invalid version number in application file."),
+	(0x8011_040a, "COMADMIN_E_BADPATH", "The file path is invalid."),
+	(0x8011_040b, "COMADMIN_E_APPLICATIONEXISTS", "The application is already installed."),
+	(0x8011_040c, "COMADMIN_E_ROLEEXISTS", "The role already exists."),
+	(0x8011_040d, "COMADMIN_E_CANTCOPYFILE", "An error occurred copying the file."),
+	(0x8011_040f, "COMADMIN_E_NOUSER", "One or more users are not valid."),
+	(0x8011_0410, "COMADMIN_E_INVALIDUSERIDS", "One or more users in the application file are not valid."),
+	(0x8011_0411, "COMADMIN_E_NOREGISTRYCLSID", "The component's CLSID is missing or corrupt."),
+	(0x8011_0412, "COMADMIN_E_BADREGISTRYPROGID", "The component's progID is missing or corrupt."),
+	(0x8011_0413, "COMADMIN_E_AUTHENTICATIONLEVEL", "Unable to set required authentication level for update request."),
+	(0x8011_0414, "COMADMIN_E_USERPASSWDNOTVALID", "The identity or password set on the application is not valid."),
+	(0x8011_0418, "COMADMIN_E_CLSIDORIIDMISMATCH", "Application file CLSIDs or IIDs do not match corresponding DLLs."),
+	(0x8011_0419, "COMADMIN_E_REMOTEINTERFACE", "Interface information is either missing or changed."),
+	(0x8011_041a, "COMADMIN_E_DLLREGISTERSERVER", "DllRegisterServer failed on component install."),
+	(0x8011_041b, "COMADMIN_E_NOSERVERSHARE", "No server file share available."),
+	(0x8011_041d, "COMADMIN_E_DLLLOADFAILED", "DLL could not be loaded."),
+	(0x8011_041e, "COMADMIN_E_BADREGISTRYLIBID", "The registered TypeLib ID is not valid."),
+	(0x8011_041f, "COMADMIN_E_APPDIRNOTFOUND", "Application install directory not found."),
+	(0x8011_0423, "COMADMIN_E_REGISTRARFAILED", "Errors occurred while in the component registrar."),
+	(0x8011_0424, "COMADMIN_E_COMPFILE_DOESNOTEXIST", "The file does not exist."),
+	(0x8011_0425, "COMADMIN_E_COMPFILE_LOADDLLFAIL", "The DLL could not be loaded."),
+	(0x8011_0426, "COMADMIN_E_COMPFILE_GETCLASSOBJ", "GetClassObject failed in the DLL."),
+	(0x8011_0427, "COMADMIN_E_COMPFILE_CLASSNOTAVAIL", "The DLL does not support the components listed in the TypeLib."),
+	(0x8011_0428, "COMADMIN_E_COMPFILE_BADTLB", "The TypeLib could not be loaded."),
+	(0x8011_0429, "COMADMIN_E_COMPFILE_NOTINSTALLABLE", "The file does not contain components or component information."),
+	(0x8011_042a, "COMADMIN_E_NOTCHANGEABLE", "Changes to this object and its sub-objects have been disabled."),
+	(0x8011_042b, "COMADMIN_E_NOTDELETEABLE", "The delete function has been disabled for this object."),
+	(0x8011_042c, "COMADMIN_E_SESSION", "The server catalog version is not supported."),
+	(0x8011_042d, "COMADMIN_E_COMP_MOVE_LOCKED", "The component move was disallowed, because the source or destination application is either a system application or currently locked against changes."),
+	(0x8011_042e, "COMADMIN_E_COMP_MOVE_BAD_DEST", "The component move failed because the destination application no longer exists."),
+	(0x8011_0430, "COMADMIN_E_REGISTERTLB", "The system was unable to register the TypeLib."),
+	(0x8011_0433, "COMADMIN_E_SYSTEMAPP", "This operation cannot be performed on the system application."),
+	(0x8011_0434, "COMADMIN_E_COMPFILE_NOREGISTRAR", "The component registrar referenced in this file is not available."),
+	(0x8011_0435, "COMADMIN_E_COREQCOMPINSTALLED", "A component in the same DLL is already installed."),
+	(0x8011_0436, "COMADMIN_E_SERVICENOTINSTALLED", "The service is not installed."),
+	(0x8011_0437, "COMADMIN_E_PROPERTYSAVEFAILED", "One or more property settings are either invalid or in conflict with each other."),
+	(0x8011_0438, "COMADMIN_E_OBJECTEXISTS", "The object you are attempting to add or rename already exists."),
+	(0x8011_0439, "COMADMIN_E_COMPONENTEXISTS", "The component already exists."),
+	(0x8011_043b, "COMADMIN_E_REGFILE_CORRUPT", "The registration file is corrupt."),
+	(0x8011_043c, "COMADMIN_E_PROPERTY_OVERFLOW", "The property value is too large."),
+	(0x8011_043e, "COMADMIN_E_NOTINREGISTRY", "Object was not found in registry."),
+	(0x8011_043f, "COMADMIN_E_OBJECTNOTPOOLABLE", "This object is not poolable."),
+	(0x8011_0446, "COMADMIN_E_APPLID_MATCHES_CLSID", "A CLSID with the same GUID as the new application ID is already installed on this machine."),
+	(0x8011_0447, "COMADMIN_E_ROLE_DOES_NOT_EXIST", "A role assigned to a component, interface, or method did not exist in the application."),
+	(0x8011_0448, "COMADMIN_E_START_APP_NEEDS_COMPONENTS", "You must have components in an application in order to start the application."),
+	(0x8011_0449, "COMADMIN_E_REQUIRES_DIFFERENT_PLATFORM", "This operation is not enabled on this platform."),
+	(0x8011_044a, "COMADMIN_E_CAN_NOT_EXPORT_APP_PROXY", "Application Proxy is not exportable."),
+	(0x8011_044b, "COMADMIN_E_CAN_NOT_START_APP", "Failed to start application because it is either a library application or an application proxy."),
+	(0x8011_044c, "COMADMIN_E_CAN_NOT_EXPORT_SYS_APP", "System application is not exportable."),
+	(0x8011_044d, "COMADMIN_E_CANT_SUBSCRIBE_TO_COMPONENT", "Cannot subscribe to this component (the component may have been imported)."),
+	(0x8011_044e, "COMADMIN_E_EVENTCLASS_CANT_BE_SUBSCRIBER", "An event class cannot also be a subscriber component."),
+	(0x8011_044f, "COMADMIN_E_LIB_APP_PROXY_INCOMPATIBLE", "Library applications and application proxies are incompatible."),
+	(0x8011_0450, "COMADMIN_E_BASE_PARTITION_ONLY", "This function is valid for the base partition only."),
+	(0x8011_0451, "COMADMIN_E_START_APP_DISABLED", "You cannot start an application that has been disabled."),
+	(0x8011_0457, "COMADMIN_E_CAT_DUPLICATE_PARTITION_NAME", "The specified partition name is already in use on this computer."),
+	(0x8011_0458, "COMADMIN_E_CAT_INVALID_PARTITION_NAME", "The specified partition name is invalid. Check that the name contains at least one visible character."),
+	(0x8011_0459, "COMADMIN_E_CAT_PARTITION_IN_USE", "The partition cannot be deleted because it is the default partition for one or more users."),
+	(0x8011_045a, "COMADMIN_E_FILE_PARTITION_DUPLICATE_FILES", "The partition cannot be exported, because one or more components in the partition have the same file name."),
+	(0x8011_045b, "COMADMIN_E_CAT_IMPORTED_COMPONENTS_NOT_ALLOWED", "Applications that contain one or more imported components cannot be installed into a non-base partition."),
+	(0x8011_045c, "COMADMIN_E_AMBIGUOUS_APPLICATION_NAME", "The application name is not unique and cannot be resolved to an application id."),
+	(0x8011_045d, "COMADMIN_E_AMBIGUOUS_PARTITION_NAME", "The partition name is not unique and cannot be resolved to a partition id."),
+	(0x8011_0472, "COMADMIN_E_REGDB_NOTINITIALIZED", "The COM+ registry database has not been initialized."),
+	(0x8011_0473, "COMADMIN_E_REGDB_NOTOPEN", "The COM+ registry database is not open."),
+	(0x8011_0474, "COMADMIN_E_REGDB_SYSTEMERR", "The COM+ registry database detected a system error."),
+	(0x8011_0475, "COMADMIN_E_REGDB_ALREADYRUNNING", "The COM+ registry database is already running."),
+	(0x8011_0480, "COMADMIN_E_MIG_VERSIONNOTSUPPORTED", "This version of the COM+ registry database cannot be migrated."),
+	(0x8011_0481, "COMADMIN_E_MIG_SCHEMANOTFOUND", "The schema version to be migrated could not be found in the COM+ registry database."),
+	(0x8011_0482, "COMADMIN_E_CAT_BITNESSMISMATCH", "There was a type mismatch between binaries."),
+	(0x8011_0483, "COMADMIN_E_CAT_UNACCEPTABLEBITNESS", "A binary of unknown or invalid type was provided."),
+	(0x8011_0484, "COMADMIN_E_CAT_WRONGAPPBITNESS", "There was a type mismatch between a binary and an application."),
+	(0x8011_0485, "COMADMIN_E_CAT_PAUSE_RESUME_NOT_SUPPORTED", "The application cannot be paused or resumed."),
+	(0x8011_0486, "COMADMIN_E_CAT_SERVERFAULT", "The COM+ Catalog Server threw an exception during execution."),
+	(0x8011_0600, "COMQC_E_APPLICATION_NOT_QUEUED", "Only COM+ Applications marked \"queued\" can be invoked using the \"queue\" moniker."),
+	(0x8011_0601, "COMQC_E_NO_QUEUEABLE_INTERFACES", "At least one interface must be marked \"queued\" in order to create a queued component instance with the \"queue\" moniker."),
+	(0x8011_0602, "COMQC_E_QUEUING_SERVICE_NOT_AVAILABLE", "MSMQ is required for the requested operation and is not installed."),
+	(0x8011_0603, "COMQC_E_NO_IPERSISTSTREAM", "Unable to marshal an interface that does not support IPersistStream."),
+	(0x8011_0604, "COMQC_E_BAD_MESSAGE", "The message is improperly formatted or was damaged in transit."),
+	(0x8011_0605, "COMQC_E_UNAUTHENTICATED", "An unauthenticated message was received by an application that accepts only authenticated messages."),
+	(0x8011_0606, "COMQC_E_UNTRUSTED_ENQUEUER", "The message was requeued or moved by a user not in the \"QC Trusted User\" role."),
+	(0x8011_0701, "MSDTC_E_DUPLICATE_RESOURCE", "Cannot create a duplicate resource of type Distributed Transaction Coordinator."),
+	(0x8011_0808, "COMADMIN_E_OBJECT_PARENT_MISSING", "One of the objects being inserted or updated does not belong to a valid parent collection."),
+	(0x8011_0809, "COMADMIN_E_OBJECT_DOES_NOT_EXIST", "One of the specified objects cannot be found."),
+	(0x8011_080a, "COMADMIN_E_APP_NOT_RUNNING", "The specified application is not currently running."),
+	(0x8011_080b, "COMADMIN_E_INVALID_PARTITION", "The partition(s) specified are not valid."),
+	(0x8011_080d, "COMADMIN_E_SVCAPP_NOT_POOLABLE_OR_RECYCLABLE", "COM+ applications that run as NT service may not be pooled or recycled."),
+	(0x8011_080e, "COMADMIN_E_USER_IN_SET", "One or more users are already assigned to a local partition set."),
+	(0x8011_080f, "COMADMIN_E_CANTRECYCLELIBRARYAPPS", "Library applications may not be recycled."),
+	(0x8011_0811, "COMADMIN_E_CANTRECYCLESERVICEAPPS", "Applications running as NT services may not be recycled."),
+	(0x8011_0812, "COMADMIN_E_PROCESSALREADYRECYCLED", "The process has already been recycled."),
+	(0x8011_0813, "COMADMIN_E_PAUSEDPROCESSMAYNOTBERECYCLED", "A paused process may not be recycled."),
+	(0x8011_0814, "COMADMIN_E_CANTMAKEINPROCSERVICE", "Library applications may not be NT services."),
+	(0x8011_0815, "COMADMIN_E_PROGIDINUSEBYCLSID", "The ProgID provided to the copy operation is invalid. The ProgID is in use by another registered CLSID."),
+	(0x8011_0816, "COMADMIN_E_DEFAULT_PARTITION_NOT_IN_SET", "The partition specified as default is not a member of the partition set."),
+	(0x8011_0817, "COMADMIN_E_RECYCLEDPROCESSMAYNOTBEPAUSED", "A recycled process may not be paused."),
+	(0x8011_0818, "COMADMIN_E_PARTITION_ACCESSDENIED", "Access to the specified partition is denied."),
+	(0x8011_0819, "COMADMIN_E_PARTITION_MSI_ONLY", "Only Application Files (*.MSI files) can be installed into partitions."),
+	(0x8011_081a, "COMADMIN_E_LEGACYCOMPS_NOT_ALLOWED_IN_1_0_FORMAT", "Applications containing one or more legacy components may not be exported to 1.0 format."),
+	(0x8011_081b, "COMADMIN_E_LEGACYCOMPS_NOT_ALLOWED_IN_NONBASE_PARTITIONS", "Legacy components may not exist in non-base partitions."),
+	(0x8011_081c, "COMADMIN_E_COMP_MOVE_SOURCE", "A component cannot be moved (or copied) from the System Application, an application proxy or a non-changeable application."),
+	(0x8011_081d, "COMADMIN_E_COMP_MOVE_DEST", "A component cannot be moved (or copied) to the System Application, an application proxy or a non-changeable application."),
+	(0x8011_081e, "COMADMIN_E_COMP_MOVE_PRIVATE", "A private component cannot be moved (or copied) to a library application or to the base partition."),
+	(0x8011_081f, "COMADMIN_E_BASEPARTITION_REQUIRED_IN_SET", "The Base Application Partition exists in all partition sets and cannot be removed."),
+	(0x8011_0820, "COMADMIN_E_CANNOT_ALIAS_EVENTCLASS", "Alas, Event Class components cannot be aliased."),
+	(0x8011_0821, "COMADMIN_E_PRIVATE_ACCESSDENIED", "Access is denied because the component is private."),
+	(0x8011_0822, "COMADMIN_E_SAFERINVALID", "The specified SAFER level is invalid."),
+	(0x8011_0823, "COMADMIN_E_REGISTRY_ACCESSDENIED", "The specified user cannot write to the system registry."),
+	(0x8011_0824, "COMADMIN_E_PARTITIONS_DISABLED", "COM+ partitions are currently disabled."),
+	(0x8019_0001, "HTTP_E_STATUS_UNEXPECTED", "Unexpected HTTP status code."),
+	(0x8019_0003, "HTTP_E_STATUS_UNEXPECTED_REDIRECTION", "Unexpected redirection status code (3xx)."),
+	(0x8019_0004, "HTTP_E_STATUS_UNEXPECTED_CLIENT_ERROR", "Unexpected client error status code (4xx)."),
+	(0x8019_0005, "HTTP_E_STATUS_UNEXPECTED_SERVER_ERROR", "Unexpected server error status code (5xx)."),
+	(0x8019_012c, "HTTP_E_STATUS_AMBIGUOUS", "Multiple choices (300)."),
+	(0x8019_012d, "HTTP_E_STATUS_MOVED", "Moved permanently (301)."),
+	(0x8019_012e, "HTTP_E_STATUS_REDIRECT", "Found (302)."),
+	(0x8019_012f, "HTTP_E_STATUS_REDIRECT_METHOD", "See Other (303)."),
+	(0x8019_0130, "HTTP_E_STATUS_NOT_MODIFIED", "Not modified (304)."),
+	(0x8019_0131, "HTTP_E_STATUS_USE_PROXY", "Use proxy (305)."),
+	(0x8019_0133, "HTTP_E_STATUS_REDIRECT_KEEP_VERB", "Temporary redirect (307)."),
+	(0x8019_0190, "HTTP_E_STATUS_BAD_REQUEST", "Bad request (400)."),
+	(0x8019_0191, "HTTP_E_STATUS_DENIED", "Unauthorized (401)."),
+	(0x8019_0192, "HTTP_E_STATUS_PAYMENT_REQ", "Payment required (402)."),
+	(0x8019_0193, "HTTP_E_STATUS_FORBIDDEN", "Forbidden (403)."),
+	(0x8019_0194, "HTTP_E_STATUS_NOT_FOUND", "Not found (404)."),
+	(0x8019_0195, "HTTP_E_STATUS_BAD_METHOD", "Method not allowed (405)."),
+	(0x8019_0196, "HTTP_E_STATUS_NONE_ACCEPTABLE", "Not acceptable (406)."),
+	(0x8019_0197, "HTTP_E_STATUS_PROXY_AUTH_REQ", "Proxy authentication required (407)."),
+	(0x8019_0198, "HTTP_E_STATUS_REQUEST_TIMEOUT", "Request timeout (408)."),
+	(0x8019_0199, "HTTP_E_STATUS_CONFLICT", "Conflict (409)."),
+	(0x8019_019a, "HTTP_E_STATUS_GONE", "Gone (410)."),
+	(0x8019_019b, "HTTP_E_STATUS_LENGTH_REQUIRED", "Length required (411)."),
+	(0x8019_019c, "HTTP_E_STATUS_PRECOND_FAILED", "Precondition failed (412)."),
+	(0x8019_019d, "HTTP_E_STATUS_REQUEST_TOO_LARGE", "Request entity too large (413)."),
+	(0x8019_019e, "HTTP_E_STATUS_URI_TOO_LONG", "Request-URI too long (414)."),
+	(0x8019_019f, "HTTP_E_STATUS_UNSUPPORTED_MEDIA", "Unsupported media type (415)."),
+	(0x8019_01a0, "HTTP_E_STATUS_RANGE_NOT_SATISFIABLE", "Requested range not satisfiable (416)."),
+	(0x8019_01a1, "HTTP_E_STATUS_EXPECTATION_FAILED", "Expectation failed (417)."),
+	(0x8019_01f4, "HTTP_E_STATUS_SERVER_ERROR", "Internal server error (500)."),
+	(0x8019_01f5, "HTTP_E_STATUS_NOT_SUPPORTED", "Not implemented (501)."),
+	(0x8019_01f6, "HTTP_E_STATUS_BAD_GATEWAY", "Bad gateway (502)."),
+	(0x8019_01f7, "HTTP_E_STATUS_SERVICE_UNAVAIL", "Service unavailable (503)."),
+	(0x8019_01f8, "HTTP_E_STATUS_GATEWAY_TIMEOUT", "Gateway timeout (504)."),
+	(0x8019_01f9, "HTTP_E_STATUS_VERSION_NOT_SUP", "Version not supported (505)."),
+	(0x801b_8000, "WER_E_CRASH_FAILURE", "Crash reporting failed."),
+	(0x801b_8001, "WER_E_CANCELED", "Report aborted due to user cancellation."),
+	(0x801b_8002, "WER_E_NETWORK_FAILURE", "Report aborted due to network failure."),
+	(0x801b_8003, "WER_E_NOT_INITIALIZED", "Report not initialized."),
+	(0x801b_8004, "WER_E_ALREADY_REPORTING", "Reporting is already in progress for the specified process."),
+	(0x801b_8005, "WER_E_DUMP_THROTTLED", "Dump not generated due to a throttle."),
+	(0x801b_8006, "WER_E_INSUFFICIENT_CONSENT", "Operation failed due to insufficient user consent."),
+	(0x801b_8007, "WER_E_TOO_HEAVY", "Report aborted due to performance criteria."),
+	(0x801f_0001, "ERROR_FLT_NO_HANDLER_DEFINED", "A handler was not defined by the filter for this operation."),
+	(0x801f_0002, "ERROR_FLT_CONTEXT_ALREADY_DEFINED", "A context is already defined for this object."),
+	(0x801f_0003, "ERROR_FLT_INVALID_ASYNCHRONOUS_REQUEST", "Asynchronous requests are not valid for this operation."),
+	(0x801f_0004, "ERROR_FLT_DISALLOW_FAST_IO", "Disallow the Fast IO path for this operation."),
+	(0x801f_0005, "ERROR_FLT_INVALID_NAME_REQUEST", "An invalid name request was made. The name requested cannot be retrieved at this time."),
+	(0x801f_0006, "ERROR_FLT_NOT_SAFE_TO_POST_OPERATION", "Posting this operation to a worker thread for further processing is not safe at this time because it could lead to a system deadlock."),
+	(0x801f_0007, "ERROR_FLT_NOT_INITIALIZED", "The Filter Manager was not initialized when a filter tried to register. Make sure that the Filter Manager is getting loaded as a driver."),
+	(0x801f_0008, "ERROR_FLT_FILTER_NOT_READY", "The filter is not ready for attachment to volumes because it has not finished initializing (FltStartFiltering has not been called)."),
+	(0x801f_0009, "ERROR_FLT_POST_OPERATION_CLEANUP", "The filter must cleanup any operation specific context at this time because it is being removed from the system before the operation is completed by the lower drivers."),
+	(0x801f_000a, "ERROR_FLT_INTERNAL_ERROR", "The Filter Manager had an internal error from which it cannot recover, therefore the operation has been failed. This is usually the result of a filter returning an invalid value from a pre-operation callback."),
+	(0x801f_000b, "ERROR_FLT_DELETING_OBJECT", "The object specified for this action is in the process of being deleted, therefore the action requested cannot be completed at this time."),
+	(0x801f_000c, "ERROR_FLT_MUST_BE_NONPAGED_POOL", "Non-paged pool must be used for this type of context."),
+	(0x801f_000d, "ERROR_FLT_DUPLICATE_ENTRY", "A duplicate handler definition has been provided for an operation."),
+	(0x801f_000e, "ERROR_FLT_CBDQ_DISABLED", "The callback data queue has been disabled."),
+	(0x801f_000f, "ERROR_FLT_DO_NOT_ATTACH", "Do not attach the filter to the volume at this time."),
+	(0x801f_0010, "ERROR_FLT_DO_NOT_DETACH", "Do not detach the filter from the volume at this time."),
+	(0x801f_0011, "ERROR_FLT_INSTANCE_ALTITUDE_COLLISION", "An instance already exists at this altitude on the volume specified."),
+	(0x801f_0012, "ERROR_FLT_INSTANCE_NAME_COLLISION", "An instance already exists with this name on the volume specified."),
+	(0x801f_0013, "ERROR_FLT_FILTER_NOT_FOUND", "The system could not find the filter specified."),
+	(0x801f_0014, "ERROR_FLT_VOLUME_NOT_FOUND", "The system could not find the volume specified."),
+	(0x801f_0015, "ERROR_FLT_INSTANCE_NOT_FOUND", "The system could not find the instance specified."),
+	(0x801f_0016, "ERROR_FLT_CONTEXT_ALLOCATION_NOT_FOUND", "No registered context allocation definition was found for the given request."),
+	(0x801f_0017, "ERROR_FLT_INVALID_CONTEXT_REGISTRATION", "An invalid parameter was specified during context registration."),
+	(0x801f_0018, "ERROR_FLT_NAME_CACHE_MISS", "The name requested was not found in Filter Manager's name cache and could not be retrieved from the file system."),
+	(0x801f_0019, "ERROR_FLT_NO_DEVICE_OBJECT", "The requested device object does not exist for the given volume."),
+	(0x801f_001a, "ERROR_FLT_VOLUME_ALREADY_MOUNTED", "The specified volume is already mounted."),
+	(0x801f_001b, "ERROR_FLT_ALREADY_ENLISTED", "The specified Transaction Context is already enlisted in a transaction."),
+	(0x801f_001c, "ERROR_FLT_CONTEXT_ALREADY_LINKED", "The specified context is already attached to another object."),
+	(0x801f_0020, "ERROR_FLT_NO_WAITER_FOR_REPLY", "No waiter is present for the filter's reply to this message."),
+	(0x801f_0023, "ERROR_FLT_REGISTRATION_BUSY", "The filesystem database resource is in use. Registration cannot complete at this time."),
+	(0x8026_0001, "ERROR_HUNG_DISPLAY_DRIVER_THREAD", "The display driver stopped working and has recovered."),
+	(0x8026_3001, "DWM_E_COMPOSITIONDISABLED", "Desktop composition is disabled."),
+	(0x8026_3002, "DWM_E_REMOTING_NOT_SUPPORTED", "Some desktop composition APIs are not supported while remoting."),
+	(0x8026_3003, "DWM_E_NO_REDIRECTION_SURFACE_AVAILABLE", "No DWM redirection surface is available."),
+	(0x8026_3004, "DWM_E_NOT_QUEUING_PRESENTS", "DWM is not queuing presents for the specified window."),
+	(0x8026_3005, "DWM_E_ADAPTER_NOT_FOUND", "The adapter specified by the LUID is not found."),
+	(0x8026_3007, "DWM_E_TEXTURE_TOO_LARGE", "Texture is too large."),
+	(0x8027_0001, "NAP_E_INVALID_PACKET", "The NAP SoH packet is invalid."),
+	(0x8027_0002, "NAP_E_MISSING_SOH", "An SoH was not present in the NAP packet."),
+	(0x8027_0003, "NAP_E_CONFLICTING_ID", "The entity ID conflicts with an already registered id."),
+	(0x8027_0004, "NAP_E_NO_CACHED_SOH", "No cached SoH is present."),
+	(0x8027_0005, "NAP_E_STILL_BOUND", "The entity is still bound to the NAP system."),
+	(0x8027_0006, "NAP_E_NOT_REGISTERED", "The entity is not registered with the NAP system."),
+	(0x8027_0007, "NAP_E_NOT_INITIALIZED", "The entity is not initialized with the NAP system."),
+	(0x8027_0008, "NAP_E_MISMATCHED_ID", "The correlation id in the SoH-Request and SoH-Response do not match up."),
+	(0x8027_0009, "NAP_E_NOT_PENDING", "Completion was indicated on a request that is not currently pending."),
+	(0x8027_000a, "NAP_E_ID_NOT_FOUND", "The NAP component's id was not found."),
+	(0x8027_000b, "NAP_E_MAXSIZE_TOO_SMALL", "The maximum size of the connection is too small for an SoH packet."),
+	(0x8027_000c, "NAP_E_SERVICE_NOT_RUNNING", "The NapAgent service is not running."),
+	(0x8027_000e, "NAP_E_ENTITY_DISABLED", "The entity is disabled with the NapAgent service."),
+	(0x8027_000f, "NAP_E_NETSH_GROUPPOLICY_ERROR", "Group Policy is not configured."),
+	(0x8027_0010, "NAP_E_TOO_MANY_CALLS", "Too many simultaneous calls."),
+	(0x8027_0011, "NAP_E_SHV_CONFIG_EXISTED", "SHV configuration already existed."),
+	(0x8027_0012, "NAP_E_SHV_CONFIG_NOT_FOUND", "SHV configuration is not found."),
+	(0x8027_0013, "NAP_E_SHV_TIMEOUT", "SHV timed out on the request."),
+	(0x8027_0220, "FA_E_MAX_PERSISTED_ITEMS_REACHED", "The maximum number of items for the access list has been reached. An item must be removed before another item is added."),
+	(0x8027_0222, "FA_E_HOMEGROUP_NOT_AVAILABLE", "Cannot access homegroup. Homegroup may not be set up or may have encountered an error."),
+	(0x8027_0250, "E_MONITOR_RESOLUTION_TOO_LOW", "This app can't start because the screen resolution is below 1024x768. Choose a higher screen resolution and then try again."),
+	(0x8027_0251, "E_ELEVATED_ACTIVATION_NOT_SUPPORTED", "This app can't be activated from an elevated context."),
+	(0x8027_0252, "E_UAC_DISABLED", "This app can't be activated when UAC is disabled."),
+	(0x8027_0253, "E_FULL_ADMIN_NOT_SUPPORTED", "This app can't be activated by the Built-in Administrator."),
+	(0x8027_0254, "E_APPLICATION_NOT_REGISTERED", "This app does not support the contract specified or is not installed."),
+	(0x8027_0255, "E_MULTIPLE_EXTENSIONS_FOR_APPLICATION", "This app has multiple extensions registered to support the specified contract. Activation by AppUserModelId is ambiguous."),
+	(0x8027_0256, "E_MULTIPLE_PACKAGES_FOR_FAMILY", "This app's package family has more than one package installed. This is not supported."),
+	(0x8027_0257, "E_APPLICATION_MANAGER_NOT_RUNNING", "The app manager is required to activate applications, but is not running."),
+	(0x8027_025a, "E_APPLICATION_ACTIVATION_TIMED_OUT", "The app didn't start in the required time."),
+	(0x8027_025b, "E_APPLICATION_ACTIVATION_EXEC_FAILURE", "The app didn't start."),
+	(0x8027_025c, "E_APPLICATION_TEMPORARY_LICENSE_ERROR", "This app failed to launch because of an issue with its license. Please try again in a moment."),
+	(0x8027_025d, "E_APPLICATION_TRIAL_LICENSE_EXPIRED", "This app failed to launch because its trial license has expired."),
+	(0x8027_0260, "E_SKYDRIVE_ROOT_TARGET_FILE_SYSTEM_NOT_SUPPORTED", "Please choose a folder on a drive that's formatted with the NTFS file system."),
+	(0x8027_0261, "E_SKYDRIVE_ROOT_TARGET_OVERLAP", "This location is already being used. Please choose a different location."),
+	(0x8027_0262, "E_SKYDRIVE_ROOT_TARGET_CANNOT_INDEX", "This location cannot be indexed. Please choose a different location."),
+	(0x8027_0263, "E_SKYDRIVE_FILE_NOT_UPLOADED", "Sorry, the action couldn't be completed because the file hasn't finished uploading. Try again later."),
+	(0x8027_0264, "E_SKYDRIVE_UPDATE_AVAILABILITY_FAIL", "Sorry, the action couldn't be completed."),
+	(0x8027_0265, "E_SKYDRIVE_ROOT_TARGET_VOLUME_ROOT_NOT_SUPPORTED", "This content can only be moved to a folder. To move the content to this drive, please choose or create a folder."),
+	(0x8028_0000, "TPM_E_ERROR_MASK", "This is an error mask to convert TPM hardware errors to win errors."),
+	(0x8028_0001, "TPM_E_AUTHFAIL", "Authentication failed."),
+	(0x8028_0002, "TPM_E_BADINDEX", "The index to a PCR, DIR or other register is incorrect."),
+	(0x8028_0003, "TPM_E_BAD_PARAMETER", "One or more parameter is bad."),
+	(0x8028_0004, "TPM_E_AUDITFAILURE", "An operation completed successfully but the auditing of that operation failed."),
+	(0x8028_0005, "TPM_E_CLEAR_DISABLED", "The clear disable flag is set and all clear operations now require physical access."),
+	(0x8028_0006, "TPM_E_DEACTIVATED", "Activate the Trusted Platform Module (TPM)."),
+	(0x8028_0007, "TPM_E_DISABLED", "Enable the Trusted Platform Module (TPM)."),
+	(0x8028_0008, "TPM_E_DISABLED_CMD", "The target command has been disabled."),
+	(0x8028_0009, "TPM_E_FAIL", "The operation failed."),
+	(0x8028_000a, "TPM_E_BAD_ORDINAL", "The ordinal was unknown or inconsistent."),
+	(0x8028_000b, "TPM_E_INSTALL_DISABLED", "The ability to install an owner is disabled."),
+	(0x8028_000c, "TPM_E_INVALID_KEYHANDLE", "The key handle cannot be interpreted."),
+	(0x8028_000d, "TPM_E_KEYNOTFOUND", "The key handle points to an invalid key."),
+	(0x8028_000e, "TPM_E_INAPPROPRIATE_ENC", "Unacceptable encryption scheme."),
+	(0x8028_000f, "TPM_E_MIGRATEFAIL", "Migration authorization failed."),
+	(0x8028_0010, "TPM_E_INVALID_PCR_INFO", "PCR information could not be interpreted."),
+	(0x8028_0011, "TPM_E_NOSPACE", "No room to load key."),
+	(0x8028_0012, "TPM_E_NOSRK", "There is no Storage Root Key (SRK) set."),
+	(0x8028_0013, "TPM_E_NOTSEALED_BLOB", "An encrypted blob is invalid or was not created by this TPM."),
+	(0x8028_0014, "TPM_E_OWNER_SET", "The Trusted Platform Module (TPM) already has an owner."),
+	(0x8028_0015, "TPM_E_RESOURCES", "The TPM has insufficient internal resources to perform the requested action."),
+	(0x8028_0016, "TPM_E_SHORTRANDOM", "A random string was too short."),
+	(0x8028_0017, "TPM_E_SIZE", "The TPM does not have the space to perform the operation."),
+	(0x8028_0018, "TPM_E_WRONGPCRVAL", "The named PCR value does not match the current PCR value."),
+	(0x8028_0019, "TPM_E_BAD_PARAM_SIZE", "The paramSize argument to the command has the incorrect value."),
+	(0x8028_001a, "TPM_E_SHA_THREAD", "There is no existing SHA-1 thread."),
+	(0x8028_001b, "TPM_E_SHA_ERROR", "The calculation is unable to proceed because the existing SHA-1 thread has already encountered an error."),
+	(0x8028_001c, "TPM_E_FAILEDSELFTEST", "The TPM hardware device reported a failure during its internal self test. Try restarting the computer to resolve the problem."),
+	(0x8028_001d, "TPM_E_AUTH2FAIL", "The authorization for the second key in a 2 key function failed authorization."),
+	(0x8028_001e, "TPM_E_BADTAG", "The tag value sent to for a command is invalid."),
+	(0x8028_001f, "TPM_E_IOERROR", "An IO error occurred transmitting information to the TPM."),
+	(0x8028_0020, "TPM_E_ENCRYPT_ERROR", "The encryption process had a problem."),
+	(0x8028_0021, "TPM_E_DECRYPT_ERROR", "The decryption process did not complete."),
+	(0x8028_0022, "TPM_E_INVALID_AUTHHANDLE", "An invalid handle was used."),
+	(0x8028_0023, "TPM_E_NO_ENDORSEMENT", "The TPM does not have an Endorsement Key (EK) installed."),
+	(0x8028_0024, "TPM_E_INVALID_KEYUSAGE", "The usage of a key is not allowed."),
+	(0x8028_0025, "TPM_E_WRONG_ENTITYTYPE", "The submitted entity type is not allowed."),
+	(0x8028_0026, "TPM_E_INVALID_POSTINIT", "The command was received in the wrong sequence relative to TPM_Init and a subsequent TPM_Startup."),
+	(0x8028_0027, "TPM_E_INAPPROPRIATE_SIG", "Signed data cannot include additional DER information."),
+	(0x8028_0028, "TPM_E_BAD_KEY_PROPERTY", "The key properties in TPM_KEY_PARMs are not supported by this TPM."),
+	(0x8028_0029, "TPM_E_BAD_MIGRATION", "The migration properties of this key are incorrect."),
+	(0x8028_002a, "TPM_E_BAD_SCHEME", "The signature or encryption scheme for this key is incorrect or not permitted in this situation."),
+	(0x8028_002b, "TPM_E_BAD_DATASIZE", "The size of the data (or blob) parameter is bad or inconsistent with the referenced key."),
+	(0x8028_002c, "TPM_E_BAD_MODE", "A mode parameter is bad, such as capArea or subCapArea for TPM_GetCapability, physicalPresence parameter for TPM_PhysicalPresence, or migrationType for TPM_CreateMigrationBlob."),
+	(0x8028_002d, "TPM_E_BAD_PRESENCE", "Either the physicalPresence or physicalPresenceLock bits have the wrong value."),
+	(0x8028_002e, "TPM_E_BAD_VERSION", "The TPM cannot perform this version of the capability."),
+	(0x8028_002f, "TPM_E_NO_WRAP_TRANSPORT", "The TPM does not allow for wrapped transport sessions."),
+	(0x8028_0030, "TPM_E_AUDITFAIL_UNSUCCESSFUL", "TPM audit construction failed and the underlying command was returning a failure code also."),
+	(0x8028_0031, "TPM_E_AUDITFAIL_SUCCESSFUL", "TPM audit construction failed and the underlying command was returning success."),
+	(0x8028_0032, "TPM_E_NOTRESETABLE", "Attempt to reset a PCR register that does not have the resettable attribute."),
+	(0x8028_0033, "TPM_E_NOTLOCAL", "Attempt to reset a PCR register that requires locality and locality modifier not part of command transport."),
+	(0x8028_0034, "TPM_E_BAD_TYPE", "Make identity blob not properly typed."),
+	(0x8028_0035, "TPM_E_INVALID_RESOURCE", "When saving context identified resource type does not match actual resource."),
+	(0x8028_0036, "TPM_E_NOTFIPS", "The TPM is attempting to execute a command only available when in FIPS mode."),
+	(0x8028_0037, "TPM_E_INVALID_FAMILY", "The command is attempting to use an invalid family ID."),
+	(0x8028_0038, "TPM_E_NO_NV_PERMISSION", "The permission to manipulate the NV storage is not available."),
+	(0x8028_0039, "TPM_E_REQUIRES_SIGN", "The operation requires a signed command."),
+	(0x8028_003a, "TPM_E_KEY_NOTSUPPORTED", "Wrong operation to load an NV key."),
+	(0x8028_003b, "TPM_E_AUTH_CONFLICT", "NV_LoadKey blob requires both owner and blob authorization."),
+	(0x8028_003c, "TPM_E_AREA_LOCKED", "The NV area is locked and not writable."),
+	(0x8028_003d, "TPM_E_BAD_LOCALITY", "The locality is incorrect for the attempted operation."),
+	(0x8028_003e, "TPM_E_READ_ONLY", "The NV area is read only and can't be written to."),
+	(0x8028_003f, "TPM_E_PER_NOWRITE", "There is no protection on the write to the NV area."),
+	(0x8028_0040, "TPM_E_FAMILYCOUNT", "The family count value does not match."),
+	(0x8028_0041, "TPM_E_WRITE_LOCKED", "The NV area has already been written to."),
+	(0x8028_0042, "TPM_E_BAD_ATTRIBUTES", "The NV area attributes conflict."),
+	(0x8028_0043, "TPM_E_INVALID_STRUCTURE", "The structure tag and version are invalid or inconsistent."),
+	(0x8028_0044, "TPM_E_KEY_OWNER_CONTROL", "The key is under control of the TPM Owner and can only be evicted by the TPM Owner."),
+	(0x8028_0045, "TPM_E_BAD_COUNTER", "The counter handle is incorrect."),
+	(0x8028_0046, "TPM_E_NOT_FULLWRITE", "The write is not a complete write of the area."),
+	(0x8028_0047, "TPM_E_CONTEXT_GAP", "The gap between saved context counts is too large."),
+	(0x8028_0048, "TPM_E_MAXNVWRITES", "The maximum number of NV writes without an owner has been exceeded."),
+	(0x8028_0049, "TPM_E_NOOPERATOR", "No operator AuthData value is set."),
+	(0x8028_004a, "TPM_E_RESOURCEMISSING", "The resource pointed to by context is not loaded."),
+	(0x8028_004b, "TPM_E_DELEGATE_LOCK", "The delegate administration is locked."),
+	(0x8028_004c, "TPM_E_DELEGATE_FAMILY", "Attempt to manage a family other than the delegated family."),
+	(0x8028_004d, "TPM_E_DELEGATE_ADMIN", "Delegation table management not enabled."),
+	(0x8028_004e, "TPM_E_TRANSPORT_NOTEXCLUSIVE", "There was a command executed outside of an exclusive transport session."),
+	(0x8028_004f, "TPM_E_OWNER_CONTROL", "Attempt to context save an owner evict controlled key."),
+	(0x8028_0050, "TPM_E_DAA_RESOURCES", "The DAA command has no resources available to execute the command."),
+	(0x8028_0051, "TPM_E_DAA_INPUT_DATA0", "The consistency check on DAA parameter inputData0 has failed."),
+	(0x8028_0052, "TPM_E_DAA_INPUT_DATA1", "The consistency check on DAA parameter inputData1 has failed."),
+	(0x8028_0053, "TPM_E_DAA_ISSUER_SETTINGS", "The consistency check on DAA_issuerSettings has failed."),
+	(0x8028_0054, "TPM_E_DAA_TPM_SETTINGS", "The consistency check on DAA_tpmSpecific has failed."),
+	(0x8028_0055, "TPM_E_DAA_STAGE", "The atomic process indicated by the submitted DAA command is not the expected process."),
+	(0x8028_0056, "TPM_E_DAA_ISSUER_VALIDITY", "The issuer's validity check has detected an inconsistency."),
+	(0x8028_0057, "TPM_E_DAA_WRONG_W", "The consistency check on w has failed."),
+	(0x8028_0058, "TPM_E_BAD_HANDLE", "The handle is incorrect."),
+	(0x8028_0059, "TPM_E_BAD_DELEGATE", "Delegation is not correct."),
+	(0x8028_005a, "TPM_E_BADCONTEXT", "The context blob is invalid."),
+	(0x8028_005b, "TPM_E_TOOMANYCONTEXTS", "Too many contexts held by the TPM."),
+	(0x8028_005c, "TPM_E_MA_TICKET_SIGNATURE", "Migration authority signature validation failure."),
+	(0x8028_005d, "TPM_E_MA_DESTINATION", "Migration destination not authenticated."),
+	(0x8028_005e, "TPM_E_MA_SOURCE", "Migration source incorrect."),
+	(0x8028_005f, "TPM_E_MA_AUTHORITY", "Incorrect migration authority."),
+	(0x8028_0061, "TPM_E_PERMANENTEK", "Attempt to revoke the EK and the EK is not revocable."),
+	(0x8028_0062, "TPM_E_BAD_SIGNATURE", "Bad signature of CMK ticket."),
+	(0x8028_0063, "TPM_E_NOCONTEXTSPACE", "There is no room in the context list for additional contexts."),
+	(0x8028_0081, "TPM_20_E_ASYMMETRIC", "TPM 2.0: Asymmetric algorithm not supported or not correct."),
+	(0x8028_0082, "TPM_20_E_ATTRIBUTES", "TPM 2.0: Inconsistent attributes."),
+	(0x8028_0083, "TPM_20_E_HASH", "TPM 2.0: Hash algorithm not supported or not appropriate."),
+	(0x8028_0084, "TPM_20_E_VALUE", "TPM 2.0: Value is out of range or is not correct for the context."),
+	(0x8028_0085, "TPM_20_E_HIERARCHY", "TPM 2.0: Hierarchy is not enabled or is not correct for the use."),
+	(0x8028_0087, "TPM_20_E_KEY_SIZE", "TPM 2.0: Key size is not supported."),
+	(0x8028_0088, "TPM_20_E_MGF", "TPM 2.0: Mask generation function not supported."),
+	(0x8028_0089, "TPM_20_E_MODE", "TPM 2.0: Mode of operation not supported."),
+	(0x8028_008a, "TPM_20_E_TYPE", "TPM 2.0: The type of the value is not appropriate for the use."),
+	(0x8028_008b, "TPM_20_E_HANDLE", "TPM 2.0: The Handle is not correct for the use."),
+	(0x8028_008c, "TPM_20_E_KDF", "TPM 2.0: Unsupported key derivation function or function not appropriate for use."),
+	(0x8028_008d, "TPM_20_E_RANGE", "TPM 2.0: Value was out of allowed range."),
+	(0x8028_008e, "TPM_20_E_AUTH_FAIL", "TPM 2.0: The authorization HMAC check failed and DA counter incremented."),
+	(0x8028_008f, "TPM_20_E_NONCE", "TPM 2.0: Invalid nonce size."),
+	(0x8028_0090, "TPM_20_E_PP", "TPM 2.0: Authorization requires assertion of PP."),
+	(0x8028_0092, "TPM_20_E_SCHEME", "TPM 2.0: Unsupported or incompatible scheme."),
+	(0x8028_0095, "TPM_20_E_SIZE", "TPM 2.0: Structure is wrong size."),
+	(0x8028_0096, "TPM_20_E_SYMMETRIC", "TPM 2.0: Unsupported symmetric algorithm or key size, or not appropriate for instance."),
+	(0x8028_0097, "TPM_20_E_TAG", "TPM 2.0: Incorrect structure tag."),
+	(0x8028_0098, "TPM_20_E_SELECTOR", "TPM 2.0: Union selector is incorrect."),
+	(0x8028_009a, "TPM_20_E_INSUFFICIENT", "TPM 2.0: The TPM was unable to unmarshal a value because there were not enough octets in the input buffer."),
+	(0x8028_009b, "TPM_20_E_SIGNATURE", "TPM 2.0: The signature is not valid."),
+	(0x8028_009c, "TPM_20_E_KEY", "TPM 2.0: Key fields are not compatible with the selected use."),
+	(0x8028_009d, "TPM_20_E_POLICY_FAIL", "TPM 2.0: A policy check failed."),
+	(0x8028_009f, "TPM_20_E_INTEGRITY", "TPM 2.0: Integrity check failed."),
+	(0x8028_00a0, "TPM_20_E_TICKET", "TPM 2.0: Invalid ticket."),
+	(0x8028_00a1, "TPM_20_E_RESERVED_BITS", "TPM 2.0: Reserved bits not set to zero as required."),
+	(0x8028_00a2, "TPM_20_E_BAD_AUTH", "TPM 2.0: Authorization failure without DA implications."),
+	(0x8028_00a3, "TPM_20_E_EXPIRED", "TPM 2.0: The policy has expired."),
+	(0x8028_00a4, "TPM_20_E_POLICY_CC", "TPM 2.0: The command code in the policy is not the command code of the command or the command code in a policy command references a command that is not implemented."),
+	(0x8028_00a5, "TPM_20_E_BINDING", "TPM 2.0: Public and sensitive portions of an object are not cryptographically bound."),
+	(0x8028_00a6, "TPM_20_E_CURVE", "TPM 2.0: Curve not supported."),
+	(0x8028_00a7, "TPM_20_E_ECC_POINT", "TPM 2.0: Point is not on the required curve."),
+	(0x8028_0100, "TPM_20_E_INITIALIZE", "TPM 2.0: TPM not initialized."),
+	(0x8028_0101, "TPM_20_E_FAILURE", "TPM 2.0: Commands not being accepted because of a TPM failure."),
+	(0x8028_0103, "TPM_20_E_SEQUENCE", "TPM 2.0: Improper use of a sequence handle."),
+	(0x8028_010b, "TPM_20_E_PRIVATE", "TPM 2.0: TPM_RC_PRIVATE error."),
+	(0x8028_0119, "TPM_20_E_HMAC", "TPM 2.0: TPM_RC_HMAC."),
+	(0x8028_0120, "TPM_20_E_DISABLED", "TPM 2.0: TPM_RC_DISABLED."),
+	(0x8028_0121, "TPM_20_E_EXCLUSIVE", "TPM 2.0: Command failed because audit sequence required exclusivity."),
+	(0x8028_0123, "TPM_20_E_ECC_CURVE", "TPM 2.0: Unsupported ECC curve."),
+	(0x8028_0124, "TPM_20_E_AUTH_TYPE", "TPM 2.0: Authorization handle is not correct for command."),
+	(0x8028_0125, "TPM_20_E_AUTH_MISSING", "TPM 2.0: Command requires an authorization session for handle and is not present."),
+	(0x8028_0126, "TPM_20_E_POLICY", "TPM 2.0: Policy failure in Math Operation or an invalid authPolicy value."),
+	(0x8028_0127, "TPM_20_E_PCR", "TPM 2.0: PCR check fail."),
+	(0x8028_0128, "TPM_20_E_PCR_CHANGED", "TPM 2.0: PCR have changed since checked."),
+	(0x8028_012d, "TPM_20_E_UPGRADE", "TPM 2.0: The TPM is not in the right mode for upgrade."),
+	(0x8028_012e, "TPM_20_E_TOO_MANY_CONTEXTS", "TPM 2.0: Context ID counter is at maximum."),
+	(0x8028_012f, "TPM_20_E_AUTH_UNAVAILABLE", "TPM 2.0: authValue or authPolicy is not available for selected entity."),
+	(0x8028_0130, "TPM_20_E_REBOOT", "TPM 2.0: A _TPM_Init and Startup(CLEAR) is required before the TPM can resume operation."),
+	(0x8028_0131, "TPM_20_E_UNBALANCED", "TPM 2.0: The protection algorithms (hash and symmetric) are not reasonably balanced. The digest size of the hash must be larger than the key size of the symmetric algorithm."),
+	(0x8028_0142, "TPM_20_E_COMMAND_SIZE", "TPM 2.0: The TPM command's commandSize value is inconsistent with contents of the command buffer; either the size is not the same as the bytes loaded by the hardware interface layer or the value is not large enough to hold a command header."),
+	(0x8028_0143, "TPM_20_E_COMMAND_CODE", "TPM 2.0: Command code not supported."),
+	(0x8028_0144, "TPM_20_E_AUTHSIZE", "TPM 2.0: The value of authorizationSize is out of range or the number of octets in the authorization Area is greater than required."),
+	(0x8028_0145, "TPM_20_E_AUTH_CONTEXT", "TPM 2.0: Use of an authorization session with a context command or another command that cannot have an authorization session."),
+	(0x8028_0146, "TPM_20_E_NV_RANGE", "TPM 2.0: NV offset+size is out of range."),
+	(0x8028_0147, "TPM_20_E_NV_SIZE", "TPM 2.0: Requested allocation size is larger than allowed."),
+	(0x8028_0148, "TPM_20_E_NV_LOCKED", "TPM 2.0: NV access locked."),
+	(0x8028_0149, "TPM_20_E_NV_AUTHORIZATION", "TPM 2.0: NV access authorization fails in command actions."),
+	(0x8028_014a, "TPM_20_E_NV_UNINITIALIZED", "TPM 2.0: An NV index is used before being initialized or the state saved by TPM2_Shutdown(STATE) could not be restored."),
+	(0x8028_014b, "TPM_20_E_NV_SPACE", "TPM 2.0: Insufficient space for NV allocation."),
+	(0x8028_014c, "TPM_20_E_NV_DEFINED", "TPM 2.0: NV index or persistent object already defined."),
+	(0x8028_0150, "TPM_20_E_BAD_CONTEXT", "TPM 2.0: Context in TPM2_ContextLoad() is not valid."),
+	(0x8028_0151, "TPM_20_E_CPHASH", "TPM 2.0: chHash value already set or not correct for use."),
+	(0x8028_0152, "TPM_20_E_PARENT", "TPM 2.0: Handle for parent is not a valid parent."),
+	(0x8028_0153, "TPM_20_E_NEEDS_TEST", "TPM 2.0: Some function needs testing."),
+	(0x8028_0154, "TPM_20_E_NO_RESULT", "TPM 2.0: Returned when an internal function cannot process a request due to an unspecified problem. This code is usually related to invalid parameters that are not properly filtered by the input unmarshaling code."),
+	(0x8028_0155, "TPM_20_E_SENSITIVE", "TPM 2.0: The sensitive area did not unmarshal correctly after decryption - this code is used in lieu of the other unmarshaling errors so that an attacker cannot determine where the unmarshaling error occurred."),
+	(0x8028_0400, "TPM_E_COMMAND_BLOCKED", "The command was blocked."),
+	(0x8028_0401, "TPM_E_INVALID_HANDLE", "The specified handle was not found."),
+	(0x8028_0402, "TPM_E_DUPLICATE_VHANDLE", "The TPM returned a duplicate handle and the command needs to be resubmitted."),
+	(0x8028_0403, "TPM_E_EMBEDDED_COMMAND_BLOCKED", "The command within the transport was blocked."),
+	(0x8028_0404, "TPM_E_EMBEDDED_COMMAND_UNSUPPORTED", "The command within the transport is not supported."),
+	(0x8028_0800, "TPM_E_RETRY", "The TPM is too busy to respond to the command immediately, but the command could be resubmitted at a later time."),
+	(0x8028_0801, "TPM_E_NEEDS_SELFTEST", "SelfTestFull has not been run."),
+	(0x8028_0802, "TPM_E_DOING_SELFTEST", "The TPM is currently executing a full selftest."),
+	(0x8028_0803, "TPM_E_DEFEND_LOCK_RUNNING", "The TPM is defending against dictionary attacks and is in a time-out period."),
+	(0x8028_0901, "TPM_20_E_CONTEXT_GAP", "TPM 2.0: Gap for context ID is too large."),
+	(0x8028_0902, "TPM_20_E_OBJECT_MEMORY", "TPM 2.0: Out of memory for object contexts."),
+	(0x8028_0903, "TPM_20_E_SESSION_MEMORY", "TPM 2.0: Out of memory for session contexts."),
+	(0x8028_0904, "TPM_20_E_MEMORY", "TPM 2.0: Out of shared object/session memory or need space for internal operations."),
+	(0x8028_0905, "TPM_20_E_SESSION_HANDLES", "TPM 2.0: Out of session handles - a session must be flushed before a new session may be created."),
+	(0x8028_0906, "TPM_20_E_OBJECT_HANDLES", "TPM 2.0: Out of object handles - the handle space for objects is depleted and a reboot is required."),
+	(0x8028_0907, "TPM_20_E_LOCALITY", "TPM 2.0: Bad locality."),
+	(0x8028_0908, "TPM_20_E_YIELDED", "TPM 2.0: The TPM has suspended operation on the command; forward progress was made and the command may be retried."),
+	(0x8028_0909, "TPM_20_E_CANCELED", "TPM 2.0: The command was canceled."),
+	(0x8028_090a, "TPM_20_E_TESTING", "TPM 2.0: TPM is performing self-tests."),
+	(0x8028_0920, "TPM_20_E_NV_RATE", "TPM 2.0: The TPM is rate-limiting accesses to prevent wearout of NV."),
+	(0x8028_0921, "TPM_20_E_LOCKOUT", "TPM 2.0: Authorization for objects subject to DA protection are not allowed at this time because the TPM is in DA lockout mode."),
+	(0x8028_0922, "TPM_20_E_RETRY", "TPM 2.0: The TPM was not able to start the command."),
+	(0x8028_0923, "TPM_20_E_NV_UNAVAILABLE", "TPM 2.0: The command may require writing of NV and NV is not current accessible."),
+	(0x8028_4001, "TBS_E_INTERNAL_ERROR", "An internal error has occurred within the Trusted Platform Module support program."),
+	(0x8028_4002, "TBS_E_BAD_PARAMETER", "One or more input parameters is bad."),
+	(0x8028_4003, "TBS_E_INVALID_OUTPUT_POINTER", "A specified output pointer is bad."),
+	(0x8028_4004, "TBS_E_INVALID_CONTEXT", "The specified context handle does not refer to a valid context."),
+	(0x8028_4005, "TBS_E_INSUFFICIENT_BUFFER", "A specified output buffer is too small."),
+	(0x8028_4006, "TBS_E_IOERROR", "An error occurred while communicating with the TPM."),
+	(0x8028_4007, "TBS_E_INVALID_CONTEXT_PARAM", "One or more context parameters is invalid."),
+	(0x8028_4008, "TBS_E_SERVICE_NOT_RUNNING", "The TBS service is not running and could not be started."),
+	(0x8028_4009, "TBS_E_TOO_MANY_TBS_CONTEXTS", "A new context could not be created because there are too many open contexts."),
+	(0x8028_400a, "TBS_E_TOO_MANY_RESOURCES", "A new virtual resource could not be created because there are too many open virtual resources."),
+	(0x8028_400b, "TBS_E_SERVICE_START_PENDING", "The TBS service has been started but is not yet running."),
+	(0x8028_400c, "TBS_E_PPI_NOT_SUPPORTED", "The physical presence interface is not supported."),
+	(0x8028_400d, "TBS_E_COMMAND_CANCELED", "The command was canceled."),
+	(0x8028_400e, "TBS_E_BUFFER_TOO_LARGE", "The input or output buffer is too large."),
+	(0x8028_400f, "TBS_E_TPM_NOT_FOUND", "A compatible Trusted Platform Module (TPM) Security Device cannot be found on this computer."),
+	(0x8028_4010, "TBS_E_SERVICE_DISABLED", "The TBS service has been disabled."),
+	(0x8028_4011, "TBS_E_NO_EVENT_LOG", "No TCG event log is available."),
+	(0x8028_4012, "TBS_E_ACCESS_DENIED", "The caller does not have the appropriate rights to perform the requested operation."),
+	(0x8028_4013, "TBS_E_PROVISIONING_NOT_ALLOWED", "The TPM provisioning action is not allowed by the specified flags. For provisioning to be successful, one of several actions may be required. The TPM management console (tpm.msc) action to make the TPM Ready may help. For further information, see the documentation for the Win32_Tpm WMI method 'Provision'."),
+	(0x8028_4014, "TBS_E_PPI_FUNCTION_UNSUPPORTED", "The Physical Presence Interface of this firmware does not support the requested method."),
+	(0x8028_4015, "TBS_E_OWNERAUTH_NOT_FOUND", "The requested TPM OwnerAuth value was not found."),
+	(0x8028_4016, "TBS_E_PROVISIONING_INCOMPLETE", "The TPM provisioning did not complete. For more information on completing the provisioning, call the Win32_Tpm WMI method for provisioning the TPM ('Provision') and check the returned Information."),
+	(0x8029_0100, "TPMAPI_E_INVALID_STATE", "The command buffer is not in the correct state."),
+	(0x8029_0101, "TPMAPI_E_NOT_ENOUGH_DATA", "The command buffer does not contain enough data to satisfy the request."),
+	(0x8029_0102, "TPMAPI_E_TOO_MUCH_DATA", "The command buffer cannot contain any more data."),
+	(0x8029_0103, "TPMAPI_E_INVALID_OUTPUT_POINTER", "One or more output parameters was NULL or invalid."),
+	(0x8029_0104, "TPMAPI_E_INVALID_PARAMETER", "One or more input parameters is invalid."),
+	(0x8029_0105, "TPMAPI_E_OUT_OF_MEMORY", "Not enough memory was available to satisfy the request."),
+	(0x8029_0106, "TPMAPI_E_BUFFER_TOO_SMALL", "The specified buffer was too small."),
+	(0x8029_0107, "TPMAPI_E_INTERNAL_ERROR", "An internal error was detected."),
+	(0x8029_0108, "TPMAPI_E_ACCESS_DENIED", "The caller does not have the appropriate rights to perform the requested operation."),
+	(0x8029_0109, "TPMAPI_E_AUTHORIZATION_FAILED", "The specified authorization information was invalid."),
+	(0x8029_010a, "TPMAPI_E_INVALID_CONTEXT_HANDLE", "The specified context handle was not valid."),
+	(0x8029_010b, "TPMAPI_E_TBS_COMMUNICATION_ERROR", "An error occurred while communicating with the TBS."),
+	(0x8029_010c, "TPMAPI_E_TPM_COMMAND_ERROR", "The TPM returned an unexpected result."),
+	(0x8029_010d, "TPMAPI_E_MESSAGE_TOO_LARGE", "The message was too large for the encoding scheme."),
+	(0x8029_010e, "TPMAPI_E_INVALID_ENCODING", "The encoding in the blob was not recognized."),
+	(0x8029_010f, "TPMAPI_E_INVALID_KEY_SIZE", "The key size is not valid."),
+	(0x8029_0110, "TPMAPI_E_ENCRYPTION_FAILED", "The encryption operation failed."),
+	(0x8029_0111, "TPMAPI_E_INVALID_KEY_PARAMS", "The key parameters structure was not valid."),
+	(0x8029_0112, "TPMAPI_E_INVALID_MIGRATION_AUTHORIZATION_BLOB", "The requested supplied data does not appear to be a valid migration authorization blob."),
+	(0x8029_0113, "TPMAPI_E_INVALID_PCR_INDEX", "The specified PCR index was invalid."),
+	(0x8029_0114, "TPMAPI_E_INVALID_DELEGATE_BLOB", "The data given does not appear to be a valid delegate blob."),
+	(0x8029_0115, "TPMAPI_E_INVALID_CONTEXT_PARAMS", "One or more of the specified context parameters was not valid."),
+	(0x8029_0116, "TPMAPI_E_INVALID_KEY_BLOB", "The data given does not appear to be a valid key blob."),
+	(0x8029_0117, "TPMAPI_E_INVALID_PCR_DATA", "The specified PCR data was invalid."),
+	(0x8029_0118, "TPMAPI_E_INVALID_OWNER_AUTH", "The format of the owner auth data was invalid."),
+	(0x8029_0119, "TPMAPI_E_FIPS_RNG_CHECK_FAILED", "The random number generated did not pass FIPS RNG check."),
+	(0x8029_011a, "TPMAPI_E_EMPTY_TCG_LOG", "The TCG Event Log does not contain any data."),
+	(0x8029_011b, "TPMAPI_E_INVALID_TCG_LOG_ENTRY", "An entry in the TCG Event Log was invalid."),
+	(0x8029_011c, "TPMAPI_E_TCG_SEPARATOR_ABSENT", "A TCG Separator was not found."),
+	(0x8029_011d, "TPMAPI_E_TCG_INVALID_DIGEST_ENTRY", "A digest value in a TCG Log entry did not match hashed data."),
+	(0x8029_011e, "TPMAPI_E_POLICY_DENIES_OPERATION", "The requested operation was blocked by current TPM policy. Please contact your system administrator for assistance."),
+	(0x8029_011f, "TPMAPI_E_NV_BITS_NOT_DEFINED", "The Windows TPM NV bits are not defined."),
+	(0x8029_0120, "TPMAPI_E_NV_BITS_NOT_READY", "The Windows TPM NV bits are not ready for use."),
+	(0x8029_0121, "TPMAPI_E_SEALING_KEY_NOT_AVAILABLE", "The sealing key is not available."),
+	(0x8029_0122, "TPMAPI_E_NO_AUTHORIZATION_CHAIN_FOUND", "No authorization chain was found."),
+	(0x8029_0123, "TPMAPI_E_SVN_COUNTER_NOT_AVAILABLE", "The SVN counter is not available."),
+	(0x8029_0124, "TPMAPI_E_OWNER_AUTH_NOT_NULL", "The TPM owner authorization value is not null."),
+	(0x8029_0125, "TPMAPI_E_ENDORSEMENT_AUTH_NOT_NULL", "The TPM endorsement authorization value is not null."),
+	(0x8029_0126, "TPMAPI_E_AUTHORIZATION_REVOKED", "The authorization has been revoked."),
+	(0x8029_0127, "TPMAPI_E_MALFORMED_AUTHORIZATION_KEY", "The authorization key is malformed."),
+	(0x8029_0128, "TPMAPI_E_AUTHORIZING_KEY_NOT_SUPPORTED", "The authorizing key is not supported."),
+	(0x8029_0129, "TPMAPI_E_INVALID_AUTHORIZATION_SIGNATURE", "The authorization signature is invalid."),
+	(0x8029_012a, "TPMAPI_E_MALFORMED_AUTHORIZATION_POLICY", "The authorization policy is malformed."),
+	(0x8029_012b, "TPMAPI_E_MALFORMED_AUTHORIZATION_OTHER", "The authorization data is malformed."),
+	(0x8029_012c, "TPMAPI_E_SEALING_KEY_CHANGED", "The sealing key has changed."),
+	(0x8029_0200, "TBSIMP_E_BUFFER_TOO_SMALL", "The specified buffer was too small."),
+	(0x8029_0201, "TBSIMP_E_CLEANUP_FAILED", "The context could not be cleaned up."),
+	(0x8029_0202, "TBSIMP_E_INVALID_CONTEXT_HANDLE", "The specified context handle is invalid."),
+	(0x8029_0203, "TBSIMP_E_INVALID_CONTEXT_PARAM", "An invalid context parameter was specified."),
+	(0x8029_0204, "TBSIMP_E_TPM_ERROR", "An error occurred while communicating with the TPM."),
+	(0x8029_0205, "TBSIMP_E_HASH_BAD_KEY", "No entry with the specified key was found."),
+	(0x8029_0206, "TBSIMP_E_DUPLICATE_VHANDLE", "The specified virtual handle matches a virtual handle already in use."),
+	(0x8029_0207, "TBSIMP_E_INVALID_OUTPUT_POINTER", "The pointer to the returned handle location was NULL or invalid."),
+	(0x8029_0208, "TBSIMP_E_INVALID_PARAMETER", "One or more parameters is invalid."),
+	(0x8029_0209, "TBSIMP_E_RPC_INIT_FAILED", "The RPC subsystem could not be initialized."),
+	(0x8029_020a, "TBSIMP_E_SCHEDULER_NOT_RUNNING", "The TBS scheduler is not running."),
+	(0x8029_020b, "TBSIMP_E_COMMAND_CANCELED", "The command was canceled."),
+	(0x8029_020c, "TBSIMP_E_OUT_OF_MEMORY", "There was not enough memory to fulfill the request."),
+	(0x8029_020d, "TBSIMP_E_LIST_NO_MORE_ITEMS", "The specified list is empty, or the iteration has reached the end of the list."),
+	(0x8029_020e, "TBSIMP_E_LIST_NOT_FOUND", "The specified item was not found in the list."),
+	(0x8029_020f, "TBSIMP_E_NOT_ENOUGH_SPACE", "The TPM does not have enough space to load the requested resource."),
+	(0x8029_0210, "TBSIMP_E_NOT_ENOUGH_TPM_CONTEXTS", "There are too many TPM contexts in use."),
+	(0x8029_0211, "TBSIMP_E_COMMAND_FAILED", "The TPM command failed."),
+	(0x8029_0212, "TBSIMP_E_UNKNOWN_ORDINAL", "The TBS does not recognize the specified ordinal."),
+	(0x8029_0213, "TBSIMP_E_RESOURCE_EXPIRED", "The requested resource is no longer available."),
+	(0x8029_0214, "TBSIMP_E_INVALID_RESOURCE", "The resource type did not match."),
+	(0x8029_0215, "TBSIMP_E_NOTHING_TO_UNLOAD", "No resources can be unloaded."),
+	(0x8029_0216, "TBSIMP_E_HASH_TABLE_FULL", "No new entries can be added to the hash table."),
+	(0x8029_0217, "TBSIMP_E_TOO_MANY_TBS_CONTEXTS", "A new TBS context could not be created because there are too many open contexts."),
+	(0x8029_0218, "TBSIMP_E_TOO_MANY_RESOURCES", "A new virtual resource could not be created because there are too many open virtual resources."),
+	(0x8029_0219, "TBSIMP_E_PPI_NOT_SUPPORTED", "The physical presence interface is not supported."),
+	(0x8029_021a, "TBSIMP_E_TPM_INCOMPATIBLE", "TBS is not compatible with the version of TPM found on the system."),
+	(0x8029_021b, "TBSIMP_E_NO_EVENT_LOG", "No TCG event log is available."),
+	(0x8029_0300, "TPM_E_PPI_ACPI_FAILURE", "A general error was detected when attempting to acquire the BIOS's response to a Physical Presence command."),
+	(0x8029_0301, "TPM_E_PPI_USER_ABORT", "The user failed to confirm the TPM operation request."),
+	(0x8029_0302, "TPM_E_PPI_BIOS_FAILURE", "The BIOS failure prevented the successful execution of the requested TPM operation (e.g. invalid TPM operation request, BIOS communication error with the TPM)."),
+	(0x8029_0303, "TPM_E_PPI_NOT_SUPPORTED", "The BIOS does not support the physical presence interface."),
+	(0x8029_0304, "TPM_E_PPI_BLOCKED_IN_BIOS", "The Physical Presence command was blocked by current BIOS settings. The system owner may be able to reconfigure the BIOS settings to allow the command."),
+	(0x8029_0400, "TPM_E_PCP_ERROR_MASK", "This is an error mask to convert Platform Crypto Provider errors to win errors."),
+	(0x8029_0401, "TPM_E_PCP_DEVICE_NOT_READY", "The Platform Crypto Device is currently not ready. It needs to be fully provisioned to be operational."),
+	(0x8029_0402, "TPM_E_PCP_INVALID_HANDLE", "The handle provided to the Platform Crypto Provider is invalid."),
+	(0x8029_0403, "TPM_E_PCP_INVALID_PARAMETER", "A parameter provided to the Platform Crypto Provider is invalid."),
+	(0x8029_0404, "TPM_E_PCP_FLAG_NOT_SUPPORTED", "A provided flag to the Platform Crypto Provider is not supported."),
+	(0x8029_0405, "TPM_E_PCP_NOT_SUPPORTED", "The requested operation is not supported by this Platform Crypto Provider."),
+	(0x8029_0406, "TPM_E_PCP_BUFFER_TOO_SMALL", "The buffer is too small to contain all data. No information has been written to the buffer."),
+	(0x8029_0407, "TPM_E_PCP_INTERNAL_ERROR", "An unexpected internal error has occurred in the Platform Crypto Provider."),
+	(0x8029_0408, "TPM_E_PCP_AUTHENTICATION_FAILED", "The authorization to use a provider object has failed."),
+	(0x8029_0409, "TPM_E_PCP_AUTHENTICATION_IGNORED", "The Platform Crypto Device has ignored the authorization for the provider object, to mitigate against a dictionary attack."),
+	(0x8029_040a, "TPM_E_PCP_POLICY_NOT_FOUND", "The referenced policy was not found."),
+	(0x8029_040b, "TPM_E_PCP_PROFILE_NOT_FOUND", "The referenced profile was not found."),
+	(0x8029_040c, "TPM_E_PCP_VALIDATION_FAILED", "The validation was not successful."),
+	(0x8029_040e, "TPM_E_PCP_WRONG_PARENT", "An attempt was made to import or load a key under an incorrect storage parent."),
+	(0x8029_040f, "TPM_E_KEY_NOT_LOADED", "The TPM key is not loaded."),
+	(0x8029_0410, "TPM_E_NO_KEY_CERTIFICATION", "The TPM key certification has not been generated."),
+	(0x8029_0411, "TPM_E_KEY_NOT_FINALIZED", "The TPM key is not yet finalized."),
+	(0x8029_0412, "TPM_E_ATTESTATION_CHALLENGE_NOT_SET", "The TPM attestation challenge is not set."),
+	(0x8029_0413, "TPM_E_NOT_PCR_BOUND", "The TPM PCR info is not available."),
+	(0x8029_0414, "TPM_E_KEY_ALREADY_FINALIZED", "The TPM key is already finalized."),
+	(0x8029_0415, "TPM_E_KEY_USAGE_POLICY_NOT_SUPPORTED", "The TPM key usage policy is not supported."),
+	(0x8029_0416, "TPM_E_KEY_USAGE_POLICY_INVALID", "The TPM key usage policy is invalid."),
+	(0x8029_0417, "TPM_E_SOFT_KEY_ERROR", "There was a problem with the software key being imported into the TPM."),
+	(0x8029_0418, "TPM_E_KEY_NOT_AUTHENTICATED", "The TPM key is not authenticated."),
+	(0x8029_0419, "TPM_E_PCP_KEY_NOT_AIK", "The TPM key is not an AIK."),
+	(0x8029_041a, "TPM_E_KEY_NOT_SIGNING_KEY", "The TPM key is not a signing key."),
+	(0x8029_041b, "TPM_E_LOCKED_OUT", "The TPM is locked out."),
+	(0x8029_041c, "TPM_E_CLAIM_TYPE_NOT_SUPPORTED", "The claim type requested is not supported."),
+	(0x8029_041d, "TPM_E_VERSION_NOT_SUPPORTED", "TPM version is not supported."),
+	(0x8029_041e, "TPM_E_BUFFER_LENGTH_MISMATCH", "The buffer lengths do not match."),
+	(0x8029_041f, "TPM_E_PCP_IFX_RSA_KEY_CREATION_BLOCKED", "The RSA key creation is blocked on this TPM due to known security vulnerabilities."),
+	(0x8029_0420, "TPM_E_PCP_TICKET_MISSING", "A ticket required to use a key was not provided."),
+	(0x8029_0421, "TPM_E_PCP_RAW_POLICY_NOT_SUPPORTED", "The Platform Crypto Provider does not support raw policies."),
+	(0x8029_0422, "TPM_E_PCP_KEY_HANDLE_INVALIDATED", "The Platform Crypto Provider key handle has been invalidated."),
+	(0x8029_0500, "TPM_E_ZERO_EXHAUST_ENABLED", "TPM related network operations are blocked as Zero Exhaust mode is enabled on client."),
+	(0x802a_0001, "UI_E_CREATE_FAILED", "The object could not be created."),
+	(0x802a_0002, "UI_E_SHUTDOWN_CALLED", "Shutdown was already called on this object or the object that owns it."),
+	(0x802a_0003, "UI_E_ILLEGAL_REENTRANCY", "This method cannot be called during this type of callback."),
+	(0x802a_0004, "UI_E_OBJECT_SEALED", "This object has been sealed, so this change is no longer allowed."),
+	(0x802a_0005, "UI_E_VALUE_NOT_SET", "The requested value was never set."),
+	(0x802a_0006, "UI_E_VALUE_NOT_DETERMINED", "The requested value cannot be determined."),
+	(0x802a_0007, "UI_E_INVALID_OUTPUT", "A callback returned an invalid output parameter."),
+	(0x802a_0008, "UI_E_BOOLEAN_EXPECTED", "A callback returned a success code other than S_OK or S_FALSE."),
+	(0x802a_0009, "UI_E_DIFFERENT_OWNER", "A parameter that should be owned by this object is owned by a different object."),
+	(0x802a_000a, "UI_E_AMBIGUOUS_MATCH", "More than one item matched the search criteria."),
+	(0x802a_000b, "UI_E_FP_OVERFLOW", "A floating-point overflow occurred."),
+	(0x802a_000c, "UI_E_WRONG_THREAD", "This method can only be called from the thread that created the object."),
+	(0x802a_0101, "UI_E_STORYBOARD_ACTIVE", "The storyboard is currently in the schedule."),
+	(0x802a_0102, "UI_E_STORYBOARD_NOT_PLAYING", "The storyboard is not playing."),
+	(0x802a_0103, "UI_E_START_KEYFRAME_AFTER_END", "The start keyframe might occur after the end keyframe."),
+	(0x802a_0104, "UI_E_END_KEYFRAME_NOT_DETERMINED", "It might not be possible to determine the end keyframe time when the start keyframe is reached."),
+	(0x802a_0105, "UI_E_LOOPS_OVERLAP", "Two repeated portions of a storyboard might overlap."),
+	(0x802a_0106, "UI_E_TRANSITION_ALREADY_USED", "The transition has already been added to a storyboard."),
+	(0x802a_0107, "UI_E_TRANSITION_NOT_IN_STORYBOARD", "The transition has not been added to a storyboard."),
+	(0x802a_0108, "UI_E_TRANSITION_ECLIPSED", "The transition might eclipse the beginning of another transition in the storyboard."),
+	(0x802a_0109, "UI_E_TIME_BEFORE_LAST_UPDATE", "The given time is earlier than the time passed to the last update."),
+	(0x802a_010a, "UI_E_TIMER_CLIENT_ALREADY_CONNECTED", "This client is already connected to a timer."),
+	(0x802a_010b, "UI_E_INVALID_DIMENSION", "The passed dimension is invalid or does not match the object's dimension."),
+	(0x802a_010c, "UI_E_PRIMITIVE_OUT_OF_BOUNDS", "The added primitive begins at or beyond the duration of the interpolator."),
+	(0x802a_0201, "UI_E_WINDOW_CLOSED", "The operation cannot be completed because the window is being closed."),
+	(0x8030_0002, "PLA_E_DCS_NOT_FOUND", "Data Collector Set was not found."),
+	(0x8030_0045, "PLA_E_TOO_MANY_FOLDERS", "Unable to start Data Collector Set because there are too many folders."),
+	(0x8030_0070, "PLA_E_NO_MIN_DISK", "Not enough free disk space to start Data Collector Set."),
+	(0x8030_00aa, "PLA_E_DCS_IN_USE", "Data Collector Set is in use."),
+	(0x8030_00b7, "PLA_E_DCS_ALREADY_EXISTS", "Data Collector Set already exists."),
+	(0x8030_0101, "PLA_E_PROPERTY_CONFLICT", "Property value conflict."),
+	(0x8030_0102, "PLA_E_DCS_SINGLETON_REQUIRED", "The current configuration for this Data Collector Set requires that it contain exactly one Data Collector."),
+	(0x8030_0103, "PLA_E_CREDENTIALS_REQUIRED", "A user account is required in order to commit the current Data Collector Set properties."),
+	(0x8030_0104, "PLA_E_DCS_NOT_RUNNING", "Data Collector Set is not running."),
+	(0x8030_0105, "PLA_E_CONFLICT_INCL_EXCL_API", "A conflict was detected in the list of include/exclude APIs. Do not specify the same API in both the include list and the exclude list."),
+	(0x8030_0106, "PLA_E_NETWORK_EXE_NOT_VALID", "The executable path you have specified refers to a network share or UNC path."),
+	(0x8030_0107, "PLA_E_EXE_ALREADY_CONFIGURED", "The executable path you have specified is already configured for API tracing."),
+	(0x8030_0108, "PLA_E_EXE_PATH_NOT_VALID", "The executable path you have specified does not exist. Verify that the specified path is correct."),
+	(0x8030_0109, "PLA_E_DC_ALREADY_EXISTS", "Data Collector already exists."),
+	(0x8030_010a, "PLA_E_DCS_START_WAIT_TIMEOUT", "The wait for the Data Collector Set start notification has timed out."),
+	(0x8030_010b, "PLA_E_DC_START_WAIT_TIMEOUT", "The wait for the Data Collector to start has timed out."),
+	(0x8030_010c, "PLA_E_REPORT_WAIT_TIMEOUT", "The wait for the report generation tool to finish has timed out."),
+	(0x8030_010d, "PLA_E_NO_DUPLICATES", "Duplicate items are not allowed."),
+	(0x8030_010e, "PLA_E_EXE_FULL_PATH_REQUIRED", "When specifying the executable that you want to trace, you must specify a full path to the executable and not just a file name."),
+	(0x8030_010f, "PLA_E_INVALID_SESSION_NAME", "The session name provided is invalid."),
+	(0x8030_0110, "PLA_E_PLA_CHANNEL_NOT_ENABLED", "The Event Log channel Microsoft-Windows-Diagnosis-PLA/Operational must be enabled to perform this operation."),
+	(0x8030_0111, "PLA_E_TASKSCHED_CHANNEL_NOT_ENABLED", "The Event Log channel Microsoft-Windows-TaskScheduler must be enabled to perform this operation."),
+	(0x8030_0112, "PLA_E_RULES_MANAGER_FAILED", "The execution of the Rules Manager failed."),
+	(0x8030_0113, "PLA_E_CABAPI_FAILURE", "An error occurred while attempting to compress or extract the data."),
+	(0x8031_0000, "FVE_E_LOCKED_VOLUME", "This drive is locked by BitLocker Drive Encryption. You must unlock this drive from Control Panel."),
+	(0x8031_0001, "FVE_E_NOT_ENCRYPTED", "This drive is not encrypted."),
+	(0x8031_0002, "FVE_E_NO_TPM_BIOS", "The BIOS did not correctly communicate with the Trusted Platform Module (TPM). Contact the computer manufacturer for BIOS upgrade instructions."),
+	(0x8031_0003, "FVE_E_NO_MBR_METRIC", "The BIOS did not correctly communicate with the master boot record (MBR). Contact the computer manufacturer for BIOS upgrade instructions."),
+	(0x8031_0004, "FVE_E_NO_BOOTSECTOR_METRIC", "A required TPM measurement is missing. If there is a bootable CD or DVD in your computer, remove it, restart the computer, and turn on BitLocker again."),
+	(0x8031_0005, "FVE_E_NO_BOOTMGR_METRIC", "The boot sector of this drive is not compatible with BitLocker Drive Encryption. Use the Bootrec.exe tool in the Windows Recovery Environment to update or repair the boot manager (BOOTMGR)."),
+	(0x8031_0006, "FVE_E_WRONG_BOOTMGR", "The boot manager of this operating system is not compatible with BitLocker Drive Encryption. Use the Bootrec.exe tool in the Windows Recovery Environment to update or repair the boot manager (BOOTMGR)."),
+	(0x8031_0007, "FVE_E_SECURE_KEY_REQUIRED", "At least one secure key protector is required for this operation to be performed."),
+	(0x8031_0008, "FVE_E_NOT_ACTIVATED", "BitLocker Drive Encryption is not enabled on this drive. Turn on BitLocker."),
+	(0x8031_0009, "FVE_E_ACTION_NOT_ALLOWED", "BitLocker Drive Encryption cannot perform the requested action. This condition may occur when two requests are issued at the same time. Wait a few moments and then try the action again."),
+	(0x8031_000a, "FVE_E_AD_SCHEMA_NOT_INSTALLED", "The Active Directory Domain Services forest does not contain the required attributes and classes to host BitLocker Drive Encryption or Trusted Platform Module information."),
+	(0x8031_000b, "FVE_E_AD_INVALID_DATATYPE", "The type of the data obtained from Active Directory was not expected. The BitLocker recovery information may be missing or corrupted."),
+	(0x8031_000c, "FVE_E_AD_INVALID_DATASIZE", "The size of the data obtained from Active Directory was not expected. The BitLocker recovery information may be missing or corrupted."),
+	(0x8031_000d, "FVE_E_AD_NO_VALUES", "The attribute read from Active Directory does not contain any values. The BitLocker recovery information may be missing or corrupted."),
+	(0x8031_000e, "FVE_E_AD_ATTR_NOT_SET", "The attribute was not set. Verify that you are logged on with a domain account that has the ability to write information to Active Directory objects."),
+	(0x8031_000f, "FVE_E_AD_GUID_NOT_FOUND", "The specified attribute cannot be found in Active Directory Domain Services. Contact your domain administrator to verify that the schema has been extended."),
+	(0x8031_0010, "FVE_E_BAD_INFORMATION", "The BitLocker metadata for the encrypted drive is not valid. You can attempt to repair the drive to restore access."),
+	(0x8031_0011, "FVE_E_TOO_SMALL", "The drive cannot be encrypted because it does not have enough free space. Delete any unnecessary data on the drive to create additional free space and then try again."),
+	(0x8031_0012, "FVE_E_SYSTEM_VOLUME", "The drive cannot be encrypted because it contains system boot information. Create a separate partition for use as the system drive that contains the boot information and a second partition for use as the operating system drive and then encrypt the operating system drive."),
+	(0x8031_0013, "FVE_E_FAILED_WRONG_FS", "The drive cannot be encrypted because the file system is not supported."),
+	(0x8031_0014, "FVE_E_BAD_PARTITION_SIZE", "The file system size is larger than the partition size in the partition table. This drive may be corrupt or may have been tampered with. To use it with BitLocker, you must reformat the partition."),
+	(0x8031_0015, "FVE_E_NOT_SUPPORTED", "This drive cannot be encrypted."),
+	(0x8031_0016, "FVE_E_BAD_DATA", "The data is not valid."),
+	(0x8031_0017, "FVE_E_VOLUME_NOT_BOUND", "The data drive specified is not set to automatically unlock on the current computer and cannot be unlocked automatically."),
+	(0x8031_0018, "FVE_E_TPM_NOT_OWNED", "You must initialize the Trusted Platform Module (TPM) before you can use BitLocker Drive Encryption."),
+	(0x8031_0019, "FVE_E_NOT_DATA_VOLUME", "The operation attempted cannot be performed on an operating system drive."),
+	(0x8031_001a, "FVE_E_AD_INSUFFICIENT_BUFFER", "The buffer supplied to a function was insufficient to contain the returned data. Increase the buffer size before running the function again."),
+	(0x8031_001b, "FVE_E_CONV_READ", "A read operation failed while converting the drive. The drive was not converted. Please re-enable BitLocker."),
+	(0x8031_001c, "FVE_E_CONV_WRITE", "A write operation failed while converting the drive. The drive was not converted. Please re-enable BitLocker."),
+	(0x8031_001d, "FVE_E_KEY_REQUIRED", "One or more BitLocker key protectors are required. You cannot delete the last key on this drive."),
+	(0x8031_001e, "FVE_E_CLUSTERING_NOT_SUPPORTED", "Cluster configurations are not supported by BitLocker Drive Encryption."),
+	(0x8031_001f, "FVE_E_VOLUME_BOUND_ALREADY", "The drive specified is already configured to be automatically unlocked on the current computer."),
+	(0x8031_0020, "FVE_E_OS_NOT_PROTECTED", "The operating system drive is not protected by BitLocker Drive Encryption."),
+	(0x8031_0021, "FVE_E_PROTECTION_DISABLED", "BitLocker Drive Encryption has been suspended on this drive. All BitLocker key protectors configured for this drive are effectively disabled, and the drive will be automatically unlocked using an unencrypted (clear) key."),
+	(0x8031_0022, "FVE_E_RECOVERY_KEY_REQUIRED", "The drive you are attempting to lock does not have any key protectors available for encryption because BitLocker protection is currently suspended. Re-enable BitLocker to lock this drive."),
+	(0x8031_0023, "FVE_E_FOREIGN_VOLUME", "BitLocker cannot use the Trusted Platform Module (TPM) to protect a data drive. TPM protection can only be used with the operating system drive."),
+	(0x8031_0024, "FVE_E_OVERLAPPED_UPDATE", "The BitLocker metadata for the encrypted drive cannot be updated because it was locked for updating by another process. Please try this process again."),
+	(0x8031_0025, "FVE_E_TPM_SRK_AUTH_NOT_ZERO", "The authorization data for the storage root key (SRK) of the Trusted Platform Module (TPM) is not zero and is therefore incompatible with BitLocker. Please initialize the TPM before attempting to use it with BitLocker."),
+	(0x8031_0026, "FVE_E_FAILED_SECTOR_SIZE", "The drive encryption algorithm cannot be used on this sector size."),
+	(0x8031_0027, "FVE_E_FAILED_AUTHENTICATION", "The drive cannot be unlocked with the key provided. Confirm that you have provided the correct key and try again."),
+	(0x8031_0028, "FVE_E_NOT_OS_VOLUME", "The drive specified is not the operating system drive."),
+	(0x8031_0029, "FVE_E_AUTOUNLOCK_ENABLED", "BitLocker Drive Encryption cannot be turned off on the operating system drive until the auto unlock feature has been disabled for the fixed data drives and removable data drives associated with this computer."),
+	(0x8031_002a, "FVE_E_WRONG_BOOTSECTOR", "The system partition boot sector does not perform Trusted Platform Module (TPM) measurements. Use the Bootrec.exe tool in the Windows Recovery Environment to update or repair the boot sector."),
+	(0x8031_002b, "FVE_E_WRONG_SYSTEM_FS", "BitLocker Drive Encryption operating system drives must be formatted with the NTFS file system in order to be encrypted. Convert the drive to NTFS, and then turn on BitLocker."),
+	(0x8031_002c, "FVE_E_POLICY_PASSWORD_REQUIRED", "Group Policy settings require that a recovery password be specified before encrypting the drive."),
+	(0x8031_002d, "FVE_E_CANNOT_SET_FVEK_ENCRYPTED", "The drive encryption algorithm and key cannot be set on a previously encrypted drive. To encrypt this drive with BitLocker Drive Encryption, remove the previous encryption and then turn on BitLocker."),
+	(0x8031_002e, "FVE_E_CANNOT_ENCRYPT_NO_KEY", "BitLocker Drive Encryption cannot encrypt the specified drive because an encryption key is not available. Add a key protector to encrypt this drive."),
+	(0x8031_0030, "FVE_E_BOOTABLE_CDDVD", "BitLocker Drive Encryption detected bootable media (CD or DVD) in the computer. Remove the media and restart the computer before configuring BitLocker."),
+	(0x8031_0031, "FVE_E_PROTECTOR_EXISTS", "This key protector cannot be added. Only one key protector of this type is allowed for this drive."),
+	(0x8031_0032, "FVE_E_RELATIVE_PATH", "The recovery password file was not found because a relative path was specified. Recovery passwords must be saved to a fully qualified path. Environment variables configured on the computer can be used in the path."),
+	(0x8031_0033, "FVE_E_PROTECTOR_NOT_FOUND", "The specified key protector was not found on the drive. Try another key protector."),
+	(0x8031_0034, "FVE_E_INVALID_KEY_FORMAT", "The recovery key provided is corrupt and cannot be used to access the drive. An alternative recovery method, such as recovery password, a data recovery agent, or a backup version of the recovery key must be used to recover access to the drive."),
+	(0x8031_0035, "FVE_E_INVALID_PASSWORD_FORMAT", "The format of the recovery password provided is invalid. BitLocker recovery passwords are 48 digits. Verify that the recovery password is in the correct format and then try again."),
+	(0x8031_0036, "FVE_E_FIPS_RNG_CHECK_FAILED", "The random number generator check test failed."),
+	(0x8031_0037, "FVE_E_FIPS_PREVENTS_RECOVERY_PASSWORD", "The Group Policy setting requiring FIPS compliance prevents a local recovery password from being generated or used by BitLocker Drive Encryption. When operating in FIPS-compliant mode, BitLocker recovery options can be either a recovery key stored on a USB drive or recovery through a data recovery agent."),
+	(0x8031_0038, "FVE_E_FIPS_PREVENTS_EXTERNAL_KEY_EXPORT", "The Group Policy setting requiring FIPS compliance prevents the recovery password from being saved to Active Directory. When operating in FIPS-compliant mode, BitLocker recovery options can be either a recovery key stored on a USB drive or recovery through a data recovery agent. Check your Group Policy settings configuration."),
+	(0x8031_0039, "FVE_E_NOT_DECRYPTED", "The drive must be fully decrypted to complete this operation."),
+	(0x8031_003a, "FVE_E_INVALID_PROTECTOR_TYPE", "The key protector specified cannot be used for this operation."),
+	(0x8031_003b, "FVE_E_NO_PROTECTORS_TO_TEST", "No key protectors exist on the drive to perform the hardware test."),
+	(0x8031_003c, "FVE_E_KEYFILE_NOT_FOUND", "The BitLocker startup key or recovery password cannot be found on the USB device. Verify that you have the correct USB device, that the USB device is plugged into the computer on an active USB port, restart the computer, and then try again. If the problem persists, contact the computer manufacturer for BIOS upgrade instructions."),
+	(0x8031_003d, "FVE_E_KEYFILE_INVALID", "The BitLocker startup key or recovery password file provided is corrupt or invalid. Verify that you have the correct startup key or recovery password file and try again."),
+	(0x8031_003e, "FVE_E_KEYFILE_NO_VMK", "The BitLocker encryption key cannot be obtained from the startup key or recovery password. Verify that you have the correct startup key or recovery password and try again."),
+	(0x8031_003f, "FVE_E_TPM_DISABLED", "The Trusted Platform Module (TPM) is disabled. The TPM must be enabled, initialized, and have valid ownership before it can be used with BitLocker Drive Encryption."),
+	(0x8031_0040, "FVE_E_NOT_ALLOWED_IN_SAFE_MODE", "The BitLocker configuration of the specified drive cannot be managed because this computer is currently operating in Safe Mode. While in Safe Mode, BitLocker Drive Encryption can only be used for recovery purposes."),
+	(0x8031_0041, "FVE_E_TPM_INVALID_PCR", "The Trusted Platform Module (TPM) was unable to unlock the drive. Either the system boot information changed after choosing BitLocker settings or the PIN did not match. If the problem persists after several tries, there may be a hardware or firmware problem."),
+	(0x8031_0042, "FVE_E_TPM_NO_VMK", "The BitLocker encryption key cannot be obtained from the Trusted Platform Module (TPM)."),
+	(0x8031_0043, "FVE_E_PIN_INVALID", "The BitLocker encryption key cannot be obtained from the Trusted Platform Module (TPM) and PIN."),
+	(0x8031_0044, "FVE_E_AUTH_INVALID_APPLICATION", "A boot application has changed since BitLocker Drive Encryption was enabled."),
+	(0x8031_0045, "FVE_E_AUTH_INVALID_CONFIG", "The Boot Configuration Data (BCD) settings have changed since BitLocker Drive Encryption was enabled."),
+	(0x8031_0046, "FVE_E_FIPS_DISABLE_PROTECTION_NOT_ALLOWED", "The Group Policy setting requiring FIPS compliance prohibits the use of unencrypted keys, which prevents BitLocker from being suspended on this drive. Please contact your domain administrator for more information."),
+	(0x8031_0047, "FVE_E_FS_NOT_EXTENDED", "This drive cannot be encrypted by BitLocker Drive Encryption because the file system does not extend to the end of the drive. Repartition this drive and then try again."),
+	(0x8031_0048, "FVE_E_FIRMWARE_TYPE_NOT_SUPPORTED", "BitLocker Drive Encryption cannot be enabled on the operating system drive. Contact the computer manufacturer for BIOS upgrade instructions."),
+	(0x8031_0049, "FVE_E_NO_LICENSE", "This version of Windows does not include BitLocker Drive Encryption. To use BitLocker Drive Encryption, please upgrade the operating system."),
+	(0x8031_004a, "FVE_E_NOT_ON_STACK", "BitLocker Drive Encryption cannot be used because critical BitLocker system files are missing or corrupted. Use Windows Startup Repair to restore these files to your computer."),
+	(0x8031_004b, "FVE_E_FS_MOUNTED", "The drive cannot be locked when the drive is in use."),
+	(0x8031_004c, "FVE_E_TOKEN_NOT_IMPERSONATED", "The access token associated with the current thread is not an impersonated token."),
+	(0x8031_004d, "FVE_E_DRY_RUN_FAILED", "The BitLocker encryption key cannot be obtained. Verify that the Trusted Platform Module (TPM) is enabled and ownership has been taken. If this computer does not have a TPM, verify that the USB drive is inserted and available."),
+	(0x8031_004e, "FVE_E_REBOOT_REQUIRED", "You must restart your computer before continuing with BitLocker Drive Encryption."),
+	(0x8031_004f, "FVE_E_DEBUGGER_ENABLED", "Drive encryption cannot occur while boot debugging is enabled. Use the bcdedit command-line tool to turn off boot debugging."),
+	(0x8031_0050, "FVE_E_RAW_ACCESS", "No action was taken as BitLocker Drive Encryption is in raw access mode."),
+	(0x8031_0051, "FVE_E_RAW_BLOCKED", "BitLocker Drive Encryption cannot enter raw access mode for this drive because the drive is currently in use."),
+	(0x8031_0052, "FVE_E_BCD_APPLICATIONS_PATH_INCORRECT", "The path specified in the Boot Configuration Data (BCD) for a BitLocker Drive Encryption integrity-protected application is incorrect. Please verify and correct your BCD settings and try again."),
+	(0x8031_0053, "FVE_E_NOT_ALLOWED_IN_VERSION", "BitLocker Drive Encryption can only be used for limited provisioning or recovery purposes when the computer is running in pre-installation or recovery environments."),
+	(0x8031_0054, "FVE_E_NO_AUTOUNLOCK_MASTER_KEY", "The auto-unlock master key was not available from the operating system drive."),
+	(0x8031_0055, "FVE_E_MOR_FAILED", "The system firmware failed to enable clearing of system memory when the computer was restarted."),
+	(0x8031_0056, "FVE_E_HIDDEN_VOLUME", "The hidden drive cannot be encrypted."),
+	(0x8031_0057, "FVE_E_TRANSIENT_STATE", "BitLocker encryption keys were ignored because the drive was in a transient state."),
+	(0x8031_0058, "FVE_E_PUBKEY_NOT_ALLOWED", "Public key based protectors are not allowed on this drive."),
+	(0x8031_0059, "FVE_E_VOLUME_HANDLE_OPEN", "BitLocker Drive Encryption is already performing an operation on this drive. Please complete all operations before continuing."),
+	(0x8031_005a, "FVE_E_NO_FEATURE_LICENSE", "This version of Windows does not support this feature of BitLocker Drive Encryption. To use this feature, upgrade the operating system."),
+	(0x8031_005b, "FVE_E_INVALID_STARTUP_OPTIONS", "The Group Policy settings for BitLocker startup options are in conflict and cannot be applied. Contact your system administrator for more information."),
+	(0x8031_005c, "FVE_E_POLICY_RECOVERY_PASSWORD_NOT_ALLOWED", "Group Policy settings do not permit the creation of a recovery password."),
+	(0x8031_005d, "FVE_E_POLICY_RECOVERY_PASSWORD_REQUIRED", "Group Policy settings require the creation of a recovery password."),
+	(0x8031_005e, "FVE_E_POLICY_RECOVERY_KEY_NOT_ALLOWED", "Group Policy settings do not permit the creation of a recovery key."),
+	(0x8031_005f, "FVE_E_POLICY_RECOVERY_KEY_REQUIRED", "Group Policy settings require the creation of a recovery key."),
+	(0x8031_0060, "FVE_E_POLICY_STARTUP_PIN_NOT_ALLOWED", "Group Policy settings do not permit the use of a PIN at startup. Please choose a different BitLocker startup option."),
+	(0x8031_0061, "FVE_E_POLICY_STARTUP_PIN_REQUIRED", "Group Policy settings require the use of a PIN at startup. Please choose this BitLocker startup option."),
+	(0x8031_0062, "FVE_E_POLICY_STARTUP_KEY_NOT_ALLOWED", "Group Policy settings do not permit the use of a startup key. Please choose a different BitLocker startup option."),
+	(0x8031_0063, "FVE_E_POLICY_STARTUP_KEY_REQUIRED", "Group Policy settings require the use of a startup key. Please choose this BitLocker startup option."),
+	(0x8031_0064, "FVE_E_POLICY_STARTUP_PIN_KEY_NOT_ALLOWED", "Group Policy settings do not permit the use of a startup key and PIN. Please choose a different BitLocker startup option."),
+	(0x8031_0065, "FVE_E_POLICY_STARTUP_PIN_KEY_REQUIRED", "Group Policy settings require the use of a startup key and PIN. Please choose this BitLocker startup option."),
+	(0x8031_0066, "FVE_E_POLICY_STARTUP_TPM_NOT_ALLOWED", "Group policy does not permit the use of TPM-only at startup. Please choose a different BitLocker startup option."),
+	(0x8031_0067, "FVE_E_POLICY_STARTUP_TPM_REQUIRED", "Group Policy settings require the use of TPM-only at startup. Please choose this BitLocker startup option."),
+	(0x8031_0068, "FVE_E_POLICY_INVALID_PIN_LENGTH", "The PIN provided does not meet minimum or maximum length requirements."),
+	(0x8031_0069, "FVE_E_KEY_PROTECTOR_NOT_SUPPORTED", "The key protector is not supported by the version of BitLocker Drive Encryption currently on the drive. Upgrade the drive to add the key protector."),
+	(0x8031_006a, "FVE_E_POLICY_PASSPHRASE_NOT_ALLOWED", "Group Policy settings do not permit the creation of a password."),
+	(0x8031_006b, "FVE_E_POLICY_PASSPHRASE_REQUIRED", "Group Policy settings require the creation of a password."),
+	(0x8031_006c, "FVE_E_FIPS_PREVENTS_PASSPHRASE", "The Group Policy setting requiring FIPS compliance prevents passwords from being generated or used. Please contact your system administrator for more information."),
+	(0x8031_006d, "FVE_E_OS_VOLUME_PASSPHRASE_NOT_ALLOWED", "A password cannot be added to the operating system drive."),
+	(0x8031_006e, "FVE_E_INVALID_BITLOCKER_OID", "The BitLocker object identifier (OID) on the drive appears to be invalid or corrupt. Use manage-BDE to reset the OID on this drive."),
+	(0x8031_006f, "FVE_E_VOLUME_TOO_SMALL", "The drive is too small to be protected using BitLocker Drive Encryption."),
+	(0x8031_0070, "FVE_E_DV_NOT_SUPPORTED_ON_FS", "The selected discovery drive type is incompatible with the file system on the drive. BitLocker To Go discovery drives must be created on FAT formatted drives."),
+	(0x8031_0071, "FVE_E_DV_NOT_ALLOWED_BY_GP", "The selected discovery drive type is not allowed by the computer's Group Policy settings. Verify that Group Policy settings allow the creation of discovery drives for use with BitLocker To Go."),
+	(0x8031_0072, "FVE_E_POLICY_USER_CERTIFICATE_NOT_ALLOWED", "Group Policy settings do not permit user certificates such as smart cards to be used with BitLocker Drive Encryption."),
+	(0x8031_0073, "FVE_E_POLICY_USER_CERTIFICATE_REQUIRED", "Group Policy settings require that you have a valid user certificate, such as a smart card, to be used with BitLocker Drive Encryption."),
+	(0x8031_0074, "FVE_E_POLICY_USER_CERT_MUST_BE_HW", "Group Policy settings requires that you use a smart card-based key protector with BitLocker Drive Encryption."),
+	(0x8031_0075, "FVE_E_POLICY_USER_CONFIGURE_FDV_AUTOUNLOCK_NOT_ALLOWED", "Group Policy settings do not permit BitLocker-protected fixed data drives to be automatically unlocked."),
+	(0x8031_0076, "FVE_E_POLICY_USER_CONFIGURE_RDV_AUTOUNLOCK_NOT_ALLOWED", "Group Policy settings do not permit BitLocker-protected removable data drives to be automatically unlocked."),
+	(0x8031_0077, "FVE_E_POLICY_USER_CONFIGURE_RDV_NOT_ALLOWED", "Group Policy settings do not permit you to configure BitLocker Drive Encryption on removable data drives."),
+	(0x8031_0078, "FVE_E_POLICY_USER_ENABLE_RDV_NOT_ALLOWED", "Group Policy settings do not permit you to turn on BitLocker Drive Encryption on removable data drives. Please contact your system administrator if you need to turn on BitLocker."),
+	(0x8031_0079, "FVE_E_POLICY_USER_DISABLE_RDV_NOT_ALLOWED", "Group Policy settings do not permit turning off BitLocker Drive Encryption on removable data drives. Please contact your system administrator if you need to turn off BitLocker."),
+	(0x8031_0080, "FVE_E_POLICY_INVALID_PASSPHRASE_LENGTH", "Your password does not meet minimum password length requirements. By default, passwords must be at least 8 characters in length. Check with your system administrator for the password length requirement in your organization."),
+	(0x8031_0081, "FVE_E_POLICY_PASSPHRASE_TOO_SIMPLE", "Your password does not meet the complexity requirements set by your system administrator. Try adding upper and lowercase characters, numbers, and symbols."),
+	(0x8031_0082, "FVE_E_RECOVERY_PARTITION", "This drive cannot be encrypted because it is reserved for Windows System Recovery Options."),
+	(0x8031_0083, "FVE_E_POLICY_CONFLICT_FDV_RK_OFF_AUK_ON", "BitLocker Drive Encryption cannot be applied to this drive because of conflicting Group Policy settings. BitLocker cannot be configured to automatically unlock fixed data drives when user recovery options are disabled."),
+	(0x8031_0084, "FVE_E_POLICY_CONFLICT_RDV_RK_OFF_AUK_ON", "BitLocker Drive Encryption cannot be applied to this drive because of conflicting Group Policy settings. BitLocker cannot be configured to automatically unlock removable data drives when user recovery option are disabled."),
+	(0x8031_0085, "FVE_E_NON_BITLOCKER_OID", "The Enhanced Key Usage (EKU) attribute of the specified certificate does not permit it to be used for BitLocker Drive Encryption. BitLocker does not require that a certificate have an EKU attribute, but if one is configured it must be set to an object identifier (OID) that matches the OID configured for BitLocker."),
+	(0x8031_0086, "FVE_E_POLICY_PROHIBITS_SELFSIGNED", "BitLocker Drive Encryption cannot be applied to this drive as currently configured because of Group Policy settings. The certificate you provided for drive encryption is self-signed."),
+	(0x8031_0087, "FVE_E_POLICY_CONFLICT_RO_AND_STARTUP_KEY_REQUIRED", "BitLocker Encryption cannot be applied to this drive because of conflicting Group Policy settings. When write access to drives not protected by BitLocker is denied, the use of a USB startup key cannot be required."),
+	(0x8031_0088, "FVE_E_CONV_RECOVERY_FAILED", "BitLocker Drive Encryption failed to recover from an abruptly terminated conversion. This could be due to either all conversion logs being corrupted or the media being write-protected."),
+	(0x8031_0089, "FVE_E_VIRTUALIZED_SPACE_TOO_BIG", "The requested virtualization size is too big."),
+	(0x8031_0090, "FVE_E_POLICY_CONFLICT_OSV_RP_OFF_ADB_ON", "BitLocker Drive Encryption cannot be applied to this drive because there are conflicting Group Policy settings for recovery options on operating system drives. Storing recovery information to Active Directory Domain Services cannot be required when the generation of recovery passwords is not permitted."),
+	(0x8031_0091, "FVE_E_POLICY_CONFLICT_FDV_RP_OFF_ADB_ON", "BitLocker Drive Encryption cannot be applied to this drive because there are conflicting Group Policy settings for recovery options on fixed data drives. Storing recovery information to Active Directory Domain Services cannot be required when the generation of recovery passwords is not permitted."),
+	(0x8031_0092, "FVE_E_POLICY_CONFLICT_RDV_RP_OFF_ADB_ON", "BitLocker Drive Encryption cannot be applied to this drive because there are conflicting Group Policy settings for recovery options on removable data drives. Storing recovery information to Active Directory Domain Services cannot be required when the generation of recovery passwords is not permitted."),
+	(0x8031_0093, "FVE_E_NON_BITLOCKER_KU", "The Key Usage (KU) attribute of the specified certificate does not permit it to be used for BitLocker Drive Encryption. BitLocker does not require that a certificate have a KU attribute, but if one is configured it must be set to either Key Encipherment or Key Agreement."),
+	(0x8031_0094, "FVE_E_PRIVATEKEY_AUTH_FAILED", "The private key associated with the specified certificate cannot be authorized. The private key authorization was either not provided or the provided authorization was invalid."),
+	(0x8031_0095, "FVE_E_REMOVAL_OF_DRA_FAILED", "Removal of the data recovery agent certificate must be done using the Certificates snap-in."),
+	(0x8031_0096, "FVE_E_OPERATION_NOT_SUPPORTED_ON_VISTA_VOLUME", "This drive was encrypted using the version of BitLocker Drive Encryption included with Windows Vista and Windows Server 2008 which does not support organizational identifiers. To specify organizational identifiers for this drive, upgrade the drive encryption to the latest version using the \"manage-bde -upgrade\" command."),
+	(0x8031_0097, "FVE_E_CANT_LOCK_AUTOUNLOCK_ENABLED_VOLUME", "The drive cannot be locked because it is automatically unlocked on this computer. Remove the automatic unlock protector to lock this drive."),
+	(0x8031_0098, "FVE_E_FIPS_HASH_KDF_NOT_ALLOWED", "The default BitLocker Key Derivation Function SP800-56A for ECC smart cards is not supported by your smart card. The Group Policy setting requiring FIPS-compliance prevents BitLocker from using any other key derivation function for encryption. You have to use a FIPS compliant smart card in FIPS restricted environments."),
+	(0x8031_0099, "FVE_E_ENH_PIN_INVALID", "The BitLocker encryption key could not be obtained from the Trusted Platform Module (TPM) and enhanced PIN. Try using a PIN containing only numerals."),
+	(0x8031_009a, "FVE_E_INVALID_PIN_CHARS", "The requested TPM PIN contains invalid characters."),
+	(0x8031_009b, "FVE_E_INVALID_DATUM_TYPE", "The management information stored on the drive contained an unknown type. If you are using an old version of Windows, try accessing the drive from the latest version."),
+	(0x8031_009c, "FVE_E_EFI_ONLY", "The feature is only supported on EFI systems."),
+	(0x8031_009d, "FVE_E_MULTIPLE_NKP_CERTS", "More than one Network Key Protector certificate has been found on the system."),
+	(0x8031_009e, "FVE_E_REMOVAL_OF_NKP_FAILED", "Removal of the Network Key Protector certificate must be done using the Certificates snap-in."),
+	(0x8031_009f, "FVE_E_INVALID_NKP_CERT", "An invalid certificate has been found in the Network Key Protector certificate store."),
+	(0x8031_00a0, "FVE_E_NO_EXISTING_PIN", "This drive isn't protected with a PIN."),
+	(0x8031_00a1, "FVE_E_PROTECTOR_CHANGE_PIN_MISMATCH", "Please enter the correct current PIN."),
+	(0x8031_00a2, "FVE_E_PIN_PROTECTOR_CHANGE_BY_STD_USER_DISALLOWED", "You must be logged on with an administrator account to change the PIN. Click the link to reset the PIN as an administrator."),
+	(0x8031_00a3, "FVE_E_PROTECTOR_CHANGE_MAX_PIN_CHANGE_ATTEMPTS_REACHED", "BitLocker has disabled PIN changes after too many failed requests. Click the link to reset the PIN as an administrator."),
+	(0x8031_00a4, "FVE_E_POLICY_PASSPHRASE_REQUIRES_ASCII", "Your system administrator requires that passwords contain only printable ASCII characters. This includes unaccented letters (A-Z, a-z), numbers (0-9), space, arithmetic signs, common punctuation, separators, and the following symbols: # $ & @ ^ _ ~ ."),
+	(0x8031_00a5, "FVE_E_FULL_ENCRYPTION_NOT_ALLOWED_ON_TP_STORAGE", "BitLocker Drive Encryption only supports Used Space Only encryption on thin provisioned storage."),
+	(0x8031_00a6, "FVE_E_WIPE_NOT_ALLOWED_ON_TP_STORAGE", "BitLocker Drive Encryption does not support wiping free space on thin provisioned storage."),
+	(0x8031_00a7, "FVE_E_KEY_LENGTH_NOT_SUPPORTED_BY_EDRIVE", "The required authentication key length is not supported by the drive."),
+	(0x8031_00a8, "FVE_E_NO_EXISTING_PASSPHRASE", "This drive isn't protected with a password."),
+	(0x8031_00a9, "FVE_E_PROTECTOR_CHANGE_PASSPHRASE_MISMATCH", "Please enter the correct current password."),
+	(0x8031_00aa, "FVE_E_PASSPHRASE_TOO_LONG", "The password cannot exceed 256 characters."),
+	(0x8031_00ab, "FVE_E_NO_PASSPHRASE_WITH_TPM", "A password key protector cannot be added because a TPM protector exists on the drive."),
+	(0x8031_00ac, "FVE_E_NO_TPM_WITH_PASSPHRASE", "A TPM key protector cannot be added because a password protector exists on the drive."),
+	(0x8031_00ad, "FVE_E_NOT_ALLOWED_ON_CSV_STACK", "This command can only be performed from the coordinator node for the specified CSV volume."),
+	(0x8031_00ae, "FVE_E_NOT_ALLOWED_ON_CLUSTER", "This command cannot be performed on a volume when it is part of a cluster."),
+	(0x8031_00af, "FVE_E_EDRIVE_NO_FAILOVER_TO_SW", "BitLocker did not revert to using BitLocker software encryption due to group policy configuration."),
+	(0x8031_00b0, "FVE_E_EDRIVE_BAND_IN_USE", "The drive cannot be managed by BitLocker because the drive's hardware encryption feature is already in use."),
+	(0x8031_00b1, "FVE_E_EDRIVE_DISALLOWED_BY_GP", "Group Policy settings do not allow the use of hardware-based encryption."),
+	(0x8031_00b2, "FVE_E_EDRIVE_INCOMPATIBLE_VOLUME", "The drive specified does not support hardware-based encryption."),
+	(0x8031_00b3, "FVE_E_NOT_ALLOWED_TO_UPGRADE_WHILE_CONVERTING", "BitLocker cannot be upgraded during disk encryption or decryption."),
+	(0x8031_00b4, "FVE_E_EDRIVE_DV_NOT_SUPPORTED", "Discovery Volumes are not supported for volumes using hardware encryption."),
+	(0x8031_00b5, "FVE_E_NO_PREBOOT_KEYBOARD_DETECTED", "No pre-boot keyboard detected. The user may not be able to provide required input to unlock the volume."),
+	(0x8031_00b6, "FVE_E_NO_PREBOOT_KEYBOARD_OR_WINRE_DETECTED", "No pre-boot keyboard or Windows Recovery Environment detected. The user may not be able to provide required input to unlock the volume."),
+	(0x8031_00b7, "FVE_E_POLICY_REQUIRES_STARTUP_PIN_ON_TOUCH_DEVICE", "Group Policy settings require the creation of a startup PIN, but a pre-boot keyboard is not available on this device. The user may not be able to provide required input to unlock the volume."),
+	(0x8031_00b8, "FVE_E_POLICY_REQUIRES_RECOVERY_PASSWORD_ON_TOUCH_DEVICE", "Group Policy settings require the creation of a recovery password, but neither a pre-boot keyboard nor Windows Recovery Environment is available on this device. The user may not be able to provide required input to unlock the volume."),
+	(0x8031_00b9, "FVE_E_WIPE_CANCEL_NOT_APPLICABLE", "Wipe of free space is not currently taking place."),
+	(0x8031_00ba, "FVE_E_SECUREBOOT_DISABLED", "BitLocker cannot use Secure Boot for platform integrity because Secure Boot has been disabled."),
+	(0x8031_00bb, "FVE_E_SECUREBOOT_CONFIGURATION_INVALID", "BitLocker cannot use Secure Boot for platform integrity because the Secure Boot configuration does not meet the requirements for BitLocker."),
+	(0x8031_00bc, "FVE_E_EDRIVE_DRY_RUN_FAILED", "Your computer doesn't support BitLocker hardware-based encryption. Check with your computer manufacturer for firmware updates."),
+	(0x8031_00bd, "FVE_E_SHADOW_COPY_PRESENT", "BitLocker cannot be enabled on the volume because it contains a Volume Shadow Copy. Remove all Volume Shadow Copies before encrypting the volume."),
+	(0x8031_00be, "FVE_E_POLICY_INVALID_ENHANCED_BCD_SETTINGS", "BitLocker Drive Encryption cannot be applied to this drive because the Group Policy setting for Enhanced Boot Configuration Data contains invalid data. Please have your system administrator resolve this invalid configuration before attempting to enable BitLocker."),
+	(0x8031_00bf, "FVE_E_EDRIVE_INCOMPATIBLE_FIRMWARE", "This PC's firmware is not capable of supporting hardware encryption."),
+	(0x8031_00c0, "FVE_E_PROTECTOR_CHANGE_MAX_PASSPHRASE_CHANGE_ATTEMPTS_REACHED", "BitLocker has disabled password changes after too many failed requests. Click the link to reset the password as an administrator."),
+	(0x8031_00c1, "FVE_E_PASSPHRASE_PROTECTOR_CHANGE_BY_STD_USER_DISALLOWED", "You must be logged on with an administrator account to change the password. Click the link to reset the password as an administrator."),
+	(0x8031_00c2, "FVE_E_LIVEID_ACCOUNT_SUSPENDED", "BitLocker cannot save the recovery password because the specified Microsoft account is Suspended."),
+	(0x8031_00c3, "FVE_E_LIVEID_ACCOUNT_BLOCKED", "BitLocker cannot save the recovery password because the specified Microsoft account is Blocked."),
+	(0x8031_00c4, "FVE_E_NOT_PROVISIONED_ON_ALL_VOLUMES", "This PC is not provisioned to support device encryption. Please enable BitLocker on all volumes to comply with device encryption policy."),
+	(0x8031_00c5, "FVE_E_DE_FIXED_DATA_NOT_SUPPORTED", "This PC cannot support device encryption because unencrypted fixed data volumes are present."),
+	(0x8031_00c6, "FVE_E_DE_HARDWARE_NOT_COMPLIANT", "This PC does not meet the hardware requirements to support device encryption."),
+	(0x8031_00c7, "FVE_E_DE_WINRE_NOT_CONFIGURED", "This PC cannot support device encryption because WinRE is not properly configured."),
+	(0x8031_00c8, "FVE_E_DE_PROTECTION_SUSPENDED", "Protection is enabled on the volume but has been suspended. This is likely to have happened due to an update being applied to your system. Please try again after a reboot."),
+	(0x8031_00c9, "FVE_E_DE_OS_VOLUME_NOT_PROTECTED", "This PC is not provisioned to support device encryption."),
+	(0x8031_00ca, "FVE_E_DE_DEVICE_LOCKEDOUT", "Device Lock has been triggered due to too many incorrect password attempts."),
+	(0x8031_00cb, "FVE_E_DE_PROTECTION_NOT_YET_ENABLED", "Protection has not been enabled on the volume. Enabling protection requires a connected account. If you already have a connected account and are seeing this error, please refer to the event log for more information."),
+	(0x8031_00cc, "FVE_E_INVALID_PIN_CHARS_DETAILED", "Your PIN can only contain numbers from 0 to 9."),
+	(0x8031_00cd, "FVE_E_DEVICE_LOCKOUT_COUNTER_UNAVAILABLE", "BitLocker cannot use hardware replay protection because no counter is available on your PC."),
+	(0x8031_00ce, "FVE_E_DEVICELOCKOUT_COUNTER_MISMATCH", "Device Lockout state validation failed due to counter mismatch."),
+	(0x8031_00cf, "FVE_E_BUFFER_TOO_LARGE", "The input buffer is too large."),
+	(0x8031_00d0, "FVE_E_NO_SUCH_CAPABILITY_ON_TARGET", "The target of an invocation does not support requested capability."),
+	(0x8031_00d1, "FVE_E_DE_PREVENTED_FOR_OS", "Device encryption is currently blocked by this PC's configuration."),
+	(0x8031_00d2, "FVE_E_DE_VOLUME_OPTED_OUT", "This drive has been opted out of device encryption."),
+	(0x8031_00d3, "FVE_E_DE_VOLUME_NOT_SUPPORTED", "Device encryption isn't available for this drive."),
+	(0x8031_00d4, "FVE_E_EOW_NOT_SUPPORTED_IN_VERSION", "The encrypt on write mode for BitLocker is not supported in this version of Windows. You can turn on BitLocker without using the encrypt on write mode."),
+	(0x8031_00d5, "FVE_E_ADBACKUP_NOT_ENABLED", "Group policy prevents you from backing up your recovery password to Active Directory for this drive type. For more info, contact your system administrator."),
+	(0x8031_00d6, "FVE_E_VOLUME_EXTEND_PREVENTS_EOW_DECRYPT", "Device encryption can't be turned off while this drive is being encrypted. Please try again later."),
+	(0x8031_00d7, "FVE_E_NOT_DE_VOLUME", "This action isn't supported because this drive isn't automatically managed with device encryption."),
+	(0x8031_00d8, "FVE_E_PROTECTION_CANNOT_BE_DISABLED", "BitLocker can't be suspended on this drive until the next restart."),
+	(0x8031_00d9, "FVE_E_OSV_KSR_NOT_ALLOWED", "BitLocker Drive Encryption policy does not allow KSR operation with protected OS volume."),
+	(0x8031_00da, "FVE_E_AD_BACKUP_REQUIRED_POLICY_NOT_SET_OS_DRIVE", "Recovery key backup to Active Directory is required by policy, but the policy for backing up recovery information for operating system drives is not set."),
+	(0x8031_00db, "FVE_E_AD_BACKUP_REQUIRED_POLICY_NOT_SET_FIXED_DRIVE", "Recovery key backup to Active Directory is required by policy, but the policy for backing up recovery information for fixed data drives is not set."),
+	(0x8031_00dc, "FVE_E_AD_BACKUP_REQUIRED_POLICY_NOT_SET_REMOVABLE_DRIVE", "Recovery key backup to Active Directory is required by policy, but the policy for backing up recovery information for removable data drives is not set."),
+	(0x8031_00dd, "FVE_E_KEY_ROTATION_NOT_SUPPORTED", "Key rotation not supported by this drive."),
+	(0x8031_00de, "FVE_E_EXECUTE_REQUEST_SENT_TOO_SOON", "This request was sent too soon after the previous one. Please try again later."),
+	(0x8031_00df, "FVE_E_KEY_ROTATION_NOT_ENABLED", "BitLocker recovery password rotation policy is not enabled."),
+	(0x8031_00e0, "FVE_E_DEVICE_NOT_JOINED", "The device is not joined to a domain or to Azure AD."),
+	(0x8032_0001, "FWP_E_CALLOUT_NOT_FOUND", "The callout does not exist."),
+	(0x8032_0002, "FWP_E_CONDITION_NOT_FOUND", "The filter condition does not exist."),
+	(0x8032_0003, "FWP_E_FILTER_NOT_FOUND", "The filter does not exist."),
+	(0x8032_0004, "FWP_E_LAYER_NOT_FOUND", "The layer does not exist."),
+	(0x8032_0005, "FWP_E_PROVIDER_NOT_FOUND", "The provider does not exist."),
+	(0x8032_0006, "FWP_E_PROVIDER_CONTEXT_NOT_FOUND", "The provider context does not exist."),
+	(0x8032_0007, "FWP_E_SUBLAYER_NOT_FOUND", "The sublayer does not exist."),
+	(0x8032_0008, "FWP_E_NOT_FOUND", "The object does not exist."),
+	(0x8032_0009, "FWP_E_ALREADY_EXISTS", "An object with that GUID or LUID already exists."),
+	(0x8032_000a, "FWP_E_IN_USE", "The object is referenced by other objects so cannot be deleted."),
+	(0x8032_000b, "FWP_E_DYNAMIC_SESSION_IN_PROGRESS", "The call is not allowed from within a dynamic session."),
+	(0x8032_000c, "FWP_E_WRONG_SESSION", "The call was made from the wrong session so cannot be completed."),
+	(0x8032_000d, "FWP_E_NO_TXN_IN_PROGRESS", "The call must be made from within an explicit transaction."),
+	(0x8032_000e, "FWP_E_TXN_IN_PROGRESS", "The call is not allowed from within an explicit transaction."),
+	(0x8032_000f, "FWP_E_TXN_ABORTED", "The explicit transaction has been forcibly cancelled."),
+	(0x8032_0010, "FWP_E_SESSION_ABORTED", "The session has been cancelled."),
+	(0x8032_0011, "FWP_E_INCOMPATIBLE_TXN", "The call is not allowed from within a read-only transaction."),
+	(0x8032_0012, "FWP_E_TIMEOUT", "The call timed out while waiting to acquire the transaction lock."),
+	(0x8032_0013, "FWP_E_NET_EVENTS_DISABLED", "Collection of network diagnostic events is disabled."),
+	(0x8032_0014, "FWP_E_INCOMPATIBLE_LAYER", "The operation is not supported by the specified layer."),
+	(0x8032_0015, "FWP_E_KM_CLIENTS_ONLY", "The call is allowed for kernel-mode callers only."),
+	(0x8032_0016, "FWP_E_LIFETIME_MISMATCH", "The call tried to associate two objects with incompatible lifetimes."),
+	(0x8032_0017, "FWP_E_BUILTIN_OBJECT", "The object is built in so cannot be deleted."),
+	(0x8032_0018, "FWP_E_TOO_MANY_CALLOUTS", "The maximum number of callouts has been reached."),
+	(0x8032_0019, "FWP_E_NOTIFICATION_DROPPED", "A notification could not be delivered because a message queue is at its maximum capacity."),
+	(0x8032_001a, "FWP_E_TRAFFIC_MISMATCH", "The traffic parameters do not match those for the security association context."),
+	(0x8032_001b, "FWP_E_INCOMPATIBLE_SA_STATE", "The call is not allowed for the current security association state."),
+	(0x8032_001c, "FWP_E_NULL_POINTER", "A required pointer is null."),
+	(0x8032_001d, "FWP_E_INVALID_ENUMERATOR", "An enumerator is not valid."),
+	(0x8032_001e, "FWP_E_INVALID_FLAGS", "The flags field contains an invalid value."),
+	(0x8032_001f, "FWP_E_INVALID_NET_MASK", "A network mask is not valid."),
+	(0x8032_0020, "FWP_E_INVALID_RANGE", "An FWP_RANGE is not valid."),
+	(0x8032_0021, "FWP_E_INVALID_INTERVAL", "The time interval is not valid."),
+	(0x8032_0022, "FWP_E_ZERO_LENGTH_ARRAY", "An array that must contain at least one element is zero length."),
+	(0x8032_0023, "FWP_E_NULL_DISPLAY_NAME", "The displayData.name field cannot be null."),
+	(0x8032_0024, "FWP_E_INVALID_ACTION_TYPE", "The action type is not one of the allowed action types for a filter."),
+	(0x8032_0025, "FWP_E_INVALID_WEIGHT", "The filter weight is not valid."),
+	(0x8032_0026, "FWP_E_MATCH_TYPE_MISMATCH", "A filter condition contains a match type that is not compatible with the operands."),
+	(0x8032_0027, "FWP_E_TYPE_MISMATCH", "An FWP_VALUE or FWPM_CONDITION_VALUE is of the wrong type."),
+	(0x8032_0028, "FWP_E_OUT_OF_BOUNDS", "An integer value is outside the allowed range."),
+	(0x8032_0029, "FWP_E_RESERVED", "A reserved field is non-zero."),
+	(0x8032_002a, "FWP_E_DUPLICATE_CONDITION", "A filter cannot contain multiple conditions operating on a single field."),
+	(0x8032_002b, "FWP_E_DUPLICATE_KEYMOD", "A policy cannot contain the same keying module more than once."),
+	(0x8032_002c, "FWP_E_ACTION_INCOMPATIBLE_WITH_LAYER", "The action type is not compatible with the layer."),
+	(0x8032_002d, "FWP_E_ACTION_INCOMPATIBLE_WITH_SUBLAYER", "The action type is not compatible with the sublayer."),
+	(0x8032_002e, "FWP_E_CONTEXT_INCOMPATIBLE_WITH_LAYER", "The raw context or the provider context is not compatible with the layer."),
+	(0x8032_002f, "FWP_E_CONTEXT_INCOMPATIBLE_WITH_CALLOUT", "The raw context or the provider context is not compatible with the callout."),
+	(0x8032_0030, "FWP_E_INCOMPATIBLE_AUTH_METHOD", "The authentication method is not compatible with the policy type."),
+	(0x8032_0031, "FWP_E_INCOMPATIBLE_DH_GROUP", "The Diffie-Hellman group is not compatible with the policy type."),
+	(0x8032_0032, "FWP_E_EM_NOT_SUPPORTED", "An IKE policy cannot contain an Extended Mode policy."),
+	(0x8032_0033, "FWP_E_NEVER_MATCH", "The enumeration template or subscription will never match any objects."),
+	(0x8032_0034, "FWP_E_PROVIDER_CONTEXT_MISMATCH", "The provider context is of the wrong type."),
+	(0x8032_0035, "FWP_E_INVALID_PARAMETER", "The parameter is incorrect."),
+	(0x8032_0036, "FWP_E_TOO_MANY_SUBLAYERS", "The maximum number of sublayers has been reached."),
+	(0x8032_0037, "FWP_E_CALLOUT_NOTIFICATION_FAILED", "The notification function for a callout returned an error."),
+	(0x8032_0038, "FWP_E_INVALID_AUTH_TRANSFORM", "The IPsec authentication transform is not valid."),
+	(0x8032_0039, "FWP_E_INVALID_CIPHER_TRANSFORM", "The IPsec cipher transform is not valid."),
+	(0x8032_003a, "FWP_E_INCOMPATIBLE_CIPHER_TRANSFORM", "The IPsec cipher transform is not compatible with the policy."),
+	(0x8032_003b, "FWP_E_INVALID_TRANSFORM_COMBINATION", "The combination of IPsec transform types is not valid."),
+	(0x8032_003c, "FWP_E_DUPLICATE_AUTH_METHOD", "A policy cannot contain the same auth method more than once."),
+	(0x8032_003d, "FWP_E_INVALID_TUNNEL_ENDPOINT", "A tunnel endpoint configuration is invalid."),
+	(0x8032_003e, "FWP_E_L2_DRIVER_NOT_READY", "The WFP MAC Layers are not ready."),
+	(0x8032_003f, "FWP_E_KEY_DICTATOR_ALREADY_REGISTERED", "A key manager capable of key dictation is already registered."),
+	(0x8032_0040, "FWP_E_KEY_DICTATION_INVALID_KEYING_MATERIAL", "A key manager dictated invalid keys."),
+	(0x8032_0041, "FWP_E_CONNECTIONS_DISABLED", "The BFE IPsec Connection Tracking is disabled."),
+	(0x8032_0042, "FWP_E_INVALID_DNS_NAME", "The DNS name is invalid."),
+	(0x8032_0043, "FWP_E_STILL_ON", "The engine option is still enabled due to other configuration settings."),
+	(0x8032_0044, "FWP_E_IKEEXT_NOT_RUNNING", "The IKEEXT service is not running. This service only runs when there is IPsec policy applied to the machine."),
+	(0x8032_0104, "FWP_E_DROP_NOICMP", "The packet should be dropped, no ICMP should be sent."),
+	(0x8037_0100, "HCS_E_TERMINATED_DURING_START", "The virtual machine or container exited unexpectedly while starting."),
+	(0x8037_0101, "HCS_E_IMAGE_MISMATCH", "The container operating system does not match the host operating system."),
+	(0x8037_0102, "HCS_E_HYPERV_NOT_INSTALLED", "The virtual machine could not be started because a required feature is not installed."),
+	(0x8037_0105, "HCS_E_INVALID_STATE", "The call to start an asynchronous operation succeeded and the completion handler was invoked with a result indicating that the virtual machine or container is in an invalid state for the requested operation."),
+	(0x8037_0106, "HCS_E_UNEXPECTED_EXIT", "The virtual machine or container exited unexpectedly."),
+	(0x8037_0107, "HCS_E_TERMINATED", "The virtual machine or container was forcefully exited."),
+	(0x8037_0108, "HCS_E_CONNECT_FAILED", "A connection could not be established with the container or virtual machine."),
+	(0x8037_0109, "HCS_E_CONNECTION_TIMEOUT", "The operation timed out because a response was not received from the virtual machine or container."),
+	(0x8037_010a, "HCS_E_CONNECTION_CLOSED", "The connection with the virtual machine or container was closed."),
+	(0x8037_010b, "HCS_E_UNKNOWN_MESSAGE", "An unknown internal message was received by the virtual machine or container."),
+	(0x8037_010c, "HCS_E_UNSUPPORTED_PROTOCOL_VERSION", "The virtual machine or container does not support an available version of the communication protocol with the host."),
+	(0x8037_010d, "HCS_E_INVALID_JSON", "The virtual machine or container JSON document is invalid."),
+	(0x8037_010e, "HCS_E_SYSTEM_NOT_FOUND", "A virtual machine or container with the specified identifier does not exist."),
+	(0x8037_010f, "HCS_E_SYSTEM_ALREADY_EXISTS", "A virtual machine or container with the specified identifier already exists."),
+	(0x8037_0110, "HCS_E_SYSTEM_ALREADY_STOPPED", "The virtual machine or container with the specified identifier is not running."),
+	(0x8037_0111, "HCS_E_PROTOCOL_ERROR", "A communication protocol error has occurred between the virtual machine or container and the host."),
+	(0x8037_0112, "HCS_E_INVALID_LAYER", "The container image contains a layer with an unrecognized format."),
+	(0x8037_0113, "HCS_E_WINDOWS_INSIDER_REQUIRED", "To use this container image, you must join the Windows Insider Program."),
+	(0x8037_0114, "HCS_E_SERVICE_NOT_AVAILABLE", "The operation could not be started because a required feature is not installed."),
+	(0x8037_0115, "HCS_E_OPERATION_NOT_STARTED", "The operation has not started."),
+	(0x8037_0116, "HCS_E_OPERATION_ALREADY_STARTED", "The operation is already running."),
+	(0x8037_0117, "HCS_E_OPERATION_PENDING", "The operation is still running."),
+	(0x8037_0118, "HCS_E_OPERATION_TIMEOUT", "The operation did not complete in time."),
+	(0x8037_0119, "HCS_E_OPERATION_SYSTEM_CALLBACK_ALREADY_SET", "An event callback has already been registered on this handle."),
+	(0x8037_011a, "HCS_E_OPERATION_RESULT_ALLOCATION_FAILED", "Not enough memory available to return the result of the operation."),
+	(0x8037_011b, "HCS_E_ACCESS_DENIED", "Insufficient privileges. Only administrators or users that are members of the Hyper-V Administrators user group are permitted to access virtual machines or containers."),
+	(0x8037_011c, "HCS_E_GUEST_CRITICAL_ERROR", "The virtual machine or container reported a critical error and was stopped or restarted."),
+	(0x8037_0300, "WHV_E_UNKNOWN_CAPABILITY", "The specified capability does not exist."),
+	(0x8037_0301, "WHV_E_INSUFFICIENT_BUFFER", "The specified buffer is too small for the requested data."),
+	(0x8037_0302, "WHV_E_UNKNOWN_PROPERTY", "The specified property does not exist."),
+	(0x8037_0303, "WHV_E_UNSUPPORTED_HYPERVISOR_CONFIG", "The configuration of the hypervisor on this system is not supported."),
+	(0x8037_0304, "WHV_E_INVALID_PARTITION_CONFIG", "The configuration of the partition is not valid."),
+	(0x8037_0305, "WHV_E_GPA_RANGE_NOT_FOUND", "The specified GPA range was not found."),
+	(0x8037_0306, "WHV_E_VP_ALREADY_EXISTS", "A virtual processor with the specified index already exists."),
+	(0x8037_0307, "WHV_E_VP_DOES_NOT_EXIST", "A virtual processor with the specified index does not exist."),
+	(0x8037_0308, "WHV_E_INVALID_VP_STATE", "The virtual processor is not in the correct state to perform the requested operation."),
+	(0x8037_0309, "WHV_E_INVALID_VP_REGISTER_NAME", "A virtual processor register with the specified name does not exist."),
+	(0x803b_0001, "HCN_E_NETWORK_NOT_FOUND", "The network was not found."),
+	(0x803b_0002, "HCN_E_ENDPOINT_NOT_FOUND", "The endpoint was not found."),
+	(0x803b_0003, "HCN_E_LAYER_NOT_FOUND", "The network's underlying layer was not found."),
+	(0x803b_0004, "HCN_E_SWITCH_NOT_FOUND", "The virtual switch was not found."),
+	(0x803b_0005, "HCN_E_SUBNET_NOT_FOUND", "The network does not have a subnet for this endpoint."),
+	(0x803b_0006, "HCN_E_ADAPTER_NOT_FOUND", "An adapter was not found."),
+	(0x803b_0007, "HCN_E_PORT_NOT_FOUND", "The switch-port was not found."),
+	(0x803b_0008, "HCN_E_POLICY_NOT_FOUND", "An expected policy was not found."),
+	(0x803b_0009, "HCN_E_VFP_PORTSETTING_NOT_FOUND", "A required VFP port setting was not found."),
+	(0x803b_000a, "HCN_E_INVALID_NETWORK", "The provided network configuration is invalid or missing parameters."),
+	(0x803b_000b, "HCN_E_INVALID_NETWORK_TYPE", "Invalid network type."),
+	(0x803b_000c, "HCN_E_INVALID_ENDPOINT", "The provided endpoint configuration is invalid or missing parameters."),
+	(0x803b_000d, "HCN_E_INVALID_POLICY", "The provided policy configuration is invalid or missing parameters."),
+	(0x803b_000e, "HCN_E_INVALID_POLICY_TYPE", "Invalid policy type."),
+	(0x803b_000f, "HCN_E_INVALID_REMOTE_ENDPOINT_OPERATION", "This requested operation is invalid for a remote endpoint."),
+	(0x803b_0010, "HCN_E_NETWORK_ALREADY_EXISTS", "A network with this name already exists."),
+	(0x803b_0011, "HCN_E_LAYER_ALREADY_EXISTS", "A network with this name already exists."),
+	(0x803b_0012, "HCN_E_POLICY_ALREADY_EXISTS", "Policy information already exists on this object."),
+	(0x803b_0013, "HCN_E_PORT_ALREADY_EXISTS", "The specified port already exists."),
+	(0x803b_0014, "HCN_E_ENDPOINT_ALREADY_ATTACHED", "This endpoint is already attached to the switch."),
+	(0x803b_0015, "HCN_E_REQUEST_UNSUPPORTED", "The specified request is unsupported."),
+	(0x803b_0016, "HCN_E_MAPPING_NOT_SUPPORTED", "Port mapping is not supported on the given network."),
+	(0x803b_0017, "HCN_E_DEGRADED_OPERATION", "There was an operation attempted on a degraded object."),
+	(0x803b_0018, "HCN_E_SHARED_SWITCH_MODIFICATION", "Cannot modify a switch shared by multiple networks."),
+	(0x803b_0019, "HCN_E_GUID_CONVERSION_FAILURE", "Failed to interpret a parameter as a GUID."),
+	(0x803b_001a, "HCN_E_REGKEY_FAILURE", "Failed to process registry key."),
+	(0x803b_001b, "HCN_E_INVALID_JSON", "Invalid JSON document string."),
+	(0x803b_001c, "HCN_E_INVALID_JSON_REFERENCE", "The reference is invalid in the JSON document."),
+	(0x803b_001d, "HCN_E_ENDPOINT_SHARING_DISABLED", "Endpoint sharing is disabled."),
+	(0x803b_001e, "HCN_E_INVALID_IP", "IP address is either invalid or not part of any configured subnet(s)."),
+	(0x803b_001f, "HCN_E_SWITCH_EXTENSION_NOT_FOUND", "The specified switch extension does not exist on this switch."),
+	(0x803b_0020, "HCN_E_MANAGER_STOPPED", "Operation cannot be performed while service is stopping."),
+	(0x803b_0021, "GCN_E_MODULE_NOT_FOUND", "The specified module was not found."),
+	(0x803b_0022, "GCN_E_NO_REQUEST_HANDLERS", "No request handlers could be found for the requested action."),
+	(0x803b_0023, "GCN_E_REQUEST_UNSUPPORTED", "The specified request is unsupported."),
+	(0x803b_0024, "GCN_E_RUNTIMEKEYS_FAILED", "Failed to add or remove the runtime keys."),
+	(0x803b_0025, "GCN_E_NETADAPTER_TIMEOUT", "Timed out waiting for the network adapter."),
+	(0x803b_0026, "GCN_E_NETADAPTER_NOT_FOUND", "The network adapter was not found."),
+	(0x803b_0027, "GCN_E_NETCOMPARTMENT_NOT_FOUND", "The network compartment was not found."),
+	(0x803b_0028, "GCN_E_NETINTERFACE_NOT_FOUND", "The network interface was not found."),
+	(0x803b_0029, "GCN_E_DEFAULTNAMESPACE_EXISTS", "A default namespace already exists."),
+	(0x803b_002a, "HCN_E_ICS_DISABLED", "Internet Connection Sharing service (SharedAccess) is disabled and cannot be started."),
+	(0x803b_002b, "HCN_E_ENDPOINT_NAMESPACE_ALREADY_EXISTS", "This requested operation is invalid as endpoint is already part of a network namespace."),
+	(0x803b_002c, "HCN_E_ENTITY_HAS_REFERENCES", "The specified entity cannot be removed while it still has references."),
+	(0x803b_002d, "HCN_E_INVALID_INTERNAL_PORT", "The internal port must exist and cannot be zero."),
+	(0x803b_002e, "HCN_E_NAMESPACE_ATTACH_FAILED", "The requested operation for attaching a network endpoint to a namespace failed."),
+	(0x803b_002f, "HCN_E_ADDR_INVALID_OR_RESERVED", "An address provided is invalid or reserved."),
+	(0x803d_0000, "WS_E_INVALID_FORMAT", "The input data was not in the expected format or did not have the expected value."),
+	(0x803d_0001, "WS_E_OBJECT_FAULTED", "The operation could not be completed because the object is in a faulted state due to a previous error."),
+	(0x803d_0002, "WS_E_NUMERIC_OVERFLOW", "The operation could not be completed because it would lead to numeric overflow."),
+	(0x803d_0003, "WS_E_INVALID_OPERATION", "The operation is not allowed due to the current state of the object."),
+	(0x803d_0004, "WS_E_OPERATION_ABORTED", "The operation was aborted."),
+	(0x803d_0005, "WS_E_ENDPOINT_ACCESS_DENIED", "Access was denied by the remote endpoint."),
+	(0x803d_0006, "WS_E_OPERATION_TIMED_OUT", "The operation did not complete within the time allotted."),
+	(0x803d_0007, "WS_E_OPERATION_ABANDONED", "The operation was abandoned."),
+	(0x803d_0008, "WS_E_QUOTA_EXCEEDED", "A quota was exceeded."),
+	(0x803d_0009, "WS_E_NO_TRANSLATION_AVAILABLE", "The information was not available in the specified language."),
+	(0x803d_000a, "WS_E_SECURITY_VERIFICATION_FAILURE", "Security verification was not successful for the received data."),
+	(0x803d_000b, "WS_E_ADDRESS_IN_USE", "The address is already being used."),
+	(0x803d_000c, "WS_E_ADDRESS_NOT_AVAILABLE", "The address is not valid for this context."),
+	(0x803d_000d, "WS_E_ENDPOINT_NOT_FOUND", "The remote endpoint does not exist or could not be located."),
+	(0x803d_000e, "WS_E_ENDPOINT_NOT_AVAILABLE", "The remote endpoint is not currently in service at this location."),
+	(0x803d_000f, "WS_E_ENDPOINT_FAILURE", "The remote endpoint could not process the request."),
+	(0x803d_0010, "WS_E_ENDPOINT_UNREACHABLE", "The remote endpoint was not reachable."),
+	(0x803d_0011, "WS_E_ENDPOINT_ACTION_NOT_SUPPORTED", "The operation was not supported by the remote endpoint."),
+	(0x803d_0012, "WS_E_ENDPOINT_TOO_BUSY", "The remote endpoint is unable to process the request due to being overloaded."),
+	(0x803d_0013, "WS_E_ENDPOINT_FAULT_RECEIVED", "A message containing a fault was received from the remote endpoint."),
+	(0x803d_0014, "WS_E_ENDPOINT_DISCONNECTED", "The connection with the remote endpoint was terminated."),
+	(0x803d_0015, "WS_E_PROXY_FAILURE", "The HTTP proxy server could not process the request."),
+	(0x803d_0016, "WS_E_PROXY_ACCESS_DENIED", "Access was denied by the HTTP proxy server."),
+	(0x803d_0017, "WS_E_NOT_SUPPORTED", "The requested feature is not available on this platform."),
+	(0x803d_0018, "WS_E_PROXY_REQUIRES_BASIC_AUTH", "The HTTP proxy server requires HTTP authentication scheme 'basic'."),
+	(0x803d_0019, "WS_E_PROXY_REQUIRES_DIGEST_AUTH", "The HTTP proxy server requires HTTP authentication scheme 'digest'."),
+	(0x803d_001a, "WS_E_PROXY_REQUIRES_NTLM_AUTH", "The HTTP proxy server requires HTTP authentication scheme 'NTLM'."),
+	(0x803d_001b, "WS_E_PROXY_REQUIRES_NEGOTIATE_AUTH", "The HTTP proxy server requires HTTP authentication scheme 'negotiate'."),
+	(0x803d_001c, "WS_E_SERVER_REQUIRES_BASIC_AUTH", "The remote endpoint requires HTTP authentication scheme 'basic'."),
+	(0x803d_001d, "WS_E_SERVER_REQUIRES_DIGEST_AUTH", "The remote endpoint requires HTTP authentication scheme 'digest'."),
+	(0x803d_001e, "WS_E_SERVER_REQUIRES_NTLM_AUTH", "The remote endpoint requires HTTP authentication scheme 'NTLM'."),
+	(0x803d_001f, "WS_E_SERVER_REQUIRES_NEGOTIATE_AUTH", "The remote endpoint requires HTTP authentication scheme 'negotiate'."),
+	(0x803d_0020, "WS_E_INVALID_ENDPOINT_URL", "The endpoint address URL is invalid."),
+	(0x803d_0021, "WS_E_OTHER", "Unrecognized error occurred in the Windows Web Services framework."),
+	(0x803d_0022, "WS_E_SECURITY_TOKEN_EXPIRED", "A security token was rejected by the server because it has expired."),
+	(0x803d_0023, "WS_E_SECURITY_SYSTEM_FAILURE", "A security operation failed in the Windows Web Services framework."),
+	(0x803e_0100, "WPN_E_CHANNEL_CLOSED", "The notification channel has already been closed."),
+	(0x803e_0101, "WPN_E_CHANNEL_REQUEST_NOT_COMPLETE", "The notification channel request did not complete successfully."),
+	(0x803e_0102, "WPN_E_INVALID_APP", "The application identifier provided is invalid."),
+	(0x803e_0103, "WPN_E_OUTSTANDING_CHANNEL_REQUEST", "A notification channel request for the provided application identifier is in progress."),
+	(0x803e_0104, "WPN_E_DUPLICATE_CHANNEL", "The channel identifier is already tied to another application endpoint."),
+	(0x803e_0105, "WPN_E_PLATFORM_UNAVAILABLE", "The notification platform is unavailable."),
+	(0x803e_0106, "WPN_E_NOTIFICATION_POSTED", "The notification has already been posted."),
+	(0x803e_0107, "WPN_E_NOTIFICATION_HIDDEN", "The notification has already been hidden."),
+	(0x803e_0108, "WPN_E_NOTIFICATION_NOT_POSTED", "The notification cannot be hidden until it has been shown."),
+	(0x803e_0109, "WPN_E_CLOUD_DISABLED", "Cloud notifications have been turned off."),
+	(0x803e_0110, "WPN_E_CLOUD_INCAPABLE", "The application does not have the cloud notification capability."),
+	(0x803e_0111, "WPN_E_NOTIFICATION_DISABLED", "Settings prevent the notification from being delivered."),
+	(0x803e_0112, "WPN_E_NOTIFICATION_INCAPABLE", "Application capabilities prevent the notification from being delivered."),
+	(0x803e_0113, "WPN_E_INTERNET_INCAPABLE", "The application does not have the internet access capability."),
+	(0x803e_0114, "WPN_E_NOTIFICATION_TYPE_DISABLED", "Settings prevent the notification type from being delivered."),
+	(0x803e_0115, "WPN_E_NOTIFICATION_SIZE", "The size of the notification content is too large."),
+	(0x803e_0116, "WPN_E_TAG_SIZE", "The size of the notification tag is too large."),
+	(0x803e_0117, "WPN_E_ACCESS_DENIED", "The notification platform doesn't have appropriate privilege on resources."),
+	(0x803e_0118, "WPN_E_DUPLICATE_REGISTRATION", "The notification platform found application is already registered."),
+	(0x803e_0119, "WPN_E_PUSH_NOTIFICATION_INCAPABLE", "The application background task does not have the push notification capability."),
+	(0x803e_011a, "WPN_E_CLOUD_AUTH_UNAVAILABLE", "The notification platform is unable to retrieve the authentication credentials required to connect to the cloud notification service."),
+	(0x803e_011b, "WPN_E_CLOUD_SERVICE_UNAVAILABLE", "The notification platform is unable to connect to the cloud notification service."),
+	(0x803e_011c, "WPN_E_FAILED_LOCK_SCREEN_UPDATE_INTIALIZATION", "The notification platform is unable to initialize a callback for lock screen updates."),
+	(0x803e_0120, "WPN_E_DEV_ID_SIZE", "The size of the developer id for scheduled notification is too large."),
+	(0x803e_012a, "WPN_E_TAG_ALPHANUMERIC", "The notification tag is not alphanumeric."),
+	(0x803e_012b, "WPN_E_INVALID_HTTP_STATUS_CODE", "The notification platform has received invalid HTTP status code other than 2xx for polling."),
+	(0x803e_0200, "WPN_E_OUT_OF_SESSION", "The notification platform has run out of presentation layer sessions."),
+	(0x803e_0201, "WPN_E_POWER_SAVE", "The notification platform rejects image download request due to system in power save mode."),
+	(0x803e_0202, "WPN_E_IMAGE_NOT_FOUND_IN_CACHE", "The notification platform doesn't have the requested image in its cache."),
+	(0x803e_0203, "WPN_E_ALL_URL_NOT_COMPLETED", "The notification platform cannot complete all of requested image."),
+	(0x803e_0204, "WPN_E_INVALID_CLOUD_IMAGE", "A cloud image downloaded from the notification platform is invalid."),
+	(0x803e_0205, "WPN_E_NOTIFICATION_ID_MATCHED", "Notification Id provided as filter is matched with what the notification platform maintains."),
+	(0x803e_0206, "WPN_E_CALLBACK_ALREADY_REGISTERED", "Notification callback interface is already registered."),
+	(0x803e_0207, "WPN_E_TOAST_NOTIFICATION_DROPPED", "Toast Notification was dropped without being displayed to the user."),
+	(0x803e_0208, "WPN_E_STORAGE_LOCKED", "The notification platform does not have the proper privileges to complete the request."),
+	(0x803e_0209, "WPN_E_GROUP_SIZE", "The size of the notification group is too large."),
+	(0x803e_020a, "WPN_E_GROUP_ALPHANUMERIC", "The notification group is not alphanumeric."),
+	(0x803e_020b, "WPN_E_CLOUD_DISABLED_FOR_APP", "Cloud notifications have been disabled for the application due to a policy setting."),
+	(0x8040_0000, "INPUT_E_OUT_OF_ORDER", "Input data cannot be processed in the non-chronological order."),
+	(0x8040_0001, "INPUT_E_REENTRANCY", "Requested operation cannot be performed inside the callback or event handler."),
+	(0x8040_0002, "INPUT_E_MULTIMODAL", "Input cannot be processed because there is ongoing interaction with another pointer type."),
+	(0x8040_0003, "INPUT_E_PACKET", "One or more fields in the input packet are invalid."),
+	(0x8040_0004, "INPUT_E_FRAME", "Packets in the frame are inconsistent. Either pointer ids are not unique or there is a discrepancy in timestamps, frame ids, pointer types or source devices."),
+	(0x8040_0005, "INPUT_E_HISTORY", "The history of frames is inconsistent. Pointer ids, types, source devices don't match, or frame ids are not unique, or timestamps are out of order."),
+	(0x8040_0006, "INPUT_E_DEVICE_INFO", "Failed to retrieve information about the input device."),
+	(0x8040_0007, "INPUT_E_TRANSFORM", "Coordinate system transformation failed to transform the data."),
+	(0x8040_0008, "INPUT_E_DEVICE_PROPERTY", "The property is not supported or not reported correctly by the input device."),
+	(0x8054_8201, "E_MBN_CONTEXT_NOT_ACTIVATED", "Context is not activated."),
+	(0x8054_8202, "E_MBN_BAD_SIM", "Bad SIM is inserted."),
+	(0x8054_8203, "E_MBN_DATA_CLASS_NOT_AVAILABLE", "Requested data class is not available."),
+	(0x8054_8204, "E_MBN_INVALID_ACCESS_STRING", "Access point name (APN) or Access string is incorrect."),
+	(0x8054_8205, "E_MBN_MAX_ACTIVATED_CONTEXTS", "Max activated contexts have reached."),
+	(0x8054_8206, "E_MBN_PACKET_SVC_DETACHED", "Device is in packet detach state."),
+	(0x8054_8207, "E_MBN_PROVIDER_NOT_VISIBLE", "Provider is not visible."),
+	(0x8054_8208, "E_MBN_RADIO_POWER_OFF", "Radio is powered off."),
+	(0x8054_8209, "E_MBN_SERVICE_NOT_ACTIVATED", "MBN subscription is not activated."),
+	(0x8054_820a, "E_MBN_SIM_NOT_INSERTED", "SIM is not inserted."),
+	(0x8054_820b, "E_MBN_VOICE_CALL_IN_PROGRESS", "Voice call in progress."),
+	(0x8054_820c, "E_MBN_INVALID_CACHE", "Visible provider cache is invalid."),
+	(0x8054_820d, "E_MBN_NOT_REGISTERED", "Device is not registered."),
+	(0x8054_820e, "E_MBN_PROVIDERS_NOT_FOUND", "Providers not found."),
+	(0x8054_820f, "E_MBN_PIN_NOT_SUPPORTED", "Pin is not supported."),
+	(0x8054_8210, "E_MBN_PIN_REQUIRED", "Pin is required."),
+	(0x8054_8211, "E_MBN_PIN_DISABLED", "PIN is disabled."),
+	(0x8054_8212, "E_MBN_FAILURE", "Generic Failure."),
+	(0x8054_8218, "E_MBN_INVALID_PROFILE", "Profile is invalid."),
+	(0x8054_8219, "E_MBN_DEFAULT_PROFILE_EXIST", "Default profile exist."),
+	(0x8054_8220, "E_MBN_SMS_ENCODING_NOT_SUPPORTED", "SMS encoding is not supported."),
+	(0x8054_8221, "E_MBN_SMS_FILTER_NOT_SUPPORTED", "SMS filter is not supported."),
+	(0x8054_8222, "E_MBN_SMS_INVALID_MEMORY_INDEX", "Invalid SMS memory index is used."),
+	(0x8054_8223, "E_MBN_SMS_LANG_NOT_SUPPORTED", "SMS language is not supported."),
+	(0x8054_8224, "E_MBN_SMS_MEMORY_FAILURE", "SMS memory failure occurred."),
+	(0x8054_8225, "E_MBN_SMS_NETWORK_TIMEOUT", "SMS network timeout happened."),
+	(0x8054_8226, "E_MBN_SMS_UNKNOWN_SMSC_ADDRESS", "Unknown SMSC address is used."),
+	(0x8054_8227, "E_MBN_SMS_FORMAT_NOT_SUPPORTED", "SMS format is not supported."),
+	(0x8054_8228, "E_MBN_SMS_OPERATION_NOT_ALLOWED", "SMS operation is not allowed."),
+	(0x8054_8229, "E_MBN_SMS_MEMORY_FULL", "Device SMS memory is full."),
+	(0x8055_0001, "EAS_E_POLICY_NOT_MANAGED_BY_OS", "Requested policy is not managed by the operating system."),
+	(0x8055_0002, "EAS_E_POLICY_COMPLIANT_WITH_ACTIONS", "The system can be made compliant to the requested policy, but further action is required."),
+	(0x8055_0003, "EAS_E_REQUESTED_POLICY_NOT_ENFORCEABLE", "The system cannot be made compliant to the requested policy."),
+	(0x8055_0004, "EAS_E_CURRENT_USER_HAS_BLANK_PASSWORD", "The current user has a blank password, and the system cannot be made compliant to the requested policy."),
+	(0x8055_0005, "EAS_E_REQUESTED_POLICY_PASSWORD_EXPIRATION_INCOMPATIBLE", "The requested password expiration is incompatible with the current system settings."),
+	(0x8055_0006, "EAS_E_USER_CANNOT_CHANGE_PASSWORD", "A user account on the system cannot change its password."),
+	(0x8055_0007, "EAS_E_ADMINS_HAVE_BLANK_PASSWORD", "An administrator account on the system has a blank password."),
+	(0x8055_0008, "EAS_E_ADMINS_CANNOT_CHANGE_PASSWORD", "An administrator account on the system cannot change its password."),
+	(0x8055_0009, "EAS_E_LOCAL_CONTROLLED_USERS_CANNOT_CHANGE_PASSWORD", "Local controlled users on the system cannot change their password."),
+	(0x8055_000a, "EAS_E_PASSWORD_POLICY_NOT_ENFORCEABLE_FOR_CONNECTED_ADMINS", "The requested password policy cannot be enforced for connected administrator accounts."),
+	(0x8055_000b, "EAS_E_CONNECTED_ADMINS_NEED_TO_CHANGE_PASSWORD", "Connected administrator accounts need to change their password."),
+	(0x8055_000c, "EAS_E_PASSWORD_POLICY_NOT_ENFORCEABLE_FOR_CURRENT_CONNECTED_USER", "The requested password policy cannot be enforced for the current connected user."),
+	(0x8055_000d, "EAS_E_CURRENT_CONNECTED_USER_NEED_TO_CHANGE_PASSWORD", "The current connected user needs to change their password."),
+	(0x8063_0001, "PEER_E_IPV6_NOT_INSTALLED", "The IPv6 protocol is not installed."),
+	(0x8063_0002, "PEER_E_NOT_INITIALIZED", "The component has not been initialized."),
+	(0x8063_0003, "PEER_E_CANNOT_START_SERVICE", "The required service cannot be started."),
+	(0x8063_0004, "PEER_E_NOT_LICENSED", "The P2P protocol is not licensed to run on this OS."),
+	(0x8063_0010, "PEER_E_INVALID_GRAPH", "The graph handle is invalid."),
+	(0x8063_0011, "PEER_E_DBNAME_CHANGED", "The graph database name has changed."),
+	(0x8063_0012, "PEER_E_DUPLICATE_GRAPH", "A graph with the same ID already exists."),
+	(0x8063_0013, "PEER_E_GRAPH_NOT_READY", "The graph is not ready."),
+	(0x8063_0014, "PEER_E_GRAPH_SHUTTING_DOWN", "The graph is shutting down."),
+	(0x8063_0015, "PEER_E_GRAPH_IN_USE", "The graph is still in use."),
+	(0x8063_0016, "PEER_E_INVALID_DATABASE", "The graph database is corrupt."),
+	(0x8063_0017, "PEER_E_TOO_MANY_ATTRIBUTES", "Too many attributes have been used."),
+	(0x8063_0103, "PEER_E_CONNECTION_NOT_FOUND", "The connection can not be found."),
+	(0x8063_0106, "PEER_E_CONNECT_SELF", "The peer attempted to connect to itself."),
+	(0x8063_0107, "PEER_E_ALREADY_LISTENING", "The peer is already listening for connections."),
+	(0x8063_0108, "PEER_E_NODE_NOT_FOUND", "The node was not found."),
+	(0x8063_0109, "PEER_E_CONNECTION_FAILED", "The Connection attempt failed."),
+	(0x8063_010a, "PEER_E_CONNECTION_NOT_AUTHENTICATED", "The peer connection could not be authenticated."),
+	(0x8063_010b, "PEER_E_CONNECTION_REFUSED", "The connection was refused."),
+	(0x8063_0201, "PEER_E_CLASSIFIER_TOO_LONG", "The peer name classifier is too long."),
+	(0x8063_0202, "PEER_E_TOO_MANY_IDENTITIES", "The maximum number of identities have been created."),
+	(0x8063_0203, "PEER_E_NO_KEY_ACCESS", "Unable to access a key."),
+	(0x8063_0204, "PEER_E_GROUPS_EXIST", "The group already exists."),
+	(0x8063_0301, "PEER_E_RECORD_NOT_FOUND", "The requested record could not be found."),
+	(0x8063_0302, "PEER_E_DATABASE_ACCESSDENIED", "Access to the database was denied."),
+	(0x8063_0303, "PEER_E_DBINITIALIZATION_FAILED", "The Database could not be initialized."),
+	(0x8063_0304, "PEER_E_MAX_RECORD_SIZE_EXCEEDED", "The record is too big."),
+	(0x8063_0305, "PEER_E_DATABASE_ALREADY_PRESENT", "The database already exists."),
+	(0x8063_0306, "PEER_E_DATABASE_NOT_PRESENT", "The database could not be found."),
+	(0x8063_0401, "PEER_E_IDENTITY_NOT_FOUND", "The identity could not be found."),
+	(0x8063_0501, "PEER_E_EVENT_HANDLE_NOT_FOUND", "The event handle could not be found."),
+	(0x8063_0601, "PEER_E_INVALID_SEARCH", "Invalid search."),
+	(0x8063_0602, "PEER_E_INVALID_ATTRIBUTES", "The search attributes are invalid."),
+	(0x8063_0701, "PEER_E_INVITATION_NOT_TRUSTED", "The invitation is not trusted."),
+	(0x8063_0703, "PEER_E_CHAIN_TOO_LONG", "The certchain is too long."),
+	(0x8063_0705, "PEER_E_INVALID_TIME_PERIOD", "The time period is invalid."),
+	(0x8063_0706, "PEER_E_CIRCULAR_CHAIN_DETECTED", "A circular cert chain was detected."),
+	(0x8063_0801, "PEER_E_CERT_STORE_CORRUPTED", "The certstore is corrupted."),
+	(0x8063_1001, "PEER_E_NO_CLOUD", "The specified PNRP cloud does not exist."),
+	(0x8063_1005, "PEER_E_CLOUD_NAME_AMBIGUOUS", "The cloud name is ambiguous."),
+	(0x8063_2010, "PEER_E_INVALID_RECORD", "The record is invalid."),
+	(0x8063_2020, "PEER_E_NOT_AUTHORIZED", "Not authorized."),
+	(0x8063_2021, "PEER_E_PASSWORD_DOES_NOT_MEET_POLICY", "The password does not meet policy requirements."),
+	(0x8063_2030, "PEER_E_DEFERRED_VALIDATION", "The record validation has been deferred."),
+	(0x8063_2040, "PEER_E_INVALID_GROUP_PROPERTIES", "The group properties are invalid."),
+	(0x8063_2050, "PEER_E_INVALID_PEER_NAME", "The peername is invalid."),
+	(0x8063_2060, "PEER_E_INVALID_CLASSIFIER", "The classifier is invalid."),
+	(0x8063_2070, "PEER_E_INVALID_FRIENDLY_NAME", "The friendly name is invalid."),
+	(0x8063_2071, "PEER_E_INVALID_ROLE_PROPERTY", "Invalid role property."),
+	(0x8063_2072, "PEER_E_INVALID_CLASSIFIER_PROPERTY", "Invalid classifier property."),
+	(0x8063_2080, "PEER_E_INVALID_RECORD_EXPIRATION", "Invalid record expiration."),
+	(0x8063_2081, "PEER_E_INVALID_CREDENTIAL_INFO", "Invalid credential info."),
+	(0x8063_2082, "PEER_E_INVALID_CREDENTIAL", "Invalid credential."),
+	(0x8063_2083, "PEER_E_INVALID_RECORD_SIZE", "Invalid record size."),
+	(0x8063_2090, "PEER_E_UNSUPPORTED_VERSION", "Unsupported version."),
+	(0x8063_2091, "PEER_E_GROUP_NOT_READY", "The group is not ready."),
+	(0x8063_2092, "PEER_E_GROUP_IN_USE", "The group is still in use."),
+	(0x8063_2093, "PEER_E_INVALID_GROUP", "The group is invalid."),
+	(0x8063_2094, "PEER_E_NO_MEMBERS_FOUND", "No members were found."),
+	(0x8063_2095, "PEER_E_NO_MEMBER_CONNECTIONS", "There are no member connections."),
+	(0x8063_2096, "PEER_E_UNABLE_TO_LISTEN", "Unable to listen."),
+	(0x8063_20a0, "PEER_E_IDENTITY_DELETED", "The identity does not exist."),
+	(0x8063_20a1, "PEER_E_SERVICE_NOT_AVAILABLE", "The service is not available."),
+	(0x8063_4001, "PEER_E_CANNOT_CONVERT_PEER_NAME", "The peer name cannot be converted."),
+	(0x8063_4002, "PEER_E_INVALID_PEER_HOST_NAME", "Invalid peer host name."),
+	(0x8063_4003, "PEER_E_NO_MORE", "No more data could be found."),
+	(0x8063_4005, "PEER_E_PNRP_DUPLICATE_PEER_NAME", "The existing peer name is already registered."),
+	(0x8063_6001, "PEER_E_CONTACT_NOT_FOUND", "The contact could not be found."),
+	(0x8063_7000, "PEER_E_INVITE_CANCELLED", "The invite was cancelled by the user."),
+	(0x8063_7001, "PEER_E_INVITE_RESPONSE_NOT_AVAILABLE", "No response of the invite was received."),
+	(0x8063_7003, "PEER_E_NOT_SIGNED_IN", "User is not signed into serverless presence."),
+	(0x8063_7004, "PEER_E_PRIVACY_DECLINED", "The user declined the privacy policy prompt."),
+	(0x8063_7005, "PEER_E_TIMEOUT", "A timeout occurred."),
+	(0x8063_7007, "PEER_E_INVALID_ADDRESS", "The address is invalid."),
+	(0x8063_7008, "PEER_E_FW_EXCEPTION_DISABLED", "A required firewall exception is disabled."),
+	(0x8063_7009, "PEER_E_FW_BLOCKED_BY_POLICY", "The service is blocked by a firewall policy."),
+	(0x8063_700a, "PEER_E_FW_BLOCKED_BY_SHIELDS_UP", "Firewall exceptions are disabled."),
+	(0x8063_700b, "PEER_E_FW_DECLINED", "The user declined to enable the firewall exceptions."),
+	(0x8065_0001, "E_BLUETOOTH_ATT_INVALID_HANDLE", "The attribute handle given was not valid on this server."),
+	(0x8065_0002, "E_BLUETOOTH_ATT_READ_NOT_PERMITTED", "The attribute cannot be read."),
+	(0x8065_0003, "E_BLUETOOTH_ATT_WRITE_NOT_PERMITTED", "The attribute cannot be written."),
+	(0x8065_0004, "E_BLUETOOTH_ATT_INVALID_PDU", "The attribute PDU was invalid."),
+	(0x8065_0005, "E_BLUETOOTH_ATT_INSUFFICIENT_AUTHENTICATION", "The attribute requires authentication before it can be read or written."),
+	(0x8065_0006, "E_BLUETOOTH_ATT_REQUEST_NOT_SUPPORTED", "Attribute server does not support the request received from the client."),
+	(0x8065_0007, "E_BLUETOOTH_ATT_INVALID_OFFSET", "Offset specified was past the end of the attribute."),
+	(0x8065_0008, "E_BLUETOOTH_ATT_INSUFFICIENT_AUTHORIZATION", "The attribute requires authorization before it can be read or written."),
+	(0x8065_0009, "E_BLUETOOTH_ATT_PREPARE_QUEUE_FULL", "Too many prepare writes have been queued."),
+	(0x8065_000a, "E_BLUETOOTH_ATT_ATTRIBUTE_NOT_FOUND", "No attribute found within the given attribute handle range."),
+	(0x8065_000b, "E_BLUETOOTH_ATT_ATTRIBUTE_NOT_LONG", "The attribute cannot be read or written using the Read Blob Request."),
+	(0x8065_000c, "E_BLUETOOTH_ATT_INSUFFICIENT_ENCRYPTION_KEY_SIZE", "The Encryption Key Size used for encrypting this link is insufficient."),
+	(0x8065_000d, "E_BLUETOOTH_ATT_INVALID_ATTRIBUTE_VALUE_LENGTH", "The attribute value length is invalid for the operation."),
+	(0x8065_000e, "E_BLUETOOTH_ATT_UNLIKELY", "The attribute request that was requested has encountered an error that was unlikely, and therefore could not be completed as requested."),
+	(0x8065_000f, "E_BLUETOOTH_ATT_INSUFFICIENT_ENCRYPTION", "The attribute requires encryption before it can be read or written."),
+	(0x8065_0010, "E_BLUETOOTH_ATT_UNSUPPORTED_GROUP_TYPE", "The attribute type is not a supported grouping attribute as defined by a higher layer specification."),
+	(0x8065_0011, "E_BLUETOOTH_ATT_INSUFFICIENT_RESOURCES", "Insufficient Resources to complete the request."),
+	(0x8065_1000, "E_BLUETOOTH_ATT_UNKNOWN_ERROR", "An error that lies in the reserved range has been received."),
+	(0x8066_0001, "E_AUDIO_ENGINE_NODE_NOT_FOUND", "PortCls could not find an audio engine node exposed by a miniport driver claiming support for IMiniportAudioEngineNode."),
+	(0x8066_0002, "E_HDAUDIO_EMPTY_CONNECTION_LIST", "HD Audio widget encountered an unexpected empty connection list."),
+	(0x8066_0003, "E_HDAUDIO_CONNECTION_LIST_NOT_SUPPORTED", "HD Audio widget does not support the connection list parameter."),
+	(0x8066_0004, "E_HDAUDIO_NO_LOGICAL_DEVICES_CREATED", "No HD Audio subdevices were successfully created."),
+	(0x8066_0005, "E_HDAUDIO_NULL_LINKED_LIST_ENTRY", "An unexpected NULL pointer was encountered in a linked list."),
+	(0x8067_0001, "STATEREPOSITORY_E_CONCURRENCY_LOCKING_FAILURE", "Optimistic locking failure. Data cannot be updated if it has changed since it was read."),
+	(0x8067_0002, "STATEREPOSITORY_E_STATEMENT_INPROGRESS", "A prepared statement has been stepped at least once but not run to completion or reset. This may result in busy waits."),
+	(0x8067_0003, "STATEREPOSITORY_E_CONFIGURATION_INVALID", "The StateRepository configuration is not valid."),
+	(0x8067_0004, "STATEREPOSITORY_E_UNKNOWN_SCHEMA_VERSION", "The StateRepository schema version is not known."),
+	(0x8067_0005, "STATEREPOSITORY_ERROR_DICTIONARY_CORRUPTED", "A StateRepository dictionary is not valid."),
+	(0x8067_0006, "STATEREPOSITORY_E_BLOCKED", "The request failed because the StateRepository is actively blocking requests."),
+	(0x8067_0007, "STATEREPOSITORY_E_BUSY_RETRY", "The database file is locked. The request will be retried."),
+	(0x8067_0008, "STATEREPOSITORY_E_BUSY_RECOVERY_RETRY", "The database file is locked because another process is busy recovering the database. The request will be retried."),
+	(0x8067_0009, "STATEREPOSITORY_E_LOCKED_RETRY", "A table in the database is locked. The request will be retried."),
+	(0x8067_000a, "STATEREPOSITORY_E_LOCKED_SHAREDCACHE_RETRY", "The shared cache for the database is locked by another connection. The request will be retried."),
+	(0x8067_000b, "STATEREPOSITORY_E_TRANSACTION_REQUIRED", "A transaction is required to perform the request operation."),
+	(0x8067_000c, "STATEREPOSITORY_E_BUSY_TIMEOUT_EXCEEDED", "The database file is locked. The request has exceeded the allowed threshold."),
+	(0x8067_000d, "STATEREPOSITORY_E_BUSY_RECOVERY_TIMEOUT_EXCEEDED", "The database file is locked because another process is busy recovering the database. The request has exceeded the allowed threshold."),
+	(0x8067_000e, "STATEREPOSITORY_E_LOCKED_TIMEOUT_EXCEEDED", "A table in the database is locked. The request has exceeded the allowed threshold."),
+	(0x8067_000f, "STATEREPOSITORY_E_LOCKED_SHAREDCACHE_TIMEOUT_EXCEEDED", "The shared cache for the database is locked by another connection. The request has exceeded the allowed threshold."),
+	(0x8067_0010, "STATEREPOSITORY_E_SERVICE_STOP_IN_PROGRESS", "The StateRepository service Stop event is in progress."),
+	(0x8067_0011, "STATEREPOSTORY_E_NESTED_TRANSACTION_NOT_SUPPORTED", "Nested transactions are not supported."),
+	(0x8067_0012, "STATEREPOSITORY_ERROR_CACHE_CORRUPTED", "The StateRepository cache is not valid."),
+	(0x8082_0001, "ERROR_VOLSNAP_BOOTFILE_NOT_VALID", "The bootfile is too small to support persistent snapshots."),
+	(0x8082_0002, "ERROR_VOLSNAP_ACTIVATION_TIMEOUT", "Activation of persistent snapshots on this volume took longer than was allowed."),
+	(0x8083_0001, "ERROR_TIERING_NOT_SUPPORTED_ON_VOLUME", "The specified volume does not support storage tiers."),
+	(0x8083_0002, "ERROR_TIERING_VOLUME_DISMOUNT_IN_PROGRESS", "The Storage Tiers Management service detected that the specified volume is in the process of being dismounted."),
+	(0x8083_0003, "ERROR_TIERING_STORAGE_TIER_NOT_FOUND", "The specified storage tier could not be found on the volume. Confirm that the storage tier name is valid."),
+	(0x8083_0004, "ERROR_TIERING_INVALID_FILE_ID", "The file identifier specified is not valid on the volume."),
+	(0x8083_0005, "ERROR_TIERING_WRONG_CLUSTER_NODE", "Storage tier operations must be called on the clustering node that owns the metadata volume."),
+	(0x8083_0006, "ERROR_TIERING_ALREADY_PROCESSING", "The Storage Tiers Management service is already optimizing the storage tiers on the specified volume."),
+	(0x8083_0007, "ERROR_TIERING_CANNOT_PIN_OBJECT", "The requested object type cannot be assigned to a storage tier."),
+	(0x8083_0008, "ERROR_TIERING_FILE_IS_NOT_PINNED", "The requested file is not pinned to a tier."),
+	(0x8083_0009, "ERROR_NOT_A_TIERED_VOLUME", "The volume is not a tiered volume."),
+	(0x8083_000a, "ERROR_ATTRIBUTE_NOT_PRESENT", "The requested attribute is not present on the specified file or directory."),
+	(0x8086_0001, "ONL_E_INVALID_AUTHENTICATION_TARGET", "The authentication target is invalid or not configured correctly."),
+	(0x8086_0002, "ONL_E_ACCESS_DENIED_BY_TOU", "Your application cannot get the Online Id properties due to the Terms of Use accepted by the user."),
+	(0x8086_0003, "ONL_E_INVALID_APPLICATION", "The application requesting authorization has been revoked."),
+	(0x8086_0004, "ONL_E_PASSWORD_UPDATE_REQUIRED", "The online id password must be updated before signin."),
+	(0x8086_0005, "ONL_E_ACCOUNT_UPDATE_REQUIRED", "The online id account properties must be updated before signin."),
+	(0x8086_0006, "ONL_E_FORCESIGNIN", "To help protect your Online ID account you must signin again."),
+	(0x8086_0007, "ONL_E_ACCOUNT_LOCKED", "The Online ID account was locked because there have been too many attempts to sign in."),
+	(0x8086_0008, "ONL_E_PARENTAL_CONSENT_REQUIRED", "The Online ID account requires parental consent before proceeding."),
+	(0x8086_0009, "ONL_E_EMAIL_VERIFICATION_REQUIRED", "The Online ID signin name is not yet verified. Email verification is required before signin."),
+	(0x8086_000a, "ONL_E_ACCOUNT_SUSPENDED_COMPROIMISE", "We have noticed some unusual activity in your Online ID account. Your action is needed to make sure no one else is using your account."),
+	(0x8086_000b, "ONL_E_ACCOUNT_SUSPENDED_ABUSE", "We detected some suspicious activity with your Online ID account. To help protect you, we've temporarily blocked your account."),
+	(0x8086_000c, "ONL_E_ACTION_REQUIRED", "User interaction is required for authentication."),
+	(0x8086_000d, "ONL_CONNECTION_COUNT_LIMIT", "User has reached the maximum device associations per user limit."),
+	(0x8086_000e, "ONL_E_CONNECTED_ACCOUNT_CAN_NOT_SIGNOUT", "Cannot sign out from the default account of a connected user."),
+	(0x8086_000f, "ONL_E_USER_AUTHENTICATION_REQUIRED", "User authentication is required for this operation."),
+	(0x8086_0010, "ONL_E_REQUEST_THROTTLED", "We want to make sure this is you. User interaction is required for authentication."),
+	(0x80b0_0001, "ERROR_DBG_CREATE_PROCESS_FAILURE_LOCKDOWN", "Could not create a process with the debugger because of Lockdown policy."),
+	(0x80b0_0002, "ERROR_DBG_ATTACH_PROCESS_FAILURE_LOCKDOWN", "Could not attach the debugger to a process because of Lockdown policy."),
+	(0x80b0_0003, "ERROR_DBG_CONNECT_SERVER_FAILURE_LOCKDOWN", "Could not connect to a debug server because of Lockdown policy."),
+	(0x80b0_0004, "ERROR_DBG_START_SERVER_FAILURE_LOCKDOWN", "Could not start a debug server because of Lockdown policy."),
+	(0x80e7_0001, "ERROR_SPACES_FAULT_DOMAIN_TYPE_INVALID", "The specified fault domain type or combination of minimum/maximum fault domain type is not valid."),
+	(0x80e7_0002, "ERROR_SPACES_INTERNAL_ERROR", "A Storage Spaces internal error occurred."),
+	(0x80e7_0003, "ERROR_SPACES_RESILIENCY_TYPE_INVALID", "The specified resiliency type is not valid."),
+	(0x80e7_0004, "ERROR_SPACES_DRIVE_SECTOR_SIZE_INVALID", "The physical disk's sector size is not supported by the storage pool."),
+	(0x80e7_0006, "ERROR_SPACES_DRIVE_REDUNDANCY_INVALID", "The requested redundancy is outside of the supported range of values."),
+	(0x80e7_0007, "ERROR_SPACES_NUMBER_OF_DATA_COPIES_INVALID", "The number of data copies requested is outside of the supported range of values."),
+	(0x80e7_0008, "ERROR_SPACES_PARITY_LAYOUT_INVALID", "The value for ParityLayout is outside of the supported range of values."),
+	(0x80e7_0009, "ERROR_SPACES_INTERLEAVE_LENGTH_INVALID", "The value for interleave length is outside of the supported range of values or is not a power of 2."),
+	(0x80e7_000a, "ERROR_SPACES_NUMBER_OF_COLUMNS_INVALID", "The number of columns specified is outside of the supported range of values."),
+	(0x80e7_000b, "ERROR_SPACES_NOT_ENOUGH_DRIVES", "There were not enough physical disks to complete the requested operation."),
+	(0x80e7_000c, "ERROR_SPACES_EXTENDED_ERROR", "Extended error information is available."),
+	(0x80e7_000d, "ERROR_SPACES_PROVISIONING_TYPE_INVALID", "The specified provisioning type is not valid."),
+	(0x80e7_000e, "ERROR_SPACES_ALLOCATION_SIZE_INVALID", "The allocation size is outside of the supported range of values."),
+	(0x80e7_000f, "ERROR_SPACES_ENCLOSURE_AWARE_INVALID", "Enclosure awareness is not supported for this virtual disk."),
+	(0x80e7_0010, "ERROR_SPACES_WRITE_CACHE_SIZE_INVALID", "The write cache size is outside of the supported range of values."),
+	(0x80e7_0011, "ERROR_SPACES_NUMBER_OF_GROUPS_INVALID", "The value for number of groups is outside of the supported range of values."),
+	(0x80e7_0012, "ERROR_SPACES_DRIVE_OPERATIONAL_STATE_INVALID", "The OperationalState of the physical disk is invalid for this operation."),
+	(0x80e7_0013, "ERROR_SPACES_ENTRY_INCOMPLETE", "The specified log entry is not complete."),
+	(0x80e7_0014, "ERROR_SPACES_ENTRY_INVALID", "The specified log entry is not valid."),
+	(0x8375_0001, "WEB_E_UNSUPPORTED_FORMAT", "Unsupported format."),
+	(0x8375_0002, "WEB_E_INVALID_XML", "Invalid XML."),
+	(0x8375_0003, "WEB_E_MISSING_REQUIRED_ELEMENT", "Missing required element."),
+	(0x8375_0004, "WEB_E_MISSING_REQUIRED_ATTRIBUTE", "Missing required attribute."),
+	(0x8375_0005, "WEB_E_UNEXPECTED_CONTENT", "Unexpected content."),
+	(0x8375_0006, "WEB_E_RESOURCE_TOO_LARGE", "Resource too large."),
+	(0x8375_0007, "WEB_E_INVALID_JSON_STRING", "Invalid JSON string."),
+	(0x8375_0008, "WEB_E_INVALID_JSON_NUMBER", "Invalid JSON number."),
+	(0x8375_0009, "WEB_E_JSON_VALUE_NOT_FOUND", "JSON value not found."),
+	(0x8376_0001, "E_INVALID_PROTOCOL_OPERATION", "Invalid operation performed by the protocol."),
+	(0x8376_0002, "E_INVALID_PROTOCOL_FORMAT", "Invalid data format for the specific protocol operation."),
+	(0x8376_0003, "E_PROTOCOL_EXTENSIONS_NOT_SUPPORTED", "Protocol extensions are not supported."),
+	(0x8376_0004, "E_SUBPROTOCOL_NOT_SUPPORTED", "Subprotocol is not supported."),
+	(0x8376_0005, "E_PROTOCOL_VERSION_NOT_SUPPORTED", "Incorrect protocol version."),
+	(0x87af_0001, "SQLITE_E_ERROR", "SQL error or missing database."),
+	(0x87af_0002, "SQLITE_E_INTERNAL", "Internal logic error in SQLite."),
+	(0x87af_0003, "SQLITE_E_PERM", "Access permission denied."),
+	(0x87af_0004, "SQLITE_E_ABORT", "Callback routine requested an abort."),
+	(0x87af_0005, "SQLITE_E_BUSY", "The database file is locked."),
+	(0x87af_0006, "SQLITE_E_LOCKED", "A table in the database is locked."),
+	(0x87af_0007, "SQLITE_E_NOMEM", "A malloc() failed."),
+	(0x87af_0008, "SQLITE_E_READONLY", "Attempt to write a readonly database."),
+	(0x87af_0009, "SQLITE_E_INTERRUPT", "Operation terminated by sqlite3_interrupt()."),
+	(0x87af_000a, "SQLITE_E_IOERR", "Some kind of disk I/O error occurred."),
+	(0x87af_000b, "SQLITE_E_CORRUPT", "The database disk image is malformed."),
+	(0x87af_000c, "SQLITE_E_NOTFOUND", "Unknown opcode in sqlite3_file_control()."),
+	(0x87af_000d, "SQLITE_E_FULL", "Insertion failed because database is full."),
+	(0x87af_000e, "SQLITE_E_CANTOPEN", "Unable to open the database file."),
+	(0x87af_000f, "SQLITE_E_PROTOCOL", "Database lock protocol error."),
+	(0x87af_0010, "SQLITE_E_EMPTY", "Database is empty."),
+	(0x87af_0011, "SQLITE_E_SCHEMA", "The database schema changed."),
+	(0x87af_0012, "SQLITE_E_TOOBIG", "String or BLOB exceeds size limit."),
+	(0x87af_0013, "SQLITE_E_CONSTRAINT", "Abort due to constraint violation."),
+	(0x87af_0014, "SQLITE_E_MISMATCH", "Data type mismatch."),
+	(0x87af_0015, "SQLITE_E_MISUSE", "Library used incorrectly."),
+	(0x87af_0016, "SQLITE_E_NOLFS", "Uses OS features not supported on host."),
+	(0x87af_0017, "SQLITE_E_AUTH", "Authorization denied."),
+	(0x87af_0018, "SQLITE_E_FORMAT", "Auxiliary database format error."),
+	(0x87af_0019, "SQLITE_E_RANGE", "2nd parameter to sqlite3_bind out of range."),
+	(0x87af_001a, "SQLITE_E_NOTADB", "File opened that is not a database file."),
+	(0x87af_001b, "SQLITE_E_NOTICE", "Notifications from sqlite3_log()."),
+	(0x87af_001c, "SQLITE_E_WARNING", "Warnings from sqlite3_log()."),
+	(0x87af_0064, "SQLITE_E_ROW", "sqlite3_step() has another row ready."),
+	(0x87af_0065, "SQLITE_E_DONE", "sqlite3_step() has finished executing."),
+	(0x87af_0105, "SQLITE_E_BUSY_RECOVERY", "SQLite extended error: the database file is locked because another process is recovering it."),
+	(0x87af_0106, "SQLITE_E_LOCKED_SHAREDCACHE", "SQLite extended error: the shared cache is locked by another connection."),
+	(0x87af_0108, "SQLITE_E_READONLY_RECOVERY", "SQLite extended error: the database needs recovery but is read-only."),
+	(0x87af_010a, "SQLITE_E_IOERR_READ", "SQLite extended error: I/O error while reading."),
+	(0x87af_010b, "SQLITE_E_CORRUPT_VTAB", "SQLite extended error: a virtual table is corrupt."),
+	(0x87af_010e, "SQLITE_E_CANTOPEN_NOTEMPDIR", "SQLite extended error: no temporary directory is available."),
+	(0x87af_0113, "SQLITE_E_CONSTRAINT_CHECK", "SQLite extended error: a CHECK constraint failed."),
+	(0x87af_011b, "SQLITE_E_NOTICE_RECOVER_WAL", "SQLite extended notice: a WAL mode database file was recovered."),
+	(0x87af_011c, "SQLITE_E_WARNING_AUTOINDEX", "SQLite extended warning: an automatic index was created."),
+	(0x87af_0204, "SQLITE_E_ABORT_ROLLBACK", "SQLite extended error: the statement was aborted by a rollback."),
+	(0x87af_0205, "SQLITE_E_BUSY_SNAPSHOT", "SQLite extended error: the read transaction snapshot is no longer current."),
+	(0x87af_0208, "SQLITE_E_READONLY_CANTLOCK", "SQLite extended error: unable to obtain a read lock on a read-only WAL database."),
+	(0x87af_020a, "SQLITE_E_IOERR_SHORT_READ", "SQLite extended error: a read returned fewer bytes than requested."),
+	(0x87af_020e, "SQLITE_E_CANTOPEN_ISDIR", "SQLite extended error: the file is a directory."),
+	(0x87af_0213, "SQLITE_E_CONSTRAINT_COMMITHOOK", "SQLite extended error: a commit hook callback requested a rollback."),
+	(0x87af_021b, "SQLITE_E_NOTICE_RECOVER_ROLLBACK", "SQLite extended notice: a hot journal was rolled back."),
+	(0x87af_0308, "SQLITE_E_READONLY_ROLLBACK", "SQLite extended error: a hot journal needs to be rolled back but the database is read-only."),
+	(0x87af_030a, "SQLITE_E_IOERR_WRITE", "SQLite extended error: I/O error while writing."),
+	(0x87af_030e, "SQLITE_E_CANTOPEN_FULLPATH", "SQLite extended error: unable to convert the filename into a full pathname."),
+	(0x87af_0313, "SQLITE_E_CONSTRAINT_FOREIGNKEY", "SQLite extended error: a foreign key constraint failed."),
+	(0x87af_0408, "SQLITE_E_READONLY_DBMOVED", "SQLite extended error: the database file has been moved since it was opened."),
+	(0x87af_040a, "SQLITE_E_IOERR_FSYNC", "SQLite extended error: I/O error while flushing data to disk."),
+	(0x87af_040e, "SQLITE_E_CANTOPEN_CONVPATH", "SQLite extended error: unable to convert the file path."),
+	(0x87af_0413, "SQLITE_E_CONSTRAINT_FUNCTION", "SQLite extended error: a constraint failed in an application-defined function."),
+	(0x87af_050a, "SQLITE_E_IOERR_DIR_FSYNC", "SQLite extended error: I/O error while flushing a directory to disk."),
+	(0x87af_0513, "SQLITE_E_CONSTRAINT_NOTNULL", "SQLite extended error: a NOT NULL constraint failed."),
+	(0x87af_060a, "SQLITE_E_IOERR_TRUNCATE", "SQLite extended error: I/O error while truncating a file."),
+	(0x87af_0613, "SQLITE_E_CONSTRAINT_PRIMARYKEY", "SQLite extended error: a PRIMARY KEY constraint failed."),
+	(0x87af_070a, "SQLITE_E_IOERR_FSTAT", "SQLite extended error: I/O error while retrieving file metadata."),
+	(0x87af_0713, "SQLITE_E_CONSTRAINT_TRIGGER", "SQLite extended error: a RAISE function within a trigger fired."),
+	(0x87af_080a, "SQLITE_E_IOERR_UNLOCK", "SQLite extended error: I/O error while unlocking a file."),
+	(0x87af_0813, "SQLITE_E_CONSTRAINT_UNIQUE", "SQLite extended error: a UNIQUE constraint failed."),
+	(0x87af_090a, "SQLITE_E_IOERR_RDLOCK", "SQLite extended error: I/O error while obtaining a read lock."),
+	(0x87af_0913, "SQLITE_E_CONSTRAINT_VTAB", "SQLite extended error: a constraint failed in a virtual table."),
+	(0x87af_0a0a, "SQLITE_E_IOERR_DELETE", "SQLite extended error: I/O error while deleting a file."),
+	(0x87af_0a13, "SQLITE_E_CONSTRAINT_ROWID", "SQLite extended error: a rowid is not unique."),
+	(0x87af_0b0a, "SQLITE_E_IOERR_BLOCKED", "SQLite extended error: I/O operation blocked."),
+	(0x87af_0c0a, "SQLITE_E_IOERR_NOMEM", "SQLite extended error: out of memory during an I/O operation."),
+	(0x87af_0d0a, "SQLITE_E_IOERR_ACCESS", "SQLite extended error: I/O error while checking file access."),
+	(0x87af_0e0a, "SQLITE_E_IOERR_CHECKRESERVEDLOCK", "SQLite extended error: I/O error while checking for a reserved lock."),
+	(0x87af_0f0a, "SQLITE_E_IOERR_LOCK", "SQLite extended error: I/O error in the advisory file locking logic."),
+	(0x87af_100a, "SQLITE_E_IOERR_CLOSE", "SQLite extended error: I/O error while closing a file."),
+	(0x87af_110a, "SQLITE_E_IOERR_DIR_CLOSE", "SQLite extended error: I/O error while closing a directory."),
+	(0x87af_120a, "SQLITE_E_IOERR_SHMOPEN", "SQLite extended error: I/O error while opening shared memory."),
+	(0x87af_130a, "SQLITE_E_IOERR_SHMSIZE", "SQLite extended error: I/O error while resizing shared memory."),
+	(0x87af_140a, "SQLITE_E_IOERR_SHMLOCK", "SQLite extended error: I/O error while locking shared memory."),
+	(0x87af_150a, "SQLITE_E_IOERR_SHMMAP", "SQLite extended error: I/O error while mapping shared memory."),
+	(0x87af_160a, "SQLITE_E_IOERR_SEEK", "SQLite extended error: I/O error while seeking in a file."),
+	(0x87af_170a, "SQLITE_E_IOERR_DELETE_NOENT", "SQLite extended error: the file to delete does not exist."),
+	(0x87af_180a, "SQLITE_E_IOERR_MMAP", "SQLite extended error: I/O error while memory mapping a file."),
+	(0x87af_190a, "SQLITE_E_IOERR_GETTEMPPATH", "SQLite extended error: unable to determine the temporary directory."),
+	(0x87af_1a02, "SQLITE_E_IOERR_VNODE", "SQLite extended error: the file was changed by another process."),
+	(0x87af_1a03, "SQLITE_E_IOERR_AUTH", "SQLite extended error: I/O authorization failure."),
+	(0x87af_1a0a, "SQLITE_E_IOERR_CONVPATH", "SQLite extended error: unable to convert the path for an I/O operation."),
+	(0x87c5_1001, "UTC_E_TOGGLE_TRACE_STARTED", "Toggle (alternative) trace started."),
+	(0x87c5_1002, "UTC_E_ALTERNATIVE_TRACE_CANNOT_PREEMPT", "Cannot pre-empt running trace. The current trace has a higher priority."),
+	(0x87c5_1003, "UTC_E_AOT_NOT_RUNNING", "The always-on-trace is not running."),
+	(0x87c5_1004, "UTC_E_SCRIPT_TYPE_INVALID", "RunScriptAction contains an invalid script type."),
+	(0x87c5_1005, "UTC_E_SCENARIODEF_NOT_FOUND", "Requested scenario definition cannot be found."),
+	(0x87c5_1006, "UTC_E_TRACEPROFILE_NOT_FOUND", "Requested trace profile cannot be found."),
+	(0x87c5_1007, "UTC_E_FORWARDER_ALREADY_ENABLED", "Trigger forwarder is already enabled."),
+	(0x87c5_1008, "UTC_E_FORWARDER_ALREADY_DISABLED", "Trigger forwarder is already disabled."),
+	(0x87c5_1009, "UTC_E_EVENTLOG_ENTRY_MALFORMED", "Cannot parse EventLog XML: the entry is malformed."),
+	(0x87c5_100a, "UTC_E_DIAGRULES_SCHEMAVERSION_MISMATCH", "<diagrules> node contains a schemaversion which is not compatible with this client."),
+	(0x87c5_100b, "UTC_E_SCRIPT_TERMINATED", "RunScriptAction was forced to terminate a script."),
+	(0x87c5_100c, "UTC_E_INVALID_CUSTOM_FILTER", "ToggleTraceWithCustomFilterAction contains an invalid custom filter."),
+	(0x87c5_100d, "UTC_E_TRACE_NOT_RUNNING", "The trace is not running."),
+	(0x87c5_100e, "UTC_E_REESCALATED_TOO_QUICKLY", "A scenario failed to escalate. This scenario has escalated too recently."),
+	(0x87c5_100f, "UTC_E_ESCALATION_ALREADY_RUNNING", "A scenario failed to escalate. This scenario is already escalating."),
+	(0x87c5_1010, "UTC_E_PERFTRACK_ALREADY_TRACING", "Cannot start tracing: PerfTrack component is already tracing."),
+	(0x87c5_1011, "UTC_E_REACHED_MAX_ESCALATIONS", "A scenario failed to escalate. This scenario has reached max escalations for this escalation type."),
+	(0x87c5_1012, "UTC_E_FORWARDER_PRODUCER_MISMATCH", "Cannot update forwarder: the forwarder passed to the function is of a different type."),
+	(0x87c5_1013, "UTC_E_INTENTIONAL_SCRIPT_FAILURE", "RunScriptAction failed intentionally to force this escalation to terminate."),
+	(0x87c5_1014, "UTC_E_SQM_INIT_FAILED", "Failed to initialize SQM logger."),
+	(0x87c5_1015, "UTC_E_NO_WER_LOGGER_SUPPORTED", "Failed to initialize WER logger. This system does not support WER for UTC."),
+	(0x87c5_1016, "UTC_E_TRACERS_DONT_EXIST", "The TraceManager has attempted to take a tracing action without initializing tracers."),
+	(0x87c5_1017, "UTC_E_WINRT_INIT_FAILED", "WinRT initialization failed."),
+	(0x87c5_1018, "UTC_E_SCENARIODEF_SCHEMAVERSION_MISMATCH", "<scenario> node contains a schemaversion that is not compatible with this client."),
+	(0x87c5_1019, "UTC_E_INVALID_FILTER", "Scenario contains an invalid filter that can never be satisfied."),
+	(0x87c5_101a, "UTC_E_EXE_TERMINATED", "RunExeWithArgsAction was forced to terminate a running executable."),
+	(0x87c5_101b, "UTC_E_ESCALATION_NOT_AUTHORIZED", "Escalation for scenario failed due to insufficient permissions."),
+	(0x87c5_101c, "UTC_E_SETUP_NOT_AUTHORIZED", "Setup for scenario failed due to insufficient permissions."),
+	(0x87c5_101d, "UTC_E_CHILD_PROCESS_FAILED", "A process launched by UTC failed with a non-zero exit code."),
+	(0x87c5_101e, "UTC_E_COMMAND_LINE_NOT_AUTHORIZED", "A RunExeWithArgs action contains an unauthorized command line."),
+	(0x87c5_101f, "UTC_E_CANNOT_LOAD_SCENARIO_EDITOR_XML", "UTC cannot load Scenario Editor XML. Convert the scenario file to a DiagTrack XML using the editor."),
+	(0x87c5_1020, "UTC_E_ESCALATION_TIMED_OUT", "Escalation for scenario has timed out."),
+	(0x87c5_1021, "UTC_E_SETUP_TIMED_OUT", "Setup for scenario has timed out."),
+	(0x87c5_1022, "UTC_E_TRIGGER_MISMATCH", "The given trigger does not match the expected trigger type."),
+	(0x87c5_1023, "UTC_E_TRIGGER_NOT_FOUND", "Requested trigger cannot be found."),
+	(0x87c5_1024, "UTC_E_SIF_NOT_SUPPORTED", "SIF is not supported on the machine."),
+	(0x87c5_1025, "UTC_E_DELAY_TERMINATED", "The delay action was terminated."),
+	(0x87c5_1026, "UTC_E_DEVICE_TICKET_ERROR", "The device ticket was not obtained."),
+	(0x87c5_1027, "UTC_E_TRACE_BUFFER_LIMIT_EXCEEDED", "The trace profile needs more buffers than the system allows."),
+	(0x87c5_1028, "UTC_E_API_RESULT_UNAVAILABLE", "The API was not completed successfully so the result is unavailable."),
+	(0x87c5_1029, "UTC_E_RPC_TIMEOUT", "The requested API encountered a timeout in the API manager."),
+	(0x87c5_102a, "UTC_E_RPC_WAIT_FAILED", "The synchronous API encountered a wait failure."),
+	(0x87c5_102b, "UTC_E_API_BUSY", "The UTC API is busy with another request."),
+	(0x87c5_102c, "UTC_E_TRACE_MIN_DURATION_REQUIREMENT_NOT_MET", "The running trace profile does not have a sufficient runtime to fulfill the escalation request."),
+	(0x87c5_102d, "UTC_E_EXCLUSIVITY_NOT_AVAILABLE", "The trace rundown action did not get exclusivity on the trace profile."),
+	(0x87c5_102e, "UTC_E_GETFILE_FILE_PATH_NOT_APPROVED", "The file path is not approved for the GetFile escalation action."),
+	(0x87c5_102f, "UTC_E_ESCALATION_DIRECTORY_ALREADY_EXISTS", "The escalation working directory for the requested escalation could not be created because it already exists."),
+	(0x87c5_1030, "UTC_E_TIME_TRIGGER_ON_START_INVALID", "Time triggers cannot be used on a transition originating from the \"_start\" state."),
+	(0x87c5_1031, "UTC_E_TIME_TRIGGER_ONLY_VALID_ON_SINGLE_TRANSITION", "Time triggers can only be used on a transition that is the only transition from a given state."),
+	(0x87c5_1032, "UTC_E_TIME_TRIGGER_INVALID_TIME_RANGE", "Time trigger duration must fall within an inclusive range of one second and 15 minutes."),
+	(0x87c5_1033, "UTC_E_MULTIPLE_TIME_TRIGGER_ON_SINGLE_STATE", "Only one Time Trigger is allowed per state."),
+	(0x87c5_1034, "UTC_E_BINARY_MISSING", "A RunExeWithArgs action contains a binary which is not present on the targeted device."),
+	(0x87c5_1035, "UTC_E_NETWORK_CAPTURE_NOT_ALLOWED", "A network capture trace is not allowed."),
+	(0x87c5_1036, "UTC_E_FAILED_TO_RESOLVE_CONTAINER_ID", "UTC failed to identify the container id to use for a scenario escalation action."),
+	(0x87c5_1037, "UTC_E_UNABLE_TO_RESOLVE_SESSION", "UTC failed to resolve the session for a scenario."),
+	(0x87c5_1038, "UTC_E_THROTTLED", "UTC has throttled the event for firing too often."),
+	(0x87c5_1039, "UTC_E_UNAPPROVED_SCRIPT", "The script is not approved to run as part of DiagTrack scenario."),
+	(0x87c5_103a, "UTC_E_SCRIPT_MISSING", "The script referenced in DiagTrack scenario is not present on the system."),
+	(0x87c5_103b, "UTC_E_SCENARIO_THROTTLED", "A trigger in this scenario is throttled, blocking the scenario from being loaded."),
+	(0x87c5_103c, "UTC_E_API_NOT_SUPPORTED", "The requested UTC API call is not supported on this device."),
+	(0x87c5_103d, "UTC_E_GETFILE_EXTERNAL_PATH_NOT_APPROVED", "The file path is not approved for collection on external rings for the GetFile escalation action."),
+	(0x87c5_103e, "UTC_E_TRY_GET_SCENARIO_TIMEOUT_EXCEEDED", "Querying a scenario definition exceeded the specified timeout."),
+	(0x87c5_103f, "UTC_E_CERT_REV_FAILED", "The certification revocation checking has failed."),
+	(0x87c5_1040, "UTC_E_FAILED_TO_START_NDISCAP", "The ETW Network Capture service failed to start."),
+	(0x87c5_1041, "UTC_E_KERNELDUMP_LIMIT_REACHED", "The kernel dump limit has been reached."),
+	(0x87c5_1042, "UTC_E_MISSING_AGGREGATE_EVENT_TAG", "The event contains an aggregation attribute but is missing the aggregation tag."),
+	(0x87c5_1043, "UTC_E_INVALID_AGGREGATION_STRUCT", "The event contains an aggregation structure that is not valid."),
+	(0x87c5_1044, "UTC_E_ACTION_NOT_SUPPORTED_IN_DESTINATION", "The action is not supported in this escalation destination."),
+	(0x87c5_1045, "UTC_E_FILTER_MISSING_ATTRIBUTE", "The filter is missing a required attribute."),
+	(0x87c5_1046, "UTC_E_FILTER_INVALID_TYPE", "The filter has an invalid type."),
+	(0x87c5_1047, "UTC_E_FILTER_VARIABLE_NOT_FOUND", "A variable referenced in the filter could not be found."),
+	(0x87c5_1048, "UTC_E_FILTER_FUNCTION_RESTRICTED", "A function used in the filter is restricted."),
+	(0x87c5_1049, "UTC_E_FILTER_VERSION_MISMATCH", "The filter version does not match the version supported by this client."),
+	(0x87c5_1050, "UTC_E_FILTER_INVALID_FUNCTION", "The filter uses an invalid function."),
+	(0x87c5_1051, "UTC_E_FILTER_INVALID_FUNCTION_PARAMS", "The filter uses a function with invalid parameters."),
+	(0x87c5_1052, "UTC_E_FILTER_INVALID_COMMAND", "The filter contains an invalid command."),
+	(0x87c5_1053, "UTC_E_FILTER_ILLEGAL_EVAL", "The filter contains an illegal evaluation."),
+	(0x87c5_1054, "UTC_E_TTTRACER_RETURNED_ERROR", "The time travel tracer returned an error."),
+	(0x87c5_1055, "UTC_E_AGENT_DIAGNOSTICS_TOO_LARGE", "The agent diagnostics data is too large."),
+	(0x87c5_1056, "UTC_E_FAILED_TO_RECEIVE_AGENT_DIAGNOSTICS", "Failed to receive the agent diagnostics data."),
+	(0x87c5_1057, "UTC_E_SCENARIO_HAS_NO_ACTIONS", "The scenario has no actions."),
+	(0x87c5_1058, "UTC_E_TTTRACER_STORAGE_FULL", "The time travel tracer storage is full."),
+	(0x87c5_1059, "UTC_E_INSUFFICIENT_SPACE_TO_START_TRACE", "There is not enough disk space to start the trace."),
+	(0x87c5_105a, "UTC_E_ESCALATION_CANCELLED_AT_SHUTDOWN", "The escalation was cancelled due to a system shutdown."),
+	(0x87c5_105b, "UTC_E_GETFILEINFOACTION_FILE_NOT_APPROVED", "The file is not approved for the GetFileInfo escalation action."),
+	(0x87c5_105c, "UTC_E_SETREGKEYACTION_TYPE_NOT_APPROVED", "The registry value type is not approved for the SetRegKey escalation action."),
+	(0x8801_0001, "WEP_E_NOT_PROVISIONED_ON_ALL_VOLUMES", "This PC is not provisioned to support device encryption. Please enable BitLocker on all volumes to comply with device encryption policy."),
+	(0x8801_0002, "WEP_E_FIXED_DATA_NOT_SUPPORTED", "This PC cannot support device encryption because unencrypted fixed data volumes are present."),
+	(0x8801_0003, "WEP_E_HARDWARE_NOT_COMPLIANT", "This PC does not meet the hardware requirements to support device encryption."),
+	(0x8801_0004, "WEP_E_LOCK_NOT_CONFIGURED", "Lock screen is not configured to be secure."),
+	(0x8801_0005, "WEP_E_PROTECTION_SUSPENDED", "Protection is suspended."),
+	(0x8801_0006, "WEP_E_NO_LICENSE", "This PC is not licensed for device encryption."),
+	(0x8801_0007, "WEP_E_OS_NOT_PROTECTED", "The operating system volume is not protected."),
+	(0x8801_0008, "WEP_E_UNEXPECTED_FAIL", "Access to the device encryption data failed unexpectedly."),
+	(0x8801_0009, "WEP_E_BUFFER_TOO_LARGE", "The buffer is too large."),
+	(0x8802_b001, "E_SYNCENGINE_FILE_SIZE_OVER_LIMIT", "The file size is larger than supported by the sync engine."),
+	(0x8802_b002, "E_SYNCENGINE_FILE_SIZE_EXCEEDS_REMAINING_QUOTA", "The file cannot be uploaded because it doesn't fit in the user's cloud storage quota."),
+	(0x8802_b003, "E_SYNCENGINE_UNSUPPORTED_FILE_NAME", "The file name contains invalid characters."),
+	(0x8802_b004, "E_SYNCENGINE_FOLDER_ITEM_COUNT_LIMIT_EXCEEDED", "The maximum file count has been reached for this folder in the sync engine."),
+	(0x8802_b005, "E_SYNCENGINE_FILE_SYNC_PARTNER_ERROR", "The file sync has been delegated to another program and has run into an issue."),
+	(0x8802_b006, "E_SYNCENGINE_SYNC_PAUSED_BY_SERVICE", "Sync has been delayed due to a throttling request from the service."),
+	(0x8802_c002, "E_SYNCENGINE_FILE_IDENTIFIER_UNKNOWN", "We can't seem to find that file. Please try again later."),
+	(0x8802_c003, "E_SYNCENGINE_SERVICE_AUTHENTICATION_FAILED", "The account you're signed in with doesn't have permission to open this file."),
+	(0x8802_c004, "E_SYNCENGINE_UNKNOWN_SERVICE_ERROR", "There was a problem connecting to the service. Please try again later."),
+	(0x8802_c005, "E_SYNCENGINE_SERVICE_RETURNED_UNEXPECTED_SIZE", "Sorry, there was a problem downloading the file."),
+	(0x8802_c006, "E_SYNCENGINE_REQUEST_BLOCKED_BY_SERVICE", "We're having trouble downloading the file right now. Please try again later."),
+	(0x8802_c007, "E_SYNCENGINE_REQUEST_BLOCKED_DUE_TO_CLIENT_ERROR", "We're having trouble downloading the file right now. Please try again later."),
+	(0x8802_d001, "E_SYNCENGINE_FOLDER_INACCESSIBLE", "The sync engine does not have permissions to access a local folder under the sync root."),
+	(0x8802_d002, "E_SYNCENGINE_UNSUPPORTED_FOLDER_NAME", "The folder name contains invalid characters."),
+	(0x8802_d003, "E_SYNCENGINE_UNSUPPORTED_MARKET", "The sync engine is not allowed to run in your current market."),
+	(0x8802_d004, "E_SYNCENGINE_PATH_LENGTH_LIMIT_EXCEEDED", "All files and folders can't be uploaded because a path of a file or folder is too long."),
+	(0x8802_d005, "E_SYNCENGINE_REMOTE_PATH_LENGTH_LIMIT_EXCEEDED", "All file and folders cannot be synchronized because a path of a file or folder would exceed the local path limit."),
+	(0x8802_d006, "E_SYNCENGINE_CLIENT_UPDATE_NEEDED", "Updates are needed in order to use the sync engine."),
+	(0x8802_d007, "E_SYNCENGINE_PROXY_AUTHENTICATION_REQUIRED", "The sync engine needs to authenticate with a proxy server."),
+	(0x8802_d008, "E_SYNCENGINE_STORAGE_SERVICE_PROVISIONING_FAILED", "There was a problem setting up the storage services for the account."),
+	(0x8802_d009, "E_SYNCENGINE_UNSUPPORTED_REPARSE_POINT", "Files can't be uploaded because there's an unsupported reparse point."),
+	(0x8802_d00a, "E_SYNCENGINE_STORAGE_SERVICE_BLOCKED", "The service has blocked your account from accessing the storage service."),
+	(0x8802_d00b, "E_SYNCENGINE_FOLDER_IN_REDIRECTION", "The action can't be performed right now because this folder is being moved. Please try again later."),
+	(0x8879_0001, "D3D10_ERROR_TOO_MANY_UNIQUE_STATE_OBJECTS", "There are too many unique instances of a particular type of state object."),
+	(0x8879_0002, "D3D10_ERROR_FILE_NOT_FOUND", "The file was not found."),
+	(0x887c_0001, "D3D11_ERROR_TOO_MANY_UNIQUE_STATE_OBJECTS", "There are too many unique instances of a particular type of state object."),
+	(0x887c_0002, "D3D11_ERROR_FILE_NOT_FOUND", "The file was not found."),
+	(0x887c_0003, "D3D11_ERROR_TOO_MANY_UNIQUE_VIEW_OBJECTS", "There are too many unique instances of a particular type of view object."),
+	(0x887c_0004, "D3D11_ERROR_DEFERRED_CONTEXT_MAP_WITHOUT_INITIAL_DISCARD", "The first call to ID3D11DeviceContext::Map after either ID3D11Device::CreateDeferredContext or ID3D11DeviceContext::FinishCommandList per Resource was not D3D11_MAP_WRITE_DISCARD."),
+	(0x887e_0001, "D3D12_ERROR_ADAPTER_NOT_FOUND", "The blob provided does not match the adapter that the device was created on."),
+	(0x887e_0002, "D3D12_ERROR_DRIVER_VERSION_MISMATCH", "The blob provided was created for a different version of the driver, and must be re-created."),
+	(0x8890_0001, "WINML_ERR_INVALID_DEVICE", "The binding is incomplete or does not match the input/output description."),
+	(0x8890_0002, "WINML_ERR_INVALID_BINDING", "An attempt was made to bind an unknown input or output."),
+	(0x8890_0003, "WINML_ERR_VALUE_NOTFOUND", "The given value was not found in the map."),
+	(0x8890_0004, "WINML_ERR_SIZE_MISMATCH", "The size of the buffer provided for a bound variable is invalid."),
+	(0x8898_0001, "MILERR_OBJECTBUSY", "The object is busy."),
+	(0x8898_0002, "MILERR_INSUFFICIENTBUFFER", "The buffer is too small."),
+	(0x8898_0003, "MILERR_WIN32ERROR", "A Win32 error occurred."),
+	(0x8898_0004, "MILERR_SCANNER_FAILED", "The scanner failed."),
+	(0x8898_0005, "MILERR_SCREENACCESSDENIED", "Access to the screen was denied."),
+	(0x8898_0006, "MILERR_DISPLAYSTATEINVALID", "The display state is invalid."),
+	(0x8898_0007, "MILERR_NONINVERTIBLEMATRIX", "The matrix is not invertible."),
+	(0x8898_0008, "MILERR_ZEROVECTOR", "A zero-length vector was supplied."),
+	(0x8898_0009, "MILERR_TERMINATED", "The operation was terminated."),
+	(0x8898_000a, "MILERR_BADNUMBER", "A bad number was supplied."),
+	(0x8898_0080, "MILERR_INTERNALERROR", "An internal error (MIL bug) occurred. On checked builds, an assert would be raised."),
+	(0x8898_0084, "MILERR_DISPLAYFORMATNOTSUPPORTED", "The display format is not supported."),
+	(0x8898_0085, "MILERR_INVALIDCALL", "A call to this method is invalid."),
+	(0x8898_0086, "MILERR_ALREADYLOCKED", "The object is already locked."),
+	(0x8898_0087, "MILERR_NOTLOCKED", "The object is not locked."),
+	(0x8898_0088, "MILERR_DEVICECANNOTRENDERTEXT", "The device cannot render text."),
+	(0x8898_0089, "MILERR_GLYPHBITMAPMISSED", "A glyph bitmap was missing from the cache."),
+	(0x8898_008a, "MILERR_MALFORMEDGLYPHCACHE", "The glyph cache is malformed."),
+	(0x8898_008b, "MILERR_GENERIC_IGNORE", "MIL error that should be ignored."),
+	(0x8898_008c, "MILERR_MALFORMED_GUIDELINE_DATA", "The guideline data is malformed."),
+	(0x8898_008d, "MILERR_NO_HARDWARE_DEVICE", "No HW rendering device is available for this operation."),
+	(0x8898_008e, "MILERR_NEED_RECREATE_AND_PRESENT", "There has been a presentation error that may be recoverable. The caller needs to recreate, rerender the entire frame, and reattempt present."),
+	(0x8898_008f, "MILERR_ALREADY_INITIALIZED", "The object has already been initialized."),
+	(0x8898_0090, "MILERR_MISMATCHED_SIZE", "The size of the object does not match the expected size."),
+	(0x8898_0091, "MILERR_NO_REDIRECTION_SURFACE_AVAILABLE", "No redirection surface is available."),
+	(0x8898_0092, "MILERR_REMOTING_NOT_SUPPORTED", "Remoting of this content is not supported."),
+	(0x8898_0093, "MILERR_QUEUED_PRESENT_NOT_SUPPORTED", "Queued presents are not supported."),
+	(0x8898_0094, "MILERR_NOT_QUEUING_PRESENTS", "Queued presents are not being used."),
+	(0x8898_0095, "MILERR_NO_REDIRECTION_SURFACE_RETRY_LATER", "No redirection surface was available. Caller should retry the call."),
+	(0x8898_0096, "MILERR_TOOMANYSHADERELEMNTS", "Shader construction failed because it was too complex."),
+	(0x8898_0097, "MILERR_MROW_READLOCK_FAILED", "MROW attempt to get a read lock failed."),
+	(0x8898_0098, "MILERR_MROW_UPDATE_FAILED", "MROW attempt to update the data failed because another update was outstanding."),
+	(0x8898_0099, "MILERR_SHADER_COMPILE_FAILED", "Shader compilation failed."),
+	(0x8898_009a, "MILERR_MAX_TEXTURE_SIZE_EXCEEDED", "Requested DX redirection surface size exceeded maximum texture size."),
+	(0x8898_009b, "MILERR_QPC_TIME_WENT_BACKWARD", "QueryPerformanceCounter returned a time in the past."),
+	(0x8898_009d, "MILERR_DXGI_ENUMERATION_OUT_OF_SYNC", "Primary Display device returned an invalid refresh rate."),
+	(0x8898_009e, "MILERR_ADAPTER_NOT_FOUND", "DWM can not find the adapter specified by the LUID."),
+	(0x8898_009f, "MILERR_COLORSPACE_NOT_SUPPORTED", "The requested bitmap color space is not supported."),
+	(0x8898_00a0, "MILERR_PREFILTER_NOT_SUPPORTED", "The requested bitmap pre-filtering state is not supported."),
+	(0x8898_00a1, "MILERR_DISPLAYID_ACCESS_DENIED", "Access is denied to the requested bitmap for the specified display id."),
+	(0x8898_0400, "UCEERR_INVALIDPACKETHEADER", "The packet header is invalid."),
+	(0x8898_0401, "UCEERR_UNKNOWNPACKET", "The packet type is unknown."),
+	(0x8898_0402, "UCEERR_ILLEGALPACKET", "The packet is illegal."),
+	(0x8898_0403, "UCEERR_MALFORMEDPACKET", "The packet is malformed."),
+	(0x8898_0404, "UCEERR_ILLEGALHANDLE", "The handle is illegal."),
+	(0x8898_0405, "UCEERR_HANDLELOOKUPFAILED", "The handle lookup failed."),
+	(0x8898_0406, "UCEERR_RENDERTHREADFAILURE", "The render thread failed."),
+	(0x8898_0407, "UCEERR_CTXSTACKFRSTTARGETNULL", "The first target on the context stack is null."),
+	(0x8898_0408, "UCEERR_CONNECTIONIDLOOKUPFAILED", "The connection ID lookup failed."),
+	(0x8898_0409, "UCEERR_BLOCKSFULL", "The blocks are full."),
+	(0x8898_040a, "UCEERR_MEMORYFAILURE", "A memory failure occurred."),
+	(0x8898_040b, "UCEERR_PACKETRECORDOUTOFRANGE", "The packet record is out of range."),
+	(0x8898_040c, "UCEERR_ILLEGALRECORDTYPE", "The record type is illegal."),
+	(0x8898_040d, "UCEERR_OUTOFHANDLES", "Out of handles."),
+	(0x8898_040e, "UCEERR_UNCHANGABLE_UPDATE_ATTEMPTED", "An attempt was made to update an unchangeable object."),
+	(0x8898_040f, "UCEERR_NO_MULTIPLE_WORKER_THREADS", "Multiple worker threads are not supported."),
+	(0x8898_0410, "UCEERR_REMOTINGNOTSUPPORTED", "Remoting is not supported."),
+	(0x8898_0411, "UCEERR_MISSINGENDCOMMAND", "The end command is missing."),
+	(0x8898_0412, "UCEERR_MISSINGBEGINCOMMAND", "The begin command is missing."),
+	(0x8898_0413, "UCEERR_CHANNELSYNCTIMEDOUT", "Channel synchronization timed out."),
+	(0x8898_0414, "UCEERR_CHANNELSYNCABANDONED", "Channel synchronization was abandoned."),
+	(0x8898_0415, "UCEERR_UNSUPPORTEDTRANSPORTVERSION", "The transport version is not supported."),
+	(0x8898_0416, "UCEERR_TRANSPORTUNAVAILABLE", "The transport is unavailable."),
+	(0x8898_0417, "UCEERR_FEEDBACK_UNSUPPORTED", "Feedback is not supported."),
+	(0x8898_0418, "UCEERR_COMMANDTRANSPORTDENIED", "The command transport was denied."),
+	(0x8898_0419, "UCEERR_GRAPHICSSTREAMUNAVAILABLE", "The graphics stream is unavailable."),
+	(0x8898_0420, "UCEERR_GRAPHICSSTREAMALREADYOPEN", "The graphics stream is already open."),
+	(0x8898_0421, "UCEERR_TRANSPORTDISCONNECTED", "The transport was disconnected."),
+	(0x8898_0422, "UCEERR_TRANSPORTOVERLOADED", "The transport is overloaded."),
+	(0x8898_0423, "UCEERR_PARTITION_ZOMBIED", "The partition is zombied."),
+	(0x8898_0500, "MILAVERR_NOCLOCK", "There is no clock."),
+	(0x8898_0501, "MILAVERR_NOMEDIATYPE", "There is no media type."),
+	(0x8898_0502, "MILAVERR_NOVIDEOMIXER", "There is no video mixer."),
+	(0x8898_0503, "MILAVERR_NOVIDEOPRESENTER", "There is no video presenter."),
+	(0x8898_0504, "MILAVERR_NOREADYFRAMES", "There are no ready frames."),
+	(0x8898_0505, "MILAVERR_MODULENOTLOADED", "The module was not loaded."),
+	(0x8898_0506, "MILAVERR_WMPFACTORYNOTREGISTERED", "The Windows Media Player factory is not registered."),
+	(0x8898_0507, "MILAVERR_INVALIDWMPVERSION", "The Windows Media Player version is invalid."),
+	(0x8898_0508, "MILAVERR_INSUFFICIENTVIDEORESOURCES", "There are insufficient video resources."),
+	(0x8898_0509, "MILAVERR_VIDEOACCELERATIONNOTAVAILABLE", "Video acceleration is not available."),
+	(0x8898_050a, "MILAVERR_REQUESTEDTEXTURETOOBIG", "The requested texture is too big."),
+	(0x8898_050b, "MILAVERR_SEEKFAILED", "The seek operation failed."),
+	(0x8898_050c, "MILAVERR_UNEXPECTEDWMPFAILURE", "An unexpected Windows Media Player failure occurred."),
+	(0x8898_050d, "MILAVERR_MEDIAPLAYERCLOSED", "The media player is closed."),
+	(0x8898_050e, "MILAVERR_UNKNOWNHARDWAREERROR", "An unknown hardware error occurred."),
+	(0x8898_060e, "MILEFFECTSERR_UNKNOWNPROPERTY", "The property is unknown."),
+	(0x8898_060f, "MILEFFECTSERR_EFFECTNOTPARTOFGROUP", "The effect is not part of a group."),
+	(0x8898_0610, "MILEFFECTSERR_NOINPUTSOURCEATTACHED", "No input source is attached."),
+	(0x8898_0611, "MILEFFECTSERR_CONNECTORNOTCONNECTED", "The connector is not connected."),
+	(0x8898_0612, "MILEFFECTSERR_CONNECTORNOTASSOCIATEDWITHEFFECT", "The connector is not associated with an effect."),
+	(0x8898_0613, "MILEFFECTSERR_RESERVED", "Reserved effects error."),
+	(0x8898_0614, "MILEFFECTSERR_CYCLEDETECTED", "A cycle was detected in the effect graph."),
+	(0x8898_0615, "MILEFFECTSERR_EFFECTINMORETHANONEGRAPH", "The effect is in more than one graph."),
+	(0x8898_0616, "MILEFFECTSERR_EFFECTALREADYINAGRAPH", "The effect is already in a graph."),
+	(0x8898_0617, "MILEFFECTSERR_EFFECTHASNOCHILDREN", "The effect has no children."),
+	(0x8898_0618, "MILEFFECTSERR_ALREADYATTACHEDTOLISTENER", "The effect is already attached to a listener."),
+	(0x8898_0619, "MILEFFECTSERR_NOTAFFINETRANSFORM", "The transform is not affine."),
+	(0x8898_061a, "MILEFFECTSERR_EMPTYBOUNDS", "The bounds are empty."),
+	(0x8898_061b, "MILEFFECTSERR_OUTPUTSIZETOOLARGE", "The output size is too large."),
+	(0x8898_0700, "DWMERR_STATE_TRANSITION_FAILED", "DWM state transition failed."),
+	(0x8898_0701, "DWMERR_THEME_FAILED", "DWM theme failed."),
+	(0x8898_0702, "DWMERR_CATASTROPHIC_FAILURE", "A catastrophic DWM failure occurred."),
+	(0x8898_0800, "DCOMPOSITION_ERROR_WINDOW_ALREADY_COMPOSED", "This window is already composed."),
+	(0x8898_0801, "DCOMPOSITION_ERROR_SURFACE_BEING_RENDERED", "The surface is currently being rendered."),
+	(0x8898_0802, "DCOMPOSITION_ERROR_SURFACE_NOT_BEING_RENDERED", "The surface is not currently being rendered."),
+	(0x8898_2f04, "WINCODEC_ERR_WRONGSTATE", "The codec is in the wrong state."),
+	(0x8898_2f05, "WINCODEC_ERR_VALUEOUTOFRANGE", "The value is out of range."),
+	(0x8898_2f07, "WINCODEC_ERR_UNKNOWNIMAGEFORMAT", "The image format is unknown."),
+	(0x8898_2f0b, "WINCODEC_ERR_UNSUPPORTEDVERSION", "The SDK version is unsupported."),
+	(0x8898_2f0c, "WINCODEC_ERR_NOTINITIALIZED", "The component is not initialized."),
+	(0x8898_2f0d, "WINCODEC_ERR_ALREADYLOCKED", "There is already an outstanding read or write lock."),
+	(0x8898_2f40, "WINCODEC_ERR_PROPERTYNOTFOUND", "The specified bitmap property cannot be found."),
+	(0x8898_2f41, "WINCODEC_ERR_PROPERTYNOTSUPPORTED", "The bitmap codec does not support the bitmap property."),
+	(0x8898_2f42, "WINCODEC_ERR_PROPERTYSIZE", "The bitmap property size is invalid."),
+	(0x8898_2f43, "WINCODEC_ERR_CODECPRESENT", "An unknown error has occurred."),
+	(0x8898_2f44, "WINCODEC_ERR_CODECNOTHUMBNAIL", "The bitmap codec does not support a thumbnail."),
+	(0x8898_2f45, "WINCODEC_ERR_PALETTEUNAVAILABLE", "The bitmap palette is unavailable."),
+	(0x8898_2f46, "WINCODEC_ERR_CODECTOOMANYSCANLINES", "Too many scanlines were requested."),
+	(0x8898_2f48, "WINCODEC_ERR_INTERNALERROR", "An internal error occurred."),
+	(0x8898_2f49, "WINCODEC_ERR_SOURCERECTDOESNOTMATCHDIMENSIONS", "The bitmap bounds do not match the bitmap dimensions."),
+	(0x8898_2f50, "WINCODEC_ERR_COMPONENTNOTFOUND", "The component cannot be found."),
+	(0x8898_2f51, "WINCODEC_ERR_IMAGESIZEOUTOFRANGE", "The bitmap size is outside the valid range."),
+	(0x8898_2f52, "WINCODEC_ERR_TOOMUCHMETADATA", "There is too much metadata to be written to the bitmap."),
+	(0x8898_2f60, "WINCODEC_ERR_BADIMAGE", "The image is unrecognized."),
+	(0x8898_2f61, "WINCODEC_ERR_BADHEADER", "The image header is unrecognized."),
+	(0x8898_2f62, "WINCODEC_ERR_FRAMEMISSING", "The bitmap frame is missing."),
+	(0x8898_2f63, "WINCODEC_ERR_BADMETADATAHEADER", "The image metadata header is unrecognized."),
+	(0x8898_2f70, "WINCODEC_ERR_BADSTREAMDATA", "The stream data is unrecognized."),
+	(0x8898_2f71, "WINCODEC_ERR_STREAMWRITE", "Failed to write to the stream."),
+	(0x8898_2f72, "WINCODEC_ERR_STREAMREAD", "Failed to read from the stream."),
+	(0x8898_2f73, "WINCODEC_ERR_STREAMNOTAVAILABLE", "The stream is not available."),
+	(0x8898_2f80, "WINCODEC_ERR_UNSUPPORTEDPIXELFORMAT", "The bitmap pixel format is unsupported."),
+	(0x8898_2f81, "WINCODEC_ERR_UNSUPPORTEDOPERATION", "The operation is unsupported."),
+	(0x8898_2f8a, "WINCODEC_ERR_INVALIDREGISTRATION", "The component registration is invalid."),
+	(0x8898_2f8b, "WINCODEC_ERR_COMPONENTINITIALIZEFAILURE", "The component initialization has failed."),
+	(0x8898_2f8c, "WINCODEC_ERR_INSUFFICIENTBUFFER", "The buffer allocated is insufficient."),
+	(0x8898_2f8d, "WINCODEC_ERR_DUPLICATEMETADATAPRESENT", "Duplicate metadata is present."),
+	(0x8898_2f8e, "WINCODEC_ERR_PROPERTYUNEXPECTEDTYPE", "The bitmap property type is unexpected."),
+	(0x8898_2f8f, "WINCODEC_ERR_UNEXPECTEDSIZE", "The size is unexpected."),
+	(0x8898_2f90, "WINCODEC_ERR_INVALIDQUERYREQUEST", "The property cannot be found."),
+	(0x8898_2f91, "WINCODEC_ERR_UNEXPECTEDMETADATATYPE", "The metadata type is unexpected."),
+	(0x8898_2f92, "WINCODEC_ERR_REQUESTONLYVALIDATMETADATAROOT", "The specified bitmap property is only valid at root level."),
+	(0x8898_2f93, "WINCODEC_ERR_INVALIDQUERYCHARACTER", "The query string contains an invalid character."),
+	(0x8898_2f94, "WINCODEC_ERR_WIN32ERROR", "Windows Codecs received an error from the Win32 system."),
+	(0x8898_2f95, "WINCODEC_ERR_INVALIDPROGRESSIVELEVEL", "The requested level of detail is not present."),
+	(0x8898_2f96, "WINCODEC_ERR_INVALIDJPEGSCANINDEX", "The scan index is invalid."),
+	(0x8898_5000, "DWRITE_E_FILEFORMAT", "Indicates an error in an input file such as a font file."),
+	(0x8898_5001, "DWRITE_E_UNEXPECTED", "Indicates an error originating in DirectWrite code, which is not expected to occur but is safe to recover from."),
+	(0x8898_5002, "DWRITE_E_NOFONT", "Indicates the specified font does not exist."),
+	(0x8898_5003, "DWRITE_E_FILENOTFOUND", "A font file could not be opened because the file, directory, network location, drive, or other storage location does not exist or is unavailable."),
+	(0x8898_5004, "DWRITE_E_FILEACCESS", "A font file exists but could not be opened due to access denied, sharing violation, or similar error."),
+	(0x8898_5005, "DWRITE_E_FONTCOLLECTIONOBSOLETE", "A font collection is obsolete due to changes in the system."),
+	(0x8898_5006, "DWRITE_E_ALREADYREGISTERED", "The given interface is already registered."),
+	(0x8898_5007, "DWRITE_E_CACHEFORMAT", "The font cache contains invalid data."),
+	(0x8898_5008, "DWRITE_E_CACHEVERSION", "A font cache file corresponds to a different version of DirectWrite."),
+	(0x8898_5009, "DWRITE_E_UNSUPPORTEDOPERATION", "The operation is not supported for this type of font."),
+	(0x8898_500a, "DWRITE_E_TEXTRENDERERINCOMPATIBLE", "The version of the text renderer interface is not compatible."),
+	(0x8898_500b, "DWRITE_E_FLOWDIRECTIONCONFLICTS", "The flow direction conflicts with the reading direction. They must be perpendicular to each other."),
+	(0x8898_500c, "DWRITE_E_NOCOLOR", "The font or glyph run does not contain any colored glyphs."),
+	(0x8898_500d, "DWRITE_E_REMOTEFONT", "A font resource could not be accessed because it is remote."),
+	(0x8898_500e, "DWRITE_E_DOWNLOADCANCELLED", "A font download was canceled."),
+	(0x8898_500f, "DWRITE_E_DOWNLOADFAILED", "A font download failed."),
+	(0x8898_5010, "DWRITE_E_TOOMANYDOWNLOADS", "A font download request was not added or a download failed because there are too many active downloads."),
+	(0x8899_0001, "D2DERR_WRONG_STATE", "The object was not in the correct state to process the method."),
+	(0x8899_0002, "D2DERR_NOT_INITIALIZED", "The object has not yet been initialized."),
+	(0x8899_0003, "D2DERR_UNSUPPORTED_OPERATION", "The requested operation is not supported."),
+	(0x8899_0004, "D2DERR_SCANNER_FAILED", "The geometry scanner failed to process the data."),
+	(0x8899_0005, "D2DERR_SCREEN_ACCESS_DENIED", "Direct2D could not access the screen."),
+	(0x8899_0006, "D2DERR_DISPLAY_STATE_INVALID", "A valid display state could not be determined."),
+	(0x8899_0007, "D2DERR_ZERO_VECTOR", "The supplied vector is zero."),
+	(0x8899_0008, "D2DERR_INTERNAL_ERROR", "An internal error (Direct2D bug) occurred. On checked builds, we would assert."),
+	(0x8899_0009, "D2DERR_DISPLAY_FORMAT_NOT_SUPPORTED", "The display format Direct2D needs to render is not supported by the hardware device."),
+	(0x8899_000a, "D2DERR_INVALID_CALL", "A call to this method is invalid."),
+	(0x8899_000b, "D2DERR_NO_HARDWARE_DEVICE", "No hardware rendering device is available for this operation."),
+	(0x8899_000c, "D2DERR_RECREATE_TARGET", "There has been a presentation error that may be recoverable. The caller needs to recreate, rerender the entire frame, and reattempt present."),
+	(0x8899_000d, "D2DERR_TOO_MANY_SHADER_ELEMENTS", "Shader construction failed because it was too complex."),
+	(0x8899_000e, "D2DERR_SHADER_COMPILE_FAILED", "Shader compilation failed."),
+	(0x8899_000f, "D2DERR_MAX_TEXTURE_SIZE_EXCEEDED", "Requested DirectX surface size exceeded maximum texture size."),
+	(0x8899_0010, "D2DERR_UNSUPPORTED_VERSION", "The requested Direct2D version is not supported."),
+	(0x8899_0011, "D2DERR_BAD_NUMBER", "Invalid number."),
+	(0x8899_0012, "D2DERR_WRONG_FACTORY", "Objects used together must be created from the same factory instance."),
+	(0x8899_0013, "D2DERR_LAYER_ALREADY_IN_USE", "A layer resource can only be in use once at any point in time."),
+	(0x8899_0014, "D2DERR_POP_CALL_DID_NOT_MATCH_PUSH", "The pop call did not match the corresponding push call."),
+	(0x8899_0015, "D2DERR_WRONG_RESOURCE_DOMAIN", "The resource was realized on the wrong render target."),
+	(0x8899_0016, "D2DERR_PUSH_POP_UNBALANCED", "The push and pop calls were unbalanced."),
+	(0x8899_0017, "D2DERR_RENDER_TARGET_HAS_LAYER_OR_CLIPRECT", "Attempt to copy from a render target while a layer or clip rect is applied."),
+	(0x8899_0018, "D2DERR_INCOMPATIBLE_BRUSH_TYPES", "The brush types are incompatible for the call."),
+	(0x8899_0019, "D2DERR_WIN32_ERROR", "An unknown win32 failure occurred."),
+	(0x8899_001a, "D2DERR_TARGET_NOT_GDI_COMPATIBLE", "The render target is not compatible with GDI."),
+	(0x8899_001b, "D2DERR_TEXT_EFFECT_IS_WRONG_TYPE", "A text client drawing effect object is of the wrong type."),
+	(0x8899_001c, "D2DERR_TEXT_RENDERER_NOT_RELEASED", "The application is holding a reference to the IDWriteTextRenderer interface after the corresponding DrawText or DrawTextLayout call has returned. The IDWriteTextRenderer instance will be invalid."),
+	(0x8899_001d, "D2DERR_EXCEEDS_MAX_BITMAP_SIZE", "The requested size is larger than the guaranteed supported texture size at the Direct3D device's current feature level."),
+	(0x8899_001e, "D2DERR_INVALID_GRAPH_CONFIGURATION", "There was a configuration error in the graph."),
+	(0x8899_001f, "D2DERR_INVALID_INTERNAL_GRAPH_CONFIGURATION", "There was a internal configuration error in the graph."),
+	(0x8899_0020, "D2DERR_CYCLIC_GRAPH", "There was a cycle in the graph."),
+	(0x8899_0021, "D2DERR_BITMAP_CANNOT_DRAW", "Cannot draw with a bitmap that has the D2D1_BITMAP_OPTIONS_CANNOT_DRAW option."),
+	(0x8899_0022, "D2DERR_OUTSTANDING_BITMAP_REFERENCES", "The operation cannot complete while there are outstanding references to the target bitmap."),
+	(0x8899_0023, "D2DERR_ORIGINAL_TARGET_NOT_BOUND", "The operation failed because the original target is not currently bound as a target."),
+	(0x8899_0024, "D2DERR_INVALID_TARGET", "Cannot set the image as a target because it is either an effect or is a bitmap that does not have the D2D1_BITMAP_OPTIONS_TARGET flag set."),
+	(0x8899_0025, "D2DERR_BITMAP_BOUND_AS_TARGET", "Cannot draw with a bitmap that is currently bound as the target bitmap."),
+	(0x8899_0026, "D2DERR_INSUFFICIENT_DEVICE_CAPABILITIES", "D3D Device does not have sufficient capabilities to perform the requested action."),
+	(0x8899_0027, "D2DERR_INTERMEDIATE_TOO_LARGE", "The graph could not be rendered with the context's current tiling settings."),
+	(0x8899_0028, "D2DERR_EFFECT_IS_NOT_REGISTERED", "The CLSID provided to Unregister did not correspond to a registered effect."),
+	(0x8899_0029, "D2DERR_INVALID_PROPERTY", "The specified property does not exist."),
+	(0x8899_002a, "D2DERR_NO_SUBPROPERTIES", "The specified sub-property does not exist."),
+	(0x8899_002b, "D2DERR_PRINT_JOB_CLOSED", "AddPage or Close called after print job is already closed."),
+	(0x8899_002c, "D2DERR_PRINT_FORMAT_NOT_SUPPORTED", "Error during print control creation. Indicates that none of the package target types (representing printer formats) are supported by Direct2D print control."),
+	(0x8899_002d, "D2DERR_TOO_MANY_TRANSFORM_INPUTS", "An effect attempted to use a transform with too many inputs."),
+	(0x8899_002e, "D2DERR_INVALID_GLYPH_IMAGE", "An error was encountered while decoding or parsing the requested glyph image."),
+	(0x8901_0001, "ERROR_IO_PREEMPTED", "The operation was preempted by a higher priority operation. It must be resumed later."),
+	(0x8902_0001, "JSCRIPT_E_CANTEXECUTE", "Function could not execute because it was deleted or garbage collected."),
+	(0xc009_0001, "ERROR_AUDITING_DISABLED", "The specified event is currently not being audited."),
+	(0xc009_0002, "ERROR_ALL_SIDS_FILTERED", "The SID filtering operation removed all SIDs."),
+	(0xc009_0003, "ERROR_BIZRULES_NOT_ENABLED", "Business rule scripts are disabled for the calling application."),
+	(0xc026_05e8, "ERROR_GRAPHICS_SESSION_TYPE_CHANGE_IN_PROGRESS", "The operation could not be completed because a session type change is in progress."),
+	(0xc026_1003, "ERROR_MONITOR_INVALID_DESCRIPTOR_CHECKSUM", "Checksum of the obtained monitor descriptor is invalid."),
+	(0xc026_1004, "ERROR_MONITOR_INVALID_STANDARD_TIMING_BLOCK", "Monitor descriptor contains an invalid standard timing block."),
+	(0xc026_1005, "ERROR_MONITOR_WMI_DATABLOCK_REGISTRATION_FAILED", "Windows Management Instrumentation (WMI) data block registration failed for one of the MSMonitorClass WMI subclasses."),
+	(0xc026_1006, "ERROR_MONITOR_INVALID_SERIAL_NUMBER_MONDSC_BLOCK", "Provided monitor descriptor block is either corrupted or does not contain the monitor's detailed serial number."),
+	(0xc026_1007, "ERROR_MONITOR_INVALID_USER_FRIENDLY_MONDSC_BLOCK", "Provided monitor descriptor block is either corrupted or does not contain the monitor's user-friendly name."),
+	(0xc026_1008, "ERROR_MONITOR_NO_MORE_DESCRIPTOR_DATA", "There is no monitor descriptor data at the specified (offset, size) region."),
+	(0xc026_1009, "ERROR_MONITOR_INVALID_DETAILED_TIMING_BLOCK", "Monitor descriptor contains an invalid detailed timing block."),
+	(0xc026_100a, "ERROR_MONITOR_INVALID_MANUFACTURE_DATE", "Monitor descriptor contains invalid manufacture date."),
+	(0xc026_2000, "ERROR_GRAPHICS_NOT_EXCLUSIVE_MODE_OWNER", "Exclusive mode ownership is needed to create unmanaged primary allocation."),
+	(0xc026_2001, "ERROR_GRAPHICS_INSUFFICIENT_DMA_BUFFER", "The driver needs more DMA buffer space in order to complete the requested operation."),
+	(0xc026_2002, "ERROR_GRAPHICS_INVALID_DISPLAY_ADAPTER", "Specified display adapter handle is invalid."),
+	(0xc026_2003, "ERROR_GRAPHICS_ADAPTER_WAS_RESET", "Specified display adapter and all of its state has been reset."),
+	(0xc026_2004, "ERROR_GRAPHICS_INVALID_DRIVER_MODEL", "The driver stack doesn't match the expected driver model."),
+	(0xc026_2005, "ERROR_GRAPHICS_PRESENT_MODE_CHANGED", "Present happened but ended up into the changed desktop mode."),
+	(0xc026_2006, "ERROR_GRAPHICS_PRESENT_OCCLUDED", "Nothing to present due to desktop occlusion."),
+	(0xc026_2007, "ERROR_GRAPHICS_PRESENT_DENIED", "Not able to present due to denial of desktop access."),
+	(0xc026_2008, "ERROR_GRAPHICS_CANNOTCOLORCONVERT", "Not able to present with color conversion."),
+	(0xc026_2009, "ERROR_GRAPHICS_DRIVER_MISMATCH", "The kernel driver detected a version mismatch between it and the user mode driver."),
+	(0xc026_200b, "ERROR_GRAPHICS_PRESENT_REDIRECTION_DISABLED", "Present redirection is disabled (desktop windowing management subsystem is off)."),
+	(0xc026_200c, "ERROR_GRAPHICS_PRESENT_UNOCCLUDED", "Previous exclusive VidPn source owner has released its ownership."),
+	(0xc026_200d, "ERROR_GRAPHICS_WINDOWDC_NOT_AVAILABLE", "Window DC is not available for presentation."),
+	(0xc026_200e, "ERROR_GRAPHICS_WINDOWLESS_PRESENT_DISABLED", "Windowless present is disabled (desktop windowing management subsystem is off)."),
+	(0xc026_200f, "ERROR_GRAPHICS_PRESENT_INVALID_WINDOW", "Window handle is invalid."),
+	(0xc026_2010, "ERROR_GRAPHICS_PRESENT_BUFFER_NOT_BOUND", "No buffer is bound to composition surface."),
+	(0xc026_2011, "ERROR_GRAPHICS_VAIL_STATE_CHANGED", "Vail state has been changed."),
+	(0xc026_2012, "ERROR_GRAPHICS_INDIRECT_DISPLAY_ABANDON_SWAPCHAIN", "Notifying indirect display UMDF class driver to abandon current swapchain."),
+	(0xc026_2013, "ERROR_GRAPHICS_INDIRECT_DISPLAY_DEVICE_STOPPED", "Notifying indirect display UMDF class driver that indirect display device has been stopped."),
+	(0xc026_2100, "ERROR_GRAPHICS_NO_VIDEO_MEMORY", "Not enough video memory is available to complete the operation."),
+	(0xc026_2101, "ERROR_GRAPHICS_CANT_LOCK_MEMORY", "Couldn't probe and lock the underlying memory of an allocation."),
+	(0xc026_2102, "ERROR_GRAPHICS_ALLOCATION_BUSY", "The allocation is currently busy."),
+	(0xc026_2103, "ERROR_GRAPHICS_TOO_MANY_REFERENCES", "An object being referenced has reached the maximum reference count already and can't be referenced further."),
+	(0xc026_2104, "ERROR_GRAPHICS_TRY_AGAIN_LATER", "A problem couldn't be solved due to some currently existing condition. The problem should be tried again later."),
+	(0xc026_2105, "ERROR_GRAPHICS_TRY_AGAIN_NOW", "A problem couldn't be solved due to some currently existing condition. The problem should be tried again immediately."),
+	(0xc026_2106, "ERROR_GRAPHICS_ALLOCATION_INVALID", "The allocation is invalid."),
+	(0xc026_2107, "ERROR_GRAPHICS_UNSWIZZLING_APERTURE_UNAVAILABLE", "No more unswizzling apertures are currently available."),
+	(0xc026_2108, "ERROR_GRAPHICS_UNSWIZZLING_APERTURE_UNSUPPORTED", "The current allocation can't be unswizzled by an aperture."),
+	(0xc026_2109, "ERROR_GRAPHICS_CANT_EVICT_PINNED_ALLOCATION", "The request failed because a pinned allocation can't be evicted."),
+	(0xc026_2110, "ERROR_GRAPHICS_INVALID_ALLOCATION_USAGE", "The allocation can't be used from its current segment location for the specified operation."),
+	(0xc026_2111, "ERROR_GRAPHICS_CANT_RENDER_LOCKED_ALLOCATION", "A locked allocation can't be used in the current command buffer."),
+	(0xc026_2112, "ERROR_GRAPHICS_ALLOCATION_CLOSED", "The allocation being referenced has been closed permanently."),
+	(0xc026_2113, "ERROR_GRAPHICS_INVALID_ALLOCATION_INSTANCE", "An invalid allocation instance is being referenced."),
+	(0xc026_2114, "ERROR_GRAPHICS_INVALID_ALLOCATION_HANDLE", "An invalid allocation handle is being referenced."),
+	(0xc026_2115, "ERROR_GRAPHICS_WRONG_ALLOCATION_DEVICE", "The allocation being referenced doesn't belong to the current device."),
+	(0xc026_2116, "ERROR_GRAPHICS_ALLOCATION_CONTENT_LOST", "The allocation being referenced has lost its content."),
+	(0xc026_2200, "ERROR_GRAPHICS_GPU_EXCEPTION_ON_DEVICE", "An exception was encountered while processing the current command buffer."),
+	(0xc026_2300, "ERROR_GRAPHICS_INVALID_VIDPN_TOPOLOGY", "Specified VidPN topology is invalid."),
+	(0xc026_2301, "ERROR_GRAPHICS_VIDPN_TOPOLOGY_NOT_SUPPORTED", "Specified VidPN topology is valid but is not supported by this model of the display adapter."),
+	(0xc026_2302, "ERROR_GRAPHICS_VIDPN_TOPOLOGY_CURRENTLY_NOT_SUPPORTED", "Specified VidPN topology is valid but is not supported by the display adapter at this time, due to current allocation of its resources."),
+	(0xc026_2303, "ERROR_GRAPHICS_INVALID_VIDPN", "Specified VidPN handle is invalid."),
+	(0xc026_2304, "ERROR_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE", "Specified video present source is invalid."),
+	(0xc026_2305, "ERROR_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET", "Specified video present target is invalid."),
+	(0xc026_2306, "ERROR_GRAPHICS_VIDPN_MODALITY_NOT_SUPPORTED", "Specified VidPN modality is not supported (e.g. at least two of the pinned modes are not cofunctional)."),
+	(0xc026_2308, "ERROR_GRAPHICS_INVALID_VIDPN_SOURCEMODESET", "Specified VidPN source mode set is invalid."),
+	(0xc026_2309, "ERROR_GRAPHICS_INVALID_VIDPN_TARGETMODESET", "Specified VidPN target mode set is invalid."),
+	(0xc026_230a, "ERROR_GRAPHICS_INVALID_FREQUENCY", "Specified video signal frequency is invalid."),
+	(0xc026_230b, "ERROR_GRAPHICS_INVALID_ACTIVE_REGION", "Specified video signal active region is invalid."),
+	(0xc026_230c, "ERROR_GRAPHICS_INVALID_TOTAL_REGION", "Specified video signal total region is invalid."),
+	(0xc026_2310, "ERROR_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE_MODE", "Specified video present source mode is invalid."),
+	(0xc026_2311, "ERROR_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET_MODE", "Specified video present target mode is invalid."),
+	(0xc026_2312, "ERROR_GRAPHICS_PINNED_MODE_MUST_REMAIN_IN_SET", "Pinned mode must remain in the set on VidPN's cofunctional modality enumeration."),
+	(0xc026_2313, "ERROR_GRAPHICS_PATH_ALREADY_IN_TOPOLOGY", "Specified video present path is already in VidPN's topology."),
+	(0xc026_2314, "ERROR_GRAPHICS_MODE_ALREADY_IN_MODESET", "Specified mode is already in the mode set."),
+	(0xc026_2315, "ERROR_GRAPHICS_INVALID_VIDEOPRESENTSOURCESET", "Specified video present source set is invalid."),
+	(0xc026_2316, "ERROR_GRAPHICS_INVALID_VIDEOPRESENTTARGETSET", "Specified video present target set is invalid."),
+	(0xc026_2317, "ERROR_GRAPHICS_SOURCE_ALREADY_IN_SET", "Specified video present source is already in the video present source set."),
+	(0xc026_2318, "ERROR_GRAPHICS_TARGET_ALREADY_IN_SET", "Specified video present target is already in the video present target set."),
+	(0xc026_2319, "ERROR_GRAPHICS_INVALID_VIDPN_PRESENT_PATH", "Specified VidPN present path is invalid."),
+	(0xc026_231a, "ERROR_GRAPHICS_NO_RECOMMENDED_VIDPN_TOPOLOGY", "Miniport has no recommendation for augmentation of the specified VidPN's topology."),
+	(0xc026_231b, "ERROR_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGESET", "Specified monitor frequency range set is invalid."),
+	(0xc026_231c, "ERROR_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE", "Specified monitor frequency range is invalid."),
+	(0xc026_231d, "ERROR_GRAPHICS_FREQUENCYRANGE_NOT_IN_SET", "Specified frequency range is not in the specified monitor frequency range set."),
+	(0xc026_231f, "ERROR_GRAPHICS_FREQUENCYRANGE_ALREADY_IN_SET", "Specified frequency range is already in the specified monitor frequency range set."),
+	(0xc026_2320, "ERROR_GRAPHICS_STALE_MODESET", "Specified mode set is stale. Please reacquire the new mode set."),
+	(0xc026_2321, "ERROR_GRAPHICS_INVALID_MONITOR_SOURCEMODESET", "Specified monitor source mode set is invalid."),
+	(0xc026_2322, "ERROR_GRAPHICS_INVALID_MONITOR_SOURCE_MODE", "Specified monitor source mode is invalid."),
+	(0xc026_2323, "ERROR_GRAPHICS_NO_RECOMMENDED_FUNCTIONAL_VIDPN", "Miniport does not have any recommendation regarding the request to provide a functional VidPN given the current display adapter configuration."),
+	(0xc026_2324, "ERROR_GRAPHICS_MODE_ID_MUST_BE_UNIQUE", "ID of the specified mode is already used by another mode in the set."),
+	(0xc026_2325, "ERROR_GRAPHICS_EMPTY_ADAPTER_MONITOR_MODE_SUPPORT_INTERSECTION", "System failed to determine a mode that is supported by both the display adapter and the monitor connected to it."),
+	(0xc026_2326, "ERROR_GRAPHICS_VIDEO_PRESENT_TARGETS_LESS_THAN_SOURCES", "Number of video present targets must be greater than or equal to the number of video present sources."),
+	(0xc026_2327, "ERROR_GRAPHICS_PATH_NOT_IN_TOPOLOGY", "Specified present path is not in VidPN's topology."),
+	(0xc026_2328, "ERROR_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_SOURCE", "Display adapter must have at least one video present source."),
+	(0xc026_2329, "ERROR_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_TARGET", "Display adapter must have at least one video present target."),
+	(0xc026_232a, "ERROR_GRAPHICS_INVALID_MONITORDESCRIPTORSET", "Specified monitor descriptor set is invalid."),
+	(0xc026_232b, "ERROR_GRAPHICS_INVALID_MONITORDESCRIPTOR", "Specified monitor descriptor is invalid."),
+	(0xc026_232c, "ERROR_GRAPHICS_MONITORDESCRIPTOR_NOT_IN_SET", "Specified descriptor is not in the specified monitor descriptor set."),
+	(0xc026_232d, "ERROR_GRAPHICS_MONITORDESCRIPTOR_ALREADY_IN_SET", "Specified descriptor is already in the specified monitor descriptor set."),
+	(0xc026_232e, "ERROR_GRAPHICS_MONITORDESCRIPTOR_ID_MUST_BE_UNIQUE", "ID of the specified monitor descriptor is already used by another descriptor in the set."),
+	(0xc026_232f, "ERROR_GRAPHICS_INVALID_VIDPN_TARGET_SUBSET_TYPE", "Specified video present target subset type is invalid."),
+	(0xc026_2330, "ERROR_GRAPHICS_RESOURCES_NOT_RELATED", "Two or more of the specified resources are not related to each other, as defined by the interface semantics."),
+	(0xc026_2331, "ERROR_GRAPHICS_SOURCE_ID_MUST_BE_UNIQUE", "ID of the specified video present source is already used by another source in the set."),
+	(0xc026_2332, "ERROR_GRAPHICS_TARGET_ID_MUST_BE_UNIQUE", "ID of the specified video present target is already used by another target in the set."),
+	(0xc026_2333, "ERROR_GRAPHICS_NO_AVAILABLE_VIDPN_TARGET", "Specified VidPN source cannot be used because there is no available VidPN target to connect it to."),
+	(0xc026_2334, "ERROR_GRAPHICS_MONITOR_COULD_NOT_BE_ASSOCIATED_WITH_ADAPTER", "Newly arrived monitor could not be associated with a display adapter."),
+	(0xc026_2335, "ERROR_GRAPHICS_NO_VIDPNMGR", "Display adapter in question does not have an associated VidPN manager."),
+	(0xc026_2336, "ERROR_GRAPHICS_NO_ACTIVE_VIDPN", "VidPN manager of the display adapter in question does not have an active VidPN."),
+	(0xc026_2337, "ERROR_GRAPHICS_STALE_VIDPN_TOPOLOGY", "Specified VidPN topology is stale. Please reacquire the new topology."),
+	(0xc026_2338, "ERROR_GRAPHICS_MONITOR_NOT_CONNECTED", "There is no monitor connected on the specified video present target."),
+	(0xc026_2339, "ERROR_GRAPHICS_SOURCE_NOT_IN_TOPOLOGY", "Specified source is not part of the specified VidPN's topology."),
+	(0xc026_233a, "ERROR_GRAPHICS_INVALID_PRIMARYSURFACE_SIZE", "Specified primary surface size is invalid."),
+	(0xc026_233b, "ERROR_GRAPHICS_INVALID_VISIBLEREGION_SIZE", "Specified visible region size is invalid."),
+	(0xc026_233c, "ERROR_GRAPHICS_INVALID_STRIDE", "Specified stride is invalid."),
+	(0xc026_233d, "ERROR_GRAPHICS_INVALID_PIXELFORMAT", "Specified pixel format is invalid."),
+	(0xc026_233e, "ERROR_GRAPHICS_INVALID_COLORBASIS", "Specified color basis is invalid."),
+	(0xc026_233f, "ERROR_GRAPHICS_INVALID_PIXELVALUEACCESSMODE", "Specified pixel value access mode is invalid."),
+	(0xc026_2340, "ERROR_GRAPHICS_TARGET_NOT_IN_TOPOLOGY", "Specified target is not part of the specified VidPN's topology."),
+	(0xc026_2341, "ERROR_GRAPHICS_NO_DISPLAY_MODE_MANAGEMENT_SUPPORT", "Failed to acquire display mode management interface."),
+	(0xc026_2342, "ERROR_GRAPHICS_VIDPN_SOURCE_IN_USE", "Specified VidPN source is already owned by a DMM client and cannot be used until that client releases it."),
+	(0xc026_2343, "ERROR_GRAPHICS_CANT_ACCESS_ACTIVE_VIDPN", "Specified VidPN is active and cannot be accessed."),
+	(0xc026_2344, "ERROR_GRAPHICS_INVALID_PATH_IMPORTANCE_ORDINAL", "Specified VidPN present path importance ordinal is invalid."),
+	(0xc026_2345, "ERROR_GRAPHICS_INVALID_PATH_CONTENT_GEOMETRY_TRANSFORMATION", "Specified VidPN present path content geometry transformation is invalid."),
+	(0xc026_2346, "ERROR_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_SUPPORTED", "Specified content geometry transformation is not supported on the respective VidPN present path."),
+	(0xc026_2347, "ERROR_GRAPHICS_INVALID_GAMMA_RAMP", "Specified gamma ramp is invalid."),
+	(0xc026_2348, "ERROR_GRAPHICS_GAMMA_RAMP_NOT_SUPPORTED", "Specified gamma ramp is not supported on the respective VidPN present path."),
+	(0xc026_2349, "ERROR_GRAPHICS_MULTISAMPLING_NOT_SUPPORTED", "Multi-sampling is not supported on the respective VidPN present path."),
+	(0xc026_234a, "ERROR_GRAPHICS_MODE_NOT_IN_MODESET", "Specified mode is not in the specified mode set."),
+	(0xc026_234d, "ERROR_GRAPHICS_INVALID_VIDPN_TOPOLOGY_RECOMMENDATION_REASON", "Specified VidPN topology recommendation reason is invalid."),
+	(0xc026_234e, "ERROR_GRAPHICS_INVALID_PATH_CONTENT_TYPE", "Specified VidPN present path content type is invalid."),
+	(0xc026_234f, "ERROR_GRAPHICS_INVALID_COPYPROTECTION_TYPE", "Specified VidPN present path copy protection type is invalid."),
+	(0xc026_2350, "ERROR_GRAPHICS_UNASSIGNED_MODESET_ALREADY_EXISTS", "No more than one unassigned mode set can exist at any given time for a given VidPN source/target."),
+	(0xc026_2352, "ERROR_GRAPHICS_INVALID_SCANLINE_ORDERING", "Specified scanline ordering type is invalid."),
+	(0xc026_2353, "ERROR_GRAPHICS_TOPOLOGY_CHANGES_NOT_ALLOWED", "Topology changes are not allowed for the specified VidPN."),
+	(0xc026_2354, "ERROR_GRAPHICS_NO_AVAILABLE_IMPORTANCE_ORDINALS", "All available importance ordinals are already used in specified topology."),
+	(0xc026_2355, "ERROR_GRAPHICS_INCOMPATIBLE_PRIVATE_FORMAT", "Specified primary surface has a different private format attribute than the current primary surface."),
+	(0xc026_2356, "ERROR_GRAPHICS_INVALID_MODE_PRUNING_ALGORITHM", "Specified mode pruning algorithm is invalid."),
+	(0xc026_2357, "ERROR_GRAPHICS_INVALID_MONITOR_CAPABILITY_ORIGIN", "Specified monitor capability origin is invalid."),
+	(0xc026_2358, "ERROR_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE_CONSTRAINT", "Specified monitor frequency range constraint is invalid."),
+	(0xc026_2359, "ERROR_GRAPHICS_MAX_NUM_PATHS_REACHED", "Maximum supported number of present paths has been reached."),
+	(0xc026_235a, "ERROR_GRAPHICS_CANCEL_VIDPN_TOPOLOGY_AUGMENTATION", "Miniport requested that augmentation be cancelled for the specified source of the specified VidPN's topology."),
+	(0xc026_235b, "ERROR_GRAPHICS_INVALID_CLIENT_TYPE", "Specified client type was not recognized."),
+	(0xc026_235c, "ERROR_GRAPHICS_CLIENTVIDPN_NOT_SET", "Client VidPN is not set on this adapter (e.g. no user mode initiated mode changes took place on this adapter yet)."),
+	(0xc026_2400, "ERROR_GRAPHICS_SPECIFIED_CHILD_ALREADY_CONNECTED", "Specified display adapter child device already has an external device connected to it."),
+	(0xc026_2401, "ERROR_GRAPHICS_CHILD_DESCRIPTOR_NOT_SUPPORTED", "Specified display adapter child device does not support descriptor exposure."),
+	(0xc026_2430, "ERROR_GRAPHICS_NOT_A_LINKED_ADAPTER", "The display adapter is not linked to any other adapters."),
+	(0xc026_2431, "ERROR_GRAPHICS_LEADLINK_NOT_ENUMERATED", "Lead adapter in a linked configuration was not enumerated yet."),
+	(0xc026_2432, "ERROR_GRAPHICS_CHAINLINKS_NOT_ENUMERATED", "Some chain adapters in a linked configuration were not enumerated yet."),
+	(0xc026_2433, "ERROR_GRAPHICS_ADAPTER_CHAIN_NOT_READY", "The chain of linked adapters is not ready to start because of an unknown failure."),
+	(0xc026_2434, "ERROR_GRAPHICS_CHAINLINKS_NOT_STARTED", "An attempt was made to start a lead link display adapter when the chain links were not started yet."),
+	(0xc026_2435, "ERROR_GRAPHICS_CHAINLINKS_NOT_POWERED_ON", "An attempt was made to power up a lead link display adapter when the chain links were powered down."),
+	(0xc026_2436, "ERROR_GRAPHICS_INCONSISTENT_DEVICE_LINK_STATE", "The adapter link was found to be in an inconsistent state. Not all adapters are in an expected PNP/Power state."),
+	(0xc026_2438, "ERROR_GRAPHICS_NOT_POST_DEVICE_DRIVER", "The driver trying to start is not the same as the driver for the POSTed display adapter."),
+	(0xc026_243b, "ERROR_GRAPHICS_ADAPTER_ACCESS_NOT_EXCLUDED", "An operation is being attempted that requires the display adapter to be in a quiescent state."),
+	(0xc026_2500, "ERROR_GRAPHICS_OPM_NOT_SUPPORTED", "The driver does not support OPM."),
+	(0xc026_2501, "ERROR_GRAPHICS_COPP_NOT_SUPPORTED", "The driver does not support COPP."),
+	(0xc026_2502, "ERROR_GRAPHICS_UAB_NOT_SUPPORTED", "The driver does not support UAB."),
+	(0xc026_2503, "ERROR_GRAPHICS_OPM_INVALID_ENCRYPTED_PARAMETERS", "The specified encrypted parameters are invalid."),
+	(0xc026_2505, "ERROR_GRAPHICS_OPM_NO_VIDEO_OUTPUTS_EXIST", "The GDI display device passed to this function does not have any active video outputs."),
+	(0xc026_250b, "ERROR_GRAPHICS_OPM_INTERNAL_ERROR", "An internal error caused this operation to fail."),
+	(0xc026_250c, "ERROR_GRAPHICS_OPM_INVALID_HANDLE", "The function failed because the caller passed in an invalid OPM user mode handle."),
+	(0xc026_250e, "ERROR_GRAPHICS_PVP_INVALID_CERTIFICATE_LENGTH", "A certificate could not be returned because the certificate buffer passed to the function was too small."),
+	(0xc026_250f, "ERROR_GRAPHICS_OPM_SPANNING_MODE_ENABLED", "A video output could not be created because the frame buffer is in spanning mode."),
+	(0xc026_2510, "ERROR_GRAPHICS_OPM_THEATER_MODE_ENABLED", "A video output could not be created because the frame buffer is in theater mode."),
+	(0xc026_2511, "ERROR_GRAPHICS_PVP_HFS_FAILED", "The function failed because the display adapter's Hardware Functionality Scan failed to validate the graphics hardware."),
+	(0xc026_2512, "ERROR_GRAPHICS_OPM_INVALID_SRM", "The HDCP System Renewability Message passed to this function did not comply with section 5 of the HDCP 1.1 specification."),
+	(0xc026_2513, "ERROR_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_HDCP", "The video output cannot enable the High-bandwidth Digital Content Protection (HDCP) System because it does not support HDCP."),
+	(0xc026_2514, "ERROR_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_ACP", "The video output cannot enable Analogue Copy Protection (ACP) because it does not support ACP."),
+	(0xc026_2515, "ERROR_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_CGMSA", "The video output cannot enable the Content Generation Management System Analogue (CGMS-A) protection technology because it does not support CGMS-A."),
+	(0xc026_2516, "ERROR_GRAPHICS_OPM_HDCP_SRM_NEVER_SET", "The IOPMVideoOutput::GetInformation method cannot return the version of the SRM being used because the application never successfully passed an SRM to the video output."),
+	(0xc026_2517, "ERROR_GRAPHICS_OPM_RESOLUTION_TOO_HIGH", "The IOPMVideoOutput::Configure method cannot enable the specified output protection technology because the output's screen resolution is too high."),
+	(0xc026_2518, "ERROR_GRAPHICS_OPM_ALL_HDCP_HARDWARE_ALREADY_IN_USE", "The IOPMVideoOutput::Configure method cannot enable HDCP because the display adapter's HDCP hardware is already being used by other physical outputs."),
+	(0xc026_251a, "ERROR_GRAPHICS_OPM_VIDEO_OUTPUT_NO_LONGER_EXISTS", "The operating system asynchronously destroyed this OPM video output because the operating system's state changed."),
+	(0xc026_251b, "ERROR_GRAPHICS_OPM_SESSION_TYPE_CHANGE_IN_PROGRESS", "The method failed because the session is changing its type. No IOPMVideoOutput methods can be called when a session is changing its type."),
+	(0xc026_251c, "ERROR_GRAPHICS_OPM_VIDEO_OUTPUT_DOES_NOT_HAVE_COPP_SEMANTICS", "Either the IOPMVideoOutput::COPPCompatibleGetInformation, IOPMVideoOutput::GetInformation, or IOPMVideoOutput::Configure method failed. This error is returned when the caller tries to use a COPP specific command while the video output has OPM semantics only."),
+	(0xc026_251d, "ERROR_GRAPHICS_OPM_INVALID_INFORMATION_REQUEST", "The IOPMVideoOutput::GetInformation and IOPMVideoOutput::COPPCompatibleGetInformation methods return this error if the passed in sequence number is not the expected sequence number or the passed in OMAC value is invalid."),
+	(0xc026_251e, "ERROR_GRAPHICS_OPM_DRIVER_INTERNAL_ERROR", "The method failed because an unexpected error occurred inside of a display driver."),
+	(0xc026_251f, "ERROR_GRAPHICS_OPM_VIDEO_OUTPUT_DOES_NOT_HAVE_OPM_SEMANTICS", "Either the IOPMVideoOutput::COPPCompatibleGetInformation, IOPMVideoOutput::GetInformation, or IOPMVideoOutput::Configure method failed. This error is returned when the caller tries to use an OPM specific command while the video output has COPP semantics only."),
+	(0xc026_2520, "ERROR_GRAPHICS_OPM_SIGNALING_NOT_SUPPORTED", "The IOPMVideoOutput::COPPCompatibleGetInformation or IOPMVideoOutput::Configure method failed because the display driver does not support the OPM_GET_ACP_AND_CGMSA_SIGNALING and OPM_SET_ACP_AND_CGMSA_SIGNALING GUIDs."),
+	(0xc026_2521, "ERROR_GRAPHICS_OPM_INVALID_CONFIGURATION_REQUEST", "The IOPMVideoOutput::Configure function returns this error code if the passed in sequence number is not the expected sequence number or the passed in OMAC value is invalid."),
+	(0xc026_2580, "ERROR_GRAPHICS_I2C_NOT_SUPPORTED", "The monitor connected to the specified video output does not have an I2C bus."),
+	(0xc026_2581, "ERROR_GRAPHICS_I2C_DEVICE_DOES_NOT_EXIST", "No device on the I2C bus has the specified address."),
+	(0xc026_2582, "ERROR_GRAPHICS_I2C_ERROR_TRANSMITTING_DATA", "An error occurred while transmitting data to the device on the I2C bus."),
+	(0xc026_2583, "ERROR_GRAPHICS_I2C_ERROR_RECEIVING_DATA", "An error occurred while receiving data from the device on the I2C bus."),
+	(0xc026_2584, "ERROR_GRAPHICS_DDCCI_VCP_NOT_SUPPORTED", "The monitor does not support the specified VCP code."),
+	(0xc026_2585, "ERROR_GRAPHICS_DDCCI_INVALID_DATA", "The data received from the monitor is invalid."),
+	(0xc026_2586, "ERROR_GRAPHICS_DDCCI_MONITOR_RETURNED_INVALID_TIMING_STATUS_BYTE", "The function failed because a monitor returned an invalid Timing Status byte when the operating system used the DDC/CI Get Timing Report & Timing Message command to get a timing report from a monitor."),
+	(0xc026_2587, "ERROR_GRAPHICS_MCA_INVALID_CAPABILITIES_STRING", "The monitor returned a DDC/CI capabilities string which did not comply with the ACCESS.bus 3.0, DDC/CI 1.1, or MCCS 2 Revision 1 specification."),
+	(0xc026_2588, "ERROR_GRAPHICS_MCA_INTERNAL_ERROR", "An internal Monitor Configuration API error occurred."),
+	(0xc026_2589, "ERROR_GRAPHICS_DDCCI_INVALID_MESSAGE_COMMAND", "An operation failed because a DDC/CI message had an invalid value in its command field."),
+	(0xc026_258a, "ERROR_GRAPHICS_DDCCI_INVALID_MESSAGE_LENGTH", "An error occurred because the field length of a DDC/CI message contained an invalid value."),
+	(0xc026_258b, "ERROR_GRAPHICS_DDCCI_INVALID_MESSAGE_CHECKSUM", "An error occurred because the checksum field in a DDC/CI message did not match the message's computed checksum value."),
+	(0xc026_258c, "ERROR_GRAPHICS_INVALID_PHYSICAL_MONITOR_HANDLE", "This function failed because an invalid monitor handle was passed to it."),
+	(0xc026_258d, "ERROR_GRAPHICS_MONITOR_NO_LONGER_EXISTS", "The operating system asynchronously destroyed the monitor which corresponds to this handle because the operating system's state changed."),
+	(0xc026_25d8, "ERROR_GRAPHICS_DDCCI_CURRENT_CURRENT_VALUE_GREATER_THAN_MAXIMUM_VALUE", "A continuous VCP code's current value is greater than its maximum value."),
+	(0xc026_25d9, "ERROR_GRAPHICS_MCA_INVALID_VCP_VERSION", "The monitor's VCP Version (0xDF) VCP code returned an invalid version value."),
+	(0xc026_25da, "ERROR_GRAPHICS_MCA_MONITOR_VIOLATES_MCCS_SPECIFICATION", "The monitor does not comply with the MCCS specification it claims to support."),
+	(0xc026_25db, "ERROR_GRAPHICS_MCA_MCCS_VERSION_MISMATCH", "The MCCS version in a monitor's mccs_ver capability does not match the MCCS version the monitor reports when the VCP Version (0xDF) VCP code is used."),
+	(0xc026_25dc, "ERROR_GRAPHICS_MCA_UNSUPPORTED_MCCS_VERSION", "The Monitor Configuration API only works with monitors that support the MCCS 1.0 specification, MCCS 2.0 specification, or the MCCS 2.0 Revision 1 specification."),
+	(0xc026_25de, "ERROR_GRAPHICS_MCA_INVALID_TECHNOLOGY_TYPE_RETURNED", "The monitor returned an invalid monitor technology type. CRT, Plasma and LCD (TFT) are examples of monitor technology types."),
+	(0xc026_25df, "ERROR_GRAPHICS_MCA_UNSUPPORTED_COLOR_TEMPERATURE", "The SetMonitorColorTemperature() caller passed a color temperature to it which the current monitor did not support."),
+	(0xc026_25e0, "ERROR_GRAPHICS_ONLY_CONSOLE_SESSION_SUPPORTED", "This function can only be used if a program is running in the local console session. It cannot be used if the program is running on a remote desktop session or on a terminal server session."),
+	(0xc026_25e1, "ERROR_GRAPHICS_NO_DISPLAY_DEVICE_CORRESPONDS_TO_NAME", "This function cannot find an actual GDI display device which corresponds to the specified GDI display device name."),
+	(0xc026_25e2, "ERROR_GRAPHICS_DISPLAY_DEVICE_NOT_ATTACHED_TO_DESKTOP", "The function failed because the specified GDI display device was not attached to the Windows desktop."),
+	(0xc026_25e3, "ERROR_GRAPHICS_MIRRORING_DEVICES_NOT_SUPPORTED", "This function does not support GDI mirroring display devices because GDI mirroring display devices do not have any physical monitors associated with them."),
+	(0xc026_25e4, "ERROR_GRAPHICS_INVALID_POINTER", "The function failed because an invalid pointer parameter was passed to it."),
+	(0xc026_25e5, "ERROR_GRAPHICS_NO_MONITORS_CORRESPOND_TO_DISPLAY_DEVICE", "The function failed because the specified GDI device did not have any monitors associated with it."),
+	(0xc026_25e6, "ERROR_GRAPHICS_PARAMETER_ARRAY_TOO_SMALL", "An array passed to the function cannot hold all of the data that the function must copy into the array."),
+	(0xc026_25e7, "ERROR_GRAPHICS_INTERNAL_ERROR", "An internal error caused an operation to fail."),
+	(0xc05c_0000, "ERROR_SVHDX_ERROR_STORED", "The initiator has a stored error response on the shared virtual disk."),
+	(0xc05c_ff00, "ERROR_SVHDX_ERROR_NOT_AVAILABLE", "The initiator does not have a stored error response on the shared virtual disk."),
+	(0xc05c_ff01, "ERROR_SVHDX_UNIT_ATTENTION_AVAILABLE", "A unit attention is pending for the initiator."),
+	(0xc05c_ff02, "ERROR_SVHDX_UNIT_ATTENTION_CAPACITY_DATA_CHANGED", "The capacity data of the shared virtual disk has changed."),
+	(0xc05c_ff03, "ERROR_SVHDX_UNIT_ATTENTION_RESERVATIONS_PREEMPTED", "The reservations on the shared virtual disk were preempted."),
+	(0xc05c_ff04, "ERROR_SVHDX_UNIT_ATTENTION_RESERVATIONS_RELEASED", "The reservations on the shared virtual disk were released."),
+	(0xc05c_ff05, "ERROR_SVHDX_UNIT_ATTENTION_REGISTRATIONS_PREEMPTED", "The registrations on the shared virtual disk were preempted."),
+	(0xc05c_ff06, "ERROR_SVHDX_UNIT_ATTENTION_OPERATING_DEFINITION_CHANGED", "The operating definition of the shared virtual disk has changed."),
+	(0xc05c_ff07, "ERROR_SVHDX_RESERVATION_CONFLICT", "The operation failed due to a reservation conflict on the shared virtual disk."),
+	(0xc05c_ff08, "ERROR_SVHDX_WRONG_FILE_TYPE", "The file cannot be used as a shared virtual disk because of its type."),
+	(0xc05c_ff09, "ERROR_SVHDX_VERSION_MISMATCH", "The version of the shared virtual disk is not supported."),
+	(0xc05c_ff0a, "ERROR_VHD_SHARED", "The requested operation cannot be performed on the virtual disk as it is currently used in shared mode."),
+	(0xc05c_ff0b, "ERROR_SVHDX_NO_INITIATOR", "Invalid Shared VHDX open due to lack of initiator ID. Check for related Continuous Availability failures."),
+	(0xc05c_ff0c, "ERROR_VHDSET_BACKING_STORAGE_NOT_FOUND", "The requested operation failed due to a missing backing storage file."),
+	(0xc05d_0000, "ERROR_SMB_NO_PREAUTH_INTEGRITY_HASH_OVERLAP", "Failed to negotiate a preauthentication integrity hash function."),
+	(0xc05d_0001, "ERROR_SMB_BAD_CLUSTER_DIALECT", "The current cluster functional level does not support this SMB dialect."),
+	(0xc0e8_0000, "ERROR_SECCORE_INVALID_COMMAND", "The command is not valid for the secure core."),
+	(0xc0ea_0001, "ERROR_NO_APPLICABLE_APP_LICENSES_FOUND", "No applicable app licenses found."),
+	(0xc0ea_0002, "ERROR_CLIP_LICENSE_NOT_FOUND", "CLiP license not found."),
+	(0xc0ea_0003, "ERROR_CLIP_DEVICE_LICENSE_MISSING", "CLiP device license not found."),
+	(0xc0ea_0004, "ERROR_CLIP_LICENSE_INVALID_SIGNATURE", "CLiP license has an invalid signature."),
+	(0xc0ea_0005, "ERROR_CLIP_KEYHOLDER_LICENSE_MISSING_OR_INVALID", "CLiP keyholder license is invalid or missing."),
+	(0xc0ea_0006, "ERROR_CLIP_LICENSE_EXPIRED", "CLiP license has expired."),
+	(0xc0ea_0007, "ERROR_CLIP_LICENSE_SIGNED_BY_UNKNOWN_SOURCE", "CLiP license is signed by an unknown source."),
+	(0xc0ea_0008, "ERROR_CLIP_LICENSE_NOT_SIGNED", "CLiP license is not signed."),
+	(0xc0ea_0009, "ERROR_CLIP_LICENSE_HARDWARE_ID_OUT_OF_TOLERANCE", "CLiP license hardware ID is out of tolerance."),
+	(0xc0ea_000a, "ERROR_CLIP_LICENSE_DEVICE_ID_MISMATCH", "CLiP license device ID does not match the device ID in the bound device license."),
+];
@@ -1,6 +1,7 @@
 mod consts;
 mod guids;
 mod hresult;
+#[cfg(feature = "error_messages")] pub(crate) mod hresult_msg;
 
 pub use consts::*;
 pub use guids::*;
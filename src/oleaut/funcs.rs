@@ -94,7 +94,8 @@ pub fn PSGetNameFromPropertyKey(prop_key: &PROPERTYKEY) -> HrResult<String> {
 /// [`VariantTimeToSystemTime`](crate::VariantTimeToSystemTime).
 ///
 /// Note that this function resolves the time to one second; milliseconds are
-/// ignored.
+/// ignored. A pure Rust alternative, which keeps the milliseconds, is
+/// [`SYSTEMTIME::to_variant_time`](crate::SYSTEMTIME::to_variant_time).
 #[must_use]
 pub fn SystemTimeToVariantTime(st: &SYSTEMTIME) -> SysResult<f64> {
 	let mut double = f64::default();
@@ -109,6 +110,9 @@ pub fn SystemTimeToVariantTime(st: &SYSTEMTIME) -> SysResult<f64> {
 /// [`VariantTimeToSystemTime`](https://learn.microsoft.com/en-us/windows/win32/api/oleauto/nf-oleauto-varianttimetosystemtime)
 /// function. The inverse operation is performed by
 /// [`SystemTimeToVariantTime`](SystemTimeToVariantTime).
+///
+/// A pure Rust alternative is
+/// [`SYSTEMTIME::from_variant_time`](crate::SYSTEMTIME::from_variant_time).
 #[must_use]
 pub fn VariantTimeToSystemTime(
 	var_time: f64,
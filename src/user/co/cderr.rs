@@ -1,5 +1,9 @@
 #![allow(non_upper_case_globals)]
 
+#[cfg(feature = "error_messages")] use std::sync::OnceLock;
+
+#[cfg(feature = "error_messages")] use crate::kernel::privs::*;
+
 const_no_debug_display! { CDERR: u32;
 	/// Common dialog box
 	/// [error codes](https://learn.microsoft.com/en-us/windows/win32/api/commdlg/nf-commdlg-commdlgextendederror).
@@ -12,6 +16,41 @@ const_no_debug_display! { CDERR: u32;
 	/// because [`FormatMessage`](crate::FormatMessage) function does not offer
 	/// support for it, so there is no way to obtain a textual description of
 	/// the error codes.
+	///
+	/// With the `error_messages` feature, the symbolic names and the English
+	/// descriptions are embedded in the binary, and they're shown by the
+	/// [`Display`](std::fmt::Display) implementation:
+	///
+	/// ```text
+	/// FNERR_BUFFERTOOSMALL (0x3003): The buffer pointed to by the lpstrFile member of the OPENFILENAME structure is too small for the file name specified by the user.
+	/// ```
+}
+
+#[cfg(not(feature = "error_messages"))]
+impl crate::prelude::NativeConst for CDERR {}
+
+#[cfg(feature = "error_messages")]
+impl crate::prelude::NativeConst for CDERR {
+	fn named_values() -> &'static [(&'static str, Self)] {
+		static NAMES: OnceLock<Vec<(String, CDERR)>> = OnceLock::new();
+		static NAMED: OnceLock<Vec<(&str, CDERR)>> = OnceLock::new();
+		NAMED.get_or_init(|| {
+			NAMES.get_or_init(|| {
+				super::cderr_msg::CDERR_MSGS.iter()
+					.map(|(code, name, _)| {
+						let name = match name.split_once("ERR_") {
+							Some(("CD", rest)) => rest.to_owned(),
+							Some((prefix, rest)) => format!("{}_{}", prefix, rest), // PDERR_X is PD_X
+							None => (*name).to_owned(),
+						};
+						(name, unsafe { Self::from_raw(*code) })
+					})
+					.collect()
+			}).iter()
+				.map(|(name, val)| (name.as_str(), *val))
+				.collect()
+		})
+	}
 }
 
 impl std::error::Error for CDERR {
@@ -22,6 +61,11 @@ impl std::error::Error for CDERR {
 
 impl std::fmt::Display for CDERR {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		#[cfg(feature = "error_messages")]
+		if let Some((name, desc)) = find_error_msg(super::cderr_msg::CDERR_MSGS, self.0) {
+			return write!(f, "{} ({:#x}): {}", name, self.0, desc);
+		}
+
 		if self.0 > 0xffff {
 			write!(f, "[{:#010x} {}] Common dialog error.", self.0, self.0)
 		} else {
@@ -35,6 +79,30 @@ impl std::fmt::Debug for CDERR {
 	}
 }
 
+#[cfg(feature = "error_messages")]
+impl CDERR {
+	/// Returns the embedded English description of the error code, if any.
+	///
+	/// Available with the `error_messages` feature.
+	#[must_use]
+	pub fn description(self) -> Option<&'static str> {
+		find_error_msg(super::cderr_msg::CDERR_MSGS, self.0)
+			.map(|(_, desc)| desc)
+	}
+
+	/// Returns the symbolic name of the error code, like
+	/// `FNERR_BUFFERTOOSMALL`, if it's a known constant. Unlike
+	/// [`NativeConst::name`](crate::prelude::NativeConst::name), which returns
+	/// `FN_BUFFERTOOSMALL`, the original prefix is kept.
+	///
+	/// Available with the `error_messages` feature.
+	#[must_use]
+	pub fn symbolic_name(self) -> Option<&'static str> {
+		find_error_msg(super::cderr_msg::CDERR_MSGS, self.0)
+			.map(|(name, _)| name)
+	}
+}
+
 const_values! { CDERR;
 	=>
 	/// None of the actual values (zero).
@@ -0,0 +1,36 @@
+/// Names and English descriptions of [`CDERR`](crate::co::CDERR) constants.
+///
+/// Sorted by code, so it can be binary-searched.
+pub(crate) const CDERR_MSGS: &[(u32, &str, &str)] = &[
+	(0x0000_0001, "CDERR_STRUCTSIZE", "The lStructSize member of the initialization structure for the common dialog box is invalid."),
+	(0x0000_0002, "CDERR_INITIALIZATION", "The common dialog box function failed during initialization."),
+	(0x0000_0003, "CDERR_NOTEMPLATE", "The ENABLETEMPLATE flag was set, but no corresponding template was provided."),
+	(0x0000_0004, "CDERR_NOHINSTANCE", "The ENABLETEMPLATE flag was set, but no corresponding instance handle was provided."),
+	(0x0000_0005, "CDERR_LOADSTRFAILURE", "The common dialog box function failed to load a specified string."),
+	(0x0000_0006, "CDERR_FINDRESFAILURE", "The common dialog box function failed to find a specified resource."),
+	(0x0000_0007, "CDERR_LOADRESFAILURE", "The common dialog box function failed to load a specified resource."),
+	(0x0000_0008, "CDERR_LOCKRESFAILURE", "The common dialog box function failed to lock a specified resource."),
+	(0x0000_0009, "CDERR_MEMALLOCFAILURE", "The common dialog box function was unable to allocate memory for internal structures."),
+	(0x0000_000a, "CDERR_MEMLOCKFAILURE", "The common dialog box function was unable to lock the memory associated with a handle."),
+	(0x0000_000b, "CDERR_NOHOOK", "The ENABLEHOOK flag was set, but no pointer to a corresponding hook procedure was provided."),
+	(0x0000_000c, "CDERR_REGISTERMSGFAIL", "The RegisterWindowMessage function returned an error code when it was called by the common dialog box function."),
+	(0x0000_1001, "PDERR_SETUPFAILURE", "The PrintDlg function failed to load the required resources."),
+	(0x0000_1002, "PDERR_PARSEFAILURE", "The PrintDlg function failed to parse the strings in the [devices] section of the WIN.INI file."),
+	(0x0000_1003, "PDERR_RETDEFFAILURE", "The PD_RETURNDEFAULT flag was specified, but the hDevMode or hDevNames member was not NULL."),
+	(0x0000_1004, "PDERR_LOADDRVFAILURE", "The PrintDlg function failed to load the device driver for the specified printer."),
+	(0x0000_1005, "PDERR_GETDEVMODEFAIL", "The printer driver failed to initialize a DEVMODE structure."),
+	(0x0000_1006, "PDERR_INITFAILURE", "The PrintDlg function failed during initialization."),
+	(0x0000_1007, "PDERR_NODEVICES", "No printer drivers were found."),
+	(0x0000_1008, "PDERR_NODEFAULTPRN", "A default printer does not exist."),
+	(0x0000_1009, "PDERR_DNDMMISMATCH", "The data in the DEVMODE and DEVNAMES structures describes two different printers."),
+	(0x0000_100a, "PDERR_CREATEICFAILURE", "The PrintDlg function failed when it attempted to create an information context."),
+	(0x0000_100b, "PDERR_PRINTERNOTFOUND", "The [devices] section of the WIN.INI file did not contain an entry for the requested printer."),
+	(0x0000_100c, "PDERR_DEFAULTDIFFERENT", "The printer described by the DEVNAMES structure did not match the current default printer."),
+	(0x0000_2001, "CFERR_NOFONTS", "No fonts exist."),
+	(0x0000_2002, "CFERR_MAXLESSTHANMIN", "The size specified in the nSizeMax member of the CHOOSEFONT structure is less than the size specified in the nSizeMin member."),
+	(0x0000_3001, "FNERR_SUBCLASSFAILURE", "An attempt to subclass a list box failed because sufficient memory was not available."),
+	(0x0000_3002, "FNERR_INVALIDFILENAME", "A file name is invalid."),
+	(0x0000_3003, "FNERR_BUFFERTOOSMALL", "The buffer pointed to by the lpstrFile member of the OPENFILENAME structure is too small for the file name specified by the user."),
+	(0x0000_4001, "FRERR_BUFFERLENGTHZERO", "A member of the FINDREPLACE structure points to an invalid buffer."),
+	(0x0000_ffff, "CDERR_DIALOGFAILURE", "The dialog box could not be created."),
+];
@@ -1075,6 +1075,31 @@ const_ordinary! { DLGID: u16;
 	CONTINUE 11
 }
 
+const_ws! { DS: u32;
+	/// Dialog box
+	/// [styles](https://learn.microsoft.com/en-us/windows/win32/dlgbox/dialog-box-styles)
+	/// (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	ABSALIGN 0x0001
+	SYSMODAL 0x0002
+	FIXEDSYS 0x0008
+	NOFAILCREATE 0x0010
+	LOCALEDIT 0x0020
+	SETFONT 0x0040
+	MODALFRAME 0x0080
+	NOIDLEMSG 0x0100
+	SETFOREGROUND 0x0200
+	CONTROL 0x0400
+	CENTER 0x0800
+	CENTERMOUSE 0x1000
+	CONTEXTHELP 0x2000
+	USEPIXELS 0x8000
+	SHELLFONT Self::SETFONT.0 | Self::FIXEDSYS.0
+}
+
 const_bitflag! { DT: u32;
 	/// [`HDC::DrawText`](crate::prelude::user_Hdc::DrawText) `format` (`u32`).
 	=>
@@ -3607,11 +3632,11 @@ const_bitflag! { WS: u32;
 	ICONIC Self::MINIMIZE.0
 	/// The window has a sizing border. Same as the `WS::THICKFRAME` style.
 	SIZEBOX Self::THICKFRAME.0
+	/// The window is an overlapped window. Same as the `WS::TILEDWINDOW` style.
+	OVERLAPPEDWINDOW Self::OVERLAPPED.0 | Self::CAPTION.0 | Self::SYSMENU.0 | Self::THICKFRAME.0 | Self::MINIMIZEBOX.0 | Self::MAXIMIZEBOX.0
 	/// The window is an overlapped window. Same as the `WS::OVERLAPPEDWINDOW`
 	/// style.
 	TILEDWINDOW Self::OVERLAPPEDWINDOW.0
-	/// The window is an overlapped window. Same as the `WS::TILEDWINDOW` style.
-	OVERLAPPEDWINDOW Self::OVERLAPPED.0 | Self::CAPTION.0 | Self::SYSMENU.0 | Self::THICKFRAME.0 | Self::MINIMIZEBOX.0 | Self::MAXIMIZEBOX.0
 	/// The window is a pop-up window. This style cannot be used with the
 	/// `WS::CHILD` style.
 	POPUPWINDOW Self::POPUP.0 | Self::BORDER.0 | Self::SYSMENU.0
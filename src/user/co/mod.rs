@@ -1,4 +1,5 @@
 mod cderr;
+#[cfg(feature = "error_messages")] mod cderr_msg;
 mod consts;
 
 pub use cderr::*;
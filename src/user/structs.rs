@@ -11,7 +11,7 @@ use crate::user::privs::*;
 /// [`ACCEL`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-accel)
 /// struct.
 #[repr(C)]
-#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ACCEL {
 	pub fVirt: co::ACCELF,
 	pub key: co::VK,
@@ -0,0 +1,137 @@
+use crate::co;
+use crate::decl::*;
+
+/// The contents of an `RT_ACCELERATOR` resource, which is an array of
+/// `ACCELTABLEENTRY` structs.
+///
+/// # Examples
+///
+/// Building a table and loading it:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let table = w::AccelTable {
+///     accels: vec![
+///         w::ACCEL {
+///             fVirt: co::ACCELF::VIRTKEY | co::ACCELF::CONTROL,
+///             key: co::VK::CHAR_O,
+///             cmd: 100,
+///         },
+///     ],
+/// };
+///
+/// let data = table.to_bytes();
+/// let same = w::AccelTable::from_bytes(&data)?;
+/// assert_eq!(table, same);
+///
+/// let haccel = w::HACCEL::CreateAcceleratorTable(&mut same.accels.clone())?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccelTable {
+	/// The accelerators, in order.
+	pub accels: Vec<ACCEL>,
+}
+
+impl AccelTable {
+	/// Parses the contents of an `RT_ACCELERATOR` resource.
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the data is malformed.
+	pub fn from_bytes(data: &[u8]) -> SysResult<Self> {
+		let mut accels = Vec::default();
+		for entry in data.chunks(ACCELTABLEENTRY_SIZE) {
+			if entry.len() < ACCELTABLEENTRY_SIZE {
+				return Err(co::ERROR::INVALID_DATA);
+			}
+			let flags = u16::from_le_bytes([entry[0], entry[1]]);
+			accels.push(ACCEL {
+				fVirt: unsafe { co::ACCELF::from_raw((flags & 0x7f) as _) },
+				key: unsafe { co::VK::from_raw(u16::from_le_bytes([entry[2], entry[3]])) },
+				cmd: u16::from_le_bytes([entry[4], entry[5]]),
+			});
+			if flags & LAST_ENTRY != 0 {
+				return Ok(Self { accels });
+			}
+		}
+
+		if accels.is_empty() {
+			Ok(Self { accels })
+		} else {
+			Err(co::ERROR::INVALID_DATA) // last entry not flagged
+		}
+	}
+
+	/// Serializes the accelerators into the contents of an `RT_ACCELERATOR`
+	/// resource.
+	#[must_use]
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let mut buf = Vec::<u8>::with_capacity(self.accels.len() * ACCELTABLEENTRY_SIZE);
+		for (idx, accel) in self.accels.iter().enumerate() {
+			let mut flags = accel.fVirt.raw() as u16;
+			if idx == self.accels.len() - 1 {
+				flags |= LAST_ENTRY;
+			}
+			buf.extend_from_slice(&flags.to_le_bytes());
+			buf.extend_from_slice(&accel.key.raw().to_le_bytes());
+			buf.extend_from_slice(&accel.cmd.to_le_bytes());
+			buf.extend_from_slice(&[0, 0]); // padding
+		}
+		buf
+	}
+}
+
+//------------------------------------------------------------------------------
+
+const ACCELTABLEENTRY_SIZE: usize = 8;
+const LAST_ENTRY: u16 = 0x80;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn round_trip() {
+		let table = AccelTable {
+			accels: vec![
+				ACCEL {
+					fVirt: co::ACCELF::VIRTKEY | co::ACCELF::CONTROL,
+					key: co::VK::CHAR_O,
+					cmd: 100,
+				},
+				ACCEL {
+					fVirt: co::ACCELF::VIRTKEY,
+					key: co::VK::F1,
+					cmd: 0xffff,
+				},
+			],
+		};
+		let data = table.to_bytes();
+		assert_eq!(data, [
+			0x09, 0x00, b'O', 0x00, 100, 0x00, 0x00, 0x00,
+			0x81, 0x00, 0x70, 0x00, 0xff, 0xff, 0x00, 0x00, // last entry
+		]);
+		assert_eq!(AccelTable::from_bytes(&data).unwrap(), table);
+
+		// Entries after the last one are ignored.
+		let mut trailing = data.clone();
+		trailing.extend_from_slice(&[0x01, 0x00, b'A', 0x00, 1, 0x00, 0x00, 0x00]);
+		assert_eq!(AccelTable::from_bytes(&trailing).unwrap(), table);
+	}
+
+	#[test]
+	fn malformed() {
+		assert_eq!(AccelTable::from_bytes(&[]).unwrap(), AccelTable::default());
+		assert!(AccelTable::default().to_bytes().is_empty());
+
+		let data = AccelTable {
+			accels: vec![ACCEL { fVirt: co::ACCELF::VIRTKEY, key: co::VK::F1, cmd: 1 }],
+		}.to_bytes();
+		assert_eq!(AccelTable::from_bytes(&data[..6]), Err(co::ERROR::INVALID_DATA));
+
+		let mut unterminated = data.clone();
+		unterminated[0] &= !(LAST_ENTRY as u8);
+		assert_eq!(AccelTable::from_bytes(&unterminated), Err(co::ERROR::INVALID_DATA));
+	}
+}
@@ -0,0 +1,595 @@
+use crate::co;
+use crate::decl::*;
+use crate::user::utilities::template_data::{align4, Reader, write_str};
+
+/// A string or an ordinal in a [`DlgTemplate`](crate::DlgTemplate), like the
+/// menu, the window class, or the text of a control.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DlgIdStr {
+	/// An ordinal, usually a resource ID.
+	Id(u16),
+	/// A string.
+	Str(String),
+}
+
+impl From<&str> for DlgIdStr {
+	fn from(s: &str) -> Self {
+		Self::Str(s.to_owned())
+	}
+}
+
+/// Window class of a [`DlgTemplateControl`](crate::DlgTemplateControl).
+///
+/// The predefined classes are stored as ordinals in the template.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DlgClass {
+	/// Button, ordinal `0x0080`.
+	Button,
+	/// Edit, ordinal `0x0081`.
+	Edit,
+	/// Static, ordinal `0x0082`.
+	Static,
+	/// List box, ordinal `0x0083`.
+	ListBox,
+	/// Scroll bar, ordinal `0x0084`.
+	ScrollBar,
+	/// Combo box, ordinal `0x0085`.
+	ComboBox,
+	/// Any other ordinal.
+	Atom(u16),
+	/// A class name, like `SysListView32`.
+	Name(String),
+}
+
+impl DlgClass {
+	fn from_id_str(id_str: DlgIdStr) -> Self {
+		match id_str {
+			DlgIdStr::Id(0x0080) => Self::Button,
+			DlgIdStr::Id(0x0081) => Self::Edit,
+			DlgIdStr::Id(0x0082) => Self::Static,
+			DlgIdStr::Id(0x0083) => Self::ListBox,
+			DlgIdStr::Id(0x0084) => Self::ScrollBar,
+			DlgIdStr::Id(0x0085) => Self::ComboBox,
+			DlgIdStr::Id(atom) => Self::Atom(atom),
+			DlgIdStr::Str(name) => Self::Name(name),
+		}
+	}
+
+	fn to_id_str(&self) -> DlgIdStr {
+		match self {
+			Self::Button => DlgIdStr::Id(0x0080),
+			Self::Edit => DlgIdStr::Id(0x0081),
+			Self::Static => DlgIdStr::Id(0x0082),
+			Self::ListBox => DlgIdStr::Id(0x0083),
+			Self::ScrollBar => DlgIdStr::Id(0x0084),
+			Self::ComboBox => DlgIdStr::Id(0x0085),
+			Self::Atom(atom) => DlgIdStr::Id(*atom),
+			Self::Name(name) => DlgIdStr::Str(name.clone()),
+		}
+	}
+}
+
+/// Font of a [`DlgTemplate`](crate::DlgTemplate).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DlgFont {
+	/// Size in points.
+	pub point_size: u16,
+	/// Weight, like [`co::FW::NORMAL`](crate::co::FW::NORMAL); zero means
+	/// default.
+	pub weight: u16,
+	/// Italic font.
+	pub italic: bool,
+	/// Character set; 1 is `DEFAULT_CHARSET`.
+	pub charset: u8,
+	/// Typeface name.
+	pub face_name: String,
+}
+
+impl Default for DlgFont {
+	/// Returns the standard `MS Shell Dlg` font, with 8 points.
+	fn default() -> Self {
+		Self {
+			point_size: 8,
+			weight: 0,
+			italic: false,
+			charset: 1,
+			face_name: "MS Shell Dlg".to_owned(),
+		}
+	}
+}
+
+/// A control in a [`DlgTemplate`](crate::DlgTemplate), serialized as a
+/// [`DLGITEMTEMPLATEEX`](https://learn.microsoft.com/en-us/windows/win32/dlgbox/dlgitemtemplateex).
+///
+/// Positions and sizes are in dialog units.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DlgTemplateControl {
+	/// Window class.
+	pub class: DlgClass,
+	/// Text, or the resource ID of an image, for static controls.
+	pub text: DlgIdStr,
+	/// Control ID.
+	pub id: u32,
+	/// Horizontal position.
+	pub x: i16,
+	/// Vertical position.
+	pub y: i16,
+	/// Width.
+	pub cx: i16,
+	/// Height.
+	pub cy: i16,
+	/// Window styles, usually combined with the control styles, like
+	/// [`co::BS`](crate::co::BS).
+	pub style: co::WS,
+	/// Extended window styles.
+	pub ex_style: co::WS_EX,
+	/// Help context ID.
+	pub help_id: u32,
+	/// Creation data, passed to the control in
+	/// [`WM_CREATE`](crate::msg::wm::Create).
+	pub creation_data: Vec<u8>,
+}
+
+impl DlgTemplateControl {
+	/// Creates a new control, with [`WS::CHILD`](crate::co::WS::CHILD) and
+	/// [`WS::VISIBLE`](crate::co::WS::VISIBLE) added to the given styles.
+	///
+	/// The rectangle is the position and the size, in dialog units.
+	#[must_use]
+	pub fn new(
+		class: DlgClass,
+		text: &str,
+		id: u16,
+		rect: (i16, i16, i16, i16),
+		style: co::WS,
+	) -> Self
+	{
+		Self {
+			class,
+			text: DlgIdStr::from(text),
+			id: id as _,
+			x: rect.0,
+			y: rect.1,
+			cx: rect.2,
+			cy: rect.3,
+			style: style | co::WS::CHILD | co::WS::VISIBLE,
+			ex_style: co::WS_EX::NoValue,
+			help_id: 0,
+			creation_data: Vec::default(),
+		}
+	}
+}
+
+/// A dialog template built in memory, serialized as a
+/// [`DLGTEMPLATEEX`](https://learn.microsoft.com/en-us/windows/win32/dlgbox/dlgtemplateex),
+/// so dialogs can be created without a resource compiler.
+///
+/// Positions and sizes are in dialog units. The
+/// [`DS::SETFONT`](crate::co::DS::SETFONT) style is managed according to the
+/// `font` field.
+///
+/// Existing templates – both `DLGTEMPLATEEX` and the older `DLGTEMPLATE` – can
+/// be parsed with [`DlgTemplate::from_bytes`](crate::DlgTemplate::from_bytes).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut tpl = w::DlgTemplate::new("Confirm", 160, 60);
+/// tpl.control(w::DlgTemplateControl::new(w::DlgClass::Static,
+///         "Delete the file?", 100, (10, 10, 140, 10), co::SS::LEFT.into()))
+///     .control(w::DlgTemplateControl::new(w::DlgClass::Button,
+///         "OK", 1, (50, 38, 50, 14), co::BS::DEFPUSHBUTTON.into()))
+///     .control(w::DlgTemplateControl::new(w::DlgClass::Button,
+///         "Cancel", 2, (104, 38, 50, 14), co::BS::PUSHBUTTON.into()));
+///
+/// let data = tpl.to_bytes()?;
+/// let same = w::DlgTemplate::from_bytes(&data)?;
+/// assert_eq!(tpl, same);
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DlgTemplate {
+	/// Window styles, usually combined with [`co::DS`](crate::co::DS).
+	pub style: co::WS,
+	/// Extended window styles.
+	pub ex_style: co::WS_EX,
+	/// Help context ID.
+	pub help_id: u32,
+	/// Horizontal position.
+	pub x: i16,
+	/// Vertical position.
+	pub y: i16,
+	/// Width.
+	pub cx: i16,
+	/// Height.
+	pub cy: i16,
+	/// Menu resource, if any.
+	pub menu: Option<DlgIdStr>,
+	/// Window class, if not the default dialog class.
+	pub class: Option<DlgIdStr>,
+	/// Title.
+	pub title: String,
+	/// Font, if not the system font.
+	pub font: Option<DlgFont>,
+	/// Controls, in tab order.
+	pub controls: Vec<DlgTemplateControl>,
+}
+
+impl DlgTemplate {
+	/// Creates a new modal-styled dialog template, without controls, with
+	/// the default [`DlgFont`](crate::DlgFont). The size is in dialog units.
+	#[must_use]
+	pub fn new(title: &str, cx: i16, cy: i16) -> Self {
+		Self {
+			style: co::WS::POPUP | co::WS::CAPTION | co::WS::SYSMENU
+				| (co::DS::MODALFRAME | co::DS::SETFONT).into(),
+			ex_style: co::WS_EX::NoValue,
+			help_id: 0,
+			x: 0,
+			y: 0,
+			cx,
+			cy,
+			menu: None,
+			class: None,
+			title: title.to_owned(),
+			font: Some(DlgFont::default()),
+			controls: Vec::default(),
+		}
+	}
+
+	/// Appends a control.
+	pub fn control(&mut self, control: DlgTemplateControl) -> &mut Self {
+		self.controls.push(control);
+		self
+	}
+
+	/// Parses a `DLGTEMPLATEEX` or a `DLGTEMPLATE`, like the ones found in
+	/// `RT_DIALOG` resources.
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the data is malformed.
+	pub fn from_bytes(data: &[u8]) -> SysResult<Self> {
+		let mut rd = Reader { data, pos: 0 };
+		if data.get(..4) == Some(&[1, 0, 0xff, 0xff]) {
+			Self::parse_ex(&mut rd)
+		} else {
+			Self::parse_classic(&mut rd)
+		}
+	}
+
+	/// Serializes the template into a `DLGTEMPLATEEX`.
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// there are more than 65535 controls, or if the creation data of a
+	/// control exceeds 65535 bytes.
+	pub fn to_bytes(&self) -> SysResult<Vec<u8>> {
+		let num_ctrls = u16::try_from(self.controls.len())
+			.map_err(|_| co::ERROR::INVALID_DATA)?;
+		let mut style = self.style & !co::WS::from(co::DS::SETFONT);
+		if self.font.is_some() {
+			style |= co::DS::SETFONT.into();
+		}
+
+		let mut buf = Vec::<u8>::default();
+		buf.extend_from_slice(&1u16.to_le_bytes()); // dlgVer
+		buf.extend_from_slice(&0xffffu16.to_le_bytes()); // signature
+		buf.extend_from_slice(&self.help_id.to_le_bytes());
+		buf.extend_from_slice(&self.ex_style.raw().to_le_bytes());
+		buf.extend_from_slice(&style.raw().to_le_bytes());
+		buf.extend_from_slice(&num_ctrls.to_le_bytes());
+		for n in [self.x, self.y, self.cx, self.cy] {
+			buf.extend_from_slice(&n.to_le_bytes());
+		}
+		write_id_str(&mut buf, self.menu.as_ref());
+		write_id_str(&mut buf, self.class.as_ref());
+		write_str(&mut buf, &self.title);
+
+		if let Some(font) = &self.font {
+			buf.extend_from_slice(&font.point_size.to_le_bytes());
+			buf.extend_from_slice(&font.weight.to_le_bytes());
+			buf.push(font.italic as _);
+			buf.push(font.charset);
+			write_str(&mut buf, &font.face_name);
+		}
+
+		for ctrl in self.controls.iter() {
+			let extra_len = u16::try_from(ctrl.creation_data.len())
+				.map_err(|_| co::ERROR::INVALID_DATA)?;
+			align4(&mut buf);
+			buf.extend_from_slice(&ctrl.help_id.to_le_bytes());
+			buf.extend_from_slice(&ctrl.ex_style.raw().to_le_bytes());
+			buf.extend_from_slice(&ctrl.style.raw().to_le_bytes());
+			for n in [ctrl.x, ctrl.y, ctrl.cx, ctrl.cy] {
+				buf.extend_from_slice(&n.to_le_bytes());
+			}
+			buf.extend_from_slice(&ctrl.id.to_le_bytes());
+			write_id_str(&mut buf, Some(&ctrl.class.to_id_str()));
+			write_id_str(&mut buf, Some(&ctrl.text));
+			buf.extend_from_slice(&extra_len.to_le_bytes());
+			buf.extend_from_slice(&ctrl.creation_data);
+		}
+		Ok(buf)
+	}
+
+	/// Serializes the template and calls the closure with it, properly
+	/// aligned, so it can be passed to functions like
+	/// [`HINSTANCE::DialogBoxIndirectParam`](crate::prelude::user_Hinstance::DialogBoxIndirectParam)
+	/// and
+	/// [`HINSTANCE::CreateDialogIndirectParam`](crate::prelude::user_Hinstance::CreateDialogIndirectParam).
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the template cannot be serialized.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// extern "system" fn dlg_proc(
+	///     hwnd: w::HWND, msg: co::WM, wparam: usize, lparam: isize) -> isize
+	/// {
+	///     0
+	/// }
+	///
+	/// let tpl = w::DlgTemplate::new("Hello", 120, 60);
+	/// let hinst = w::HINSTANCE::GetModuleHandle(None)?;
+	///
+	/// let ret = tpl.with_dlgtemplate(|dt| unsafe {
+	///     hinst.DialogBoxIndirectParam(dt, None, dlg_proc, None)
+	/// })??;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	pub fn with_dlgtemplate<R, F>(&self, func: F) -> SysResult<R>
+		where F: FnOnce(&DLGTEMPLATE) -> R,
+	{
+		let bytes = self.to_bytes()?;
+		let mut buf = vec![0u32; bytes.len().div_ceil(4)]; // DWORD-aligned
+		unsafe {
+			std::ptr::copy_nonoverlapping(bytes.as_ptr(), buf.as_mut_ptr() as *mut u8, bytes.len());
+			Ok(func(&*(buf.as_ptr() as *const DLGTEMPLATE)))
+		}
+	}
+
+	fn parse_ex(rd: &mut Reader) -> SysResult<Self> {
+		rd.u32()?; // dlgVer and signature
+		let help_id = rd.u32()?;
+		let ex_style = unsafe { co::WS_EX::from_raw(rd.u32()?) };
+		let style = unsafe { co::WS::from_raw(rd.u32()?) };
+		let num_ctrls = rd.u16()?;
+		let (x, y, cx, cy) = (rd.i16()?, rd.i16()?, rd.i16()?, rd.i16()?);
+		let menu = read_id_str(rd)?;
+		let class = read_id_str(rd)?;
+		let title = rd.str()?;
+
+		let font = if Self::has_font(style) {
+			Some(DlgFont {
+				point_size: rd.u16()?,
+				weight: rd.u16()?,
+				italic: rd.u8()? != 0,
+				charset: rd.u8()?,
+				face_name: rd.str()?,
+			})
+		} else {
+			None
+		};
+
+		let mut controls = Vec::with_capacity(num_ctrls as _);
+		for _ in 0..num_ctrls {
+			rd.align4();
+			let help_id = rd.u32()?;
+			let ex_style = unsafe { co::WS_EX::from_raw(rd.u32()?) };
+			let style = unsafe { co::WS::from_raw(rd.u32()?) };
+			let (x, y, cx, cy) = (rd.i16()?, rd.i16()?, rd.i16()?, rd.i16()?);
+			let id = rd.u32()?;
+			let class = read_id_str(rd)?.ok_or(co::ERROR::INVALID_DATA)?;
+			let text = read_id_str(rd)?.unwrap_or(DlgIdStr::Str(String::default()));
+			let extra_len = rd.u16()?;
+			controls.push(DlgTemplateControl {
+				class: DlgClass::from_id_str(class),
+				text,
+				id,
+				x, y, cx, cy,
+				style,
+				ex_style,
+				help_id,
+				creation_data: rd.bytes(extra_len as _)?.to_vec(),
+			});
+		}
+
+		Ok(Self { style, ex_style, help_id, x, y, cx, cy, menu, class, title, font, controls })
+	}
+
+	fn parse_classic(rd: &mut Reader) -> SysResult<Self> {
+		let style = unsafe { co::WS::from_raw(rd.u32()?) };
+		let ex_style = unsafe { co::WS_EX::from_raw(rd.u32()?) };
+		let num_ctrls = rd.u16()?;
+		let (x, y, cx, cy) = (rd.i16()?, rd.i16()?, rd.i16()?, rd.i16()?);
+		let menu = read_id_str(rd)?;
+		let class = read_id_str(rd)?;
+		let title = rd.str()?;
+
+		let font = if Self::has_font(style) {
+			Some(DlgFont {
+				point_size: rd.u16()?,
+				face_name: rd.str()?,
+				..DlgFont::default()
+			})
+		} else {
+			None
+		};
+
+		let mut controls = Vec::with_capacity(num_ctrls as _);
+		for _ in 0..num_ctrls {
+			rd.align4();
+			let style = unsafe { co::WS::from_raw(rd.u32()?) };
+			let ex_style = unsafe { co::WS_EX::from_raw(rd.u32()?) };
+			let (x, y, cx, cy) = (rd.i16()?, rd.i16()?, rd.i16()?, rd.i16()?);
+			let id = rd.u16()?;
+			let class = read_id_str(rd)?.ok_or(co::ERROR::INVALID_DATA)?;
+			let text = read_id_str(rd)?.unwrap_or(DlgIdStr::Str(String::default()));
+			let extra_len = rd.u16()?;
+			controls.push(DlgTemplateControl {
+				class: DlgClass::from_id_str(class),
+				text,
+				id: id as _,
+				x, y, cx, cy,
+				style,
+				ex_style,
+				help_id: 0,
+				creation_data: rd.bytes(extra_len as _)?.to_vec(),
+			});
+		}
+
+		Ok(Self { style, ex_style, help_id: 0, x, y, cx, cy, menu, class, title, font, controls })
+	}
+
+	fn has_font(style: co::WS) -> bool {
+		(style & co::DS::SETFONT.into()) != co::WS::NoValue
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Reads a `sz_Or_Ord` field, which is empty if the first `WORD` is zero.
+fn read_id_str(rd: &mut Reader) -> SysResult<Option<DlgIdStr>> {
+	match rd.data.get(rd.pos..rd.pos + 2) {
+		Some([0, 0]) => {
+			rd.pos += 2;
+			Ok(None)
+		},
+		Some([0xff, 0xff]) => {
+			rd.pos += 2;
+			Ok(Some(DlgIdStr::Id(rd.u16()?)))
+		},
+		Some(_) => Ok(Some(DlgIdStr::Str(rd.str()?))),
+		None => Err(co::ERROR::INVALID_DATA),
+	}
+}
+
+/// Writes a `sz_Or_Ord` field.
+fn write_id_str(buf: &mut Vec<u8>, id_str: Option<&DlgIdStr>) {
+	match id_str {
+		None => buf.extend_from_slice(&[0, 0]),
+		Some(DlgIdStr::Id(id)) => {
+			buf.extend_from_slice(&[0xff, 0xff]);
+			buf.extend_from_slice(&id.to_le_bytes());
+		},
+		Some(DlgIdStr::Str(s)) => write_str(buf, s),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// A `DLGTEMPLATEEX` without font, with a single button.
+	const TEMPLATE_EX: [u8; 72] = [
+		0x01, 0x00, 0xff, 0xff, // dlgVer, signature
+		0x00, 0x00, 0x00, 0x00, // helpID
+		0x00, 0x00, 0x00, 0x00, // exStyle
+		0x80, 0x00, 0xc8, 0x80, // style
+		0x01, 0x00, // cDlgItems
+		0x00, 0x00, 0x00, 0x00, 0x64, 0x00, 0x32, 0x00, // x, y, cx, cy
+		0x00, 0x00, // menu
+		0x00, 0x00, // windowClass
+		0x48, 0x00, 0x69, 0x00, 0x00, 0x00, // title
+		0x00, 0x00, 0x00, 0x00, // helpID
+		0x00, 0x00, 0x00, 0x00, // exStyle
+		0x00, 0x00, 0x01, 0x50, // style
+		0x0a, 0x00, 0x14, 0x00, 0x32, 0x00, 0x0e, 0x00, // x, y, cx, cy
+		0x01, 0x00, 0x00, 0x00, // id
+		0xff, 0xff, 0x80, 0x00, // windowClass
+		0x4f, 0x00, 0x4b, 0x00, 0x00, 0x00, // title
+		0x00, 0x00, // extraCount
+	];
+
+	/// A classic `DLGTEMPLATE` with font, with a single static control.
+	const TEMPLATE_CLASSIC: [u8; 72] = [
+		0x40, 0x00, 0xc8, 0x80, // style
+		0x00, 0x00, 0x00, 0x00, // dwExtendedStyle
+		0x01, 0x00, // cdit
+		0x00, 0x00, 0x00, 0x00, 0x50, 0x00, 0x28, 0x00, // x, y, cx, cy
+		0x00, 0x00, // menu
+		0x00, 0x00, // class
+		0x41, 0x00, 0x00, 0x00, // title
+		0x09, 0x00, // point size
+		0x54, 0x00, 0x61, 0x00, 0x68, 0x00, 0x6f, 0x00, 0x6d, 0x00, 0x61, 0x00, 0x00, 0x00, // typeface
+		0x00, 0x00, // padding
+		0x00, 0x00, 0x00, 0x50, // style
+		0x00, 0x00, 0x00, 0x00, // dwExtendedStyle
+		0x05, 0x00, 0x05, 0x00, 0x46, 0x00, 0x0a, 0x00, // x, y, cx, cy
+		0x64, 0x00, // id
+		0xff, 0xff, 0x82, 0x00, // class
+		0x42, 0x00, 0x00, 0x00, // title
+		0x00, 0x00, // creation data
+	];
+
+	fn template_ex() -> DlgTemplate {
+		let mut tpl = DlgTemplate::new("Hi", 100, 50);
+		tpl.style = co::WS::POPUP | co::WS::CAPTION | co::WS::SYSMENU | co::DS::MODALFRAME.into();
+		tpl.font = None;
+		tpl.control(DlgTemplateControl::new(
+			DlgClass::Button, "OK", 1, (10, 20, 50, 14), co::WS::TABSTOP));
+		tpl
+	}
+
+	#[test]
+	fn serialize_ex() {
+		assert_eq!(template_ex().to_bytes().unwrap(), TEMPLATE_EX);
+	}
+
+	#[test]
+	fn parse_ex() {
+		let tpl = DlgTemplate::from_bytes(&TEMPLATE_EX).unwrap();
+		assert_eq!(tpl, template_ex());
+	}
+
+	#[test]
+	fn parse_classic() {
+		let tpl = DlgTemplate::from_bytes(&TEMPLATE_CLASSIC).unwrap();
+		assert_eq!(tpl.style, co::WS::POPUP | co::WS::CAPTION | co::WS::SYSMENU | co::DS::SETFONT.into());
+		assert_eq!((tpl.x, tpl.y, tpl.cx, tpl.cy), (0, 0, 80, 40));
+		assert_eq!(tpl.title, "A");
+		assert_eq!(tpl.font, Some(DlgFont {
+			point_size: 9,
+			face_name: "Tahoma".to_owned(),
+			..DlgFont::default()
+		}));
+		assert_eq!(tpl.controls, [DlgTemplateControl {
+			help_id: 0,
+			..DlgTemplateControl::new(DlgClass::Static, "B", 100, (5, 5, 70, 10), co::WS::NoValue)
+		}]);
+	}
+
+	#[test]
+	fn round_trip_with_font() {
+		let mut tpl = template_ex();
+		tpl.font = Some(DlgFont::default());
+		tpl.controls[0].help_id = 7;
+		tpl.controls[0].text = DlgIdStr::Id(200);
+		tpl.controls[0].creation_data = vec![1, 2, 3];
+		tpl.control(DlgTemplateControl::new(
+			DlgClass::Name("SysListView32".to_owned()), "", 2, (0, 0, 10, 10), co::WS::NoValue));
+
+		let data = tpl.to_bytes().unwrap();
+		let same = DlgTemplate::from_bytes(&data).unwrap();
+		assert_eq!(same.style, tpl.style | co::DS::SETFONT.into());
+		assert_eq!(same.font, tpl.font);
+		assert_eq!(same.controls, tpl.controls);
+		assert_eq!(same.to_bytes().unwrap(), data);
+	}
+
+	#[test]
+	fn malformed() {
+		for len in [0, 3, 20, 36, 60, 71] {
+			assert_eq!(DlgTemplate::from_bytes(&TEMPLATE_EX[..len]), Err(co::ERROR::INVALID_DATA));
+		}
+		assert_eq!(DlgTemplate::from_bytes(&TEMPLATE_CLASSIC[..50]), Err(co::ERROR::INVALID_DATA));
+
+		let mut tpl = template_ex();
+		tpl.controls[0].creation_data = vec![0; 0x1_0000];
+		assert_eq!(tpl.to_bytes(), Err(co::ERROR::INVALID_DATA));
+	}
+}
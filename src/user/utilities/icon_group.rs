@@ -0,0 +1,212 @@
+use crate::co;
+use crate::decl::*;
+use crate::user::utilities::template_data::Reader;
+
+/// The contents of an `RT_GROUP_ICON` or `RT_GROUP_CURSOR` resource, which is
+/// a directory of the images of the same icon or cursor, each one stored as a
+/// separate `RT_ICON` or `RT_CURSOR` resource.
+///
+/// # Examples
+///
+/// Listing the images of the main icon of an executable:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let exe_data = std::fs::read("C:\\Temp\\foo.exe").unwrap();
+/// let pe = w::PeImage::from_bytes(&exe_data)?;
+/// let data = pe.find_resource(
+///     &w::RtStr::Rt(co::RT::GROUP_ICON), &w::IdStr::Id(1), None)?;
+///
+/// let group = w::IconGroup::from_bytes(data)?;
+/// for entry in group.entries.iter() {
+///     println!("{}x{}, {} bpp, RT_ICON {}",
+///         entry.width, entry.height, entry.bit_count, entry.id);
+/// }
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IconGroup {
+	/// Whether this is an `RT_GROUP_CURSOR` resource, instead of an
+	/// `RT_GROUP_ICON` one.
+	pub cursor: bool,
+	/// The images, in order.
+	pub entries: Vec<IconGroupEntry>,
+}
+
+impl IconGroup {
+	/// Parses the contents of an `RT_GROUP_ICON` or `RT_GROUP_CURSOR`
+	/// resource.
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the data is malformed.
+	pub fn from_bytes(data: &[u8]) -> SysResult<Self> {
+		let mut rd = Reader { data, pos: 0 };
+		rd.u16()?; // idReserved
+		let cursor = match rd.u16()? {
+			1 => false,
+			2 => true,
+			_ => return Err(co::ERROR::INVALID_DATA),
+		};
+		let count = rd.u16()?;
+
+		let mut entries = Vec::with_capacity(count as _);
+		for _ in 0..count {
+			let (width, height, color_count) = if cursor {
+				(rd.u16()?, rd.u16()?, 0)
+			} else {
+				let width = rd.u8()?;
+				let height = rd.u8()?;
+				let color_count = rd.u8()?;
+				rd.u8()?; // bReserved
+				(icon_dim(width), icon_dim(height), color_count)
+			};
+			entries.push(IconGroupEntry {
+				width,
+				height,
+				color_count,
+				planes: rd.u16()?,
+				bit_count: rd.u16()?,
+				bytes_in_res: rd.u32()?,
+				id: rd.u16()?,
+			});
+		}
+
+		Ok(Self { cursor, entries })
+	}
+
+	/// Serializes the directory into the contents of an `RT_GROUP_ICON` or
+	/// `RT_GROUP_CURSOR` resource.
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// there are more than 65535 entries, or if an icon is larger than 256
+	/// pixels.
+	pub fn to_bytes(&self) -> SysResult<Vec<u8>> {
+		let count = u16::try_from(self.entries.len())
+			.map_err(|_| co::ERROR::INVALID_DATA)?;
+
+		let mut buf = Vec::<u8>::with_capacity(6 + self.entries.len() * 14);
+		buf.extend_from_slice(&0u16.to_le_bytes()); // idReserved
+		buf.extend_from_slice(&(if self.cursor { 2u16 } else { 1 }).to_le_bytes());
+		buf.extend_from_slice(&count.to_le_bytes());
+
+		for entry in self.entries.iter() {
+			if self.cursor {
+				buf.extend_from_slice(&entry.width.to_le_bytes());
+				buf.extend_from_slice(&entry.height.to_le_bytes());
+			} else {
+				if entry.width > 256 || entry.height > 256 {
+					return Err(co::ERROR::INVALID_DATA);
+				}
+				buf.push(entry.width as u8); // 256 becomes zero
+				buf.push(entry.height as u8);
+				buf.push(entry.color_count);
+				buf.push(0); // bReserved
+			}
+			buf.extend_from_slice(&entry.planes.to_le_bytes());
+			buf.extend_from_slice(&entry.bit_count.to_le_bytes());
+			buf.extend_from_slice(&entry.bytes_in_res.to_le_bytes());
+			buf.extend_from_slice(&entry.id.to_le_bytes());
+		}
+		Ok(buf)
+	}
+}
+
+/// An image in an [`IconGroup`](crate::IconGroup), which is a `GRPICONDIRENTRY`
+/// for icons, or a `CURSORDIR` followed by the common fields, for cursors.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IconGroupEntry {
+	/// Width in pixels.
+	pub width: u16,
+	/// Height in pixels. For cursors, this is the height of both the XOR and
+	/// AND masks, which is twice the height of the cursor.
+	pub height: u16,
+	/// Number of colors in the palette, or zero if there's no palette. Not
+	/// used by cursors.
+	pub color_count: u8,
+	/// Number of color planes.
+	pub planes: u16,
+	/// Bits per pixel.
+	pub bit_count: u16,
+	/// Size of the image resource, in bytes.
+	pub bytes_in_res: u32,
+	/// Name of the `RT_ICON` or `RT_CURSOR` resource which holds the image.
+	pub id: u16,
+}
+
+//------------------------------------------------------------------------------
+
+/// Icon dimensions are stored in a byte, where zero means 256.
+fn icon_dim(dim: u8) -> u16 {
+	if dim == 0 { 256 } else { dim as _ }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn icons() {
+		let group = IconGroup {
+			cursor: false,
+			entries: vec![
+				IconGroupEntry {
+					width: 16,
+					height: 16,
+					color_count: 16,
+					planes: 1,
+					bit_count: 4,
+					bytes_in_res: 0x128,
+					id: 1,
+				},
+				IconGroupEntry {
+					width: 256,
+					height: 256,
+					color_count: 0,
+					planes: 1,
+					bit_count: 32,
+					bytes_in_res: 0x1_0000,
+					id: 2,
+				},
+			],
+		};
+		let data = group.to_bytes().unwrap();
+		assert_eq!(data, [
+			0, 0, 1, 0, 2, 0, // header
+			16, 16, 16, 0, 1, 0, 4, 0, 0x28, 0x01, 0, 0, 1, 0,
+			0, 0, 0, 0, 1, 0, 32, 0, 0, 0, 1, 0, 2, 0, // 256 stored as zero
+		]);
+		assert_eq!(IconGroup::from_bytes(&data).unwrap(), group);
+		assert_eq!(IconGroup::from_bytes(&data[..data.len() - 1]), Err(co::ERROR::INVALID_DATA));
+
+		let mut too_large = group.clone();
+		too_large.entries[1].width = 257;
+		assert_eq!(too_large.to_bytes(), Err(co::ERROR::INVALID_DATA));
+	}
+
+	#[test]
+	fn cursors() {
+		let group = IconGroup {
+			cursor: true,
+			entries: vec![
+				IconGroupEntry {
+					width: 32,
+					height: 64, // XOR and AND masks
+					color_count: 0,
+					planes: 1,
+					bit_count: 1,
+					bytes_in_res: 0x134,
+					id: 7,
+				},
+			],
+		};
+		let data = group.to_bytes().unwrap();
+		assert_eq!(data, [
+			0, 0, 2, 0, 1, 0,
+			32, 0, 64, 0, 1, 0, 1, 0, 0x34, 0x01, 0, 0, 7, 0,
+		]);
+		assert_eq!(IconGroup::from_bytes(&data).unwrap(), group);
+
+		assert_eq!(IconGroup::from_bytes(&[0, 0, 3, 0, 0, 0]), Err(co::ERROR::INVALID_DATA));
+	}
+}
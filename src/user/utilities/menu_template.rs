@@ -0,0 +1,403 @@
+use crate::co;
+use crate::decl::*;
+use crate::user::utilities::template_data::{align4, Reader, write_str};
+
+/// The contents of an `RT_MENU` resource, which is either a
+/// [`MENUITEMTEMPLATEHEADER`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-menuitemtemplateheader)
+/// or a
+/// [`MENUEX_TEMPLATE_HEADER`](https://learn.microsoft.com/en-us/windows/win32/menurc/menuex-template-header),
+/// followed by the items.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let menu = w::MenuTemplate {
+///     items: vec![
+///         w::MenuTemplateItem::popup("&File", vec![
+///             w::MenuTemplateItem::new("&Open\tCtrl+O", 100),
+///             w::MenuTemplateItem::separator(),
+///             w::MenuTemplateItem::new("E&xit", 101),
+///         ]),
+///     ],
+///     ..Default::default()
+/// };
+///
+/// let data = menu.to_bytes()?;
+/// let same = w::MenuTemplate::from_bytes(&data)?;
+/// assert_eq!(menu, same);
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MenuTemplate {
+	/// Whether this is a `MENUEX` template. Only extended templates store
+	/// [`MenuTemplateItem`](crate::MenuTemplateItem) IDs above 65535, help IDs
+	/// and some types and states, like
+	/// [`co::MFT::RADIOCHECK`](crate::co::MFT::RADIOCHECK).
+	pub extended: bool,
+	/// Help context ID of the menu. Only stored in extended templates.
+	pub help_id: u32,
+	/// The items of the menu bar.
+	pub items: Vec<MenuTemplateItem>,
+}
+
+impl MenuTemplate {
+	/// Parses the contents of an `RT_MENU` resource, either a `MENU` or a
+	/// `MENUEX` template.
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the data is malformed, or if submenus are nested deeper than 64 levels.
+	pub fn from_bytes(data: &[u8]) -> SysResult<Self> {
+		let mut rd = Reader { data, pos: 0 };
+		let version = rd.u16()?;
+		let offset = rd.u16()?;
+		match version {
+			0 => {
+				rd.pos += offset as usize;
+				Ok(Self {
+					extended: false,
+					help_id: 0,
+					items: Self::parse_items(&mut rd, 0)?,
+				})
+			},
+			1 => {
+				let help_id = rd.u32()?;
+				rd.pos = 4 + offset as usize;
+				Ok(Self {
+					extended: true,
+					help_id,
+					items: Self::parse_items_ex(&mut rd, 0)?,
+				})
+			},
+			_ => Err(co::ERROR::INVALID_DATA),
+		}
+	}
+
+	/// Serializes the menu into the contents of an `RT_MENU` resource, either
+	/// a `MENU` or a `MENUEX` template, according to
+	/// [`MenuTemplate::extended`](crate::MenuTemplate::extended).
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the menu or a submenu has no items, since the templates have no way to
+	/// represent an empty item list, or if an item of a `MENU` template has an
+	/// ID above 65535.
+	pub fn to_bytes(&self) -> SysResult<Vec<u8>> {
+		let mut buf = Vec::<u8>::default();
+		if self.extended {
+			buf.extend_from_slice(&1u16.to_le_bytes()); // wVersion
+			buf.extend_from_slice(&4u16.to_le_bytes()); // wOffset
+			buf.extend_from_slice(&self.help_id.to_le_bytes());
+			Self::write_items_ex(&mut buf, &self.items)?;
+		} else {
+			buf.extend_from_slice(&[0; 4]); // versionNumber, offset
+			Self::write_items(&mut buf, &self.items)?;
+		}
+		Ok(buf)
+	}
+
+	fn parse_items(rd: &mut Reader, depth: usize) -> SysResult<Vec<MenuTemplateItem>> {
+		if depth > MAX_DEPTH {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		let mut items = Vec::default();
+		loop {
+			let option = rd.u16()?;
+			let is_popup = option & MF_POPUP != 0;
+			let id = if is_popup { 0 } else { rd.u16()? };
+			let text = rd.str()?;
+			let flags = (option & !(MF_POPUP | MF_END)) as u32;
+
+			let (kind, state) = if flags == 0 && id == 0 && text.is_empty() && !is_popup {
+				(co::MFT::SEPARATOR, co::MFS::ENABLED)
+			} else {
+				(
+					unsafe { co::MFT::from_raw(flags & !MFS_MASK) },
+					unsafe { co::MFS::from_raw(flags & MFS_MASK) },
+				)
+			};
+
+			items.push(MenuTemplateItem {
+				text,
+				id: id as _,
+				kind,
+				state,
+				help_id: 0,
+				submenu: if is_popup { Some(Self::parse_items(rd, depth + 1)?) } else { None },
+			});
+			if option & MF_END != 0 {
+				return Ok(items);
+			}
+		}
+	}
+
+	fn parse_items_ex(rd: &mut Reader, depth: usize) -> SysResult<Vec<MenuTemplateItem>> {
+		if depth > MAX_DEPTH {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		let mut items = Vec::default();
+		loop {
+			rd.align4();
+			let kind = unsafe { co::MFT::from_raw(rd.u32()?) };
+			let state = unsafe { co::MFS::from_raw(rd.u32()?) };
+			let id = rd.u32()?;
+			let res_info = rd.u16()?;
+			let text = rd.str()?;
+
+			let (help_id, submenu) = if res_info & MFR_POPUP != 0 {
+				rd.align4();
+				(rd.u32()?, Some(Self::parse_items_ex(rd, depth + 1)?))
+			} else {
+				(0, None)
+			};
+
+			items.push(MenuTemplateItem { text, id, kind, state, help_id, submenu });
+			if res_info & MFR_END != 0 {
+				return Ok(items);
+			}
+		}
+	}
+
+	fn write_items(buf: &mut Vec<u8>, items: &[MenuTemplateItem]) -> SysResult<()> {
+		if items.is_empty() {
+			return Err(co::ERROR::INVALID_DATA); // the last item carries the end flag
+		}
+		for (idx, item) in items.iter().enumerate() {
+			let mut option = if item.is_plain_separator() {
+				0
+			} else {
+				(item.kind.raw() | item.state.raw()) as u16 & !(MF_POPUP | MF_END)
+			};
+			if item.submenu.is_some() {
+				option |= MF_POPUP;
+			}
+			if idx == items.len() - 1 {
+				option |= MF_END;
+			}
+
+			buf.extend_from_slice(&option.to_le_bytes());
+			if item.submenu.is_none() {
+				let id = u16::try_from(item.id)
+					.map_err(|_| co::ERROR::INVALID_DATA)?;
+				buf.extend_from_slice(&id.to_le_bytes());
+			}
+			write_str(buf, &item.text);
+			if let Some(submenu) = &item.submenu {
+				Self::write_items(buf, submenu)?;
+			}
+		}
+		Ok(())
+	}
+
+	fn write_items_ex(buf: &mut Vec<u8>, items: &[MenuTemplateItem]) -> SysResult<()> {
+		if items.is_empty() {
+			return Err(co::ERROR::INVALID_DATA); // the last item carries the end flag
+		}
+		for (idx, item) in items.iter().enumerate() {
+			let mut res_info = 0;
+			if item.submenu.is_some() {
+				res_info |= MFR_POPUP;
+			}
+			if idx == items.len() - 1 {
+				res_info |= MFR_END;
+			}
+
+			align4(buf);
+			buf.extend_from_slice(&item.kind.raw().to_le_bytes());
+			buf.extend_from_slice(&item.state.raw().to_le_bytes());
+			buf.extend_from_slice(&item.id.to_le_bytes());
+			buf.extend_from_slice(&res_info.to_le_bytes());
+			write_str(buf, &item.text);
+			if let Some(submenu) = &item.submenu {
+				align4(buf);
+				buf.extend_from_slice(&item.help_id.to_le_bytes());
+				Self::write_items_ex(buf, submenu)?;
+			}
+		}
+		Ok(())
+	}
+}
+
+/// An item of a [`MenuTemplate`](crate::MenuTemplate).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MenuTemplateItem {
+	/// Text of the item; for separators, it's empty.
+	pub text: String,
+	/// Command ID of the item. Popups only store it in extended templates.
+	pub id: u32,
+	/// Type of the item.
+	pub kind: co::MFT,
+	/// State of the item.
+	pub state: co::MFS,
+	/// Help context ID of a popup. Only stored in extended templates.
+	pub help_id: u32,
+	/// Items of the submenu, if this item is a popup.
+	pub submenu: Option<Vec<MenuTemplateItem>>,
+}
+
+impl MenuTemplateItem {
+	/// Creates a new string item.
+	#[must_use]
+	pub fn new(text: &str, id: u32) -> Self {
+		Self {
+			text: text.to_owned(),
+			id,
+			kind: co::MFT::STRING,
+			state: co::MFS::ENABLED,
+			help_id: 0,
+			submenu: None,
+		}
+	}
+
+	/// Creates a new popup item, which opens a submenu.
+	#[must_use]
+	pub fn popup(text: &str, submenu: Vec<MenuTemplateItem>) -> Self {
+		Self {
+			submenu: Some(submenu),
+			..Self::new(text, 0)
+		}
+	}
+
+	/// Creates a new separator.
+	#[must_use]
+	pub fn separator() -> Self {
+		Self {
+			kind: co::MFT::SEPARATOR,
+			..Self::new("", 0)
+		}
+	}
+
+	/// A separator which, in a `MENU` template, is written as an empty item,
+	/// like the resource compiler does.
+	fn is_plain_separator(&self) -> bool {
+		self.kind == co::MFT::SEPARATOR
+			&& self.state == co::MFS::ENABLED
+			&& self.id == 0
+			&& self.text.is_empty()
+			&& self.submenu.is_none()
+	}
+}
+
+//------------------------------------------------------------------------------
+
+const MF_POPUP: u16 = 0x0010;
+const MF_END: u16 = 0x0080;
+const MFR_POPUP: u16 = 0x01;
+const MFR_END: u16 = 0x80;
+
+/// Maximum nesting of submenus accepted when parsing, so a crafted template
+/// won't overflow the stack.
+const MAX_DEPTH: usize = 64;
+
+/// Bits of a `MENU` template item option which are [`co::MFS`] values; the
+/// others are [`co::MFT`] values.
+const MFS_MASK: u32 = 0x0000_0003 | 0x0000_0008 | 0x0000_1000;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// A `MENU` template with the given number of nested popups, the innermost
+	/// holding a single item.
+	fn nested(levels: usize) -> Vec<u8> {
+		let mut data = vec![0; 4]; // versionNumber, offset
+		for _ in 0..levels {
+			data.extend_from_slice(&(MF_POPUP | MF_END).to_le_bytes());
+			data.extend_from_slice(&[0, 0]); // empty text
+		}
+		data.extend_from_slice(&MF_END.to_le_bytes());
+		data.extend_from_slice(&1u16.to_le_bytes()); // ID
+		data.extend_from_slice(&[0, 0]);
+		data
+	}
+
+	#[test]
+	fn nesting_limit() {
+		let menu = MenuTemplate::from_bytes(&nested(MAX_DEPTH)).unwrap();
+		let mut items = &menu.items;
+		for _ in 0..MAX_DEPTH {
+			items = items[0].submenu.as_ref().unwrap();
+		}
+		assert_eq!(items[0].id, 1);
+		assert_eq!(menu.to_bytes().unwrap(), nested(MAX_DEPTH));
+
+		for levels in [MAX_DEPTH + 1, 100_000] {
+			assert_eq!(MenuTemplate::from_bytes(&nested(levels)), Err(co::ERROR::INVALID_DATA));
+		}
+	}
+
+	#[test]
+	fn large_ids() {
+		let mut menu = MenuTemplate {
+			items: vec![
+				MenuTemplateItem::popup("&File", vec![
+					MenuTemplateItem::new("&Open", 0xffff),
+					MenuTemplateItem::new("E&xit", 0x1_0000),
+				]),
+			],
+			..Default::default()
+		};
+		assert_eq!(menu.to_bytes(), Err(co::ERROR::INVALID_DATA));
+
+		menu.extended = true;
+		let data = menu.to_bytes().unwrap();
+		assert_eq!(MenuTemplate::from_bytes(&data).unwrap(), menu);
+	}
+
+	#[test]
+	fn empty_submenus() {
+		for extended in [false, true] {
+			let mut menu = MenuTemplate {
+				extended,
+				items: vec![
+					MenuTemplateItem::popup("&File", vec![]),
+					MenuTemplateItem::new("&Help", 100),
+				],
+				..Default::default()
+			};
+			assert_eq!(menu.to_bytes(), Err(co::ERROR::INVALID_DATA));
+
+			menu.items.clear();
+			assert_eq!(menu.to_bytes(), Err(co::ERROR::INVALID_DATA));
+		}
+	}
+
+	#[test]
+	fn round_trip() {
+		let mut menu = MenuTemplate {
+			items: vec![
+				MenuTemplateItem::popup("&File", vec![
+					MenuTemplateItem::new("&Open\tCtrl+O", 100),
+					MenuTemplateItem::separator(),
+					MenuTemplateItem::popup("&Recent", vec![
+						MenuTemplateItem {
+							state: co::MFS::GRAYED,
+							..MenuTemplateItem::new("(none)", 110)
+						},
+					]),
+					MenuTemplateItem {
+						state: co::MFS::CHECKED,
+						..MenuTemplateItem::new("E&xit", 101)
+					},
+				]),
+				MenuTemplateItem::new("&Help", 102),
+			],
+			..Default::default()
+		};
+		let data = menu.to_bytes().unwrap();
+		assert_eq!(&data[..8], [0, 0, 0, 0, MF_POPUP as u8, 0, b'&', 0]);
+		assert_eq!(MenuTemplate::from_bytes(&data).unwrap(), menu);
+
+		menu.extended = true;
+		menu.help_id = 7;
+		menu.items[0].help_id = 8;
+		menu.items[0].id = 200;
+		menu.items[0].submenu.as_mut().unwrap()[0].kind = co::MFT::STRING | co::MFT::RADIOCHECK;
+		let data = menu.to_bytes().unwrap();
+		assert_eq!(&data[..8], [1, 0, 4, 0, 7, 0, 0, 0]);
+		assert_eq!(MenuTemplate::from_bytes(&data).unwrap(), menu);
+
+		assert_eq!(MenuTemplate::from_bytes(&[2, 0, 0, 0]), Err(co::ERROR::INVALID_DATA));
+		assert_eq!(MenuTemplate::from_bytes(&data[..data.len() - 2]), Err(co::ERROR::INVALID_DATA));
+	}
+}
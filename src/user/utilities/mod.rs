@@ -0,0 +1,10 @@
+mod accel_table;
+mod dlg_template;
+mod icon_group;
+mod menu_template;
+mod template_data;
+
+pub use accel_table::AccelTable;
+pub use dlg_template::{DlgClass, DlgFont, DlgIdStr, DlgTemplate, DlgTemplateControl};
+pub use icon_group::{IconGroup, IconGroupEntry};
+pub use menu_template::{MenuTemplate, MenuTemplateItem};
@@ -0,0 +1,62 @@
+use crate::co;
+use crate::decl::*;
+
+/// Sequential reader of little-endian resource template data.
+pub(in crate::user) struct Reader<'a> {
+	pub(in crate::user) data: &'a [u8],
+	pub(in crate::user) pos: usize,
+}
+
+impl<'a> Reader<'a> {
+	pub(in crate::user) fn bytes(&mut self, len: usize) -> SysResult<&'a [u8]> {
+		let chunk = self.data.get(self.pos..self.pos + len)
+			.ok_or(co::ERROR::INVALID_DATA)?;
+		self.pos += len;
+		Ok(chunk)
+	}
+
+	pub(in crate::user) fn u8(&mut self) -> SysResult<u8> {
+		Ok(self.bytes(1)?[0])
+	}
+
+	pub(in crate::user) fn u16(&mut self) -> SysResult<u16> {
+		Ok(u16::from_le_bytes(self.bytes(2)?.try_into().unwrap()))
+	}
+
+	pub(in crate::user) fn i16(&mut self) -> SysResult<i16> {
+		Ok(self.u16()? as _)
+	}
+
+	pub(in crate::user) fn u32(&mut self) -> SysResult<u32> {
+		Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+	}
+
+	pub(in crate::user) fn align4(&mut self) {
+		self.pos = self.pos.next_multiple_of(4);
+	}
+
+	/// Reads a null-terminated UTF-16 string.
+	pub(in crate::user) fn str(&mut self) -> SysResult<String> {
+		let mut chars = Vec::<u16>::default();
+		loop {
+			match self.u16()? {
+				0 => break,
+				ch => chars.push(ch),
+			}
+		}
+		Ok(String::from_utf16_lossy(&chars))
+	}
+}
+
+/// Pads the buffer with zeros up to a `DWORD` boundary.
+pub(in crate::user) fn align4(buf: &mut Vec<u8>) {
+	buf.resize(buf.len().next_multiple_of(4), 0);
+}
+
+/// Writes a null-terminated UTF-16 string.
+pub(in crate::user) fn write_str(buf: &mut Vec<u8>, s: &str) {
+	for ch in s.encode_utf16().chain(std::iter::once(0)) {
+		buf.extend_from_slice(&ch.to_le_bytes());
+	}
+}
+
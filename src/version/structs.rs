@@ -7,7 +7,7 @@ use crate::decl::*;
 /// struct.
 #[repr(C)]
 pub struct VS_FIXEDFILEINFO {
-	dwSignature: u32,
+	pub(in crate::version) dwSignature: u32,
 	pub dwStrucVersion: u32,
 	dwFileVersionMS: u32,
 	dwFileVersionLS: u32,
@@ -25,12 +25,14 @@ pub struct VS_FIXEDFILEINFO {
 impl Default for VS_FIXEDFILEINFO {
 	fn default() -> Self {
 		let mut obj = unsafe { std::mem::zeroed::<Self>() };
-		obj.dwSignature = 0xfeef_04bd;
+		obj.dwSignature = Self::SIGNATURE;
 		obj
 	}
 }
 
 impl VS_FIXEDFILEINFO {
+	pub(in crate::version) const SIGNATURE: u32 = 0xfeef_04bd;
+
 	/// Returns the `dwFileVersionMS` and `dwFileVersionLS` fields.
 	#[must_use]
 	pub const fn dwFileVersion(&self) -> [u16; 4] {
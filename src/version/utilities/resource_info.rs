@@ -4,6 +4,13 @@ use crate::decl::*;
 /// Retrieves data from an embedded resource, which can be read from an
 /// EXE or a DLL file.
 ///
+/// The
+/// [`VS_VERSIONINFO`](https://learn.microsoft.com/en-us/windows/win32/menurc/vs-versioninfo)
+/// tree is parsed entirely in Rust, so it can also be loaded from a memory
+/// buffer with [`ResourceInfo::from_bytes`](crate::ResourceInfo::from_bytes),
+/// and written back with
+/// [`ResourceInfo::serialize_to_bytes`](crate::ResourceInfo::serialize_to_bytes).
+///
 /// # Examples
 ///
 /// Reading version information:
@@ -42,15 +49,116 @@ use crate::decl::*;
 /// }
 /// # Ok::<_, winsafe::co::ERROR>(())
 /// ```
+///
+/// Building a version resource and stamping it into an executable:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut fixed = w::VS_FIXEDFILEINFO::default();
+/// fixed.set_dwFileVersion([1, 2, 0, 0]);
+/// fixed.set_dwProductVersion([1, 2, 0, 0]);
+///
+/// let mut res_info = w::ResourceInfo::new(fixed);
+/// let lang = w::LANGID::new(co::LANG::ENGLISH, co::SUBLANG::ENGLISH_US);
+/// res_info.set_string(lang, co::CP::UTF16, "ProductName", "Foo");
+/// res_info.set_string(lang, co::CP::UTF16, "FileVersion", "1.2.0.0");
+///
+/// let hupd = w::HUPDATERSRC::BeginUpdateResource("C:\\Temp\\foo.exe", false)?;
+/// hupd.UpdateResource(
+///     w::RtStr::Rt(co::RT::VERSION),
+///     w::IdStr::Id(1),
+///     lang,
+///     &res_info.serialize_to_bytes()?,
+/// )?;
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
 pub struct ResourceInfo {
-	res_buf: HeapBlock,
+	fixed: Option<VS_FIXEDFILEINFO>,
+	translations: Vec<(LANGID, co::CP)>,
+	str_tables: Vec<StringTable>,
 }
 
 impl ResourceInfo {
+	/// Creates a new `ResourceInfo` with the given version information, and no
+	/// language blocks.
+	#[must_use]
+	pub fn new(version_info: VS_FIXEDFILEINFO) -> Self {
+		Self {
+			fixed: Some(version_info),
+			translations: Vec::default(),
+			str_tables: Vec::default(),
+		}
+	}
+
 	/// Reads and stores the resource data from an executable file or a DLL.
 	#[must_use]
 	pub fn read_from(exe_file: &str) -> SysResult<ResourceInfo> {
-		Ok(Self { res_buf: GetFileVersionInfo(exe_file)? })
+		Self::from_bytes(GetFileVersionInfo(exe_file)?.as_slice())
+	}
+
+	/// Parses the resource data from a raw
+	/// [`VS_VERSIONINFO`](https://learn.microsoft.com/en-us/windows/win32/menurc/vs-versioninfo)
+	/// buffer, like the one stored in an `RT_VERSION` resource.
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the buffer is malformed.
+	#[must_use]
+	pub fn from_bytes(data: &[u8]) -> SysResult<ResourceInfo> {
+		let (root, _) = VerNode::parse(data, 0)?;
+		if root.key != "VS_VERSION_INFO" {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+
+		let fixed = if root.value.len() >= std::mem::size_of::<VS_FIXEDFILEINFO>() {
+			let fixed = unsafe {
+				std::ptr::read_unaligned(root.value.as_ptr() as *const VS_FIXEDFILEINFO)
+			};
+			if fixed.dwSignature != VS_FIXEDFILEINFO::SIGNATURE {
+				return Err(co::ERROR::INVALID_DATA);
+			}
+			Some(fixed)
+		} else {
+			None // no VS_FIXEDFILEINFO in this resource
+		};
+
+		let mut translations = Vec::<(LANGID, co::CP)>::default();
+		let mut str_tables = Vec::<StringTable>::default();
+
+		for child in root.children.iter() {
+			match child.key.as_str() {
+				"StringFileInfo" => {
+					for table in child.children.iter() {
+						let (lang_id, code_page) = StringTable::parse_key(&table.key)?;
+						str_tables.push(StringTable {
+							lang_id,
+							code_page,
+							entries: table.children.iter()
+								.map(|s| (s.key.clone(), s.value_as_str()))
+								.collect(),
+						});
+					}
+				},
+				"VarFileInfo" => {
+					for var in child.children.iter() {
+						if var.key == "Translation" {
+							translations.extend(
+								var.value.chunks_exact(4)
+									.map(|pair| unsafe {
+										(
+											LANGID::from_raw(u16::from_le_bytes([pair[0], pair[1]])),
+											co::CP::from_raw(u16::from_le_bytes([pair[2], pair[3]])),
+										)
+									}),
+							);
+						}
+					}
+				},
+				_ => {}, // unknown blocks are ignored
+			}
+		}
+
+		Ok(Self { fixed, translations, str_tables })
 	}
 
 	/// Returns an iterator over the language blocks, if any, which are composed
@@ -60,38 +168,131 @@ impl ResourceInfo {
 	/// respective languages.
 	#[must_use]
 	pub fn blocks(&self) -> impl Iterator<Item = ResourceInfoBlock> + '_ {
-		unsafe {
-			VarQueryValue::<(LANGID, co::CP)>(
-				self.res_buf.as_slice(),
-				"\\VarFileInfo\\Translation",
-			).ok()
-				.map(|(plangs, sz)|
-					std::slice::from_raw_parts(
-						plangs,
-						sz as usize / std::mem::size_of::<(LANGID, co::CP)>(),
-					)
-				)
-				.unwrap_or(&[])
-				.iter()
-				.map(|(lang_id, code_page)|
-					ResourceInfoBlock {
-						res_info: self,
-						lang_id: *lang_id,
-						code_page: *code_page,
-					}
-				)
-		}
+		self.translations.iter()
+			.map(|(lang_id, code_page)|
+				ResourceInfoBlock {
+					res_info: self,
+					lang_id: *lang_id,
+					code_page: *code_page,
+				}
+			)
 	}
 
 	/// Returns the version information, if any.
 	#[must_use]
-	pub fn version_info(&self) -> Option<&VS_FIXEDFILEINFO> {
-		unsafe {
-			VarQueryValue::<VS_FIXEDFILEINFO>(self.res_buf.as_slice(), "\\")
-				.ok()
-				.map(|(pvsf, _)| &*pvsf)
+	pub const fn version_info(&self) -> Option<&VS_FIXEDFILEINFO> {
+		self.fixed.as_ref()
+	}
+
+	/// Returns a mutable reference to the version information, if any.
+	#[must_use]
+	pub fn version_info_mut(&mut self) -> Option<&mut VS_FIXEDFILEINFO> {
+		self.fixed.as_mut()
+	}
+
+	/// Replaces the version information.
+	pub fn set_version_info(&mut self, version_info: Option<VS_FIXEDFILEINFO>) {
+		self.fixed = version_info;
+	}
+
+	/// If the string exists in the given language block, changes its value,
+	/// otherwise creates it. The language block is also created, if needed.
+	pub fn set_string(&mut self,
+		lang_id: LANGID, code_page: co::CP, key: &str, new_value: &str)
+	{
+		if !self.translations.contains(&(lang_id, code_page)) {
+			self.translations.push((lang_id, code_page));
+		}
+
+		let table = match self.str_tables.iter()
+			.position(|t| t.lang_id == lang_id && t.code_page == code_page)
+		{
+			Some(idx) => &mut self.str_tables[idx],
+			None => {
+				self.str_tables.push(StringTable {
+					lang_id,
+					code_page,
+					entries: Vec::default(),
+				});
+				self.str_tables.last_mut().unwrap()
+			},
+		};
+
+		let key_uc = key.to_uppercase();
+		match table.entries.iter_mut().find(|(k, _)| k.to_uppercase() == key_uc) {
+			Some((_, val)) => *val = new_value.to_owned(),
+			None => table.entries.push((key.to_owned(), new_value.to_owned())),
 		}
 	}
+
+	/// Serializes the data to a raw
+	/// [`VS_VERSIONINFO`](https://learn.microsoft.com/en-us/windows/win32/menurc/vs-versioninfo)
+	/// buffer, which can be written with
+	/// [`HUPDATERSRC::UpdateResource`](crate::prelude::kernel_Hupdatersrc::UpdateResource).
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// a node, or the whole buffer, exceeds the 65535 bytes its length field
+	/// can hold.
+	pub fn serialize_to_bytes(&self) -> SysResult<Vec<u8>> {
+		let mut buf = Vec::<u8>::default();
+
+		let fixed_bytes = self.fixed.as_ref()
+			.map(|fixed| unsafe {
+				std::slice::from_raw_parts(
+					fixed as *const _ as *const u8,
+					std::mem::size_of::<VS_FIXEDFILEINFO>(),
+				)
+			})
+			.unwrap_or(&[]);
+
+		VerNode::serialize(&mut buf, "VS_VERSION_INFO", false,
+			fixed_bytes, fixed_bytes.len(), |buf| {
+
+			if !self.str_tables.is_empty() {
+				VerNode::serialize(buf, "StringFileInfo", true, &[], 0, |buf| {
+					for table in self.str_tables.iter() {
+						VerNode::serialize(buf, &table.key(), true, &[], 0, |buf| {
+							for (key, val) in table.entries.iter() {
+								let val_bytes = val.encode_utf16()
+									.chain(std::iter::once(0x0000)) // terminating null
+									.flat_map(|ch| ch.to_le_bytes())
+									.collect::<Vec<_>>();
+								VerNode::serialize(buf, key, true,
+									&val_bytes, val_bytes.len() / 2, |_| Ok(()))?;
+							}
+							Ok(())
+						})?;
+					}
+					Ok(())
+				})?;
+			}
+
+			if !self.translations.is_empty() {
+				VerNode::serialize(buf, "VarFileInfo", true, &[], 0, |buf| {
+					let trans_bytes = self.translations.iter()
+						.flat_map(|(lang_id, code_page)| {
+							let mut pair = [0u8; 4];
+							pair[..2].copy_from_slice(&u16::from(*lang_id).to_le_bytes());
+							pair[2..].copy_from_slice(&u16::from(*code_page).to_le_bytes());
+							pair
+						})
+						.collect::<Vec<_>>();
+					VerNode::serialize(buf, "Translation", false,
+						&trans_bytes, trans_bytes.len(), |_| Ok(()))
+				})?;
+			}
+			Ok(())
+		})?;
+
+		Ok(buf)
+	}
+
+	fn find_table(&self,
+		lang_id: LANGID, code_page: co::CP) -> Option<&StringTable>
+	{
+		self.str_tables.iter()
+			.find(|t| t.lang_id == lang_id && t.code_page == code_page)
+	}
 }
 
 /// An language block of [`ResourceInfo`](crate::ResourceInfo), composed of a
@@ -106,31 +307,343 @@ impl<'a> ResourceInfoBlock<'a> {
 	#[must_use] pub const fn lang_id(&self) -> LANGID { self.lang_id }
 	#[must_use] pub const fn code_page(&self) -> co::CP { self.code_page }
 
-	#[must_use] pub fn comments(&self) -> Option<String> { self.generic_string_info("Comments") }
-	#[must_use] pub fn company_name(&self) -> Option<String> { self.generic_string_info("CompanyName") }
-	#[must_use] pub fn file_description(&self) -> Option<String> { self.generic_string_info("FileDescrition") }
-	#[must_use] pub fn file_version(&self) -> Option<String> { self.generic_string_info("FileVersion") }
-	#[must_use] pub fn internal_name(&self) -> Option<String> { self.generic_string_info("InternalName") }
-	#[must_use] pub fn legal_copyright(&self) -> Option<String> { self.generic_string_info("LegalCopyright") }
-	#[must_use] pub fn legal_trademarks(&self) -> Option<String> { self.generic_string_info("LegalTrademarks") }
-	#[must_use] pub fn original_filename(&self) -> Option<String> { self.generic_string_info("OriginalFilename") }
-	#[must_use] pub fn product_name(&self) -> Option<String> { self.generic_string_info("ProductName") }
-	#[must_use] pub fn product_version(&self) -> Option<String> { self.generic_string_info("ProductVersion") }
-	#[must_use] pub fn private_build(&self) -> Option<String> { self.generic_string_info("PrivateBuild") }
-	#[must_use] pub fn special_build(&self) -> Option<String> { self.generic_string_info("SpecialBuild") }
-
-	fn generic_string_info(&self, info: &str) -> Option<String> {
-		unsafe {
-			VarQueryValue::<u16>(
-				self.res_info.res_buf.as_slice(),
-				&format!("\\StringFileInfo\\{:04x}{:04x}\\{}",
-					u16::from(self.lang_id), u16::from(self.code_page), info),
-			).ok()
-				.map(|(pstr, len)| {
-					WString::from_wchars_slice(
-						std::slice::from_raw_parts(pstr, len as _),
-					).to_string()
-				})
+	#[must_use] pub fn comments(&self) -> Option<String> { self.string("Comments") }
+	#[must_use] pub fn company_name(&self) -> Option<String> { self.string("CompanyName") }
+	#[must_use] pub fn file_description(&self) -> Option<String> { self.string("FileDescription") }
+	#[must_use] pub fn file_version(&self) -> Option<String> { self.string("FileVersion") }
+	#[must_use] pub fn internal_name(&self) -> Option<String> { self.string("InternalName") }
+	#[must_use] pub fn legal_copyright(&self) -> Option<String> { self.string("LegalCopyright") }
+	#[must_use] pub fn legal_trademarks(&self) -> Option<String> { self.string("LegalTrademarks") }
+	#[must_use] pub fn original_filename(&self) -> Option<String> { self.string("OriginalFilename") }
+	#[must_use] pub fn product_name(&self) -> Option<String> { self.string("ProductName") }
+	#[must_use] pub fn product_version(&self) -> Option<String> { self.string("ProductVersion") }
+	#[must_use] pub fn private_build(&self) -> Option<String> { self.string("PrivateBuild") }
+	#[must_use] pub fn special_build(&self) -> Option<String> { self.string("SpecialBuild") }
+
+	/// Returns the information string with the given key, if any. Any key can
+	/// be queried, including vendor-specific ones.
+	///
+	/// The search is case-insensitive.
+	#[must_use]
+	pub fn string(&self, key: &str) -> Option<String> {
+		let key_uc = key.to_uppercase();
+		self.strings()
+			.find(|(k, _)| k.to_uppercase() == key_uc)
+			.map(|(_, val)| val.to_owned())
+	}
+
+	/// Returns an iterator over all the key/value pairs of the information
+	/// strings of this block, in the order they're stored.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let res_info = w::ResourceInfo::read_from("C:\\Temp\\foo.dll")?;
+	///
+	/// for block in res_info.blocks() {
+	///     for (key, val) in block.strings() {
+	///         println!("{}: {}", key, val);
+	///     }
+	/// }
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	pub fn strings(&self) -> impl Iterator<Item = (&'a str, &'a str)> + 'a {
+		self.res_info.find_table(self.lang_id, self.code_page)
+			.map(|table| table.entries.as_slice())
+			.unwrap_or(&[])
+			.iter()
+			.map(|(key, val)| (key.as_str(), val.as_str()))
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A `StringTable` block, child of `StringFileInfo`.
+struct StringTable {
+	lang_id: LANGID,
+	code_page: co::CP,
+	entries: Vec<(String, String)>,
+}
+
+impl StringTable {
+	/// Parses the language ID and code page from a key like `040904B0`.
+	fn parse_key(key: &str) -> SysResult<(LANGID, co::CP)> {
+		if key.len() != 8 {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		let raw = u32::from_str_radix(key, 16)
+			.map_err(|_| co::ERROR::INVALID_DATA)?;
+		Ok(unsafe {
+			(LANGID::from_raw(HIWORD(raw)), co::CP::from_raw(LOWORD(raw)))
+		})
+	}
+
+	fn key(&self) -> String {
+		format!("{:04X}{:04X}", u16::from(self.lang_id), u16::from(self.code_page))
+	}
+}
+
+/// A generic node of the `VS_VERSIONINFO` tree: a `wLength`, `wValueLength`
+/// and `wType` header, followed by the key, the value and the children, all
+/// aligned to 32 bits.
+struct VerNode<'a> {
+	key: String,
+	value: &'a [u8],
+	children: Vec<VerNode<'a>>,
+}
+
+impl<'a> VerNode<'a> {
+	/// Parses the node at the given offset, returning it along with the offset
+	/// past its end. Alignment is relative to the start of `blob`.
+	fn parse(blob: &'a [u8], off: usize) -> SysResult<(Self, usize)> {
+		let read_u16 = |pos: usize| -> SysResult<u16> {
+			blob.get(pos..pos + 2)
+				.map(|b| u16::from_le_bytes([b[0], b[1]]))
+				.ok_or(co::ERROR::INVALID_DATA)
+		};
+
+		let len = read_u16(off)? as usize;
+		let end = off + len;
+		if len < 6 || end > blob.len() {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		let val_len = read_u16(off + 2)? as usize;
+		let is_text = read_u16(off + 4)? == 1;
+
+		let mut pos = off + 6;
+		let mut key = Vec::<u16>::default();
+		loop {
+			if pos >= end {
+				return Err(co::ERROR::INVALID_DATA); // key without terminating null
+			}
+			let ch = read_u16(pos)?;
+			pos += 2;
+			if ch == 0x0000 {
+				break;
+			}
+			key.push(ch);
+		}
+
+		pos = Self::align(pos).min(end);
+		let val_end = (pos + if is_text { val_len * 2 } else { val_len }).min(end);
+		let value = &blob[pos..val_end];
+
+		pos = Self::align(val_end);
+		let mut children = Vec::<VerNode>::default();
+		while pos + 6 <= end && read_u16(pos)? != 0 { // stop at zero padding
+			let (child, next) = Self::parse(blob, pos)?;
+			if next > end {
+				return Err(co::ERROR::INVALID_DATA); // child overflows its parent
+			}
+			children.push(child);
+			pos = Self::align(next);
+		}
+
+		Ok((
+			Self {
+				key: String::from_utf16_lossy(&key),
+				value,
+				children,
+			},
+			end,
+		))
+	}
+
+	/// Interprets the value as a null-terminated UTF-16 string.
+	fn value_as_str(&self) -> String {
+		String::from_utf16_lossy(
+			&self.value.chunks_exact(2)
+				.map(|ch2| u16::from_le_bytes([ch2[0], ch2[1]]))
+				.take_while(|ch| *ch != 0x0000) // ignore terminating null
+				.collect::<Vec<_>>(),
+		)
+	}
+
+	/// Appends a node to the buffer, which is assumed to start at an aligned
+	/// address. `val_len` is the `wValueLength` field, which counts bytes for
+	/// binary nodes and chars for text nodes.
+	///
+	/// Fails with [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA)
+	/// if `wLength` or `wValueLength` don't fit in 16 bits.
+	fn serialize(
+		buf: &mut Vec<u8>,
+		key: &str,
+		is_text: bool,
+		value: &[u8],
+		val_len: usize,
+		children: impl FnOnce(&mut Vec<u8>) -> SysResult<()>,
+	) -> SysResult<()>
+	{
+		let val_len = u16::try_from(val_len).map_err(|_| co::ERROR::INVALID_DATA)?;
+
+		Self::pad(buf);
+		let start = buf.len();
+		buf.extend_from_slice(&[0, 0]); // wLength, filled at the end
+		buf.extend_from_slice(&val_len.to_le_bytes());
+		buf.extend_from_slice(&(is_text as u16).to_le_bytes());
+		key.encode_utf16()
+			.chain(std::iter::once(0x0000)) // terminating null
+			.for_each(|ch| buf.extend_from_slice(&ch.to_le_bytes()));
+
+		Self::pad(buf);
+		buf.extend_from_slice(value);
+		children(buf)?;
+
+		let len = u16::try_from(buf.len() - start).map_err(|_| co::ERROR::INVALID_DATA)?;
+		buf[start..start + 2].copy_from_slice(&len.to_le_bytes());
+		Ok(())
+	}
+
+	const fn align(pos: usize) -> usize {
+		(pos + 3) & !3
+	}
+
+	fn pad(buf: &mut Vec<u8>) {
+		buf.resize(Self::align(buf.len()), 0);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// As written by the resource compiler.
+	const FIXTURE: [u8; 300] = [
+		0x2c, 0x01, 0x34, 0x00, 0x00, 0x00, // VS_VERSION_INFO: wLength, wValueLength, wType
+		0x56, 0x00, 0x53, 0x00, 0x5f, 0x00, 0x56, 0x00, 0x45, 0x00, 0x52, 0x00, 0x53, 0x00, 0x49, 0x00, // "VS_VERSION_INFO"
+		0x4f, 0x00, 0x4e, 0x00, 0x5f, 0x00, 0x49, 0x00, 0x4e, 0x00, 0x46, 0x00, 0x4f, 0x00, 0x00, 0x00,
+		0x00, 0x00, // padding
+		0xbd, 0x04, 0xef, 0xfe, 0x00, 0x00, 0x01, 0x00, 0x02, 0x00, 0x01, 0x00, 0x04, 0x00, 0x03, 0x00, // dwSignature, dwStrucVersion, dwFileVersionMS/LS
+		0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // dwProductVersionMS/LS, dwFileFlagsMask
+		0x04, 0x00, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // dwFileFlags, dwFileOS, dwFileType, dwFileSubtype, dwFileDate
+		0x00, 0x00, 0x00, 0x00,
+		0x8c, 0x00, 0x00, 0x00, 0x01, 0x00, // StringFileInfo
+		0x53, 0x00, 0x74, 0x00, 0x72, 0x00, 0x69, 0x00, 0x6e, 0x00, 0x67, 0x00, 0x46, 0x00, 0x69, 0x00, // "StringFileInfo"
+		0x6c, 0x00, 0x65, 0x00, 0x49, 0x00, 0x6e, 0x00, 0x66, 0x00, 0x6f, 0x00, 0x00, 0x00,
+		0x68, 0x00, 0x00, 0x00, 0x01, 0x00, // StringTable
+		0x30, 0x00, 0x34, 0x00, 0x30, 0x00, 0x39, 0x00, 0x30, 0x00, 0x34, 0x00, 0x42, 0x00, 0x30, 0x00, // "040904B0"
+		0x00, 0x00,
+		0x28, 0x00, 0x04, 0x00, 0x01, 0x00, // String
+		0x50, 0x00, 0x72, 0x00, 0x6f, 0x00, 0x64, 0x00, 0x75, 0x00, 0x63, 0x00, 0x74, 0x00, 0x4e, 0x00, // "ProductName"
+		0x61, 0x00, 0x6d, 0x00, 0x65, 0x00, 0x00, 0x00,
+		0x00, 0x00, // padding
+		0x46, 0x00, 0x6f, 0x00, 0x6f, 0x00, 0x00, 0x00, // "Foo"
+		0x28, 0x00, 0x04, 0x00, 0x01, 0x00, // String
+		0x46, 0x00, 0x69, 0x00, 0x6c, 0x00, 0x65, 0x00, 0x56, 0x00, 0x65, 0x00, 0x72, 0x00, 0x73, 0x00, // "FileVersion"
+		0x69, 0x00, 0x6f, 0x00, 0x6e, 0x00, 0x00, 0x00,
+		0x00, 0x00, // padding
+		0x31, 0x00, 0x2e, 0x00, 0x32, 0x00, 0x00, 0x00, // "1.2"
+		0x44, 0x00, 0x00, 0x00, 0x01, 0x00, // VarFileInfo
+		0x56, 0x00, 0x61, 0x00, 0x72, 0x00, 0x46, 0x00, 0x69, 0x00, 0x6c, 0x00, 0x65, 0x00, 0x49, 0x00, // "VarFileInfo"
+		0x6e, 0x00, 0x66, 0x00, 0x6f, 0x00, 0x00, 0x00,
+		0x00, 0x00, // padding
+		0x24, 0x00, 0x04, 0x00, 0x00, 0x00, // Var
+		0x54, 0x00, 0x72, 0x00, 0x61, 0x00, 0x6e, 0x00, 0x73, 0x00, 0x6c, 0x00, 0x61, 0x00, 0x74, 0x00, // "Translation"
+		0x69, 0x00, 0x6f, 0x00, 0x6e, 0x00, 0x00, 0x00,
+		0x00, 0x00, // padding
+		0x09, 0x04, 0xb0, 0x04, // en-US, UTF-16
+	];
+
+	fn en_us() -> LANGID {
+		LANGID::new(co::LANG::ENGLISH, co::SUBLANG::ENGLISH_US)
+	}
+
+	#[test]
+	fn parse_fixture() {
+		let res_info = ResourceInfo::from_bytes(&FIXTURE).unwrap();
+		let fixed = res_info.version_info().unwrap();
+		assert_eq!(fixed.dwFileVersion(), [1, 2, 3, 4]);
+		assert_eq!(fixed.dwProductVersion(), [1, 2, 0, 0]);
+		assert_eq!(fixed.dwFileOS.raw(), 0x0004_0004);
+		assert_eq!(fixed.dwFileType.raw(), 1);
+
+		let blocks = res_info.blocks().collect::<Vec<_>>();
+		assert_eq!(blocks.len(), 1);
+		assert_eq!((blocks[0].lang_id(), blocks[0].code_page()), (en_us(), co::CP::UTF16));
+		assert_eq!(blocks[0].product_name().as_deref(), Some("Foo"));
+		assert_eq!(blocks[0].string("FILEVERSION").as_deref(), Some("1.2"));
+		assert_eq!(blocks[0].comments(), None);
+		assert_eq!(blocks[0].strings().collect::<Vec<_>>(),
+			[("ProductName", "Foo"), ("FileVersion", "1.2")]);
+
+		assert_eq!(res_info.serialize_to_bytes().unwrap(), FIXTURE);
+	}
+
+	#[test]
+	fn round_trip() {
+		let mut fixed = VS_FIXEDFILEINFO::default();
+		fixed.set_dwFileVersion([10, 0, 19041, 1]);
+		let pt_br = LANGID::new(co::LANG::PORTUGUESE, co::SUBLANG::PORTUGUESE_BRAZILIAN);
+
+		let mut res_info = ResourceInfo::new(fixed);
+		res_info.set_string(en_us(), co::CP::UTF16, "ProductName", "Foo");
+		res_info.set_string(pt_br, co::CP::WINDOWS_1252, "ProductName", "Coração");
+		res_info.set_string(pt_br, co::CP::WINDOWS_1252, "Empty", "");
+		res_info.set_string(en_us(), co::CP::UTF16, "PRODUCTNAME", "Bar"); // value replaced, key kept
+
+		let bytes = res_info.serialize_to_bytes().unwrap();
+		assert_eq!(bytes.len() % 4, 0);
+		let parsed = ResourceInfo::from_bytes(&bytes).unwrap();
+		assert_eq!(parsed.version_info().unwrap().dwFileVersion(), [10, 0, 19041, 1]);
+
+		let blocks = parsed.blocks().collect::<Vec<_>>();
+		assert_eq!(blocks.len(), 2);
+		assert_eq!(blocks[0].strings().collect::<Vec<_>>(), [("ProductName", "Bar")]);
+		assert_eq!((blocks[1].lang_id(), blocks[1].code_page()), (pt_br, co::CP::WINDOWS_1252));
+		assert_eq!(blocks[1].strings().collect::<Vec<_>>(),
+			[("ProductName", "Coração"), ("Empty", "")]);
+		assert_eq!(parsed.serialize_to_bytes().unwrap(), bytes);
+
+		res_info.set_version_info(None);
+		let parsed = ResourceInfo::from_bytes(&res_info.serialize_to_bytes().unwrap()).unwrap();
+		assert!(parsed.version_info().is_none());
+		assert_eq!(parsed.blocks().count(), 2);
+	}
+
+	#[test]
+	fn too_large() {
+		let mut res_info = ResourceInfo::new(VS_FIXEDFILEINFO::default());
+		res_info.set_string(en_us(), co::CP::UTF16, "Comments", &"x".repeat(32_000));
+		assert!(res_info.serialize_to_bytes().is_ok());
+
+		res_info.set_string(en_us(), co::CP::UTF16, "Comments", &"x".repeat(40_000)); // single node
+		assert_eq!(res_info.serialize_to_bytes().err(), Some(co::ERROR::INVALID_DATA));
+
+		let mut res_info = ResourceInfo::new(VS_FIXEDFILEINFO::default());
+		for key in ["Comments", "ProductName", "FileDescription"] { // whole buffer
+			res_info.set_string(en_us(), co::CP::UTF16, key, &"x".repeat(12_000));
 		}
+		assert_eq!(res_info.serialize_to_bytes().err(), Some(co::ERROR::INVALID_DATA));
+	}
+
+	#[test]
+	fn malformed() {
+		let parse_err = |data: &[u8]| ResourceInfo::from_bytes(data).err();
+
+		assert_eq!(parse_err(&[]), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(parse_err(&FIXTURE[..299]), Some(co::ERROR::INVALID_DATA));
+
+		let mut bad = FIXTURE;
+		bad[6] = b'X'; // root key
+		assert_eq!(parse_err(&bad), Some(co::ERROR::INVALID_DATA));
+
+		let mut bad = FIXTURE;
+		bad[40] = 0x00; // dwSignature
+		assert_eq!(parse_err(&bad), Some(co::ERROR::INVALID_DATA));
+
+		let mut bad = FIXTURE;
+		bad[128] = 0x04; // StringTable wLength too small for its key
+		assert_eq!(parse_err(&bad), Some(co::ERROR::INVALID_DATA));
+
+		let mut bad = FIXTURE;
+		bad[134] = b'Z'; // StringTable key not hex
+		assert_eq!(parse_err(&bad), Some(co::ERROR::INVALID_DATA));
+
+		let mut bad = FIXTURE;
+		bad[92] = 0xff; // StringFileInfo overflows the root
+		assert_eq!(parse_err(&bad), Some(co::ERROR::INVALID_DATA));
 	}
 }